@@ -21,7 +21,7 @@ async fn main() {
     let client: TmiClient<surf::Client> = TmiClient::new();
 
     let response = client
-        .get_hosts(true, HostsRequestId::Host(channel_id))
+        .get_hosts(true, HostsRequestId::Host(channel_id.into()))
         .await
         .expect("`HostsRequest::Host` failed");
 
@@ -39,7 +39,7 @@ async fn main() {
             println!("{} is hosting: {:#?}", host_name, response.hosts.first());
 
             let response = client
-                .get_hosts(true, HostsRequestId::Target(*target_id))
+                .get_hosts(true, HostsRequestId::Target(target_id.clone()))
                 .await
                 .expect("`HostsRequest::Target` failed");
 
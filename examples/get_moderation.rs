@@ -1,6 +1,9 @@
 use futures::TryStreamExt;
 use twitch_api2::{
-    helix::moderation::{GetBannedEventsRequest, GetBannedUsersRequest, GetModeratorEventsRequest},
+    helix::{
+        moderation::{GetBannedEventsRequest, GetBannedUsersRequest, GetModeratorEventsRequest},
+        PageSize, PaginationParams,
+    },
     HelixClient,
 };
 use twitch_oauth2::{AccessToken, UserToken};
@@ -88,7 +91,7 @@ async fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>>
     println!("====Last 10 Banned Events====");
     let banned_events_req = GetBannedEventsRequest::builder()
         .broadcaster_id(broadcaster_id)
-        .first(Some(10))
+        .pagination(PaginationParams::default().with_first(PageSize::new(10)?))
         .build();
     let mut response = client.req_get(banned_events_req, &token).await?;
     println!("{:?}", response.data);
@@ -159,7 +159,7 @@ pub async fn moderation<'a, C: twitch_api2::HttpClient<'a> + Sync>(
     println!("====Last 10 Banned Events====");
     let banned_events_req = GetBannedEventsRequest::builder()
         .broadcaster_id(broadcaster_id)
-        .first(Some(10))
+        .pagination(helix::PaginationParams::default().with_first(helix::PageSize::new(10)?))
         .build();
     let mut response = client.req_get(banned_events_req, token).await?;
     println!("{:?}", response.data);
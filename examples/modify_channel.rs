@@ -37,7 +37,7 @@ async fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>>
         .build();
 
     let data = twitch_api2::helix::channels::ModifyChannelInformationBody::builder()
-        .title("Hello World!".to_string())
+        .title("Hello World!")
         .build();
 
     println!("scopes: {:?}", token.scopes());
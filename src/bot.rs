@@ -0,0 +1,989 @@
+//! A small framework for bots, built on [`HelixClient`] and [EventSub over websockets](crate::eventsub::websocket)
+//!
+//! [`Bot`] keeps a [`WebsocketEventSubClient`] session alive over a [`Connection`] you provide,
+//! creates the requested subscriptions on every new session, refreshes the [`UserToken`] before it expires
+//! and hands the events to your [`Handler`].
+//!
+//! # Examples
+//!
+//! Fulfill every channel point redemption on a channel
+//!
+//! ```rust, no_run
+//! use twitch_api2::{
+//!     bot::{Bot, Context, Handler},
+//!     eventsub::channel::{
+//!         ChannelPointsCustomRewardRedemptionAddV1,
+//!         ChannelPointsCustomRewardRedemptionAddV1Payload,
+//!     },
+//!     helix::{self, points},
+//! };
+//! # type MyConnection = twitch_api2::bot::DummyConnection;
+//! # type Client = twitch_api2::client::DummyHttpClient;
+//!
+//! struct Fulfill;
+//!
+//! #[async_trait::async_trait]
+//! impl<'a> Handler<'a, Client> for Fulfill {
+//!     async fn on_redemption(
+//!         &mut self,
+//!         ctx: &Context<'_, 'a, Client>,
+//!         redemption: ChannelPointsCustomRewardRedemptionAddV1Payload,
+//!     ) {
+//!         let request = points::UpdateRedemptionStatusRequest::builder()
+//!             .broadcaster_id(redemption.broadcaster_user_id)
+//!             .reward_id(redemption.reward.id)
+//!             .id(redemption.id)
+//!             .build();
+//!         let body = points::UpdateRedemptionStatusBody::builder()
+//!             .status(points::CustomRewardRedemptionStatus::Fulfilled)
+//!             .build();
+//!         let _ = ctx.client.req_patch(request, body, ctx.token).await;
+//!     }
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! let client: helix::HelixClient<Client> = helix::HelixClient::default();
+//! let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let redemptions = ChannelPointsCustomRewardRedemptionAddV1::builder()
+//!     .broadcaster_user_id(token.user_id.clone())
+//!     .build();
+//! Bot::new(&client, MyConnection::default(), token)
+//!     .subscribe(redemptions)?
+//!     .run(&mut Fulfill)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Announce the text after `!announce` in chat
+//!
+//! ```rust, no_run
+//! use twitch_api2::{
+//!     bot::{Bot, Command, Context},
+//!     eventsub::channel::{ChannelChatMessageV1, ChannelChatMessageV1Payload},
+//!     helix::{self, chat::send_chat_announcement},
+//! };
+//! # type MyConnection = twitch_api2::bot::DummyConnection;
+//! # type Client = twitch_api2::client::DummyHttpClient;
+//!
+//! struct Announce;
+//!
+//! #[async_trait::async_trait]
+//! impl<'a> Command<'a, Client> for Announce {
+//!     async fn run(
+//!         &mut self,
+//!         ctx: &Context<'_, 'a, Client>,
+//!         args: &str,
+//!         message: &ChannelChatMessageV1Payload,
+//!     ) {
+//!         let request = send_chat_announcement::SendChatAnnouncementRequest::builder()
+//!             .auth(helix::ModeratorAuth::new(
+//!                 message.broadcaster_user_id.clone(),
+//!                 ctx.token.user_id.clone(),
+//!             ))
+//!             .build();
+//!         let body = send_chat_announcement::SendChatAnnouncementBody::builder()
+//!             .message(args)
+//!             .build();
+//!         let _ = ctx.client.req_post(request, body, ctx.token).await;
+//!     }
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! let client: helix::HelixClient<Client> = helix::HelixClient::default();
+//! let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! // commands are read from the chat messages of this subscription
+//! let chat = ChannelChatMessageV1::builder()
+//!     .broadcaster_user_id(token.user_id.clone())
+//!     .user_id(token.user_id.clone())
+//!     .build();
+//! Bot::new(&client, MyConnection::default(), token)
+//!     .subscribe(chat)?
+//!     .command("announce", Announce)
+//!     .run(&mut ())
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use twitch_oauth2::{TwitchToken, UserToken};
+
+use crate::{
+    eventsub::{
+        channel::{ChannelChatMessageV1Payload, ChannelPointsCustomRewardRedemptionAddV1Payload},
+        websocket::{Action, WebsocketEventSubClient},
+        Event, EventSubscription, EventType, Message, Payload, PayloadParseError,
+    },
+    helix::{ClientRequestError, HelixClient},
+};
+
+/// Tokens expiring in less than this are refreshed before they are used
+const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+/// Prefix of chat commands used until [`Bot::prefix`] is called
+const DEFAULT_PREFIX: &str = "!";
+
+/// A websocket connection to Twitch, bring your favorite websocket client.
+///
+/// During a reconnect two connections are open: the new connection is opened with [`connect`](Connection::connect),
+/// and messages are read from it, but the old one must be kept open until [`close_old`](Connection::close_old).
+#[async_trait::async_trait]
+pub trait Connection: Send {
+    /// Error returned by the connection
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Open a connection to `url`
+    async fn connect(&mut self, url: &str) -> Result<(), Self::Error>;
+
+    /// Close the connection that was active before the last [`connect`](Connection::connect)
+    async fn close_old(&mut self) -> Result<(), Self::Error>;
+
+    /// Wait for the next message on the current connection, or until `deadline` passes.
+    async fn next_message(&mut self, deadline: Option<Instant>) -> Result<Incoming, Self::Error>;
+
+    /// Wait for `duration` before reconnecting, e.g. with `tokio::time::sleep`
+    async fn sleep(&mut self, duration: Duration);
+}
+
+/// What happened on a [`Connection`]
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[non_exhaustive]
+pub enum Incoming {
+    /// A text message was received
+    Text(String),
+    /// The deadline passed without a message
+    Timeout,
+    /// The connection was closed
+    Closed,
+}
+
+/// A [`Connection`] that never receives anything, for examples and tests.
+#[doc(hidden)]
+#[derive(Default, Debug)]
+pub struct DummyConnection;
+
+#[async_trait::async_trait]
+impl Connection for DummyConnection {
+    type Error = std::io::Error;
+
+    async fn connect(&mut self, _: &str) -> Result<(), Self::Error> { Ok(()) }
+
+    async fn close_old(&mut self) -> Result<(), Self::Error> { Ok(()) }
+
+    async fn next_message(&mut self, _: Option<Instant>) -> Result<Incoming, Self::Error> {
+        Ok(Incoming::Closed)
+    }
+
+    async fn sleep(&mut self, _: Duration) {}
+}
+
+/// What a [`Handler`] can use to act on events
+#[non_exhaustive]
+pub struct Context<'c, 'a, C: crate::HttpClient<'a>> {
+    /// The client used by the bot
+    pub client: &'a HelixClient<'a, C>,
+    /// The token of the bot, refreshed if needed before the handler is called
+    pub token: &'c UserToken,
+}
+
+/// Handles the events received by a [`Bot`]
+///
+/// Every event is passed to [`on_event`](Handler::on_event), which by default dispatches to the more specific methods.
+#[async_trait::async_trait]
+pub trait Handler<'a, C: crate::HttpClient<'a> + Sync>: Send {
+    /// An event was received
+    async fn on_event(&mut self, ctx: &Context<'_, 'a, C>, event: Event) {
+        match event {
+            Event::ChannelPointsCustomRewardRedemptionAddV1(Payload {
+                message: Message::Notification(redemption),
+                ..
+            }) => self.on_redemption(ctx, redemption).await,
+            Event::ChannelChatMessageV1(Payload {
+                message: Message::Notification(message),
+                ..
+            }) => self.on_chat_message(ctx, message).await,
+            _ => {}
+        }
+    }
+
+    /// A chat message was sent, after it was passed to its [`Command`] if it is one
+    async fn on_chat_message(
+        &mut self,
+        _ctx: &Context<'_, 'a, C>,
+        _message: ChannelChatMessageV1Payload,
+    ) {
+    }
+
+    /// A channel point reward was redeemed
+    async fn on_redemption(
+        &mut self,
+        _ctx: &Context<'_, 'a, C>,
+        _redemption: ChannelPointsCustomRewardRedemptionAddV1Payload,
+    ) {
+    }
+
+    /// A subscription was revoked by Twitch
+    async fn on_revocation(&mut self, _ctx: &Context<'_, 'a, C>, _event: Event) {}
+
+    /// A subscription could not be created for a new session.
+    ///
+    /// The bot keeps running with the other subscriptions, the subscription is tried again on the next session.
+    async fn on_subscribe_error(
+        &mut self,
+        _ctx: &Context<'_, 'a, C>,
+        _event_type: EventType,
+        _error: ClientRequestError<<C as crate::HttpClient<'a>>::Error>,
+    ) {
+    }
+}
+
+/// A [`Handler`] that ignores all events, for bots that only use [commands](Command)
+#[async_trait::async_trait]
+impl<'a, C: crate::HttpClient<'a> + Sync> Handler<'a, C> for () {}
+
+/// A chat command, registered with [`Bot::command`]
+///
+/// Commands are read from the chat messages of a [`ChannelChatMessageV1`](crate::eventsub::channel::ChannelChatMessageV1) subscription.
+#[async_trait::async_trait]
+pub trait Command<'a, C: crate::HttpClient<'a> + Sync>: Send {
+    /// The command was used in `message`, `args` is the text after the name of the command
+    async fn run(
+        &mut self,
+        ctx: &Context<'_, 'a, C>,
+        args: &str,
+        message: &ChannelChatMessageV1Payload,
+    );
+}
+
+/// Errors that stop a [`Bot`]
+#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[non_exhaustive]
+pub enum BotError<CE, RE>
+where
+    CE: std::error::Error + Send + Sync + 'static,
+    RE: std::error::Error + Send + Sync + 'static, {
+    /// connection failed
+    Connection(#[source] CE),
+    /// could not parse websocket message
+    Parse(#[from] PayloadParseError),
+    /// could not refresh token
+    Refresh(#[source] twitch_oauth2::tokens::errors::RefreshTokenError<RE>),
+    /// could not serialize subscription condition
+    Condition(#[from] serde_json::Error),
+    /// gave up reconnecting after {attempts} failed attempts
+    Reconnect {
+        /// How many times reconnecting failed
+        attempts: u32,
+    },
+}
+
+/// An EventSub subscription to create on every session
+#[derive(Debug, Clone)]
+struct Subscription {
+    type_: EventType,
+    version: &'static str,
+    condition: serde_json::Value,
+}
+
+/// A bot, see the [module documentation](self)
+pub struct Bot<'a, C: crate::HttpClient<'a> + Sync, W> {
+    client: &'a HelixClient<'a, C>,
+    connection: W,
+    token: UserToken,
+    websocket: WebsocketEventSubClient,
+    subscriptions: Vec<Subscription>,
+    prefix: String,
+    commands: HashMap<String, Box<dyn Command<'a, C> + 'a>>,
+}
+
+impl<'a, C, W> Bot<'a, C, W>
+where
+    C: crate::HttpClient<'a> + Sync,
+    W: Connection,
+{
+    /// Create a bot using `client` and `token`, receiving events on `connection`
+    pub fn new(client: &'a HelixClient<'a, C>, connection: W, token: UserToken) -> Self {
+        Bot {
+            client,
+            connection,
+            token,
+            websocket: WebsocketEventSubClient::new(),
+            subscriptions: vec![],
+            prefix: DEFAULT_PREFIX.to_owned(),
+            commands: HashMap::new(),
+        }
+    }
+
+    /// Subscribe to `subscription` on every session
+    pub fn subscribe<E: EventSubscription>(
+        mut self,
+        subscription: E,
+    ) -> Result<Self, serde_json::Error> {
+        self.subscriptions.push(Subscription {
+            type_: E::EVENT_TYPE,
+            version: E::VERSION,
+            condition: subscription.condition()?,
+        });
+        Ok(self)
+    }
+
+    /// Run `command` when a chat message starts with the [prefix](Bot::prefix) and `name`, ignoring case.
+    ///
+    /// Registering a command with the same name again replaces it.
+    pub fn command(mut self, name: &str, command: impl Command<'a, C> + 'a) -> Self {
+        self.commands.insert(name.to_lowercase(), Box::new(command));
+        self
+    }
+
+    /// Set the prefix of [commands](Bot::command), `!` by default
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// The token of the bot
+    pub fn token(&self) -> &UserToken { &self.token }
+
+    /// Run the bot, passing events to `handler`.
+    ///
+    /// Only returns on errors. Losing the connection reconnects, waiting longer after every failed attempt,
+    /// until [`BotError::Reconnect`] after 10 failed attempts in a row.
+    /// Chat messages starting with a [command](Bot::command) are passed to the command before the handler.
+    /// Subscriptions that can't be created are passed to [`Handler::on_subscribe_error`] and don't stop the bot.
+    pub async fn run<H>(
+        mut self,
+        handler: &mut H,
+    ) -> Result<(), BotError<W::Error, <C as crate::HttpClient<'a>>::Error>>
+    where
+        H: Handler<'a, C>,
+    {
+        let mut actions = vec![self.websocket.connect()];
+        loop {
+            for action in std::mem::take(&mut actions) {
+                match action {
+                    Action::Connect { url } => self
+                        .connection
+                        .connect(&url)
+                        .await
+                        .map_err(BotError::Connection)?,
+                    Action::CloseOld => self
+                        .connection
+                        .close_old()
+                        .await
+                        .map_err(BotError::Connection)?,
+                    Action::Reconnect { url, delay } => {
+                        self.connection.sleep(delay).await;
+                        self.connection
+                            .connect(&url)
+                            .await
+                            .map_err(BotError::Connection)?;
+                        self.connection
+                            .close_old()
+                            .await
+                            .map_err(BotError::Connection)?
+                    }
+                    Action::Subscribe { session_id } => {
+                        self.refresh_token().await?;
+                        for subscription in &self.subscriptions {
+                            if let Err(e) = self
+                                .client
+                                .create_websocket_subscription(
+                                    subscription.type_.clone(),
+                                    subscription.version.to_owned(),
+                                    subscription.condition.clone(),
+                                    &session_id,
+                                    &self.token,
+                                )
+                                .await
+                            {
+                                handler
+                                    .on_subscribe_error(
+                                        &self.context(),
+                                        subscription.type_.clone(),
+                                        e,
+                                    )
+                                    .await
+                            }
+                        }
+                    }
+                    Action::GiveUp { attempts } => return Err(BotError::Reconnect { attempts }),
+                    Action::Event(event) => {
+                        self.refresh_token().await?;
+                        let ctx = Context {
+                            client: self.client,
+                            token: &self.token,
+                        };
+                        if let Event::ChannelChatMessageV1(Payload {
+                            message: Message::Notification(message),
+                            ..
+                        }) = &event
+                        {
+                            if let Some((name, args)) =
+                                parse_command(&self.prefix, &message.message.text)
+                            {
+                                if let Some(command) = self.commands.get_mut(&name.to_lowercase()) {
+                                    command.run(&ctx, args, message).await
+                                }
+                            }
+                        }
+                        handler.on_event(&ctx, event).await
+                    }
+                    Action::Revoked(event) => handler.on_revocation(&self.context(), event).await,
+                    _ => {}
+                }
+            }
+            match self
+                .connection
+                .next_message(self.websocket.keepalive_deadline())
+                .await
+                .map_err(BotError::Connection)?
+            {
+                Incoming::Text(text) => {
+                    actions = self.websocket.handle_message(&text, Instant::now())?
+                }
+                Incoming::Timeout => actions.extend(self.websocket.check_keepalive(Instant::now())),
+                Incoming::Closed => {
+                    actions.extend(self.websocket.connection_closed(Instant::now()))
+                }
+            }
+        }
+    }
+
+    fn context(&self) -> Context<'_, 'a, C> {
+        Context {
+            client: self.client,
+            token: &self.token,
+        }
+    }
+
+    async fn refresh_token(
+        &mut self,
+    ) -> Result<(), BotError<W::Error, <C as crate::HttpClient<'a>>::Error>> {
+        if self.token.expires_in() < REFRESH_MARGIN {
+            self.token
+                .refresh_token(self.client)
+                .await
+                .map_err(BotError::Refresh)?;
+        }
+        Ok(())
+    }
+}
+
+/// Split `text` into the name and arguments of a command, if it starts with `prefix`
+fn parse_command<'t>(prefix: &str, text: &'t str) -> Option<(&'t str, &'t str)> {
+    let text = text.strip_prefix(prefix)?;
+    let (name, args) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+    if name.is_empty() {
+        return None;
+    }
+    Some((name, args.trim_start()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::VecDeque,
+        sync::{Arc, Mutex},
+    };
+
+    use super::*;
+    use crate::client::{BoxedFuture, Req, Response};
+    use crate::eventsub::channel::ChannelPointsCustomRewardRedemptionAddV1;
+
+    type Log = Arc<Mutex<Vec<String>>>;
+
+    /// A [`Connection`] replaying `script`, failing once it's exhausted
+    struct ScriptedConnection {
+        script: VecDeque<Incoming>,
+        log: Log,
+    }
+
+    #[async_trait::async_trait]
+    impl Connection for ScriptedConnection {
+        type Error = std::io::Error;
+
+        async fn connect(&mut self, url: &str) -> Result<(), Self::Error> {
+            self.log.lock().unwrap().push(format!("connect {}", url));
+            Ok(())
+        }
+
+        async fn close_old(&mut self) -> Result<(), Self::Error> {
+            self.log.lock().unwrap().push("close_old".to_owned());
+            Ok(())
+        }
+
+        async fn next_message(&mut self, _: Option<Instant>) -> Result<Incoming, Self::Error> {
+            self.script
+                .pop_front()
+                .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::Other, "end of script"))
+        }
+
+        async fn sleep(&mut self, duration: Duration) {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("sleep {:?}", duration));
+        }
+    }
+
+    /// Client answering token refreshes and subscriptions, failing subscriptions for the broadcaster `fail`
+    struct MockClient(Log);
+
+    impl<'a> crate::HttpClient<'a> for MockClient {
+        type Error = crate::DummyHttpClient;
+
+        fn req(&'a self, request: Req) -> BoxedFuture<'a, Result<Response, Self::Error>> {
+            let (status, body) = match request.uri().path() {
+                "/oauth2/token" => {
+                    self.0.lock().unwrap().push("refresh".to_owned());
+                    (
+                        200,
+                        r#"{"access_token":"newtoken","refresh_token":"newrefresh","expires_in":14400,"scope":[],"token_type":"bearer"}"#.to_owned(),
+                    )
+                }
+                "/helix/eventsub/subscriptions" => {
+                    let body: serde_json::Value = serde_json::from_slice(request.body()).unwrap();
+                    let broadcaster = body["condition"]["broadcaster_user_id"].as_str().unwrap();
+                    let session_id = body["transport"]["session_id"].as_str().unwrap();
+                    self.0
+                        .lock()
+                        .unwrap()
+                        .push(format!("subscribe {} {}", session_id, broadcaster));
+                    if broadcaster == "fail" {
+                        (
+                            400,
+                            r#"{"error":"Bad Request","status":400,"message":"invalid condition"}"#
+                                .to_owned(),
+                        )
+                    } else {
+                        (
+                            202,
+                            serde_json::json!({
+                                "data": [{
+                                    "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+                                    "status": "enabled",
+                                    "type": body["type"],
+                                    "version": body["version"],
+                                    "condition": body["condition"],
+                                    "created_at": "2022-11-16T10:11:12.464757833Z",
+                                    "transport": body["transport"],
+                                    "cost": 0
+                                }],
+                                "total": 1,
+                                "total_cost": 0,
+                                "max_total_cost": 10000
+                            })
+                            .to_string(),
+                        )
+                    }
+                }
+                path => panic!("unexpected request to {}", path),
+            };
+            let response = http::Response::builder()
+                .status(status)
+                .body(body.into_bytes())
+                .unwrap();
+            Box::pin(async { Ok(response) })
+        }
+    }
+
+    struct LogHandler(Log);
+
+    #[async_trait::async_trait]
+    impl<'a> Handler<'a, MockClient> for LogHandler {
+        async fn on_event(&mut self, ctx: &Context<'_, 'a, MockClient>, event: Event) {
+            assert!(matches!(event, Event::ChannelFollowV1(_)));
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("event {}", ctx.token.access_token.secret()));
+        }
+
+        async fn on_revocation(&mut self, _: &Context<'_, 'a, MockClient>, event: Event) {
+            assert!(matches!(
+                event,
+                Event::ChannelFollowV1(Payload {
+                    message: Message::Revocation(),
+                    ..
+                })
+            ));
+            self.0.lock().unwrap().push("revoked".to_owned());
+        }
+
+        async fn on_subscribe_error(
+            &mut self,
+            _: &Context<'_, 'a, MockClient>,
+            event_type: EventType,
+            error: ClientRequestError<crate::DummyHttpClient>,
+        ) {
+            assert!(matches!(
+                error,
+                ClientRequestError::HelixRequestError(crate::helix::HelixRequestError::Error {
+                    status,
+                    ..
+                }) if status == http::StatusCode::BAD_REQUEST
+            ));
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("subscribe error {:?}", event_type));
+        }
+    }
+
+    fn welcome(session_id: &str) -> Incoming {
+        Incoming::Text(format!(
+            r#"{{
+    "metadata": {{
+        "message_id": "{0}-welcome",
+        "message_type": "session_welcome",
+        "message_timestamp": "2022-11-16T10:11:12.634234626Z"
+    }},
+    "payload": {{
+        "session": {{
+            "id": "{0}",
+            "status": "connected",
+            "connected_at": "2022-11-16T10:11:12.634234626Z",
+            "keepalive_timeout_seconds": 10,
+            "reconnect_url": null
+        }}
+    }}
+}}"#,
+            session_id
+        ))
+    }
+
+    fn message(message_type: &str, payload: &str) -> Incoming {
+        Incoming::Text(format!(
+            r#"{{
+    "metadata": {{
+        "message_id": "{0}-message",
+        "message_type": "{0}",
+        "message_timestamp": "2022-11-16T10:11:12.464757833Z",
+        "subscription_type": "channel.follow",
+        "subscription_version": "1"
+    }},
+    "payload": {1}
+}}"#,
+            message_type, payload
+        ))
+    }
+
+    const SUBSCRIPTION: &str = r#"{
+        "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+        "status": "enabled",
+        "type": "channel.follow",
+        "version": "1",
+        "cost": 1,
+        "condition": {
+            "broadcaster_user_id": "12826"
+        },
+        "transport": {
+            "method": "websocket",
+            "session_id": "session1"
+        },
+        "created_at": "2022-11-16T10:11:12.464757833Z"
+    }"#;
+
+    #[tokio::test]
+    async fn run() {
+        let log = Log::default();
+        let notification = format!(
+            r#"{{
+    "subscription": {},
+    "event": {{
+        "user_id": "1337",
+        "user_login": "awesome_user",
+        "user_name": "Awesome_User",
+        "broadcaster_user_id": "12826",
+        "broadcaster_user_login": "twitch",
+        "broadcaster_user_name": "Twitch",
+        "followed_at": "2022-11-16T10:11:12.464757833Z"
+    }}
+}}"#,
+            SUBSCRIPTION
+        );
+        let revocation = format!(
+            r#"{{"subscription": {}}}"#,
+            SUBSCRIPTION.replace(r#""enabled""#, r#""authorization_revoked""#)
+        );
+        let reconnect = Incoming::Text(
+            r#"{
+    "metadata": {
+        "message_id": "84c1e79a-2a4b-4c13-ba0b-4312293e9308",
+        "message_type": "session_reconnect",
+        "message_timestamp": "2022-11-18T09:10:11.634234626Z"
+    },
+    "payload": {
+        "session": {
+           "id": "session1",
+           "status": "reconnecting",
+           "keepalive_timeout_seconds": null,
+           "reconnect_url": "wss://reconnect.example.com",
+           "connected_at": "2022-11-16T10:11:12.634234626Z"
+        }
+    }
+}"#
+            .to_owned(),
+        );
+        let connection = ScriptedConnection {
+            script: vec![
+                welcome("session1"),
+                message("notification", &notification),
+                reconnect,
+                // the new connection takes over the session, nothing to subscribe to
+                welcome("session1"),
+                message("revocation", &revocation),
+                Incoming::Closed,
+                // a new session, all subscriptions are created again
+                welcome("session2"),
+            ]
+            .into(),
+            log: log.clone(),
+        };
+
+        // an expired token, refreshed before it's used
+        let token = UserToken::from_existing_unchecked(
+            twitch_oauth2::AccessToken::new("token".to_string()),
+            twitch_oauth2::RefreshToken::new("refresh".to_string()),
+            twitch_oauth2::ClientId::new("clientid".to_string()),
+            twitch_oauth2::ClientSecret::new("secret".to_string()),
+            "justintv".to_string(),
+            "1337".to_string(),
+            None,
+            Some(Duration::from_secs(0)),
+        );
+        let client = HelixClient::with_client(MockClient(log.clone()));
+        let result = Bot::new(&client, connection, token)
+            .subscribe(
+                ChannelPointsCustomRewardRedemptionAddV1::builder()
+                    .broadcaster_user_id("1337")
+                    .build(),
+            )
+            .unwrap()
+            .subscribe(
+                ChannelPointsCustomRewardRedemptionAddV1::builder()
+                    .broadcaster_user_id("fail")
+                    .build(),
+            )
+            .unwrap()
+            .run(&mut LogHandler(log.clone()))
+            .await;
+        assert!(matches!(result, Err(BotError::Connection(_))));
+
+        let url = crate::TWITCH_EVENTSUB_WEBSOCKET_URL.to_string();
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                format!("connect {}", url),
+                "refresh".to_owned(),
+                "subscribe session1 1337".to_owned(),
+                "subscribe session1 fail".to_owned(),
+                "subscribe error ChannelPointsCustomRewardRedemptionAdd".to_owned(),
+                "event newtoken".to_owned(),
+                "connect wss://reconnect.example.com".to_owned(),
+                "close_old".to_owned(),
+                "revoked".to_owned(),
+                "sleep 0ns".to_owned(),
+                format!("connect {}", url),
+                "close_old".to_owned(),
+                "subscribe session2 1337".to_owned(),
+                "subscribe session2 fail".to_owned(),
+                "subscribe error ChannelPointsCustomRewardRedemptionAdd".to_owned(),
+            ]
+        );
+    }
+
+    fn token() -> UserToken {
+        UserToken::from_existing_unchecked(
+            twitch_oauth2::AccessToken::new("token".to_string()),
+            twitch_oauth2::RefreshToken::new("refresh".to_string()),
+            twitch_oauth2::ClientId::new("clientid".to_string()),
+            twitch_oauth2::ClientSecret::new("secret".to_string()),
+            "justintv".to_string(),
+            "1337".to_string(),
+            None,
+            Some(Duration::from_secs(3600)),
+        )
+    }
+
+    #[tokio::test]
+    async fn reconnect_backoff() {
+        let log = Log::default();
+        let mut script = vec![welcome("session1")];
+        // the connection is lost, and every new connection is closed before its welcome message
+        script.extend(std::iter::repeat(Incoming::Closed).take(11));
+        let connection = ScriptedConnection {
+            script: script.into(),
+            log: log.clone(),
+        };
+        let client = HelixClient::with_client(MockClient(log.clone()));
+        let result = Bot::new(&client, connection, token()).run(&mut ()).await;
+        assert!(matches!(result, Err(BotError::Reconnect { attempts: 10 })));
+
+        let url = crate::TWITCH_EVENTSUB_WEBSOCKET_URL.to_string();
+        let mut expected = vec![format!("connect {}", url)];
+        for delay in &[0, 1, 2, 4, 8, 16, 32, 60, 60, 60] {
+            expected.push(format!("sleep {:?}", Duration::from_secs(*delay)));
+            expected.push(format!("connect {}", url));
+            expected.push("close_old".to_owned());
+        }
+        assert_eq!(*log.lock().unwrap(), expected);
+    }
+
+    struct LogCommand(&'static str, Log);
+
+    #[async_trait::async_trait]
+    impl<'a> Command<'a, MockClient> for LogCommand {
+        async fn run(
+            &mut self,
+            _: &Context<'_, 'a, MockClient>,
+            args: &str,
+            message: &ChannelChatMessageV1Payload,
+        ) {
+            self.1.lock().unwrap().push(format!(
+                "{} {:?} from {}",
+                self.0, args, message.chatter_user_login
+            ));
+        }
+    }
+
+    struct ChatHandler(Log);
+
+    #[async_trait::async_trait]
+    impl<'a> Handler<'a, MockClient> for ChatHandler {
+        async fn on_chat_message(
+            &mut self,
+            _: &Context<'_, 'a, MockClient>,
+            message: ChannelChatMessageV1Payload,
+        ) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("chat {:?}", message.message.text));
+        }
+    }
+
+    fn chat(id: usize, text: &str) -> Incoming {
+        Incoming::Text(
+            serde_json::json!({
+                "metadata": {
+                    "message_id": format!("chat-{}", id),
+                    "message_type": "notification",
+                    "message_timestamp": "2023-11-06T18:11:47.492253549Z",
+                    "subscription_type": "channel.chat.message",
+                    "subscription_version": "1"
+                },
+                "payload": {
+                    "subscription": {
+                        "id": "0b7f3361-672b-4d39-b307-dd5b576c9b27",
+                        "type": "channel.chat.message",
+                        "version": "1",
+                        "status": "enabled",
+                        "cost": 0,
+                        "condition": {
+                            "broadcaster_user_id": "1337",
+                            "user_id": "1337"
+                        },
+                        "transport": {
+                            "method": "websocket",
+                            "session_id": "session1"
+                        },
+                        "created_at": "2023-11-06T18:11:47.492253549Z"
+                    },
+                    "event": {
+                        "broadcaster_user_id": "1337",
+                        "broadcaster_user_login": "justintv",
+                        "broadcaster_user_name": "JustinTV",
+                        "chatter_user_id": "4145994",
+                        "chatter_user_login": "viewer32",
+                        "chatter_user_name": "viewer32",
+                        "message_id": format!("chat-{}", id),
+                        "message": {
+                            "text": text,
+                            "fragments": [{
+                                "type": "text",
+                                "text": text,
+                                "cheermote": null,
+                                "emote": null,
+                                "mention": null
+                            }]
+                        },
+                        "color": "",
+                        "badges": [],
+                        "message_type": "text",
+                        "cheer": null,
+                        "reply": null,
+                        "channel_points_custom_reward_id": null
+                    }
+                }
+            })
+            .to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn commands() {
+        let log = Log::default();
+        let connection = ScriptedConnection {
+            script: vec![
+                welcome("session1"),
+                chat(0, "!hello  chat, how are you?"),
+                chat(1, "!HELLO"),
+                chat(2, "?dice 2d6"),
+                chat(3, "!dice"),
+                chat(4, "hello !hello"),
+                chat(5, "!unknown command"),
+                chat(6, "!"),
+            ]
+            .into(),
+            log: log.clone(),
+        };
+        let client = HelixClient::with_client(MockClient(log.clone()));
+        let result = Bot::new(&client, connection, token())
+            .command("hello", LogCommand("hello", log.clone()))
+            .command("Dice", LogCommand("dice", log.clone()))
+            .command("dice", LogCommand("new dice", log.clone()))
+            .run(&mut ChatHandler(log.clone()))
+            .await;
+        assert!(matches!(result, Err(BotError::Connection(_))));
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                format!("connect {}", crate::TWITCH_EVENTSUB_WEBSOCKET_URL),
+                r#"hello "chat, how are you?" from viewer32"#.to_owned(),
+                r#"chat "!hello  chat, how are you?""#.to_owned(),
+                r#"hello "" from viewer32"#.to_owned(),
+                r#"chat "!HELLO""#.to_owned(),
+                r#"chat "?dice 2d6""#.to_owned(),
+                // replaced by the second `dice`
+                r#"new dice "" from viewer32"#.to_owned(),
+                r#"chat "!dice""#.to_owned(),
+                r#"chat "hello !hello""#.to_owned(),
+                r#"chat "!unknown command""#.to_owned(),
+                r#"chat "!""#.to_owned(),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_command() {
+        assert_eq!(
+            super::parse_command("!", "!hello  chat, how are you?"),
+            Some(("hello", "chat, how are you?"))
+        );
+        assert_eq!(super::parse_command("!", "!hello"), Some(("hello", "")));
+        assert_eq!(super::parse_command("!", "! hello"), None);
+        assert_eq!(super::parse_command("!", "hello !hello"), None);
+        assert_eq!(
+            super::parse_command("~~", "~~so\tjustintv"),
+            Some(("so", "justintv"))
+        );
+    }
+}
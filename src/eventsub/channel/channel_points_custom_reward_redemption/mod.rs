@@ -18,7 +18,13 @@ pub use update::{
 };
 
 /// Basic information about the reward that was redeemed, at the time it was redeemed.
+///
+/// Use [`HelixClient::get_custom_reward_from_id`](crate::helix::HelixClient::get_custom_reward_from_id) to get the full reward.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Reward {
@@ -34,8 +40,12 @@ pub struct Reward {
 
 /// Custom reward redemption statuses: UNFULFILLED, FULFILLED or CANCELED
 ///
-/// See also [`CustomRewardRedemptionStatus`](crate::helix::points::CustomRewardRedemptionStatus)
+/// See also [`CustomRewardRedemptionStatus`](types::CustomRewardRedemptionStatus), used by [`update`]
 #[derive(PartialEq, Eq, Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[serde(rename_all = "lowercase")]
 pub enum RedemptionStatus {
     /// Unfulfilled reward - the user has claimed it but it is still pending.
@@ -4,6 +4,10 @@
 use super::*;
 /// [`channel.channel_points_custom_reward_redemption.update`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelchannel_points_custom_reward_redemptionupdate): a redemption of a channel points custom reward has been updated for the specified channel.
 #[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPointsCustomRewardRedemptionUpdateV1 {
@@ -26,6 +30,10 @@ impl EventSubscription for ChannelPointsCustomRewardRedemptionUpdateV1 {
 
 /// [`channel.channel_points_custom_reward_redemption.update`](ChannelPointsCustomRewardRedemptionUpdateV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPointsCustomRewardRedemptionUpdateV1Payload {
@@ -41,8 +49,9 @@ pub struct ChannelPointsCustomRewardRedemptionUpdateV1Payload {
     pub redeemed_at: types::Timestamp,
     /// Basic information about the reward that was redeemed, at the time it was redeemed.
     pub reward: Reward,
-    /// Will be fulfilled or canceled. Possible values are unknown, unfulfilled, fulfilled, and canceled.
-    pub status: RedemptionStatus,
+    /// Will be fulfilled or canceled, or [unknown](types::CustomRewardRedemptionStatus::Unknown).
+    #[serde(serialize_with = "serialize_status")]
+    pub status: types::CustomRewardRedemptionStatus,
     /// User ID of the user that redeemed the reward.
     pub user_id: types::UserId,
     /// Login of the user that redeemed the reward.
@@ -53,6 +62,27 @@ pub struct ChannelPointsCustomRewardRedemptionUpdateV1Payload {
     pub user_input: String,
 }
 
+impl ChannelPointsCustomRewardRedemptionUpdateV1Payload {
+    /// Whether the redemption was fulfilled
+    pub fn was_fulfilled(&self) -> bool {
+        self.status == types::CustomRewardRedemptionStatus::Fulfilled
+    }
+
+    /// Whether the redemption was canceled, refunding the spent points to the user
+    pub fn was_refunded(&self) -> bool { self.status.is_refunded() }
+}
+
+/// Serialize the status in lowercase, as EventSub sends it
+fn serialize_status<S>(
+    status: &types::CustomRewardRedemptionStatus,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(status.as_lowercase_str())
+}
+
 #[cfg(test)]
 #[test]
 fn parse_payload() {
@@ -95,5 +125,78 @@ fn parse_payload() {
     "##;
 
     let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
-    crate::tests::roundtrip(&val)
+    crate::tests::roundtrip(&val);
+    if let crate::eventsub::Event::ChannelPointsCustomRewardRedemptionUpdateV1(
+        crate::eventsub::Payload {
+            message: crate::eventsub::Message::Notification(notification),
+            ..
+        },
+    ) = val
+    {
+        assert!(notification.was_fulfilled());
+        assert!(!notification.was_refunded());
+    } else {
+        panic!("invalid event type")
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn parse_payload_unknown_status() {
+    let payload = r##"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "channel.channel_points_custom_reward_redemption.update",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "1337"
+            },
+             "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "id": "1234",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "user_id": "9001",
+            "user_login": "cooler_user",
+            "user_name": "Cooler_User",
+            "user_input": "pogchamp",
+            "status": "unknown",
+            "reward": {
+                "id": "9001",
+                "title": "title",
+                "cost": 100,
+                "prompt": "reward prompt"
+            },
+            "redeemed_at": "2020-07-15T17:16:03.17106713Z"
+        }
+    }
+    "##;
+
+    let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
+    crate::tests::roundtrip(&val);
+    if let crate::eventsub::Event::ChannelPointsCustomRewardRedemptionUpdateV1(
+        crate::eventsub::Payload {
+            message: crate::eventsub::Message::Notification(notification),
+            ..
+        },
+    ) = val
+    {
+        assert_eq!(
+            notification.status,
+            crate::types::CustomRewardRedemptionStatus::Unknown
+        );
+        assert!(!notification.was_fulfilled());
+        assert!(!notification.was_refunded());
+    } else {
+        panic!("invalid event type")
+    }
 }
@@ -0,0 +1,185 @@
+#![doc(alias = "channel.chat.message")]
+//! A user sends a message in a chat room.
+
+use super::*;
+/// [`channel.chat.message`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelchatmessage): a user sends a message in a chat room.
+#[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelChatMessageV1 {
+    /// The User ID of the channel to receive chat message events for.
+    #[builder(setter(into))]
+    pub broadcaster_user_id: types::UserId,
+    /// The User ID to read chat as.
+    #[builder(setter(into))]
+    pub user_id: types::UserId,
+}
+
+impl EventSubscription for ChannelChatMessageV1 {
+    type Payload = ChannelChatMessageV1Payload;
+
+    const EVENT_TYPE: EventType = EventType::ChannelChatMessage;
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = SCOPE;
+    const VERSION: &'static str = "1";
+}
+
+/// [`channel.chat.message`](ChannelChatMessageV1) response payload.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelChatMessageV1Payload {
+    /// The broadcaster user ID.
+    pub broadcaster_user_id: types::UserId,
+    /// The broadcaster login.
+    pub broadcaster_user_login: types::UserName,
+    /// The broadcaster display name.
+    pub broadcaster_user_name: types::DisplayName,
+    /// The user ID of the user that sent the message.
+    pub chatter_user_id: types::UserId,
+    /// The user login of the user that sent the message.
+    pub chatter_user_login: types::UserName,
+    /// The user name of the user that sent the message.
+    pub chatter_user_name: types::DisplayName,
+    /// A UUID that identifies the message.
+    pub message_id: types::MsgId,
+    /// The structured chat message.
+    pub message: ChatMessage,
+    /// The type of message.
+    pub message_type: ChatMessageType,
+    /// List of chat badges.
+    #[serde(deserialize_with = "crate::deserialize_default_from_null")]
+    pub badges: Vec<Badge>,
+    /// Metadata if this message is a cheer.
+    pub cheer: Option<Cheer>,
+    /// The color of the user’s name in the chat room, empty if the user never set a color.
+    pub color: String,
+    /// Metadata if this message is a reply.
+    pub reply: Option<Reply>,
+    /// The ID of a channel points custom reward that was redeemed.
+    pub channel_points_custom_reward_id: Option<types::RewardId>,
+    /// The ID of the animation of a power-up message effect.
+    #[serde(default)]
+    pub channel_points_animation_id: Option<String>,
+}
+
+#[cfg(test)]
+#[test]
+fn parse_payload() {
+    let payload = r##"
+    {
+        "subscription": {
+            "id": "0b7f3361-672b-4d39-b307-dd5b576c9b27",
+            "type": "channel.chat.message",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "1971641",
+                "user_id": "2914196"
+            },
+            "transport": {
+                "method": "websocket",
+                "session_id": "AgoQHR3s6Mb4T8GFB1l3DlPfiRIGY2VsbC1h"
+            },
+            "created_at": "2023-11-06T18:11:47.492253549Z"
+        },
+        "event": {
+            "broadcaster_user_id": "1971641",
+            "broadcaster_user_login": "streamer",
+            "broadcaster_user_name": "streamer",
+            "chatter_user_id": "4145994",
+            "chatter_user_login": "viewer32",
+            "chatter_user_name": "viewer32",
+            "message_id": "cc106a89-1814-919d-454c-f4f2f970aae7",
+            "message": {
+                "text": "Hi chat @streamer Kappa Cheer100",
+                "fragments": [
+                    {
+                        "type": "text",
+                        "text": "Hi chat ",
+                        "cheermote": null,
+                        "emote": null,
+                        "mention": null
+                    },
+                    {
+                        "type": "mention",
+                        "text": "@streamer",
+                        "cheermote": null,
+                        "emote": null,
+                        "mention": {
+                            "user_id": "1971641",
+                            "user_name": "streamer",
+                            "user_login": "streamer"
+                        }
+                    },
+                    {
+                        "type": "emote",
+                        "text": "Kappa",
+                        "cheermote": null,
+                        "emote": {
+                            "id": "25",
+                            "emote_set_id": "0",
+                            "owner_id": "0",
+                            "format": ["static"]
+                        },
+                        "mention": null
+                    },
+                    {
+                        "type": "cheermote",
+                        "text": "Cheer100",
+                        "cheermote": {
+                            "prefix": "cheer",
+                            "bits": 100,
+                            "tier": 1
+                        },
+                        "emote": null,
+                        "mention": null
+                    }
+                ]
+            },
+            "color": "#00FF7F",
+            "badges": [
+                {
+                    "set_id": "moderator",
+                    "id": "1",
+                    "info": ""
+                },
+                {
+                    "set_id": "subscriber",
+                    "id": "12",
+                    "info": "16"
+                }
+            ],
+            "message_type": "text",
+            "cheer": {
+                "bits": 100
+            },
+            "reply": {
+                "parent_message_id": "ab1fbc28-2385-4d60-a3f6-5ee4f2a7dd7a",
+                "parent_message_body": "hello",
+                "parent_user_id": "1971641",
+                "parent_user_name": "streamer",
+                "parent_user_login": "streamer",
+                "thread_message_id": "ab1fbc28-2385-4d60-a3f6-5ee4f2a7dd7a",
+                "thread_user_id": "1971641",
+                "thread_user_name": "streamer",
+                "thread_user_login": "streamer"
+            },
+            "channel_points_custom_reward_id": null,
+            "channel_points_animation_id": null
+        }
+    }
+    "##;
+
+    let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
+    crate::tests::roundtrip(&val)
+}
@@ -0,0 +1,211 @@
+#![doc(alias = "channel.chat")]
+//! A message has been sent in chat.
+use super::{EventSubscription, EventType};
+use crate::types;
+use serde::{Deserialize, Serialize};
+
+pub mod message;
+
+#[doc(inline)]
+pub use message::{ChannelChatMessageV1, ChannelChatMessageV1Payload};
+
+/// Scope needed for the chat subscriptions, not yet known to [`twitch_oauth2`]
+///
+/// Apps also need `user:bot` from the chatting user and `channel:bot` from the broadcaster, or moderator status in the channel.
+#[cfg(feature = "twitch_oauth2")]
+const SCOPE: &[twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed(
+    "user:read:chat",
+))];
+
+/// A chat message
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChatMessage {
+    /// The chat message in plain text.
+    pub text: String,
+    /// Ordered list of chat message fragments.
+    #[serde(deserialize_with = "crate::deserialize_default_from_null")]
+    pub fragments: Vec<Fragment>,
+}
+
+/// A fragment of a [`ChatMessage`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Fragment {
+    /// The type of message fragment.
+    #[serde(rename = "type")]
+    pub type_: FragmentType,
+    /// Message text in fragment.
+    pub text: String,
+    /// Metadata pertaining to the cheermote, if this fragment is a cheermote.
+    pub cheermote: Option<Cheermote>,
+    /// Metadata pertaining to the emote, if this fragment is an emote.
+    pub emote: Option<Emote>,
+    /// Metadata pertaining to the mention, if this fragment is a mention.
+    pub mention: Option<Mention>,
+}
+
+/// Type of a [`Fragment`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum FragmentType {
+    /// Plain text
+    Text,
+    /// A cheermote
+    Cheermote,
+    /// An emote
+    Emote,
+    /// A mention of a user
+    Mention,
+}
+
+/// A cheermote in a [`Fragment`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Cheermote {
+    /// The name portion of the Cheermote string that you use in chat to cheer Bits.
+    pub prefix: String,
+    /// The amount of bits cheered.
+    pub bits: i64,
+    /// The tier level of the cheermote.
+    pub tier: i64,
+}
+
+/// An emote in a [`Fragment`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Emote {
+    /// An ID that uniquely identifies this emote.
+    pub id: types::EmoteId,
+    /// An ID that identifies the emote set that the emote belongs to.
+    pub emote_set_id: types::EmoteSetId,
+    /// The ID of the broadcaster who owns the emote.
+    pub owner_id: types::UserId,
+    /// The formats that the emote is available in, `static` or `animated`.
+    #[serde(deserialize_with = "crate::deserialize_default_from_null")]
+    pub format: Vec<String>,
+}
+
+/// A mention in a [`Fragment`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Mention {
+    /// The user ID of the mentioned user.
+    pub user_id: types::UserId,
+    /// The user name of the mentioned user.
+    pub user_name: types::DisplayName,
+    /// The user login of the mentioned user.
+    pub user_login: types::UserName,
+}
+
+/// A chat badge of the user that sent a message
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Badge {
+    /// An ID that identifies this set of chat badges. For example, Bits or Subscriber.
+    pub set_id: types::BadgeSetId,
+    /// An ID that identifies this version of the badge.
+    pub id: types::ChatBadgeId,
+    /// Contains metadata related to the chat badges in the badges tag, e.g. the number of months for subscriber badges.
+    pub info: String,
+}
+
+/// Bits cheered with a message
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Cheer {
+    /// The amount of Bits the user cheered.
+    pub bits: i64,
+}
+
+/// The message a chat message is replying to
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Reply {
+    /// An ID that uniquely identifies the parent message that this message is replying to.
+    pub parent_message_id: types::MsgId,
+    /// The message body of the parent message.
+    pub parent_message_body: String,
+    /// User ID of the sender of the parent message.
+    pub parent_user_id: types::UserId,
+    /// User name of the sender of the parent message.
+    pub parent_user_name: types::DisplayName,
+    /// User login of the sender of the parent message.
+    pub parent_user_login: types::UserName,
+    /// An ID that identifies the parent message of the reply thread.
+    pub thread_message_id: types::MsgId,
+    /// User ID of the sender of the thread’s parent message.
+    pub thread_user_id: types::UserId,
+    /// User name of the sender of the thread’s parent message.
+    pub thread_user_name: types::DisplayName,
+    /// User login of the sender of the thread’s parent message.
+    pub thread_user_login: types::UserName,
+}
+
+/// Type of a [`ChannelChatMessageV1Payload`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ChatMessageType {
+    /// A normal message
+    Text,
+    /// Highlighted with channel points
+    ChannelPointsHighlighted,
+    /// Sent in sub-only mode with channel points
+    ChannelPointsSubOnly,
+    /// The first message of a user in the channel
+    UserIntro,
+    /// Sent with a power-up message effect
+    PowerUpsMessageEffect,
+    /// Sent with a gigantified emote power-up
+    PowerUpsGigantifiedEmote,
+}
@@ -4,6 +4,10 @@
 use super::*;
 /// [`channel.goal.begin`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#begin-goals-event): a specified broadcaster begins a goal.
 #[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelGoalBeginV1 {
@@ -23,6 +27,10 @@ impl EventSubscription for ChannelGoalBeginV1 {
 
 /// [`channel.goal.begin`](ChannelGoalBeginV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelGoalBeginV1Payload {
@@ -4,6 +4,10 @@
 use super::*;
 /// [`channel.goal.progress`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#progress-goals-event): progress is made towards the specified broadcaster’s goal.
 #[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelGoalProgressV1 {
@@ -23,6 +27,10 @@ impl EventSubscription for ChannelGoalProgressV1 {
 
 /// [`channel.goal.progress`](ChannelGoalProgressV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelGoalProgressV1Payload {
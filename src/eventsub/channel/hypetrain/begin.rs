@@ -4,6 +4,10 @@
 use super::*;
 /// [`channel.hype_train.begin`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelhype_trainbegin): a hype train begins on the specified channel.
 #[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelHypeTrainBeginV1 {
@@ -24,6 +28,10 @@ impl EventSubscription for ChannelHypeTrainBeginV1 {
 
 /// [`channel.hype_train.begin`](ChannelHypeTrainBeginV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelHypeTrainBeginV1Payload {
@@ -47,11 +55,86 @@ pub struct ChannelHypeTrainBeginV1Payload {
     pub started_at: types::Timestamp,
     // FIXME: Contains a maximum of two user objects
     /// The contributors with the most points contributed.
+    #[serde(deserialize_with = "crate::deserialize_default_from_null")]
     pub top_contributions: Vec<Contribution>,
     /// Total points contributed to the hype train.
     pub total: i64,
 }
 
+/// [`channel.hype_train.begin`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelhype_trainbeginv2): a hype train begins on the specified channel.
+#[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelHypeTrainBeginV2 {
+    /// The broadcaster user ID for the channel you want hype train begin notifications for.
+    #[builder(setter(into))]
+    pub broadcaster_user_id: types::UserId,
+}
+
+impl EventSubscription for ChannelHypeTrainBeginV2 {
+    type Payload = ChannelHypeTrainBeginV2Payload;
+
+    const EVENT_TYPE: EventType = EventType::ChannelHypeTrainBegin;
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelReadHypeTrain];
+    const VERSION: &'static str = "2";
+}
+
+/// [`channel.hype_train.begin`](ChannelHypeTrainBeginV2) response payload.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelHypeTrainBeginV2Payload {
+    /// The Hype Train ID.
+    pub id: types::HypeTrainId,
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: types::UserId,
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: types::UserName,
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: types::DisplayName,
+    /// The time at which the hype train expires. The expiration is extended when the hype train reaches a new level.
+    pub expires_at: types::Timestamp,
+    /// The number of points required to reach the next level.
+    pub goal: i64,
+    /// Current level of hype train event.
+    pub level: i64,
+    /// The highest level that a hype train has reached in this channel.
+    pub all_time_high_level: i64,
+    /// The most points contributed to a hype train in this channel.
+    pub all_time_high_total: i64,
+    /// The number of points contributed to the hype train at the current level.
+    pub progress: i64,
+    /// The timestamp at which the hype train started.
+    pub started_at: types::Timestamp,
+    /// The contributors with the most points contributed.
+    #[serde(deserialize_with = "crate::deserialize_default_from_null")]
+    pub top_contributions: Vec<Contribution>,
+    /// Total points contributed to the hype train.
+    pub total: i64,
+    /// The type of the hype train.
+    #[serde(rename = "type")]
+    pub type_: HypeTrainType,
+    /// Whether the hype train is shared between multiple channels.
+    pub is_shared_train: bool,
+    /// The channels participating in the hype train, if it is shared.
+    #[serde(default)]
+    pub shared_train_participants: Option<Vec<SharedTrainParticipant>>,
+}
+
+impl ChannelHypeTrainBeginV2Payload {
+    /// Returns `true` if this is a Golden Kappa train.
+    pub fn is_golden_kappa_train(&self) -> bool { self.type_ == HypeTrainType::GoldenKappa }
+}
+
 #[cfg(test)]
 #[test]
 fn parse_payload() {
@@ -94,3 +177,108 @@ fn parse_payload() {
     let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
     crate::tests::roundtrip(&val)
 }
+
+#[cfg(test)]
+#[test]
+fn parse_payload_v2() {
+    let payload = r##"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "channel.hype_train.begin",
+            "version": "2",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "1337"
+            },
+             "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "id": "1b0AsbInCHZW2SQFQkCzqN07Ib2",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "total": 137,
+            "progress": 137,
+            "goal": 500,
+            "top_contributions": [
+                { "user_id": "123", "user_login": "pogchamp", "user_name": "PogChamp", "type": "bits", "total": 50 },
+                { "user_id": "456", "user_login": "kappa", "user_name": "Kappa", "type": "subscription", "total": 45 }
+            ],
+            "level": 2,
+            "all_time_high_level": 4,
+            "all_time_high_total": 2845,
+            "shared_train_participants": null,
+            "started_at": "2020-07-15T17:16:03.17106713Z",
+            "expires_at": "2020-07-15T17:16:11.17106713Z",
+            "type": "golden_kappa",
+            "is_shared_train": false
+        }
+    }
+    "##;
+
+    let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
+    if let crate::eventsub::Event::ChannelHypeTrainBeginV2(crate::eventsub::Payload {
+        message: crate::eventsub::Message::Notification(notification),
+        ..
+    }) = &val
+    {
+        assert!(notification.is_golden_kappa_train());
+        assert_eq!(notification.all_time_high_level, 4);
+    } else {
+        panic!("expected a v2 begin notification")
+    }
+    crate::tests::roundtrip(&val)
+}
+
+#[cfg(test)]
+#[test]
+fn parse_payload_null_contributions() {
+    let payload = r##"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "channel.hype_train.begin",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "1337"
+            },
+             "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "id": "1b0AsbInCHZW2SQFQkCzqN07Ib2",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "total": 50,
+            "progress": 50,
+            "goal": 500,
+            "top_contributions": null,
+            "last_contribution": { "user_id": "123", "user_login": "pogchamp", "user_name": "PogChamp", "type": "bits", "total": 50 },
+            "started_at": "2020-07-15T17:16:03.17106713Z",
+            "expires_at": "2020-07-15T17:16:11.17106713Z"
+        }
+    }
+    "##;
+
+    let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
+    crate::tests::roundtrip(&val);
+    match val {
+        crate::eventsub::Event::ChannelHypeTrainBeginV1(crate::eventsub::Payload {
+            message: crate::eventsub::Message::Notification(notification),
+            ..
+        }) => assert!(notification.top_contributions.is_empty()),
+        _ => panic!("unexpected event"),
+    }
+}
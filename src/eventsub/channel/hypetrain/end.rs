@@ -4,6 +4,10 @@
 use super::*;
 /// [`channel.hype_train.end`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelhype_trainend): a hype train ends on the specified channel.
 #[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelHypeTrainEndV1 {
@@ -24,6 +28,10 @@ impl EventSubscription for ChannelHypeTrainEndV1 {
 
 /// [`channel.hype_train.end`](ChannelHypeTrainEndV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelHypeTrainEndV1Payload {
@@ -44,11 +52,80 @@ pub struct ChannelHypeTrainEndV1Payload {
     /// The timestamp at which the hype train started.
     pub started_at: types::Timestamp,
     /// The contributors with the most points contributed.
+    #[serde(deserialize_with = "crate::deserialize_default_from_null")]
     pub top_contributions: Vec<Contribution>,
     /// Total points contributed to the hype train.
     pub total: i64,
 }
 
+/// [`channel.hype_train.end`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelhype_trainendv2): a hype train ends on the specified channel.
+#[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelHypeTrainEndV2 {
+    /// The broadcaster user ID for the channel you want hype train end notifications for.
+    #[builder(setter(into))]
+    pub broadcaster_user_id: types::UserId,
+}
+
+impl EventSubscription for ChannelHypeTrainEndV2 {
+    type Payload = ChannelHypeTrainEndV2Payload;
+
+    const EVENT_TYPE: EventType = EventType::ChannelHypeTrainEnd;
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelReadHypeTrain];
+    const VERSION: &'static str = "2";
+}
+
+/// [`channel.hype_train.end`](ChannelHypeTrainEndV2) response payload.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelHypeTrainEndV2Payload {
+    /// The Hype Train ID.
+    pub id: types::HypeTrainId,
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: types::UserId,
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: types::UserName,
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: types::DisplayName,
+    /// The timestamp at which the hype train cooldown ends so that the next hype train can start.
+    pub cooldown_ends_at: types::Timestamp,
+    /// The timestamp at which the hype train ended.
+    pub ended_at: types::Timestamp,
+    /// Current level of hype train event.
+    pub level: i64,
+    /// The timestamp at which the hype train started.
+    pub started_at: types::Timestamp,
+    /// The contributors with the most points contributed.
+    #[serde(deserialize_with = "crate::deserialize_default_from_null")]
+    pub top_contributions: Vec<Contribution>,
+    /// Total points contributed to the hype train.
+    pub total: i64,
+    /// The type of the hype train.
+    #[serde(rename = "type")]
+    pub type_: HypeTrainType,
+    /// Whether the hype train is shared between multiple channels.
+    pub is_shared_train: bool,
+    /// The channels participating in the hype train, if it is shared.
+    #[serde(default)]
+    pub shared_train_participants: Option<Vec<SharedTrainParticipant>>,
+}
+
+impl ChannelHypeTrainEndV2Payload {
+    /// Returns `true` if this is a Golden Kappa train.
+    pub fn is_golden_kappa_train(&self) -> bool { self.type_ == HypeTrainType::GoldenKappa }
+}
+
 #[cfg(test)]
 #[test]
 fn parse_payload() {
@@ -90,3 +167,48 @@ fn parse_payload() {
     let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
     crate::tests::roundtrip(&val)
 }
+
+#[cfg(test)]
+#[test]
+fn parse_payload_v2() {
+    let payload = r##"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "channel.hype_train.end",
+            "version": "2",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "1337"
+            },
+             "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "id": "1b0AsbInCHZW2SQFQkCzqN07Ib2",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "total": 137,
+            "level": 2,
+            "top_contributions": [
+                { "user_id": "123", "user_login": "pogchamp", "user_name": "PogChamp", "type": "bits", "total": 50 },
+                { "user_id": "456", "user_login": "kappa", "user_name": "Kappa", "type": "subscription", "total": 45 }
+            ],
+            "shared_train_participants": null,
+            "started_at": "2020-07-15T17:16:03.17106713Z",
+            "ended_at": "2020-07-15T17:16:11.17106713Z",
+            "cooldown_ends_at": "2020-07-15T18:16:11.17106713Z",
+            "type": "treasure",
+            "is_shared_train": false
+        }
+    }
+    "##;
+
+    let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
+    crate::tests::roundtrip(&val)
+}
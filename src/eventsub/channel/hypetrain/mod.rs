@@ -11,12 +11,22 @@ pub mod progress;
 #[doc(inline)]
 pub use begin::{ChannelHypeTrainBeginV1, ChannelHypeTrainBeginV1Payload};
 #[doc(inline)]
+pub use begin::{ChannelHypeTrainBeginV2, ChannelHypeTrainBeginV2Payload};
+#[doc(inline)]
 pub use end::{ChannelHypeTrainEndV1, ChannelHypeTrainEndV1Payload};
 #[doc(inline)]
+pub use end::{ChannelHypeTrainEndV2, ChannelHypeTrainEndV2Payload};
+#[doc(inline)]
 pub use progress::{ChannelHypeTrainProgressV1, ChannelHypeTrainProgressV1Payload};
+#[doc(inline)]
+pub use progress::{ChannelHypeTrainProgressV2, ChannelHypeTrainProgressV2Payload};
 
 /// Type of contribution
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[non_exhaustive]
 #[serde(rename_all = "lowercase")]
 pub enum ContributionType {
@@ -24,10 +34,16 @@ pub enum ContributionType {
     Bits,
     /// Channel Subscriptions. Either gifted or not.
     Subscription,
+    /// Other contributions, like chat messages. Only sent in version 2 events.
+    Other,
 }
 
 /// A contribution to hype train
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Contribution {
@@ -43,3 +59,37 @@ pub struct Contribution {
     /// The display name of the user.
     pub user_name: types::DisplayName,
 }
+
+/// Type of hype train
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[non_exhaustive]
+#[serde(rename_all = "snake_case")]
+pub enum HypeTrainType {
+    /// A regular hype train
+    Regular,
+    /// A Golden Kappa train, where contributors receive the Golden Kappa emote
+    GoldenKappa,
+    /// A treasure train, where the broadcaster receives a bonus on the revenue
+    Treasure,
+}
+
+/// A channel participating in a shared hype train
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct SharedTrainParticipant {
+    /// The broadcaster ID.
+    pub broadcaster_user_id: types::UserId,
+    /// The broadcaster login.
+    pub broadcaster_user_login: types::UserName,
+    /// The broadcaster display name.
+    pub broadcaster_user_name: types::DisplayName,
+}
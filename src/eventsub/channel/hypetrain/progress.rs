@@ -4,6 +4,10 @@
 use super::*;
 /// [`channel.hype_train.progress`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelhype_trainprogress): a hype train makes progress on the specified channel.
 #[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelHypeTrainProgressV1 {
@@ -24,6 +28,10 @@ impl EventSubscription for ChannelHypeTrainProgressV1 {
 
 /// [`channel.hype_train.progress`](ChannelHypeTrainProgressV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelHypeTrainProgressV1Payload {
@@ -49,11 +57,82 @@ pub struct ChannelHypeTrainProgressV1Payload {
     pub started_at: types::Timestamp,
     // FIXME: Contains a maximum of two user objects
     /// The contributors with the most points contributed.
+    #[serde(deserialize_with = "crate::deserialize_default_from_null")]
     pub top_contributions: Vec<Contribution>,
     /// Total points contributed to the hype train.
     pub total: i64,
 }
 
+/// [`channel.hype_train.progress`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelhype_trainprogressv2): a hype train makes progress on the specified channel.
+#[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelHypeTrainProgressV2 {
+    /// The broadcaster user ID for the channel you want hype train progress notifications for.
+    #[builder(setter(into))]
+    pub broadcaster_user_id: types::UserId,
+}
+
+impl EventSubscription for ChannelHypeTrainProgressV2 {
+    type Payload = ChannelHypeTrainProgressV2Payload;
+
+    const EVENT_TYPE: EventType = EventType::ChannelHypeTrainProgress;
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelReadHypeTrain];
+    const VERSION: &'static str = "2";
+}
+
+/// [`channel.hype_train.progress`](ChannelHypeTrainProgressV2) response payload.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelHypeTrainProgressV2Payload {
+    /// The Hype Train ID.
+    pub id: types::HypeTrainId,
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: types::UserId,
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: types::UserName,
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: types::DisplayName,
+    /// The time at which the hype train expires. The expiration is extended when the hype train reaches a new level.
+    pub expires_at: types::Timestamp,
+    /// The number of points required to reach the next level.
+    pub goal: i64,
+    /// Current level of hype train event.
+    pub level: i64,
+    /// The number of points contributed to the hype train at the current level.
+    pub progress: i64,
+    /// The timestamp at which the hype train started.
+    pub started_at: types::Timestamp,
+    /// The contributors with the most points contributed.
+    #[serde(deserialize_with = "crate::deserialize_default_from_null")]
+    pub top_contributions: Vec<Contribution>,
+    /// Total points contributed to the hype train.
+    pub total: i64,
+    /// The type of the hype train.
+    #[serde(rename = "type")]
+    pub type_: HypeTrainType,
+    /// Whether the hype train is shared between multiple channels.
+    pub is_shared_train: bool,
+    /// The channels participating in the hype train, if it is shared.
+    #[serde(default)]
+    pub shared_train_participants: Option<Vec<SharedTrainParticipant>>,
+}
+
+impl ChannelHypeTrainProgressV2Payload {
+    /// Returns `true` if this is a Golden Kappa train.
+    pub fn is_golden_kappa_train(&self) -> bool { self.type_ == HypeTrainType::GoldenKappa }
+}
+
 #[cfg(test)]
 #[test]
 fn parse_payload() {
@@ -97,3 +176,52 @@ fn parse_payload() {
     let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
     crate::tests::roundtrip(&val)
 }
+
+#[cfg(test)]
+#[test]
+fn parse_payload_v2() {
+    let payload = r##"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "channel.hype_train.progress",
+            "version": "2",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "1337"
+            },
+             "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "id": "1b0AsbInCHZW2SQFQkCzqN07Ib2",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "total": 700,
+            "progress": 200,
+            "goal": 1000,
+            "top_contributions": [
+                { "user_id": "123", "user_login": "pogchamp", "user_name": "PogChamp", "type": "bits", "total": 50 },
+                { "user_id": "456", "user_login": "kappa", "user_name": "Kappa", "type": "other", "total": 45 }
+            ],
+            "level": 2,
+            "shared_train_participants": [
+                { "broadcaster_user_id": "1337", "broadcaster_user_login": "cool_user", "broadcaster_user_name": "Cool_User" },
+                { "broadcaster_user_id": "1338", "broadcaster_user_login": "cooler_user", "broadcaster_user_name": "Cooler_User" }
+            ],
+            "started_at": "2020-07-15T17:16:03.17106713Z",
+            "expires_at": "2020-07-15T17:16:11.17106713Z",
+            "type": "regular",
+            "is_shared_train": true
+        }
+    }
+    "##;
+
+    let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
+    crate::tests::roundtrip(&val)
+}
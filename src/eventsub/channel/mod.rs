@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 pub mod ban;
 pub mod channel_points_custom_reward;
 pub mod channel_points_custom_reward_redemption;
+pub mod chat;
 pub mod cheer;
 pub mod follow;
 pub mod goal;
@@ -16,8 +17,11 @@ pub mod prediction;
 pub mod raid;
 pub mod subscribe;
 pub mod subscription;
+pub mod suspicious_user;
 pub mod unban;
+pub mod unban_request;
 pub mod update;
+pub mod warning;
 
 #[doc(inline)]
 pub use ban::{ChannelBanV1, ChannelBanV1Payload};
@@ -42,6 +46,8 @@ pub use channel_points_custom_reward_redemption::{
     ChannelPointsCustomRewardRedemptionUpdateV1, ChannelPointsCustomRewardRedemptionUpdateV1Payload,
 };
 #[doc(inline)]
+pub use chat::{ChannelChatMessageV1, ChannelChatMessageV1Payload};
+#[doc(inline)]
 pub use cheer::{ChannelCheerV1, ChannelCheerV1Payload};
 #[doc(inline)]
 pub use follow::{ChannelFollowV1, ChannelFollowV1Payload};
@@ -54,10 +60,16 @@ pub use goal::{ChannelGoalProgressV1, ChannelGoalProgressV1Payload};
 #[doc(inline)]
 pub use hypetrain::{ChannelHypeTrainBeginV1, ChannelHypeTrainBeginV1Payload};
 #[doc(inline)]
+pub use hypetrain::{ChannelHypeTrainBeginV2, ChannelHypeTrainBeginV2Payload};
+#[doc(inline)]
 pub use hypetrain::{ChannelHypeTrainEndV1, ChannelHypeTrainEndV1Payload};
 #[doc(inline)]
+pub use hypetrain::{ChannelHypeTrainEndV2, ChannelHypeTrainEndV2Payload};
+#[doc(inline)]
 pub use hypetrain::{ChannelHypeTrainProgressV1, ChannelHypeTrainProgressV1Payload};
 #[doc(inline)]
+pub use hypetrain::{ChannelHypeTrainProgressV2, ChannelHypeTrainProgressV2Payload};
+#[doc(inline)]
 pub use poll::{ChannelPollBeginV1, ChannelPollBeginV1Payload};
 #[doc(inline)]
 pub use poll::{ChannelPollEndV1, ChannelPollEndV1Payload};
@@ -82,6 +94,18 @@ pub use subscription::{ChannelSubscriptionGiftV1, ChannelSubscriptionGiftV1Paylo
 #[doc(inline)]
 pub use subscription::{ChannelSubscriptionMessageV1, ChannelSubscriptionMessageV1Payload};
 #[doc(inline)]
+pub use suspicious_user::{ChannelSuspiciousUserMessageV1, ChannelSuspiciousUserMessageV1Payload};
+#[doc(inline)]
+pub use suspicious_user::{ChannelSuspiciousUserUpdateV1, ChannelSuspiciousUserUpdateV1Payload};
+#[doc(inline)]
 pub use unban::{ChannelUnbanV1, ChannelUnbanV1Payload};
 #[doc(inline)]
+pub use unban_request::{ChannelUnbanRequestCreateV1, ChannelUnbanRequestCreateV1Payload};
+#[doc(inline)]
+pub use unban_request::{ChannelUnbanRequestResolveV1, ChannelUnbanRequestResolveV1Payload};
+#[doc(inline)]
 pub use update::{ChannelUpdateV1, ChannelUpdateV1Payload};
+#[doc(inline)]
+pub use warning::{ChannelWarningAcknowledgeV1, ChannelWarningAcknowledgeV1Payload};
+#[doc(inline)]
+pub use warning::{ChannelWarningSendV1, ChannelWarningSendV1Payload};
@@ -4,6 +4,10 @@
 use super::*;
 /// [`channel.poll.begin`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelpollbegin-beta): a poll begins on the specified channel.
 #[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPollBeginV1 {
@@ -23,6 +27,10 @@ impl EventSubscription for ChannelPollBeginV1 {
 
 /// [`channel.poll.begin`](ChannelPollBeginV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPollBeginV1Payload {
@@ -37,6 +45,7 @@ pub struct ChannelPollBeginV1Payload {
     /// The Channel Points voting settings for the poll.
     pub channel_points_voting: ChannelPointsVoting,
     /// An array of choices for the poll.
+    #[serde(deserialize_with = "crate::deserialize_default_from_null")]
     pub choices: Vec<types::PollChoice>,
     /// The time the poll will end.
     pub ends_at: types::Timestamp,
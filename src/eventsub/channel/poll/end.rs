@@ -4,6 +4,10 @@
 use super::*;
 /// [`channel.hype_train.end`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelpollend-beta): a poll ends on the specified channel.
 #[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPollEndV1 {
@@ -23,6 +27,10 @@ impl EventSubscription for ChannelPollEndV1 {
 
 /// [`channel.hype_train.end`](ChannelPollEndV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPollEndV1Payload {
@@ -37,6 +45,7 @@ pub struct ChannelPollEndV1Payload {
     /// The Channel Points voting settings for the poll.
     pub channel_points_voting: ChannelPointsVoting,
     /// An array of choices for the poll. Includes vote counts.
+    #[serde(deserialize_with = "crate::deserialize_default_from_null")]
     pub choices: Vec<types::PollChoice>,
     /// The time the poll ended.
     pub ended_at: types::Timestamp,
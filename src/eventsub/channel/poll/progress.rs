@@ -4,6 +4,10 @@
 use super::*;
 /// [`channel.hype_train.progress`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelpollprogress-beta): an user responds to a poll on the specified channel
 #[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPollProgressV1 {
@@ -23,6 +27,10 @@ impl EventSubscription for ChannelPollProgressV1 {
 
 /// [`channel.hype_train.progress`](ChannelPollProgressV1) response payload.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPollProgressV1Payload {
@@ -37,6 +45,7 @@ pub struct ChannelPollProgressV1Payload {
     /// The Channel Points voting settings for the poll.
     pub channel_points_voting: ChannelPointsVoting,
     /// An array of choices for the poll. Includes vote counts.
+    #[serde(deserialize_with = "crate::deserialize_default_from_null")]
     pub choices: Vec<types::PollChoice>,
     /// The time the poll will end.
     pub ends_at: types::Timestamp,
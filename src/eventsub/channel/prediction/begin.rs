@@ -4,6 +4,10 @@
 use super::*;
 /// [`channel.prediction.begin`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelpredictionbegin-beta): a Prediction begins on the specified channel
 #[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPredictionBeginV1 {
@@ -23,6 +27,10 @@ impl EventSubscription for ChannelPredictionBeginV1 {
 
 /// [`channel.prediction.begin`](ChannelPredictionBeginV1) response payload.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPredictionBeginV1Payload {
@@ -37,6 +45,7 @@ pub struct ChannelPredictionBeginV1Payload {
     /// The time the Channel Points Prediction will automatically lock.
     pub locks_at: types::Timestamp,
     /// An array of outcomes for the Channel Points Prediction.
+    #[serde(deserialize_with = "crate::deserialize_default_from_null")]
     pub outcomes: Vec<types::PredictionOutcome>,
     /// The time the Channel Points Prediction started.
     pub started_at: types::Timestamp,
@@ -4,6 +4,10 @@
 use super::*;
 /// [`channel.hype_train.end`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelpredictionend-beta): a Prediction ends on the specified channel.
 #[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPredictionEndV1 {
@@ -23,6 +27,10 @@ impl EventSubscription for ChannelPredictionEndV1 {
 
 /// [`channel.hype_train.end`](ChannelPredictionEndV1) response payload.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPredictionEndV1Payload {
@@ -37,6 +45,7 @@ pub struct ChannelPredictionEndV1Payload {
     /// Channel Points Prediction ID.
     pub id: types::PredictionId,
     /// An array of outcomes for the Channel Points Prediction. Includes top_predictors.
+    #[serde(deserialize_with = "crate::deserialize_default_from_null")]
     pub outcomes: Vec<types::PredictionOutcome>,
     /// The time the Channel Points Prediction started.
     pub started_at: types::Timestamp,
@@ -4,6 +4,10 @@
 use super::*;
 /// [`channel.hype_train.lock`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelpredictionlock-beta): an user responds to a prediction on the specified channel
 #[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPredictionLockV1 {
@@ -23,6 +27,10 @@ impl EventSubscription for ChannelPredictionLockV1 {
 
 /// [`channel.hype_train.lock`](ChannelPredictionLockV1) response payload.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelPredictionLockV1Payload {
@@ -37,6 +45,7 @@ pub struct ChannelPredictionLockV1Payload {
     /// The time the Channel Points Prediction will automatically lock.
     pub locked_at: types::Timestamp,
     /// An array of outcomes for the Channel Points Prediction. Includes top_predictors.
+    #[serde(deserialize_with = "crate::deserialize_default_from_null")]
     pub outcomes: Vec<types::PredictionOutcome>,
     /// The time the Channel Points Prediction started.
     pub started_at: types::Timestamp,
@@ -0,0 +1,135 @@
+#![doc(alias = "channel.suspicious_user.message")]
+//! A chat message has been sent by a suspicious user.
+
+use super::*;
+/// [`channel.suspicious_user.message`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelsuspicious_usermessage): a chat message has been sent by a suspicious user.
+#[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelSuspiciousUserMessageV1 {
+    /// The broadcaster user ID for the channel you want to get suspicious user message notifications for.
+    #[builder(setter(into))]
+    pub broadcaster_user_id: types::UserId,
+    /// The ID of a user that has permission to moderate the broadcaster’s channel and has granted your app permission to subscribe to this subscription type.
+    #[builder(setter(into))]
+    pub moderator_user_id: types::UserId,
+}
+
+impl EventSubscription for ChannelSuspiciousUserMessageV1 {
+    type Payload = ChannelSuspiciousUserMessageV1Payload;
+
+    const EVENT_TYPE: EventType = EventType::ChannelSuspiciousUserMessage;
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = SCOPE;
+    const VERSION: &'static str = "1";
+}
+
+/// [`channel.suspicious_user.message`](ChannelSuspiciousUserMessageV1) response payload.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelSuspiciousUserMessageV1Payload {
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: types::UserId,
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: types::UserName,
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: types::DisplayName,
+    /// The user ID of the user that sent the message.
+    pub user_id: types::UserId,
+    /// The user login of the user that sent the message.
+    pub user_login: types::UserName,
+    /// The user display name of the user that sent the message.
+    pub user_name: types::DisplayName,
+    /// The status set for the suspicious user.
+    pub low_trust_status: LowTrustStatus,
+    /// IDs of the channels where the user has been banned, if they are banned in a channel sharing bans with this channel.
+    #[serde(deserialize_with = "crate::deserialize_default_from_null")]
+    pub shared_ban_channel_ids: Vec<types::UserId>,
+    /// Reasons for the user being treated as suspicious.
+    #[serde(deserialize_with = "crate::deserialize_default_from_null")]
+    pub types: Vec<SuspiciousUserType>,
+    /// Evaluation of the likelihood that the user is evading a ban.
+    pub ban_evasion_evaluation: BanEvasionEvaluation,
+    /// The message sent by the user.
+    pub message: SuspiciousUserMessage,
+}
+
+#[cfg(test)]
+#[test]
+fn parse_payload() {
+    let payload = r##"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "channel.suspicious_user.message",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "1050263432",
+                "moderator_user_id": "1050263433"
+            },
+            "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "broadcaster_user_id": "1050263432",
+            "broadcaster_user_name": "dcf9e2fc3d2b4a6e8c3d",
+            "broadcaster_user_login": "dcf9e2fc3d2b4a6e8c3d",
+            "user_id": "1050263434",
+            "user_name": "4a46e2cc1cd64bfdbe55",
+            "user_login": "4a46e2cc1cd64bfdbe55",
+            "low_trust_status": "active_monitoring",
+            "shared_ban_channel_ids": ["100", "200"],
+            "types": ["ban_evader_detector", "shared_channel_ban"],
+            "ban_evasion_evaluation": "likely",
+            "message": {
+                "message_id": "101010",
+                "text": "bad stuff pogchamp Cheer100",
+                "fragments": [
+                    {
+                        "type": "text",
+                        "text": "bad stuff ",
+                        "cheermote": null,
+                        "emote": null
+                    },
+                    {
+                        "type": "emote",
+                        "text": "pogchamp",
+                        "cheermote": null,
+                        "emote": {
+                            "id": "305954156",
+                            "emote_set_id": "0"
+                        }
+                    },
+                    {
+                        "type": "cheermote",
+                        "text": " Cheer100",
+                        "cheermote": {
+                            "prefix": "cheer",
+                            "bits": 100,
+                            "tier": 1
+                        },
+                        "emote": null
+                    }
+                ]
+            }
+        }
+    }
+    "##;
+
+    let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
+    crate::tests::roundtrip(&val)
+}
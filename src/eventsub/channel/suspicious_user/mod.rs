@@ -0,0 +1,157 @@
+#![doc(alias = "channel.suspicious_user")]
+//! A suspicious user has sent a message or their low trust status has been updated.
+use super::{EventSubscription, EventType};
+use crate::types;
+use serde::{Deserialize, Serialize};
+
+pub mod message;
+pub mod update;
+
+#[doc(inline)]
+pub use message::{ChannelSuspiciousUserMessageV1, ChannelSuspiciousUserMessageV1Payload};
+#[doc(inline)]
+pub use update::{ChannelSuspiciousUserUpdateV1, ChannelSuspiciousUserUpdateV1Payload};
+
+/// Scope needed for the suspicious user subscriptions, not yet known to [`twitch_oauth2`]
+#[cfg(feature = "twitch_oauth2")]
+const SCOPE: &[twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed(
+    "moderator:read:suspicious_users",
+))];
+
+/// How messages from a suspicious user are treated
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum LowTrustStatus {
+    /// The user is not treated as suspicious
+    None,
+    /// Messages from the user are shown, but highlighted to moderators
+    ActiveMonitoring,
+    /// Messages from the user are only shown to moderators
+    Restricted,
+}
+
+/// Reason for a user being treated as suspicious
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SuspiciousUserType {
+    /// Added by a moderator
+    ManuallyAdded,
+    /// Detected as a possible ban evader
+    BanEvaderDetector,
+    /// Banned in a channel sharing bans with this channel
+    SharedChannelBan,
+}
+
+/// Likelihood that a suspicious user is evading a ban
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum BanEvasionEvaluation {
+    /// The user has not been evaluated
+    Unknown,
+    /// The user is possibly evading a ban
+    Possible,
+    /// The user is likely evading a ban
+    Likely,
+}
+
+/// A chat message sent by a suspicious user
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct SuspiciousUserMessage {
+    /// The UUID that identifies the message.
+    pub message_id: types::MsgId,
+    /// The chat message in plain text.
+    pub text: String,
+    /// Ordered list of chat message fragments.
+    #[serde(deserialize_with = "crate::deserialize_default_from_null")]
+    pub fragments: Vec<MessageFragment>,
+}
+
+/// A fragment of a [`SuspiciousUserMessage`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct MessageFragment {
+    /// The type of message fragment.
+    #[serde(rename = "type")]
+    pub type_: MessageFragmentType,
+    /// Message text in fragment.
+    pub text: String,
+    /// Metadata pertaining to the cheermote, if this fragment is a cheermote.
+    pub cheermote: Option<Cheermote>,
+    /// Metadata pertaining to the emote, if this fragment is an emote.
+    pub emote: Option<Emote>,
+}
+
+/// Type of a [`MessageFragment`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum MessageFragmentType {
+    /// Plain text
+    Text,
+    /// A cheermote
+    Cheermote,
+    /// An emote
+    Emote,
+}
+
+/// A cheermote in a [`MessageFragment`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Cheermote {
+    /// The name portion of the Cheermote string that you use in chat to cheer Bits.
+    pub prefix: String,
+    /// The amount of bits cheered.
+    pub bits: i64,
+    /// The tier level of the cheermote.
+    pub tier: i64,
+}
+
+/// An emote in a [`MessageFragment`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Emote {
+    /// An ID that uniquely identifies this emote.
+    pub id: types::EmoteId,
+    /// An ID that identifies the emote set that the emote belongs to.
+    pub emote_set_id: types::EmoteSetId,
+}
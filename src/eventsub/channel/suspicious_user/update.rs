@@ -0,0 +1,100 @@
+#![doc(alias = "channel.suspicious_user.update")]
+//! A suspicious user has been updated.
+
+use super::*;
+/// [`channel.suspicious_user.update`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelsuspicious_userupdate): a suspicious user has been updated.
+#[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelSuspiciousUserUpdateV1 {
+    /// The broadcaster user ID for the channel you want to get suspicious user update notifications for.
+    #[builder(setter(into))]
+    pub broadcaster_user_id: types::UserId,
+    /// The ID of a user that has permission to moderate the broadcaster’s channel and has granted your app permission to subscribe to this subscription type.
+    #[builder(setter(into))]
+    pub moderator_user_id: types::UserId,
+}
+
+impl EventSubscription for ChannelSuspiciousUserUpdateV1 {
+    type Payload = ChannelSuspiciousUserUpdateV1Payload;
+
+    const EVENT_TYPE: EventType = EventType::ChannelSuspiciousUserUpdate;
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = SCOPE;
+    const VERSION: &'static str = "1";
+}
+
+/// [`channel.suspicious_user.update`](ChannelSuspiciousUserUpdateV1) response payload.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelSuspiciousUserUpdateV1Payload {
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: types::UserId,
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: types::UserName,
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: types::DisplayName,
+    /// The ID of the moderator that updated the user.
+    pub moderator_user_id: types::UserId,
+    /// The login of the moderator that updated the user.
+    pub moderator_user_login: types::UserName,
+    /// The display name of the moderator that updated the user.
+    pub moderator_user_name: types::DisplayName,
+    /// The ID of the suspicious user.
+    pub user_id: types::UserId,
+    /// The login of the suspicious user.
+    pub user_login: types::UserName,
+    /// The display name of the suspicious user.
+    pub user_name: types::DisplayName,
+    /// The status set for the suspicious user.
+    pub low_trust_status: LowTrustStatus,
+}
+
+#[cfg(test)]
+#[test]
+fn parse_payload() {
+    let payload = r##"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "channel.suspicious_user.update",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "1050263432",
+                "moderator_user_id": "1050263433"
+            },
+            "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "broadcaster_user_id": "1050263432",
+            "broadcaster_user_name": "dcf9e2fc3d2b4a6e8c3d",
+            "broadcaster_user_login": "dcf9e2fc3d2b4a6e8c3d",
+            "moderator_user_id": "1050263433",
+            "moderator_user_name": "7fd3c8f3f1a44e0d9a3b",
+            "moderator_user_login": "7fd3c8f3f1a44e0d9a3b",
+            "user_id": "1050263434",
+            "user_name": "4a46e2cc1cd64bfdbe55",
+            "user_login": "4a46e2cc1cd64bfdbe55",
+            "low_trust_status": "restricted"
+        }
+    }
+    "##;
+
+    let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
+    crate::tests::roundtrip(&val)
+}
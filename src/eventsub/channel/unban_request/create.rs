@@ -0,0 +1,97 @@
+#![doc(alias = "channel.unban_request.create")]
+//! A user creates an unban request.
+
+use super::*;
+/// [`channel.unban_request.create`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelunban_requestcreate): a user creates an unban request.
+#[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelUnbanRequestCreateV1 {
+    /// The broadcaster user ID for the channel you want to get unban request notifications for.
+    #[builder(setter(into))]
+    pub broadcaster_user_id: types::UserId,
+    /// The ID of a user that has permission to moderate the broadcaster’s channel and has granted your app permission to subscribe to this subscription type.
+    #[builder(setter(into))]
+    pub moderator_user_id: types::UserId,
+}
+
+impl EventSubscription for ChannelUnbanRequestCreateV1 {
+    type Payload = ChannelUnbanRequestCreateV1Payload;
+
+    const EVENT_TYPE: EventType = EventType::ChannelUnbanRequestCreate;
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = SCOPE;
+    const VERSION: &'static str = "1";
+}
+
+/// [`channel.unban_request.create`](ChannelUnbanRequestCreateV1) response payload.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelUnbanRequestCreateV1Payload {
+    /// The ID of the unban request.
+    pub id: String,
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: types::UserId,
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: types::UserName,
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: types::DisplayName,
+    /// User ID of user that is requesting to be unbanned.
+    pub user_id: types::UserId,
+    /// User login of user that is requesting to be unbanned.
+    pub user_login: types::UserName,
+    /// User name of user that is requesting to be unbanned.
+    pub user_name: types::DisplayName,
+    /// Message sent in the unban request.
+    pub text: String,
+    /// The time the unban request was created.
+    pub created_at: types::Timestamp,
+}
+
+#[cfg(test)]
+#[test]
+fn parse_payload() {
+    let payload = r##"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "channel.unban_request.create",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "1337",
+                "moderator_user_id": "1339"
+            },
+            "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "id": "60",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "user_id": "1339",
+            "user_login": "banned_user",
+            "user_name": "Banned_User",
+            "text": "unban me",
+            "created_at": "2023-11-16T10:11:12.634234626Z"
+        }
+    }
+    "##;
+
+    let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
+    crate::tests::roundtrip(&val)
+}
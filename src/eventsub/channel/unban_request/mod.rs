@@ -0,0 +1,38 @@
+#![doc(alias = "channel.unban_request")]
+//! A user has created an unban request or an unban request has been resolved.
+use super::{EventSubscription, EventType};
+use crate::types;
+use serde::{Deserialize, Serialize};
+
+pub mod create;
+pub mod resolve;
+
+#[doc(inline)]
+pub use create::{ChannelUnbanRequestCreateV1, ChannelUnbanRequestCreateV1Payload};
+#[doc(inline)]
+pub use resolve::{ChannelUnbanRequestResolveV1, ChannelUnbanRequestResolveV1Payload};
+
+/// Scope needed for the unban request subscriptions, not yet known to [`twitch_oauth2`]
+///
+/// `moderator:manage:unban_requests` is also accepted.
+#[cfg(feature = "twitch_oauth2")]
+const SCOPE: &[twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed(
+    "moderator:read:unban_requests",
+))];
+
+/// Resolution of an unban request
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum UnbanRequestStatus {
+    /// The unban request was approved by a moderator, and the user was unbanned
+    Approved,
+    /// The unban request was canceled by the user
+    Canceled,
+    /// The unban request was denied by a moderator
+    Denied,
+}
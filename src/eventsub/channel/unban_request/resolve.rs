@@ -0,0 +1,158 @@
+#![doc(alias = "channel.unban_request.resolve")]
+//! An unban request has been resolved.
+
+use super::*;
+/// [`channel.unban_request.resolve`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelunban_requestresolve): an unban request has been resolved.
+#[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelUnbanRequestResolveV1 {
+    /// The broadcaster user ID for the channel you want to get unban request resolution notifications for.
+    #[builder(setter(into))]
+    pub broadcaster_user_id: types::UserId,
+    /// The ID of a user that has permission to moderate the broadcaster’s channel and has granted your app permission to subscribe to this subscription type.
+    #[builder(setter(into))]
+    pub moderator_user_id: types::UserId,
+}
+
+impl EventSubscription for ChannelUnbanRequestResolveV1 {
+    type Payload = ChannelUnbanRequestResolveV1Payload;
+
+    const EVENT_TYPE: EventType = EventType::ChannelUnbanRequestResolve;
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = SCOPE;
+    const VERSION: &'static str = "1";
+}
+
+/// [`channel.unban_request.resolve`](ChannelUnbanRequestResolveV1) response payload.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelUnbanRequestResolveV1Payload {
+    /// The ID of the unban request.
+    pub id: String,
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: types::UserId,
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: types::UserName,
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: types::DisplayName,
+    /// User ID of the moderator who resolved the unban request. `None` if the user canceled the request.
+    pub moderator_user_id: Option<types::UserId>,
+    /// User login of the moderator who resolved the unban request.
+    pub moderator_user_login: Option<types::UserName>,
+    /// User name of the moderator who resolved the unban request.
+    pub moderator_user_name: Option<types::DisplayName>,
+    /// User ID of user that requested to be unbanned.
+    pub user_id: types::UserId,
+    /// User login of user that requested to be unbanned.
+    pub user_login: types::UserName,
+    /// User name of user that requested to be unbanned.
+    pub user_name: types::DisplayName,
+    /// Resolution text supplied by the moderator who resolved the unban request.
+    pub resolution_text: Option<String>,
+    /// How the unban request was resolved.
+    pub status: UnbanRequestStatus,
+}
+
+#[cfg(test)]
+#[test]
+fn parse_payload() {
+    let payload = r##"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "channel.unban_request.resolve",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "1337",
+                "moderator_user_id": "1339"
+            },
+            "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "id": "60",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "moderator_user_id": "1339",
+            "moderator_user_login": "cool_mod",
+            "moderator_user_name": "Cool_Mod",
+            "user_id": "3141",
+            "user_login": "banned_user",
+            "user_name": "Banned_User",
+            "resolution_text": "no",
+            "status": "denied"
+        }
+    }
+    "##;
+
+    let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
+    crate::tests::roundtrip(&val)
+}
+
+#[cfg(test)]
+#[test]
+fn parse_payload_canceled() {
+    let payload = r##"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "channel.unban_request.resolve",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "1337",
+                "moderator_user_id": "1339"
+            },
+            "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "id": "60",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "moderator_user_id": null,
+            "moderator_user_login": null,
+            "moderator_user_name": null,
+            "user_id": "3141",
+            "user_login": "banned_user",
+            "user_name": "Banned_User",
+            "resolution_text": null,
+            "status": "canceled"
+        }
+    }
+    "##;
+
+    let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
+    crate::tests::roundtrip(&val);
+    if let crate::eventsub::Event::ChannelUnbanRequestResolveV1(crate::eventsub::Payload {
+        message: crate::eventsub::Message::Notification(notification),
+        ..
+    }) = val
+    {
+        assert_eq!(notification.status, UnbanRequestStatus::Canceled);
+        assert!(notification.moderator_user_id.is_none());
+    } else {
+        panic!("invalid event type")
+    }
+}
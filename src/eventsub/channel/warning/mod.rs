@@ -0,0 +1,21 @@
+#![doc(alias = "channel.warning")]
+//! A user has been sent a warning or has acknowledged a warning.
+use super::{EventSubscription, EventType};
+use crate::types;
+use serde::{Deserialize, Serialize};
+
+pub mod acknowledge;
+pub mod send;
+
+#[doc(inline)]
+pub use acknowledge::{ChannelWarningAcknowledgeV1, ChannelWarningAcknowledgeV1Payload};
+#[doc(inline)]
+pub use send::{ChannelWarningSendV1, ChannelWarningSendV1Payload};
+
+/// Scope needed for the warning subscriptions, not yet known to [`twitch_oauth2`]
+///
+/// `moderator:manage:warnings` is also accepted.
+#[cfg(feature = "twitch_oauth2")]
+const SCOPE: &[twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed(
+    "moderator:read:warnings",
+))];
@@ -0,0 +1,104 @@
+#![doc(alias = "channel.warning.send")]
+//! A user is sent a warning.
+
+use super::*;
+/// [`channel.warning.send`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelwarningsend): a user is sent a warning.
+#[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelWarningSendV1 {
+    /// The broadcaster user ID for the channel you want to get warning send notifications for.
+    #[builder(setter(into))]
+    pub broadcaster_user_id: types::UserId,
+    /// The ID of a user that has permission to moderate the broadcaster’s channel and has granted your app permission to subscribe to this subscription type.
+    #[builder(setter(into))]
+    pub moderator_user_id: types::UserId,
+}
+
+impl EventSubscription for ChannelWarningSendV1 {
+    type Payload = ChannelWarningSendV1Payload;
+
+    const EVENT_TYPE: EventType = EventType::ChannelWarningSend;
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = SCOPE;
+    const VERSION: &'static str = "1";
+}
+
+/// [`channel.warning.send`](ChannelWarningSendV1) response payload.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelWarningSendV1Payload {
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: types::UserId,
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: types::UserName,
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: types::DisplayName,
+    /// The user ID of the moderator who sent the warning.
+    pub moderator_user_id: types::UserId,
+    /// The user login of the moderator who sent the warning.
+    pub moderator_user_login: types::UserName,
+    /// The user name of the moderator who sent the warning.
+    pub moderator_user_name: types::DisplayName,
+    /// The ID of the user being warned.
+    pub user_id: types::UserId,
+    /// The user login of the user being warned.
+    pub user_login: types::UserName,
+    /// The user name of the user being warned.
+    pub user_name: types::DisplayName,
+    /// The reason given for the warning.
+    pub reason: Option<String>,
+    /// The chat rules cited for the warning.
+    #[serde(default)]
+    pub chat_rules_cited: Option<Vec<String>>,
+}
+
+#[cfg(test)]
+#[test]
+fn parse_payload() {
+    let payload = r##"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "channel.warning.send",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "1337",
+                "moderator_user_id": "1339"
+            },
+            "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "moderator_user_id": "1339",
+            "moderator_user_login": "cool_mod",
+            "moderator_user_name": "Cool_Mod",
+            "user_id": "3141",
+            "user_login": "warned_user",
+            "user_name": "Warned_User",
+            "reason": "cut it out",
+            "chat_rules_cited": ["No Spamming", "Be respectful"]
+        }
+    }
+    "##;
+
+    let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
+    crate::tests::roundtrip(&val)
+}
@@ -0,0 +1,152 @@
+#![doc(alias = "drop.entitlement.grant")]
+//! An entitlement for a drop is granted to a user.
+use super::*;
+/// [`drop.entitlement.grant`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#dropentitlementgrant): an entitlement for a drop is granted to a user.
+///
+/// This subscription type is only supported by webhooks, and is [batched](EventSubscription::IS_BATCHING_ENABLED): Twitch delivers multiple grants in one notification.
+/// Consequently, the [payload](EventSubscription::Payload) of a notification is a list of [grants](DropEntitlementGrantV1Payload).
+#[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct DropEntitlementGrantV1 {
+    /// The organization ID of the organization that owns the game on the developer portal.
+    #[builder(setter(into))]
+    pub organization_id: String,
+    /// The category (or game) ID of the game for which entitlement notifications will be received.
+    #[builder(default, setter(into))]
+    pub category_id: Option<types::CategoryId>,
+    /// The campaign ID for a specific campaign for which entitlement notifications will be received.
+    #[builder(default, setter(into, strip_option))]
+    pub campaign_id: Option<String>,
+}
+
+impl EventSubscription for DropEntitlementGrantV1 {
+    type Payload = Vec<DropEntitlementGrantV1Payload>;
+
+    const EVENT_TYPE: EventType = EventType::DropEntitlementGrant;
+    const IS_BATCHING_ENABLED: bool = true;
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+    const VERSION: &'static str = "1";
+}
+
+/// A single grant in a [`drop.entitlement.grant`](DropEntitlementGrantV1) notification.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct DropEntitlementGrantV1Payload {
+    /// Individual event ID, as assigned by EventSub. Use this for de-duplicating messages.
+    pub id: String,
+    /// Entitlement object.
+    pub data: DropEntitlement,
+}
+
+/// Entitlement granted in a [`DropEntitlementGrantV1Payload`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct DropEntitlement {
+    /// The ID of the organization that owns the game that has Drops enabled.
+    pub organization_id: String,
+    /// Twitch category ID of the game that was being played when this benefit was entitled.
+    pub category_id: types::CategoryId,
+    /// The category name.
+    pub category_name: String,
+    /// The campaign this entitlement is associated with.
+    pub campaign_id: String,
+    /// Twitch user ID of the user who was granted the entitlement.
+    pub user_id: types::UserId,
+    /// The user display name of the user who was granted the entitlement.
+    pub user_name: types::DisplayName,
+    /// The user login of the user who was granted the entitlement.
+    pub user_login: types::UserName,
+    /// Unique identifier of the entitlement. Use this to de-duplicate entitlements.
+    pub entitlement_id: String,
+    /// Identifier of the Benefit.
+    pub benefit_id: String,
+    /// UTC timestamp in ISO format when this entitlement was granted on Twitch.
+    pub created_at: types::Timestamp,
+}
+
+#[cfg(test)]
+#[test]
+fn parse_payload() {
+    let payload = r#"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "drop.entitlement.grant",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "organization_id": "9001",
+                "category_id": "9002",
+                "campaign_id": null
+            },
+            "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "events": [
+            {
+                "id": "bf7c8577-e3e6-4d7e-bf1c-2b7e1c8b0d6f",
+                "data": {
+                    "organization_id": "9001",
+                    "category_id": "9002",
+                    "category_name": "Fortnite",
+                    "campaign_id": "9003",
+                    "user_id": "1234",
+                    "user_name": "Cool_User",
+                    "user_login": "cool_user",
+                    "entitlement_id": "fb78259e-fb81-4d1b-8333-34a06ffc24c0",
+                    "benefit_id": "74c52265-e214-48a6-91b9-23b6014e8041",
+                    "created_at": "2019-01-28T04:17:53.325Z"
+                }
+            },
+            {
+                "id": "8b7c8577-e3e6-4d7e-bf1c-2b7e1c8b0d6e",
+                "data": {
+                    "organization_id": "9001",
+                    "category_id": "9002",
+                    "category_name": "Fortnite",
+                    "campaign_id": "9003",
+                    "user_id": "12345",
+                    "user_name": "Cooler_User",
+                    "user_login": "cooler_user",
+                    "entitlement_id": "c2a43b63-5765-4d34-9d2e-6dce69f4e7ee",
+                    "benefit_id": "74c52265-e214-48a6-91b9-23b6014e8041",
+                    "created_at": "2019-01-28T04:17:53.325Z"
+                }
+            }
+        ]
+    }
+    "#;
+
+    let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
+    crate::tests::roundtrip(&val);
+    if let crate::eventsub::Event::DropEntitlementGrantV1(crate::eventsub::Payload {
+        message: crate::eventsub::Message::Notification(grants),
+        ..
+    }) = val
+    {
+        assert_eq!(grants.len(), 2);
+        assert_eq!(grants[1].data.user_login.as_str(), "cooler_user");
+    } else {
+        panic!("invalid event type")
+    }
+}
@@ -0,0 +1,9 @@
+//! An entitlement for a drop has been granted to a user
+use super::{EventSubscription, EventType};
+use crate::types;
+use serde::{Deserialize, Serialize};
+
+pub mod grant;
+
+#[doc(inline)]
+pub use grant::{DropEntitlementGrantV1, DropEntitlementGrantV1Payload};
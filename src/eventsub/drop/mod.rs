@@ -0,0 +1,9 @@
+#![doc(alias = "drops")]
+//! Subscription types regarding drops
+
+use super::{EventSubscription, EventType};
+
+pub mod entitlement;
+
+#[doc(inline)]
+pub use entitlement::{DropEntitlementGrantV1, DropEntitlementGrantV1Payload};
@@ -7,8 +7,8 @@ use serde::{Deserialize, Serialize};
 use super::*;
 
 macro_rules! is_thing {
-    ($s:expr, $thing:ident) => {
-        is_thing!(@inner $s, $thing;
+    ($s:expr, $thing:ident, $message_type:literal) => {
+        is_thing!(@inner $s, $thing, $message_type;
             channel::ChannelUpdateV1;
             channel::ChannelFollowV1;
             channel::ChannelSubscribeV1;
@@ -37,16 +37,29 @@ macro_rules! is_thing {
             channel::ChannelHypeTrainBeginV1;
             channel::ChannelHypeTrainProgressV1;
             channel::ChannelHypeTrainEndV1;
+            channel::ChannelHypeTrainBeginV2;
+            channel::ChannelHypeTrainProgressV2;
+            channel::ChannelHypeTrainEndV2;
+            channel::ChannelSuspiciousUserMessageV1;
+            channel::ChannelSuspiciousUserUpdateV1;
+            channel::ChannelUnbanRequestCreateV1;
+            channel::ChannelUnbanRequestResolveV1;
+            channel::ChannelWarningAcknowledgeV1;
+            channel::ChannelWarningSendV1;
+            channel::ChannelChatMessageV1;
             stream::StreamOnlineV1;
             stream::StreamOfflineV1;
             user::UserUpdateV1;
             user::UserAuthorizationGrantV1;
             user::UserAuthorizationRevokeV1;
+            drop::DropEntitlementGrantV1;
+            extension::ExtensionBitsTransactionCreateV1;
         )
     };
-    (@inner $s:expr, $thing:ident; $($module:ident::$event:ident);* $(;)?) => {
+    (@inner $s:expr, $thing:ident, $message_type:literal; $($module:ident::$event:ident);* $(;)?) => {
         match $s {
             $(Event::$event(Payload { message : Message::$thing(..), ..}) => true,)*
+            Event::Unknown(unknown) => unknown.message_type == $message_type,
             _ => false,
         }
     };
@@ -54,7 +67,14 @@ macro_rules! is_thing {
 
 /// Event types
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+// Derives inherent `EventType::serialize` and `EventType::deserialize` for the known event types, see the impls of `Serialize` and `Deserialize` below.
+#[serde(remote = "Self")]
 #[non_exhaustive]
 pub enum EventType {
     /// `channel.update` subscription type sends notifications when a broadcaster updates the category, title, mature flag, or broadcast language for their channel.
@@ -141,6 +161,27 @@ pub enum EventType {
     /// `channel.hype_train.end`: a hype train ends on the specified channel.
     #[serde(rename = "channel.hype_train.end")]
     ChannelHypeTrainEnd,
+    /// `channel.suspicious_user.message`: a chat message has been sent by a suspicious user.
+    #[serde(rename = "channel.suspicious_user.message")]
+    ChannelSuspiciousUserMessage,
+    /// `channel.suspicious_user.update`: a suspicious user has been updated.
+    #[serde(rename = "channel.suspicious_user.update")]
+    ChannelSuspiciousUserUpdate,
+    /// `channel.unban_request.create`: a user creates an unban request.
+    #[serde(rename = "channel.unban_request.create")]
+    ChannelUnbanRequestCreate,
+    /// `channel.unban_request.resolve`: an unban request has been resolved.
+    #[serde(rename = "channel.unban_request.resolve")]
+    ChannelUnbanRequestResolve,
+    /// `channel.warning.acknowledge`: a user acknowledges a warning.
+    #[serde(rename = "channel.warning.acknowledge")]
+    ChannelWarningAcknowledge,
+    /// `channel.warning.send`: a user is sent a warning.
+    #[serde(rename = "channel.warning.send")]
+    ChannelWarningSend,
+    /// `channel.chat.message`: a user sends a message in a chat room.
+    #[serde(rename = "channel.chat.message")]
+    ChannelChatMessage,
     /// `stream.online`: the specified broadcaster starts a stream.
     #[serde(rename = "stream.online")]
     StreamOnline,
@@ -156,6 +197,38 @@ pub enum EventType {
     /// `user.authorization.revoke`: a user’s authorization has been granted to your client id.
     #[serde(rename = "user.authorization.grant")]
     UserAuthorizationGrant,
+    /// `drop.entitlement.grant`: an entitlement for a drop is granted to a user.
+    #[serde(rename = "drop.entitlement.grant")]
+    DropEntitlementGrant,
+    /// `extension.bits_transaction.create`: a Bits transaction occurred for a specified Twitch Extension.
+    #[serde(rename = "extension.bits_transaction.create")]
+    ExtensionBitsTransactionCreate,
+    /// An event type not known to this library.
+    #[serde(skip)]
+    Other(String),
+}
+
+impl Serialize for EventType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: serde::Serializer {
+        match self {
+            EventType::Other(event_type) => serializer.serialize_str(event_type),
+            _ => EventType::serialize(self, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for EventType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: serde::Deserializer<'de> {
+        use serde::de::IntoDeserializer;
+
+        let event_type = String::deserialize(deserializer)?;
+        Ok(
+            EventType::deserialize(event_type.as_str().into_deserializer())
+                .unwrap_or_else(|_: serde::de::value::Error| EventType::Other(event_type)),
+        )
+    }
 }
 
 /// A notification with an event payload. Enumerates all possible [`Payload`s](Payload)
@@ -216,6 +289,26 @@ pub enum Event {
     ChannelHypeTrainProgressV1(Payload<channel::ChannelHypeTrainProgressV1>),
     /// Channel Hype Train End V1 Event
     ChannelHypeTrainEndV1(Payload<channel::ChannelHypeTrainEndV1>),
+    /// Channel Hype Train Begin V2 Event
+    ChannelHypeTrainBeginV2(Payload<channel::ChannelHypeTrainBeginV2>),
+    /// Channel Hype Train Progress V2 Event
+    ChannelHypeTrainProgressV2(Payload<channel::ChannelHypeTrainProgressV2>),
+    /// Channel Hype Train End V2 Event
+    ChannelHypeTrainEndV2(Payload<channel::ChannelHypeTrainEndV2>),
+    /// Channel Suspicious User Message V1 Event
+    ChannelSuspiciousUserMessageV1(Payload<channel::ChannelSuspiciousUserMessageV1>),
+    /// Channel Suspicious User Update V1 Event
+    ChannelSuspiciousUserUpdateV1(Payload<channel::ChannelSuspiciousUserUpdateV1>),
+    /// Channel Unban Request Create V1 Event
+    ChannelUnbanRequestCreateV1(Payload<channel::ChannelUnbanRequestCreateV1>),
+    /// Channel Unban Request Resolve V1 Event
+    ChannelUnbanRequestResolveV1(Payload<channel::ChannelUnbanRequestResolveV1>),
+    /// Channel Warning Acknowledge V1 Event
+    ChannelWarningAcknowledgeV1(Payload<channel::ChannelWarningAcknowledgeV1>),
+    /// Channel Warning Send V1 Event
+    ChannelWarningSendV1(Payload<channel::ChannelWarningSendV1>),
+    /// Channel Chat Message V1 Event
+    ChannelChatMessageV1(Payload<channel::ChannelChatMessageV1>),
     /// StreamOnline V1 Event
     StreamOnlineV1(Payload<stream::StreamOnlineV1>),
     /// StreamOffline V1 Event
@@ -226,6 +319,10 @@ pub enum Event {
     UserAuthorizationGrantV1(Payload<user::UserAuthorizationGrantV1>),
     /// User Authorization Revoke V1 Event
     UserAuthorizationRevokeV1(Payload<user::UserAuthorizationRevokeV1>),
+    /// Drop Entitlement Grant V1 Event
+    DropEntitlementGrantV1(Payload<drop::DropEntitlementGrantV1>),
+    /// Extension Bits Transaction Create V1 Event
+    ExtensionBitsTransactionCreateV1(Payload<extension::ExtensionBitsTransactionCreateV1>),
     /// Channel Raid V1 Event
     ChannelRaidV1(Payload<channel::ChannelRaidV1>),
     /// Channel Subscription End V1 Event
@@ -234,6 +331,27 @@ pub enum Event {
     ChannelSubscriptionGiftV1(Payload<channel::ChannelSubscriptionGiftV1>),
     /// Channel Subscription Message V1 Event
     ChannelSubscriptionMessageV1(Payload<channel::ChannelSubscriptionMessageV1>),
+    /// An event with a type or version not implemented in this library
+    Unknown(UnknownEvent),
+}
+
+/// An event with a type or version not implemented in this library, see [`Event::Unknown`]
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
+#[non_exhaustive]
+pub struct UnknownEvent {
+    /// Event type, [`EventType::Other`] if the type is not known.
+    pub event_type: EventType,
+    /// Event version
+    pub version: String,
+    /// Message type, `notification`, `webhook_callback_verification` or `revocation`
+    pub message_type: String,
+    /// The whole payload, including `subscription` and `event` or `challenge`.
+    pub payload: serde_json::Value,
+}
+
+impl UnknownEvent {
+    /// The challenge to respond with if this is a verification request
+    pub fn challenge(&self) -> Option<&str> { self.payload.get("challenge")?.as_str() }
 }
 
 impl Event {
@@ -247,17 +365,19 @@ impl Event {
     /// Returns `true` if the message in the [`Payload`] is [`Revocation`].
     ///
     /// [`Revocation`]: Message::Revocation
-    pub fn is_notification(&self) -> bool { is_thing!(self, Notification) }
+    pub fn is_notification(&self) -> bool { is_thing!(self, Notification, "notification") }
 
     /// Returns `true` if the message in the [`Payload`] is [`Revocation`].
     ///
     /// [`Revocation`]: Message::Revocation
-    pub fn is_revocation(&self) -> bool { is_thing!(self, Revocation) }
+    pub fn is_revocation(&self) -> bool { is_thing!(self, Revocation, "revocation") }
 
     /// Returns `true` if the message in the [`Payload`] is [`Revocation`].
     ///
     /// [`Revocation`]: Message::Revocation
-    pub fn is_verification_request(&self) -> bool { is_thing!(self, VerificationRequest) }
+    pub fn is_verification_request(&self) -> bool {
+        is_thing!(self, VerificationRequest, "webhook_callback_verification")
+    }
 
     /// If this event is a [`VerificationRequest`], return the [`VerificationRequest`] message, including the message.
     #[rustfmt::skip]
@@ -288,11 +408,23 @@ impl Event {
             Event::ChannelHypeTrainBeginV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
             Event::ChannelHypeTrainProgressV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
             Event::ChannelHypeTrainEndV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
+            Event::ChannelHypeTrainBeginV2(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
+            Event::ChannelHypeTrainProgressV2(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
+            Event::ChannelHypeTrainEndV2(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
+            Event::ChannelSuspiciousUserMessageV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
+            Event::ChannelSuspiciousUserUpdateV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
+            Event::ChannelUnbanRequestCreateV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
+            Event::ChannelUnbanRequestResolveV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
+            Event::ChannelWarningAcknowledgeV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
+            Event::ChannelWarningSendV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
+            Event::ChannelChatMessageV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
             Event::StreamOnlineV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
             Event::StreamOfflineV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
             Event::UserUpdateV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
             Event::UserAuthorizationGrantV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
             Event::UserAuthorizationRevokeV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
+            Event::DropEntitlementGrantV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
+            Event::ExtensionBitsTransactionCreateV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
             Event::ChannelRaidV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
             Event::ChannelSubscriptionEndV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
             Event::ChannelSubscriptionGiftV1(Payload { message: Message::VerificationRequest(v), ..}) => Some(v),
@@ -307,20 +439,11 @@ impl Event {
         ($($module:ident::$event:ident);* $(;)?) => {{
             match &self {
                 $(
-                    Event::$event(notif) => Ok({
-                        let self::Payload {subscription, ..} = notif; // FIXME: Use @ pattern-binding, currently stable
-
-                        EventSubSubscription {
-                        cost: subscription.cost,
-                        condition: subscription.condition.condition()?,
-                        created_at: subscription.created_at.clone(),
-                        id: subscription.id.clone(),
-                        status: subscription.status.clone(),
-                        transport: subscription.transport.clone(),
-                        type_: notif.get_event_type(),
-                        version: notif.get_event_version().to_owned(),
-                    }}),
+                    Event::$event(notif) => notif.subscription.to_subscription(),
                 )*
+                Event::Unknown(unknown) => serde_json::from_value(
+                    unknown.payload.get("subscription").cloned().unwrap_or_default(),
+                ),
             }
         }}
     }
@@ -354,11 +477,23 @@ impl Event {
             channel::ChannelHypeTrainBeginV1;
             channel::ChannelHypeTrainProgressV1;
             channel::ChannelHypeTrainEndV1;
+            channel::ChannelHypeTrainBeginV2;
+            channel::ChannelHypeTrainProgressV2;
+            channel::ChannelHypeTrainEndV2;
+            channel::ChannelSuspiciousUserMessageV1;
+            channel::ChannelSuspiciousUserUpdateV1;
+            channel::ChannelUnbanRequestCreateV1;
+            channel::ChannelUnbanRequestResolveV1;
+            channel::ChannelWarningAcknowledgeV1;
+            channel::ChannelWarningSendV1;
+            channel::ChannelChatMessageV1;
             stream::StreamOnlineV1;
             stream::StreamOfflineV1;
             user::UserUpdateV1;
             user::UserAuthorizationGrantV1;
             user::UserAuthorizationRevokeV1;
+            drop::DropEntitlementGrantV1;
+            extension::ExtensionBitsTransactionCreateV1;
         )
     }
 
@@ -366,64 +501,271 @@ impl Event {
     ///
     /// HMAC key is `secret`, HMAC message is a concatenation of `Twitch-Eventsub-Message-Id` header, `Twitch-Eventsub-Message-Timestamp` header and the request body.
     /// HMAC signature is `Twitch-Eventsub-Message-Signature` header.
+    ///
+    /// See [`Event::try_verify_payload`] to get the reason verification failed.
     #[cfg(feature = "hmac")]
     #[cfg_attr(nightly, doc(cfg(feature = "hmac")))]
     #[must_use]
     pub fn verify_payload<B>(request: &http::Request<B>, secret: &[u8]) -> bool
     where B: AsRef<[u8]> {
+        Self::try_verify_payload(request, secret).is_ok()
+    }
+
+    /// Verify that this event is authentic using `HMAC-SHA256`, returning why verification failed.
+    ///
+    /// Headers are matched case-insensitively.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twitch_api2::eventsub::{Event, VerificationError};
+    /// # let request = http::Request::new(vec![]);
+    /// match Event::try_verify_payload(&request, b"secretabcd") {
+    ///     Ok(()) => println!("request is authentic"),
+    ///     Err(VerificationError::MissingHeader(header)) => println!("request is missing {}", header),
+    ///     Err(e) => println!("request could not be verified: {}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "hmac")]
+    #[cfg_attr(nightly, doc(cfg(feature = "hmac")))]
+    pub fn try_verify_payload<B>(
+        request: &http::Request<B>,
+        secret: &[u8],
+    ) -> Result<(), VerificationError>
+    where
+        B: AsRef<[u8]>,
+    {
         use crypto_hmac::{Hmac, Mac, NewMac};
 
-        fn message_and_signature<B>(request: &http::Request<B>) -> Option<(Vec<u8>, Vec<u8>)>
-        where B: AsRef<[u8]> {
-            static SHA_HEADER: &str = "sha256=";
+        static SHA_HEADER: &str = "sha256=";
 
-            let id = request
-                .headers()
-                .get("Twitch-Eventsub-Message-Id")?
-                .as_bytes();
-            let timestamp = request
+        fn header<'a, B>(
+            request: &'a http::Request<B>,
+            name: &'static str,
+        ) -> Result<&'a http::HeaderValue, VerificationError> {
+            request
                 .headers()
-                .get("Twitch-Eventsub-Message-Timestamp")?
-                .as_bytes();
-            let body = request.body().as_ref();
+                .get(name)
+                .ok_or(VerificationError::MissingHeader(name))
+        }
 
-            let mut message = Vec::with_capacity(id.len() + timestamp.len() + body.len());
-            message.extend_from_slice(id);
-            message.extend_from_slice(timestamp);
-            message.extend_from_slice(body);
+        let id = header(request, MESSAGE_ID_HEADER)?.as_bytes();
+        let timestamp = header(request, MESSAGE_TIMESTAMP_HEADER)?.as_bytes();
+        let body = request.body().as_ref();
 
-            let signature = request
-                .headers()
-                .get("Twitch-Eventsub-Message-Signature")?
-                .to_str()
-                .ok()?;
-            if !signature.starts_with(&SHA_HEADER) {
-                return None;
-            }
-            let signature = signature.split_at(SHA_HEADER.len()).1;
-            if signature.len() % 2 == 0 {
-                // Convert signature to [u8] from hex digits
-                // Hex decode inspired by https://stackoverflow.com/a/52992629
-                let signature = ((0..signature.len())
-                    .step_by(2)
-                    .map(|i| u8::from_str_radix(&signature[i..i + 2], 16))
-                    .collect::<Result<Vec<u8>, _>>())
-                .ok()?;
-
-                Some((message, signature))
-            } else {
-                None
-            }
+        let signature = header(request, MESSAGE_SIGNATURE_HEADER)?
+            .to_str()
+            .map_err(|_| VerificationError::InvalidHeader(MESSAGE_SIGNATURE_HEADER))?;
+        let signature = signature
+            .strip_prefix(SHA_HEADER)
+            .ok_or(VerificationError::UnsupportedSignature)?;
+        if signature.len() % 2 != 0 {
+            return Err(VerificationError::OddLengthSignature(signature.len()));
+        }
+        // Convert signature to [u8] from hex digits
+        // Hex decode inspired by https://stackoverflow.com/a/52992629
+        // `from_str_radix` accepts a leading `+`, so make sure all bytes are hex digits first.
+        if !signature.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(VerificationError::InvalidHex);
         }
+        let signature = (0..signature.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&signature[i..i + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| VerificationError::InvalidHex)?;
 
-        if let Some((message, signature)) = message_and_signature(request) {
-            let mut mac = Hmac::<sha2::Sha256>::new_from_slice(secret).expect("");
-            mac.update(&message);
-            mac.verify(&signature).is_ok()
-        } else {
-            false
+        let mut mac = Hmac::<sha2::Sha256>::new_from_slice(secret).expect("");
+        mac.update(id);
+        mac.update(timestamp);
+        mac.update(body);
+        mac.verify(&signature)
+            .map_err(|_| VerificationError::SignatureMismatch)
+    }
+
+    /// The message type of this event, as sent by Twitch in the `Twitch-Eventsub-Message-Type` header.
+    pub fn message_type(&self) -> &str {
+        match self {
+            Event::Unknown(unknown) => &unknown.message_type,
+            _ if self.is_verification_request() => "webhook_callback_verification",
+            _ if self.is_revocation() => "revocation",
+            _ => "notification",
         }
     }
+
+    /// Serialize this event as the body Twitch sends, the inverse of [`Event::parse`].
+    ///
+    /// See [`Payload::to_json`]
+    pub fn to_json(&self) -> Result<serde_json::Value, serde_json::Error> {
+        macro_rules! match_event {
+            ($($module:ident::$event:ident);* $(;)?) => {{
+                match &self {
+                    $(
+                        Event::$event(payload) => payload.to_json(),
+                    )*
+                    Event::Unknown(unknown) => Ok(unknown.payload.clone()),
+                }
+            }}
+        }
+
+        match_event!(
+            channel::ChannelUpdateV1;
+            channel::ChannelFollowV1;
+            channel::ChannelSubscribeV1;
+            channel::ChannelCheerV1;
+            channel::ChannelBanV1;
+            channel::ChannelUnbanV1;
+            channel::ChannelPointsCustomRewardAddV1;
+            channel::ChannelPointsCustomRewardUpdateV1;
+            channel::ChannelPointsCustomRewardRemoveV1;
+            channel::ChannelPointsCustomRewardRedemptionAddV1;
+            channel::ChannelPointsCustomRewardRedemptionUpdateV1;
+            channel::ChannelPollBeginV1;
+            channel::ChannelPollProgressV1;
+            channel::ChannelPollEndV1;
+            channel::ChannelPredictionBeginV1;
+            channel::ChannelPredictionProgressV1;
+            channel::ChannelPredictionLockV1;
+            channel::ChannelPredictionEndV1;
+            channel::ChannelRaidV1;
+            channel::ChannelSubscriptionEndV1;
+            channel::ChannelSubscriptionGiftV1;
+            channel::ChannelSubscriptionMessageV1;
+            channel::ChannelGoalBeginV1;
+            channel::ChannelGoalProgressV1;
+            channel::ChannelGoalEndV1;
+            channel::ChannelHypeTrainBeginV1;
+            channel::ChannelHypeTrainProgressV1;
+            channel::ChannelHypeTrainEndV1;
+            channel::ChannelHypeTrainBeginV2;
+            channel::ChannelHypeTrainProgressV2;
+            channel::ChannelHypeTrainEndV2;
+            channel::ChannelSuspiciousUserMessageV1;
+            channel::ChannelSuspiciousUserUpdateV1;
+            channel::ChannelUnbanRequestCreateV1;
+            channel::ChannelUnbanRequestResolveV1;
+            channel::ChannelWarningAcknowledgeV1;
+            channel::ChannelWarningSendV1;
+            channel::ChannelChatMessageV1;
+            stream::StreamOnlineV1;
+            stream::StreamOfflineV1;
+            user::UserUpdateV1;
+            user::UserAuthorizationGrantV1;
+            user::UserAuthorizationRevokeV1;
+            drop::DropEntitlementGrantV1;
+            extension::ExtensionBitsTransactionCreateV1;
+        )
+    }
+
+    /// Make the signed request Twitch would send to the callback of the subscription, for testing your webhook server.
+    ///
+    /// The message id is derived from the body, and the timestamp is the current time with the `time` feature,
+    /// otherwise the creation time of the subscription. Use [`Event::to_webhook_request_with`] to choose them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twitch_api2::eventsub::Event;
+    /// # let body = r#"{"subscription":{"id":"f2b5fd7c-3e28-3d1a-9bd5-2b8e0bf1a8a5","status":"enabled","type":"channel.follow","version":"1","condition":{"broadcaster_user_id":"52453745"},"transport":{"method":"webhook","callback":"https://example.com/eventsub"},"created_at":"2022-07-21T16:36:11.282726Z","cost":0},
+    /// # "event":{"user_id":"25009227","user_login":"testfromuser","user_name":"testFromUser","broadcaster_user_id":"52453745","broadcaster_user_login":"testbroadcaster","broadcaster_user_name":"testBroadcaster","followed_at":"2022-07-21T16:36:11.282726Z"}}"#;
+    /// let event = Event::parse(body)?;
+    /// let request = event.to_webhook_request(b"secretabcd")?;
+    /// assert!(Event::verify_payload(&request, b"secretabcd"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "hmac")]
+    #[cfg_attr(nightly, doc(cfg(feature = "hmac")))]
+    pub fn to_webhook_request(
+        &self,
+        secret: &[u8],
+    ) -> Result<http::Request<Vec<u8>>, WebhookRequestError> {
+        use std::hash::{Hash, Hasher};
+
+        let body = serde_json::to_vec(&self.to_json()?)?;
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        body.hash(&mut hasher);
+        let message_id = format!("{:016x}", hasher.finish());
+        #[cfg(feature = "time")]
+        let timestamp = types::Timestamp::now();
+        #[cfg(not(feature = "time"))]
+        let timestamp = self.subscription()?.created_at;
+        self.to_webhook_request_with(&message_id, &timestamp, secret)
+    }
+
+    /// Make the signed request Twitch would send to the callback of the subscription, with the given message id and timestamp.
+    ///
+    /// See [`Event::to_webhook_request`]
+    #[cfg(feature = "hmac")]
+    #[cfg_attr(nightly, doc(cfg(feature = "hmac")))]
+    pub fn to_webhook_request_with(
+        &self,
+        message_id: &str,
+        timestamp: &types::TimestampRef,
+        secret: &[u8],
+    ) -> Result<http::Request<Vec<u8>>, WebhookRequestError> {
+        use crypto_hmac::{Hmac, Mac, NewMac};
+
+        let subscription = self.subscription()?;
+        let body = serde_json::to_vec(&self.to_json()?)?;
+
+        let mut mac = Hmac::<sha2::Sha256>::new_from_slice(secret).expect("");
+        mac.update(message_id.as_bytes());
+        mac.update(timestamp.as_str().as_bytes());
+        mac.update(&body);
+        let signature: String = mac
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+
+        let uri = if subscription.transport.callback.starts_with("http") {
+            subscription.transport.callback.as_str()
+        } else {
+            "/"
+        };
+        Ok(http::Request::post(uri)
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .header(MESSAGE_ID_HEADER, message_id)
+            .header("twitch-eventsub-message-retry", "0")
+            .header(MESSAGE_TYPE_HEADER, self.message_type())
+            .header(MESSAGE_SIGNATURE_HEADER, format!("sha256={}", signature))
+            .header(MESSAGE_TIMESTAMP_HEADER, timestamp.as_str())
+            .header(SUBSCRIPTION_TYPE_HEADER, subscription.type_.to_string())
+            .header(SUBSCRIPTION_VERSION_HEADER, subscription.version)
+            .body(body)?)
+    }
+}
+
+/// Errors when making a [webhook request](Event::to_webhook_request) for an event
+#[cfg(feature = "hmac")]
+#[cfg_attr(nightly, doc(cfg(feature = "hmac")))]
+#[derive(thiserror::Error, displaydoc::Display, Debug)]
+#[non_exhaustive]
+pub enum WebhookRequestError {
+    /// could not serialize event
+    Serialize(#[from] serde_json::Error),
+    /// could not build request
+    Http(#[from] http::Error),
+}
+
+/// Errors that can happen when [verifying](Event::try_verify_payload) a payload
+#[cfg(feature = "hmac")]
+#[cfg_attr(nightly, doc(cfg(feature = "hmac")))]
+#[derive(thiserror::Error, displaydoc::Display, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VerificationError {
+    /// header `{0}` is missing
+    MissingHeader(&'static str),
+    /// header `{0}` is not valid visible ASCII
+    InvalidHeader(&'static str),
+    /// signature is not prefixed with `sha256=`
+    UnsupportedSignature,
+    /// signature has an odd length of {0}, expected hex encoded bytes
+    OddLengthSignature(usize),
+    /// signature is not valid hex
+    InvalidHex,
+    /// signature does not match the computed signature
+    SignatureMismatch,
 }
 
 /// Helper function to get version and type of event from text.
@@ -446,8 +788,9 @@ fn get_version_event_type_and_message_type_from_text(
     #[derive(Deserialize)]
     struct IEvent {
         subscription: IEventSubscripionInformation,
-        challenge: Option<Empty>,
+        challenge: Option<serde::de::IgnoredAny>,
         event: Option<Empty>,
+        events: Option<serde::de::IgnoredAny>,
     }
 
     #[derive(Deserialize)]
@@ -457,9 +800,10 @@ fn get_version_event_type_and_message_type_from_text(
         subscription,
         challenge,
         event,
+        events,
     } = parse_json(source, false)?;
     // FIXME: A visitor is really what we want.
-    if event.is_some() {
+    if event.is_some() || events.is_some() {
         Ok((
             subscription.version.into(),
             subscription.type_,
@@ -490,24 +834,24 @@ where B: AsRef<[u8]> {
     match (
         request
             .headers()
-            .get("Twitch-Eventsub-Subscription-Type")
+            .get(SUBSCRIPTION_TYPE_HEADER)
             .map(|v| v.as_bytes())
             .map(std::str::from_utf8)
             .transpose()?,
         request
             .headers()
-            .get("Twitch-Eventsub-Subscription-Version")
+            .get(SUBSCRIPTION_VERSION_HEADER)
             .map(|v| v.as_bytes())
             .map(std::str::from_utf8)
             .transpose()?,
         request
             .headers()
-            .get("Twitch-Eventsub-Message-Type")
+            .get(MESSAGE_TYPE_HEADER)
             .map(|v| v.as_bytes()),
     ) {
         (Some(ty), Some(version), Some(message_type)) => Ok((
             version.into(),
-            EventType::deserialize(ty.into_deserializer()).map_err(
+            <EventType as Deserialize>::deserialize(ty.into_deserializer()).map_err(
                 |_: serde::de::value::Error| PayloadParseError::UnknownEventType(ty.to_owned()),
             )?,
             message_type.into(),
@@ -516,6 +860,32 @@ where B: AsRef<[u8]> {
     }
 }
 
+impl UnknownEvent {
+    fn parse_request<'a>(
+        version: &str,
+        event_type: &EventType,
+        message_type: Cow<'a, [u8]>,
+        source: Cow<'a, [u8]>,
+    ) -> Result<UnknownEvent, PayloadParseError> {
+        let message_type = match message_type.as_ref() {
+            b"notification" => "notification",
+            b"webhook_callback_verification" => "webhook_callback_verification",
+            b"revocation" => "revocation",
+            typ => {
+                return Err(PayloadParseError::UnknownMessageType(
+                    String::from_utf8_lossy(typ).into_owned(),
+                ))
+            }
+        };
+        Ok(UnknownEvent {
+            event_type: event_type.clone(),
+            version: version.to_owned(),
+            message_type: message_type.to_owned(),
+            payload: parse_json(std::str::from_utf8(&source)?, false)?,
+        })
+    }
+}
+
 impl Event {
     /// Parse a http payload as an [`Event`]
     pub fn parse_http<B>(request: &http::Request<B>) -> Result<Event, PayloadParseError>
@@ -526,6 +896,66 @@ impl Event {
         Self::parse_request(version, &ty, message_type, source)
     }
 
+    /// Parse a http payload as an [`Event`], accepting payloads forwarded by [`twitch-cli`](https://github.com/twitchdev/twitch-cli).
+    ///
+    /// Like [`Event::parse_http`], but the message type header is matched case-insensitively,
+    /// and headers that are missing are filled in from the body, e.g. when forwarding the output of `twitch event trigger` yourself.
+    /// Prefer [`Event::parse_http`] for payloads sent by Twitch.
+    pub fn parse_http_compat<B>(request: &http::Request<B>) -> Result<Event, PayloadParseError>
+    where B: AsRef<[u8]> {
+        use serde::de::IntoDeserializer;
+
+        #[derive(Deserialize)]
+        struct Subscription<'a> {
+            #[serde(rename = "type")]
+            type_: EventType,
+            #[serde(borrow)]
+            version: Cow<'a, str>,
+        }
+        #[derive(Deserialize)]
+        struct Body<'a> {
+            #[serde(borrow)]
+            subscription: Subscription<'a>,
+            #[serde(default)]
+            challenge: Option<serde::de::IgnoredAny>,
+            #[serde(default)]
+            event: Option<serde::de::IgnoredAny>,
+            #[serde(default)]
+            events: Option<serde::de::IgnoredAny>,
+        }
+
+        let header = |name| {
+            request
+                .headers()
+                .get(name)
+                .map(|v| std::str::from_utf8(v.as_bytes()))
+                .transpose()
+        };
+        let body: Body = parse_json(std::str::from_utf8(request.body().as_ref())?, false)?;
+        let ty = match header(SUBSCRIPTION_TYPE_HEADER)? {
+            Some(ty) => <EventType as Deserialize>::deserialize(ty.into_deserializer()).map_err(
+                |_: serde::de::value::Error| PayloadParseError::UnknownEventType(ty.to_owned()),
+            )?,
+            None => body.subscription.type_,
+        };
+        let version = match header(SUBSCRIPTION_VERSION_HEADER)? {
+            Some(version) => version.into(),
+            None => body.subscription.version,
+        };
+        let message_type = match header(MESSAGE_TYPE_HEADER)? {
+            Some(message_type) => message_type.to_ascii_lowercase(),
+            None if body.challenge.is_some() => "webhook_callback_verification".to_owned(),
+            None if body.event.is_some() || body.events.is_some() => "notification".to_owned(),
+            None => "revocation".to_owned(),
+        };
+        Self::parse_request(
+            version,
+            &ty,
+            message_type.into_bytes().into(),
+            request.body().as_ref().into(),
+        )
+    }
+
     /// Parse a string slice as an [`Event`]. You should not use this, instead, use [`Event::parse_http`] or [`Event::parse`].
     #[doc(hidden)]
     pub fn parse_request<'a>(
@@ -545,7 +975,7 @@ impl Event {
                     $(  (<$module::$event as EventSubscription>::VERSION, &<$module::$event as EventSubscription>::EVENT_TYPE) => {
                         Event::$event(Payload::parse_request(message_type, source)?)
                     }  )*
-                    (v, e) => return Err(PayloadParseError::UnimplementedEvent{version: v.to_owned(), event_type: e.clone()})
+                    (v, e) => Event::Unknown(UnknownEvent::parse_request(v, e, message_type, source)?)
                 }
             }}
         }
@@ -579,11 +1009,23 @@ impl Event {
             channel::ChannelHypeTrainBeginV1;
             channel::ChannelHypeTrainProgressV1;
             channel::ChannelHypeTrainEndV1;
+            channel::ChannelHypeTrainBeginV2;
+            channel::ChannelHypeTrainProgressV2;
+            channel::ChannelHypeTrainEndV2;
+            channel::ChannelSuspiciousUserMessageV1;
+            channel::ChannelSuspiciousUserUpdateV1;
+            channel::ChannelUnbanRequestCreateV1;
+            channel::ChannelUnbanRequestResolveV1;
+            channel::ChannelWarningAcknowledgeV1;
+            channel::ChannelWarningSendV1;
+            channel::ChannelChatMessageV1;
             stream::StreamOnlineV1;
             stream::StreamOfflineV1;
             user::UserUpdateV1;
             user::UserAuthorizationGrantV1;
             user::UserAuthorizationRevokeV1;
+            drop::DropEntitlementGrantV1;
+            extension::ExtensionBitsTransactionCreateV1;
         })
     }
 }
@@ -0,0 +1,118 @@
+#![doc(alias = "extension.bits_transaction.create")]
+//! A Bits transaction occurred for a specified Twitch Extension.
+use super::*;
+/// [`extension.bits_transaction.create`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#extensionbits_transactioncreate): a Bits transaction occurred for a specified Twitch Extension.
+///
+/// This subscription type is only supported by webhooks.
+#[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionBitsTransactionCreateV1 {
+    /// The client ID of the extension.
+    #[builder(setter(into))]
+    pub extension_client_id: String,
+}
+
+impl EventSubscription for ExtensionBitsTransactionCreateV1 {
+    type Payload = ExtensionBitsTransactionCreateV1Payload;
+
+    const EVENT_TYPE: EventType = EventType::ExtensionBitsTransactionCreate;
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+    const VERSION: &'static str = "1";
+}
+
+/// [`extension.bits_transaction.create`](ExtensionBitsTransactionCreateV1) response payload.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionBitsTransactionCreateV1Payload {
+    /// Client ID of the extension.
+    pub extension_client_id: String,
+    /// Transaction ID.
+    pub id: String,
+    /// The transaction’s broadcaster ID.
+    pub broadcaster_user_id: types::UserId,
+    /// The transaction’s broadcaster login.
+    pub broadcaster_user_login: types::UserName,
+    /// The transaction’s broadcaster display name.
+    pub broadcaster_user_name: types::DisplayName,
+    /// The transaction’s user ID.
+    pub user_id: types::UserId,
+    /// The transaction’s user login.
+    pub user_login: types::UserName,
+    /// The transaction’s user display name.
+    pub user_name: types::DisplayName,
+    /// Additional extension product information.
+    pub product: ExtensionProduct,
+}
+
+/// Product bought in a [`ExtensionBitsTransactionCreateV1Payload`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionProduct {
+    /// Product name.
+    pub name: String,
+    /// Bits involved in the transaction.
+    pub bits: i64,
+    /// Unique identifier for the product acquired.
+    pub sku: String,
+    /// Flag indicating if the product is in development. If `in_development` is true, bits will be 0.
+    pub in_development: bool,
+}
+
+#[cfg(test)]
+#[test]
+fn parse_payload() {
+    let payload = r#"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "extension.bits_transaction.create",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "extension_client_id": "deadbeef"
+            },
+            "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "id": "bits-tx-id",
+            "extension_client_id": "deadbeef",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "user_name": "Coolest_User",
+            "user_login": "coolest_user",
+            "user_id": "1236",
+            "product": {
+                "name": "great_product",
+                "sku": "skuskusku",
+                "bits": 1234,
+                "in_development": false
+            }
+        }
+    }
+    "#;
+
+    let val = dbg!(crate::eventsub::Event::parse(payload).unwrap());
+    crate::tests::roundtrip(&val)
+}
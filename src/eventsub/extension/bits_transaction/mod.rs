@@ -0,0 +1,9 @@
+//! A Bits transaction has occurred for a specific extension
+use super::{EventSubscription, EventType};
+use crate::types;
+use serde::{Deserialize, Serialize};
+
+pub mod create;
+
+#[doc(inline)]
+pub use create::{ExtensionBitsTransactionCreateV1, ExtensionBitsTransactionCreateV1Payload};
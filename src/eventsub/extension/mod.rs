@@ -0,0 +1,11 @@
+#![doc(alias = "extensions")]
+//! Subscription types regarding extensions
+
+use super::{EventSubscription, EventType};
+
+pub mod bits_transaction;
+
+#[doc(inline)]
+pub use bits_transaction::{
+    ExtensionBitsTransactionCreateV1, ExtensionBitsTransactionCreateV1Payload,
+};
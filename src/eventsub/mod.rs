@@ -9,6 +9,7 @@
 //!
 //! ```rust, no_run
 //! use twitch_api2::eventsub::{channel::ChannelFollowV1, Transport, TransportMethod};
+//! use twitch_api2::types::CallbackUrl;
 //! use twitch_api2::helix::{self, eventsub::{
 //!     CreateEventSubSubscriptionBody, CreateEventSubSubscriptionRequest,
 //! }};
@@ -22,8 +23,8 @@
 //! let event = ChannelFollowV1::builder()
 //!     .broadcaster_user_id("1234")
 //!     .build();
-//! let transport = Transport::webhook(
-//!     "https://example.org/eventsub/channelfollow",
+//! let transport = Transport::webhook_url(
+//!     CallbackUrl::new("https://example.org/eventsub/channelfollow")?,
 //!     String::from("secretabcd"),
 //! );
 //!
@@ -87,12 +88,30 @@ use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use crate::parse_json;
 
 pub mod channel;
+pub mod drop;
 pub mod event;
+pub mod extension;
 pub mod stream;
 pub mod user;
+pub mod websocket;
 
 #[doc(inline)]
-pub use event::{Event, EventType};
+pub use event::{Event, EventType, UnknownEvent};
+
+#[cfg(feature = "hmac")]
+#[doc(inline)]
+pub use event::{VerificationError, WebhookRequestError};
+
+// Header names are lowercase, matching how `http` stores them. Lookups with `HeaderMap::get` are case-insensitive.
+#[cfg(feature = "hmac")]
+pub(crate) const MESSAGE_ID_HEADER: &str = "twitch-eventsub-message-id";
+#[cfg(feature = "hmac")]
+pub(crate) const MESSAGE_TIMESTAMP_HEADER: &str = "twitch-eventsub-message-timestamp";
+#[cfg(feature = "hmac")]
+pub(crate) const MESSAGE_SIGNATURE_HEADER: &str = "twitch-eventsub-message-signature";
+pub(crate) const MESSAGE_TYPE_HEADER: &str = "twitch-eventsub-message-type";
+pub(crate) const SUBSCRIPTION_TYPE_HEADER: &str = "twitch-eventsub-subscription-type";
+pub(crate) const SUBSCRIPTION_VERSION_HEADER: &str = "twitch-eventsub-subscription-version";
 
 /// An EventSub subscription.
 pub trait EventSubscription: DeserializeOwned + Serialize + PartialEq + Clone {
@@ -109,6 +128,12 @@ pub trait EventSubscription: DeserializeOwned + Serialize + PartialEq + Clone {
     const VERSION: &'static str;
     /// Subscription type name.
     const EVENT_TYPE: EventType;
+    /// Whether notifications for this subscription are batched.
+    ///
+    /// Batched notifications deliver multiple events in `events`, instead of a single event in `event`.
+    /// The [`Payload`](EventSubscription::Payload) of a batched subscription should be a [`Vec`] of events.
+    /// Batching is also requested when creating the subscription with `Create EventSub Subscription`.
+    const IS_BATCHING_ENABLED: bool = false;
 
     /// Creates the [`condition`](https://dev.twitch.tv/docs/eventsub/eventsub-reference#conditions) for this EventSub subscription
     fn condition(&self) -> Result<serde_json::Value, serde_json::Error> {
@@ -168,6 +193,8 @@ impl<E: EventSubscription> Payload<E> {
     }
 
     /// Parse string slice as a [`Payload`] with a message of [`Message::Notification`].
+    ///
+    /// If the subscription [is batched](EventSubscription::IS_BATCHING_ENABLED), the events are read from `events`, otherwise from `event`.
     pub fn parse_notification(source: &str) -> Result<Payload<E>, PayloadParseError> {
         #[derive(Deserialize)]
         #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
@@ -178,10 +205,28 @@ impl<E: EventSubscription> Payload<E> {
             pub event: <E as EventSubscription>::Payload,
         }
 
-        let Notification {
-            subscription,
-            event,
-        } = parse_json::<Notification<E>>(source, true)?;
+        #[derive(Deserialize)]
+        #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+        struct BatchedNotification<E: EventSubscription> {
+            #[serde(bound = "E: EventSubscription")]
+            pub subscription: EventSubscriptionInformation<E>,
+            #[serde(bound = "E: EventSubscription")]
+            pub events: <E as EventSubscription>::Payload,
+        }
+
+        let (subscription, event) = if E::IS_BATCHING_ENABLED {
+            let BatchedNotification {
+                subscription,
+                events,
+            } = parse_json::<BatchedNotification<E>>(source, true)?;
+            (subscription, events)
+        } else {
+            let Notification {
+                subscription,
+                event,
+            } = parse_json::<Notification<E>>(source, true)?;
+            (subscription, event)
+        };
 
         Ok(Payload {
             subscription,
@@ -253,7 +298,7 @@ impl<E: EventSubscription> Payload<E> {
         let source = request.body().as_ref().into();
         let ty = request
             .headers()
-            .get("Twitch-Eventsub-Message-Type")
+            .get(MESSAGE_TYPE_HEADER)
             .map(|v| v.as_bytes())
             .unwrap_or_else(|| b"notification")
             .into();
@@ -319,6 +364,41 @@ impl<E: EventSubscription + Clone> Payload<E> {
 
     /// Convenience method for getting the event version from the payload.
     pub fn get_event_version(&self) -> &'static str { E::VERSION }
+
+    /// The message type of this payload, as sent by Twitch in the `Twitch-Eventsub-Message-Type` header.
+    pub fn message_type(&self) -> &'static str {
+        match self.message {
+            Message::VerificationRequest(..) => "webhook_callback_verification",
+            Message::Revocation() => "revocation",
+            Message::Notification(..) => "notification",
+        }
+    }
+
+    /// Serialize this payload as the body Twitch sends, the inverse of [`Payload::parse_http`].
+    ///
+    /// The [`Serialize`] implementation of [`Payload`] doesn't produce the format of Twitch, use this to make bodies for testing your webhook server.
+    pub fn to_json(&self) -> Result<serde_json::Value, serde_json::Error> {
+        let mut json = serde_json::Map::new();
+        json.insert(
+            "subscription".to_owned(),
+            serde_json::to_value(&self.subscription)?,
+        );
+        match &self.message {
+            Message::VerificationRequest(VerificationRequest { challenge }) => {
+                json.insert("challenge".to_owned(), challenge.clone().into());
+            }
+            Message::Revocation() => {}
+            Message::Notification(event) => {
+                let key = if E::IS_BATCHING_ENABLED {
+                    "events"
+                } else {
+                    "event"
+                };
+                json.insert(key.to_owned(), serde_json::to_value(event)?);
+            }
+        }
+        Ok(json.into())
+    }
 }
 
 /// Metadata about the subscription.
@@ -346,30 +426,104 @@ pub struct EventSubscriptionInformation<E: EventSubscription> {
     pub version: String,
 }
 
+impl<E: EventSubscription> EventSubscriptionInformation<E> {
+    /// The condition of the subscription, as the subscription type itself.
+    pub fn condition(&self) -> &E { &self.condition }
+
+    /// The condition of the subscription as JSON, the same as sent when creating the subscription.
+    pub fn condition_json(&self) -> Result<serde_json::Value, serde_json::Error> {
+        self.condition.condition()
+    }
+
+    /// Make a [`EventSubSubscription`] from this subscription information.
+    pub fn to_subscription(&self) -> Result<EventSubSubscription, serde_json::Error> {
+        Ok(EventSubSubscription {
+            cost: self.cost,
+            condition: self.condition_json()?,
+            created_at: self.created_at.clone(),
+            id: self.id.clone(),
+            status: self.status.clone(),
+            transport: self.transport.clone(),
+            type_: E::EVENT_TYPE,
+            version: E::VERSION.to_owned(),
+        })
+    }
+}
+
 /// Transport setting for event notification
 #[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Transport {
     /// Method for transport
     pub method: TransportMethod,
-    /// Callback
+    /// Callback, empty for [websocket](TransportMethod::Websocket) transports
+    #[builder(default, setter(into))]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub callback: String,
-    /// Secret attached to the subscription.
+    /// Secret attached to the subscription, empty for [websocket](TransportMethod::Websocket) transports
     ///
     /// # Notes
     ///
     /// Secret must be between 10 and 100 characters
+    #[builder(default, setter(into))]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub secret: String,
+    /// Websocket session to deliver notifications to, only for [websocket](TransportMethod::Websocket) transports
+    #[builder(default, setter(into))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
 }
 
 impl Transport {
     /// Convenience method for making a webhook transport
+    ///
+    /// The callback is not checked, use [`Transport::webhook_url`] to catch an invalid callback before Twitch rejects the subscription.
+    #[deprecated(
+        since = "0.6.0",
+        note = "the callback is not checked, use `Transport::webhook_url` with a `types::CallbackUrl` instead"
+    )]
     pub fn webhook(callback: impl std::string::ToString, secret: String) -> Transport {
         Transport {
             method: TransportMethod::Webhook,
             callback: callback.to_string(),
             secret,
+            session_id: None,
+        }
+    }
+
+    /// Convenience method for making a webhook transport with a [checked](types::CallbackUrl) callback
+    ///
+    /// ```rust
+    /// use twitch_api2::{eventsub::Transport, types::CallbackUrl};
+    ///
+    /// let callback = CallbackUrl::new("https://example.com/eventsub")?;
+    /// let transport = Transport::webhook_url(callback, "s3cre7abcd".to_string());
+    /// # Ok::<(), twitch_api2::types::CallbackUrlError>(())
+    /// ```
+    pub fn webhook_url(callback: types::CallbackUrl, secret: String) -> Transport {
+        Transport {
+            method: TransportMethod::Webhook,
+            callback: callback.into_string(),
+            secret,
+            session_id: None,
+        }
+    }
+
+    /// Convenience method for making a websocket transport
+    ///
+    /// The session id is sent in the [welcome message](websocket::WebsocketData::Welcome) of the connection.
+    pub fn websocket(session_id: impl std::string::ToString) -> Transport {
+        Transport {
+            method: TransportMethod::Websocket,
+            callback: String::new(),
+            secret: String::new(),
+            session_id: Some(session_id.to_string()),
         }
     }
 }
@@ -378,24 +532,46 @@ impl Transport {
 ///
 /// Does not include secret.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct TransportResponse {
     /// Method for transport
     pub method: TransportMethod,
-    /// Callback
+    /// Callback, empty for [websocket](TransportMethod::Websocket) transports
+    #[serde(default)]
     pub callback: String,
+    /// Websocket session the subscription delivers to, only for [websocket](TransportMethod::Websocket) transports
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    /// When the websocket session connected, only for [websocket](TransportMethod::Websocket) transports
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connected_at: Option<types::Timestamp>,
+    /// When the websocket session disconnected, only for [websocket](TransportMethod::Websocket) transports
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disconnected_at: Option<types::Timestamp>,
 }
 
 /// Transport method
 ///
-/// Currently, only webhooks are supported
+/// Websocket sessions are handled by [`websocket`]
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[non_exhaustive]
 #[serde(rename_all = "lowercase")]
 pub enum TransportMethod {
     /// Webhook
     Webhook,
+    /// Websocket
+    Websocket,
 }
 
 impl std::fmt::Display for EventType {
@@ -404,6 +580,11 @@ impl std::fmt::Display for EventType {
 
 ///  Subscription request status
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[non_exhaustive]
 #[serde(rename_all = "snake_case")] // FIXME: Most examples use kebab-case... but reality seems to be snake_case
 pub enum Status {
@@ -419,6 +600,20 @@ pub enum Status {
     AuthorizationRevoked,
     /// A user in the condition of the subscription was removed.
     UserRemoved,
+    /// The client closed the connection.
+    WebsocketDisconnected,
+    /// The client failed to respond to a ping message.
+    WebsocketFailedPingPong,
+    /// The client sent a non-pong message.
+    WebsocketReceivedInboundTraffic,
+    /// The client failed to subscribe to events within the required time.
+    WebsocketConnectionUnused,
+    /// The Twitch WebSocket server experienced an unexpected error.
+    WebsocketInternalError,
+    /// The Twitch WebSocket server timed out writing the message to the client.
+    WebsocketNetworkTimeout,
+    /// The Twitch WebSocket server experienced a network error writing the message to the client.
+    WebsocketNetworkError,
 }
 
 /// General information about an EventSub subscription.
@@ -452,6 +647,127 @@ pub struct EventSubSubscription {
 #[cfg(test)]
 mod test {
 
+    #[test]
+    fn test_batched_event_on_unbatched_subscription() {
+        use crate::eventsub::{channel::ChannelBanV1, Payload, PayloadParseError};
+
+        let body = r#"{
+            "subscription": {
+                "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+                "status": "enabled",
+                "type": "channel.ban",
+                "version": "1",
+                "cost": 0,
+                "condition": {
+                        "broadcaster_user_id": "1337"
+                },
+                "transport": {
+                    "method": "webhook",
+                    "callback": "https://example.com/webhooks/callback"
+                },
+                "created_at": "2019-11-16T10:11:12.123Z"
+            },
+            "events": []
+        }"#;
+
+        assert!(matches!(
+            Payload::<ChannelBanV1>::parse_notification(body),
+            Err(PayloadParseError::DeserializeError(_))
+        ));
+    }
+
+    #[test]
+    fn test_subscription_condition() {
+        use crate::eventsub::{channel::ChannelBanV1, Payload};
+
+        let body = r#"{
+            "subscription": {
+                "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+                "status": "enabled",
+                "type": "channel.ban",
+                "version": "1",
+                "cost": 0,
+                "condition": {
+                        "broadcaster_user_id": "1337"
+                },
+                "transport": {
+                    "method": "webhook",
+                    "callback": "https://example.com/webhooks/callback"
+                },
+                "created_at": "2019-11-16T10:11:12.123Z"
+            },
+            "event": {
+                "user_id": "1234",
+                "user_login": "cool_user",
+                "user_name": "Cool_User",
+                "broadcaster_user_id": "1337",
+                "broadcaster_user_login": "cooler_user",
+                "broadcaster_user_name": "Cooler_User",
+                "moderator_user_id": "1339",
+                "moderator_user_login": "mod_user",
+                "moderator_user_name": "Mod_User",
+                "reason": "Offensive language",
+                "ends_at": "2020-07-15T18:16:11.17106713Z",
+                "is_permanent": false
+            }
+        }"#;
+
+        let payload = Payload::<ChannelBanV1>::parse_notification(body).unwrap();
+        assert_eq!(
+            payload.subscription.condition(),
+            &ChannelBanV1::builder().broadcaster_user_id("1337").build()
+        );
+        assert_eq!(
+            payload.subscription.condition_json().unwrap(),
+            serde_json::json!({"broadcaster_user_id": "1337"})
+        );
+        let subscription = payload.subscription.to_subscription().unwrap();
+        assert_eq!(subscription.type_, crate::eventsub::EventType::ChannelBan);
+        assert_eq!(subscription.version, "1");
+    }
+
+    #[test]
+    fn test_unknown_event() {
+        use crate::eventsub::{Event, EventType};
+
+        let body = r#"{
+            "subscription": {
+                "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+                "status": "enabled",
+                "type": "channel.brand_new_thing",
+                "version": "beta",
+                "cost": 0,
+                "condition": {
+                        "broadcaster_user_id": "1337"
+                },
+                "transport": {
+                    "method": "webhook",
+                    "callback": "https://example.com/webhooks/callback"
+                },
+                "created_at": "2019-11-16T10:11:12.123Z"
+            },
+            "event": {
+                "broadcaster_user_id": "1337"
+            }
+        }"#;
+
+        let event = Event::parse(body).unwrap();
+        assert!(event.is_notification());
+        let unknown = match &event {
+            Event::Unknown(unknown) => unknown,
+            _ => panic!("expected an unknown event, got {:?}", event),
+        };
+        assert_eq!(
+            unknown.event_type,
+            EventType::Other("channel.brand_new_thing".to_owned())
+        );
+        assert_eq!(unknown.event_type.to_string(), "channel.brand_new_thing");
+        assert_eq!(unknown.version, "beta");
+        let subscription = event.subscription().unwrap();
+        assert_eq!(subscription.type_, unknown.event_type);
+        crate::tests::roundtrip(&event)
+    }
+
     #[test]
     fn test_verification_response() {
         use http::header::{HeaderMap, HeaderName, HeaderValue};
@@ -500,6 +816,107 @@ mod test {
         crate::tests::roundtrip(&payload)
     }
 
+    #[test]
+    fn test_twitch_cli_compat() {
+        use crate::{eventsub::Event, fixtures::eventsub::twitch_cli};
+
+        // Forwarded output of `twitch event trigger`, without any headers
+        let request = http::Request::builder()
+            .body(twitch_cli::CHANNEL_FOLLOW.as_bytes().to_vec())
+            .unwrap();
+        let event = Event::parse_http_compat(&request).unwrap();
+        assert!(event.is_notification());
+        assert!(matches!(event, Event::ChannelFollowV1(..)));
+
+        let request = http::Request::builder()
+            .header(
+                "twitch-eventsub-message-type",
+                "Webhook_Callback_Verification",
+            )
+            .header("TWITCH-EVENTSUB-SUBSCRIPTION-TYPE", "channel.follow")
+            .body(twitch_cli::CHANNEL_FOLLOW_VERIFICATION.as_bytes().to_vec())
+            .unwrap();
+        assert!(Event::parse_http(&request).is_err());
+        let event = Event::parse_http_compat(&request).unwrap();
+        assert!(event.is_verification_request());
+    }
+
+    #[test]
+    fn test_to_json() {
+        use crate::{eventsub::Event, fixtures::eventsub::twitch_cli};
+
+        let revocation = r#"{"subscription":{"id":"f1c2a387-161a-49f9-a165-0f21d7a4e1c4","status":"authorization_revoked","type":"channel.follow","cost":1,"version":"1","condition":{"broadcaster_user_id":"12826"},"transport":{"method":"webhook","callback":"https://example.com/webhooks/callback"},"created_at":"2019-11-16T10:11:12.123Z"}}"#;
+        let batched = r#"{
+            "subscription": {
+                "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+                "type": "drop.entitlement.grant",
+                "version": "1",
+                "status": "enabled",
+                "cost": 0,
+                "condition": {
+                    "organization_id": "9001",
+                    "category_id": null,
+                    "campaign_id": null
+                },
+                "transport": {
+                    "method": "webhook",
+                    "callback": "https://example.com/webhooks/callback"
+                },
+                "created_at": "2019-11-16T10:11:12.123Z"
+            },
+            "events": [
+                {
+                    "id": "bf7c8577-e3e6-4d7e-bf1c-2b7e1c8b0d6f",
+                    "data": {
+                        "organization_id": "9001",
+                        "category_id": "9002",
+                        "category_name": "Fortnite",
+                        "campaign_id": "9003",
+                        "user_id": "1234",
+                        "user_name": "Cool_User",
+                        "user_login": "cool_user",
+                        "entitlement_id": "fb78259e-fb81-4d1b-8333-34a06ffc24c0",
+                        "benefit_id": "74c52265-e214-48a6-91b9-23b6014e8041",
+                        "created_at": "2019-01-28T04:17:53.325Z"
+                    }
+                }
+            ]
+        }"#;
+
+        for payload in [
+            twitch_cli::CHANNEL_FOLLOW,
+            twitch_cli::CHANNEL_FOLLOW_VERIFICATION,
+            revocation,
+            batched,
+        ] {
+            let event = Event::parse(payload).unwrap();
+            assert_eq!(
+                event.to_json().unwrap(),
+                serde_json::from_str::<serde_json::Value>(payload).unwrap(),
+                "{}",
+                payload
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "hmac")]
+    fn test_to_webhook_request() {
+        use crate::{eventsub::Event, fixtures::eventsub::twitch_cli};
+
+        let event = Event::parse(twitch_cli::CHANNEL_FOLLOW).unwrap();
+        let request = event
+            .to_webhook_request_with(
+                "befa7b53-d79d-478f-86b9-120f112b044e",
+                &crate::types::Timestamp::new("2022-07-21T16:36:11Z").unwrap(),
+                b"secretabcd",
+            )
+            .unwrap();
+        assert!(Event::verify_payload(&request, b"secretabcd"));
+        assert!(!Event::verify_payload(&request, b"othersecret"));
+        assert_eq!(Event::parse_http(&request).unwrap(), event);
+    }
+
     #[test]
     fn test_revoke() {
         use http::header::{HeaderMap, HeaderName, HeaderValue};
@@ -563,4 +980,74 @@ mod test {
         dbg!(&body);
         assert!(crate::eventsub::Event::verify_payload(&request, secret));
     }
+
+    #[test]
+    #[cfg(feature = "hmac")]
+    fn verify_request_errors() {
+        use crate::eventsub::{Event, VerificationError};
+        use http::header::{HeaderMap, HeaderName, HeaderValue};
+
+        let secret = b"secretabcd";
+        let body = r#"{"subscription":{"id":"ae2ff348-e102-16be-a3eb-6830c1bf38d2","status":"enabled","type":"channel.follow","version":"1","condition":{"broadcaster_user_id":"44429626"},"transport":{"method":"webhook","callback":"null"},"created_at":"2021-02-19T23:47:00.7621315Z"},"event":{"user_id":"28408015","user_login":"testFromUser","user_name":"testFromUser","broadcaster_user_id":"44429626","broadcaster_user_login":"44429626","broadcaster_user_name":"testBroadcaster"}}"#;
+        let request = |signature: Option<&str>| {
+            #[rustfmt::skip]
+            let mut headers: HeaderMap = vec![
+                ("twitch-eventsub-message-id", "ae2ff348-e102-16be-a3eb-6830c1bf38d2"),
+                ("TWITCH-EVENTSUB-MESSAGE-TIMESTAMP", "2021-02-19T23:47:00.8091512Z"),
+            ].into_iter()
+            .map(|(h, v)| {
+                (
+                    h.parse::<HeaderName>().unwrap(),
+                    v.parse::<HeaderValue>().unwrap(),
+                )
+            })
+            .collect();
+            if let Some(signature) = signature {
+                headers.insert(
+                    "Twitch-Eventsub-Message-Signature",
+                    signature.parse().unwrap(),
+                );
+            }
+            let mut request = http::Request::builder();
+            let _ = std::mem::replace(request.headers_mut().unwrap(), headers);
+            request.body(body.as_bytes().to_vec()).unwrap()
+        };
+
+        assert_eq!(
+            Event::try_verify_payload(
+                &request(Some(
+                    "sha256=d10f5bd9474b7ac7bd7105eb79c2d52768b4d0cd2a135982c3bf5a1d59a78823"
+                )),
+                secret
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            Event::try_verify_payload(&request(None), secret),
+            Err(VerificationError::MissingHeader(
+                "twitch-eventsub-message-signature"
+            ))
+        );
+        assert_eq!(
+            Event::try_verify_payload(&request(Some("md5=abcd")), secret),
+            Err(VerificationError::UnsupportedSignature)
+        );
+        assert_eq!(
+            Event::try_verify_payload(&request(Some("sha256=abc")), secret),
+            Err(VerificationError::OddLengthSignature(3))
+        );
+        assert_eq!(
+            Event::try_verify_payload(&request(Some("sha256=+f+f")), secret),
+            Err(VerificationError::InvalidHex)
+        );
+        assert_eq!(
+            Event::try_verify_payload(
+                &request(Some(
+                    "sha256=d10f5bd9474b7ac7bd7105eb79c2d52768b4d0cd2a135982c3bf5a1d59a78824"
+                )),
+                secret
+            ),
+            Err(VerificationError::SignatureMismatch)
+        );
+    }
 }
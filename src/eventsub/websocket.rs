@@ -0,0 +1,605 @@
+//! EventSub over websockets
+//!
+//! Like [PubSub](crate::pubsub), this does not open connections itself, bring your favorite websocket client.
+//! [`WebsocketMessage::parse`] parses the messages received on a connection,
+//! and [`WebsocketEventSubClient`] keeps track of the session: it handles `session_reconnect` migrations without losing events,
+//! watches the keepalive timeout and tells you when to subscribe again.
+//!
+//! # Examples
+//!
+//! ```rust, no_run
+//! use twitch_api2::eventsub::websocket::{Action, WebsocketEventSubClient};
+//! # fn open(url: &str) {}
+//! # fn close_old() {}
+//! # fn close_all() {}
+//! # fn sleep(delay: std::time::Duration) {}
+//! # fn subscribe(session_id: &str) {}
+//! # fn next_message() -> Option<String> { None }
+//! # fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! let mut client = WebsocketEventSubClient::new();
+//! let mut actions = vec![client.connect()];
+//! loop {
+//!     for action in actions.drain(..) {
+//!         match action {
+//!             // Keep the current connection open until told to close it
+//!             Action::Connect { url } => open(&url),
+//!             Action::CloseOld => close_old(),
+//!             // The connection is dead, wait before opening a new one
+//!             Action::Reconnect { url, delay } => {
+//!                 close_all();
+//!                 sleep(delay);
+//!                 open(&url)
+//!             }
+//!             Action::GiveUp { attempts } => panic!("no connection after {} attempts", attempts),
+//!             // Create the subscriptions with `Transport` method `websocket` and this session
+//!             Action::Subscribe { session_id } => subscribe(&session_id),
+//!             Action::Event(event) => println!("{:?}", event),
+//!             _ => {}
+//!         }
+//!     }
+//!     for state in client.drain_state_changes() {
+//!         println!("connection is now {:?}", state);
+//!     }
+//!     // Wait for a message, or until `client.keepalive_deadline()`
+//!     match next_message() {
+//!         Some(text) => actions = client.handle_message(&text, std::time::Instant::now())?,
+//!         None => actions.extend(client.check_keepalive(std::time::Instant::now())),
+//!         // or `client.connection_closed(now)` if the connection was closed
+//!     }
+//! }
+//! # }
+//! ```
+
+use std::{
+    borrow::Cow,
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{Event, EventType, PayloadParseError};
+use crate::{parse_json, types};
+
+/// How many message IDs are remembered to drop duplicated notifications
+const SEEN_MESSAGES: usize = 100;
+/// Time given on top of the keepalive timeout before a connection is considered dead
+const KEEPALIVE_GRACE: Duration = Duration::from_secs(5);
+/// Keepalive timeout used until a session says otherwise
+const DEFAULT_KEEPALIVE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Delay before the second attempt to reconnect, doubled on every following attempt
+const RECONNECT_BACKOFF: Duration = Duration::from_secs(1);
+/// Longest delay between two attempts to reconnect
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(60);
+/// Attempts to reconnect without receiving a welcome message before giving up
+const MAX_RECONNECT_ATTEMPTS: u32 = 10;
+
+/// Metadata of a [`WebsocketMessage`]
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct MessageMetadata {
+    /// An ID that uniquely identifies the message. Twitch may send a notification more than once, with the same ID.
+    pub message_id: String,
+    /// The type of the message.
+    pub message_type: String,
+    /// The time the message was sent.
+    pub message_timestamp: types::Timestamp,
+    /// The type of event sent in the message, only for notifications and revocations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription_type: Option<EventType>,
+    /// The version number of the subscription type’s definition, only for notifications and revocations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription_version: Option<String>,
+}
+
+/// A websocket session
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct SessionData {
+    /// An ID that uniquely identifies this WebSocket connection. Use this ID to set the `session_id` field in all subscription requests.
+    pub id: String,
+    /// The connection’s status, `connected` or `reconnecting`.
+    pub status: String,
+    /// The UTC date and time that the connection was created.
+    pub connected_at: types::Timestamp,
+    /// The maximum number of seconds that you should expect silence before receiving a keepalive message.
+    #[serde(default)]
+    pub keepalive_timeout_seconds: Option<u64>,
+    /// The URL to reconnect to, only set in `session_reconnect` messages.
+    #[serde(default)]
+    pub reconnect_url: Option<String>,
+    /// Reserved by Twitch.
+    #[serde(default)]
+    pub recovery_url: Option<String>,
+}
+
+/// Data of a [`WebsocketMessage`]
+#[derive(PartialEq, Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+#[non_exhaustive]
+pub enum WebsocketData {
+    /// `session_welcome` - the connection is ready
+    Welcome(SessionData),
+    /// `session_keepalive` - the connection is still alive
+    Keepalive,
+    /// `notification` - an event
+    Notification(Event),
+    /// `session_reconnect` - connect to [`reconnect_url`](SessionData::reconnect_url), the current connection will be closed soon
+    Reconnect(SessionData),
+    /// `revocation` - a subscription was revoked
+    Revocation(Event),
+}
+
+/// A message received on an EventSub websocket connection
+#[derive(PartialEq, Debug, Clone)]
+#[non_exhaustive]
+pub struct WebsocketMessage {
+    /// Metadata of the message
+    pub metadata: MessageMetadata,
+    /// The message
+    pub data: WebsocketData,
+}
+
+impl WebsocketMessage {
+    /// Parse a text message received on an EventSub websocket connection
+    pub fn parse(source: &str) -> Result<WebsocketMessage, PayloadParseError> {
+        #[derive(Deserialize)]
+        struct Message {
+            metadata: MessageMetadata,
+            payload: serde_json::Value,
+        }
+
+        #[derive(Deserialize)]
+        struct Session {
+            session: SessionData,
+        }
+
+        let Message { metadata, payload } = parse_json::<Message>(source, true)?;
+        let data = match metadata.message_type.as_str() {
+            "session_welcome" => {
+                WebsocketData::Welcome(parse_json::<Session>(&payload.to_string(), true)?.session)
+            }
+            "session_keepalive" => WebsocketData::Keepalive,
+            "session_reconnect" => {
+                WebsocketData::Reconnect(parse_json::<Session>(&payload.to_string(), true)?.session)
+            }
+            "notification" => WebsocketData::Notification(parse_event(&metadata, &payload)?),
+            "revocation" => WebsocketData::Revocation(parse_event(&metadata, &payload)?),
+            typ => return Err(PayloadParseError::UnknownMessageType(typ.to_owned())),
+        };
+        Ok(WebsocketMessage { metadata, data })
+    }
+}
+
+/// Parse the payload of a `notification` or `revocation`
+fn parse_event(
+    metadata: &MessageMetadata,
+    payload: &serde_json::Value,
+) -> Result<Event, PayloadParseError> {
+    match (&metadata.subscription_type, &metadata.subscription_version) {
+        (Some(event_type), Some(version)) => Event::parse_request(
+            Cow::Borrowed(version),
+            event_type,
+            Cow::Borrowed(metadata.message_type.as_bytes()),
+            Cow::Owned(payload.to_string().into_bytes()),
+        ),
+        _ => Err(PayloadParseError::MalformedEvent),
+    }
+}
+
+/// State of the connection, see [`WebsocketEventSubClient::drain_state_changes`]
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[non_exhaustive]
+pub enum ConnectionState {
+    /// Not connected
+    Disconnected,
+    /// Waiting for the welcome message of a new session
+    Connecting,
+    /// Connected to a session
+    Connected {
+        /// The session
+        session_id: String,
+    },
+    /// Migrating the session to a new connection, events are still received on the old one
+    Reconnecting {
+        /// URL of the new connection
+        url: String,
+    },
+}
+
+/// What to do after handing a message to [`WebsocketEventSubClient`]
+#[derive(PartialEq, Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+#[non_exhaustive]
+pub enum Action {
+    /// Open a new connection to `url`. Keep the current connection open until told to [close it](Action::CloseOld).
+    Connect {
+        /// URL to connect to
+        url: String,
+    },
+    /// Close the old connection, the new connection took over its session.
+    CloseOld,
+    /// The connection is dead and its session is lost. Close all connections, then open a new connection to `url` after `delay`.
+    ///
+    /// The delay grows with every attempt that fails in a row.
+    Reconnect {
+        /// URL to connect to
+        url: String,
+        /// How long to wait before connecting
+        delay: Duration,
+    },
+    /// Reconnecting failed `attempts` times in a row, close all connections.
+    ///
+    /// The client is [disconnected](ConnectionState::Disconnected), use [`connect`](WebsocketEventSubClient::connect) to try again.
+    GiveUp {
+        /// How many times reconnecting failed
+        attempts: u32,
+    },
+    /// Create your subscriptions for this session, a new session has none.
+    Subscribe {
+        /// The session to subscribe with
+        session_id: String,
+    },
+    /// An event was received
+    Event(Event),
+    /// A subscription was revoked
+    Revoked(Event),
+}
+
+/// Keeps track of an EventSub websocket session, see the [module](self) for an example.
+///
+/// The client does no IO, it tells you what to do with [actions](Action).
+#[derive(Debug, Clone)]
+pub struct WebsocketEventSubClient {
+    state: ConnectionState,
+    keepalive_timeout: Duration,
+    last_message: Option<Instant>,
+    failed_attempts: u32,
+    seen: VecDeque<String>,
+    state_changes: VecDeque<ConnectionState>,
+}
+
+impl Default for WebsocketEventSubClient {
+    fn default() -> Self { Self::new() }
+}
+
+impl WebsocketEventSubClient {
+    /// Create a new, disconnected, client
+    pub fn new() -> Self {
+        WebsocketEventSubClient {
+            state: ConnectionState::Disconnected,
+            keepalive_timeout: DEFAULT_KEEPALIVE_TIMEOUT,
+            last_message: None,
+            failed_attempts: 0,
+            seen: VecDeque::with_capacity(SEEN_MESSAGES),
+            state_changes: VecDeque::new(),
+        }
+    }
+
+    /// The current state of the connection
+    pub fn state(&self) -> &ConnectionState { &self.state }
+
+    /// Start a new session
+    pub fn connect(&mut self) -> Action {
+        self.last_message = None;
+        self.failed_attempts = 0;
+        self.set_state(ConnectionState::Connecting);
+        Action::Connect {
+            url: crate::TWITCH_EVENTSUB_WEBSOCKET_URL.to_string(),
+        }
+    }
+
+    /// Handle a text message received on any open connection
+    pub fn handle_message(
+        &mut self,
+        source: &str,
+        now: Instant,
+    ) -> Result<Vec<Action>, PayloadParseError> {
+        let message = WebsocketMessage::parse(source)?;
+        self.last_message = Some(now);
+        Ok(match message.data {
+            WebsocketData::Welcome(session) => {
+                if let Some(timeout) = session.keepalive_timeout_seconds {
+                    self.keepalive_timeout = Duration::from_secs(timeout);
+                }
+                let reconnected = matches!(self.state, ConnectionState::Reconnecting { .. });
+                self.failed_attempts = 0;
+                self.set_state(ConnectionState::Connected {
+                    session_id: session.id.clone(),
+                });
+                if reconnected {
+                    vec![Action::CloseOld]
+                } else {
+                    vec![Action::Subscribe {
+                        session_id: session.id,
+                    }]
+                }
+            }
+            WebsocketData::Keepalive => vec![],
+            WebsocketData::Notification(event) => {
+                if self.is_new(&message.metadata.message_id) {
+                    vec![Action::Event(event)]
+                } else {
+                    vec![]
+                }
+            }
+            WebsocketData::Reconnect(session) => match session.reconnect_url {
+                Some(url) => {
+                    self.set_state(ConnectionState::Reconnecting { url: url.clone() });
+                    vec![Action::Connect { url }]
+                }
+                None => return Err(PayloadParseError::MalformedEvent),
+            },
+            WebsocketData::Revocation(event) => vec![Action::Revoked(event)],
+        })
+    }
+
+    /// When the connection is considered dead if no message is received, see [`check_keepalive`](WebsocketEventSubClient::check_keepalive)
+    ///
+    /// While [reconnecting](Action::Reconnect), this is when the attempt is considered failed if no welcome message was received.
+    pub fn keepalive_deadline(&self) -> Option<Instant> {
+        match self.state {
+            ConnectionState::Disconnected => None,
+            _ => self
+                .last_message
+                .map(|last| last + self.keepalive_timeout + KEEPALIVE_GRACE),
+        }
+    }
+
+    /// Check the keepalive timeout, [reconnecting](Action::Reconnect) if the connection is dead.
+    pub fn check_keepalive(&mut self, now: Instant) -> Option<Action> {
+        if now < self.keepalive_deadline()? {
+            return None;
+        }
+        Some(self.reconnect(now))
+    }
+
+    /// The current connection was closed, [reconnecting](Action::Reconnect) unless a `session_reconnect` migration is in progress.
+    pub fn connection_closed(&mut self, now: Instant) -> Option<Action> {
+        match self.state {
+            ConnectionState::Reconnecting { .. } | ConnectionState::Disconnected => None,
+            _ => Some(self.reconnect(now)),
+        }
+    }
+
+    /// Changes of the [connection state](ConnectionState) since the last call
+    pub fn drain_state_changes(
+        &mut self,
+    ) -> std::collections::vec_deque::Drain<'_, ConnectionState> {
+        self.state_changes.drain(..)
+    }
+
+    /// Start a new session after the connection died, waiting longer after every failed attempt
+    fn reconnect(&mut self, now: Instant) -> Action {
+        self.failed_attempts += 1;
+        if self.failed_attempts > MAX_RECONNECT_ATTEMPTS {
+            self.last_message = None;
+            self.set_state(ConnectionState::Disconnected);
+            return Action::GiveUp {
+                attempts: MAX_RECONNECT_ATTEMPTS,
+            };
+        }
+        let delay = match self.failed_attempts {
+            1 => Duration::from_secs(0),
+            n => (RECONNECT_BACKOFF * 2u32.pow(n - 2)).min(MAX_RECONNECT_DELAY),
+        };
+        // the new attempt fails if there's no welcome message in time
+        self.last_message = Some(now + delay);
+        self.set_state(ConnectionState::Connecting);
+        Action::Reconnect {
+            url: crate::TWITCH_EVENTSUB_WEBSOCKET_URL.to_string(),
+            delay,
+        }
+    }
+
+    fn set_state(&mut self, state: ConnectionState) {
+        if self.state != state {
+            self.state = state.clone();
+            self.state_changes.push_back(state);
+        }
+    }
+
+    /// Remember `message_id`, returning `false` if it was already seen
+    fn is_new(&mut self, message_id: &str) -> bool {
+        if self.seen.iter().any(|seen| seen == message_id) {
+            return false;
+        }
+        if self.seen.len() == SEEN_MESSAGES {
+            self.seen.pop_front();
+        }
+        self.seen.push_back(message_id.to_owned());
+        true
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn session() {
+    let welcome = |id: &str| {
+        format!(
+            r#"{{
+    "metadata": {{
+        "message_id": "96a3f3b5-5dec-4eed-908e-e11ee657416c",
+        "message_type": "session_welcome",
+        "message_timestamp": "2022-11-16T10:11:12.634234626Z"
+    }},
+    "payload": {{
+        "session": {{
+            "id": "{}",
+            "status": "connected",
+            "connected_at": "2022-11-16T10:11:12.634234626Z",
+            "keepalive_timeout_seconds": 10,
+            "reconnect_url": null
+        }}
+    }}
+}}"#,
+            id
+        )
+    };
+    let notification = r#"{
+    "metadata": {
+        "message_id": "befa7b53-d79d-478f-86b9-120f112b044e",
+        "message_type": "notification",
+        "message_timestamp": "2022-11-16T10:11:12.464757833Z",
+        "subscription_type": "channel.follow",
+        "subscription_version": "1"
+    },
+    "payload": {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "status": "enabled",
+            "type": "channel.follow",
+            "version": "1",
+            "cost": 1,
+            "condition": {
+                "broadcaster_user_id": "12826"
+            },
+            "transport": {
+                "method": "websocket",
+                "session_id": "AQoQexAWVYKSTIu4ec_2VAxyuhAB"
+            },
+            "created_at": "2022-11-16T10:11:12.464757833Z"
+        },
+        "event": {
+            "user_id": "1337",
+            "user_login": "awesome_user",
+            "user_name": "Awesome_User",
+            "broadcaster_user_id": "12826",
+            "broadcaster_user_login": "twitch",
+            "broadcaster_user_name": "Twitch",
+            "followed_at": "2022-11-16T10:11:12.464757833Z"
+        }
+    }
+}"#;
+    let reconnect = r#"{
+    "metadata": {
+        "message_id": "84c1e79a-2a4b-4c13-ba0b-4312293e9308",
+        "message_type": "session_reconnect",
+        "message_timestamp": "2022-11-18T09:10:11.634234626Z"
+    },
+    "payload": {
+        "session": {
+           "id": "AQoQexAWVYKSTIu4ec_2VAxyuhAB",
+           "status": "reconnecting",
+           "keepalive_timeout_seconds": null,
+           "reconnect_url": "wss://eventsub.wss.twitch.tv?...",
+           "connected_at": "2022-11-16T10:11:12.634234626Z"
+        }
+    }
+}"#;
+
+    let now = Instant::now();
+    let mut client = WebsocketEventSubClient::new();
+    client.connect();
+    assert_eq!(
+        client
+            .handle_message(&welcome("AQoQexAWVYKSTIu4ec_2VAxyuhAB"), now)
+            .unwrap(),
+        vec![Action::Subscribe {
+            session_id: "AQoQexAWVYKSTIu4ec_2VAxyuhAB".to_owned()
+        }]
+    );
+    assert!(matches!(
+        client.handle_message(notification, now).unwrap().as_slice(),
+        [Action::Event(Event::ChannelFollowV1(_))]
+    ));
+    // The same notification, e.g. received on both connections while reconnecting
+    assert!(client.handle_message(notification, now).unwrap().is_empty());
+
+    assert_eq!(
+        client.handle_message(reconnect, now).unwrap(),
+        vec![Action::Connect {
+            url: "wss://eventsub.wss.twitch.tv?...".to_owned()
+        }]
+    );
+    assert_eq!(
+        client
+            .handle_message(&welcome("AQoQexAWVYKSTIu4ec_2VAxyuhAB"), now)
+            .unwrap(),
+        vec![Action::CloseOld]
+    );
+
+    assert_eq!(client.check_keepalive(now + Duration::from_secs(1)), None);
+    assert_eq!(
+        client.check_keepalive(now + Duration::from_secs(16)),
+        Some(Action::Reconnect {
+            url: crate::TWITCH_EVENTSUB_WEBSOCKET_URL.to_string(),
+            delay: Duration::from_secs(0),
+        })
+    );
+    assert_eq!(
+        client.drain_state_changes().collect::<Vec<_>>(),
+        vec![
+            ConnectionState::Connecting,
+            ConnectionState::Connected {
+                session_id: "AQoQexAWVYKSTIu4ec_2VAxyuhAB".to_owned()
+            },
+            ConnectionState::Reconnecting {
+                url: "wss://eventsub.wss.twitch.tv?...".to_owned()
+            },
+            ConnectionState::Connected {
+                session_id: "AQoQexAWVYKSTIu4ec_2VAxyuhAB".to_owned()
+            },
+            ConnectionState::Connecting,
+        ]
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn reconnect_backoff() {
+    let mut now = Instant::now();
+    let mut client = WebsocketEventSubClient::new();
+    client.connect();
+    // not connected yet, nothing to reconnect
+    assert_eq!(client.check_keepalive(now), None);
+
+    let mut delays = vec![];
+    // the first attempt is the closed connection, every other one a connection that didn't welcome us in time
+    let mut action = client.connection_closed(now);
+    while let Some(Action::Reconnect { delay, .. }) = action {
+        delays.push(delay.as_secs());
+        assert_eq!(client.state(), &ConnectionState::Connecting);
+        assert_eq!(client.check_keepalive(now + delay), None);
+        now = client.keepalive_deadline().unwrap();
+        action = client.check_keepalive(now);
+    }
+    assert_eq!(delays, vec![0, 1, 2, 4, 8, 16, 32, 60, 60, 60]);
+    assert_eq!(action, Some(Action::GiveUp { attempts: 10 }));
+    assert_eq!(client.state(), &ConnectionState::Disconnected);
+    assert_eq!(client.keepalive_deadline(), None);
+    assert_eq!(client.check_keepalive(now), None);
+    assert_eq!(client.connection_closed(now), None);
+
+    // a welcome message resets the backoff
+    client.connect();
+    client.connection_closed(now);
+    client.check_keepalive(client.keepalive_deadline().unwrap());
+    client
+        .handle_message(
+            r#"{
+    "metadata": {
+        "message_id": "96a3f3b5-5dec-4eed-908e-e11ee657416c",
+        "message_type": "session_welcome",
+        "message_timestamp": "2022-11-16T10:11:12.634234626Z"
+    },
+    "payload": {
+        "session": {
+            "id": "AQoQexAWVYKSTIu4ec_2VAxyuhAB",
+            "status": "connected",
+            "connected_at": "2022-11-16T10:11:12.634234626Z",
+            "keepalive_timeout_seconds": 10,
+            "reconnect_url": null
+        }
+    }
+}"#,
+            now,
+        )
+        .unwrap();
+    assert!(matches!(
+        client.connection_closed(now),
+        Some(Action::Reconnect { delay, .. }) if delay == Duration::from_secs(0)
+    ));
+}
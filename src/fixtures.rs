@@ -0,0 +1,577 @@
+//! Example payloads for endpoints, mostly taken from the Twitch documentation.
+//!
+//! These are the same payloads used in the tests of this crate, and can be used to mock responses from Twitch.
+//! The files themselves live in `fixtures/` in the repository. To add a new one, put the payload there, add a constant for it and list it in [`ALL`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use twitch_api2::fixtures;
+//!
+//! let response = http::Response::builder()
+//!     .body(fixtures::helix::users::GET_USERS.as_bytes().to_vec())
+//!     .unwrap();
+//! # let _ = response;
+//! for (path, payload) in fixtures::ALL {
+//!     println!("{}: {} bytes", path, payload.len());
+//! }
+//! ```
+
+/// Helix payloads
+pub mod helix {
+    /// Payloads for `helix::channels`
+    pub mod channels {
+        /// Response for [Get Channel Editors](https://dev.twitch.tv/docs/api/reference#get-channel-editors)
+        pub const GET_CHANNEL_EDITORS: &str =
+            include_str!("../fixtures/helix/channels/get_channel_editors.json");
+        /// Response for [Get Channel Followers](https://dev.twitch.tv/docs/api/reference#get-channel-followers)
+        pub const GET_CHANNEL_FOLLOWERS: &str =
+            include_str!("../fixtures/helix/channels/get_channel_followers.json");
+        /// Response for [Get Channel Information](https://dev.twitch.tv/docs/api/reference#get-channel-information)
+        pub const GET_CHANNEL_INFORMATION: &str =
+            include_str!("../fixtures/helix/channels/get_channel_information.json");
+        /// Response for [Get VIPs](https://dev.twitch.tv/docs/api/reference#get-vips)
+        pub const GET_VIPS: &str = include_str!("../fixtures/helix/channels/get_vips.json");
+        /// Response for [Start Commercial](https://dev.twitch.tv/docs/api/reference#start-commercial)
+        pub const START_COMMERCIAL: &str =
+            include_str!("../fixtures/helix/channels/start_commercial.json");
+    }
+
+    /// Payloads for `helix::charity`
+    pub mod charity {
+        /// Response for [Get Charity Campaign Donations](https://dev.twitch.tv/docs/api/reference#get-charity-campaign-donations)
+        pub const GET_CHARITY_CAMPAIGN_DONATIONS: &str =
+            include_str!("../fixtures/helix/charity/get_charity_campaign_donations.json");
+    }
+
+    /// Payloads for `helix::chat`
+    pub mod chat {
+        /// Response for [Get Channel Chat Badges](https://dev.twitch.tv/docs/api/reference#get-channel-chat-badges)
+        pub const GET_CHANNEL_CHAT_BADGES: &str =
+            include_str!("../fixtures/helix/chat/get_channel_chat_badges.json");
+        /// Response for [Get Channel Emotes](https://dev.twitch.tv/docs/api/reference#get-channel-emotes)
+        pub const GET_CHANNEL_EMOTES: &str =
+            include_str!("../fixtures/helix/chat/get_channel_emotes.json");
+        /// Response for [Get Chat Settings](https://dev.twitch.tv/docs/api/reference#get-chat-settings)
+        pub const GET_CHAT_SETTINGS: &str =
+            include_str!("../fixtures/helix/chat/get_chat_settings.json");
+        /// Response for [Get Chatters](https://dev.twitch.tv/docs/api/reference#get-chatters)
+        pub const GET_CHATTERS: &str = include_str!("../fixtures/helix/chat/get_chatters.json");
+        /// Response for [Get Emote Sets](https://dev.twitch.tv/docs/api/reference#get-emote-sets)
+        pub const GET_EMOTE_SETS: &str = include_str!("../fixtures/helix/chat/get_emote_sets.json");
+        /// Response for [Get Global Chat Badges](https://dev.twitch.tv/docs/api/reference#get-global-chat-badges)
+        pub const GET_GLOBAL_CHAT_BADGES: &str =
+            include_str!("../fixtures/helix/chat/get_global_chat_badges.json");
+        /// Response for [Get Global Emotes](https://dev.twitch.tv/docs/api/reference#get-global-emotes)
+        pub const GET_GLOBAL_EMOTES: &str =
+            include_str!("../fixtures/helix/chat/get_global_emotes.json");
+    }
+
+    /// Payloads for `helix::clips`
+    pub mod clips {
+        /// Response for [Get Clips](https://dev.twitch.tv/docs/api/reference#get-clips)
+        pub const GET_CLIPS: &str = include_str!("../fixtures/helix/clips/get_clips.json");
+    }
+
+    /// Payloads for `helix::eventsub`
+    pub mod eventsub {
+        /// Response for [Create EventSub Subscription](https://dev.twitch.tv/docs/api/reference#create-eventsub-subscription)
+        pub const CREATE_EVENTSUB_SUBSCRIPTION: &str =
+            include_str!("../fixtures/helix/eventsub/create_eventsub_subscription.json");
+        /// Response for [Get EventSub Subscriptions](https://dev.twitch.tv/docs/api/reference#get-eventsub-subscriptions)
+        pub const GET_EVENTSUB_SUBSCRIPTIONS: &str =
+            include_str!("../fixtures/helix/eventsub/get_eventsub_subscriptions.json");
+    }
+
+    /// Payloads for `helix::extensions`
+    pub mod extensions {
+        /// Response for [Create Extension Secret](https://dev.twitch.tv/docs/api/reference#create-extension-secret)
+        pub const CREATE_EXTENSION_SECRET: &str =
+            include_str!("../fixtures/helix/extensions/create_extension_secret.json");
+        /// Response for [Get Extension Bits Products](https://dev.twitch.tv/docs/api/reference#get-extension-bits-products)
+        pub const GET_EXTENSION_BITS_PRODUCTS: &str =
+            include_str!("../fixtures/helix/extensions/get_extension_bits_products.json");
+        /// Response for [Get Extension Configuration Segment](https://dev.twitch.tv/docs/api/reference#get-extension-configuration-segment)
+        pub const GET_EXTENSION_CONFIGURATION_SEGMENT: &str =
+            include_str!("../fixtures/helix/extensions/get_extension_configuration_segment.json");
+        /// Response for [Get Extension Secrets](https://dev.twitch.tv/docs/api/reference#get-extension-secrets)
+        pub const GET_EXTENSION_SECRETS: &str =
+            include_str!("../fixtures/helix/extensions/get_extension_secrets.json");
+        /// Response for [Get Extension Transactions](https://dev.twitch.tv/docs/api/reference#get-extension-transactions)
+        pub const GET_EXTENSION_TRANSACTIONS: &str =
+            include_str!("../fixtures/helix/extensions/get_extension_transactions.json");
+        /// Response for [Update Extension Bits Product](https://dev.twitch.tv/docs/api/reference#update-extension-bits-product)
+        pub const UPDATE_EXTENSION_BITS_PRODUCT: &str =
+            include_str!("../fixtures/helix/extensions/update_extension_bits_product.json");
+    }
+
+    /// Payloads for `helix::games`
+    pub mod games {
+        /// Response for [Get Games](https://dev.twitch.tv/docs/api/reference#get-games)
+        pub const GET_GAMES: &str = include_str!("../fixtures/helix/games/get_games.json");
+        /// Response for [Get Top Games](https://dev.twitch.tv/docs/api/reference#get-top-games)
+        pub const GET_TOP_GAMES: &str = include_str!("../fixtures/helix/games/get_top_games.json");
+    }
+
+    /// Payloads for `helix::goals`
+    pub mod goals {
+        /// Response for [Get Creator Goals](https://dev.twitch.tv/docs/api/reference#get-creator-goals)
+        pub const GET_CREATOR_GOALS: &str =
+            include_str!("../fixtures/helix/goals/get_creator_goals.json");
+    }
+
+    /// Payloads for `helix::hypetrain`
+    pub mod hypetrain {
+        /// Response for [Get Hype Train Events](https://dev.twitch.tv/docs/api/reference#get-hype-train-events)
+        pub const GET_HYPETRAIN_EVENTS: &str =
+            include_str!("../fixtures/helix/hypetrain/get_hypetrain_events.json");
+    }
+
+    /// Payloads for `helix::moderation`
+    pub mod moderation {
+        /// Response for [Add Blocked Term](https://dev.twitch.tv/docs/api/reference#add-blocked-term)
+        pub const ADD_BLOCKED_TERM: &str =
+            include_str!("../fixtures/helix/moderation/add_blocked_term.json");
+        /// Response for [Check AutoMod Status](https://dev.twitch.tv/docs/api/reference#check-automod-status)
+        pub const CHECK_AUTOMOD_STATUS: &str =
+            include_str!("../fixtures/helix/moderation/check_automod_status.json");
+        /// Response for [Get Banned Events](https://dev.twitch.tv/docs/api/reference#get-banned-events)
+        pub const GET_BANNED_EVENTS: &str =
+            include_str!("../fixtures/helix/moderation/get_banned_events.json");
+        /// Response for [Get Banned Users](https://dev.twitch.tv/docs/api/reference#get-banned-users)
+        pub const GET_BANNED_USERS: &str =
+            include_str!("../fixtures/helix/moderation/get_banned_users.json");
+        /// Response for [Get Moderated Channels](https://dev.twitch.tv/docs/api/reference#get-moderated-channels)
+        pub const GET_MODERATED_CHANNELS: &str =
+            include_str!("../fixtures/helix/moderation/get_moderated_channels.json");
+        /// Response for [Get Moderator Events](https://dev.twitch.tv/docs/api/reference#get-moderator-events)
+        pub const GET_MODERATOR_EVENTS: &str =
+            include_str!("../fixtures/helix/moderation/get_moderator_events.json");
+        /// Response for [Get Moderators](https://dev.twitch.tv/docs/api/reference#get-moderators)
+        pub const GET_MODERATORS: &str =
+            include_str!("../fixtures/helix/moderation/get_moderators.json");
+        /// Response for [Update AutoMod Settings](https://dev.twitch.tv/docs/api/reference#update-automod-settings)
+        pub const UPDATE_AUTOMOD_SETTINGS: &str =
+            include_str!("../fixtures/helix/moderation/update_automod_settings.json");
+    }
+
+    /// Payloads for `helix::points`
+    pub mod points {
+        /// Response for [Create Custom Rewards](https://dev.twitch.tv/docs/api/reference#create-custom-rewards)
+        pub const CREATE_CUSTOM_REWARDS: &str =
+            include_str!("../fixtures/helix/points/create_custom_rewards.json");
+        /// Response for [Get Custom Reward](https://dev.twitch.tv/docs/api/reference#get-custom-reward)
+        pub const GET_CUSTOM_REWARD: &str =
+            include_str!("../fixtures/helix/points/get_custom_reward.json");
+        /// Response for [Get Custom Reward Redemption](https://dev.twitch.tv/docs/api/reference#get-custom-reward-redemption)
+        pub const GET_CUSTOM_REWARD_REDEMPTION: &str =
+            include_str!("../fixtures/helix/points/get_custom_reward_redemption.json");
+        /// Response for [Update Custom Reward](https://dev.twitch.tv/docs/api/reference#update-custom-reward)
+        pub const UPDATE_CUSTOM_REWARD: &str =
+            include_str!("../fixtures/helix/points/update_custom_reward.json");
+        /// Response for [Update Redemption Status](https://dev.twitch.tv/docs/api/reference#update-redemption-status)
+        pub const UPDATE_REDEMPTION_STATUS: &str =
+            include_str!("../fixtures/helix/points/update_redemption_status.json");
+    }
+
+    /// Payloads for `helix::polls`
+    pub mod polls {
+        /// Response for [Create Poll](https://dev.twitch.tv/docs/api/reference#create-poll)
+        pub const CREATE_POLL: &str = include_str!("../fixtures/helix/polls/create_poll.json");
+        /// Response for [End Poll](https://dev.twitch.tv/docs/api/reference#end-poll)
+        pub const END_POLL: &str = include_str!("../fixtures/helix/polls/end_poll.json");
+        /// Response for [Get Polls](https://dev.twitch.tv/docs/api/reference#get-polls)
+        pub const GET_POLLS: &str = include_str!("../fixtures/helix/polls/get_polls.json");
+    }
+
+    /// Payloads for `helix::predictions`
+    pub mod predictions {
+        /// Response for [Create Prediction](https://dev.twitch.tv/docs/api/reference#create-prediction)
+        pub const CREATE_PREDICTION: &str =
+            include_str!("../fixtures/helix/predictions/create_prediction.json");
+        /// Response for [End Prediction](https://dev.twitch.tv/docs/api/reference#end-prediction)
+        pub const END_PREDICTION: &str =
+            include_str!("../fixtures/helix/predictions/end_prediction.json");
+        /// Response for [Get Predictions](https://dev.twitch.tv/docs/api/reference#get-predictions)
+        pub const GET_PREDICTIONS: &str =
+            include_str!("../fixtures/helix/predictions/get_predictions.json");
+    }
+
+    /// Payloads for `helix::schedule`
+    pub mod schedule {
+        /// Response for [Create Channel Stream Schedule Segment](https://dev.twitch.tv/docs/api/reference#create-channel-stream-schedule-segment)
+        pub const CREATE_CHANNEL_STREAM_SCHEDULE_SEGMENT: &str =
+            include_str!("../fixtures/helix/schedule/create_channel_stream_schedule_segment.json");
+        /// Response for [Get Channel Stream Schedule](https://dev.twitch.tv/docs/api/reference#get-channel-stream-schedule)
+        pub const GET_CHANNEL_STREAM_SCHEDULE: &str =
+            include_str!("../fixtures/helix/schedule/get_channel_stream_schedule.json");
+        /// Response for [Update Channel Stream Schedule Segment](https://dev.twitch.tv/docs/api/reference#update-channel-stream-schedule-segment)
+        pub const UPDATE_CHANNEL_STREAM_SCHEDULE_SEGMENT: &str =
+            include_str!("../fixtures/helix/schedule/update_channel_stream_schedule_segment.json");
+    }
+
+    /// Payloads for `helix::search`
+    pub mod search {
+        /// Response for [Search Categories](https://dev.twitch.tv/docs/api/reference#search-categories)
+        pub const SEARCH_CATEGORIES: &str =
+            include_str!("../fixtures/helix/search/search_categories.json");
+        /// Response for [Search Channels](https://dev.twitch.tv/docs/api/reference#search-channels)
+        pub const SEARCH_CHANNELS: &str =
+            include_str!("../fixtures/helix/search/search_channels.json");
+    }
+
+    /// Payloads for `helix::streams`
+    pub mod streams {
+        /// Response for [Get Followed Streams](https://dev.twitch.tv/docs/api/reference#get-followed-streams)
+        pub const GET_FOLLOWED_STREAMS: &str =
+            include_str!("../fixtures/helix/streams/get_followed_streams.json");
+        /// Response for [Get Stream Markers](https://dev.twitch.tv/docs/api/reference#get-stream-markers)
+        pub const GET_STREAM_MARKERS: &str =
+            include_str!("../fixtures/helix/streams/get_stream_markers.json");
+        /// Response for [Get Stream Tags](https://dev.twitch.tv/docs/api/reference#get-stream-tags)
+        pub const GET_STREAM_TAGS: &str =
+            include_str!("../fixtures/helix/streams/get_stream_tags.json");
+        /// Response for [Get Streams](https://dev.twitch.tv/docs/api/reference#get-streams)
+        pub const GET_STREAMS: &str = include_str!("../fixtures/helix/streams/get_streams.json");
+    }
+
+    /// Payloads for `helix::subscriptions`
+    pub mod subscriptions {
+        /// Response for [Check User Subscription](https://dev.twitch.tv/docs/api/reference#check-user-subscription)
+        pub const CHECK_USER_SUBSCRIPTION: &str =
+            include_str!("../fixtures/helix/subscriptions/check_user_subscription.json");
+        /// Response for [Get Broadcaster Subscriptions](https://dev.twitch.tv/docs/api/reference#get-broadcaster-subscriptions)
+        pub const GET_BROADCASTER_SUBSCRIPTIONS: &str =
+            include_str!("../fixtures/helix/subscriptions/get_broadcaster_subscriptions.json");
+        /// Response for Get Broadcaster Subscriptions Events
+        pub const GET_BROADCASTER_SUBSCRIPTIONS_EVENTS: &str = include_str!(
+            "../fixtures/helix/subscriptions/get_broadcaster_subscriptions_events.json"
+        );
+    }
+
+    /// Payloads for `helix::tags`
+    pub mod tags {
+        /// Response for [Get All Stream Tags](https://dev.twitch.tv/docs/api/reference#get-all-stream-tags)
+        pub const GET_ALL_STREAM_TAGS: &str =
+            include_str!("../fixtures/helix/tags/get_all_stream_tags.json");
+    }
+
+    /// Payloads for `helix::teams`
+    pub mod teams {
+        /// Response for [Get Channel Teams](https://dev.twitch.tv/docs/api/reference#get-teams)
+        pub const GET_CHANNEL_TEAMS: &str =
+            include_str!("../fixtures/helix/teams/get_channel_teams.json");
+        /// Response for [Get Teams](https://dev.twitch.tv/docs/api/reference#get-teams)
+        pub const GET_TEAMS: &str = include_str!("../fixtures/helix/teams/get_teams.json");
+    }
+
+    /// Payloads for `helix::users`
+    pub mod users {
+        /// Response for [Get User Block List](https://dev.twitch.tv/docs/api/reference#get-user-block-list)
+        pub const GET_USER_BLOCK_LIST: &str =
+            include_str!("../fixtures/helix/users/get_user_block_list.json");
+        /// Response for [Get User Extensions](https://dev.twitch.tv/docs/api/reference#get-user-extensions)
+        pub const GET_USER_EXTENSIONS: &str =
+            include_str!("../fixtures/helix/users/get_user_extensions.json");
+        /// Response for [Get Users](https://dev.twitch.tv/docs/api/reference#get-users)
+        pub const GET_USERS: &str = include_str!("../fixtures/helix/users/get_users.json");
+        /// Response for [Get Users Follows](https://dev.twitch.tv/docs/api/reference#get-users-follows)
+        pub const GET_USERS_FOLLOWS: &str =
+            include_str!("../fixtures/helix/users/get_users_follows.json");
+    }
+
+    /// Payloads for `helix::videos`
+    pub mod videos {
+        /// Response for [Get Videos](https://dev.twitch.tv/docs/api/reference#get-videos)
+        pub const GET_VIDEOS: &str = include_str!("../fixtures/helix/videos/get_videos.json");
+    }
+}
+
+/// EventSub payloads
+pub mod eventsub {
+    /// Payloads generated by [`twitch-cli`](https://github.com/twitchdev/twitch-cli), see `Event::parse_http_compat`
+    pub mod twitch_cli {
+        /// Notification from `twitch event trigger follow`
+        pub const CHANNEL_FOLLOW: &str =
+            include_str!("../fixtures/eventsub/twitch_cli/channel_follow.json");
+        /// Verification request from `twitch event verify-subscription follow`
+        pub const CHANNEL_FOLLOW_VERIFICATION: &str =
+            include_str!("../fixtures/eventsub/twitch_cli/channel_follow_verification.json");
+    }
+}
+
+/// All fixtures, as `(path, payload)` where `path` is relative to `fixtures/`
+pub const ALL: &[(&str, &str)] = &[
+    (
+        "helix/channels/get_channel_editors.json",
+        helix::channels::GET_CHANNEL_EDITORS,
+    ),
+    (
+        "helix/channels/get_channel_followers.json",
+        helix::channels::GET_CHANNEL_FOLLOWERS,
+    ),
+    (
+        "helix/channels/get_channel_information.json",
+        helix::channels::GET_CHANNEL_INFORMATION,
+    ),
+    ("helix/channels/get_vips.json", helix::channels::GET_VIPS),
+    (
+        "helix/channels/start_commercial.json",
+        helix::channels::START_COMMERCIAL,
+    ),
+    (
+        "helix/charity/get_charity_campaign_donations.json",
+        helix::charity::GET_CHARITY_CAMPAIGN_DONATIONS,
+    ),
+    (
+        "helix/chat/get_channel_chat_badges.json",
+        helix::chat::GET_CHANNEL_CHAT_BADGES,
+    ),
+    (
+        "helix/chat/get_channel_emotes.json",
+        helix::chat::GET_CHANNEL_EMOTES,
+    ),
+    (
+        "helix/chat/get_chat_settings.json",
+        helix::chat::GET_CHAT_SETTINGS,
+    ),
+    ("helix/chat/get_chatters.json", helix::chat::GET_CHATTERS),
+    (
+        "helix/chat/get_emote_sets.json",
+        helix::chat::GET_EMOTE_SETS,
+    ),
+    (
+        "helix/chat/get_global_chat_badges.json",
+        helix::chat::GET_GLOBAL_CHAT_BADGES,
+    ),
+    (
+        "helix/chat/get_global_emotes.json",
+        helix::chat::GET_GLOBAL_EMOTES,
+    ),
+    ("helix/clips/get_clips.json", helix::clips::GET_CLIPS),
+    (
+        "helix/eventsub/create_eventsub_subscription.json",
+        helix::eventsub::CREATE_EVENTSUB_SUBSCRIPTION,
+    ),
+    (
+        "helix/eventsub/get_eventsub_subscriptions.json",
+        helix::eventsub::GET_EVENTSUB_SUBSCRIPTIONS,
+    ),
+    (
+        "helix/extensions/create_extension_secret.json",
+        helix::extensions::CREATE_EXTENSION_SECRET,
+    ),
+    (
+        "helix/extensions/get_extension_bits_products.json",
+        helix::extensions::GET_EXTENSION_BITS_PRODUCTS,
+    ),
+    (
+        "helix/extensions/get_extension_configuration_segment.json",
+        helix::extensions::GET_EXTENSION_CONFIGURATION_SEGMENT,
+    ),
+    (
+        "helix/extensions/get_extension_secrets.json",
+        helix::extensions::GET_EXTENSION_SECRETS,
+    ),
+    (
+        "helix/extensions/get_extension_transactions.json",
+        helix::extensions::GET_EXTENSION_TRANSACTIONS,
+    ),
+    (
+        "helix/extensions/update_extension_bits_product.json",
+        helix::extensions::UPDATE_EXTENSION_BITS_PRODUCT,
+    ),
+    ("helix/games/get_games.json", helix::games::GET_GAMES),
+    (
+        "helix/games/get_top_games.json",
+        helix::games::GET_TOP_GAMES,
+    ),
+    (
+        "helix/goals/get_creator_goals.json",
+        helix::goals::GET_CREATOR_GOALS,
+    ),
+    (
+        "helix/hypetrain/get_hypetrain_events.json",
+        helix::hypetrain::GET_HYPETRAIN_EVENTS,
+    ),
+    (
+        "helix/moderation/add_blocked_term.json",
+        helix::moderation::ADD_BLOCKED_TERM,
+    ),
+    (
+        "helix/moderation/check_automod_status.json",
+        helix::moderation::CHECK_AUTOMOD_STATUS,
+    ),
+    (
+        "helix/moderation/get_banned_events.json",
+        helix::moderation::GET_BANNED_EVENTS,
+    ),
+    (
+        "helix/moderation/get_banned_users.json",
+        helix::moderation::GET_BANNED_USERS,
+    ),
+    (
+        "helix/moderation/get_moderated_channels.json",
+        helix::moderation::GET_MODERATED_CHANNELS,
+    ),
+    (
+        "helix/moderation/get_moderator_events.json",
+        helix::moderation::GET_MODERATOR_EVENTS,
+    ),
+    (
+        "helix/moderation/get_moderators.json",
+        helix::moderation::GET_MODERATORS,
+    ),
+    (
+        "helix/moderation/update_automod_settings.json",
+        helix::moderation::UPDATE_AUTOMOD_SETTINGS,
+    ),
+    (
+        "helix/points/create_custom_rewards.json",
+        helix::points::CREATE_CUSTOM_REWARDS,
+    ),
+    (
+        "helix/points/get_custom_reward.json",
+        helix::points::GET_CUSTOM_REWARD,
+    ),
+    (
+        "helix/points/get_custom_reward_redemption.json",
+        helix::points::GET_CUSTOM_REWARD_REDEMPTION,
+    ),
+    (
+        "helix/points/update_custom_reward.json",
+        helix::points::UPDATE_CUSTOM_REWARD,
+    ),
+    (
+        "helix/points/update_redemption_status.json",
+        helix::points::UPDATE_REDEMPTION_STATUS,
+    ),
+    ("helix/polls/create_poll.json", helix::polls::CREATE_POLL),
+    ("helix/polls/end_poll.json", helix::polls::END_POLL),
+    ("helix/polls/get_polls.json", helix::polls::GET_POLLS),
+    (
+        "helix/predictions/create_prediction.json",
+        helix::predictions::CREATE_PREDICTION,
+    ),
+    (
+        "helix/predictions/end_prediction.json",
+        helix::predictions::END_PREDICTION,
+    ),
+    (
+        "helix/predictions/get_predictions.json",
+        helix::predictions::GET_PREDICTIONS,
+    ),
+    (
+        "helix/schedule/create_channel_stream_schedule_segment.json",
+        helix::schedule::CREATE_CHANNEL_STREAM_SCHEDULE_SEGMENT,
+    ),
+    (
+        "helix/schedule/get_channel_stream_schedule.json",
+        helix::schedule::GET_CHANNEL_STREAM_SCHEDULE,
+    ),
+    (
+        "helix/schedule/update_channel_stream_schedule_segment.json",
+        helix::schedule::UPDATE_CHANNEL_STREAM_SCHEDULE_SEGMENT,
+    ),
+    (
+        "helix/search/search_categories.json",
+        helix::search::SEARCH_CATEGORIES,
+    ),
+    (
+        "helix/search/search_channels.json",
+        helix::search::SEARCH_CHANNELS,
+    ),
+    (
+        "helix/streams/get_followed_streams.json",
+        helix::streams::GET_FOLLOWED_STREAMS,
+    ),
+    (
+        "helix/streams/get_stream_markers.json",
+        helix::streams::GET_STREAM_MARKERS,
+    ),
+    (
+        "helix/streams/get_stream_tags.json",
+        helix::streams::GET_STREAM_TAGS,
+    ),
+    (
+        "helix/streams/get_streams.json",
+        helix::streams::GET_STREAMS,
+    ),
+    (
+        "helix/subscriptions/check_user_subscription.json",
+        helix::subscriptions::CHECK_USER_SUBSCRIPTION,
+    ),
+    (
+        "helix/subscriptions/get_broadcaster_subscriptions.json",
+        helix::subscriptions::GET_BROADCASTER_SUBSCRIPTIONS,
+    ),
+    (
+        "helix/subscriptions/get_broadcaster_subscriptions_events.json",
+        helix::subscriptions::GET_BROADCASTER_SUBSCRIPTIONS_EVENTS,
+    ),
+    (
+        "helix/tags/get_all_stream_tags.json",
+        helix::tags::GET_ALL_STREAM_TAGS,
+    ),
+    (
+        "helix/teams/get_channel_teams.json",
+        helix::teams::GET_CHANNEL_TEAMS,
+    ),
+    ("helix/teams/get_teams.json", helix::teams::GET_TEAMS),
+    (
+        "helix/users/get_user_block_list.json",
+        helix::users::GET_USER_BLOCK_LIST,
+    ),
+    (
+        "helix/users/get_user_extensions.json",
+        helix::users::GET_USER_EXTENSIONS,
+    ),
+    ("helix/users/get_users.json", helix::users::GET_USERS),
+    (
+        "helix/users/get_users_follows.json",
+        helix::users::GET_USERS_FOLLOWS,
+    ),
+    ("helix/videos/get_videos.json", helix::videos::GET_VIDEOS),
+    (
+        "eventsub/twitch_cli/channel_follow.json",
+        eventsub::twitch_cli::CHANNEL_FOLLOW,
+    ),
+    (
+        "eventsub/twitch_cli/channel_follow_verification.json",
+        eventsub::twitch_cli::CHANNEL_FOLLOW_VERIFICATION,
+    ),
+];
+
+#[cfg(all(test, feature = "serde_json"))]
+#[test]
+fn fixtures_are_json() {
+    for (path, payload) in ALL {
+        serde_json::from_str::<serde_json::Value>(payload)
+            .unwrap_or_else(|e| panic!("fixture {} is not valid json: {}", path, e));
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn fixtures_are_listed() {
+    fn visit(dir: &std::path::Path, found: &mut Vec<String>) {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                visit(&path, found);
+            } else {
+                found.push(path.to_string_lossy().replace('\\', "/"));
+            }
+        }
+    }
+    let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+    let mut found = vec![];
+    visit(&root, &mut found);
+    for file in found {
+        assert!(
+            ALL.iter().any(|(path, _)| file.ends_with(path)),
+            "fixture {} is not listed in `fixtures::ALL`",
+            file
+        );
+    }
+}
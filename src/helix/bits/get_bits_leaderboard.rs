@@ -12,7 +12,7 @@
 //! ```rust, no_run
 //! use twitch_api2::helix::bits::get_bits_leaderboard;
 //! let request = get_bits_leaderboard::GetBitsLeaderboardRequest::builder()
-//!     .period("day".to_string())
+//!     .period(get_bits_leaderboard::LeaderboardPeriod::Day)
 //!     .build();
 //! // Get leaderbord for the lifetime of the channel
 //! let request = get_bits_leaderboard::GetBitsLeaderboardRequest::builder().build();
@@ -47,21 +47,15 @@ use helix::RequestGet;
 ///
 /// [`get-bits-leaderboard`](https://dev.twitch.tv/docs/api/reference#get-bits-leaderboard)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetBitsLeaderboardRequest {
     /// Number of results to be returned. Maximum: 100. Default: 10.
     #[builder(default, setter(into))]
     pub count: Option<i32>,
-    // TODO: Enum
-    /// Time period over which data is aggregated (PST time zone). This parameter interacts with started_at. Valid values follow. Default: "all".
-    ///
-    /// * "day" – 00:00:00 on the day specified in started_at, through 00:00:00 on the following day.
-    /// * "week" – 00:00:00 on Monday of the week specified in started_at, through 00:00:00 on the following Monday.
-    /// * "month" – 00:00:00 on the first day of the month specified in started_at, through 00:00:00 on the first day of the following month.
-    /// * "year" – 00:00:00 on the first day of the year specified in started_at, through 00:00:00 on the first day of the following year.
-    /// * "all" – The lifetime of the broadcaster's channel. If this is specified (or used by default), started_at is ignored.
+    /// Time period over which data is aggregated (PST time zone). This parameter interacts with started_at. Default: [`All`](LeaderboardPeriod::All).
     #[builder(default, setter(into))]
-    pub period: Option<String>,
+    pub period: Option<LeaderboardPeriod>,
     /// Timestamp for the period over which the returned data is aggregated. Must be in RFC 3339 format. If this is not provided, data is aggregated over the current period; e.g., the current day/week/month/year. This value is ignored if period is "all".
     #[builder(default, setter(into))]
     pub started_at: Option<types::Timestamp>,
@@ -70,14 +64,38 @@ pub struct GetBitsLeaderboardRequest {
     pub user_id: Option<types::UserId>,
 }
 
+/// Time period over which the leaderboard is aggregated, see [`GetBitsLeaderboardRequest::period`]
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Copy, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum LeaderboardPeriod {
+    /// 00:00:00 on the day specified in started_at, through 00:00:00 on the following day.
+    Day,
+    /// 00:00:00 on Monday of the week specified in started_at, through 00:00:00 on the following Monday.
+    Week,
+    /// 00:00:00 on the first day of the month specified in started_at, through 00:00:00 on the first day of the following month.
+    Month,
+    /// 00:00:00 on the first day of the year specified in started_at, through 00:00:00 on the first day of the following year.
+    Year,
+    /// The lifetime of the broadcaster's channel. If this is specified (or used by default), started_at is ignored.
+    All,
+}
+
 /// Return Values for [Get Bits Leaderboard](super::get_bits_leaderboard)
 ///
 /// [`get-bits-leaderboard`](https://dev.twitch.tv/docs/api/reference#get-bits-leaderboard)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct BitsLeaderboard {
     /// Leaderboard
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub leaderboard: Vec<LeaderboardUser>,
     /// Period over which the returned data is aggregated.
     pub date_range: DateRange,
@@ -87,6 +105,11 @@ pub struct BitsLeaderboard {
 
 /// Period over which the returned data is aggregated.
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct DateRange {
@@ -98,6 +121,11 @@ pub struct DateRange {
 
 /// Information about user in leaderboard
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct LeaderboardUser {
@@ -116,6 +144,7 @@ pub struct LeaderboardUser {
 impl Request for GetBitsLeaderboardRequest {
     type Response = BitsLeaderboard;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "bits/leaderboard";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -127,7 +156,7 @@ impl RequestGet for GetBitsLeaderboardRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestGetError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
@@ -138,12 +167,14 @@ impl RequestGet for GetBitsLeaderboardRequest {
             /// Total number of results (users) returned. This is count or the total number of entries in the leaderboard, whichever is less.
             total: i64,
         }
-        let response: InnerResponse = helix::parse_json(response, true).map_err(|e| {
-            helix::HelixRequestGetError::DeserializeError(
+        let response: InnerResponse = helix::parse_json_owned(response, true).map_err(|e| {
+            helix::HelixRequestError::DeserializeError(
                 response.to_string(),
                 e,
+                Self::METHOD,
                 uri.clone(),
                 status,
+                None,
             )
         })?;
         Ok(helix::Response {
@@ -156,6 +187,8 @@ impl RequestGet for GetBitsLeaderboardRequest {
             request,
             total: Some(response.total),
             other: None,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
@@ -204,3 +237,19 @@ fn test_request() {
 
     dbg!(GetBitsLeaderboardRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+#[test]
+fn test_request_period() {
+    use helix::*;
+    let req = GetBitsLeaderboardRequest::builder()
+        .period(LeaderboardPeriod::Week)
+        .started_at(types::Timestamp::new("2018-02-05T08:00:00Z").unwrap())
+        .build();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/bits/leaderboard?period=week&started_at=2018-02-05T08%3A00%3A00Z"
+    );
+}
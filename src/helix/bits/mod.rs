@@ -29,6 +29,128 @@ pub mod get_bits_leaderboard;
 pub mod get_cheermotes;
 
 #[doc(inline)]
-pub use get_bits_leaderboard::{BitsLeaderboard, GetBitsLeaderboardRequest};
+pub use get_bits_leaderboard::{BitsLeaderboard, GetBitsLeaderboardRequest, LeaderboardPeriod};
 #[doc(inline)]
 pub use get_cheermotes::{Cheermote, GetCheermotesRequest};
+
+/// The bits leaderboard of a channel together with the cheermotes usable in it.
+///
+/// Returned by [`HelixClient::get_bits_summary`](helix::HelixClient::get_bits_summary), or made from the responses of [Get Bits Leaderboard](get_bits_leaderboard) and [Get Cheermotes](get_cheermotes) with [`BitsSummary::new`]
+#[derive(PartialEq, Debug, Clone)]
+#[non_exhaustive]
+pub struct BitsSummary {
+    /// Leaderboard entries, in order of rank.
+    pub entries: Vec<BitsSummaryEntry>,
+    /// Period over which the leaderboard is aggregated.
+    pub date_range: get_bits_leaderboard::DateRange,
+    /// Cheermotes usable in the channel.
+    pub cheermotes: Vec<Cheermote>,
+}
+
+/// A user in a [`BitsSummary`]
+#[derive(PartialEq, Debug, Clone)]
+#[non_exhaustive]
+pub struct BitsSummaryEntry {
+    /// The user in the leaderboard.
+    pub user: get_bits_leaderboard::LeaderboardUser,
+    /// The entry ready to be displayed, e.g. `#1 TwitchDev: 12,345 bits`
+    pub display: String,
+    /// Highest tier of the [channel cheermote](BitsSummary::cheermote) reached by the score of the user.
+    pub tier: Option<get_cheermotes::Tiers>,
+}
+
+impl BitsSummary {
+    /// Combine a leaderboard with the cheermotes of the channel
+    pub fn new(leaderboard: BitsLeaderboard, cheermotes: Vec<Cheermote>) -> Self {
+        let cheermote = Self::find_cheermote(&cheermotes);
+        let entries = leaderboard
+            .leaderboard
+            .into_iter()
+            .map(|user| BitsSummaryEntry {
+                display: format!(
+                    "#{} {}: {} bits",
+                    user.rank,
+                    user.user_name,
+                    group_thousands(user.score)
+                ),
+                tier: cheermote.and_then(|cheermote| {
+                    cheermote
+                        .tiers
+                        .iter()
+                        .filter(|tier| tier.min_bits <= user.score)
+                        .max_by_key(|tier| tier.min_bits)
+                        .cloned()
+                }),
+                user,
+            })
+            .collect();
+        BitsSummary {
+            entries,
+            date_range: leaderboard.date_range,
+            cheermotes,
+        }
+    }
+
+    /// The cheermote used for [tiers](BitsSummaryEntry::tier), the custom cheermote of the channel if it has one, otherwise the global `Cheer`.
+    pub fn cheermote(&self) -> Option<&Cheermote> { Self::find_cheermote(&self.cheermotes) }
+
+    fn find_cheermote(cheermotes: &[Cheermote]) -> Option<&Cheermote> {
+        cheermotes
+            .iter()
+            .find(|c| c.type_ == get_cheermotes::CheermoteType::ChannelCustom)
+            .or_else(|| cheermotes.iter().find(|c| c.prefix == "Cheer"))
+    }
+}
+
+/// Format a number with `,` between groups of thousands
+fn group_thousands(n: i64) -> String {
+    let digits = n.unsigned_abs().to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+    if n < 0 {
+        grouped.push('-');
+    }
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+#[cfg(test)]
+#[test]
+fn test_bits_summary() {
+    let leaderboard: BitsLeaderboard = serde_json::from_str(
+        r#"{
+            "leaderboard": [
+                {"user_id": "158010205", "user_login": "tundracowboy", "user_name": "TundraCowboy", "rank": 1, "score": 12543},
+                {"user_id": "7168163", "user_login": "topramens", "user_name": "Topramens", "rank": 2, "score": 50}
+            ],
+            "date_range": {"started_at": "2018-02-05T08:00:00Z", "ended_at": "2018-02-12T08:00:00Z"},
+            "total": 2
+        }"#,
+    )
+    .unwrap();
+    let tier = |min_bits: i64| {
+        let sizes = serde_json::json!({"1": "", "1.5": "", "2": "", "3": "", "4": ""});
+        let image = serde_json::json!({"animated": sizes, "static": sizes});
+        serde_json::json!({
+            "min_bits": min_bits, "id": min_bits.to_string(), "color": "#979797", "can_cheer": true, "show_in_bits_card": true,
+            "images": {"dark": image, "light": image}
+        })
+    };
+    let cheermotes: Vec<Cheermote> = serde_json::from_value(serde_json::json!([
+        {"prefix": "Cheer", "tiers": [tier(1), tier(100)], "type": "global_first_party", "order": 1, "last_updated": "2018-05-22T00:06:04Z", "is_charitable": false},
+        {"prefix": "Tundra", "tiers": [tier(1), tier(100), tier(10000)], "type": "channel_custom", "order": 1, "last_updated": "2018-05-22T00:06:04Z", "is_charitable": false}
+    ]))
+    .unwrap();
+
+    let summary = BitsSummary::new(leaderboard, cheermotes);
+    assert_eq!(summary.cheermote().unwrap().prefix, "Tundra");
+    assert_eq!(summary.entries[0].display, "#1 TundraCowboy: 12,543 bits");
+    assert_eq!(summary.entries[0].tier.as_ref().unwrap().min_bits, 10000);
+    assert_eq!(summary.entries[1].display, "#2 Topramens: 50 bits");
+    assert_eq!(summary.entries[1].tier.as_ref().unwrap().min_bits, 1);
+    assert_eq!(group_thousands(-1234567), "-1,234,567");
+}
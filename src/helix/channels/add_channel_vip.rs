@@ -0,0 +1,217 @@
+//! Adds the specified user as a VIP in the broadcaster’s channel.
+//! [`add-channel-vip`](https://dev.twitch.tv/docs/api/reference#add-channel-vip)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [AddChannelVipRequest]
+//!
+//! To use this endpoint, construct a [`AddChannelVipRequest`] with the [`AddChannelVipRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::channels::add_channel_vip;
+//! let request = add_channel_vip::AddChannelVipRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .user_id("5678")
+//!     .build();
+//! ```
+//!
+//! ## Response: [AddChannelVip]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! Errors specific to this endpoint, like the user already being a VIP, can be found with [`AddChannelVipError::from_helix_error`].
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, channels::add_channel_vip};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = add_channel_vip::AddChannelVipRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .user_id("5678")
+//!     .build();
+//! match client.req_post(request, helix::EmptyBody, &token).await {
+//!     Ok(response) => println!("{:?}", response.data),
+//!     Err(helix::ClientRequestError::HelixRequestError(e)) => {
+//!         match add_channel_vip::AddChannelVipError::from_helix_error(&e) {
+//!             Some(add_channel_vip::AddChannelVipError::AlreadyVip) => println!("already a VIP"),
+//!             _ => return Err(e.into()),
+//!         }
+//!     }
+//!     Err(e) => return Err(e.into()),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`AddChannelVipRequest::parse_response(None, &request.get_uri(), response)`](AddChannelVipRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Add Channel VIP](super::add_channel_vip)
+///
+/// [`add-channel-vip`](https://dev.twitch.tv/docs/api/reference#add-channel-vip)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct AddChannelVipRequest {
+    /// The ID of the broadcaster that’s granting VIP status to the user. Must match the User ID in the Bearer token.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// The ID of the user to add as a VIP in the broadcaster’s chat room.
+    #[builder(setter(into))]
+    pub user_id: types::UserId,
+}
+
+/// Return Values for [Add Channel VIP](super::add_channel_vip)
+///
+/// [`add-channel-vip`](https://dev.twitch.tv/docs/api/reference#add-channel-vip)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[non_exhaustive]
+pub enum AddChannelVip {
+    /// 204 - Successfully added the VIP.
+    Success,
+}
+
+/// Errors returned by [Add Channel VIP](super::add_channel_vip) that are specific to the endpoint
+#[derive(PartialEq, Eq, Debug, Clone, Copy, thiserror::Error, displaydoc::Display)]
+#[non_exhaustive]
+pub enum AddChannelVipError {
+    /// the user is already a VIP
+    AlreadyVip,
+    /// the user is a moderator, remove them as a moderator before making them a VIP
+    UserIsModerator,
+    /// the broadcaster doesn't have available VIP slots
+    NoVipSlots,
+    /// the broadcaster must complete the Build a Community requirement before assigning VIPs
+    BuildACommunityRequired,
+}
+
+impl AddChannelVipError {
+    /// Get the error specific to this endpoint from the error Twitch returned, if any.
+    pub fn from_helix_error(error: &helix::HelixRequestError) -> Option<Self> {
+        match error {
+            helix::HelixRequestError::Error {
+                status, message, ..
+            } => match status.as_u16() {
+                409 => Some(AddChannelVipError::NoVipSlots),
+                425 => Some(AddChannelVipError::BuildACommunityRequired),
+                400 | 422 if message.contains("moderator") => {
+                    Some(AddChannelVipError::UserIsModerator)
+                }
+                400 | 422 if message.contains("already") => Some(AddChannelVipError::AlreadyVip),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl Request for AddChannelVipRequest {
+    type Response = AddChannelVip;
+
+    const METHOD: http::Method = http::Method::POST;
+    const PATH: &'static str = "channels/vips";
+    /// Scope needed for this endpoint, not yet known to [`twitch_oauth2`]
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:manage:vips"),
+    )];
+}
+
+impl RequestPost for AddChannelVipRequest {
+    type Body = helix::EmptyBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
+    where
+        Self: Sized,
+    {
+        match status {
+            http::StatusCode::NO_CONTENT => Ok(helix::Response {
+                data: AddChannelVip::Success,
+                pagination: None,
+                request,
+                total: None,
+                other: None,
+                request_id: None,
+                diagnostics: Default::default(),
+            }),
+            _ => Err(helix::HelixRequestError::InvalidResponse {
+                reason: "unexpected status",
+                response: response.to_string(),
+                status,
+                method: Self::METHOD,
+                uri: uri.clone(),
+                request_id: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = AddChannelVipRequest::builder()
+        .broadcaster_id("123")
+        .user_id("456")
+        .build();
+
+    dbg!(req.create_request(EmptyBody, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/channels/vips?broadcaster_id=123&user_id=456"
+    );
+
+    dbg!(AddChannelVipRequest::parse_response(Some(req), &uri, http_response).unwrap());
+}
+
+#[cfg(test)]
+#[test]
+fn test_request_error() {
+    use helix::*;
+    let req = AddChannelVipRequest::builder()
+        .broadcaster_id("123")
+        .user_id("456")
+        .build();
+
+    let data = br#"
+{
+    "error": "Unprocessable Entity",
+    "status": 422,
+    "message": "The user in the user_id query parameter is a moderator. To make them a VIP, you must first remove them as a moderator."
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().status(422).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    let error = AddChannelVipRequest::parse_response(Some(req), &uri, http_response).unwrap_err();
+    assert_eq!(
+        AddChannelVipError::from_helix_error(&error),
+        Some(AddChannelVipError::UserIsModerator)
+    );
+}
@@ -45,6 +45,7 @@ use helix::RequestGet;
 ///
 /// [`get-channel-editors`](https://dev.twitch.tv/docs/api/reference#get-channel-editors)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetChannelEditorsRequest {
     /// Broadcaster’s user ID associated with the channel.
@@ -56,6 +57,11 @@ pub struct GetChannelEditorsRequest {
 ///
 /// [`get-channel-editors`](https://dev.twitch.tv/docs/api/reference#get-channel-editors)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Editor {
@@ -70,6 +76,7 @@ pub struct Editor {
 impl Request for GetChannelEditorsRequest {
     type Response = Vec<Editor>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "channels/editors";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelReadEditors];
@@ -82,27 +89,13 @@ impl RequestGet for GetChannelEditorsRequest {}
 fn test_request() {
     use helix::*;
     let req = GetChannelEditorsRequest::builder()
-        .broadcaster_id("44445592".to_string())
+        .broadcaster_id("44445592")
         .build();
 
     // From twitch docs
-    let data = br#"
-    {
-        "data": [
-          {
-            "user_id": "182891647",
-            "user_name": "mauerbac",
-            "created_at": "2019-02-15T21:19:50.380833Z"
-          },
-          {
-            "user_id": "135093069",
-            "user_name": "BlueLava",
-            "created_at": "2018-03-07T16:28:29.872937Z"
-          }
-        ]
-      }
-        "#
-    .to_vec();
+    let data = crate::fixtures::helix::channels::GET_CHANNEL_EDITORS
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
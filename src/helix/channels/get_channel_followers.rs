@@ -0,0 +1,167 @@
+//! Gets a list of users that follow the specified broadcaster.
+//! [`get-channel-followers`](https://dev.twitch.tv/docs/api/reference#get-channel-followers)
+//!
+//! Replaces [Get Users Follows](crate::helix::users::get_users_follows) for getting the followers of a channel.
+//!
+//! # Notes
+//!
+//! The followers are only returned if the token is for the broadcaster, or for one of their moderators with the `moderator:read:followers` scope.
+//! Otherwise, the response only contains the [total](helix::Response::total) number of followers,
+//! or whether the user of the token follows the channel, if [`user_id`](GetChannelFollowersRequest::user_id) is that user.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetChannelFollowersRequest]
+//!
+//! To use this endpoint, construct a [`GetChannelFollowersRequest`] with the [`GetChannelFollowersRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::channels::get_channel_followers;
+//! let request = get_channel_followers::GetChannelFollowersRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! ```
+//!
+//! ## Response: [Follower]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, channels::get_channel_followers};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = get_channel_followers::GetChannelFollowersRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! let response = client.req_get(request, &token).await?;
+//! let followers: Vec<get_channel_followers::Follower> = response.data;
+//! println!("{} followers", response.total.unwrap_or_default());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetChannelFollowersRequest::parse_response(None, &request.get_uri(), response)`](GetChannelFollowersRequest::parse_response)
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Channel Followers](super::get_channel_followers)
+///
+/// [`get-channel-followers`](https://dev.twitch.tv/docs/api/reference#get-channel-followers)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct GetChannelFollowersRequest {
+    /// The broadcaster’s ID. Returns the list of users that follow this broadcaster.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// A user’s ID. Use this parameter to see whether the user follows this broadcaster.
+    #[builder(default, setter(into))]
+    pub user_id: Option<types::UserId>,
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
+    ///
+    /// Backward pagination with [`before`](helix::PaginationParams::before) is not supported.
+    #[builder(default)]
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
+}
+
+/// Return Values for [Get Channel Followers](super::get_channel_followers)
+///
+/// [`get-channel-followers`](https://dev.twitch.tv/docs/api/reference#get-channel-followers)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Follower {
+    /// The UTC timestamp when the user started following the broadcaster.
+    pub followed_at: types::Timestamp,
+    /// An ID that uniquely identifies the user that’s following the broadcaster.
+    pub user_id: types::UserId,
+    /// The user’s login name.
+    pub user_login: types::UserName,
+    /// The user’s display name.
+    pub user_name: types::DisplayName,
+}
+
+impl Request for GetChannelFollowersRequest {
+    type Response = Vec<Follower>;
+
+    const METHOD: http::Method = http::Method::GET;
+    /// Scope needed to get the followers and not only their total, not yet known to [`twitch_oauth2`]
+    #[cfg(feature = "twitch_oauth2")]
+    const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:read:followers"),
+    )];
+    const PATH: &'static str = "channels/followers";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
+}
+
+impl RequestGet for GetChannelFollowersRequest {}
+
+impl helix::Paginated for GetChannelFollowersRequest {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetChannelFollowersRequest::builder()
+        .broadcaster_id("123456")
+        .build();
+
+    // From twitch docs
+    let data = crate::fixtures::helix::channels::GET_CHANNEL_FOLLOWERS
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/channels/followers?broadcaster_id=123456"
+    );
+
+    let resp = GetChannelFollowersRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(resp.total, Some(8));
+    assert_eq!(resp.data[0].user_login.as_str(), "userloginname");
+}
+
+#[cfg(test)]
+#[test]
+fn test_request_without_scope() {
+    use helix::*;
+    let req = GetChannelFollowersRequest::builder()
+        .broadcaster_id("123456")
+        .build();
+
+    let data = br#"
+{
+  "total": 8,
+  "data": [],
+  "pagination": {}
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    let resp = GetChannelFollowersRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(resp.total, Some(8));
+    assert!(resp.data.is_empty());
+    assert!(resp.pagination.is_none());
+}
@@ -45,6 +45,7 @@ use helix::RequestGet;
 ///
 /// [`get-channel-information`](https://dev.twitch.tv/docs/api/reference#get-channel-information)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetChannelInformationRequest {
     /// ID of the channel
@@ -56,6 +57,11 @@ pub struct GetChannelInformationRequest {
 ///
 /// [`get-channel-information`](https://dev.twitch.tv/docs/api/reference#get-channel-information)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelInformation {
@@ -70,7 +76,7 @@ pub struct ChannelInformation {
     /// Name of the game being played on the channel
     pub game_name: types::CategoryId,
     /// Language of the channel
-    pub broadcaster_language: String,
+    pub broadcaster_language: types::RawLanguage,
     /// Title of the stream
     pub title: String,
     /// Description of the stream
@@ -78,11 +84,15 @@ pub struct ChannelInformation {
     pub description: String,
     /// Stream delay in seconds
     pub delay: i64,
+    /// Tags applied to the channel
+    #[serde(default, deserialize_with = "helix::deserialize_default_from_null")]
+    pub tags: Vec<String>,
 }
 
 impl Request for GetChannelInformationRequest {
     type Response = Option<ChannelInformation>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "channels";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -94,17 +104,19 @@ impl RequestGet for GetChannelInformationRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestGetError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
         let response: helix::InnerResponse<Vec<ChannelInformation>> =
-            helix::parse_json(response, true).map_err(|e| {
-                helix::HelixRequestGetError::DeserializeError(
+            helix::parse_json_owned(response, true).map_err(|e| {
+                helix::HelixRequestError::DeserializeError(
                     response.to_string(),
                     e,
+                    Self::METHOD,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         Ok(helix::Response {
@@ -113,6 +125,8 @@ impl RequestGet for GetChannelInformationRequest {
             request,
             total: None,
             other: None,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
@@ -122,10 +136,35 @@ impl RequestGet for GetChannelInformationRequest {
 fn test_request() {
     use helix::*;
     let req = GetChannelInformationRequest::builder()
-        .broadcaster_id("44445592".to_string())
+        .broadcaster_id("44445592")
         .build();
 
     // From twitch docs
+    let data = crate::fixtures::helix::channels::GET_CHANNEL_INFORMATION
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/channels?broadcaster_id=44445592"
+    );
+
+    let response =
+        dbg!(GetChannelInformationRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    assert_eq!(response.data.unwrap().tags, ["English", "Competitive"]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_request_null_tags() {
+    use helix::*;
+    let req = GetChannelInformationRequest::builder()
+        .broadcaster_id("44445592")
+        .build();
+
     let data = br#"
         {
           "data": [
@@ -137,7 +176,8 @@ fn test_request() {
               "game_id": "21779",
               "game_name": "League of Legends",
               "title": "title",
-              "delay": 0
+              "delay": 0,
+              "tags": null
             }
           ]
         }
@@ -147,10 +187,34 @@ fn test_request() {
     let http_response = http::Response::builder().body(data).unwrap();
 
     let uri = req.get_uri().unwrap();
-    assert_eq!(
-        uri.to_string(),
-        "https://api.twitch.tv/helix/channels?broadcaster_id=44445592"
-    );
+    let response =
+        dbg!(
+            GetChannelInformationRequest::parse_response(Some(req.clone()), &uri, http_response)
+                .unwrap()
+        );
+    assert!(response.data.unwrap().tags.is_empty());
+
+    // omitted while tags are rolled out
+    let data = br#"
+        {
+          "data": [
+            {
+              "broadcaster_id": "44445592",
+              "broadcaster_name": "pokimane",
+              "broadcaster_login": "pokimane",
+              "broadcaster_language": "en",
+              "game_id": "21779",
+              "game_name": "League of Legends",
+              "title": "title",
+              "delay": 0
+            }
+          ]
+        }
+        "#
+    .to_vec();
 
-    dbg!(GetChannelInformationRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    let http_response = http::Response::builder().body(data).unwrap();
+    let response =
+        dbg!(GetChannelInformationRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    assert!(response.data.unwrap().tags.is_empty());
 }
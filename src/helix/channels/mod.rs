@@ -27,18 +27,30 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
+pub mod add_channel_vip;
 pub mod get_channel_editors;
+pub mod get_channel_followers;
 pub mod get_channel_information;
+pub mod get_vips;
 pub mod modify_channel_information;
+pub mod remove_channel_vip;
 pub mod start_commercial;
 
+#[doc(inline)]
+pub use add_channel_vip::{AddChannelVip, AddChannelVipError, AddChannelVipRequest};
 #[doc(inline)]
 pub use get_channel_editors::{Editor, GetChannelEditorsRequest};
 #[doc(inline)]
+pub use get_channel_followers::{Follower, GetChannelFollowersRequest};
+#[doc(inline)]
 pub use get_channel_information::{ChannelInformation, GetChannelInformationRequest};
 #[doc(inline)]
+pub use get_vips::{GetVipsRequest, Vip};
+#[doc(inline)]
 pub use modify_channel_information::{
     ModifyChannelInformation, ModifyChannelInformationBody, ModifyChannelInformationRequest,
 };
 #[doc(inline)]
+pub use remove_channel_vip::{RemoveChannelVip, RemoveChannelVipError, RemoveChannelVipRequest};
+#[doc(inline)]
 pub use start_commercial::{StartCommercial, StartCommercialBody, StartCommercialRequest};
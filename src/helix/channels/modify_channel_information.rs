@@ -21,7 +21,7 @@
 //! ```
 //! # use twitch_api2::helix::channels::modify_channel_information;
 //! let body = modify_channel_information::ModifyChannelInformationBody::builder()
-//!     .title("Hello World!".to_string())
+//!     .title("Hello World!")
 //!     .build();
 //! ```
 //!
@@ -43,7 +43,7 @@
 //!     .broadcaster_id("1234")
 //!     .build();
 //! let body = modify_channel_information::ModifyChannelInformationBody::builder()
-//!     .title("Hello World!".to_string())
+//!     .title("Hello World!")
 //!     .build();
 //! let response: modify_channel_information::ModifyChannelInformation = client.req_patch(request, body, &token).await?.data;
 //! # Ok(())
@@ -59,6 +59,7 @@ use helix::RequestPatch;
 ///
 /// [`modify-channel-information`](https://dev.twitch.tv/docs/api/reference#modify-channel-information)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct ModifyChannelInformationRequest {
     /// ID of the channel
@@ -71,6 +72,7 @@ pub struct ModifyChannelInformationRequest {
 ///
 /// [`modify-channel-information`](https://dev.twitch.tv/docs/api/reference#modify-channel-information)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct ModifyChannelInformationBody {
     /// Current game ID being played on the channel. Use “0” or “” (an empty string) to unset the game.
@@ -78,10 +80,13 @@ pub struct ModifyChannelInformationBody {
     pub game_id: Option<types::CategoryId>,
     /// Language of the channel
     #[builder(default, setter(into))]
-    pub broadcaster_language: Option<String>,
+    pub broadcaster_language: Option<types::Language>,
     /// Title of the stream. Value must not be an empty string.
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub title: Option<String>,
+    /// Stream delay in seconds. Stream delay is a Twitch Partner feature; trying to set this value for other account types will return a 400 error.
+    #[builder(default, setter(into))]
+    pub delay: Option<i64>,
 }
 
 impl helix::private::SealedSerialize for ModifyChannelInformationBody {}
@@ -89,6 +94,11 @@ impl helix::private::SealedSerialize for ModifyChannelInformationBody {}
 ///
 /// [`modify-channel-information`](https://dev.twitch.tv/docs/api/reference#modify-channel-information)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[non_exhaustive]
 pub enum ModifyChannelInformation {
     /// 204 - Channel/Stream updated successfully
@@ -98,6 +108,7 @@ pub enum ModifyChannelInformation {
 impl Request for ModifyChannelInformationRequest {
     type Response = ModifyChannelInformation;
 
+    const METHOD: http::Method = http::Method::PATCH;
     const PATH: &'static str = "channels";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserEditBroadcast];
@@ -111,7 +122,7 @@ impl RequestPatch for ModifyChannelInformationRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPatchError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
@@ -121,11 +132,13 @@ impl RequestPatch for ModifyChannelInformationRequest {
                     ModifyChannelInformation::Success
                 }
                 _ => {
-                    return Err(helix::HelixRequestPatchError::InvalidResponse {
+                    return Err(helix::HelixRequestError::InvalidResponse {
                         reason: "unexpected status code",
                         response: response.to_string(),
                         status,
+                        method: Self::METHOD,
                         uri: uri.clone(),
+                        request_id: None,
                     })
                 }
             },
@@ -133,6 +146,8 @@ impl RequestPatch for ModifyChannelInformationRequest {
             request,
             total: None,
             other: None,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
@@ -146,10 +161,18 @@ fn test_request() {
         .build();
 
     let body = ModifyChannelInformationBody::builder()
-        .title("Hello World!".to_string())
+        .title("Hello World!")
+        .broadcaster_language(types::Language::new("en").unwrap())
+        .delay(5)
         .build();
 
-    dbg!(req.create_request(body, "token", "clientid").unwrap());
+    let body_json: serde_json::Value =
+        serde_json::from_slice(&body.try_to_body().unwrap()).unwrap();
+    assert_eq!(body_json["broadcaster_language"], "en");
+    assert_eq!(body_json["delay"], 5);
+
+    let request = dbg!(req.create_request(body, "token", "clientid").unwrap());
+    assert_eq!(request.method(), ModifyChannelInformationRequest::METHOD);
 
     // From twitch docs
     let data = br#""#.to_vec();
@@ -0,0 +1,162 @@
+//! Removes the specified user as a VIP in the broadcaster’s channel.
+//! [`remove-channel-vip`](https://dev.twitch.tv/docs/api/reference#remove-channel-vip)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [RemoveChannelVipRequest]
+//!
+//! To use this endpoint, construct a [`RemoveChannelVipRequest`] with the [`RemoveChannelVipRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::channels::remove_channel_vip;
+//! let request = remove_channel_vip::RemoveChannelVipRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .user_id("5678")
+//!     .build();
+//! ```
+//!
+//! ## Response: [RemoveChannelVip]
+//!
+//! Send the request to receive the response with [`HelixClient::req_delete()`](helix::HelixClient::req_delete).
+//!
+//! Errors specific to this endpoint, like the user not being a VIP, can be found with [`RemoveChannelVipError::from_helix_error`].
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, channels::remove_channel_vip};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = remove_channel_vip::RemoveChannelVipRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .user_id("5678")
+//!     .build();
+//! let response: remove_channel_vip::RemoveChannelVip = client.req_delete(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestDelete::create_request)
+//! and parse the [`http::Response`] with [`RemoveChannelVipRequest::parse_response(None, &request.get_uri(), response)`](RemoveChannelVipRequest::parse_response)
+
+use super::*;
+use helix::RequestDelete;
+
+/// Query Parameters for [Remove Channel VIP](super::remove_channel_vip)
+///
+/// [`remove-channel-vip`](https://dev.twitch.tv/docs/api/reference#remove-channel-vip)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct RemoveChannelVipRequest {
+    /// The ID of the broadcaster who owns the channel where the user has VIP status. Must match the User ID in the Bearer token.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// The ID of the user to remove VIP status from.
+    #[builder(setter(into))]
+    pub user_id: types::UserId,
+}
+
+/// Return Values for [Remove Channel VIP](super::remove_channel_vip)
+///
+/// [`remove-channel-vip`](https://dev.twitch.tv/docs/api/reference#remove-channel-vip)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[non_exhaustive]
+pub enum RemoveChannelVip {
+    /// 204 - Successfully removed the VIP status from the user.
+    Success,
+}
+
+/// Errors returned by [Remove Channel VIP](super::remove_channel_vip) that are specific to the endpoint
+#[derive(PartialEq, Eq, Debug, Clone, Copy, thiserror::Error, displaydoc::Display)]
+#[non_exhaustive]
+pub enum RemoveChannelVipError {
+    /// the user is not a VIP
+    NotVip,
+}
+
+impl RemoveChannelVipError {
+    /// Get the error specific to this endpoint from the error Twitch returned, if any.
+    pub fn from_helix_error(error: &helix::HelixRequestError) -> Option<Self> {
+        match error {
+            helix::HelixRequestError::Error { status, .. } if status.as_u16() == 422 => {
+                Some(RemoveChannelVipError::NotVip)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Request for RemoveChannelVipRequest {
+    type Response = RemoveChannelVip;
+
+    const METHOD: http::Method = http::Method::DELETE;
+    const PATH: &'static str = "channels/vips";
+    /// Scope needed for this endpoint, not yet known to [`twitch_oauth2`]
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:manage:vips"),
+    )];
+}
+
+impl RequestDelete for RemoveChannelVipRequest {
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
+    where
+        Self: Sized,
+    {
+        match status {
+            http::StatusCode::NO_CONTENT => Ok(helix::Response {
+                data: RemoveChannelVip::Success,
+                pagination: None,
+                request,
+                total: None,
+                other: None,
+                request_id: None,
+                diagnostics: Default::default(),
+            }),
+            _ => Err(helix::HelixRequestError::InvalidResponse {
+                reason: "unexpected status",
+                response: response.to_string(),
+                status,
+                method: Self::METHOD,
+                uri: uri.clone(),
+                request_id: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = RemoveChannelVipRequest::builder()
+        .broadcaster_id("123")
+        .user_id("456")
+        .build();
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/channels/vips?broadcaster_id=123&user_id=456"
+    );
+
+    dbg!(RemoveChannelVipRequest::parse_response(Some(req), &uri, http_response).unwrap());
+}
@@ -19,7 +19,7 @@
 //! ```
 //! # use twitch_api2::helix::channels::start_commercial;
 //! let body = start_commercial::StartCommercialBody::builder()
-//!     .broadcaster_id("1234".to_string())
+//!     .broadcaster_id("1234")
 //!     .length(twitch_api2::types::CommercialLength::Length90)
 //!     .build();
 //! ```
@@ -38,7 +38,7 @@
 //! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
 //! let request = start_commercial::StartCommercialRequest::new();
 //! let body = start_commercial::StartCommercialBody::builder()
-//!     .broadcaster_id("1234".to_string())
+//!     .broadcaster_id("1234")
 //!     .length(twitch_api2::types::CommercialLength::Length90)
 //!     .build();
 //! let response: Vec<start_commercial::StartCommercial> = client.req_post(request, body, &token).await?.data;
@@ -60,6 +60,7 @@ impl StartCommercialRequest {
 ///
 /// [`start-commercial`](https://dev.twitch.tv/docs/api/reference#start-commercial)
 #[derive(PartialEq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct StartCommercialRequest {}
 
@@ -71,6 +72,7 @@ impl Default for StartCommercialRequest {
 ///
 /// [`start-commercial`](https://dev.twitch.tv/docs/api/reference#start-commercial)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct StartCommercialBody {
     /// ID of the channel requesting a commercial
@@ -84,6 +86,11 @@ pub struct StartCommercialBody {
 ///
 /// [`start-commercial`](https://dev.twitch.tv/docs/api/reference#start-commercial)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct StartCommercial {
@@ -99,6 +106,7 @@ impl Request for StartCommercialRequest {
     /// FIXME: Make non-vec
     type Response = Vec<StartCommercial>;
 
+    const METHOD: http::Method = http::Method::POST;
     const PATH: &'static str = "channels/commercial";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelEditCommercial];
@@ -124,16 +132,9 @@ fn test_request() {
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
     // From twitch docs
-    let data = br#"
-{
-    "data": [{
-      "length" : 60,
-      "message" : "",
-      "retry_after" : 480
-    }]
-}
-"#
-    .to_vec();
+    let data = crate::fixtures::helix::channels::START_COMMERCIAL
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -0,0 +1,132 @@
+//! Gets the list of donations that users have made to the broadcaster’s active charity campaign.
+//! [`get-charity-campaign-donations`](https://dev.twitch.tv/docs/api/reference#get-charity-campaign-donations)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetCharityCampaignDonationsRequest]
+//!
+//! To use this endpoint, construct a [`GetCharityCampaignDonationsRequest`] with the [`GetCharityCampaignDonationsRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::charity::get_charity_campaign_donations;
+//! let request = get_charity_campaign_donations::GetCharityCampaignDonationsRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! ```
+//!
+//! ## Response: [CharityDonation]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, charity::get_charity_campaign_donations};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = get_charity_campaign_donations::GetCharityCampaignDonationsRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! let response: Vec<get_charity_campaign_donations::CharityDonation> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetCharityCampaignDonationsRequest::parse_response(None, &request.get_uri(), response)`](GetCharityCampaignDonationsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Charity Campaign Donations](super::get_charity_campaign_donations)
+///
+/// [`get-charity-campaign-donations`](https://dev.twitch.tv/docs/api/reference#get-charity-campaign-donations)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct GetCharityCampaignDonationsRequest {
+    /// The ID of the broadcaster that’s currently running a charity campaign. This ID must match the user ID in the access token.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
+    ///
+    /// Backward pagination with [`before`](helix::PaginationParams::before) is not supported.
+    #[builder(default)]
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
+}
+
+/// Return Values for [Get Charity Campaign Donations](super::get_charity_campaign_donations)
+///
+/// [`get-charity-campaign-donations`](https://dev.twitch.tv/docs/api/reference#get-charity-campaign-donations)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct CharityDonation {
+    /// An ID that identifies the donation. The ID is unique across campaigns.
+    pub id: types::CharityDonationId,
+    /// An ID that identifies the charity campaign that the donation applies to.
+    pub campaign_id: types::CharityCampaignId,
+    /// An ID that identifies a user that donated money to the campaign.
+    pub user_id: types::UserId,
+    /// The user’s login name.
+    pub user_login: types::UserName,
+    /// The user’s display name.
+    pub user_name: types::DisplayName,
+    /// The amount of money that the user donated.
+    pub amount: CharityAmount,
+}
+
+impl Request for GetCharityCampaignDonationsRequest {
+    type Response = Vec<CharityDonation>;
+
+    const METHOD: http::Method = http::Method::GET;
+    const PATH: &'static str = "charity/donations";
+    /// Scope needed for this endpoint, not yet known to [`twitch_oauth2`]
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:read:charity"),
+    )];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
+}
+
+impl RequestGet for GetCharityCampaignDonationsRequest {}
+
+impl helix::Paginated for GetCharityCampaignDonationsRequest {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetCharityCampaignDonationsRequest::builder()
+        .broadcaster_id("123456")
+        .build();
+
+    // From twitch docs
+    let data = crate::fixtures::helix::charity::GET_CHARITY_CAMPAIGN_DONATIONS
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/charity/donations?broadcaster_id=123456"
+    );
+
+    let resp =
+        GetCharityCampaignDonationsRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert!(resp.pagination.is_some());
+    assert_eq!(resp.data[0].amount.value, 500);
+}
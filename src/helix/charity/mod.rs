@@ -0,0 +1,35 @@
+//! Helix endpoints regarding charity campaigns
+use crate::{
+    helix::{self, Request},
+    types,
+};
+
+use serde::{Deserialize, Serialize};
+
+pub mod get_charity_campaign_donations;
+
+#[doc(inline)]
+pub use get_charity_campaign_donations::{CharityDonation, GetCharityCampaignDonationsRequest};
+
+/// An amount of money
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct CharityAmount {
+    /// The monetary amount. The amount is specified in the currency’s minor unit. For example, the minor units for USD is cents, so if the amount is $5.50 USD, value is set to 550.
+    pub value: i64,
+    /// The number of decimal places used by the currency. For example, USD uses two decimal places.
+    pub decimal_places: u32,
+    /// The ISO-4217 three-letter currency code that identifies the type of currency in value.
+    pub currency: String,
+}
+
+impl CharityAmount {
+    /// The amount in the currency's major unit, e.g. `5.5` for $5.50 USD
+    pub fn as_f64(&self) -> f64 { self.value as f64 / 10f64.powi(self.decimal_places as i32) }
+}
@@ -10,7 +10,7 @@
 //! ```rust
 //! use twitch_api2::helix::chat::get_channel_chat_badges;
 //! let request = get_channel_chat_badges::GetChannelChatBadgesRequest::builder()
-//!     .broadcaster_id("1234".to_string())
+//!     .broadcaster_id("1234")
 //!     .build();
 //! ```
 //!
@@ -27,7 +27,7 @@
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
 //! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
 //! let request = get_channel_chat_badges::GetChannelChatBadgesRequest::builder()
-//!     .broadcaster_id("1234".to_string())
+//!     .broadcaster_id("1234")
 //!     .build();
 //! let response: Vec<helix::chat::BadgeSet> = client.req_get(request, &token).await?.data;
 //! # Ok(())
@@ -44,6 +44,7 @@ use helix::RequestGet;
 ///
 /// [`get-channel-chat-badges`](https://dev.twitch.tv/docs/api/reference#get-channel-chat-badges)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetChannelChatBadgesRequest {
     /// The broadcaster whose chat badges are being requested. Provided broadcaster_id must match the user_id in the user OAuth token.
@@ -59,6 +60,7 @@ pub type GetChannelChatBadgesResponse = BadgeSet;
 impl Request for GetChannelChatBadgesRequest {
     type Response = Vec<GetChannelChatBadgesResponse>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "chat/badges";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -76,35 +78,9 @@ fn test_request() {
 
     // From twitch docs
     // FIXME: Example has ...
-    let data = br#"
-    {
-        "data": [
-          {
-            "set_id": "bits",
-            "versions": [
-              {
-                "id": "1",
-                "image_url_1x": "https://static-cdn.jtvnw.net/badges/v1/743a0f3b-84b3-450b-96a0-503d7f4a9764/1",
-                "image_url_2x": "https://static-cdn.jtvnw.net/badges/v1/743a0f3b-84b3-450b-96a0-503d7f4a9764/2",
-                "image_url_4x": "https://static-cdn.jtvnw.net/badges/v1/743a0f3b-84b3-450b-96a0-503d7f4a9764/3"
-              }
-            ]
-          },
-          {
-            "set_id": "subscriber",
-            "versions": [
-              {
-                "id": "0",
-                "image_url_1x": "https://static-cdn.jtvnw.net/badges/v1/eb4a8a4c-eacd-4f5e-b9f2-394348310442/1",
-                "image_url_2x": "https://static-cdn.jtvnw.net/badges/v1/eb4a8a4c-eacd-4f5e-b9f2-394348310442/2",
-                "image_url_4x": "https://static-cdn.jtvnw.net/badges/v1/eb4a8a4c-eacd-4f5e-b9f2-394348310442/3"
-              }
-            ]
-          }
-        ]
-      }
-"#
-    .to_vec();
+    let data = crate::fixtures::helix::chat::GET_CHANNEL_CHAT_BADGES
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
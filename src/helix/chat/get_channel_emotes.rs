@@ -10,7 +10,7 @@
 //! ```rust
 //! use twitch_api2::helix::chat::get_channel_emotes;
 //! let request = get_channel_emotes::GetChannelEmotesRequest::builder()
-//!     .broadcaster_id("1234".to_string())
+//!     .broadcaster_id("1234")
 //!     .build();
 //! ```
 //!
@@ -27,7 +27,7 @@
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
 //! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
 //! let request = get_channel_emotes::GetChannelEmotesRequest::builder()
-//!     .broadcaster_id("1234".to_string())
+//!     .broadcaster_id("1234")
 //!     .build();
 //! let response: Vec<helix::chat::ChannelEmote> = client.req_get(request, &token).await?.data;
 //! # Ok(())
@@ -44,6 +44,7 @@ use helix::RequestGet;
 ///
 /// [`get-channel-emotes`](https://dev.twitch.tv/docs/api/reference#get-channel-emotes)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetChannelEmotesRequest {
     /// The broadcaster whose emotes are being requested.
@@ -59,6 +60,8 @@ pub type GetChannelEmotesResponse = ChannelEmote;
 impl Request for GetChannelEmotesRequest {
     type Response = Vec<GetChannelEmotesResponse>;
 
+    const COLLECT_OTHER_FIELDS: bool = true;
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "chat/emotes";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -66,6 +69,10 @@ impl Request for GetChannelEmotesRequest {
 
 impl RequestGet for GetChannelEmotesRequest {}
 
+impl helix::ResponseExtras for GetChannelEmotesRequest {
+    type Extras = EmotesExtras;
+}
+
 #[cfg(test)]
 #[test]
 fn test_request() {
@@ -76,38 +83,9 @@ fn test_request() {
 
     // From twitch docs
     // FIXME: Example has ... and is malformed, uses [] in images
-    let data = br#"
-    {
-      "data": [
-        {
-          "id": "304456832",
-          "name": "twitchdevPitchfork",
-          "images": {
-            "url_1x": "https://static-cdn.jtvnw.net/emoticons/v2/304456832/static/light/1.0",
-            "url_2x": "https://static-cdn.jtvnw.net/emoticons/v2/304456832/static/light/2.0",
-            "url_4x": "https://static-cdn.jtvnw.net/emoticons/v2/304456832/static/light/3.0"
-          },
-          "tier": "1000",
-          "emote_type": "subscriptions",
-          "emote_set_id": "301590448",
-          "format": [
-            "static"
-          ],
-          "scale": [
-            "1.0",
-            "2.0",
-            "3.0"
-          ],
-          "theme_mode": [
-            "light",
-            "dark"
-          ]
-        }
-      ],
-      "template": "https://static-cdn.jtvnw.net/emoticons/v2/{{id}}/{{format}}/{{theme_mode}}/{{scale}}"
-    }
-"#
-    .to_vec();
+    let data = crate::fixtures::helix::chat::GET_CHANNEL_EMOTES
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -117,5 +95,14 @@ fn test_request() {
         "https://api.twitch.tv/helix/chat/emotes?broadcaster_id=304456832"
     );
 
-    dbg!(GetChannelEmotesRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    let resp =
+        dbg!(GetChannelEmotesRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    assert_eq!(
+        resp.extras().unwrap().template,
+        types::EMOTE_V2_URL_TEMPLATE
+    );
+    assert_eq!(
+        resp.get_other::<_, String>("template").unwrap().as_deref(),
+        Some(types::EMOTE_V2_URL_TEMPLATE)
+    );
 }
@@ -0,0 +1,216 @@
+//! Gets the broadcaster’s chat settings.
+//! [`get-chat-settings`](https://dev.twitch.tv/docs/api/reference#get-chat-settings)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetChatSettingsRequest]
+//!
+//! To use this endpoint, construct a [`GetChatSettingsRequest`] with the [`GetChatSettingsRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::chat::get_chat_settings;
+//! let request = get_chat_settings::GetChatSettingsRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! ```
+//!
+//! ## Response: [ChatSettings]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, chat::get_chat_settings};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = get_chat_settings::GetChatSettingsRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! let response: helix::chat::ChatSettings = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetChatSettingsRequest::parse_response(None, &request.get_uri(), response)`](GetChatSettingsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Chat Settings](super::get_chat_settings)
+///
+/// [`get-chat-settings`](https://dev.twitch.tv/docs/api/reference#get-chat-settings)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct GetChatSettingsRequest {
+    /// The ID of the broadcaster whose chat settings you want to get.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// Required only to access the [`non_moderator_chat_delay`](ChatSettings::non_moderator_chat_delay) or [`non_moderator_chat_delay_duration`](ChatSettings::non_moderator_chat_delay_duration) settings.
+    ///
+    /// The ID of a user that has permission to moderate the broadcaster’s chat room. This ID must match the user ID associated with the user OAuth token.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moderator_id: Option<types::UserId>,
+}
+
+/// Return Values for [Get Chat Settings](super::get_chat_settings)
+///
+/// [`get-chat-settings`](https://dev.twitch.tv/docs/api/reference#get-chat-settings)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChatSettings {
+    /// The ID of the broadcaster specified in the request.
+    pub broadcaster_id: types::UserId,
+    /// A Boolean value that determines whether chat messages must contain only emotes.
+    pub emote_mode: bool,
+    /// A Boolean value that determines whether the broadcaster restricts the chat room to followers only, based on how long they’ve followed.
+    pub follower_mode: bool,
+    /// The length of time, in minutes, that the followers must have followed the broadcaster to participate in the chat room. Is null if [`follower_mode`](ChatSettings::follower_mode) is `false`.
+    pub follower_mode_duration: Option<u64>,
+    /// The moderator’s ID. The response includes this field only if the request specifies a [`moderator_id`](GetChatSettingsRequest::moderator_id).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub moderator_id: Option<types::UserId>,
+    /// A Boolean value that determines whether the broadcaster adds a short delay before chat messages appear in the chat room. This gives chat moderators and bots a chance to remove them before viewers can see the message.
+    ///
+    /// Only included if the request specifies a [`moderator_id`](GetChatSettingsRequest::moderator_id).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub non_moderator_chat_delay: Option<bool>,
+    /// The amount of time, in seconds, that messages are delayed from appearing in chat. Is null if [`non_moderator_chat_delay`](ChatSettings::non_moderator_chat_delay) is `false`.
+    ///
+    /// Only included if the request specifies a [`moderator_id`](GetChatSettingsRequest::moderator_id).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub non_moderator_chat_delay_duration: Option<u64>,
+    /// A Boolean value that determines whether the broadcaster limits how often users in the chat room are allowed to send messages.
+    pub slow_mode: bool,
+    /// The amount of time, in seconds, that users need to wait between sending messages. Is null if [`slow_mode`](ChatSettings::slow_mode) is `false`.
+    pub slow_mode_wait_time: Option<u64>,
+    /// A Boolean value that determines whether only users that subscribe to the broadcaster’s channel can talk in the chat room.
+    pub subscriber_mode: bool,
+    /// A Boolean value that determines whether the broadcaster requires users to post only unique messages in the chat room.
+    pub unique_chat_mode: bool,
+}
+
+impl ChatSettings {
+    /// The [restrictions](ChatRestrictions) these settings put on chatters.
+    pub fn restrictions(&self) -> ChatRestrictions { self.into() }
+}
+
+impl From<&ChatSettings> for ChatRestrictions {
+    fn from(settings: &ChatSettings) -> Self {
+        ChatRestrictions {
+            follower_mode_duration: settings.follower_mode.then(|| {
+                std::time::Duration::from_secs(
+                    settings.follower_mode_duration.unwrap_or_default() * 60,
+                )
+            }),
+            subscriber_mode: settings.subscriber_mode,
+            slow_mode_wait_time: settings.slow_mode.then(|| {
+                std::time::Duration::from_secs(settings.slow_mode_wait_time.unwrap_or_default())
+            }),
+            emote_mode: settings.emote_mode,
+            unique_chat_mode: settings.unique_chat_mode,
+        }
+    }
+}
+
+impl From<ChatSettings> for ChatRestrictions {
+    fn from(settings: ChatSettings) -> Self { (&settings).into() }
+}
+
+impl Request for GetChatSettingsRequest {
+    type Response = ChatSettings;
+
+    const METHOD: http::Method = http::Method::GET;
+    const PATH: &'static str = "chat/settings";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestGet for GetChatSettingsRequest {
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response_str: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
+    where
+        Self: Sized,
+    {
+        let response: helix::InnerResponse<Vec<Self::Response>> =
+            helix::parse_json_owned(response_str, true).map_err(|e| {
+                helix::HelixRequestError::DeserializeError(
+                    response_str.to_string(),
+                    e,
+                    Self::METHOD,
+                    uri.clone(),
+                    status,
+                    None,
+                )
+            })?;
+        let data = response.data.into_iter().next().ok_or_else(|| {
+            helix::HelixRequestError::InvalidResponse {
+                reason: "response included no data",
+                response: response_str.to_string(),
+                status,
+                method: Self::METHOD,
+                uri: uri.clone(),
+                request_id: None,
+            }
+        })?;
+        Ok(helix::Response {
+            data,
+            pagination: response.pagination.cursor,
+            request,
+            total: None,
+            other: None,
+            request_id: None,
+            diagnostics: Default::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetChatSettingsRequest::builder()
+        .broadcaster_id("1234")
+        .moderator_id(Some("5678".into()))
+        .build();
+
+    // From twitch docs
+    let data = crate::fixtures::helix::chat::GET_CHAT_SETTINGS
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/chat/settings?broadcaster_id=1234&moderator_id=5678"
+    );
+
+    let resp = GetChatSettingsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(resp.non_moderator_chat_delay_duration, Some(4));
+    let restrictions = resp.restrictions();
+    assert_eq!(
+        restrictions.follower_mode_duration,
+        Some(std::time::Duration::from_secs(0))
+    );
+    assert_eq!(restrictions.slow_mode_wait_time, None);
+    dbg!(resp);
+}
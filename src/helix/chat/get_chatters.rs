@@ -0,0 +1,155 @@
+//! Gets the list of users that are connected to the specified broadcaster’s chat session.
+//! [`get-chatters`](https://dev.twitch.tv/docs/api/reference#get-chatters)
+//!
+//! This is the supported replacement for the undocumented TMI `chatters` endpoint.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetChattersRequest]
+//!
+//! To use this endpoint, construct a [`GetChattersRequest`] with the [`GetChattersRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::{chat::get_chatters, ModeratorAuth};
+//! let request = get_chatters::GetChattersRequest::builder()
+//!     .auth(ModeratorAuth::new("1234", "4321"))
+//!     .build();
+//! ```
+//!
+//! ## Response: [Chatter]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, chat::get_chatters};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = get_chatters::GetChattersRequest::builder()
+//!     .auth(helix::ModeratorAuth::from_token("1234", &token).expect("token has a user"))
+//!     .build();
+//! let response = client.req_get(request, &token).await?;
+//! let chatters: &[get_chatters::Chatter] = &response.data;
+//! println!("{} users in chat", response.total.unwrap_or_default());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetChattersRequest::parse_response(None, &request.get_uri(), response)`](GetChattersRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Chatters](super::get_chatters)
+///
+/// [`get-chatters`](https://dev.twitch.tv/docs/api/reference#get-chatters)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct GetChattersRequest {
+    /// The broadcaster the request is for, and the broadcaster or one of their moderators making it.
+    #[builder(setter(into))]
+    #[serde(flatten)]
+    pub auth: helix::ModeratorAuth,
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 1000. Default: 100.
+    ///
+    /// Backward pagination with [`before`](helix::PaginationParams::before) is not supported.
+    #[builder(default)]
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
+}
+
+/// Return Values for [Get Chatters](super::get_chatters)
+///
+/// [`get-chatters`](https://dev.twitch.tv/docs/api/reference#get-chatters)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Chatter {
+    /// The ID of a user that’s connected to the broadcaster’s chat room.
+    pub user_id: types::UserId,
+    /// The user’s login name.
+    pub user_login: types::UserName,
+    /// The user’s display name.
+    pub user_name: types::DisplayName,
+}
+
+impl Request for GetChattersRequest {
+    type Response = Vec<Chatter>;
+
+    const MAX_PAGE_SIZE: u32 = 1000;
+    const METHOD: http::Method = http::Method::GET;
+    const PATH: &'static str = "chat/chatters";
+    /// Scope needed for this endpoint, not yet known to [`twitch_oauth2`]
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:read:chatters"),
+    )];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
+}
+
+impl RequestGet for GetChattersRequest {}
+
+impl helix::ResponseExtras for GetChattersRequest {
+    type Extras = ChattersExtras;
+}
+
+/// Fields returned by [Get Chatters](super::get_chatters) besides `data`, see [`Response::extras`](helix::Response::extras)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct ChattersExtras {
+    /// The total number of users that are connected to the broadcaster’s chat room.
+    pub total: i64,
+}
+
+impl helix::Paginated for GetChattersRequest {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetChattersRequest::builder()
+        .auth(ModeratorAuth::new("123456", "654321"))
+        .build();
+
+    // From twitch docs
+    let data = crate::fixtures::helix::chat::GET_CHATTERS
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/chat/chatters?broadcaster_id=123456&moderator_id=654321"
+    );
+
+    let resp = GetChattersRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(resp.total, Some(8));
+    assert_eq!(resp.extras().unwrap().total, 8);
+    assert_eq!(resp.get_other::<_, i64>("total").unwrap(), Some(8));
+    let resp = resp.map_data(|chatters| {
+        chatters
+            .into_iter()
+            .map(|c| c.user_login)
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(resp.total, Some(8));
+    assert_eq!(
+        resp.into_iter().collect::<Vec<_>>(),
+        vec![types::UserName::from("smittysmithers")]
+    );
+}
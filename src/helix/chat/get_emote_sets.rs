@@ -44,6 +44,7 @@ use helix::RequestGet;
 ///
 /// [`get-emote-sets`](https://dev.twitch.tv/docs/api/reference#get-emote-sets)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetEmoteSetsRequest {
     // FIXME: twitch doc specifies maximum as 25, but it actually is 10
@@ -56,6 +57,11 @@ pub struct GetEmoteSetsRequest {
 ///
 /// [`get-emote-sets`](https://dev.twitch.tv/docs/api/reference#get-emote-sets)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Emote {
@@ -73,10 +79,13 @@ pub struct Emote {
     /// User ID of the broadcaster who owns the emote.
     pub owner_id: types::UserId,
     /// The formats that the emote is available in.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub format: Vec<types::EmoteAnimationSetting>,
     /// The sizes that the emote is available in.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub scale: Vec<types::EmoteScale>,
     /// The background themes that the emote is available in.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub theme_mode: Vec<types::EmoteThemeMode>,
 }
 
@@ -112,6 +121,8 @@ impl Emote {
 impl Request for GetEmoteSetsRequest {
     type Response = Vec<Emote>;
 
+    const COLLECT_OTHER_FIELDS: bool = true;
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "chat/emotes/set";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -119,6 +130,10 @@ impl Request for GetEmoteSetsRequest {
 
 impl RequestGet for GetEmoteSetsRequest {}
 
+impl helix::ResponseExtras for GetEmoteSetsRequest {
+    type Extras = EmotesExtras;
+}
+
 #[cfg(test)]
 #[test]
 fn test_request() {
@@ -129,38 +144,9 @@ fn test_request() {
 
     // From twitch docs
     // FIXME: Example has ... and is malformed, uses [] in images
-    let data = br#"
-    {
-      "data": [
-        {
-          "id": "304456832",
-          "name": "twitchdevPitchfork",
-          "images": {
-            "url_1x": "https://static-cdn.jtvnw.net/emoticons/v2/304456832/static/light/1.0",
-            "url_2x": "https://static-cdn.jtvnw.net/emoticons/v2/304456832/static/light/2.0",
-            "url_4x": "https://static-cdn.jtvnw.net/emoticons/v2/304456832/static/light/3.0"
-          },
-          "emote_type": "subscriptions",
-          "emote_set_id": "301590448",
-          "owner_id": "141981764",
-          "format": [
-            "static"
-          ],
-          "scale": [
-            "1.0",
-            "2.0",
-            "3.0"
-          ],
-          "theme_mode": [
-            "light",
-            "dark"
-          ]
-        }
-      ],
-      "template": "https://static-cdn.jtvnw.net/emoticons/v2/{{id}}/{{format}}/{{theme_mode}}/{{scale}}"
-    }
-"#
-    .to_vec();
+    let data = crate::fixtures::helix::chat::GET_EMOTE_SETS
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -170,5 +156,13 @@ fn test_request() {
         "https://api.twitch.tv/helix/chat/emotes/set?emote_set_id=301590448"
     );
 
-    dbg!(GetEmoteSetsRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    let resp = dbg!(GetEmoteSetsRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    assert_eq!(
+        resp.extras().unwrap().template,
+        types::EMOTE_V2_URL_TEMPLATE
+    );
+    assert_eq!(
+        resp.get_other::<_, String>("template").unwrap().as_deref(),
+        Some(types::EMOTE_V2_URL_TEMPLATE)
+    );
 }
@@ -40,6 +40,7 @@ use helix::RequestGet;
 ///
 /// [`get-global-chat-badges`](https://dev.twitch.tv/docs/api/reference#get-global-chat-badges)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetGlobalChatBadgesRequest {}
 
@@ -56,6 +57,7 @@ pub type GetGlobalChatBadgesResponse = BadgeSet;
 impl Request for GetGlobalChatBadgesRequest {
     type Response = Vec<GetGlobalChatBadgesResponse>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "chat/badges/global";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -71,24 +73,9 @@ fn test_request() {
 
     // From twitch docs
     // FIXME: Example has ...
-    let data = br#"
-    {
-        "data": [
-          {
-            "set_id": "vip",
-            "versions": [
-              {
-                "id": "1",
-                "image_url_1x": "https://static-cdn.jtvnw.net/badges/v1/b817aba4-fad8-49e2-b88a-7cc744dfa6ec/1",
-                "image_url_2x": "https://static-cdn.jtvnw.net/badges/v1/b817aba4-fad8-49e2-b88a-7cc744dfa6ec/2",
-                "image_url_4x": "https://static-cdn.jtvnw.net/badges/v1/b817aba4-fad8-49e2-b88a-7cc744dfa6ec/3"
-              }
-            ]
-          }
-        ]
-    }
-"#
-    .to_vec();
+    let data = crate::fixtures::helix::chat::GET_GLOBAL_CHAT_BADGES
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -40,6 +40,7 @@ use helix::RequestGet;
 ///
 /// [`get-global-emotes`](https://dev.twitch.tv/docs/api/reference#get-global-emotes)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetGlobalEmotesRequest {}
 
@@ -51,6 +52,8 @@ pub type GetChannelEmotesResponse = GlobalEmote;
 impl Request for GetGlobalEmotesRequest {
     type Response = Vec<GetChannelEmotesResponse>;
 
+    const COLLECT_OTHER_FIELDS: bool = true;
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "chat/emotes/global";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -58,6 +61,10 @@ impl Request for GetGlobalEmotesRequest {
 
 impl RequestGet for GetGlobalEmotesRequest {}
 
+impl helix::ResponseExtras for GetGlobalEmotesRequest {
+    type Extras = EmotesExtras;
+}
+
 #[cfg(test)]
 #[test]
 fn test_request() {
@@ -66,36 +73,9 @@ fn test_request() {
 
     // From twitch docs
     // FIXME: Example has ...
-    let data = br#"
-
-    {
-      "data": [
-        {
-          "id": "196892",
-          "name": "TwitchUnity",
-          "images": {
-            "url_1x": "https://static-cdn.jtvnw.net/emoticons/v2/196892/static/light/1.0",
-            "url_2x": "https://static-cdn.jtvnw.net/emoticons/v2/196892/static/light/2.0",
-            "url_4x": "https://static-cdn.jtvnw.net/emoticons/v2/196892/static/light/3.0"
-          },
-          "format": [
-            "static"
-          ],
-          "scale": [
-            "1.0",
-            "2.0",
-            "3.0"
-          ],
-          "theme_mode": [
-            "light",
-            "dark"
-          ]
-        }
-      ],
-      "template": "https://static-cdn.jtvnw.net/emoticons/v2/{{id}}/{{format}}/{{theme_mode}}/{{scale}}"
-    }
-"#
-    .to_vec();
+    let data = crate::fixtures::helix::chat::GET_GLOBAL_EMOTES
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -105,5 +85,14 @@ fn test_request() {
         "https://api.twitch.tv/helix/chat/emotes/global?"
     );
 
-    dbg!(GetGlobalEmotesRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    let resp =
+        dbg!(GetGlobalEmotesRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    assert_eq!(
+        resp.extras().unwrap().template,
+        types::EMOTE_V2_URL_TEMPLATE
+    );
+    assert_eq!(
+        resp.get_other::<_, String>("template").unwrap().as_deref(),
+        Some(types::EMOTE_V2_URL_TEMPLATE)
+    );
 }
@@ -8,9 +8,13 @@ use serde::{Deserialize, Serialize};
 
 pub mod get_channel_chat_badges;
 pub mod get_channel_emotes;
+pub mod get_chat_settings;
+pub mod get_chatters;
 pub mod get_emote_sets;
 pub mod get_global_chat_badges;
 pub mod get_global_emotes;
+pub mod send_a_shoutout;
+pub mod send_chat_announcement;
 
 #[doc(inline)]
 pub use get_channel_chat_badges::GetChannelChatBadgesRequest;
@@ -27,19 +31,121 @@ pub use get_global_emotes::GetGlobalEmotesRequest;
 #[doc(inline)]
 pub use get_emote_sets::GetEmoteSetsRequest;
 
+#[doc(inline)]
+pub use get_chatters::{Chatter, ChattersExtras, GetChattersRequest};
+
+#[doc(inline)]
+pub use get_chat_settings::{ChatSettings, GetChatSettingsRequest};
+
+#[doc(inline)]
+pub use send_a_shoutout::{SendAShoutout, SendAShoutoutError, SendAShoutoutRequest};
+
+#[doc(inline)]
+pub use send_chat_announcement::{
+    AnnouncementColor, SendChatAnnouncement, SendChatAnnouncementBody, SendChatAnnouncementRequest,
+};
+
+/// Maximum length of a chat message or [announcement](send_chat_announcement), in characters
+pub const MAX_MESSAGE_LENGTH: usize = 500;
+
+/// Split `text` into messages of at most [`MAX_MESSAGE_LENGTH`] characters, see [`split_message_at`]
+pub fn split_message(text: &str) -> Vec<String> { split_message_at(text, MAX_MESSAGE_LENGTH) }
+
+/// Split `text` into messages of at most `max_length` characters, breaking between words
+///
+/// Words are separated by single spaces in the messages, other whitespace like newlines is not kept.
+/// Words longer than `max_length` are split over several messages.
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api2::helix::chat::split_message_at;
+///
+/// assert_eq!(
+///     split_message_at("the quick brown fox", 10),
+///     vec!["the quick", "brown fox"]
+/// );
+/// ```
+pub fn split_message_at(text: &str, max_length: usize) -> Vec<String> {
+    let max_length = max_length.max(1);
+    let mut messages = vec![];
+    let mut current = String::new();
+    let mut current_len = 0;
+    for mut word in text.split_whitespace() {
+        let mut word_len = word.chars().count();
+        while word_len > max_length {
+            if current_len > 0 {
+                messages.push(std::mem::take(&mut current));
+                current_len = 0;
+            }
+            let (end, _) = word
+                .char_indices()
+                .nth(max_length)
+                .expect("word is longer than max_length");
+            messages.push(word[..end].to_owned());
+            word = &word[end..];
+            word_len -= max_length;
+        }
+        if current_len == 0 {
+            current.push_str(word);
+            current_len = word_len;
+        } else if current_len + 1 + word_len <= max_length {
+            current.push(' ');
+            current.push_str(word);
+            current_len += 1 + word_len;
+        } else {
+            messages.push(std::mem::replace(&mut current, word.to_owned()));
+            current_len = word_len;
+        }
+    }
+    if current_len > 0 {
+        messages.push(current);
+    }
+    messages
+}
+
+/// Fail with a [`BodyError`](helix::BodyError) if `message` is longer than `max_length` characters
+pub(crate) fn check_message_length(
+    message: &str,
+    max_length: usize,
+) -> Result<(), helix::BodyError> {
+    let length = message.chars().count();
+    if length > max_length {
+        return Err(helix::BodyError::Custom(
+            format!(
+                "message is {} characters long, at most {} are allowed",
+                length, max_length
+            )
+            .into(),
+        ));
+    }
+    Ok(())
+}
+
 /// A set of badges
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct BadgeSet {
     /// ID for the chat badge set.
     pub set_id: types::BadgeSetId,
     /// Contains chat badge objects for the set.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub versions: Vec<ChatBadge>,
 }
 
 /// A chat Badge
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChatBadge {
@@ -54,8 +160,21 @@ pub struct ChatBadge {
     pub image_url_4x: String,
 }
 
+/// Fields returned by the emote endpoints besides `data`, see [`Response::extras`](helix::Response::extras)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct EmotesExtras {
+    /// A templated URL for the emotes, see [`EmoteUrlBuilder`]
+    pub template: String,
+}
+
 /// A chat emote
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ChannelEmote {
@@ -85,10 +204,13 @@ pub struct ChannelEmote {
     /// ID of the emote set the emote belongs to.
     pub emote_set_id: types::EmoteSetId,
     /// The formats that the emote is available in.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub format: Vec<types::EmoteAnimationSetting>,
     /// The sizes that the emote is available in.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub scale: Vec<types::EmoteScale>,
     /// The background themes that the emote is available in.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub theme_mode: Vec<types::EmoteThemeMode>,
 }
 
@@ -122,6 +244,11 @@ impl ChannelEmote {
 
 /// A chat emote
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct GlobalEmote {
@@ -132,9 +259,115 @@ pub struct GlobalEmote {
     /// Object of image URLs for the emote.
     pub images: types::Image,
     /// The formats that the emote is available in.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub format: Vec<types::EmoteAnimationSetting>,
     /// The sizes that the emote is available in.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub scale: Vec<types::EmoteScale>,
     /// The background themes that the emote is available in.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub theme_mode: Vec<types::EmoteThemeMode>,
 }
+
+/// Restrictions on who can chat in a channel and how often, summarizing the chat settings of a channel.
+///
+/// Useful for deciding whether to attempt sending a message at all.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use twitch_api2::helix::chat::ChatRestrictions;
+///
+/// let restrictions = ChatRestrictions::builder()
+///     .follower_mode_duration(Duration::from_secs(10 * 60))
+///     .slow_mode_wait_time(Duration::from_secs(30))
+///     .build();
+///
+/// // not following
+/// assert!(!restrictions.can_chat(None, false, false));
+/// // following for five minutes
+/// assert!(!restrictions.can_chat(Some(Duration::from_secs(5 * 60)), false, false));
+/// // following for an hour
+/// assert!(restrictions.can_chat(Some(Duration::from_secs(60 * 60)), false, false));
+/// // moderators are exempt
+/// assert!(restrictions.can_chat(None, false, true));
+/// assert_eq!(
+///     restrictions.message_interval(false),
+///     Some(Duration::from_secs(30))
+/// );
+/// assert_eq!(restrictions.message_interval(true), None);
+/// ```
+#[derive(PartialEq, Eq, typed_builder::TypedBuilder, Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct ChatRestrictions {
+    /// How long users need to follow the channel before being able to chat. `None` if follower mode is disabled.
+    #[builder(default, setter(strip_option))]
+    pub follower_mode_duration: Option<std::time::Duration>,
+    /// Only subscribers can chat.
+    #[builder(default)]
+    pub subscriber_mode: bool,
+    /// How long users need to wait between sending messages. `None` if slow mode is disabled.
+    #[builder(default, setter(strip_option))]
+    pub slow_mode_wait_time: Option<std::time::Duration>,
+    /// Messages can only contain emotes.
+    #[builder(default)]
+    pub emote_mode: bool,
+    /// Messages must be unique, i.e. not repeat a recent message.
+    #[builder(default)]
+    pub unique_chat_mode: bool,
+}
+
+impl ChatRestrictions {
+    /// Whether a user can chat, given how long they have followed the channel (`None` if they don't follow it) and whether they're a subscriber or moderator.
+    ///
+    /// Moderators are exempt from all restrictions.
+    pub fn can_chat(
+        &self,
+        followed_for: Option<std::time::Duration>,
+        is_subscriber: bool,
+        is_moderator: bool,
+    ) -> bool {
+        if is_moderator {
+            return true;
+        }
+        if self.subscriber_mode && !is_subscriber {
+            return false;
+        }
+        match (self.follower_mode_duration, followed_for) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(required), Some(followed_for)) => followed_for >= required,
+        }
+    }
+
+    /// How long a user needs to wait between messages, `None` if they can send messages without waiting.
+    ///
+    /// Moderators are exempt from slow mode.
+    pub fn message_interval(&self, is_moderator: bool) -> Option<std::time::Duration> {
+        if is_moderator {
+            None
+        } else {
+            self.slow_mode_wait_time
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_split_message() {
+    assert!(split_message("").is_empty());
+    assert_eq!(split_message("  hello\n world "), vec!["hello world"]);
+    assert_eq!(split_message_at("aaaa bb cc", 5), vec!["aaaa", "bb cc"]);
+    assert_eq!(
+        split_message_at("x ääääääää y", 3),
+        vec!["x", "äää", "äää", "ää", "y"]
+    );
+    let text = "word ".repeat(300);
+    let messages = split_message(&text);
+    assert_eq!(messages.len(), 3);
+    assert!(messages
+        .iter()
+        .all(|message| message.chars().count() <= MAX_MESSAGE_LENGTH));
+    assert_eq!(messages.join(" "), text.trim_end());
+}
@@ -0,0 +1,240 @@
+//! Sends a Shoutout to the specified broadcaster.
+//! [`send-a-shoutout`](https://dev.twitch.tv/docs/api/reference#send-a-shoutout)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [SendAShoutoutRequest]
+//!
+//! To use this endpoint, construct a [`SendAShoutoutRequest`] with the [`SendAShoutoutRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::{chat::send_a_shoutout, ModeratorAuth};
+//! let request = send_a_shoutout::SendAShoutoutRequest::builder()
+//!     .auth(ModeratorAuth::broadcaster("1234"))
+//!     .to_broadcaster_id("5678")
+//!     .build();
+//! ```
+//!
+//! ## Response: [SendAShoutout]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! Errors specific to this endpoint, like the broadcaster being in a cooldown, can be found with [`SendAShoutoutError::from_helix_error`].
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, chat::send_a_shoutout};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = send_a_shoutout::SendAShoutoutRequest::builder()
+//!     .auth(helix::ModeratorAuth::broadcaster("1234"))
+//!     .to_broadcaster_id("5678")
+//!     .build();
+//! match client.req_post(request, helix::EmptyBody, &token).await {
+//!     Ok(response) => println!("{:?}", response.data),
+//!     Err(helix::ClientRequestError::HelixRequestError(e)) => {
+//!         match send_a_shoutout::SendAShoutoutError::from_helix_error(&e) {
+//!             Some(send_a_shoutout::SendAShoutoutError::Cooldown) => println!("try again later"),
+//!             _ => return Err(e.into()),
+//!         }
+//!     }
+//!     Err(e) => return Err(e.into()),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`SendAShoutoutRequest::parse_response(None, &request.get_uri(), response)`](SendAShoutoutRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Send A Shoutout](super::send_a_shoutout)
+///
+/// [`send-a-shoutout`](https://dev.twitch.tv/docs/api/reference#send-a-shoutout)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(from = "ShoutoutQuery", into = "ShoutoutQuery")]
+#[non_exhaustive]
+pub struct SendAShoutoutRequest {
+    /// The broadcaster that’s sending the Shoutout, sent as `from_broadcaster_id`, and the broadcaster or one of their moderators sending it.
+    #[builder(setter(into))]
+    pub auth: helix::ModeratorAuth,
+    /// The ID of the broadcaster that’s receiving the Shoutout.
+    #[builder(setter(into))]
+    pub to_broadcaster_id: types::UserId,
+}
+
+/// The query of [`SendAShoutoutRequest`], the endpoint calls the broadcaster of [`ModeratorAuth`](helix::ModeratorAuth) `from_broadcaster_id`
+#[derive(Deserialize, Serialize)]
+struct ShoutoutQuery {
+    from_broadcaster_id: types::UserId,
+    to_broadcaster_id: types::UserId,
+    moderator_id: types::UserId,
+}
+
+impl From<ShoutoutQuery> for SendAShoutoutRequest {
+    fn from(query: ShoutoutQuery) -> Self {
+        SendAShoutoutRequest {
+            auth: helix::ModeratorAuth::new(query.from_broadcaster_id, query.moderator_id),
+            to_broadcaster_id: query.to_broadcaster_id,
+        }
+    }
+}
+
+impl From<SendAShoutoutRequest> for ShoutoutQuery {
+    fn from(request: SendAShoutoutRequest) -> Self {
+        ShoutoutQuery {
+            from_broadcaster_id: request.auth.broadcaster_id,
+            to_broadcaster_id: request.to_broadcaster_id,
+            moderator_id: request.auth.moderator_id,
+        }
+    }
+}
+
+/// Return Values for [Send A Shoutout](super::send_a_shoutout)
+///
+/// [`send-a-shoutout`](https://dev.twitch.tv/docs/api/reference#send-a-shoutout)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[non_exhaustive]
+pub enum SendAShoutout {
+    /// 204 - Successfully sent the specified broadcaster a Shoutout.
+    Success,
+}
+
+/// Errors returned by [Send A Shoutout](super::send_a_shoutout) that are specific to the endpoint
+#[derive(PartialEq, Eq, Debug, Clone, Copy, thiserror::Error, displaydoc::Display)]
+#[non_exhaustive]
+pub enum SendAShoutoutError {
+    /// the broadcaster is in a cooldown period, Shoutouts can be sent every 2 minutes and to the same broadcaster every 60 minutes
+    Cooldown,
+    /// the broadcaster may not give themselves a Shoutout
+    SelfShoutout,
+    /// the broadcaster is not streaming live or does not have one or more viewers
+    NotLive,
+}
+
+impl SendAShoutoutError {
+    /// Get the error specific to this endpoint from the error Twitch returned, if any.
+    pub fn from_helix_error(error: &helix::HelixRequestError) -> Option<Self> {
+        match error {
+            helix::HelixRequestError::Error {
+                status, message, ..
+            } => match status.as_u16() {
+                429 => Some(SendAShoutoutError::Cooldown),
+                400 if message.contains("themselves") => Some(SendAShoutoutError::SelfShoutout),
+                400 if message.contains("live") => Some(SendAShoutoutError::NotLive),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl Request for SendAShoutoutRequest {
+    type Response = SendAShoutout;
+
+    const METHOD: http::Method = http::Method::POST;
+    const PATH: &'static str = "chat/shoutouts";
+    /// Scope needed for this endpoint, not yet known to [`twitch_oauth2`]
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:manage:shoutouts"),
+    )];
+}
+
+impl RequestPost for SendAShoutoutRequest {
+    type Body = helix::EmptyBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
+    where
+        Self: Sized,
+    {
+        match status {
+            http::StatusCode::NO_CONTENT => Ok(helix::Response {
+                data: SendAShoutout::Success,
+                pagination: None,
+                request,
+                total: None,
+                other: None,
+                request_id: None,
+                diagnostics: Default::default(),
+            }),
+            _ => Err(helix::HelixRequestError::InvalidResponse {
+                reason: "unexpected status",
+                response: response.to_string(),
+                status,
+                method: Self::METHOD,
+                uri: uri.clone(),
+                request_id: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = SendAShoutoutRequest::builder()
+        .auth(ModeratorAuth::new("12345", "98765"))
+        .to_broadcaster_id("626262")
+        .build();
+
+    dbg!(req.create_request(EmptyBody, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/chat/shoutouts?from_broadcaster_id=12345&to_broadcaster_id=626262&moderator_id=98765"
+    );
+
+    dbg!(SendAShoutoutRequest::parse_response(Some(req), &uri, http_response).unwrap());
+}
+
+#[cfg(test)]
+#[test]
+fn test_request_error() {
+    use helix::*;
+    let req = SendAShoutoutRequest::builder()
+        .auth(ModeratorAuth::new("12345", "98765"))
+        .to_broadcaster_id("626262")
+        .build();
+
+    let data = br#"
+{
+    "error": "Too Many Requests",
+    "status": 429,
+    "message": "The broadcaster may not send a Shoutout while in a cooldown period."
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().status(429).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    let error = SendAShoutoutRequest::parse_response(Some(req), &uri, http_response).unwrap_err();
+    assert_eq!(
+        SendAShoutoutError::from_helix_error(&error),
+        Some(SendAShoutoutError::Cooldown)
+    );
+}
@@ -0,0 +1,229 @@
+//! Sends an announcement to the broadcaster’s chat room.
+//! [`send-chat-announcement`](https://dev.twitch.tv/docs/api/reference#send-chat-announcement)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [SendChatAnnouncementRequest]
+//!
+//! To use this endpoint, construct a [`SendChatAnnouncementRequest`] with the [`SendChatAnnouncementRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::{chat::send_chat_announcement, ModeratorAuth};
+//! let request = send_chat_announcement::SendChatAnnouncementRequest::builder()
+//!     .auth(ModeratorAuth::new("1234", "5678"))
+//!     .build();
+//! ```
+//!
+//! ## Body: [SendChatAnnouncementBody]
+//!
+//! We also need to provide a body to the request containing the message.
+//!
+//! Messages longer than [`MAX_MESSAGE_LENGTH`](super::MAX_MESSAGE_LENGTH) fail when creating the request, see [`split_message`](super::split_message) for sending longer texts.
+//!
+//! ```
+//! # use twitch_api2::helix::chat::send_chat_announcement;
+//! let body = send_chat_announcement::SendChatAnnouncementBody::builder()
+//!     .message("Hello chat!")
+//!     .color(send_chat_announcement::AnnouncementColor::Purple)
+//!     .build();
+//! ```
+//!
+//! ## Response: [SendChatAnnouncement]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, chat::send_chat_announcement};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = send_chat_announcement::SendChatAnnouncementRequest::builder()
+//!     .auth(helix::ModeratorAuth::new("1234", "5678"))
+//!     .build();
+//! let body = send_chat_announcement::SendChatAnnouncementBody::builder()
+//!     .message("Hello chat!")
+//!     .build();
+//! let response: send_chat_announcement::SendChatAnnouncement = client.req_post(request, body, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`SendChatAnnouncementRequest::parse_response(None, &request.get_uri(), response)`](SendChatAnnouncementRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Send Chat Announcement](super::send_chat_announcement)
+///
+/// [`send-chat-announcement`](https://dev.twitch.tv/docs/api/reference#send-chat-announcement)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct SendChatAnnouncementRequest {
+    /// The broadcaster the request is for, and the broadcaster or one of their moderators making it.
+    #[builder(setter(into))]
+    #[serde(flatten)]
+    pub auth: helix::ModeratorAuth,
+}
+
+/// Body Parameters for [Send Chat Announcement](super::send_chat_announcement)
+///
+/// [`send-chat-announcement`](https://dev.twitch.tv/docs/api/reference#send-chat-announcement)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct SendChatAnnouncementBody {
+    /// The announcement to make in the broadcaster’s chat room. Announcements are limited to a maximum of [500](super::MAX_MESSAGE_LENGTH) characters.
+    #[builder(setter(into))]
+    pub message: String,
+    /// The color used to highlight the announcement. If `None`, the channel’s accent color is used.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<AnnouncementColor>,
+}
+
+impl helix::HelixRequestBody for SendChatAnnouncementBody {
+    fn try_to_body(&self) -> Result<Vec<u8>, helix::BodyError> {
+        check_message_length(&self.message, MAX_MESSAGE_LENGTH)?;
+        serde_json::to_vec(self).map_err(Into::into)
+    }
+}
+
+/// The color used to highlight an announcement
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone, Copy)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum AnnouncementColor {
+    /// Blue
+    Blue,
+    /// Green
+    Green,
+    /// Orange
+    Orange,
+    /// Purple
+    Purple,
+    /// The channel’s accent color
+    Primary,
+}
+
+/// Return Values for [Send Chat Announcement](super::send_chat_announcement)
+///
+/// [`send-chat-announcement`](https://dev.twitch.tv/docs/api/reference#send-chat-announcement)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[non_exhaustive]
+pub enum SendChatAnnouncement {
+    /// 204 - Successfully sent the announcement.
+    Success,
+}
+
+impl Request for SendChatAnnouncementRequest {
+    type Response = SendChatAnnouncement;
+
+    const METHOD: http::Method = http::Method::POST;
+    const PATH: &'static str = "chat/announcements";
+    /// Scope needed for this endpoint, not yet known to [`twitch_oauth2`]
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:manage:announcements"),
+    )];
+}
+
+impl RequestPost for SendChatAnnouncementRequest {
+    type Body = SendChatAnnouncementBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
+    where
+        Self: Sized,
+    {
+        match status {
+            http::StatusCode::NO_CONTENT => Ok(helix::Response {
+                data: SendChatAnnouncement::Success,
+                pagination: None,
+                request,
+                total: None,
+                other: None,
+                request_id: None,
+                diagnostics: Default::default(),
+            }),
+            _ => Err(helix::HelixRequestError::InvalidResponse {
+                reason: "unexpected status",
+                response: response.to_string(),
+                status,
+                method: Self::METHOD,
+                uri: uri.clone(),
+                request_id: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = SendChatAnnouncementRequest::builder()
+        .auth(ModeratorAuth::new("11111", "44444"))
+        .build();
+
+    let body = SendChatAnnouncementBody::builder()
+        .message("Hello chat!")
+        .color(AnnouncementColor::Purple)
+        .build();
+
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"message":"Hello chat!","color":"purple"}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/chat/announcements?broadcaster_id=11111&moderator_id=44444"
+    );
+
+    dbg!(SendChatAnnouncementRequest::parse_response(Some(req), &uri, http_response).unwrap());
+}
+
+#[cfg(test)]
+#[test]
+fn test_request_too_long() {
+    use helix::*;
+    let req = SendChatAnnouncementRequest::builder()
+        .auth(ModeratorAuth::new("11111", "44444"))
+        .build();
+
+    let body = SendChatAnnouncementBody::builder()
+        .message("ä".repeat(MAX_MESSAGE_LENGTH))
+        .build();
+    assert!(body.try_to_body().is_ok());
+
+    let body = SendChatAnnouncementBody::builder()
+        .message("a".repeat(MAX_MESSAGE_LENGTH + 1))
+        .build();
+    assert!(matches!(
+        req.create_request(body, "token", "clientid"),
+        Err(CreateRequestError::SerializeError(BodyError::Custom(_)))
+    ));
+}
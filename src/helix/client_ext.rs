@@ -47,6 +47,92 @@ impl<'a, C: crate::HttpClient<'a> + Sync> HelixClient<'a, C> {
         .map(|response| response.first())
     }
 
+    /// Get [User](helix::users::User) from an id, login or display name
+    ///
+    /// [Localized](types::DisplayNameRef::is_localized) display names are resolved by searching for channels with that name.
+    pub async fn get_user<T>(
+        &'a self,
+        user: impl Into<types::UserIdentifier>,
+        token: &T,
+    ) -> Result<Option<helix::users::User>, ClientError<'a, C>>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        match user.into() {
+            types::UserIdentifier::Id(id) => self.get_user_from_id(id, token).await,
+            types::UserIdentifier::Login(login) => self.get_user_from_login(login, token).await,
+            types::UserIdentifier::DisplayName(name) if !name.is_localized() => {
+                self.get_user_from_login(name.as_str().to_lowercase(), token)
+                    .await
+            }
+            types::UserIdentifier::DisplayName(name) => {
+                let channels = self
+                    .req_get(
+                        helix::search::SearchChannelsRequest::builder()
+                            .query(name.as_str())
+                            .build(),
+                        token,
+                    )
+                    .await?
+                    .data;
+                match channels.into_iter().find(|channel| {
+                    channel.display_name.as_str().to_lowercase() == name.as_str().to_lowercase()
+                }) {
+                    Some(channel) => self.get_user_from_id(channel.id, token).await,
+                    None => Ok(None),
+                }
+            }
+        }
+    }
+
+    /// Get the [User](helix::users::User) the token belongs to
+    ///
+    /// Uses the user id of the token, see [`get_followed_streams`](HelixClient::get_followed_streams) for the followed streams of this user.
+    pub async fn me<T>(
+        &'a self,
+        token: &T,
+    ) -> Result<Option<helix::users::User>, ClientError<'a, C>>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        let user_id = token
+            .user_id()
+            .ok_or_else(|| ClientRequestError::Custom("no user_id found on token".into()))?;
+        self.get_user_from_id(user_id, token).await
+    }
+
+    /// Get the [ChannelInformation](helix::channels::ChannelInformation) of the user the token belongs to
+    pub async fn my_channel<T>(
+        &'a self,
+        token: &T,
+    ) -> Result<Option<helix::channels::ChannelInformation>, ClientError<'a, C>>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        let user_id = token
+            .user_id()
+            .ok_or_else(|| ClientRequestError::Custom("no user_id found on token".into()))?;
+        self.get_channel_from_id(user_id, token).await
+    }
+
+    /// Get [ChannelInformation](helix::channels::ChannelInformation) from a broadcasters id, login or display name
+    pub async fn get_channel<T>(
+        &'a self,
+        broadcaster: impl Into<types::UserIdentifier>,
+        token: &T,
+    ) -> Result<Option<helix::channels::ChannelInformation>, ClientError<'a, C>>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        match broadcaster.into() {
+            types::UserIdentifier::Id(id) => self.get_channel_from_id(id, token).await,
+            broadcaster => match self.get_user(broadcaster, token).await? {
+                Some(user) => self.get_channel_from_id(user.id, token).await,
+                None => Ok(None),
+            },
+        }
+    }
+
     /// Get [ChannelInformation](helix::channels::ChannelInformation) from a broadcasters login
     pub async fn get_channel_from_login<T>(
         &'a self,
@@ -82,6 +168,24 @@ impl<'a, C: crate::HttpClient<'a> + Sync> HelixClient<'a, C> {
         .map(|response| response.first())
     }
 
+    /// Get the tags of a channel from a broadcasters id
+    ///
+    /// Returns an empty list if the channel doesn't exist or has no tags. This replaces [Get Stream Tags](helix::streams::GetStreamTagsRequest), as tag IDs are deprecated.
+    pub async fn get_channel_tags<T>(
+        &'a self,
+        broadcaster_id: impl Into<types::UserId>,
+        token: &T,
+    ) -> Result<Vec<String>, ClientError<'a, C>>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        Ok(self
+            .get_channel_from_id(broadcaster_id, token)
+            .await?
+            .map(|channel| channel.tags)
+            .unwrap_or_default())
+    }
+
     /// Search [Categories](helix::search::Category)
     ///
     /// # Examples
@@ -319,6 +423,161 @@ impl<'a, C: crate::HttpClient<'a> + Sync> HelixClient<'a, C> {
         make_stream(req, token, self, std::collections::VecDeque::from)
     }
 
+    /// Get all [channels](helix::moderation::ModeratedChannel) the user of the token is a moderator in
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api2::helix;
+    /// use futures::TryStreamExt;
+    ///
+    /// let channels: Vec<helix::moderation::ModeratedChannel> = client.get_moderated_channels(&token).try_collect().await?;
+    ///
+    /// # Ok(()) }
+    /// ```
+    pub fn get_moderated_channels<T>(
+        &'a self,
+        token: &'a T,
+    ) -> std::pin::Pin<
+        Box<
+            dyn futures::Stream<
+                    Item = Result<helix::moderation::ModeratedChannel, ClientError<'a, C>>,
+                > + 'a,
+        >,
+    >
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        use futures::StreamExt;
+
+        let user_id = match token
+            .user_id()
+            .ok_or_else(|| ClientRequestError::Custom("no user_id found on token".into()))
+        {
+            Ok(t) => t,
+            Err(e) => return futures::stream::once(async { Err(e) }).boxed(),
+        };
+        let req = helix::moderation::GetModeratedChannelsRequest::builder()
+            .user_id(user_id)
+            .build();
+
+        make_stream(req, token, self, std::collections::VecDeque::from)
+    }
+
+    /// Get all [VIPs](helix::channels::Vip) in a channel
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api2::helix;
+    /// use futures::TryStreamExt;
+    ///
+    /// let vips: Vec<helix::channels::Vip> = client.get_vips_in_channel_from_id("1234", &token).try_collect().await?;
+    ///
+    /// # Ok(()) }
+    /// ```
+    pub fn get_vips_in_channel_from_id<T>(
+        &'a self,
+        broadcaster_id: impl Into<types::UserId>,
+        token: &'a T,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Stream<Item = Result<helix::channels::Vip, ClientError<'a, C>>> + 'a>,
+    >
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::channels::GetVipsRequest::builder()
+            .broadcaster_id(broadcaster_id)
+            .build();
+
+        make_stream(req, token, self, std::collections::VecDeque::from)
+    }
+
+    /// Get all [moderator events](helix::moderation::ModeratorEvent) in a channel, see [Get Moderator Events](helix::moderation::GetModeratorEventsRequest)
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api2::helix;
+    /// use futures::TryStreamExt;
+    ///
+    /// let events: Vec<helix::moderation::ModeratorEvent> = client.moderator_events_stream("1234", &token).try_collect().await?;
+    ///
+    /// # Ok(()) }
+    /// ```
+    pub fn moderator_events_stream<T>(
+        &'a self,
+        broadcaster_id: impl Into<types::UserId>,
+        token: &'a T,
+    ) -> std::pin::Pin<
+        Box<
+            dyn futures::Stream<
+                    Item = Result<helix::moderation::ModeratorEvent, ClientError<'a, C>>,
+                > + 'a,
+        >,
+    >
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::moderation::GetModeratorEventsRequest::builder()
+            .broadcaster_id(broadcaster_id)
+            .build();
+
+        make_stream(req, token, self, std::collections::VecDeque::from)
+    }
+
+    /// Get all [ban events](helix::moderation::BannedEvent) in a channel, see [Get Banned Events](helix::moderation::GetBannedEventsRequest)
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api2::helix;
+    /// use futures::TryStreamExt;
+    ///
+    /// let events: Vec<helix::moderation::BannedEvent> = client.banned_events_stream("1234", &token).try_collect().await?;
+    ///
+    /// # Ok(()) }
+    /// ```
+    pub fn banned_events_stream<T>(
+        &'a self,
+        broadcaster_id: impl Into<types::UserId>,
+        token: &'a T,
+    ) -> std::pin::Pin<
+        Box<
+            dyn futures::Stream<Item = Result<helix::moderation::BannedEvent, ClientError<'a, C>>>
+                + 'a,
+        >,
+    >
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::moderation::GetBannedEventsRequest::builder()
+            .broadcaster_id(broadcaster_id)
+            .build();
+
+        make_stream(req, token, self, std::collections::VecDeque::from)
+    }
+
     /// Get a users, with login, follow count
     pub async fn get_total_followers_from_login<T>(
         &'a self,
@@ -362,6 +621,26 @@ impl<'a, C: crate::HttpClient<'a> + Sync> HelixClient<'a, C> {
         Ok(resp.data.total)
     }
 
+    /// Get the total number of followers of a channel, with [Get Channel Followers](helix::channels::get_channel_followers)
+    ///
+    /// The total is returned for any token, even without the `moderator:read:followers` scope.
+    pub async fn get_total_channel_followers<T>(
+        &'a self,
+        broadcaster_id: impl Into<types::UserId>,
+        token: &T,
+    ) -> Result<i64, ClientError<'a, C>>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        let mut req = helix::channels::GetChannelFollowersRequest::builder()
+            .broadcaster_id(broadcaster_id)
+            .build();
+        req.pagination.first = helix::PageSize::new(1).ok();
+        let resp = self.req_get(req, token).await?;
+
+        Ok(resp.total.unwrap_or_default())
+    }
+
     /// Get games by ID. Can only be at max 100 ids.
     pub async fn get_games_by_id<T>(
         &'a self,
@@ -385,12 +664,114 @@ impl<'a, C: crate::HttpClient<'a> + Sync> HelixClient<'a, C> {
             .await?;
 
         Ok(resp
-            .data
             .into_iter()
             .map(|g: helix::games::Game| (g.id.clone(), g))
             .collect())
     }
 
+    /// Check which channels are live, getting their [Stream](helix::streams::Stream) if they are.
+    ///
+    /// Every id in `ids` is a key in the returned map, with `None` if the channel is not live. Requests are made in chunks of 100 ids.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api2::{helix, types};
+    ///
+    /// let ids: Vec<types::UserId> = vec!["44445592".into(), "27620241".into()];
+    /// for (id, stream) in client.are_live(&ids, &token).await? {
+    ///     if let Some(stream) = stream {
+    ///         println!("{} is live: {}", id, stream.title);
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn are_live<T>(
+        &'a self,
+        ids: &[types::UserId],
+        token: &T,
+    ) -> Result<
+        std::collections::HashMap<types::UserId, Option<helix::streams::Stream>>,
+        ClientError<'a, C>,
+    >
+    where
+        T: TwitchToken + ?Sized,
+    {
+        let mut live: std::collections::HashMap<_, _> =
+            ids.iter().map(|id| (id.clone(), None)).collect();
+        for chunk in ids.chunks(100) {
+            let resp = self
+                .req_get(
+                    helix::streams::GetStreamsRequest::builder()
+                        .user_id(chunk.to_vec())
+                        .pagination(
+                            helix::PaginationParams::default()
+                                .with_first(helix::PageSize::new(100).ok()),
+                        )
+                        .build(),
+                    token,
+                )
+                .await?;
+            for stream in resp {
+                live.insert(stream.user_id.clone(), Some(stream));
+            }
+        }
+        Ok(live)
+    }
+
+    /// Search for live channels matching `query`, together with their [Stream](helix::streams::Stream)
+    ///
+    /// Only the first 100 search results are returned. Their streams are requested in one batch with [`are_live`](HelixClient::are_live).
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api2::helix;
+    ///
+    /// for live in client.search_live_channels("twitchdev", &token).await? {
+    ///     if let Some(stream) = &live.stream {
+    ///         println!("{}: {} viewers", live.channel.display_name, stream.viewer_count);
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn search_live_channels<T>(
+        &'a self,
+        query: impl Into<String>,
+        token: &T,
+    ) -> Result<Vec<LiveChannel>, ClientError<'a, C>>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        let req = helix::search::SearchChannelsRequest::builder()
+            .query(query)
+            .live_only(true)
+            .pagination(
+                helix::PaginationParams::default().with_first(helix::PageSize::new(100).ok()),
+            )
+            .build();
+        let channels = self.req_get(req, token).await?.data;
+        let ids: Vec<_> = channels.iter().map(|channel| channel.id.clone()).collect();
+        let mut streams = self.are_live(&ids, token).await?;
+        Ok(channels
+            .into_iter()
+            .map(|channel| LiveChannel {
+                stream: streams.remove(&channel.id).flatten(),
+                channel,
+            })
+            .collect())
+    }
+
     /// Block a user
     pub async fn block_user<T>(
         &'a self,
@@ -478,12 +859,316 @@ impl<'a, C: crate::HttpClient<'a> + Sync> HelixClient<'a, C> {
         make_stream(req, token, self, |broadcasts| broadcasts.segments.into())
     }
 
-    /// Get all global emotes
-    pub async fn get_global_emotes<T>(
-        &'a self,
-        token: &T,
-    ) -> Result<Vec<helix::chat::GlobalEmote>, ClientError<'a, C>>
-    where
+    /// Get the next scheduled stream in a channel, or `None` if it's canceled, within a vacation or the channel has no schedule.
+    ///
+    /// Returns the start time, title and category of the stream, see [`ScheduledBroadcasts::next_segment_after`](helix::schedule::ScheduledBroadcasts::next_segment_after).
+    /// Only the first segment starting after now is requested.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api2::helix;
+    ///
+    /// if let Some((start, title, _category)) = client.get_next_stream("twitchdev", &token).await? {
+    ///     println!("{} is live next at {}", title, start);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "time")]
+    #[cfg_attr(nightly, doc(cfg(feature = "time")))]
+    pub async fn get_next_stream<T>(
+        &'a self,
+        broadcaster_id: impl Into<types::UserId>,
+        token: &T,
+    ) -> Result<
+        Option<(types::Timestamp, String, Option<helix::schedule::Category>)>,
+        ClientError<'a, C>,
+    >
+    where
+        T: TwitchToken + ?Sized,
+    {
+        let now = types::Timestamp::now();
+        let req = helix::schedule::GetChannelStreamScheduleRequest::builder()
+            .broadcaster_id(broadcaster_id)
+            .start_time(now.clone())
+            .pagination(helix::PaginationParams::default().with_first(helix::PageSize::new(1).ok()))
+            .build();
+        let schedule = match self.req_get(req, token).await {
+            Ok(response) => response.data,
+            // channels without a schedule respond with `404 Not Found`
+            Err(ClientRequestError::HelixRequestError(helix::HelixRequestError::Error {
+                status,
+                ..
+            })) if status == http::StatusCode::NOT_FOUND => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        Ok(schedule.next_segment_after(&now).map(|segment| {
+            (
+                segment.start_time.clone(),
+                segment.title.clone(),
+                segment.category.clone(),
+            )
+        }))
+    }
+
+    /// Get a [CustomReward](helix::points::CustomReward) of a channel from its id
+    ///
+    /// Useful for getting the full reward of a redemption received through EventSub, which only contains basic information about the reward.
+    pub async fn get_custom_reward_from_id<T>(
+        &'a self,
+        broadcaster_id: impl Into<types::UserId>,
+        reward_id: impl Into<types::RewardId>,
+        token: &T,
+    ) -> Result<Option<helix::points::CustomReward>, ClientError<'a, C>>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        self.req_get(
+            helix::points::GetCustomRewardRequest::builder()
+                .broadcaster_id(broadcaster_id)
+                .id(vec![reward_id.into()])
+                .build(),
+            token,
+        )
+        .await
+        .map(|response| response.first())
+    }
+
+    /// Get the [Poll](helix::polls::Poll) currently running in a channel, if any
+    ///
+    /// Polls are returned most recent first, so only the first page is checked.
+    pub async fn active_poll<T>(
+        &'a self,
+        broadcaster_id: impl Into<types::UserId>,
+        token: &T,
+    ) -> Result<Option<helix::polls::Poll>, ClientError<'a, C>>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        let req = helix::polls::GetPollsRequest::builder()
+            .broadcaster_id(broadcaster_id)
+            .build();
+        Ok(self
+            .req_get(req, token)
+            .await?
+            .into_iter()
+            .find(|poll| poll.status.is_active()))
+    }
+
+    /// Get the [Prediction](helix::predictions::Prediction) currently running in a channel, if any
+    ///
+    /// A prediction is running while it is [active or locked](types::PredictionStatus::is_running).
+    /// Predictions are returned most recent first, so only the first page is checked.
+    pub async fn active_prediction<T>(
+        &'a self,
+        broadcaster_id: impl Into<types::UserId>,
+        token: &T,
+    ) -> Result<Option<helix::predictions::Prediction>, ClientError<'a, C>>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        let req = helix::predictions::GetPredictionsRequest::builder()
+            .broadcaster_id(broadcaster_id)
+            .build();
+        Ok(self
+            .req_get(req, token)
+            .await?
+            .into_iter()
+            .find(|prediction| prediction.status.is_running()))
+    }
+
+    /// Get the bits leaderboard of the channel the token belongs to, together with the cheermotes of that channel
+    ///
+    /// See [`GetBitsLeaderboardRequest::period`](helix::bits::GetBitsLeaderboardRequest::period) for the periods.
+    pub async fn get_bits_summary<T>(
+        &'a self,
+        period: helix::bits::LeaderboardPeriod,
+        token: &T,
+    ) -> Result<helix::bits::BitsSummary, ClientError<'a, C>>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        let broadcaster_id: types::UserId = token
+            .user_id()
+            .ok_or_else(|| ClientRequestError::Custom("no user_id found on token".into()))?
+            .into();
+        let leaderboard = self
+            .req_get(
+                helix::bits::GetBitsLeaderboardRequest::builder()
+                    .period(period)
+                    .build(),
+                token,
+            )
+            .await?
+            .data;
+        let cheermotes = self
+            .req_get(
+                helix::bits::GetCheermotesRequest::builder()
+                    .broadcaster_id(broadcaster_id)
+                    .build(),
+                token,
+            )
+            .await?
+            .data;
+        Ok(helix::bits::BitsSummary::new(leaderboard, cheermotes))
+    }
+
+    /// Get a [snapshot](DashboardSnapshot) of the channel the token belongs to, for streamer dashboards
+    ///
+    /// The channel information, stream, latest followers, active poll and active prediction are requested concurrently.
+    /// The token needs the `moderator:read:followers`, `channel:read:polls` and `channel:read:predictions` scopes.
+    /// Polls and predictions are only available to partners and affiliates, if they can't be requested the error is recorded in
+    /// [`poll_error`](DashboardSnapshot::poll_error) and [`prediction_error`](DashboardSnapshot::prediction_error) instead of failing the snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api2::helix;
+    ///
+    /// let snapshot = client.dashboard_snapshot(&token).await?;
+    /// if let Some(stream) = &snapshot.stream {
+    ///     println!("live with {} viewers", stream.viewer_count);
+    /// }
+    /// println!("{} followers", snapshot.total_followers);
+    /// if let Some(error) = &snapshot.poll_error {
+    ///     println!("could not get poll: {}", error);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn dashboard_snapshot<T>(
+        &'a self,
+        token: &T,
+    ) -> Result<DashboardSnapshot, ClientError<'a, C>>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        let user_id: types::UserId = token
+            .user_id()
+            .ok_or_else(|| ClientRequestError::Custom("no user_id found on token".into()))?
+            .into();
+        let stream = async {
+            self.req_get(
+                helix::streams::GetStreamsRequest::builder()
+                    .user_id(vec![user_id.clone()])
+                    .build(),
+                token,
+            )
+            .await
+            .map(|response| response.first())
+        };
+        let followers = async {
+            self.req_get(
+                helix::channels::GetChannelFollowersRequest::builder()
+                    .broadcaster_id(user_id.clone())
+                    .build(),
+                token,
+            )
+            .await
+        };
+        let (channel, stream, followers, poll, prediction) = futures::join!(
+            self.get_channel_from_id(user_id.clone(), token),
+            stream,
+            followers,
+            self.active_poll(user_id.clone(), token),
+            self.active_prediction(user_id.clone(), token),
+        );
+        let followers = followers?;
+        let (poll, poll_error) = best_effort(poll)?;
+        let (prediction, prediction_error) = best_effort(prediction)?;
+        Ok(DashboardSnapshot {
+            channel: channel?,
+            stream: stream?,
+            total_followers: followers.total.unwrap_or_default(),
+            followers: followers.data,
+            poll,
+            poll_error,
+            prediction,
+            prediction_error,
+        })
+    }
+
+    /// Get the [roles](ChannelRoles) of users in a channel, for permission checks
+    ///
+    /// The moderators, VIPs and editors of the channel are requested concurrently.
+    /// The token must be a user access token of the broadcaster with the `moderation:read`, `channel:read:vips` and `channel:read:editors` scopes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api2::{helix, types};
+    ///
+    /// let roles = client.get_channel_roles("1234", &token).await?;
+    /// if roles.get(types::UserIdRef::from_str("5678")).moderator {
+    ///     println!("user is a moderator");
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_channel_roles<T>(
+        &'a self,
+        broadcaster_id: impl Into<types::UserId>,
+        token: &'a T,
+    ) -> Result<ChannelRoles, ClientError<'a, C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        use futures::TryStreamExt;
+
+        let broadcaster_id = broadcaster_id.into();
+        let editors = async {
+            self.req_get(
+                helix::channels::GetChannelEditorsRequest::builder()
+                    .broadcaster_id(broadcaster_id.clone())
+                    .build(),
+                token,
+            )
+            .await
+            .map(|response| response.data)
+        };
+        let (moderators, vips, editors): (
+            Vec<helix::moderation::Moderator>,
+            Vec<helix::channels::Vip>,
+            _,
+        ) = futures::try_join!(
+            self.get_moderators_in_channel_from_id(broadcaster_id.clone(), token)
+                .try_collect(),
+            self.get_vips_in_channel_from_id(broadcaster_id.clone(), token)
+                .try_collect(),
+            editors,
+        )?;
+        let mut roles = ChannelRoles::default();
+        for moderator in moderators {
+            roles.roles.entry(moderator.user_id).or_default().moderator = true;
+        }
+        for vip in vips {
+            roles.roles.entry(vip.user_id).or_default().vip = true;
+        }
+        for editor in editors {
+            roles.roles.entry(editor.user_id).or_default().editor = true;
+        }
+        Ok(roles)
+    }
+
+    /// Get all global emotes
+    pub async fn get_global_emotes<T>(
+        &'a self,
+        token: &T,
+    ) -> Result<Vec<helix::chat::GlobalEmote>, ClientError<'a, C>>
+    where
         T: TwitchToken + ?Sized,
     {
         let req = helix::chat::GetGlobalEmotesRequest::builder().build();
@@ -537,6 +1222,406 @@ impl<'a, C: crate::HttpClient<'a> + Sync> HelixClient<'a, C> {
             .build();
         Ok(self.req_get(req, token).await?.data)
     }
+
+    /// Recreate `subscriptions` on the websocket session `session_id`
+    ///
+    /// Twitch doesn't carry subscriptions over to a new session when reconnecting after the connection was lost,
+    /// pass the subscriptions of the old session, e.g. from [`get_eventsub_subscriptions`](helix::eventsub::get_eventsub_subscriptions),
+    /// to subscribe to the same events again. The result of each subscription is returned in the same order as `subscriptions`.
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// # let old_subscriptions: Vec<twitch_api2::eventsub::EventSubSubscription> = vec![];
+    /// use twitch_api2::helix;
+    ///
+    /// for (subscription, result) in old_subscriptions.iter().zip(
+    ///     client
+    ///         .resubscribe_to_session(&old_subscriptions, "AQoQexAWVYKSTIu4ec_2VAxyuhAB", &token)
+    ///         .await,
+    /// ) {
+    ///     if let Err(e) = result {
+    ///         println!("could not resubscribe to {}: {}", subscription.type_, e);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "eventsub")]
+    #[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
+    pub async fn resubscribe_to_session<T>(
+        &'a self,
+        subscriptions: &[crate::eventsub::EventSubSubscription],
+        session_id: &str,
+        token: &T,
+    ) -> Vec<Result<crate::eventsub::EventSubSubscription, ClientError<'a, C>>>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        let mut results = Vec::with_capacity(subscriptions.len());
+        for subscription in subscriptions {
+            results.push(
+                self.create_websocket_subscription(
+                    subscription.type_.clone(),
+                    subscription.version.clone(),
+                    subscription.condition.clone(),
+                    session_id,
+                    token,
+                )
+                .await,
+            );
+        }
+        results
+    }
+
+    /// Create a subscription from its untyped definition on the websocket session `session_id`
+    #[cfg(feature = "eventsub")]
+    pub(crate) async fn create_websocket_subscription<T>(
+        &'a self,
+        type_: crate::eventsub::EventType,
+        version: String,
+        condition: serde_json::Value,
+        session_id: &str,
+        token: &T,
+    ) -> Result<crate::eventsub::EventSubSubscription, ClientError<'a, C>>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        let body = resubscribe::ResubscribeBody {
+            type_,
+            version,
+            condition,
+            transport: crate::eventsub::Transport::websocket(session_id),
+        };
+        self.req_post(resubscribe::ResubscribeRequest {}, body, token)
+            .await
+            .map(|response| response.data)
+    }
+
+    /// Create an EventSub subscription, treating an existing subscription as an [outcome](helix::eventsub::CreateEventSubSubscriptionOutcome) instead of an error
+    ///
+    /// If `fetch_existing` is set and the subscription already exists, all subscriptions are fetched to find the existing subscription.
+    /// This makes it easy to subscribe on every startup, without deleting the subscriptions on shutdown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::AppAccessToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api2::{eventsub, helix, types::CallbackUrl};
+    ///
+    /// let outcome = client
+    ///     .create_eventsub_subscription(
+    ///         eventsub::user::UserUpdateV1::builder().user_id("1234").build(),
+    ///         eventsub::Transport::webhook_url(
+    ///             CallbackUrl::new("https://example.com/eventsub")?,
+    ///             "secretabcd".to_string(),
+    ///         ),
+    ///         true,
+    ///         &token,
+    ///     )
+    ///     .await?;
+    /// println!("subscription {:?}", outcome.id());
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "eventsub")]
+    #[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
+    pub async fn create_eventsub_subscription<E, T>(
+        &'a self,
+        subscription: E,
+        transport: crate::eventsub::Transport,
+        fetch_existing: bool,
+        token: &'a T,
+    ) -> Result<helix::eventsub::CreateEventSubSubscriptionOutcome<E>, ClientError<'a, C>>
+    where
+        E: crate::eventsub::EventSubscription + Send,
+        T: TwitchToken + Send + Sync + ?Sized,
+        C: Send,
+    {
+        use futures::TryStreamExt;
+        use helix::eventsub::{
+            CreateEventSubSubscriptionBody, CreateEventSubSubscriptionError,
+            CreateEventSubSubscriptionOutcome, CreateEventSubSubscriptionRequest,
+        };
+
+        let body = CreateEventSubSubscriptionBody::new(subscription, transport);
+        let req = CreateEventSubSubscriptionRequest::default();
+        match self.req_post(req, body.clone(), token).await {
+            Ok(response) => return Ok(CreateEventSubSubscriptionOutcome::Created(response.data)),
+            Err(ClientRequestError::HelixRequestError(e))
+                if CreateEventSubSubscriptionError::from_helix_error(&e)
+                    == Some(CreateEventSubSubscriptionError::SubscriptionAlreadyExists) => {}
+            Err(e) => return Err(e),
+        }
+        if !fetch_existing {
+            return Ok(
+                CreateEventSubSubscriptionOutcome::SubscriptionAlreadyExists { existing: None },
+            );
+        }
+        let req = helix::eventsub::GetEventSubSubscriptionsRequest::builder().build();
+        let existing = make_stream(req, token, self, |s| s.subscriptions.into())
+            .try_filter(|s| futures::future::ready(body.is_same_subscription(s)))
+            .try_next()
+            .await?;
+        Ok(CreateEventSubSubscriptionOutcome::SubscriptionAlreadyExists { existing })
+    }
+
+    /// Delete all EventSub subscriptions matching `filter`
+    ///
+    /// All pages of subscriptions are fetched before anything is deleted,
+    /// then at most [`EVENTSUB_DELETE_CONCURRENCY`] subscriptions are deleted at once.
+    /// Failing to fetch the subscriptions is an error, failing to delete a subscription is recorded in the [report](DeletedEventSubSubscriptions).
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::AppAccessToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api2::{eventsub, helix};
+    ///
+    /// let report = client
+    ///     .delete_eventsub_subscriptions_where(
+    ///         |subscription| subscription.status == eventsub::Status::WebsocketDisconnected,
+    ///         &token,
+    ///     )
+    ///     .await?;
+    /// println!(
+    ///     "deleted {}, failed {}",
+    ///     report.deleted.len(),
+    ///     report.failed.len()
+    /// );
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "eventsub")]
+    #[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
+    pub async fn delete_eventsub_subscriptions_where<T>(
+        &'a self,
+        filter: impl Fn(&crate::eventsub::EventSubSubscription) -> bool,
+        token: &'a T,
+    ) -> Result<DeletedEventSubSubscriptions<<C as crate::HttpClient<'a>>::Error>, ClientError<'a, C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+        C: Send,
+    {
+        use futures::{StreamExt, TryStreamExt};
+
+        let req = helix::eventsub::GetEventSubSubscriptionsRequest::builder().build();
+        let subscriptions: Vec<crate::eventsub::EventSubSubscription> =
+            make_stream(req, token, self, |s| s.subscriptions.into())
+                .try_collect()
+                .await?;
+        let total = subscriptions.len();
+        let matching: Vec<_> = subscriptions.into_iter().filter(|s| filter(s)).collect();
+        let mut report = DeletedEventSubSubscriptions {
+            kept: total - matching.len(),
+            deleted: vec![],
+            failed: vec![],
+        };
+        let mut deletions = futures::stream::iter(matching)
+            .map(|subscription| async move {
+                let req = helix::eventsub::DeleteEventSubSubscriptionRequest::builder()
+                    .id(subscription.id.clone())
+                    .build();
+                let result = self.req_delete(req, token).await;
+                (subscription, result)
+            })
+            .buffer_unordered(EVENTSUB_DELETE_CONCURRENCY);
+        while let Some((subscription, result)) = deletions.next().await {
+            match result {
+                Ok(_) => report.deleted.push(subscription),
+                Err(e) => report.failed.push((subscription, e)),
+            }
+        }
+        Ok(report)
+    }
+}
+
+/// Maximum amount of subscriptions [`HelixClient::delete_eventsub_subscriptions_where`] deletes at once
+#[cfg(feature = "eventsub")]
+pub const EVENTSUB_DELETE_CONCURRENCY: usize = 10;
+
+/// Report of [`HelixClient::delete_eventsub_subscriptions_where`]
+#[cfg(feature = "eventsub")]
+#[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct DeletedEventSubSubscriptions<RE: std::error::Error + Send + Sync + 'static> {
+    /// Subscriptions that were deleted.
+    pub deleted: Vec<crate::eventsub::EventSubSubscription>,
+    /// Subscriptions that matched but could not be deleted, with the error.
+    pub failed: Vec<(
+        crate::eventsub::EventSubSubscription,
+        ClientRequestError<RE>,
+    )>,
+    /// Amount of subscriptions that didn't match the filter.
+    pub kept: usize,
+}
+
+/// Untyped [Create EventSub Subscription](helix::eventsub::create_eventsub_subscription), used by [`HelixClient::resubscribe_to_session`]
+#[cfg(feature = "eventsub")]
+mod resubscribe {
+    use crate::eventsub::{EventSubSubscription, EventType, Transport};
+    use crate::helix;
+    use serde::Serialize;
+
+    #[derive(PartialEq, Serialize, Clone, Debug)]
+    pub struct ResubscribeRequest {}
+
+    #[derive(Serialize, Debug)]
+    pub struct ResubscribeBody {
+        #[serde(rename = "type")]
+        pub type_: EventType,
+        pub version: String,
+        pub condition: serde_json::Value,
+        pub transport: Transport,
+    }
+
+    impl helix::private::SealedSerialize for ResubscribeBody {}
+
+    impl helix::Request for ResubscribeRequest {
+        type Response = EventSubSubscription;
+
+        const METHOD: http::Method = http::Method::POST;
+        const PATH: &'static str = "eventsub/subscriptions";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+    }
+
+    impl helix::RequestPost for ResubscribeRequest {
+        type Body = ResubscribeBody;
+
+        fn parse_inner_response(
+            request: Option<Self>,
+            uri: &http::Uri,
+            text: &str,
+            status: http::StatusCode,
+        ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
+        where
+            Self: Sized,
+        {
+            let response: helix::InnerResponse<Vec<EventSubSubscription>> =
+                helix::parse_json_owned(text, true).map_err(|e| {
+                    helix::HelixRequestError::DeserializeError(
+                        text.to_string(),
+                        e,
+                        Self::METHOD,
+                        uri.clone(),
+                        status,
+                        None,
+                    )
+                })?;
+            let data = response.data.into_iter().next().ok_or_else(|| {
+                helix::HelixRequestError::InvalidResponse {
+                    reason: "missing response data",
+                    response: text.to_string(),
+                    status,
+                    method: Self::METHOD,
+                    uri: uri.clone(),
+                    request_id: None,
+                }
+            })?;
+            Ok(helix::Response {
+                data,
+                pagination: None,
+                request,
+                total: None,
+                other: None,
+                request_id: None,
+                diagnostics: Default::default(),
+            })
+        }
+    }
+}
+
+/// A snapshot of a broadcaster's channel, returned by [`HelixClient::dashboard_snapshot`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct DashboardSnapshot {
+    /// Information about the channel.
+    pub channel: Option<helix::channels::ChannelInformation>,
+    /// The current stream, `None` if the channel is offline.
+    pub stream: Option<helix::streams::Stream>,
+    /// The latest followers of the channel.
+    pub followers: Vec<helix::channels::Follower>,
+    /// The total amount of followers.
+    pub total_followers: i64,
+    /// The [active poll](HelixClient::active_poll), if any.
+    pub poll: Option<helix::polls::Poll>,
+    /// Why the poll could not be requested, e.g. because the channel can't have polls.
+    pub poll_error: Option<helix::HelixRequestError>,
+    /// The [running prediction](HelixClient::active_prediction), if any.
+    pub prediction: Option<helix::predictions::Prediction>,
+    /// Why the prediction could not be requested, e.g. because the channel can't have predictions.
+    pub prediction_error: Option<helix::HelixRequestError>,
+}
+
+/// Turn errors returned by helix into a recorded error, for parts of a response that are optional
+fn best_effort<T, E>(
+    result: Result<Option<T>, ClientRequestError<E>>,
+) -> Result<(Option<T>, Option<helix::HelixRequestError>), ClientRequestError<E>>
+where E: std::error::Error + Send + Sync + 'static {
+    match result {
+        Ok(value) => Ok((value, None)),
+        Err(ClientRequestError::HelixRequestError(e)) => Ok((None, Some(e))),
+        Err(e) => Err(e),
+    }
+}
+
+/// A channel found by [`HelixClient::search_live_channels`], with its stream
+#[derive(PartialEq, Debug, Clone)]
+#[non_exhaustive]
+pub struct LiveChannel {
+    /// The search result.
+    pub channel: helix::search::Channel,
+    /// The stream of the channel, `None` if it went offline after the search.
+    pub stream: Option<helix::streams::Stream>,
+}
+
+impl LiveChannel {
+    /// Time since the stream started, `None` if the channel is offline
+    #[cfg(feature = "time")]
+    #[cfg_attr(nightly, doc(cfg(feature = "time")))]
+    pub fn uptime(&self) -> Option<time::Duration> {
+        self.stream
+            .as_ref()
+            .map(|stream| time::OffsetDateTime::now_utc() - stream.started_at.to_utc())
+    }
+}
+
+/// Roles of users in a channel, returned by [`HelixClient::get_channel_roles`]
+#[derive(PartialEq, Eq, Default, Debug, Clone)]
+#[non_exhaustive]
+pub struct ChannelRoles {
+    /// The roles of every user with at least one role.
+    pub roles: std::collections::HashMap<types::UserId, Roles>,
+}
+
+impl ChannelRoles {
+    /// The roles of `user_id`, no roles if the user has none.
+    pub fn get(&self, user_id: &types::UserIdRef) -> Roles {
+        self.roles.get(user_id).copied().unwrap_or_default()
+    }
+}
+
+/// Roles of a user in a channel, see [`ChannelRoles`]
+#[derive(PartialEq, Eq, Default, Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct Roles {
+    /// The user is a moderator.
+    pub moderator: bool,
+    /// The user is a VIP.
+    pub vip: bool,
+    /// The user is an editor.
+    pub editor: bool,
 }
 
 /// Make a paginate-able request into a stream
@@ -714,3 +1799,62 @@ where
     })
     .boxed()
 }
+
+#[cfg(all(test, feature = "time"))]
+#[tokio::test]
+async fn test_get_next_stream() {
+    use crate::client::{BoxedFuture, Req, Response};
+
+    /// Client answering schedule requests for the broadcaster `1234`, and `404 Not Found` for everyone else
+    struct ScheduleClient;
+
+    impl<'a> crate::HttpClient<'a> for ScheduleClient {
+        type Error = crate::DummyHttpClient;
+
+        fn req(&'a self, request: Req) -> BoxedFuture<'a, Result<Response, Self::Error>> {
+            let query = request.uri().query().unwrap_or_default();
+            assert!(query.contains("first=1"), "{}", query);
+            assert!(query.contains("start_time="), "{}", query);
+            let response = if query.contains("broadcaster_id=1234") {
+                http::Response::builder()
+                    .body(
+                        br#"{"data":{"segments":[{"id":"eyJzZWdtZW50SUQiOiJlNGFjYzcyNC0zNzFmLTQwMmMtODFjYS0yM2FkYTc5NzU5ZDQiLCJpc29ZZWFyIjoyMDIxLCJpc29XZWVrIjoyNn0=","start_time":"2999-07-01T18:00:00Z","end_time":"2999-07-01T19:00:00Z","title":"TwitchDev Monthly Update // July 1, 2021","canceled_until":null,"category":{"id":"509670","name":"Science & Technology"},"is_recurring":false}],"broadcaster_id":"1234","broadcaster_name":"TwitchDev","broadcaster_login":"twitchdev","vacation":null},"pagination":{"cursor":"eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6ImV5SnpaV2R0Wlc1MFNVUWlPaUpsTkdGall6Y3lOQzB6TnpGbUxUUXdNbU10T0RGallTMHlNMkZrWVRjNU56VTVaRFFpTENKcGMyOVpaV0Z5SWpveU1ESXhMQ0pwYzI5WFpXVnJJam95Tm4wPSJ9fQ"}}"#
+                            .to_vec(),
+                    )
+                    .unwrap()
+            } else {
+                http::Response::builder()
+                    .status(http::StatusCode::NOT_FOUND)
+                    .body(
+                        br#"{"error":"Not Found","status":404,"message":"segments were either not found or have been deleted"}"#
+                            .to_vec(),
+                    )
+                    .unwrap()
+            };
+            Box::pin(async { Ok(response) })
+        }
+    }
+
+    let token = twitch_oauth2::UserToken::from_existing_unchecked(
+        twitch_oauth2::AccessToken::new("token".to_string()),
+        None,
+        twitch_oauth2::ClientId::new("clientid".to_string()),
+        None,
+        "twitchdev".to_string(),
+        "1234".to_string(),
+        None,
+        None,
+    );
+    let client = HelixClient::with_client(ScheduleClient);
+
+    let (start, title, category) = client
+        .get_next_stream("1234", &token)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(start.as_str(), "2999-07-01T18:00:00Z");
+    assert_eq!(title, "TwitchDev Monthly Update // July 1, 2021");
+    assert_eq!(category.unwrap().name, "Science & Technology");
+    // a channel without a schedule
+    assert_eq!(client.get_next_stream("4321", &token).await.unwrap(), None);
+}
@@ -44,6 +44,7 @@ use helix::RequestGet;
 ///
 /// [`get-clips`](https://dev.twitch.tv/docs/api/reference#get-clips)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetClipsRequest {
     /// ID of the broadcaster for whom clips are returned. The number of clips returned is determined by the first query-string parameter (default: 20). Results are ordered by view count.
@@ -56,20 +57,15 @@ pub struct GetClipsRequest {
     #[builder(default)]
     pub id: Vec<String>,
     // one of above is needed.
-    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. This applies only to queries specifying broadcaster_id or game_id. The cursor value specified here is from the pagination response field of a prior query.
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
     #[builder(default)]
-    pub after: Option<helix::Cursor>,
-    /// Cursor for backward pagination: tells the server where to start fetching the next set of results, in a multi-page response. This applies only to queries specifying broadcaster_id or game_id. The cursor value specified here is from the pagination response field of a prior query.
-    #[builder(default)]
-    pub before: Option<helix::Cursor>,
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
     /// Ending date/time for returned clips, in RFC3339 format. (Note that the seconds value is ignored.) If this is specified, started_at also must be specified; otherwise, the time period is ignored.
-    #[builder(default)]
-    pub ended_at: Option<types::Timestamp>,
-    /// Maximum number of objects to return. Maximum: 100. Default: 20.
     #[builder(default, setter(into))]
-    pub first: Option<usize>,
+    pub ended_at: Option<types::Timestamp>,
     /// Starting date/time for returned clips, in RFC3339 format. (Note that the seconds value is ignored.) If this is specified, ended_at also should be specified; otherwise, the ended_at date/time will be 1 week after the started_at value.
-    #[builder(default)]
+    #[builder(default, setter(into))]
     pub started_at: Option<types::Timestamp>,
 }
 
@@ -77,6 +73,11 @@ pub struct GetClipsRequest {
 ///
 /// [`get-clips`](https://dev.twitch.tv/docs/api/reference#get-clips)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Clip {
@@ -115,15 +116,18 @@ pub struct Clip {
 impl Request for GetClipsRequest {
     type Response = Vec<Clip>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "clips";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for GetClipsRequest {}
 
 impl helix::Paginated for GetClipsRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
 }
 
 #[cfg(test)]
@@ -135,30 +139,7 @@ fn test_request() {
         .build();
 
     // From twitch docs
-    let data = br#"
-{
-    "data": [
-      {
-        "id": "AwkwardHelplessSalamanderSwiftRage",
-        "url": "https://clips.twitch.tv/AwkwardHelplessSalamanderSwiftRage",
-        "embed_url": "https://clips.twitch.tv/embed?clip=AwkwardHelplessSalamanderSwiftRage",
-        "broadcaster_id": "67955580",
-        "broadcaster_name": "ChewieMelodies",
-        "creator_id": "53834192",
-        "creator_name": "BlackNova03",
-        "video_id": "205586603",
-        "game_id": "488191",
-        "language": "en",
-        "title": "babymetal",
-        "view_count": 10,
-        "created_at": "2017-11-30T22:34:18Z",
-        "thumbnail_url": "https://clips-media-assets.twitch.tv/157589949-preview-480x272.jpg",
-        "duration": 60
-      }
-    ]
-}
-"#
-    .to_vec();
+    let data = crate::fixtures::helix::clips::GET_CLIPS.as_bytes().to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
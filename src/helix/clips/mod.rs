@@ -3,7 +3,7 @@
 //! # Examples
 //!
 //! ```rust,no_run
-//! # use twitch_api2::helix::{HelixClient, clips::GetClipsRequest};
+//! # use twitch_api2::helix::{HelixClient, PageSize, PaginationParams, clips::GetClipsRequest};
 //! # #[tokio::main]
 //! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 //! let client = HelixClient::new();
@@ -12,7 +12,7 @@
 //! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
 //! let req = GetClipsRequest::builder()
 //!     .game_id(Some("1234".into()))
-//!     .first(100) // max 100, 20 if left unspecified
+//!     .pagination(PaginationParams::default().with_first(PageSize::new(100)?)) // max 100, 20 if left unspecified
 //!     .build();
 //!
 //! println!("{:?}", &client.req_get(req, &token).await?.data.get(0));
@@ -0,0 +1,241 @@
+//! Conditional requests with `ETag` and `If-None-Match`.
+//!
+//! Some GET endpoints return an `ETag` header, a validator for the returned data. Sending it back in `If-None-Match` makes Twitch answer with `304 Not Modified`
+//! and an empty body if nothing changed, which makes polling endpoints like [Get Channel Information](super::channels::get_channel_information) cheap.
+//!
+//! [`HelixClient::req_get_conditional`] sends a single conditional request, [`ConditionalRequest`] remembers the validator between polls.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! use twitch_api2::helix::{self, conditional::ConditionalRequest};
+//!
+//! let req = helix::channels::GetChannelInformationRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! let mut req = ConditionalRequest::new(req);
+//! loop {
+//!     if let Some(response) = req.poll(&client, &token).await? {
+//!         println!("channel changed: {:?}", response.data);
+//!     }
+//!     tokio::time::sleep(std::time::Duration::from_secs(30)).await;
+//! }
+//! # }
+//! ```
+
+use super::{ClientRequestError, HelixClient, Request, RequestGet, Response};
+use twitch_oauth2::TwitchToken;
+
+/// Response to a conditional request, see [`HelixClient::req_get_conditional`]
+#[derive(PartialEq, Debug, Clone)]
+pub enum Conditional<T> {
+    /// The data changed, or no validator was sent
+    Modified {
+        /// The response
+        response: T,
+        /// Validator for the response, to send with the next request
+        etag: Option<String>,
+    },
+    /// 304 - The data did not change since the validator was returned
+    NotModified,
+}
+
+impl<T> Conditional<T> {
+    /// The data did not change
+    pub fn is_not_modified(&self) -> bool { matches!(self, Conditional::NotModified) }
+
+    /// The response, if the data changed
+    pub fn modified(self) -> Option<T> {
+        match self {
+            Conditional::Modified { response, .. } => Some(response),
+            Conditional::NotModified => None,
+        }
+    }
+
+    /// The returned validator, if the data changed
+    pub fn etag(&self) -> Option<&str> {
+        match self {
+            Conditional::Modified { etag, .. } => etag.as_deref(),
+            Conditional::NotModified => None,
+        }
+    }
+}
+
+impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
+    /// Request on a valid [`RequestGet`] endpoint, sending `etag` in `If-None-Match`.
+    ///
+    /// Returns [`Conditional::NotModified`] if Twitch responds with `304 Not Modified`.
+    pub async fn req_get_conditional<R, D, T>(
+        &'a self,
+        request: R,
+        etag: Option<&str>,
+        token: &T,
+    ) -> Result<Conditional<Response<R, D>>, ClientRequestError<<C as crate::HttpClient<'a>>::Error>>
+    where
+        R: Request<Response = D> + RequestGet,
+        D: serde::de::DeserializeOwned + PartialEq,
+        T: TwitchToken + ?Sized,
+        C: Send,
+    {
+        let mut req = request.create_request(token.token().secret(), token.client_id().as_str())?;
+        if let Some(etag) = etag {
+            let etag = http::HeaderValue::from_str(etag).map_err(|_| {
+                ClientRequestError::Custom("etag is not a valid header value".into())
+            })?;
+            req.headers_mut().insert(http::header::IF_NONE_MATCH, etag);
+        }
+        let uri = req.uri().clone();
+        let response = self.send::<R>(req).await?;
+        if response.status() == http::StatusCode::NOT_MODIFIED {
+            return Ok(Conditional::NotModified);
+        }
+        let etag = response
+            .headers()
+            .get(http::header::ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(ToOwned::to_owned);
+        let response = <R>::parse_response(Some(request), &uri, response)?;
+        Ok(Conditional::Modified { response, etag })
+    }
+}
+
+/// A [`RequestGet`] that remembers the validator of its last response, for polling.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ConditionalRequest<R> {
+    request: R,
+    etag: Option<String>,
+}
+
+impl<R: RequestGet + Clone> ConditionalRequest<R> {
+    /// Poll `request` conditionally
+    pub fn new(request: R) -> Self {
+        ConditionalRequest {
+            request,
+            etag: None,
+        }
+    }
+
+    /// The validator sent with the next poll
+    pub fn etag(&self) -> Option<&str> { self.etag.as_deref() }
+
+    /// The polled request
+    pub fn request(&self) -> &R { &self.request }
+
+    /// Send the request, returning [`None`] if the data did not change since the last poll.
+    pub async fn poll<'a, C, D, T>(
+        &mut self,
+        client: &'a HelixClient<'a, C>,
+        token: &T,
+    ) -> Result<Option<Response<R, D>>, ClientRequestError<<C as crate::HttpClient<'a>>::Error>>
+    where
+        R: Request<Response = D>,
+        C: crate::HttpClient<'a> + Send,
+        D: serde::de::DeserializeOwned + PartialEq,
+        T: TwitchToken + ?Sized,
+    {
+        match client
+            .req_get_conditional(self.request.clone(), self.etag.as_deref(), token)
+            .await?
+        {
+            Conditional::Modified { response, etag } => {
+                self.etag = etag;
+                Ok(Some(response))
+            }
+            Conditional::NotModified => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_conditional() {
+    use crate::client::{BoxedFuture, Req, Response};
+    use std::sync::Mutex;
+
+    /// Client with a channel that never changes, recording the `If-None-Match` headers it receives
+    struct EtagClient(Mutex<Vec<Option<String>>>);
+
+    impl<'a> crate::HttpClient<'a> for EtagClient {
+        type Error = crate::DummyHttpClient;
+
+        fn req(&'a self, request: Req) -> BoxedFuture<'a, Result<Response, Self::Error>> {
+            let if_none_match = request
+                .headers()
+                .get(http::header::IF_NONE_MATCH)
+                .map(|etag| etag.to_str().unwrap().to_owned());
+            let response = if if_none_match.as_deref() == Some("\"v1\"") {
+                http::Response::builder()
+                    .status(http::StatusCode::NOT_MODIFIED)
+                    .body(vec![])
+                    .unwrap()
+            } else {
+                http::Response::builder()
+                    .header(http::header::ETAG, "\"v1\"")
+                    .body(
+                        br#"{"data":[{"broadcaster_id":"44445592","broadcaster_name":"pokimane","broadcaster_login":"pokimane","broadcaster_language":"en","game_id":"21779","game_name":"League of Legends","title":"title","delay":0,"tags":[]}]}"#
+                            .to_vec(),
+                    )
+                    .unwrap()
+            };
+            self.0.lock().unwrap().push(if_none_match);
+            Box::pin(async { Ok(response) })
+        }
+    }
+
+    let token = twitch_oauth2::UserToken::from_existing_unchecked(
+        twitch_oauth2::AccessToken::new("token".to_string()),
+        None,
+        twitch_oauth2::ClientId::new("clientid".to_string()),
+        None,
+        "justintv".to_string(),
+        "1337".to_string(),
+        None,
+        None,
+    );
+    let client = HelixClient::with_client(EtagClient(Mutex::default()));
+    let req = super::channels::GetChannelInformationRequest::builder()
+        .broadcaster_id("44445592")
+        .build();
+
+    let response = client
+        .req_get_conditional(req.clone(), None, &token)
+        .await
+        .unwrap();
+    assert_eq!(response.etag(), Some("\"v1\""));
+    assert_eq!(
+        response
+            .modified()
+            .unwrap()
+            .data
+            .unwrap()
+            .broadcaster_login
+            .as_str(),
+        "pokimane"
+    );
+    assert!(client
+        .req_get_conditional(req.clone(), Some("\"v1\""), &token)
+        .await
+        .unwrap()
+        .is_not_modified());
+
+    let mut req = ConditionalRequest::new(req);
+    assert!(req.poll(&client, &token).await.unwrap().is_some());
+    assert_eq!(req.etag(), Some("\"v1\""));
+    assert!(req.poll(&client, &token).await.unwrap().is_none());
+    assert_eq!(req.etag(), Some("\"v1\""));
+
+    assert_eq!(
+        *client.client.0.lock().unwrap(),
+        vec![
+            None,
+            Some("\"v1\"".to_owned()),
+            None,
+            Some("\"v1\"".to_owned()),
+        ]
+    );
+}
@@ -0,0 +1,206 @@
+//! Diagnostics collected while parsing responses, see [`Response::diagnostics`](super::Response::diagnostics).
+//!
+//! Diagnostics are only collected with the `diagnostics` feature, without it [`Diagnostics`] is always empty.
+//! They point out where Twitch returned something this library didn't expect, which is useful evidence when reporting API drift.
+//!
+//! Top-level fields besides `data`, `pagination` and `total` that are collected into [`Response::other`](super::Response::other) are not reported as [unknown](Diagnostic::UnknownField).
+//! With the `collect_other_fields` feature this is the case for all endpoints.
+//!
+//! # Examples
+//!
+//! ```rust, no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! use twitch_api2::helix;
+//!
+//! let req = helix::users::GetUsersRequest::builder()
+//!     .login(vec!["justintv123".into()])
+//!     .build();
+//! let response = client.req_get(req, &token).await?;
+//! if !response.diagnostics.is_empty() {
+//!     eprintln!("unexpected response from twitch:\n{}", response.diagnostics);
+//! }
+//! # Ok(()) }
+//! ```
+
+/// Something unexpected found while parsing a response
+#[derive(PartialEq, Eq, Debug, Clone, displaydoc::Display)]
+#[non_exhaustive]
+pub enum Diagnostic {
+    /// unknown field `{path}` was ignored
+    UnknownField {
+        /// Path to the field
+        path: String,
+    },
+    /// an empty string was parsed as null
+    EmptyStringAsNull,
+    /// a pagination cursor was returned without any data
+    CursorWithoutData,
+    /// the pagination cursor was an empty string
+    EmptyCursor,
+    /// `total` is {total}, but `data` has {len} entries
+    TotalTooSmall {
+        /// The returned `total`
+        total: i64,
+        /// Amount of entries in `data`
+        len: usize,
+    },
+}
+
+/// Collection of [diagnostics](Diagnostic) for a response
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct Diagnostics {
+    /// The diagnostics, in the order they were found.
+    pub entries: Vec<Diagnostic>,
+}
+
+impl Diagnostics {
+    /// No diagnostics were collected
+    pub fn is_empty(&self) -> bool { self.entries.is_empty() }
+
+    /// Iterate over the diagnostics
+    pub fn iter(&self) -> std::slice::Iter<'_, Diagnostic> { self.entries.iter() }
+}
+
+impl<'a> IntoIterator for &'a Diagnostics {
+    type IntoIter = std::slice::Iter<'a, Diagnostic>;
+    type Item = &'a Diagnostic;
+
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl std::fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for diagnostic in self {
+            writeln!(f, "{}", diagnostic)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "diagnostics")]
+thread_local! {
+    static COLLECTING: std::cell::RefCell<Option<Vec<Diagnostic>>> = std::cell::RefCell::new(None);
+}
+
+/// Record a diagnostic, if diagnostics are being [collected](collect) on this thread.
+#[cfg(feature = "diagnostics")]
+pub(crate) fn record(diagnostic: impl FnOnce() -> Diagnostic) {
+    COLLECTING.with(|collecting| {
+        if let Some(entries) = collecting.borrow_mut().as_mut() {
+            entries.push(diagnostic())
+        }
+    })
+}
+
+/// Collect the diagnostics recorded while running `parse`, together with oddities in the shape of `text`.
+#[cfg(feature = "diagnostics")]
+pub(crate) fn collect<T>(text: &str, parse: impl FnOnce() -> T) -> (T, Diagnostics) {
+    let previous = COLLECTING.with(|collecting| collecting.replace(Some(vec![])));
+    let parsed = parse();
+    let mut entries = COLLECTING
+        .with(|collecting| collecting.replace(previous))
+        .unwrap_or_default();
+    check_shape(text, &mut entries);
+    (parsed, Diagnostics { entries })
+}
+
+/// Collect the diagnostics recorded while running `parse`, without the `diagnostics` feature nothing is collected.
+#[cfg(not(feature = "diagnostics"))]
+pub(crate) fn collect<T>(_text: &str, parse: impl FnOnce() -> T) -> (T, Diagnostics) {
+    (parse(), Diagnostics::default())
+}
+
+/// Check `data`, `pagination` and `total` of a response.
+#[cfg(feature = "diagnostics")]
+fn check_shape(text: &str, entries: &mut Vec<Diagnostic>) {
+    use serde::de::IgnoredAny;
+
+    #[derive(serde::Deserialize)]
+    struct Shape {
+        #[serde(default)]
+        data: Option<DataShape>,
+        #[serde(default)]
+        pagination: Option<PaginationShape>,
+        #[serde(default)]
+        total: Option<i64>,
+    }
+
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum DataShape {
+        List(Vec<IgnoredAny>),
+        Other(IgnoredAny),
+    }
+
+    #[derive(serde::Deserialize)]
+    struct PaginationShape {
+        #[serde(default)]
+        cursor: Option<String>,
+    }
+
+    let shape: Shape = match serde_json::from_str(text) {
+        Ok(shape) => shape,
+        Err(_) => return,
+    };
+    let len = match shape.data {
+        Some(DataShape::List(list)) => Some(list.len()),
+        _ => None,
+    };
+    match shape.pagination.and_then(|p| p.cursor) {
+        Some(cursor) if cursor.is_empty() => entries.push(Diagnostic::EmptyCursor),
+        Some(_) if len == Some(0) => entries.push(Diagnostic::CursorWithoutData),
+        _ => (),
+    }
+    if let (Some(total), Some(len)) = (shape.total, len) {
+        if total < len as i64 {
+            entries.push(Diagnostic::TotalTooSmall { total, len });
+        }
+    }
+}
+
+#[cfg(all(test, feature = "diagnostics"))]
+mod tests {
+    use super::*;
+    use crate::helix::{self, Request, RequestGet};
+
+    #[test]
+    // `unexpected` is collected into `other` instead
+    #[cfg(not(feature = "collect_other_fields"))]
+    fn collects_diagnostics() {
+        let req = helix::moderation::GetModeratorsRequest::builder()
+            .broadcaster_id("198704263")
+            .build();
+
+        let data = br#"
+{
+    "data": [],
+    "pagination": {
+        "cursor": ""
+    },
+    "total": 0,
+    "unexpected": true
+}
+"#
+        .to_vec();
+
+        let http_response = http::Response::builder().body(data).unwrap();
+        let uri = req.get_uri().unwrap();
+        let response =
+            helix::moderation::GetModeratorsRequest::parse_response(Some(req), &uri, http_response)
+                .unwrap();
+        assert_eq!(
+            response.diagnostics.entries,
+            vec![
+                Diagnostic::UnknownField {
+                    path: "unexpected".to_string()
+                },
+                Diagnostic::EmptyCursor,
+            ]
+        );
+    }
+}
@@ -0,0 +1,436 @@
+//! Index of all Helix endpoints, for finding the request type of an endpoint.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use twitch_api2::helix::endpoints;
+//!
+//! let endpoint = endpoints::find("Get Users").unwrap();
+//! assert_eq!(endpoint.module, "helix::users::get_users");
+//! assert_eq!(endpoint.path, "users");
+//!
+//! for endpoint in endpoints::search("emote") {
+//!     println!("{} - {} {}", endpoint.name, endpoint.method, endpoint.path);
+//! }
+//! ```
+
+use super::*;
+
+/// A Helix endpoint, see [`ALL`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Endpoint {
+    /// Name of the endpoint, as in the Twitch API reference
+    pub name: &'static str,
+    /// Path of the module implementing the endpoint, e.g. `helix::users::get_users`
+    pub module: &'static str,
+    /// Name of the [`Request`] type of the endpoint
+    pub request: &'static str,
+    /// HTTP method of the endpoint
+    pub method: http::Method,
+    /// Path of the endpoint, relative to the Helix root
+    pub path: &'static str,
+    /// Scopes needed for the endpoint
+    #[cfg(feature = "twitch_oauth2")]
+    pub scope: &'static [twitch_oauth2::Scope],
+}
+
+macro_rules! endpoint {
+    ($name:literal, $category:ident::$module:ident, $request:ident $(<$generic:ty>)?) => {
+        Endpoint {
+            name: $name,
+            module: concat!("helix::", stringify!($category), "::", stringify!($module)),
+            request: stringify!($request),
+            method: <$category::$module::$request $(<$generic>)? as Request>::METHOD,
+            path: <$category::$module::$request $(<$generic>)? as Request>::PATH,
+            #[cfg(feature = "twitch_oauth2")]
+            scope: <$category::$module::$request $(<$generic>)? as Request>::SCOPE,
+        }
+    };
+}
+
+/// All endpoints implemented in [`helix`](crate::helix)
+#[allow(deprecated)]
+pub static ALL: &[Endpoint] = &[
+    endpoint!(
+        "Get Bits Leaderboard",
+        bits::get_bits_leaderboard,
+        GetBitsLeaderboardRequest
+    ),
+    endpoint!("Get Cheermotes", bits::get_cheermotes, GetCheermotesRequest),
+    endpoint!(
+        "Add Channel VIP",
+        channels::add_channel_vip,
+        AddChannelVipRequest
+    ),
+    endpoint!(
+        "Get Channel Editors",
+        channels::get_channel_editors,
+        GetChannelEditorsRequest
+    ),
+    endpoint!(
+        "Get Channel Followers",
+        channels::get_channel_followers,
+        GetChannelFollowersRequest
+    ),
+    endpoint!(
+        "Get Channel Information",
+        channels::get_channel_information,
+        GetChannelInformationRequest
+    ),
+    endpoint!("Get VIPs", channels::get_vips, GetVipsRequest),
+    endpoint!(
+        "Modify Channel Information",
+        channels::modify_channel_information,
+        ModifyChannelInformationRequest
+    ),
+    endpoint!(
+        "Remove Channel VIP",
+        channels::remove_channel_vip,
+        RemoveChannelVipRequest
+    ),
+    endpoint!(
+        "Start Commercial",
+        channels::start_commercial,
+        StartCommercialRequest
+    ),
+    endpoint!(
+        "Get Charity Campaign Donations",
+        charity::get_charity_campaign_donations,
+        GetCharityCampaignDonationsRequest
+    ),
+    endpoint!(
+        "Get Channel Chat Badges",
+        chat::get_channel_chat_badges,
+        GetChannelChatBadgesRequest
+    ),
+    endpoint!(
+        "Get Channel Emotes",
+        chat::get_channel_emotes,
+        GetChannelEmotesRequest
+    ),
+    endpoint!(
+        "Get Chat Settings",
+        chat::get_chat_settings,
+        GetChatSettingsRequest
+    ),
+    endpoint!("Get Chatters", chat::get_chatters, GetChattersRequest),
+    endpoint!("Get Emote Sets", chat::get_emote_sets, GetEmoteSetsRequest),
+    endpoint!(
+        "Get Global Chat Badges",
+        chat::get_global_chat_badges,
+        GetGlobalChatBadgesRequest
+    ),
+    endpoint!(
+        "Get Global Emotes",
+        chat::get_global_emotes,
+        GetGlobalEmotesRequest
+    ),
+    endpoint!(
+        "Send A Shoutout",
+        chat::send_a_shoutout,
+        SendAShoutoutRequest
+    ),
+    endpoint!(
+        "Send Chat Announcement",
+        chat::send_chat_announcement,
+        SendChatAnnouncementRequest
+    ),
+    endpoint!("Get Clips", clips::get_clips, GetClipsRequest),
+    #[cfg(feature = "eventsub")]
+    endpoint!(
+        "Create EventSub Subscription",
+        eventsub::create_eventsub_subscription,
+        CreateEventSubSubscriptionRequest<crate::eventsub::user::UserUpdateV1>
+    ),
+    #[cfg(feature = "eventsub")]
+    endpoint!(
+        "Delete EventSub Subscription",
+        eventsub::delete_eventsub_subscription,
+        DeleteEventSubSubscriptionRequest
+    ),
+    #[cfg(feature = "eventsub")]
+    endpoint!(
+        "Get EventSub Subscriptions",
+        eventsub::get_eventsub_subscriptions,
+        GetEventSubSubscriptionsRequest
+    ),
+    endpoint!(
+        "Create Extension Secret",
+        extensions::create_extension_secret,
+        CreateExtensionSecretRequest
+    ),
+    endpoint!(
+        "Get Extension Bits Products",
+        extensions::get_extension_bits_products,
+        GetExtensionBitsProductsRequest
+    ),
+    endpoint!(
+        "Get Extension Configuration Segment",
+        extensions::get_extension_configuration_segment,
+        GetExtensionConfigurationSegmentRequest
+    ),
+    endpoint!(
+        "Get Extension Secrets",
+        extensions::get_extension_secrets,
+        GetExtensionSecretsRequest
+    ),
+    endpoint!(
+        "Get Extension Transactions",
+        extensions::get_extension_transactions,
+        GetExtensionTransactionsRequest
+    ),
+    endpoint!(
+        "Set Extension Configuration Segment",
+        extensions::set_extension_configuration_segment,
+        SetExtensionConfigurationSegmentRequest
+    ),
+    endpoint!(
+        "Set Extension Required Configuration",
+        extensions::set_extension_required_configuration,
+        SetExtensionRequiredConfigurationRequest
+    ),
+    endpoint!(
+        "Update Extension Bits Product",
+        extensions::update_extension_bits_product,
+        UpdateExtensionBitsProductRequest
+    ),
+    endpoint!("Get Games", games::get_games, GetGamesRequest),
+    endpoint!("Get Top Games", games::get_top_games, GetTopGamesRequest),
+    endpoint!(
+        "Get Creator Goals",
+        goals::get_creator_goals,
+        GetCreatorGoalsRequest
+    ),
+    endpoint!(
+        "Get Hype Train Events",
+        hypetrain::get_hypetrain_events,
+        GetHypeTrainEventsRequest
+    ),
+    endpoint!(
+        "Add Blocked Term",
+        moderation::add_blocked_term,
+        AddBlockedTermRequest
+    ),
+    endpoint!(
+        "Check AutoMod Status",
+        moderation::check_automod_status,
+        CheckAutoModStatusRequest
+    ),
+    endpoint!(
+        "Get Banned Events",
+        moderation::get_banned_events,
+        GetBannedEventsRequest
+    ),
+    endpoint!(
+        "Get Banned Users",
+        moderation::get_banned_users,
+        GetBannedUsersRequest
+    ),
+    endpoint!(
+        "Get Moderated Channels",
+        moderation::get_moderated_channels,
+        GetModeratedChannelsRequest
+    ),
+    endpoint!(
+        "Get Moderator Events",
+        moderation::get_moderator_events,
+        GetModeratorEventsRequest
+    ),
+    endpoint!(
+        "Get Moderators",
+        moderation::get_moderators,
+        GetModeratorsRequest
+    ),
+    endpoint!(
+        "Manage Held AutoMod Messages",
+        moderation::manage_held_automod_messages,
+        ManageHeldAutoModMessagesRequest
+    ),
+    endpoint!(
+        "Remove Blocked Term",
+        moderation::remove_blocked_term,
+        RemoveBlockedTermRequest
+    ),
+    endpoint!(
+        "Update AutoMod Settings",
+        moderation::update_automod_settings,
+        UpdateAutoModSettingsRequest
+    ),
+    endpoint!(
+        "Create Custom Rewards",
+        points::create_custom_rewards,
+        CreateCustomRewardRequest
+    ),
+    endpoint!(
+        "Delete Custom Reward",
+        points::delete_custom_reward,
+        DeleteCustomRewardRequest
+    ),
+    endpoint!(
+        "Get Custom Reward",
+        points::get_custom_reward,
+        GetCustomRewardRequest
+    ),
+    endpoint!(
+        "Get Custom Reward Redemption",
+        points::get_custom_reward_redemption,
+        GetCustomRewardRedemptionRequest
+    ),
+    endpoint!(
+        "Update Custom Reward",
+        points::update_custom_reward,
+        UpdateCustomRewardRequest
+    ),
+    endpoint!(
+        "Update Redemption Status",
+        points::update_redemption_status,
+        UpdateRedemptionStatusRequest
+    ),
+    endpoint!("Create Poll", polls::create_poll, CreatePollRequest),
+    endpoint!("End Poll", polls::end_poll, EndPollRequest),
+    endpoint!("Get Polls", polls::get_polls, GetPollsRequest),
+    endpoint!(
+        "Create Prediction",
+        predictions::create_prediction,
+        CreatePredictionRequest
+    ),
+    endpoint!(
+        "End Prediction",
+        predictions::end_prediction,
+        EndPredictionRequest
+    ),
+    endpoint!(
+        "Get Predictions",
+        predictions::get_predictions,
+        GetPredictionsRequest
+    ),
+    endpoint!(
+        "Create Channel Stream Schedule Segment",
+        schedule::create_channel_stream_schedule_segment,
+        CreateChannelStreamScheduleSegmentRequest
+    ),
+    endpoint!(
+        "Get Channel Stream Schedule",
+        schedule::get_channel_stream_schedule,
+        GetChannelStreamScheduleRequest
+    ),
+    endpoint!(
+        "Update Channel Stream Schedule",
+        schedule::update_channel_stream_schedule,
+        UpdateChannelStreamScheduleRequest
+    ),
+    endpoint!(
+        "Update Channel Stream Schedule Segment",
+        schedule::update_channel_stream_schedule_segment,
+        UpdateChannelStreamScheduleSegmentRequest
+    ),
+    endpoint!(
+        "Search Categories",
+        search::search_categories,
+        SearchCategoriesRequest
+    ),
+    endpoint!(
+        "Search Channels",
+        search::search_channels,
+        SearchChannelsRequest
+    ),
+    endpoint!(
+        "Get Followed Streams",
+        streams::get_followed_streams,
+        GetFollowedStreamsRequest
+    ),
+    endpoint!(
+        "Get Stream Markers",
+        streams::get_stream_markers,
+        GetStreamMarkersRequest
+    ),
+    endpoint!(
+        "Get Stream Tags",
+        streams::get_stream_tags,
+        GetStreamTagsRequest
+    ),
+    endpoint!("Get Streams", streams::get_streams, GetStreamsRequest),
+    endpoint!(
+        "Replace Stream Tags",
+        streams::replace_stream_tags,
+        ReplaceStreamTagsRequest
+    ),
+    endpoint!(
+        "Check User Subscription",
+        subscriptions::check_user_subscription,
+        CheckUserSubscriptionRequest
+    ),
+    endpoint!(
+        "Get Broadcaster Subscriptions",
+        subscriptions::get_broadcaster_subscriptions,
+        GetBroadcasterSubscriptionsRequest
+    ),
+    endpoint!(
+        "Get Broadcaster Subscriptions Events",
+        subscriptions::get_broadcaster_subscriptions_events,
+        GetBroadcasterSubscriptionsEventsRequest
+    ),
+    endpoint!(
+        "Get All Stream Tags",
+        tags::get_all_stream_tags,
+        GetAllStreamTagsRequest
+    ),
+    endpoint!(
+        "Get Channel Teams",
+        teams::get_channel_teams,
+        GetChannelTeamsRequest
+    ),
+    endpoint!("Get Teams", teams::get_teams, GetTeamsRequest),
+    endpoint!("Block User", users::block_user, BlockUserRequest),
+    endpoint!(
+        "Get User Block List",
+        users::get_user_block_list,
+        GetUserBlockListRequest
+    ),
+    endpoint!(
+        "Get User Extensions",
+        users::get_user_extensions,
+        GetUserExtensionsRequest
+    ),
+    endpoint!("Get Users", users::get_users, GetUsersRequest),
+    endpoint!(
+        "Get Users Follows",
+        users::get_users_follows,
+        GetUsersFollowsRequest
+    ),
+    endpoint!("Unblock User", users::unblock_user, UnblockUserRequest),
+    endpoint!("Delete Videos", videos::delete_videos, DeleteVideosRequest),
+    endpoint!("Get Videos", videos::get_videos, GetVideosRequest),
+    endpoint!("Send Whisper", whispers::send_whisper, SendWhisperRequest),
+];
+
+/// Find an endpoint by its name, ignoring case
+pub fn find(name: &str) -> Option<&'static Endpoint> {
+    ALL.iter()
+        .find(|endpoint| endpoint.name.eq_ignore_ascii_case(name))
+}
+
+/// Endpoints whose name or path contain `query`, ignoring case
+pub fn search(query: &str) -> impl Iterator<Item = &'static Endpoint> {
+    let query = query.to_ascii_lowercase();
+    ALL.iter().filter(move |endpoint| {
+        endpoint.name.to_ascii_lowercase().contains(&query) || endpoint.path.contains(&query)
+    })
+}
+
+#[cfg(test)]
+#[test]
+fn test_endpoints() {
+    let endpoint = find("get users").unwrap();
+    assert_eq!(endpoint.method, http::Method::GET);
+    assert_eq!(endpoint.request, "GetUsersRequest");
+    assert!(search("VIP").any(|endpoint| endpoint.name == "Add Channel VIP"));
+    for (i, endpoint) in ALL.iter().enumerate() {
+        assert!(
+            !ALL[..i].iter().any(|other| other.name == endpoint.name),
+            "duplicate endpoint {}",
+            endpoint.name
+        );
+    }
+}
@@ -6,6 +6,7 @@ use crate::eventsub::{EventSubscription, EventType, Status, Transport, Transport
 ///
 /// [`create-eventsub-subscription`](https://dev.twitch.tv/docs/api/reference#create-eventsub-subscription)
 #[derive(PartialEq, typed_builder::TypedBuilder, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct CreateEventSubSubscriptionRequest<E: EventSubscription> {
     #[builder(setter(skip), default)]
@@ -24,6 +25,7 @@ impl<E: EventSubscription> Default for CreateEventSubSubscriptionRequest<E> {
 impl<E: EventSubscription> helix::Request for CreateEventSubSubscriptionRequest<E> {
     type Response = CreateEventSubSubscription<E>;
 
+    const METHOD: http::Method = http::Method::POST;
     const PATH: &'static str = "eventsub/subscriptions";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -37,6 +39,7 @@ impl<E: EventSubscription> helix::Request for CreateEventSubSubscriptionRequest<
 ///
 /// This body is quite different from the official body. If you want the true representation in text, see [`helix::HelixRequestBody::try_to_body`] on [`CreateEventSubSubscriptionRequest<E: EventSubscription>`](CreateEventSubSubscriptionRequest)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct CreateEventSubSubscriptionBody<E: EventSubscription> {
     /// Subscription that will be created
@@ -54,6 +57,8 @@ impl<E: EventSubscription> helix::HelixRequestBody for CreateEventSubSubscriptio
             version: &'static str,
             condition: serde_json::Value,
             transport: &'a Transport,
+            #[serde(skip_serializing_if = "std::ops::Not::not")]
+            is_batching_enabled: bool,
         }
 
         let b = IEventSubRequestBody {
@@ -61,6 +66,7 @@ impl<E: EventSubscription> helix::HelixRequestBody for CreateEventSubSubscriptio
             version: E::VERSION,
             condition: self.subscription.condition()?,
             transport: &self.transport,
+            is_batching_enabled: E::IS_BATCHING_ENABLED,
         };
         serde_json::to_vec(&b).map_err(Into::into)
     }
@@ -116,6 +122,79 @@ pub struct CreateEventSubSubscription<E: EventSubscription> {
     pub cost: usize,
 }
 
+/// Errors returned by [Create EventSub Subscription](super::create_eventsub_subscription) that are specific to the endpoint
+#[derive(PartialEq, Eq, Debug, Clone, Copy, thiserror::Error, displaydoc::Display)]
+#[non_exhaustive]
+pub enum CreateEventSubSubscriptionError {
+    /// a subscription with the same type, version, condition and transport already exists
+    SubscriptionAlreadyExists,
+}
+
+impl CreateEventSubSubscriptionError {
+    /// Get the error specific to this endpoint from the error Twitch returned, if any.
+    pub fn from_helix_error(error: &helix::HelixRequestError) -> Option<Self> {
+        match error {
+            helix::HelixRequestError::Error { status, .. } => match status.as_u16() {
+                409 => Some(CreateEventSubSubscriptionError::SubscriptionAlreadyExists),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Outcome of [`HelixClient::create_eventsub_subscription`](helix::HelixClient::create_eventsub_subscription)
+#[derive(PartialEq, Debug, Clone)]
+#[non_exhaustive]
+pub enum CreateEventSubSubscriptionOutcome<E: EventSubscription> {
+    /// The subscription was created.
+    Created(CreateEventSubSubscription<E>),
+    /// The subscription already exists.
+    SubscriptionAlreadyExists {
+        /// The existing subscription, if it was asked for and found.
+        existing: Option<crate::eventsub::EventSubSubscription>,
+    },
+}
+
+impl<E: EventSubscription> CreateEventSubSubscriptionOutcome<E> {
+    /// ID of the created or existing subscription, if known
+    pub fn id(&self) -> Option<&types::EventSubIdRef> {
+        match self {
+            CreateEventSubSubscriptionOutcome::Created(created) => Some(&created.id),
+            CreateEventSubSubscriptionOutcome::SubscriptionAlreadyExists { existing } => {
+                existing.as_ref().map(|existing| &*existing.id)
+            }
+        }
+    }
+}
+
+impl<E: EventSubscription> CreateEventSubSubscriptionBody<E> {
+    /// Whether `subscription` is the subscription this body creates
+    ///
+    /// The type, version and transport have to be the same, and every field of the condition of this body has to be in the condition of `subscription`.
+    pub fn is_same_subscription(
+        &self,
+        subscription: &crate::eventsub::EventSubSubscription,
+    ) -> bool {
+        if subscription.type_ != E::EVENT_TYPE
+            || subscription.version != E::VERSION
+            || subscription.transport.method != self.transport.method
+            || subscription.transport.callback != self.transport.callback
+            || subscription.transport.session_id != self.transport.session_id
+        {
+            return false;
+        }
+        match (self.subscription.condition(), &subscription.condition) {
+            (Ok(serde_json::Value::Object(condition)), serde_json::Value::Object(existing)) => {
+                condition
+                    .iter()
+                    .all(|(key, value)| existing.get(key) == Some(value))
+            }
+            _ => false,
+        }
+    }
+}
+
 impl<E: EventSubscription> helix::RequestPost for CreateEventSubSubscriptionRequest<E> {
     type Body = CreateEventSubSubscriptionBody<E>;
 
@@ -124,7 +203,7 @@ impl<E: EventSubscription> helix::RequestPost for CreateEventSubSubscriptionRequ
         uri: &http::Uri,
         text: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
@@ -152,15 +231,24 @@ impl<E: EventSubscription> helix::RequestPost for CreateEventSubSubscriptionRequ
             total_cost: usize,
             max_total_cost: usize,
         }
-        let response: InnerResponse<E> = helix::parse_json(text, true).map_err(|e| {
-            helix::HelixRequestPostError::DeserializeError(text.to_string(), e, uri.clone(), status)
+        let response: InnerResponse<E> = helix::parse_json_owned(text, true).map_err(|e| {
+            helix::HelixRequestError::DeserializeError(
+                text.to_string(),
+                e,
+                Self::METHOD,
+                uri.clone(),
+                status,
+                None,
+            )
         })?;
         let data = response.data.into_iter().next().ok_or_else(|| {
-            helix::HelixRequestPostError::InvalidResponse {
+            helix::HelixRequestError::InvalidResponse {
                 reason: "missing response data",
                 response: text.to_string(),
                 status,
+                method: Self::METHOD,
                 uri: uri.clone(),
+                request_id: None,
             }
         })?;
         #[allow(deprecated)]
@@ -184,6 +272,8 @@ impl<E: EventSubscription> helix::RequestPost for CreateEventSubSubscriptionRequ
             // helix::Response total is generally the total number of results, not what the total for this endpoint means. Thus, we set it to None.
             total: None,
             other: None,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
@@ -198,42 +288,19 @@ fn test_request() {
 
     let body = CreateEventSubSubscriptionBody::new(
         UserUpdateV1::builder().user_id("1234").build(),
-        eventsub::Transport {
-            method: eventsub::TransportMethod::Webhook,
-            callback: "example.com".to_string(),
-            secret: "heyhey13".to_string(),
-        },
+        eventsub::Transport::webhook_url(
+            types::CallbackUrl::new("https://example.com").unwrap(),
+            "heyhey13".to_string(),
+        ),
     );
 
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
     // From twitch docs, FIXME: docs say `users.update` in example for Create EventSub Subscription, they also use kebab-case for status
     // "{"type":"users.update","version":"1","condition":{"user_id":"1234"},"transport":{"method":"webhook","callback":"https://this-is-a-callback.com","secret":"s3cre7"}}"
-    let data = br#"{
-    "data": [
-        {
-            "id": "26b1c993-bfcf-44d9-b876-379dacafe75a",
-            "status": "webhook_callback_verification_pending",
-            "type": "user.update",
-            "version": "1",
-            "condition": {
-                "user_id": "1234"
-            },
-            "created_at": "2020-11-10T20:29:44Z",
-            "transport": {
-                "method": "webhook",
-                "callback": "https://this-is-a-callback.com"
-            },
-            "cost": 1
-        }
-    ],
-    "limit": 10000,
-    "total": 1,
-    "total_cost": 1,
-    "max_total_cost": 10000
-}
-    "#
-    .to_vec();
+    let data = crate::fixtures::helix::eventsub::CREATE_EVENTSUB_SUBSCRIPTION
+        .as_bytes()
+        .to_vec();
     let http_response = http::Response::builder().status(202).body(data).unwrap();
 
     let uri = req.get_uri().unwrap();
@@ -247,3 +314,88 @@ fn test_request() {
         CreateEventSubSubscriptionRequest::parse_response(Some(req), &uri, http_response).unwrap()
     );
 }
+
+#[cfg(test)]
+#[test]
+fn test_request_batched() {
+    use crate::eventsub::{self, drop::DropEntitlementGrantV1, user::UserUpdateV1};
+    use helix::*;
+
+    let transport = eventsub::Transport::webhook_url(
+        types::CallbackUrl::new("https://example.com").unwrap(),
+        "heyhey13".to_string(),
+    );
+    let body = CreateEventSubSubscriptionBody::new(
+        DropEntitlementGrantV1::builder()
+            .organization_id("9001")
+            .build(),
+        transport.clone(),
+    );
+    let body: serde_json::Value = serde_json::from_slice(&body.try_to_body().unwrap()).unwrap();
+    assert_eq!(body["type"], "drop.entitlement.grant");
+    assert_eq!(body["is_batching_enabled"], true);
+
+    let body = CreateEventSubSubscriptionBody::new(
+        UserUpdateV1::builder().user_id("1234").build(),
+        transport,
+    );
+    let body: serde_json::Value = serde_json::from_slice(&body.try_to_body().unwrap()).unwrap();
+    assert!(body.get("is_batching_enabled").is_none());
+}
+
+#[cfg(test)]
+#[test]
+fn test_request_error() {
+    use crate::eventsub::{self, user::UserUpdateV1};
+    use helix::*;
+    let req: CreateEventSubSubscriptionRequest<UserUpdateV1> =
+        CreateEventSubSubscriptionRequest::builder().build();
+
+    let data = br#"
+{
+    "error": "Conflict",
+    "status": 409,
+    "message": "subscription already exists"
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().status(409).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    let error = CreateEventSubSubscriptionRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap_err();
+    assert_eq!(
+        CreateEventSubSubscriptionError::from_helix_error(&error),
+        Some(CreateEventSubSubscriptionError::SubscriptionAlreadyExists)
+    );
+
+    let body = CreateEventSubSubscriptionBody::new(
+        UserUpdateV1::builder().user_id("1234").build(),
+        eventsub::Transport::webhook_url(
+            types::CallbackUrl::new("https://this-is-a-callback.com").unwrap(),
+            "s3cre7abcd".to_string(),
+        ),
+    );
+    let mut existing: eventsub::EventSubSubscription = serde_json::from_str(
+        r#"{
+            "id": "26b1c993-bfcf-44d9-b876-379dacafe75a",
+            "status": "enabled",
+            "type": "user.update",
+            "version": "1",
+            "condition": {
+                "user_id": "1234"
+            },
+            "created_at": "2020-11-10T20:29:44Z",
+            "transport": {
+                "method": "webhook",
+                "callback": "https://this-is-a-callback.com"
+            },
+            "cost": 1
+        }"#,
+    )
+    .unwrap();
+    assert!(body.is_same_subscription(&existing));
+    existing.condition["user_id"] = "5678".into();
+    assert!(!body.is_same_subscription(&existing));
+}
@@ -7,6 +7,7 @@ use helix::RequestDelete;
 ///
 /// [`get-eventsub-subscriptions`](https://dev.twitch.tv/docs/api/reference#delete-eventsub-subscription)
 #[derive(PartialEq, typed_builder::TypedBuilder, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct DeleteEventSubSubscriptionRequest {
     /// The subscription ID for the subscription you want to delete.
@@ -17,6 +18,7 @@ pub struct DeleteEventSubSubscriptionRequest {
 impl Request for DeleteEventSubSubscriptionRequest {
     type Response = DeleteEventSubSubscription;
 
+    const METHOD: http::Method = http::Method::DELETE;
     const PATH: &'static str = "eventsub/subscriptions";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -26,6 +28,11 @@ impl Request for DeleteEventSubSubscriptionRequest {
 ///
 /// [`get-eventsub-subscriptions`](https://dev.twitch.tv/docs/api/reference#delete-eventsub-subscription)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[non_exhaustive]
 pub enum DeleteEventSubSubscription {
     /// 204 - Subscription deleted
@@ -38,7 +45,7 @@ impl RequestDelete for DeleteEventSubSubscriptionRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestDeleteError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
@@ -49,12 +56,16 @@ impl RequestDelete for DeleteEventSubSubscriptionRequest {
                 request,
                 total: None,
                 other: None,
+                request_id: None,
+                diagnostics: Default::default(),
             }),
-            _ => Err(helix::HelixRequestDeleteError::InvalidResponse {
+            _ => Err(helix::HelixRequestError::InvalidResponse {
                 reason: "unexpected status",
                 response: response.to_string(),
                 status,
+                method: Self::METHOD,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
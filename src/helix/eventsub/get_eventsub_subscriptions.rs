@@ -8,27 +8,29 @@ use helix::RequestGet;
 ///
 /// [`get-eventsub-subscriptions`](https://dev.twitch.tv/docs/api/reference#get-eventsub-subscriptions)
 #[derive(PartialEq, typed_builder::TypedBuilder, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetEventSubSubscriptionsRequest {
     /// Include this parameter to filter subscriptions by their status.
     #[builder(default, setter(into))]
     pub status: Option<eventsub::Status>,
-    // FIXME: https://github.com/twitchdev/issues/issues/272
-    /// Cursor for forward pagination
-    #[builder(default, setter(into))]
-    pub after: Option<helix::Cursor>,
-    // FIXME: https://github.com/twitchdev/issues/issues/271
-    /// Maximum number of objects to return. Maximum: 100. Default: 20.
-    #[builder(default, setter(into))]
-    pub first: Option<usize>,
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
+    ///
+    /// Backward pagination with [`before`](helix::PaginationParams::before) is not supported.
+    #[builder(default)]
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
 }
 
 impl Request for GetEventSubSubscriptionsRequest {
     type Response = EventSubSubscriptions;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "eventsub/subscriptions";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 /// Return Values for [Get EventSub Subscriptions](super::get_eventsub_subscriptions)
@@ -52,6 +54,7 @@ pub struct EventSubSubscriptions {
     /// Subscription limit for client id that made the subscription creation request.
     pub limit: Option<usize>,
     /// Array containing subscriptions.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub subscriptions: Vec<eventsub::EventSubSubscription>,
 }
 
@@ -61,7 +64,7 @@ impl RequestGet for GetEventSubSubscriptionsRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestGetError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
@@ -78,12 +81,14 @@ impl RequestGet for GetEventSubSubscriptionsRequest {
             limit: Option<usize>,
         }
 
-        let response: InnerResponse = helix::parse_json(response, true).map_err(|e| {
-            helix::HelixRequestGetError::DeserializeError(
+        let response: InnerResponse = helix::parse_json_owned(response, true).map_err(|e| {
+            helix::HelixRequestError::DeserializeError(
                 response.to_string(),
                 e,
+                Self::METHOD,
                 uri.clone(),
                 status,
+                None,
             )
         })?;
         #[allow(deprecated)]
@@ -100,12 +105,14 @@ impl RequestGet for GetEventSubSubscriptionsRequest {
             request,
             total: Some(response.total),
             other: None,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
 
 impl helix::Paginated for GetEventSubSubscriptionsRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
 }
 
 #[cfg(test)]
@@ -115,47 +122,9 @@ fn test_request() {
     let req: GetEventSubSubscriptionsRequest = GetEventSubSubscriptionsRequest::builder().build();
     // From twitch docs.
     // FIXME: Twitch says in example that status is kebab-case, it's actually snake_case. also, users vs user and stream vs streams
-    let data = br#"{
-        "total": 2,
-        "data": [
-            {
-                "id": "26b1c993-bfcf-44d9-b876-379dacafe75a",
-                "status": "enabled",
-                "type": "stream.online",
-                "version": "1",
-                "condition": {
-                    "broadcaster_user_id": "1234"
-                },
-                "created_at": "2020-11-10T20:08:33Z",
-                "transport": {
-                    "method": "webhook",
-                    "callback": "https://this-is-a-callback.com"
-                },
-                "cost": 1
-            },
-            {
-                "id": "35016908-41ff-33ce-7879-61b8dfc2ee16",
-                "status": "webhook_callback_verification_pending",
-                "type": "user.update",
-                "version": "1",
-                "condition": {
-                    "user_id": "1234"
-                },
-                "created_at": "2020-11-10T20:31:52Z",
-                "transport": {
-                    "method": "webhook",
-                    "callback": "https://this-is-a-callback.com"
-                },
-                "cost": 0
-            }
-        ],
-        "limit": 10000,
-        "total_cost": 1,
-        "max_total_cost": 10000,
-        "pagination": {}
-    }
-    "#
-    .to_vec();
+    let data = crate::fixtures::helix::eventsub::GET_EVENTSUB_SUBSCRIPTIONS
+        .as_bytes()
+        .to_vec();
     let http_response = http::Response::builder().status(200).body(data).unwrap();
 
     let uri = req.get_uri().unwrap();
@@ -12,7 +12,8 @@ pub mod get_eventsub_subscriptions;
 
 #[doc(inline)]
 pub use create_eventsub_subscription::{
-    CreateEventSubSubscription, CreateEventSubSubscriptionBody, CreateEventSubSubscriptionRequest,
+    CreateEventSubSubscription, CreateEventSubSubscriptionBody, CreateEventSubSubscriptionError,
+    CreateEventSubSubscriptionOutcome, CreateEventSubSubscriptionRequest,
 };
 #[doc(inline)]
 pub use delete_eventsub_subscription::{
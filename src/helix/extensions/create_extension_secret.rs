@@ -0,0 +1,134 @@
+//! Creates a shared secret used to sign and verify JWT tokens.
+//! [`create-extension-secret`](https://dev.twitch.tv/docs/api/reference#create-extension-secret)
+//!
+//! Creating a new secret removes the current secrets from service after the specified delay. Use this to rotate secrets.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [CreateExtensionSecretRequest]
+//!
+//! To use this endpoint, construct a [`CreateExtensionSecretRequest`] with the [`CreateExtensionSecretRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::extensions::create_extension_secret;
+//! let request = create_extension_secret::CreateExtensionSecretRequest::builder()
+//!     .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2")
+//!     .delay(600)
+//!     .build();
+//! ```
+//!
+//! ## Response: [ExtensionSecrets]
+//!
+//! This endpoint is authorized with a signed JWT, which [`HelixClient`](helix::HelixClient) can not make.
+//! Create the [`http::Request`] with [`AuthStyle::Extension`](helix::AuthStyle::Extension), send it with your client
+//! and parse the [`http::Response`] with [`CreateExtensionSecretRequest::parse_response(None, &request.get_uri(), response)`](CreateExtensionSecretRequest::parse_response)
+//!
+//! ```rust
+//! use twitch_api2::helix::{self, extensions::create_extension_secret, AuthStyle, RequestPost};
+//! let request = create_extension_secret::CreateExtensionSecretRequest::builder()
+//!     .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2")
+//!     .build();
+//! let http_request =
+//!     request.create_request(helix::EmptyBody, AuthStyle::Extension("jwt"), "client_id")?;
+//! # let _ = http_request;
+//! # Ok::<(), helix::CreateRequestError>(())
+//! ```
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Create Extension Secret](super::create_extension_secret)
+///
+/// [`create-extension-secret`](https://dev.twitch.tv/docs/api/reference#create-extension-secret)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct CreateExtensionSecretRequest {
+    /// The ID of the extension to apply the shared secret to.
+    #[builder(setter(into))]
+    pub extension_id: String,
+    /// The amount of time, in seconds, to delay activating the secret. The delay should provide enough time for instances of the extension to gracefully switch over to the new secret. Minimum: 300. Default: 300.
+    #[builder(default, setter(into))]
+    pub delay: Option<u64>,
+}
+
+impl Request for CreateExtensionSecretRequest {
+    type Response = Vec<ExtensionSecrets>;
+
+    const AUTH: helix::AuthKind = helix::AuthKind::Extension;
+    const METHOD: http::Method = http::Method::POST;
+    const PATH: &'static str = "extensions/jwt/secrets";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestPost for CreateExtensionSecretRequest {
+    type Body = helix::EmptyBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response_str: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
+    where
+        Self: Sized,
+    {
+        let response: helix::InnerResponse<Self::Response> =
+            helix::parse_json_owned(response_str, true).map_err(|e| {
+                helix::HelixRequestError::DeserializeError(
+                    response_str.to_string(),
+                    e,
+                    Self::METHOD,
+                    uri.clone(),
+                    status,
+                    None,
+                )
+            })?;
+        Ok(helix::Response {
+            data: response.data,
+            pagination: response.pagination.cursor,
+            request,
+            total: response.total,
+            other: None,
+            request_id: None,
+            diagnostics: Default::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = CreateExtensionSecretRequest::builder()
+        .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2")
+        .delay(600)
+        .build();
+
+    let http_request = req
+        .create_request(EmptyBody, AuthStyle::Extension("jwt"), "clientid")
+        .unwrap();
+    assert_eq!(
+        http_request.headers()[http::header::AUTHORIZATION],
+        "Bearer jwt"
+    );
+
+    // From twitch docs
+    let data = crate::fixtures::helix::extensions::CREATE_EXTENSION_SECRET
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/extensions/jwt/secrets?extension_id=uo6dggojyb8d6soh92zknwmi5ej1q2&delay=600"
+    );
+
+    let response = CreateExtensionSecretRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(response[0].secrets.len(), 2);
+}
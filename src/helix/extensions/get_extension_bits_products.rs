@@ -0,0 +1,121 @@
+//! Gets the list of Bits products that belongs to an extension.
+//! [`get-extension-bits-products`](https://dev.twitch.tv/docs/api/reference#get-extension-bits-products)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetExtensionBitsProductsRequest]
+//!
+//! To use this endpoint, construct a [`GetExtensionBitsProductsRequest`] with the [`GetExtensionBitsProductsRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::extensions::get_extension_bits_products;
+//! let request = get_extension_bits_products::GetExtensionBitsProductsRequest::builder()
+//!     .should_include_all(true)
+//!     .build();
+//! ```
+//!
+//! ## Response: [ExtensionBitsProduct]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! The token must be an app access token, made with the client id of the extension.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, extensions::get_extension_bits_products};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let client_id = twitch_oauth2::ClientId::new("validclientid");
+//! # let client_secret = twitch_oauth2::ClientSecret::new("validclientsecret");
+//! # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+//! let request = get_extension_bits_products::GetExtensionBitsProductsRequest::builder().build();
+//! let response: Vec<get_extension_bits_products::ExtensionBitsProduct> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetExtensionBitsProductsRequest::parse_response(None, &request.get_uri(), response)`](GetExtensionBitsProductsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Extension Bits Products](super::get_extension_bits_products)
+///
+/// [`get-extension-bits-products`](https://dev.twitch.tv/docs/api/reference#get-extension-bits-products)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct GetExtensionBitsProductsRequest {
+    /// Whether to include disabled or expired Bits products in the response. The default is false.
+    #[builder(default, setter(into))]
+    pub should_include_all: Option<bool>,
+}
+
+/// Return Values for [Get Extension Bits Products](super::get_extension_bits_products)
+///
+/// [`get-extension-bits-products`](https://dev.twitch.tv/docs/api/reference#get-extension-bits-products)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionBitsProduct {
+    /// An ID that identifies the Bits product.
+    pub sku: String,
+    /// The product’s price.
+    pub cost: BitsProductCost,
+    /// Whether the product is in development and not yet available for public use.
+    pub in_development: bool,
+    /// The product’s name as displayed in the extension.
+    pub display_name: String,
+    /// The date and time when the product expires, if it expires.
+    #[serde(deserialize_with = "helix::deserialize_none_from_empty_string")]
+    pub expiration: Option<types::Timestamp>,
+    /// Whether transactions for this product are broadcast to all instances of the extension.
+    pub is_broadcast: bool,
+}
+
+impl Request for GetExtensionBitsProductsRequest {
+    type Response = Vec<ExtensionBitsProduct>;
+
+    const METHOD: http::Method = http::Method::GET;
+    const PATH: &'static str = "bits/extensions";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestGet for GetExtensionBitsProductsRequest {}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetExtensionBitsProductsRequest::builder()
+        .should_include_all(true)
+        .build();
+
+    // From twitch docs
+    let data = crate::fixtures::helix::extensions::GET_EXTENSION_BITS_PRODUCTS
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/bits/extensions?should_include_all=true"
+    );
+
+    let response = GetExtensionBitsProductsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(response[0].cost, BitsProductCost::bits(990));
+    assert!(response[1].expiration.is_none());
+    dbg!(response);
+}
@@ -0,0 +1,106 @@
+//! Gets the specified configuration segment from the specified extension.
+//! [`get-extension-configuration-segment`](https://dev.twitch.tv/docs/api/reference#get-extension-configuration-segment)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetExtensionConfigurationSegmentRequest]
+//!
+//! To use this endpoint, construct a [`GetExtensionConfigurationSegmentRequest`] with the [`GetExtensionConfigurationSegmentRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::extensions::{
+//!     get_extension_configuration_segment, ConfigurationSegment,
+//! };
+//! let request =
+//!     get_extension_configuration_segment::GetExtensionConfigurationSegmentRequest::builder()
+//!         .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2")
+//!         .segment(vec![ConfigurationSegment::Global])
+//!         .build();
+//! ```
+//!
+//! ## Response: [ExtensionConfigurationSegment]
+//!
+//! This endpoint is authorized with a signed JWT, which [`HelixClient`](helix::HelixClient) can not make.
+//! Create the [`http::Request`] with [`AuthStyle::Extension`](helix::AuthStyle::Extension), send it with your client
+//! and parse the [`http::Response`] with [`GetExtensionConfigurationSegmentRequest::parse_response(None, &request.get_uri(), response)`](GetExtensionConfigurationSegmentRequest::parse_response)
+//!
+//! ```rust
+//! use twitch_api2::helix::{
+//!     self,
+//!     extensions::{get_extension_configuration_segment, ConfigurationSegment},
+//!     AuthStyle, RequestGet,
+//! };
+//! let request =
+//!     get_extension_configuration_segment::GetExtensionConfigurationSegmentRequest::builder()
+//!         .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2")
+//!         .segment(vec![ConfigurationSegment::Broadcaster])
+//!         .broadcaster_id(Some("1234".into()))
+//!         .build();
+//! let http_request = request.create_request(AuthStyle::Extension("jwt"), "client_id")?;
+//! # let _ = http_request;
+//! # Ok::<(), helix::CreateRequestError>(())
+//! ```
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Extension Configuration Segment](super::get_extension_configuration_segment)
+///
+/// [`get-extension-configuration-segment`](https://dev.twitch.tv/docs/api/reference#get-extension-configuration-segment)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct GetExtensionConfigurationSegmentRequest {
+    /// The ID of the extension that contains the configuration segment you want to get.
+    #[builder(setter(into))]
+    pub extension_id: String,
+    /// The type of configuration segment to get. You may specify one or more segments.
+    pub segment: Vec<ConfigurationSegment>,
+    /// The ID of the broadcaster that installed the extension. Required if `segment` contains [`Broadcaster`](ConfigurationSegment::Broadcaster) or [`Developer`](ConfigurationSegment::Developer).
+    #[builder(default, setter(into))]
+    pub broadcaster_id: Option<types::UserId>,
+}
+
+impl Request for GetExtensionConfigurationSegmentRequest {
+    type Response = Vec<ExtensionConfigurationSegment>;
+
+    const AUTH: helix::AuthKind = helix::AuthKind::Extension;
+    const METHOD: http::Method = http::Method::GET;
+    const PATH: &'static str = "extensions/configurations";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestGet for GetExtensionConfigurationSegmentRequest {}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetExtensionConfigurationSegmentRequest::builder()
+        .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2")
+        .segment(vec![ConfigurationSegment::Global])
+        .build();
+
+    // From twitch docs
+    let data = crate::fixtures::helix::extensions::GET_EXTENSION_CONFIGURATION_SEGMENT
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/extensions/configurations?extension_id=uo6dggojyb8d6soh92zknwmi5ej1q2&segment=global"
+    );
+
+    let response =
+        GetExtensionConfigurationSegmentRequest::parse_response(Some(req), &uri, http_response)
+            .unwrap()
+            .data;
+    assert_eq!(response[0].segment, ConfigurationSegment::Global);
+    let content: std::collections::HashMap<String, String> =
+        response[0].content_json().unwrap().unwrap();
+    assert_eq!(content["foo"], "bar");
+}
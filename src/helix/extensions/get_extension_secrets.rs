@@ -0,0 +1,86 @@
+//! Gets an extension’s list of shared secrets.
+//! [`get-extension-secrets`](https://dev.twitch.tv/docs/api/reference#get-extension-secrets)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetExtensionSecretsRequest]
+//!
+//! To use this endpoint, construct a [`GetExtensionSecretsRequest`] with the [`GetExtensionSecretsRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::extensions::get_extension_secrets;
+//! let request = get_extension_secrets::GetExtensionSecretsRequest::builder()
+//!     .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2")
+//!     .build();
+//! ```
+//!
+//! ## Response: [ExtensionSecrets]
+//!
+//! This endpoint is authorized with a signed JWT, which [`HelixClient`](helix::HelixClient) can not make.
+//! Create the [`http::Request`] with [`AuthStyle::Extension`](helix::AuthStyle::Extension), send it with your client
+//! and parse the [`http::Response`] with [`GetExtensionSecretsRequest::parse_response(None, &request.get_uri(), response)`](GetExtensionSecretsRequest::parse_response)
+//!
+//! ```rust
+//! use twitch_api2::helix::{self, extensions::get_extension_secrets, AuthStyle, RequestGet};
+//! let request = get_extension_secrets::GetExtensionSecretsRequest::builder()
+//!     .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2")
+//!     .build();
+//! let http_request = request.create_request(AuthStyle::Extension("jwt"), "client_id")?;
+//! # let _ = http_request;
+//! # Ok::<(), helix::CreateRequestError>(())
+//! ```
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Extension Secrets](super::get_extension_secrets)
+///
+/// [`get-extension-secrets`](https://dev.twitch.tv/docs/api/reference#get-extension-secrets)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct GetExtensionSecretsRequest {
+    /// The ID of the extension whose shared secrets you want to get.
+    #[builder(setter(into))]
+    pub extension_id: String,
+}
+
+impl Request for GetExtensionSecretsRequest {
+    type Response = Vec<ExtensionSecrets>;
+
+    const AUTH: helix::AuthKind = helix::AuthKind::Extension;
+    const METHOD: http::Method = http::Method::GET;
+    const PATH: &'static str = "extensions/jwt/secrets";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestGet for GetExtensionSecretsRequest {}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetExtensionSecretsRequest::builder()
+        .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2")
+        .build();
+
+    // From twitch docs
+    let data = crate::fixtures::helix::extensions::GET_EXTENSION_SECRETS
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/extensions/jwt/secrets?extension_id=uo6dggojyb8d6soh92zknwmi5ej1q2"
+    );
+
+    let response = GetExtensionSecretsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(response[0].secrets[0].content, "secret");
+    assert!(!format!("{:?}", response).contains("\"secret\""));
+}
@@ -0,0 +1,186 @@
+//! Gets an extension’s list of transactions. A transaction records the exchange of a currency (for example, Bits) for a digital product.
+//! [`get-extension-transactions`](https://dev.twitch.tv/docs/api/reference#get-extension-transactions)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetExtensionTransactionsRequest]
+//!
+//! To use this endpoint, construct a [`GetExtensionTransactionsRequest`] with the [`GetExtensionTransactionsRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::extensions::get_extension_transactions;
+//! let request = get_extension_transactions::GetExtensionTransactionsRequest::builder()
+//!     .extension_id("1234")
+//!     .build();
+//! ```
+//!
+//! ## Response: [ExtensionTransaction]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, extensions::get_extension_transactions};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let client_id = twitch_oauth2::ClientId::new("validclientid");
+//! # let client_secret = twitch_oauth2::ClientSecret::new("validclientsecret");
+//! # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+//! let request = get_extension_transactions::GetExtensionTransactionsRequest::builder()
+//!     .extension_id("1234")
+//!     .build();
+//! let response: Vec<get_extension_transactions::ExtensionTransaction> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetExtensionTransactionsRequest::parse_response(None, &request.get_uri(), response)`](GetExtensionTransactionsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Extension Transactions](super::get_extension_transactions)
+///
+/// [`get-extension-transactions`](https://dev.twitch.tv/docs/api/reference#get-extension-transactions)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct GetExtensionTransactionsRequest {
+    /// The ID of the extension whose list of transactions you want to get.
+    #[builder(setter(into))]
+    pub extension_id: String,
+    /// A transaction ID used to filter the list of transactions. Multiple IDs can be specified. Limit: 100.
+    #[builder(default)]
+    pub id: Vec<String>,
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
+    ///
+    /// Backward pagination with [`before`](helix::PaginationParams::before) is not supported.
+    #[builder(default)]
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
+}
+
+/// Return Values for [Get Extension Transactions](super::get_extension_transactions)
+///
+/// [`get-extension-transactions`](https://dev.twitch.tv/docs/api/reference#get-extension-transactions)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionTransaction {
+    /// An ID that identifies the transaction.
+    pub id: String,
+    /// The UTC date and time of the transaction.
+    pub timestamp: types::Timestamp,
+    /// The ID of the broadcaster that owns the channel where the transaction occurred.
+    pub broadcaster_id: types::UserId,
+    /// The broadcaster’s login name.
+    pub broadcaster_login: types::UserName,
+    /// The broadcaster’s display name.
+    pub broadcaster_name: types::DisplayName,
+    /// The ID of the user that purchased the digital product.
+    pub user_id: types::UserId,
+    /// The user’s login name.
+    pub user_login: types::UserName,
+    /// The user’s display name.
+    pub user_name: types::DisplayName,
+    /// The type of transaction.
+    pub product_type: ProductType,
+    /// Contains details about the digital product.
+    pub product_data: TransactionProductData,
+}
+
+/// Type of an [extension transaction](ExtensionTransaction)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum ProductType {
+    /// Bits spent in an extension
+    BitsInExtension,
+}
+
+/// The digital product of an [extension transaction](ExtensionTransaction)
+///
+/// See also [`ExtensionBitsProduct`]
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TransactionProductData {
+    /// Set to `twitch.ext.` + `<the extension's ID>`.
+    pub domain: String,
+    /// An ID that identifies the digital product.
+    pub sku: String,
+    /// Contains details about the digital product’s cost.
+    pub cost: BitsProductCost,
+    /// Whether the product is in development.
+    pub in_development: bool,
+    /// The name of the digital product.
+    pub display_name: String,
+    /// This field is always empty since you may purchase only unexpired products.
+    #[serde(deserialize_with = "helix::deserialize_none_from_empty_string")]
+    pub expiration: Option<types::Timestamp>,
+    /// Whether the data was broadcast to all instances of the extension.
+    pub broadcast: bool,
+}
+
+impl Request for GetExtensionTransactionsRequest {
+    type Response = Vec<ExtensionTransaction>;
+
+    const METHOD: http::Method = http::Method::GET;
+    const PATH: &'static str = "extensions/transactions";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
+}
+
+impl RequestGet for GetExtensionTransactionsRequest {}
+
+impl helix::Paginated for GetExtensionTransactionsRequest {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetExtensionTransactionsRequest::builder()
+        .extension_id("1234")
+        .build();
+
+    // From twitch docs
+    let data = crate::fixtures::helix::extensions::GET_EXTENSION_TRANSACTIONS
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/extensions/transactions?extension_id=1234"
+    );
+
+    let resp = GetExtensionTransactionsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(resp[0].product_data.cost, BitsProductCost::bits(100));
+    assert_eq!(resp[0].product_data.expiration, None);
+}
@@ -0,0 +1,202 @@
+//! Helix endpoints regarding extensions
+//!
+//! The Bits product and transaction endpoints require an app access token, made with the client id of the extension.
+//! The secret endpoints are authorized with a signed JWT instead, see [`AuthStyle::Extension`](helix::AuthStyle::Extension).
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api2::helix::{HelixClient, extensions::GetExtensionBitsProductsRequest};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! let client = HelixClient::default();
+//! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+//! # let client_id = twitch_oauth2::ClientId::new("validclientid");
+//! # let client_secret = twitch_oauth2::ClientSecret::new("validclientsecret");
+//! # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+//! let req = GetExtensionBitsProductsRequest::builder()
+//!     .should_include_all(true)
+//!     .build();
+//!
+//! println!("{:?}", &client.req_get(req, &token).await?.data);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    helix::{self, Request},
+    types,
+};
+use serde::{Deserialize, Serialize};
+
+pub mod create_extension_secret;
+pub mod get_extension_bits_products;
+pub mod get_extension_configuration_segment;
+pub mod get_extension_secrets;
+pub mod get_extension_transactions;
+pub mod set_extension_configuration_segment;
+pub mod set_extension_required_configuration;
+pub mod update_extension_bits_product;
+
+#[doc(inline)]
+pub use create_extension_secret::CreateExtensionSecretRequest;
+
+#[doc(inline)]
+pub use get_extension_bits_products::{ExtensionBitsProduct, GetExtensionBitsProductsRequest};
+#[doc(inline)]
+pub use get_extension_configuration_segment::GetExtensionConfigurationSegmentRequest;
+#[doc(inline)]
+pub use get_extension_secrets::GetExtensionSecretsRequest;
+#[doc(inline)]
+pub use get_extension_transactions::{ExtensionTransaction, GetExtensionTransactionsRequest};
+#[doc(inline)]
+pub use set_extension_configuration_segment::{
+    SetExtensionConfigurationSegmentBody, SetExtensionConfigurationSegmentRequest,
+};
+#[doc(inline)]
+pub use set_extension_required_configuration::{
+    SetExtensionRequiredConfigurationBody, SetExtensionRequiredConfigurationRequest,
+};
+#[doc(inline)]
+pub use update_extension_bits_product::{
+    UpdateExtensionBitsProductBody, UpdateExtensionBitsProductRequest,
+};
+
+/// Cost of a [Bits product](ExtensionBitsProduct)
+#[derive(PartialEq, Eq, typed_builder::TypedBuilder, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct BitsProductCost {
+    /// The product’s price.
+    pub amount: i64,
+    /// The type of currency.
+    #[serde(rename = "type")]
+    #[builder(default = CostType::Bits)]
+    pub type_: CostType,
+}
+
+impl BitsProductCost {
+    /// Cost of the given amount of Bits
+    pub fn bits(amount: i64) -> Self {
+        BitsProductCost {
+            amount,
+            type_: CostType::Bits,
+        }
+    }
+}
+
+/// Currency of a [Bits product](ExtensionBitsProduct)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum CostType {
+    /// Bits
+    Bits,
+}
+
+/// Shared secrets of an extension, returned by [Get Extension Secrets](get_extension_secrets) and [Create Extension Secret](create_extension_secret)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionSecrets {
+    /// The version number that identifies this definition of the secret’s data.
+    pub format_version: i64,
+    /// The list of secrets.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
+    pub secrets: Vec<ExtensionSecret>,
+}
+
+/// A shared secret of an extension
+///
+/// The secret is redacted in the [`Debug`] output.
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionSecret {
+    /// The raw secret that you use with JWT encoding, base64 encoded.
+    pub content: String,
+    /// The UTC date and time that you may begin using this secret to sign a JWT.
+    pub active_at: types::Timestamp,
+    /// The UTC date and time that you must stop using this secret to decode a JWT.
+    pub expires_at: types::Timestamp,
+}
+
+impl std::fmt::Debug for ExtensionSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExtensionSecret")
+            .field("content", &"[redacted]")
+            .field("active_at", &self.active_at)
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+/// Segment of an extension's configuration
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone, Copy)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ConfigurationSegment {
+    /// Configuration of a broadcaster, set by the broadcaster
+    Broadcaster,
+    /// Configuration of a broadcaster, set by the developer
+    Developer,
+    /// Configuration for all broadcasters
+    Global,
+}
+
+/// A configuration segment of an extension, returned by [Get Extension Configuration Segment](get_extension_configuration_segment)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionConfigurationSegment {
+    /// The type of segment.
+    pub segment: ConfigurationSegment,
+    /// The ID of the broadcaster that installed the extension. Not set for the [global](ConfigurationSegment::Global) segment.
+    #[serde(default)]
+    pub broadcaster_id: Option<types::UserId>,
+    /// The contents of the segment. This string may be a plain-text string or a string-encoded JSON object, see [`content_json`](Self::content_json).
+    #[serde(default)]
+    pub content: Option<String>,
+    /// The version number that identifies this definition of the segment’s data.
+    #[serde(default)]
+    pub version: Option<String>,
+}
+
+impl ExtensionConfigurationSegment {
+    /// Deserialize the [content](Self::content) as JSON, `None` if the segment has no content
+    pub fn content_json<T: serde::de::DeserializeOwned>(
+        &self,
+    ) -> Option<Result<T, serde_json::Error>> {
+        self.content.as_deref().map(serde_json::from_str)
+    }
+}
@@ -0,0 +1,201 @@
+//! Updates a configuration segment.
+//! [`set-extension-configuration-segment`](https://dev.twitch.tv/docs/api/reference#set-extension-configuration-segment)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [SetExtensionConfigurationSegmentRequest]
+//!
+//! To use this endpoint, construct a [`SetExtensionConfigurationSegmentRequest`] with the [`SetExtensionConfigurationSegmentRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::extensions::set_extension_configuration_segment;
+//! let request =
+//!     set_extension_configuration_segment::SetExtensionConfigurationSegmentRequest::builder()
+//!         .build();
+//! ```
+//!
+//! ## Body: [SetExtensionConfigurationSegmentBody]
+//!
+//! We also need to provide a body to the request containing the segment to update.
+//!
+//! ```
+//! # use twitch_api2::helix::extensions::{set_extension_configuration_segment, ConfigurationSegment};
+//! let mut body = set_extension_configuration_segment::SetExtensionConfigurationSegmentBody::builder()
+//!     .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2")
+//!     .segment(ConfigurationSegment::Global)
+//!     .version(Some("0.0.1".to_string()))
+//!     .build();
+//! body.set_content_json(&serde_json::json!({ "foo": "bar" }))?;
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! ## Response: [SetExtensionConfigurationSegment]
+//!
+//! This endpoint is authorized with a signed JWT, which [`HelixClient`](helix::HelixClient) can not make.
+//! Create the [`http::Request`] with [`AuthStyle::Extension`](helix::AuthStyle::Extension), send it with your client
+//! and parse the [`http::Response`] with [`SetExtensionConfigurationSegmentRequest::parse_response(None, &request.get_uri(), response)`](SetExtensionConfigurationSegmentRequest::parse_response)
+//!
+//! ```rust
+//! use twitch_api2::helix::{
+//!     self,
+//!     extensions::{set_extension_configuration_segment, ConfigurationSegment},
+//!     AuthStyle, RequestPut,
+//! };
+//! let request =
+//!     set_extension_configuration_segment::SetExtensionConfigurationSegmentRequest::builder()
+//!         .build();
+//! let body = set_extension_configuration_segment::SetExtensionConfigurationSegmentBody::builder()
+//!     .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2")
+//!     .segment(ConfigurationSegment::Global)
+//!     .build();
+//! let http_request = request.create_request(body, AuthStyle::Extension("jwt"), "client_id")?;
+//! # let _ = http_request;
+//! # Ok::<(), helix::CreateRequestError>(())
+//! ```
+
+use super::*;
+use helix::RequestPut;
+
+/// Query Parameters for [Set Extension Configuration Segment](super::set_extension_configuration_segment)
+///
+/// [`set-extension-configuration-segment`](https://dev.twitch.tv/docs/api/reference#set-extension-configuration-segment)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct SetExtensionConfigurationSegmentRequest {}
+
+/// Body Parameters for [Set Extension Configuration Segment](super::set_extension_configuration_segment)
+///
+/// [`set-extension-configuration-segment`](https://dev.twitch.tv/docs/api/reference#set-extension-configuration-segment)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct SetExtensionConfigurationSegmentBody {
+    /// The ID of the extension to update.
+    #[builder(setter(into))]
+    pub extension_id: String,
+    /// The configuration segment to update.
+    pub segment: ConfigurationSegment,
+    /// The ID of the broadcaster that installed the extension. Required if `segment` is [`Broadcaster`](ConfigurationSegment::Broadcaster) or [`Developer`](ConfigurationSegment::Developer).
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub broadcaster_id: Option<types::UserId>,
+    /// The contents of the segment. This string may be a plain-text string or a string-encoded JSON object, see [`set_content_json`](Self::set_content_json).
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// The version number that identifies this definition of the segment’s data.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+impl SetExtensionConfigurationSegmentBody {
+    /// Set the [content](Self::content) to `content` encoded as JSON
+    pub fn set_content_json<T: Serialize + ?Sized>(
+        &mut self,
+        content: &T,
+    ) -> Result<(), serde_json::Error> {
+        self.content = Some(serde_json::to_string(content)?);
+        Ok(())
+    }
+}
+
+impl helix::private::SealedSerialize for SetExtensionConfigurationSegmentBody {}
+
+/// Return Values for [Set Extension Configuration Segment](super::set_extension_configuration_segment)
+///
+/// [`set-extension-configuration-segment`](https://dev.twitch.tv/docs/api/reference#set-extension-configuration-segment)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[non_exhaustive]
+pub enum SetExtensionConfigurationSegment {
+    /// 204 - Successfully updated the extension’s configuration.
+    Success,
+}
+
+impl Request for SetExtensionConfigurationSegmentRequest {
+    type Response = SetExtensionConfigurationSegment;
+
+    const AUTH: helix::AuthKind = helix::AuthKind::Extension;
+    const METHOD: http::Method = http::Method::PUT;
+    const PATH: &'static str = "extensions/configurations";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestPut for SetExtensionConfigurationSegmentRequest {
+    type Body = SetExtensionConfigurationSegmentBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
+    where
+        Self: Sized,
+    {
+        match status {
+            http::StatusCode::NO_CONTENT => Ok(helix::Response {
+                data: SetExtensionConfigurationSegment::Success,
+                pagination: None,
+                request,
+                total: None,
+                other: None,
+                request_id: None,
+                diagnostics: Default::default(),
+            }),
+            _ => Err(helix::HelixRequestError::InvalidResponse {
+                reason: "unexpected status",
+                response: response.to_string(),
+                status,
+                method: Self::METHOD,
+                uri: uri.clone(),
+                request_id: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = SetExtensionConfigurationSegmentRequest::builder().build();
+
+    let mut body = SetExtensionConfigurationSegmentBody::builder()
+        .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2")
+        .segment(ConfigurationSegment::Broadcaster)
+        .broadcaster_id(Some("1234".into()))
+        .version(Some("0.0.1".to_string()))
+        .build();
+    body.set_content_json(&["a", "b"]).unwrap();
+
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"extension_id":"uo6dggojyb8d6soh92zknwmi5ej1q2","segment":"broadcaster","broadcaster_id":"1234","content":"[\"a\",\"b\"]","version":"0.0.1"}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/extensions/configurations?"
+    );
+
+    dbg!(
+        SetExtensionConfigurationSegmentRequest::parse_response(Some(req), &uri, http_response)
+            .unwrap()
+    );
+}
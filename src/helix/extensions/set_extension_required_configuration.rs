@@ -0,0 +1,189 @@
+//! Updates the extension’s required configuration string.
+//! [`set-extension-required-configuration`](https://dev.twitch.tv/docs/api/reference#set-extension-required-configuration)
+//!
+//! Use this when the broadcaster has finished configuring the extension, so it can be activated.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [SetExtensionRequiredConfigurationRequest]
+//!
+//! To use this endpoint, construct a [`SetExtensionRequiredConfigurationRequest`] with the [`SetExtensionRequiredConfigurationRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::extensions::set_extension_required_configuration;
+//! let request =
+//!     set_extension_required_configuration::SetExtensionRequiredConfigurationRequest::builder()
+//!         .broadcaster_id("1234")
+//!         .build();
+//! ```
+//!
+//! ## Body: [SetExtensionRequiredConfigurationBody]
+//!
+//! We also need to provide a body to the request containing the configuration.
+//!
+//! ```
+//! # use twitch_api2::helix::extensions::set_extension_required_configuration;
+//! let body =
+//!     set_extension_required_configuration::SetExtensionRequiredConfigurationBody::builder()
+//!         .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2")
+//!         .extension_version("0.0.1")
+//!         .required_configuration("RCS")
+//!         .build();
+//! ```
+//!
+//! ## Response: [SetExtensionRequiredConfiguration]
+//!
+//! This endpoint is authorized with a signed JWT, which [`HelixClient`](helix::HelixClient) can not make.
+//! Create the [`http::Request`] with [`AuthStyle::Extension`](helix::AuthStyle::Extension), send it with your client
+//! and parse the [`http::Response`] with [`SetExtensionRequiredConfigurationRequest::parse_response(None, &request.get_uri(), response)`](SetExtensionRequiredConfigurationRequest::parse_response)
+//!
+//! ```rust
+//! use twitch_api2::helix::{
+//!     self, extensions::set_extension_required_configuration, AuthStyle, RequestPut,
+//! };
+//! let request =
+//!     set_extension_required_configuration::SetExtensionRequiredConfigurationRequest::builder()
+//!         .broadcaster_id("1234")
+//!         .build();
+//! let body =
+//!     set_extension_required_configuration::SetExtensionRequiredConfigurationBody::builder()
+//!         .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2")
+//!         .extension_version("0.0.1")
+//!         .required_configuration("RCS")
+//!         .build();
+//! let http_request = request.create_request(body, AuthStyle::Extension("jwt"), "client_id")?;
+//! # let _ = http_request;
+//! # Ok::<(), helix::CreateRequestError>(())
+//! ```
+
+use super::*;
+use helix::RequestPut;
+
+/// Query Parameters for [Set Extension Required Configuration](super::set_extension_required_configuration)
+///
+/// [`set-extension-required-configuration`](https://dev.twitch.tv/docs/api/reference#set-extension-required-configuration)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct SetExtensionRequiredConfigurationRequest {
+    /// The ID of the broadcaster that installed the extension on their channel.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+}
+
+/// Body Parameters for [Set Extension Required Configuration](super::set_extension_required_configuration)
+///
+/// [`set-extension-required-configuration`](https://dev.twitch.tv/docs/api/reference#set-extension-required-configuration)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct SetExtensionRequiredConfigurationBody {
+    /// The ID of the extension to update.
+    #[builder(setter(into))]
+    pub extension_id: String,
+    /// The version of the extension to update.
+    #[builder(setter(into))]
+    pub extension_version: String,
+    /// The required_configuration string to use with the extension.
+    #[builder(setter(into))]
+    pub required_configuration: String,
+}
+
+impl helix::private::SealedSerialize for SetExtensionRequiredConfigurationBody {}
+
+/// Return Values for [Set Extension Required Configuration](super::set_extension_required_configuration)
+///
+/// [`set-extension-required-configuration`](https://dev.twitch.tv/docs/api/reference#set-extension-required-configuration)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[non_exhaustive]
+pub enum SetExtensionRequiredConfiguration {
+    /// 204 - Successfully updated the extension’s required_configuration string.
+    Success,
+}
+
+impl Request for SetExtensionRequiredConfigurationRequest {
+    type Response = SetExtensionRequiredConfiguration;
+
+    const AUTH: helix::AuthKind = helix::AuthKind::Extension;
+    const METHOD: http::Method = http::Method::PUT;
+    const PATH: &'static str = "extensions/required_configuration";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestPut for SetExtensionRequiredConfigurationRequest {
+    type Body = SetExtensionRequiredConfigurationBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
+    where
+        Self: Sized,
+    {
+        match status {
+            http::StatusCode::NO_CONTENT => Ok(helix::Response {
+                data: SetExtensionRequiredConfiguration::Success,
+                pagination: None,
+                request,
+                total: None,
+                other: None,
+                request_id: None,
+                diagnostics: Default::default(),
+            }),
+            _ => Err(helix::HelixRequestError::InvalidResponse {
+                reason: "unexpected status",
+                response: response.to_string(),
+                status,
+                method: Self::METHOD,
+                uri: uri.clone(),
+                request_id: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = SetExtensionRequiredConfigurationRequest::builder()
+        .broadcaster_id("1234")
+        .build();
+
+    let body = SetExtensionRequiredConfigurationBody::builder()
+        .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2")
+        .extension_version("0.0.1")
+        .required_configuration("RCS")
+        .build();
+
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"extension_id":"uo6dggojyb8d6soh92zknwmi5ej1q2","extension_version":"0.0.1","required_configuration":"RCS"}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/extensions/required_configuration?broadcaster_id=1234"
+    );
+
+    dbg!(
+        SetExtensionRequiredConfigurationRequest::parse_response(Some(req), &uri, http_response)
+            .unwrap()
+    );
+}
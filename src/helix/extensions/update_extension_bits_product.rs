@@ -0,0 +1,191 @@
+//! Adds or updates a Bits product that the extension created.
+//! [`update-extension-bits-product`](https://dev.twitch.tv/docs/api/reference#update-extension-bits-product)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [UpdateExtensionBitsProductRequest]
+//!
+//! To use this endpoint, construct a [`UpdateExtensionBitsProductRequest`] with the [`UpdateExtensionBitsProductRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::extensions::update_extension_bits_product;
+//! let request =
+//!     update_extension_bits_product::UpdateExtensionBitsProductRequest::builder().build();
+//! ```
+//!
+//! ## Body: [UpdateExtensionBitsProductBody]
+//!
+//! We also need to provide a body to the request containing the product to add or update.
+//!
+//! ```
+//! # use twitch_api2::helix::extensions::{update_extension_bits_product, BitsProductCost};
+//! let body = update_extension_bits_product::UpdateExtensionBitsProductBody::builder()
+//!     .sku("1010")
+//!     .cost(BitsProductCost::bits(990))
+//!     .display_name("Rusty Crate 2")
+//!     .in_development(true)
+//!     .build();
+//! ```
+//!
+//! ## Response: [ExtensionBitsProduct]
+//!
+//! Send the request to receive the response with [`HelixClient::req_put()`](helix::HelixClient::req_put).
+//!
+//! The token must be an app access token, made with the client id of the extension.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, extensions::{update_extension_bits_product, BitsProductCost}};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let client_id = twitch_oauth2::ClientId::new("validclientid");
+//! # let client_secret = twitch_oauth2::ClientSecret::new("validclientsecret");
+//! # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+//! let request = update_extension_bits_product::UpdateExtensionBitsProductRequest::builder().build();
+//! let body = update_extension_bits_product::UpdateExtensionBitsProductBody::builder()
+//!     .sku("1010")
+//!     .cost(BitsProductCost::bits(990))
+//!     .display_name("Rusty Crate 2")
+//!     .build();
+//! let response: helix::extensions::ExtensionBitsProduct = client.req_put(request, body, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPut::create_request)
+//! and parse the [`http::Response`] with [`UpdateExtensionBitsProductRequest::parse_response(None, &request.get_uri(), response)`](UpdateExtensionBitsProductRequest::parse_response)
+
+use super::*;
+use helix::RequestPut;
+
+/// Query Parameters for [Update Extension Bits Product](super::update_extension_bits_product)
+///
+/// [`update-extension-bits-product`](https://dev.twitch.tv/docs/api/reference#update-extension-bits-product)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct UpdateExtensionBitsProductRequest {}
+
+/// Body Parameters for [Update Extension Bits Product](super::update_extension_bits_product)
+///
+/// [`update-extension-bits-product`](https://dev.twitch.tv/docs/api/reference#update-extension-bits-product)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct UpdateExtensionBitsProductBody {
+    /// The product’s SKU. The SKU must be unique within an extension. Maximum: 255 characters, no spaces.
+    #[builder(setter(into))]
+    pub sku: String,
+    /// The product’s price.
+    pub cost: BitsProductCost,
+    /// The product’s name as displayed in the extension. Maximum: 255 characters.
+    #[builder(setter(into))]
+    pub display_name: String,
+    /// Whether the product is in development. Defaults to false.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_development: Option<bool>,
+    /// The date and time when the product expires. If not set, the product does not expire.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<types::Timestamp>,
+    /// Whether transactions for this product are broadcast to all instances of the extension. Defaults to false.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_broadcast: Option<bool>,
+}
+
+impl helix::private::SealedSerialize for UpdateExtensionBitsProductBody {}
+
+impl Request for UpdateExtensionBitsProductRequest {
+    type Response = ExtensionBitsProduct;
+
+    const METHOD: http::Method = http::Method::PUT;
+    const PATH: &'static str = "bits/extensions";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestPut for UpdateExtensionBitsProductRequest {
+    type Body = UpdateExtensionBitsProductBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response_str: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
+    where
+        Self: Sized,
+    {
+        let response: helix::InnerResponse<Vec<Self::Response>> =
+            helix::parse_json_owned(response_str, true).map_err(|e| {
+                helix::HelixRequestError::DeserializeError(
+                    response_str.to_string(),
+                    e,
+                    Self::METHOD,
+                    uri.clone(),
+                    status,
+                    None,
+                )
+            })?;
+        let data = response.data.into_iter().next().ok_or_else(|| {
+            helix::HelixRequestError::InvalidResponse {
+                reason: "response included no data",
+                response: response_str.to_string(),
+                status,
+                method: Self::METHOD,
+                uri: uri.clone(),
+                request_id: None,
+            }
+        })?;
+        Ok(helix::Response {
+            data,
+            pagination: response.pagination.cursor,
+            request,
+            total: response.total,
+            other: None,
+            request_id: None,
+            diagnostics: Default::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = UpdateExtensionBitsProductRequest::builder().build();
+
+    let body = UpdateExtensionBitsProductBody::builder()
+        .sku("1010")
+        .cost(BitsProductCost::bits(990))
+        .display_name("Rusty Crate 2")
+        .in_development(true)
+        .build();
+
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"sku":"1010","cost":{"amount":990,"type":"bits"},"display_name":"Rusty Crate 2","in_development":true}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = crate::fixtures::helix::extensions::UPDATE_EXTENSION_BITS_PRODUCT
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/bits/extensions?"
+    );
+
+    dbg!(
+        UpdateExtensionBitsProductRequest::parse_response(Some(req), &uri, http_response).unwrap()
+    );
+}
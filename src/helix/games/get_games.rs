@@ -44,6 +44,7 @@ use helix::RequestGet;
 ///
 /// [`get-games`](https://dev.twitch.tv/docs/api/reference#get-games)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetGamesRequest {
     /// Game ID. At most 100 id values can be specified.
@@ -62,6 +63,7 @@ pub type Game = types::TwitchCategory;
 impl Request for GetGamesRequest {
     type Response = Vec<Game>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "games";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -76,26 +78,7 @@ fn test_request() {
     let req = GetGamesRequest::builder().id(vec!["493057".into()]).build();
 
     // From twitch docs
-    let data = br#"
-{
-    "data": [
-        {
-            "box_art_url": "https://static-cdn.jtvnw.net/ttv-boxart/Fortnite-52x72.jpg",
-            "id": "33214",
-            "name": "Fortnite"
-        },
-        {
-            "box_art_url": "https://static-cdn.jtvnw.net/ttv-boxart/Fortnite-52x72.jpg",
-            "id": "33214",
-            "name": "Fortnite"
-        }
-    ],
-    "pagination": {
-    "cursor": "eyJiIjpudWxsLCJhIjp7IkN"
-  }
-}
-"#
-    .to_vec();
+    let data = crate::fixtures::helix::games::GET_GAMES.as_bytes().to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
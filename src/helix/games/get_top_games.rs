@@ -8,10 +8,11 @@
 //! To use this endpoint, construct a [`GetTopGamesRequest`] with the [`GetTopGamesRequest::builder()`] method.
 //!
 //! ```rust
-//! use twitch_api2::helix::games::get_top_games;
+//! use twitch_api2::helix::{games::get_top_games, PageSize, PaginationParams};
 //! let request = get_top_games::GetTopGamesRequest::builder()
-//!     .first(100)
+//!     .pagination(PaginationParams::default().with_first(PageSize::new(100)?))
 //!     .build();
+//! # Ok::<(), twitch_api2::helix::PageSizeError>(())
 //! ```
 //!
 //! ## Response: [Game](types::TwitchCategory)
@@ -43,17 +44,13 @@ use helix::RequestGet;
 ///
 /// [`get-top-games`](https://dev.twitch.tv/docs/api/reference#get-top-games)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetTopGamesRequest {
-    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
-    #[builder(default, setter(into))]
-    pub after: Option<helix::Cursor>,
-    /// Cursor for backward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
-    #[builder(default, setter(into))]
-    pub before: Option<helix::Cursor>,
-    /// Maximum number of objects to return. Maximum: 100. Default: 20.
-    #[builder(default, setter(into))]
-    pub first: Option<usize>,
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
+    #[builder(default)]
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
 }
 
 /// Return Values for [Get Top Games](super::get_games)
@@ -64,15 +61,18 @@ pub type Game = types::TwitchCategory;
 impl Request for GetTopGamesRequest {
     type Response = Vec<Game>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "games/top";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for GetTopGamesRequest {}
 
 impl helix::Paginated for GetTopGamesRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
 }
 
 #[cfg(test)]
@@ -82,23 +82,8 @@ fn test_request() {
     let req = GetTopGamesRequest::builder().build();
 
     // From twitch docs
-    let data = br#"
-{
-    "data": [
-      {
-        "id": "493057",
-        "name": "PLAYERUNKNOWN'S BATTLEGROUNDS",
-        "box_art_url": "https://static-cdn.jtvnw.net/ttv-boxart/PLAYERUNKNOWN%27S%20BATTLEGROUNDS-{width}x{height}.jpg"
-      },
-      {
-        "id": "493057",
-        "name": "PLAYERUNKNOWN'S BATTLEGROUNDS",
-        "box_art_url": "https://static-cdn.jtvnw.net/ttv-boxart/PLAYERUNKNOWN%27S%20BATTLEGROUNDS-{width}x{height}.jpg"
-      }
-    ],
-    "pagination":{"cursor":"eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6MjB9fQ=="}
-}
-"#
+    let data = crate::fixtures::helix::games::GET_TOP_GAMES
+        .as_bytes()
         .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
@@ -10,7 +10,7 @@
 //! ```rust
 //! use twitch_api2::helix::goals::get_creator_goals;
 //! let request = get_creator_goals::GetCreatorGoalsRequest::builder()
-//!     .broadcaster_id("4321".to_string())
+//!     .broadcaster_id("4321")
 //!     .build();
 //! ```
 //!
@@ -27,7 +27,7 @@
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
 //! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
 //! let request = get_creator_goals::GetCreatorGoalsRequest::builder()
-//!     .broadcaster_id("4321".to_string())
+//!     .broadcaster_id("4321")
 //!     .build();
 //! let response: Vec<get_creator_goals::CreatorGoal> = client.req_get(request, &token).await?.data;
 //! # Ok(())
@@ -44,19 +44,20 @@ use helix::RequestGet;
 ///
 /// [`get-creator-goals`](https://dev.twitch.tv/docs/api/reference#get-creator-goals)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetCreatorGoalsRequest {
     /// Must match the User ID in the Bearer token.
     #[builder(setter(into))]
     pub broadcaster_id: types::UserId,
     /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
-    #[builder(default)]
+    #[builder(default, setter(into))]
     pub cursor: Option<helix::Cursor>,
     /// Maximum number of objects to return. Maximum: 100. Default: 20.
     #[builder(default, setter(into))]
-    pub first: Option<usize>,
+    pub first: Option<helix::PageSize>,
     /// Retreive a single event by event ID
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub id: Option<String>,
 }
 
@@ -64,6 +65,11 @@ pub struct GetCreatorGoalsRequest {
 ///
 /// [`get-creator-goals`](https://dev.twitch.tv/docs/api/reference#get-creator-goals)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct CreatorGoal {
@@ -91,6 +97,7 @@ pub struct CreatorGoal {
 impl Request for GetCreatorGoalsRequest {
     type Response = Vec<CreatorGoal>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "goals";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelReadGoals];
@@ -103,28 +110,13 @@ impl RequestGet for GetCreatorGoalsRequest {}
 fn test_request() {
     use helix::*;
     let req = GetCreatorGoalsRequest::builder()
-        .broadcaster_id("141981764".to_string())
+        .broadcaster_id("141981764")
         .build();
 
     // From twitch docs
-    let data = br#"
-    {
-        "data": [
-          {
-            "id": "1woowvbkiNv8BRxEWSqmQz6Zk92",
-            "broadcaster_id": "141981764",
-            "broadcaster_name": "TwitchDev",
-            "broadcaster_login": "twitchdev",
-            "type": "follower",
-            "description": "Follow goal for Helix testing",
-            "current_amount": 27062,
-            "target_amount": 30000,
-            "created_at": "2021-08-16T17:22:23Z"
-          }
-        ]
-      }
-"#
-    .to_vec();
+    let data = crate::fixtures::helix::goals::GET_CREATOR_GOALS
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -10,7 +10,7 @@
 //! ```rust
 //! use twitch_api2::helix::hypetrain::get_hypetrain_events;
 //! let request = get_hypetrain_events::GetHypeTrainEventsRequest::builder()
-//!     .broadcaster_id("4321".to_string())
+//!     .broadcaster_id("4321")
 //!     .build();
 //! ```
 //!
@@ -27,7 +27,7 @@
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
 //! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
 //! let request = get_hypetrain_events::GetHypeTrainEventsRequest::builder()
-//!     .broadcaster_id("4321".to_string())
+//!     .broadcaster_id("4321")
 //!     .build();
 //! let response: Vec<get_hypetrain_events::HypeTrainEvent> = client.req_get(request, &token).await?.data;
 //! # Ok(())
@@ -44,26 +44,29 @@ use helix::RequestGet;
 ///
 /// [`get-hype-train-events`](https://dev.twitch.tv/docs/api/reference#get-hype-train-events)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetHypeTrainEventsRequest {
     /// Must match the User ID in the Bearer token.
     #[builder(setter(into))]
     pub broadcaster_id: types::UserId,
-    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 1.
+    ///
+    /// Backward pagination with [`before`](helix::PaginationParams::before) is not supported.
     #[builder(default)]
-    pub cursor: Option<helix::Cursor>,
-    /// Maximum number of objects to return. Maximum: 100. Default: 20.
-    #[builder(default, setter(into))]
-    pub first: Option<usize>,
-    /// Retreive a single event by event ID
-    #[builder(default, setter(into))]
-    pub id: Option<String>,
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
 }
 
 /// Return Values for [Get Hype Train Events](super::get_hypetrain_events)
 ///
 /// [`get-hype-train-events`](https://dev.twitch.tv/docs/api/reference#get-hype-train-events)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct HypeTrainEvent {
@@ -81,6 +84,11 @@ pub struct HypeTrainEvent {
 
 /// Type of Hype Train event
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[non_exhaustive]
 pub enum HypeTrainEventType {
     /// Progression
@@ -90,6 +98,11 @@ pub enum HypeTrainEventType {
 
 /// Event data for
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct HypeTrainEventData {
@@ -109,6 +122,7 @@ pub struct HypeTrainEventData {
     pub started_at: types::Timestamp,
     // FIXME: Contains a maximum of two user objects
     /// The contributors with the most points contributed.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub top_contributions: Vec<Contribution>,
     /// Total points contributed to the hype train.
     pub total: i64,
@@ -119,15 +133,18 @@ pub struct HypeTrainEventData {
 impl Request for GetHypeTrainEventsRequest {
     type Response = Vec<HypeTrainEvent>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "hypetrain/events";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for GetHypeTrainEventsRequest {}
 
 impl helix::Paginated for GetHypeTrainEventsRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.cursor = cursor }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
 }
 
 #[cfg(test)]
@@ -135,48 +152,13 @@ impl helix::Paginated for GetHypeTrainEventsRequest {
 fn test_request() {
     use helix::*;
     let req = GetHypeTrainEventsRequest::builder()
-        .broadcaster_id("270954519".to_string())
+        .broadcaster_id("270954519")
         .build();
 
     // From twitch docs
-    let data = br#"
-    {
-        "data": [
-          {
-            "id": "1b0AsbInCHZW2SQFQkCzqN07Ib2",
-            "event_type": "hypetrain.progression",
-            "event_timestamp": "2020-04-24T20:07:24Z",
-            "version": "1.0",
-            "event_data": {
-              "broadcaster_id": "270954519",
-              "cooldown_end_time": "2020-04-24T20:13:21.003802269Z",
-              "expires_at": "2020-04-24T20:12:21.003802269Z",
-              "goal": 1800,
-              "id": "70f0c7d8-ff60-4c50-b138-f3a352833b50",
-              "last_contribution": {
-                "total": 200,
-                "type": "BITS",
-                "user": "134247454"
-              },
-              "level": 2,
-              "started_at": "2020-04-24T20:05:47.30473127Z",
-              "top_contributions": [
-                {
-                  "total": 600,
-                  "type": "BITS",
-                  "user": "134247450"
-                }
-              ],
-              "total": 600
-            }
-          }
-        ],
-        "pagination": {
-          "cursor": "eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6IjI3MDk1NDUxOToxNTg3NzU4ODQ0OjFiMEFzYkluQ0haVzJTUUZRa0N6cU4wN0liMiJ9fQ"
-        }
-      }
-"#
-    .to_vec();
+    let data = crate::fixtures::helix::hypetrain::GET_HYPETRAIN_EVENTS
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -186,5 +168,31 @@ fn test_request() {
         "https://api.twitch.tv/helix/hypetrain/events?broadcaster_id=270954519"
     );
 
-    dbg!(GetHypeTrainEventsRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    let response =
+        GetHypeTrainEventsRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    let mut req = response.request.clone().unwrap();
+    req.set_pagination(response.pagination.clone());
+    assert_eq!(
+        req.get_uri().unwrap().to_string(),
+        "https://api.twitch.tv/helix/hypetrain/events?broadcaster_id=270954519&after=eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6IjI3MDk1NDUxOToxNTg3NzU4ODQ0OjFiMEFzYkluQ0haVzJTUUZRa0N6cU4wN0liMiJ9fQ"
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_pagination() {
+    use helix::*;
+    // Query parameters documented by Twitch
+    let req = GetHypeTrainEventsRequest::builder()
+        .broadcaster_id("270954519")
+        .pagination(
+            PaginationParams::default()
+                .with_after(Cursor::from("eyJiIjpudWxs"))
+                .with_first(PageSize::new(100).unwrap()),
+        )
+        .build();
+    assert_eq!(
+        req.get_uri().unwrap().to_string(),
+        "https://api.twitch.tv/helix/hypetrain/events?broadcaster_id=270954519&after=eyJiIjpudWxs&first=100"
+    );
 }
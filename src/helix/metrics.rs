@@ -0,0 +1,82 @@
+//! Metrics for requests made with [`HelixClient`](super::HelixClient), enabled with the `metrics` feature.
+//!
+//! Metrics are emitted through the [`metrics`](::metrics) facade, install a recorder/exporter of your choice to collect them.
+//! Every metric is labeled with `path`, the [`Request::PATH`](super::Request::PATH) of the endpoint, and `method`, the http method used.
+//!
+//! | Metric | Kind | Description |
+//! | ------ | ---- | ----------- |
+//! | `twitch_api2_helix_requests_total` | counter | Requests made, additionally labeled with `status` being one of `2xx`, `3xx`, `4xx`, `5xx` or `error` if no response was received |
+//! | `twitch_api2_helix_request_duration_seconds` | histogram | Time taken from sending the request to receiving the response |
+//! | `twitch_api2_helix_response_size_bytes` | histogram | Size of the response body |
+
+/// Name of the request counter
+pub const REQUESTS_TOTAL: &str = "twitch_api2_helix_requests_total";
+/// Name of the request latency histogram
+pub const REQUEST_DURATION_SECONDS: &str = "twitch_api2_helix_request_duration_seconds";
+/// Name of the response size histogram
+pub const RESPONSE_SIZE_BYTES: &str = "twitch_api2_helix_response_size_bytes";
+
+/// Measures a single request
+pub(crate) struct RequestMetrics {
+    path: &'static str,
+    method: &'static str,
+    start: std::time::Instant,
+}
+
+impl RequestMetrics {
+    /// Start measuring a request
+    pub(crate) fn start(path: &'static str, method: &http::Method) -> Self {
+        RequestMetrics {
+            path,
+            method: method_label(method),
+            start: std::time::Instant::now(),
+        }
+    }
+
+    /// Record the outcome of the request
+    pub(crate) fn finish<E>(self, response: &Result<http::Response<Vec<u8>>, E>) {
+        let elapsed = self.start.elapsed();
+        let (path, method) = (self.path, self.method);
+        let status = match response {
+            Ok(response) => status_label(response.status()),
+            Err(_) => "error",
+        };
+        ::metrics::increment_counter!(REQUESTS_TOTAL, "path" => path, "method" => method, "status" => status);
+        if let Ok(response) = response {
+            ::metrics::histogram!(REQUEST_DURATION_SECONDS, elapsed.as_secs_f64(), "path" => path, "method" => method);
+            ::metrics::histogram!(RESPONSE_SIZE_BYTES, response.body().len() as f64, "path" => path, "method" => method);
+        }
+    }
+}
+
+/// Static label for a http method, helix only uses the standard methods.
+fn method_label(method: &http::Method) -> &'static str {
+    match *method {
+        http::Method::GET => "GET",
+        http::Method::POST => "POST",
+        http::Method::PUT => "PUT",
+        http::Method::PATCH => "PATCH",
+        http::Method::DELETE => "DELETE",
+        _ => "OTHER",
+    }
+}
+
+/// Status class of a response, to keep the amount of label values low.
+fn status_label(status: http::StatusCode) -> &'static str {
+    match status.as_u16() {
+        200..=299 => "2xx",
+        300..=399 => "3xx",
+        400..=499 => "4xx",
+        500..=599 => "5xx",
+        _ => "other",
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn labels() {
+    assert_eq!(method_label(&http::Method::PATCH), "PATCH");
+    assert_eq!(status_label(http::StatusCode::NO_CONTENT), "2xx");
+    assert_eq!(status_label(http::StatusCode::TOO_MANY_REQUESTS), "4xx");
+    assert_eq!(status_label(http::StatusCode::BAD_GATEWAY), "5xx");
+}
@@ -15,7 +15,7 @@
 //!     .build();
 //!
 //! // Send it however you want
-//! // Create a [`http::Response<Vec<u8>>`] with RequestGet::create_request, which takes an access token and a client_id
+//! // Create a [`http::Response<Vec<u8>>`] with RequestGet::create_request, which takes an access token (or another [`AuthStyle`]) and a client_id
 //! let response = send_http_request(request.create_request("accesstoken", "client_id")?)?;
 //!
 //! // then parse the response
@@ -43,23 +43,48 @@ mod client_ext;
 
 #[cfg(all(feature = "client"))]
 #[cfg_attr(nightly, doc(cfg(all(feature = "client", feature = "helix"))))]
-pub use client_ext::make_stream;
+pub use client_ext::{make_stream, ChannelRoles, DashboardSnapshot, LiveChannel, Roles};
+
+#[cfg(all(feature = "client", feature = "eventsub"))]
+#[cfg_attr(
+    nightly,
+    doc(cfg(all(feature = "client", feature = "helix", feature = "eventsub")))
+)]
+pub use client_ext::{DeletedEventSubSubscriptions, EVENTSUB_DELETE_CONCURRENCY};
 
 pub mod bits;
 pub mod channels;
+pub mod charity;
 pub mod chat;
 pub mod clips;
+#[cfg(feature = "client")]
+#[cfg_attr(nightly, doc(cfg(feature = "client")))]
+pub mod conditional;
+pub mod diagnostics;
+pub mod encoding;
+pub mod endpoints;
 #[cfg(feature = "eventsub")]
 #[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
 pub mod eventsub;
+pub mod extensions;
 pub mod games;
 pub mod goals;
 pub mod hypetrain;
 pub mod moderation;
+#[cfg(feature = "client")]
+#[cfg_attr(nightly, doc(cfg(feature = "client")))]
+pub mod multi_tenant;
 pub mod points;
 pub mod polls;
 pub mod predictions;
+pub mod prepared;
+#[cfg(feature = "client")]
+#[cfg_attr(nightly, doc(cfg(feature = "client")))]
+pub mod proxy;
 pub mod schedule;
+#[cfg(feature = "client")]
+#[cfg_attr(nightly, doc(cfg(feature = "client")))]
+pub mod scoped;
 pub mod search;
 pub mod streams;
 pub mod subscriptions;
@@ -67,10 +92,14 @@ pub mod tags;
 pub mod teams;
 pub mod users;
 pub mod videos;
+pub mod whispers;
 
+#[cfg(all(feature = "client", feature = "metrics"))]
+#[cfg_attr(nightly, doc(cfg(all(feature = "client", feature = "metrics"))))]
+pub mod metrics;
 pub(crate) mod ser;
 pub(crate) use crate::deserialize_default_from_null;
-use crate::{parse_json, parse_json_value};
+use crate::{parse_json, parse_json_owned, parse_json_value};
 pub use ser::Error as SerializeError;
 
 #[doc(no_inline)]
@@ -119,6 +148,7 @@ pub use twitch_oauth2::Scope;
 pub struct HelixClient<'a, C>
 where C: crate::HttpClient<'a> {
     pub(crate) client: C,
+    compression: bool,
     _pd: std::marker::PhantomData<&'a ()>, // TODO: Implement rate limiter...
 }
 
@@ -130,10 +160,44 @@ struct InnerResponse<D> {
     pagination: Pagination,
     #[serde(default)]
     total: Option<i64>,
+    /// Only filled by [`InnerResponse::parse`] when the request [collects other fields](Request::COLLECT_OTHER_FIELDS).
+    #[serde(skip)]
+    other: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+/// [`InnerResponse`] with unknown fields collected into `other`.
+///
+/// `flatten` makes serde buffer the whole response before deserializing `data`, so this is only used when needed.
+#[derive(Deserialize)]
+struct InnerResponseWithOther<D> {
+    data: D,
+    #[serde(default)]
+    pagination: Pagination,
+    #[serde(default)]
+    total: Option<i64>,
     #[serde(default, flatten)]
     other: Option<serde_json::Map<String, serde_json::Value>>,
 }
 
+impl<D> InnerResponse<D>
+where D: serde::de::DeserializeOwned
+{
+    /// Parse a response for `R`, collecting fields not part of the response into `other` only if `R` [asks for it](Request::COLLECT_OTHER_FIELDS).
+    fn parse<R: Request>(s: &str) -> Result<Self, crate::DeserError> {
+        if R::COLLECT_OTHER_FIELDS {
+            let response: InnerResponseWithOther<D> = parse_json_owned(s, true)?;
+            Ok(InnerResponse {
+                data: response.data,
+                pagination: response.pagination,
+                total: response.total,
+                other: response.other,
+            })
+        } else {
+            parse_json_owned(s, true)
+        }
+    }
+}
+
 #[derive(Deserialize, Debug)]
 #[cfg(feature = "unsupported")]
 #[cfg_attr(nightly, doc(cfg(feature = "unsupported")))]
@@ -150,18 +214,36 @@ struct CustomInnerResponse<'a> {
 }
 
 #[derive(Deserialize, Clone, Debug)]
-struct HelixRequestError {
+struct HelixErrorResponse {
     error: String,
     status: u16,
     message: String,
 }
 
+/// Headers that Twitch uses to identify a request, in order of preference.
+const REQUEST_ID_HEADERS: &[&str] = &["Twitch-Request-Id", "Twitch-Trace-Id"];
+
+/// Get the ID Twitch assigned to the request that produced this response, if any.
+///
+/// Include this when reporting an issue with a request to Twitch, it lets them find the request in their logs.
+/// The ID is also available on [`Response::request_id`] and on the errors returned when parsing a response.
+pub fn request_id<B>(response: &http::Response<B>) -> Option<String> {
+    REQUEST_ID_HEADERS.iter().find_map(|header| {
+        response
+            .headers()
+            .get(*header)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_owned())
+    })
+}
+
 #[cfg(feature = "client")]
 impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
     /// Create a new client with an existing client
     pub fn with_client(client: C) -> HelixClient<'a, C> {
         HelixClient {
             client,
+            compression: true,
             _pd: std::marker::PhantomData::default(),
         }
     }
@@ -182,6 +264,61 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
     /// Retrieve a reference of the [`HttpClient`][crate::HttpClient] inside this [`HelixClient`]
     pub fn get_client(&self) -> &C { &self.client }
 
+    /// Enable or disable [compressed responses](encoding), enabled by default.
+    ///
+    /// Only has an effect with the `gzip` or `brotli` feature.
+    pub fn set_compression(&mut self, enabled: bool) { self.compression = enabled }
+
+    /// Send a request with the inner [`HttpClient`][crate::HttpClient], recording metrics for it if the `metrics` feature is enabled.
+    ///
+    /// The response is [decompressed](encoding) if needed. Redirects are never followed by Helix, responses with a `3xx` status other than `304 Not Modified`
+    /// are returned as [`ClientRequestError::Redirect`] so that the redirect handling of the http client does not matter.
+    async fn send<R: Request>(
+        &'a self,
+        req: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, ClientRequestError<<C as crate::HttpClient<'a>>::Error>>
+    {
+        self.send_to(R::PATH, &R::METHOD, req).await
+    }
+
+    /// Like [`send`](HelixClient::send), for a request to the endpoint at `path` with `method` when its [`Request`] type is not known.
+    #[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+    async fn send_to(
+        &'a self,
+        path: &'static str,
+        method: &http::Method,
+        mut req: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, ClientRequestError<<C as crate::HttpClient<'a>>::Error>>
+    {
+        if !self.compression {
+            req.headers_mut().remove(http::header::ACCEPT_ENCODING);
+        }
+        #[cfg(feature = "metrics")]
+        let metrics = metrics::RequestMetrics::start(path, method);
+        let response = self.client.req(req).await;
+        #[cfg(feature = "metrics")]
+        metrics.finish(&response);
+        let response = response.map_err(ClientRequestError::RequestError)?;
+        // Helix never redirects, so a redirect followed or not by the http client should not change what is returned.
+        // `304 Not Modified` is the answer to a conditional request.
+        if response.status().is_redirection() && response.status() != http::StatusCode::NOT_MODIFIED
+        {
+            return Err(ClientRequestError::Redirect {
+                status: response.status(),
+                location: response
+                    .headers()
+                    .get(http::header::LOCATION)
+                    .and_then(|location| location.to_str().ok())
+                    .map(ToOwned::to_owned),
+            });
+        }
+        if self.compression {
+            encoding::decompress_response(response).map_err(ClientRequestError::DecompressError)
+        } else {
+            Ok(response)
+        }
+    }
+
     /// Request on a valid [`RequestGet`] endpoint
     ///
     /// ```rust,no_run
@@ -212,11 +349,7 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
     {
         let req = request.create_request(token.token().secret(), token.client_id().as_str())?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let response = self.send::<R>(req).await?;
         <R>::parse_response(Some(request), &uri, response).map_err(Into::into)
     }
 
@@ -236,11 +369,7 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
         let req =
             request.create_request(body, token.token().secret(), token.client_id().as_str())?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let response = self.send::<R>(req).await?;
         <R>::parse_response(Some(request), &uri, response).map_err(Into::into)
     }
 
@@ -260,11 +389,7 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
         let req =
             request.create_request(body, token.token().secret(), token.client_id().as_str())?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let response = self.send::<R>(req).await?;
         <R>::parse_response(Some(request), &uri, response).map_err(Into::into)
     }
 
@@ -281,11 +406,7 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
     {
         let req = request.create_request(token.token().secret(), token.client_id().as_str())?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let response = self.send::<R>(req).await?;
         <R>::parse_response(Some(request), &uri, response).map_err(Into::into)
     }
 
@@ -305,11 +426,7 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
         let req =
             request.create_request(body, token.token().secret(), token.client_id().as_str())?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let response = self.send::<R>(req).await?;
         <R>::parse_response(Some(request), &uri, response).map_err(Into::into)
     }
 }
@@ -331,38 +448,46 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
     {
         let req = request.create_request(token.token().secret(), token.client_id().as_str())?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let response = self.send::<R>(req).await?;
         {
             let request = Some(request);
             let uri = &uri;
+            let request_id = request_id(&response);
             let text = std::str::from_utf8(response.body()).map_err(|e| {
-                HelixRequestGetError::Utf8Error(response.body().clone(), e, uri.clone())
+                HelixRequestError::Utf8Error(
+                    response.body().clone(),
+                    e,
+                    R::METHOD,
+                    uri.clone(),
+                    request_id.clone(),
+                )
             })?;
             //eprintln!("\n\nmessage is ------------ {} ------------", text);
-            if let Ok(HelixRequestError {
+            if let Ok(HelixErrorResponse {
                 error,
                 status,
                 message,
-            }) = parse_json::<HelixRequestError>(text, false)
+            }) = parse_json::<HelixErrorResponse>(text, false)
             {
-                return Err(HelixRequestGetError::Error {
+                return Err(HelixRequestError::Error {
                     error,
                     status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                     message,
+                    method: R::METHOD,
                     uri: uri.clone(),
+                    body: response.body().clone(),
+                    request_id: request_id.clone(),
                 }
                 .into());
             }
             let response: CustomInnerResponse<'_> = crate::parse_json(text, true).map_err(|e| {
-                HelixRequestGetError::DeserializeError(
+                HelixRequestError::DeserializeError(
                     text.to_owned(),
                     e,
+                    R::METHOD,
                     uri.clone(),
                     response.status(),
+                    request_id.clone(),
                 )
             })?;
             Ok(CustomResponse {
@@ -371,6 +496,7 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
                 total: response.total,
                 other: response.other,
                 raw_data: response.data.to_owned(),
+                request_id,
                 pd: <_>::default(),
             })
         }
@@ -393,39 +519,46 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
         let req =
             request.create_request(body, token.token().secret(), token.client_id().as_str())?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let response = self.send::<R>(req).await?;
         {
             let request = Some(request);
             let uri = &uri;
+            let request_id = request_id(&response);
             let text = std::str::from_utf8(response.body()).map_err(|e| {
-                HelixRequestPostError::Utf8Error(response.body().clone(), e, uri.clone())
+                HelixRequestError::Utf8Error(
+                    response.body().clone(),
+                    e,
+                    R::METHOD,
+                    uri.clone(),
+                    request_id.clone(),
+                )
             })?;
             //eprintln!("\n\nmessage is ------------ {} ------------", text);
-            if let Ok(HelixRequestError {
+            if let Ok(HelixErrorResponse {
                 error,
                 status,
                 message,
-            }) = parse_json::<HelixRequestError>(text, false)
+            }) = parse_json::<HelixErrorResponse>(text, false)
             {
-                return Err(HelixRequestPostError::Error {
+                return Err(HelixRequestError::Error {
                     error,
                     status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                     message,
+                    method: R::METHOD,
                     uri: uri.clone(),
                     body: response.body().clone(),
+                    request_id: request_id.clone(),
                 }
                 .into());
             }
             let response: CustomInnerResponse<'_> = crate::parse_json(text, true).map_err(|e| {
-                HelixRequestPostError::DeserializeError(
+                HelixRequestError::DeserializeError(
                     text.to_owned(),
                     e,
+                    R::METHOD,
                     uri.clone(),
                     response.status(),
+                    request_id.clone(),
                 )
             })?;
             Ok(CustomResponse {
@@ -434,6 +567,7 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
                 total: response.total,
                 other: response.other,
                 raw_data: response.data.to_owned(),
+                request_id,
                 pd: <_>::default(),
             })
         }
@@ -457,43 +591,50 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
         D: serde::de::Deserialize<'d> + 'd,
         T: TwitchToken + ?Sized,
         C: Send,
-        F: Fn(&R, &http::Uri, &str, http::StatusCode) -> Result<(), HelixRequestPatchError>,
+        F: Fn(&R, &http::Uri, &str, http::StatusCode) -> Result<(), HelixRequestError>,
     {
         let req =
             request.create_request(body, token.token().secret(), token.client_id().as_str())?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let response = self.send::<R>(req).await?;
         {
             let uri = &uri;
+            let request_id = request_id(&response);
             let text = std::str::from_utf8(response.body()).map_err(|e| {
-                HelixRequestPatchError::Utf8Error(response.body().clone(), e, uri.clone())
+                HelixRequestError::Utf8Error(
+                    response.body().clone(),
+                    e,
+                    R::METHOD,
+                    uri.clone(),
+                    request_id.clone(),
+                )
             })?;
-            if let Ok(HelixRequestError {
+            if let Ok(HelixErrorResponse {
                 error,
                 status,
                 message,
-            }) = parse_json::<HelixRequestError>(text, false)
+            }) = parse_json::<HelixErrorResponse>(text, false)
             {
-                return Err(HelixRequestPatchError::Error {
+                return Err(HelixRequestError::Error {
                     error,
                     status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                     message,
+                    method: R::METHOD,
                     uri: uri.clone(),
                     body: response.body().clone(),
+                    request_id: request_id.clone(),
                 }
                 .into());
             }
             function(&request, uri, text, response.status())?;
             let response: CustomInnerResponse<'_> = crate::parse_json(text, true).map_err(|e| {
-                HelixRequestPatchError::DeserializeError(
+                HelixRequestError::DeserializeError(
                     text.to_owned(),
                     e,
+                    R::METHOD,
                     uri.clone(),
                     response.status(),
+                    request_id.clone(),
                 )
             })?;
             Ok(CustomResponse {
@@ -502,6 +643,7 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
                 total: response.total,
                 other: response.other,
                 raw_data: response.data.to_owned(),
+                request_id,
                 pd: <_>::default(),
             })
         }
@@ -523,42 +665,49 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
         D: serde::de::Deserialize<'d> + 'd,
         T: TwitchToken + ?Sized,
         C: Send,
-        F: Fn(&R, &http::Uri, &str, http::StatusCode) -> Result<(), HelixRequestDeleteError>,
+        F: Fn(&R, &http::Uri, &str, http::StatusCode) -> Result<(), HelixRequestError>,
     {
         let req = request.create_request(token.token().secret(), token.client_id().as_str())?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let response = self.send::<R>(req).await?;
         {
             let uri = &uri;
+            let request_id = request_id(&response);
             let text = std::str::from_utf8(response.body()).map_err(|e| {
-                HelixRequestDeleteError::Utf8Error(response.body().clone(), e, uri.clone())
+                HelixRequestError::Utf8Error(
+                    response.body().clone(),
+                    e,
+                    R::METHOD,
+                    uri.clone(),
+                    request_id.clone(),
+                )
             })?;
-            if let Ok(HelixRequestError {
+            if let Ok(HelixErrorResponse {
                 error,
                 status,
                 message,
-            }) = parse_json::<HelixRequestError>(text, false)
+            }) = parse_json::<HelixErrorResponse>(text, false)
             {
-                return Err(HelixRequestDeleteError::Error {
+                return Err(HelixRequestError::Error {
                     error,
                     status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                     message,
+                    method: R::METHOD,
                     uri: uri.clone(),
                     body: response.body().clone(),
+                    request_id: request_id.clone(),
                 }
                 .into());
             }
             function(&request, uri, text, response.status())?;
             let response: CustomInnerResponse<'_> = crate::parse_json(text, true).map_err(|e| {
-                HelixRequestPatchError::DeserializeError(
+                HelixRequestError::DeserializeError(
                     text.to_owned(),
                     e,
+                    R::METHOD,
                     uri.clone(),
                     response.status(),
+                    request_id.clone(),
                 )
             })?;
             Ok(CustomResponse {
@@ -567,6 +716,7 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
                 total: response.total,
                 other: response.other,
                 raw_data: response.data.to_owned(),
+                request_id,
                 pd: <_>::default(),
             })
         }
@@ -590,43 +740,50 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
         D: serde::de::Deserialize<'d> + 'd,
         T: TwitchToken + ?Sized,
         C: Send,
-        F: Fn(&R, &http::Uri, &str, http::StatusCode) -> Result<(), HelixRequestDeleteError>,
+        F: Fn(&R, &http::Uri, &str, http::StatusCode) -> Result<(), HelixRequestError>,
     {
         let req =
             request.create_request(body, token.token().secret(), token.client_id().as_str())?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let response = self.send::<R>(req).await?;
         {
             let uri = &uri;
+            let request_id = request_id(&response);
             let text = std::str::from_utf8(response.body()).map_err(|e| {
-                HelixRequestPutError::Utf8Error(response.body().clone(), e, uri.clone())
+                HelixRequestError::Utf8Error(
+                    response.body().clone(),
+                    e,
+                    R::METHOD,
+                    uri.clone(),
+                    request_id.clone(),
+                )
             })?;
-            if let Ok(HelixRequestError {
+            if let Ok(HelixErrorResponse {
                 error,
                 status,
                 message,
-            }) = parse_json::<HelixRequestError>(text, false)
+            }) = parse_json::<HelixErrorResponse>(text, false)
             {
-                return Err(HelixRequestPutError::Error {
+                return Err(HelixRequestError::Error {
                     error,
                     status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                     message,
+                    method: R::METHOD,
                     uri: uri.clone(),
                     body: response.body().clone(),
+                    request_id: request_id.clone(),
                 }
                 .into());
             }
             function(&request, uri, text, response.status())?;
             let response: CustomInnerResponse<'_> = crate::parse_json(text, true).map_err(|e| {
-                HelixRequestPatchError::DeserializeError(
+                HelixRequestError::DeserializeError(
                     text.to_owned(),
                     e,
+                    R::METHOD,
                     uri.clone(),
                     response.status(),
+                    request_id.clone(),
                 )
             })?;
             Ok(CustomResponse {
@@ -635,6 +792,7 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
                 total: response.total,
                 other: response.other,
                 raw_data: response.data.to_owned(),
+                request_id,
                 pd: <_>::default(),
             })
         }
@@ -655,7 +813,11 @@ where
     T: serde::de::DeserializeOwned, {
     let val = serde_json::Value::deserialize(deserializer)?;
     match val {
-        serde_json::Value::String(string) if string.is_empty() => Ok(None),
+        serde_json::Value::String(string) if string.is_empty() => {
+            #[cfg(feature = "diagnostics")]
+            diagnostics::record(|| diagnostics::Diagnostic::EmptyStringAsNull);
+            Ok(None)
+        }
         other => Ok(parse_json_value(other, true).map_err(serde::de::Error::custom)?),
     }
 }
@@ -665,18 +827,39 @@ where
 pub trait Request: serde::Serialize {
     /// The path to the endpoint relative to the helix root. eg. `channels` for [Get Channel Information](https://dev.twitch.tv/docs/api/reference#get-channel-information)
     const PATH: &'static str;
+    /// The http method of the endpoint, matching the [`RequestGet`], [`RequestPost`], [`RequestPut`], [`RequestPatch`] or [`RequestDelete`] trait implemented.
+    const METHOD: http::Method;
     /// Scopes needed by this endpoint
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope];
     /// Optional scopes needed by this endpoint
     #[cfg(feature = "twitch_oauth2")]
     const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[];
+    /// How this endpoint is authorized, most endpoints take an OAuth access token
+    const AUTH: AuthKind = AuthKind::Bearer;
+    /// Largest [page size](PageSize) accepted by this endpoint, if it is paginated. Creating a request with a larger page size fails.
+    const MAX_PAGE_SIZE: u32 = 100;
+    /// Whether fields in the response besides `data`, `pagination` and `total` are collected into [`Response::other`].
+    ///
+    /// Collecting them is slower for large responses, so endpoints only do it when they return such fields, or when the `collect_other_fields` feature is enabled.
+    const COLLECT_OTHER_FIELDS: bool = cfg!(feature = "collect_other_fields");
     /// Response type. twitch's response will  deserialize to this.
     type Response: serde::de::DeserializeOwned + PartialEq;
+    /// The page size requested, checked against [`MAX_PAGE_SIZE`](Request::MAX_PAGE_SIZE) in [`get_uri`](Request::get_uri)
+    fn page_size(&self) -> Option<PageSize> { None }
     /// Defines layout of the url parameters.
     fn query(&self) -> Result<String, ser::Error> { ser::to_string(&self) }
     /// Returns full URI for the request, including query parameters.
     fn get_uri(&self) -> Result<http::Uri, InvalidUri> {
+        if let Some(size) = self.page_size() {
+            if size.get() > Self::MAX_PAGE_SIZE {
+                return Err(PageSizeError::TooLarge {
+                    size: size.get(),
+                    max: Self::MAX_PAGE_SIZE,
+                }
+                .into());
+            }
+        }
         let query = self.query()?;
         let url = crate::TWITCH_HELIX_URL
             .join(<Self as Request>::PATH)
@@ -693,36 +876,42 @@ pub trait Request: serde::Serialize {
     }
 }
 
+/// Create a [`http::Request`] for a request, using the [method](Request::METHOD) of the request and sending `body` if there is one
+fn create_request<R: Request + ?Sized>(
+    req: &R,
+    body: Option<Vec<u8>>,
+    auth: AuthStyle<'_>,
+    client_id: &str,
+) -> Result<http::Request<Vec<u8>>, CreateRequestError> {
+    let uri = req.get_uri()?;
+
+    let mut request = http::Request::builder()
+        .method(R::METHOD)
+        .uri(uri)
+        .header("Client-ID", client_id)
+        .header("Content-Type", "application/json");
+    if let Some(accept_encoding) = encoding::ACCEPT_ENCODING {
+        request = request.header(http::header::ACCEPT_ENCODING, accept_encoding);
+    }
+    if let Some(authorization) = auth.header_value()? {
+        request = request.header(http::header::AUTHORIZATION, authorization);
+    }
+    request.body(body.unwrap_or_default()).map_err(Into::into)
+}
+
 /// Helix endpoint POSTs information
 pub trait RequestPost: Request {
     /// Body parameters
     type Body: HelixRequestBody;
 
-    /// Create a [`http::Request`] from this [`Request`] in your client
-    fn create_request(
+    /// Create a [`http::Request`] from this [`Request`] in your client, authorized as described by [`AuthStyle`]
+    fn create_request<'t>(
         &self,
         body: Self::Body,
-        token: &str,
+        auth: impl Into<AuthStyle<'t>>,
         client_id: &str,
     ) -> Result<http::Request<Vec<u8>>, CreateRequestError> {
-        let uri = self.get_uri()?;
-
-        let body = body.try_to_body()?;
-        //eprintln!("\n\nbody is ------------ {} ------------", body);
-
-        let mut bearer =
-            http::HeaderValue::from_str(&format!("Bearer {}", token)).map_err(|_| {
-                CreateRequestError::Custom("Could not make token into headervalue".into())
-            })?;
-        bearer.set_sensitive(true);
-        http::Request::builder()
-            .method(http::Method::POST)
-            .uri(uri)
-            .header("Client-ID", client_id)
-            .header("Content-Type", "application/json")
-            .header(http::header::AUTHORIZATION, bearer)
-            .body(body)
-            .map_err(Into::into)
+        create_request(self, Some(body.try_to_body()?), auth.into(), client_id)
     }
 
     /// Parse response.
@@ -735,28 +924,45 @@ pub trait RequestPost: Request {
         request: Option<Self>,
         uri: &http::Uri,
         response: http::Response<Vec<u8>>,
-    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestPostError>
+    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestError>
     where
         Self: Sized,
     {
+        let request_id = request_id(&response);
         let text = std::str::from_utf8(response.body()).map_err(|e| {
-            HelixRequestPostError::Utf8Error(response.body().clone(), e, uri.clone())
+            HelixRequestError::Utf8Error(
+                response.body().clone(),
+                e,
+                Self::METHOD,
+                uri.clone(),
+                request_id.clone(),
+            )
         })?;
-        if let Ok(HelixRequestError {
+        if let Ok(HelixErrorResponse {
             error,
             status,
             message,
-        }) = parse_json::<HelixRequestError>(text, false)
+        }) = parse_json::<HelixErrorResponse>(text, false)
         {
-            return Err(HelixRequestPostError::Error {
+            return Err(HelixRequestError::Error {
                 error,
                 status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                 message,
+                method: Self::METHOD,
                 uri: uri.clone(),
                 body: response.body().clone(),
+                request_id: request_id.clone(),
             });
         }
-        <Self as RequestPost>::parse_inner_response(request, uri, text, response.status())
+        let (parsed, diagnostics) = diagnostics::collect(text, || {
+            <Self as RequestPost>::parse_inner_response(request, uri, text, response.status())
+        });
+        parsed
+            .map(|r| {
+                r.with_request_id(request_id.clone())
+                    .with_diagnostics(diagnostics)
+            })
+            .map_err(|e| e.with_request_id(request_id))
     }
 
     /// Parse a response string into the response.
@@ -765,17 +971,19 @@ pub trait RequestPost: Request {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestPostError>
+    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestError>
     where
         Self: Sized,
     {
-        let response: InnerResponse<<Self as Request>::Response> = parse_json(response, true)
+        let response: InnerResponse<<Self as Request>::Response> = parse_json_owned(response, true)
             .map_err(|e| {
-                HelixRequestPostError::DeserializeError(
+                HelixRequestError::DeserializeError(
                     response.to_string(),
                     e,
+                    Self::METHOD,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         Ok(Response {
@@ -784,6 +992,8 @@ pub trait RequestPost: Request {
             request,
             total: response.total,
             other: None,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
@@ -793,31 +1003,14 @@ pub trait RequestPatch: Request {
     /// Body parameters
     type Body: HelixRequestBody;
 
-    /// Create a [`http::Request`] from this [`Request`] in your client
-    fn create_request(
+    /// Create a [`http::Request`] from this [`Request`] in your client, authorized as described by [`AuthStyle`]
+    fn create_request<'t>(
         &self,
         body: Self::Body,
-        token: &str,
+        auth: impl Into<AuthStyle<'t>>,
         client_id: &str,
     ) -> Result<http::Request<Vec<u8>>, CreateRequestError> {
-        let uri = self.get_uri()?;
-
-        let body = body.try_to_body()?;
-        // eprintln!("\n\nbody is ------------ {} ------------", body);
-
-        let mut bearer =
-            http::HeaderValue::from_str(&format!("Bearer {}", token)).map_err(|_| {
-                CreateRequestError::Custom("Could not make token into headervalue".into())
-            })?;
-        bearer.set_sensitive(true);
-        http::Request::builder()
-            .method(http::Method::PATCH)
-            .uri(uri)
-            .header("Client-ID", client_id)
-            .header("Content-Type", "application/json")
-            .header(http::header::AUTHORIZATION, bearer)
-            .body(body)
-            .map_err(Into::into)
+        create_request(self, Some(body.try_to_body()?), auth.into(), client_id)
     }
 
     /// Parse response.
@@ -830,28 +1023,45 @@ pub trait RequestPatch: Request {
         request: Option<Self>,
         uri: &http::Uri,
         response: http::Response<Vec<u8>>,
-    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestPatchError>
+    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestError>
     where
         Self: Sized,
     {
+        let request_id = request_id(&response);
         let text = std::str::from_utf8(response.body()).map_err(|e| {
-            HelixRequestPatchError::Utf8Error(response.body().clone(), e, uri.clone())
+            HelixRequestError::Utf8Error(
+                response.body().clone(),
+                e,
+                Self::METHOD,
+                uri.clone(),
+                request_id.clone(),
+            )
         })?;
-        if let Ok(HelixRequestError {
+        if let Ok(HelixErrorResponse {
             error,
             status,
             message,
-        }) = parse_json::<HelixRequestError>(text, false)
+        }) = parse_json::<HelixErrorResponse>(text, false)
         {
-            return Err(HelixRequestPatchError::Error {
+            return Err(HelixRequestError::Error {
                 error,
                 status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                 message,
+                method: Self::METHOD,
                 uri: uri.clone(),
                 body: response.body().clone(),
+                request_id: request_id.clone(),
             });
         }
-        <Self as RequestPatch>::parse_inner_response(request, uri, text, response.status())
+        let (parsed, diagnostics) = diagnostics::collect(text, || {
+            <Self as RequestPatch>::parse_inner_response(request, uri, text, response.status())
+        });
+        parsed
+            .map(|r| {
+                r.with_request_id(request_id.clone())
+                    .with_diagnostics(diagnostics)
+            })
+            .map_err(|e| e.with_request_id(request_id))
     }
 
     /// Parse a response string into the response.
@@ -860,34 +1070,20 @@ pub trait RequestPatch: Request {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestPatchError>
+    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestError>
     where
         Self: Sized;
 }
 
 /// Helix endpoint DELETEs information
 pub trait RequestDelete: Request {
-    /// Create a [`http::Request`] from this [`Request`] in your client
-    fn create_request(
+    /// Create a [`http::Request`] from this [`Request`] in your client, authorized as described by [`AuthStyle`]
+    fn create_request<'t>(
         &self,
-        token: &str,
+        auth: impl Into<AuthStyle<'t>>,
         client_id: &str,
     ) -> Result<http::Request<Vec<u8>>, CreateRequestError> {
-        let uri = self.get_uri()?;
-
-        let mut bearer =
-            http::HeaderValue::from_str(&format!("Bearer {}", token)).map_err(|_| {
-                CreateRequestError::Custom("Could not make token into headervalue".into())
-            })?;
-        bearer.set_sensitive(true);
-        http::Request::builder()
-            .method(http::Method::DELETE)
-            .uri(uri)
-            .header("Client-ID", client_id)
-            .header("Content-Type", "application/json")
-            .header(http::header::AUTHORIZATION, bearer)
-            .body(Vec::with_capacity(0))
-            .map_err(Into::into)
+        create_request(self, None, auth.into(), client_id)
     }
     /// Parse response.
     ///
@@ -899,28 +1095,45 @@ pub trait RequestDelete: Request {
         request: Option<Self>,
         uri: &http::Uri,
         response: http::Response<Vec<u8>>,
-    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestDeleteError>
+    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestError>
     where
         Self: Sized,
     {
+        let request_id = request_id(&response);
         let text = std::str::from_utf8(response.body()).map_err(|e| {
-            HelixRequestDeleteError::Utf8Error(response.body().clone(), e, uri.clone())
+            HelixRequestError::Utf8Error(
+                response.body().clone(),
+                e,
+                Self::METHOD,
+                uri.clone(),
+                request_id.clone(),
+            )
         })?;
-        if let Ok(HelixRequestError {
+        if let Ok(HelixErrorResponse {
             error,
             status,
             message,
-        }) = parse_json::<HelixRequestError>(text, false)
+        }) = parse_json::<HelixErrorResponse>(text, false)
         {
-            return Err(HelixRequestDeleteError::Error {
+            return Err(HelixRequestError::Error {
                 error,
                 status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                 message,
+                method: Self::METHOD,
                 uri: uri.clone(),
                 body: response.body().clone(),
+                request_id: request_id.clone(),
             });
         }
-        <Self as RequestDelete>::parse_inner_response(request, uri, text, response.status())
+        let (parsed, diagnostics) = diagnostics::collect(text, || {
+            <Self as RequestDelete>::parse_inner_response(request, uri, text, response.status())
+        });
+        parsed
+            .map(|r| {
+                r.with_request_id(request_id.clone())
+                    .with_diagnostics(diagnostics)
+            })
+            .map_err(|e| e.with_request_id(request_id))
     }
     /// Parse a response string into the response.
     fn parse_inner_response(
@@ -928,7 +1141,7 @@ pub trait RequestDelete: Request {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestDeleteError>
+    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestError>
     where
         Self: Sized;
 }
@@ -938,31 +1151,14 @@ pub trait RequestPut: Request {
     /// Body parameters
     type Body: HelixRequestBody;
 
-    /// Create a [`http::Request`] from this [`Request`] in your client
-    fn create_request(
+    /// Create a [`http::Request`] from this [`Request`] in your client, authorized as described by [`AuthStyle`]
+    fn create_request<'t>(
         &self,
         body: Self::Body,
-        token: &str,
+        auth: impl Into<AuthStyle<'t>>,
         client_id: &str,
     ) -> Result<http::Request<Vec<u8>>, CreateRequestError> {
-        let uri = self.get_uri()?;
-
-        let body = body.try_to_body()?;
-        // eprintln!("\n\nbody is ------------ {} ------------", body);
-
-        let mut bearer =
-            http::HeaderValue::from_str(&format!("Bearer {}", token)).map_err(|_| {
-                CreateRequestError::Custom("Could not make token into headervalue".into())
-            })?;
-        bearer.set_sensitive(true);
-        http::Request::builder()
-            .method(http::Method::PUT)
-            .uri(uri)
-            .header("Client-ID", client_id)
-            .header("Content-Type", "application/json")
-            .header(http::header::AUTHORIZATION, bearer)
-            .body(body)
-            .map_err(Into::into)
+        create_request(self, Some(body.try_to_body()?), auth.into(), client_id)
     }
 
     /// Parse response.
@@ -975,28 +1171,45 @@ pub trait RequestPut: Request {
         request: Option<Self>,
         uri: &http::Uri,
         response: http::Response<Vec<u8>>,
-    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestPutError>
+    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestError>
     where
         Self: Sized,
     {
+        let request_id = request_id(&response);
         let text = std::str::from_utf8(response.body()).map_err(|e| {
-            HelixRequestPutError::Utf8Error(response.body().clone(), e, uri.clone())
+            HelixRequestError::Utf8Error(
+                response.body().clone(),
+                e,
+                Self::METHOD,
+                uri.clone(),
+                request_id.clone(),
+            )
         })?;
-        if let Ok(HelixRequestError {
+        if let Ok(HelixErrorResponse {
             error,
             status,
             message,
-        }) = parse_json::<HelixRequestError>(text, false)
+        }) = parse_json::<HelixErrorResponse>(text, false)
         {
-            return Err(HelixRequestPutError::Error {
+            return Err(HelixRequestError::Error {
                 error,
                 status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                 message,
+                method: Self::METHOD,
                 uri: uri.clone(),
                 body: response.body().clone(),
+                request_id: request_id.clone(),
             });
         }
-        <Self as RequestPut>::parse_inner_response(request, uri, text, response.status())
+        let (parsed, diagnostics) = diagnostics::collect(text, || {
+            <Self as RequestPut>::parse_inner_response(request, uri, text, response.status())
+        });
+        parsed
+            .map(|r| {
+                r.with_request_id(request_id.clone())
+                    .with_diagnostics(diagnostics)
+            })
+            .map_err(|e| e.with_request_id(request_id))
     }
 
     /// Parse a response string into the response.
@@ -1005,34 +1218,20 @@ pub trait RequestPut: Request {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestPutError>
+    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestError>
     where
         Self: Sized;
 }
 
 /// Helix endpoint GETs information
 pub trait RequestGet: Request {
-    /// Create a [`http::Request`] from this [`Request`] in your client
-    fn create_request(
+    /// Create a [`http::Request`] from this [`Request`] in your client, authorized as described by [`AuthStyle`]
+    fn create_request<'t>(
         &self,
-        token: &str,
+        auth: impl Into<AuthStyle<'t>>,
         client_id: &str,
     ) -> Result<http::Request<Vec<u8>>, CreateRequestError> {
-        let uri = self.get_uri()?;
-
-        let mut bearer =
-            http::HeaderValue::from_str(&format!("Bearer {}", token)).map_err(|_| {
-                CreateRequestError::Custom("Could not make token into headervalue".into())
-            })?;
-        bearer.set_sensitive(true);
-        http::Request::builder()
-            .method(http::Method::GET)
-            .uri(uri)
-            .header("Client-ID", client_id)
-            .header("Content-Type", "application/json")
-            .header(http::header::AUTHORIZATION, bearer)
-            .body(Vec::with_capacity(0))
-            .map_err(Into::into)
+        create_request(self, None, auth.into(), client_id)
     }
 
     /// Parse response.
@@ -1044,28 +1243,46 @@ pub trait RequestGet: Request {
         request: Option<Self>,
         uri: &http::Uri,
         response: http::Response<Vec<u8>>,
-    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestGetError>
+    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestError>
     where
         Self: Sized,
     {
+        let request_id = request_id(&response);
         let text = std::str::from_utf8(response.body()).map_err(|e| {
-            HelixRequestGetError::Utf8Error(response.body().clone(), e, uri.clone())
+            HelixRequestError::Utf8Error(
+                response.body().clone(),
+                e,
+                Self::METHOD,
+                uri.clone(),
+                request_id.clone(),
+            )
         })?;
         //eprintln!("\n\nmessage is ------------ {} ------------", text);
-        if let Ok(HelixRequestError {
+        if let Ok(HelixErrorResponse {
             error,
             status,
             message,
-        }) = parse_json::<HelixRequestError>(text, false)
+        }) = parse_json::<HelixErrorResponse>(text, false)
         {
-            return Err(HelixRequestGetError::Error {
+            return Err(HelixRequestError::Error {
                 error,
                 status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                 message,
+                method: Self::METHOD,
                 uri: uri.clone(),
+                body: response.body().clone(),
+                request_id: request_id.clone(),
             });
         }
-        <Self as RequestGet>::parse_inner_response(request, uri, text, response.status())
+        let (parsed, diagnostics) = diagnostics::collect(text, || {
+            <Self as RequestGet>::parse_inner_response(request, uri, text, response.status())
+        });
+        parsed
+            .map(|r| {
+                r.with_request_id(request_id.clone())
+                    .with_diagnostics(diagnostics)
+            })
+            .map_err(|e| e.with_request_id(request_id))
     }
 
     /// Parse a response string into the response.
@@ -1074,12 +1291,19 @@ pub trait RequestGet: Request {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestGetError>
+    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestError>
     where
         Self: Sized,
     {
-        let response: InnerResponse<_> = parse_json(response, true).map_err(|e| {
-            HelixRequestGetError::DeserializeError(response.to_string(), e, uri.clone(), status)
+        let response: InnerResponse<_> = InnerResponse::parse::<Self>(response).map_err(|e| {
+            HelixRequestError::DeserializeError(
+                response.to_string(),
+                e,
+                Self::METHOD,
+                uri.clone(),
+                status,
+                None,
+            )
         })?;
         Ok(Response {
             data: response.data,
@@ -1087,6 +1311,8 @@ pub trait RequestGet: Request {
             request,
             total: response.total,
             other: response.other,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
@@ -1109,7 +1335,12 @@ where
     /// Fields which are not part of the data response, but are returned by the endpoint.
     ///
     /// See for example [Get Broadcaster Subscriptions](https://dev.twitch.tv/docs/api/reference#get-broadcaster-subscriptions) which returns this.
+    /// Only collected if the request [asks for it](Request::COLLECT_OTHER_FIELDS).
     pub other: Option<serde_json::Map<String, serde_json::Value>>,
+    /// ID Twitch assigned to the request, see [`request_id`]
+    pub request_id: Option<String>,
+    /// Unexpected things found while parsing the response, only collected with the `diagnostics` feature. See [`diagnostics`]
+    pub diagnostics: diagnostics::Diagnostics,
 }
 
 impl<R, D> Response<R, D>
@@ -1117,6 +1348,18 @@ where
     R: Request,
     D: serde::de::DeserializeOwned + PartialEq,
 {
+    /// Set the request ID, used when parsing a response.
+    fn with_request_id(mut self, request_id: Option<String>) -> Self {
+        self.request_id = request_id;
+        self
+    }
+
+    /// Set the diagnostics, used when parsing a response.
+    fn with_diagnostics(mut self, diagnostics: diagnostics::Diagnostics) -> Self {
+        self.diagnostics = diagnostics;
+        self
+    }
+
     /// Get a field from the response that is not part of `data`.
     pub fn get_other<Q, V>(&self, key: &Q) -> Result<Option<V>, serde_json::Error>
     where
@@ -1143,6 +1386,23 @@ where
     }
 }
 
+impl<R, D> Response<R, D>
+where
+    R: ResponseExtras,
+    D: serde::de::DeserializeOwned + PartialEq,
+{
+    /// Get the fields returned by the endpoint besides `data` and `pagination`.
+    ///
+    /// The fields stay available with [`get_other`](Response::get_other).
+    pub fn extras(&self) -> Result<R::Extras, serde_json::Error> {
+        let mut extras = self.other.clone().unwrap_or_default();
+        if let Some(total) = self.total {
+            extras.insert("total".to_string(), total.into());
+        }
+        serde_json::from_value(serde_json::Value::Object(extras))
+    }
+}
+
 /// Custom response retrieved from endpoint, used for specializing responses
 #[cfg(all(feature = "client", feature = "unsupported"))]
 #[cfg_attr(nightly, doc(cfg(all(feature = "client", feature = "unsupported"))))]
@@ -1163,6 +1423,8 @@ where
     pub other: serde_json::Map<String, serde_json::Value>,
     /// The owned data. Use [`CustomResponse::data()`] to deserialize.
     pub raw_data: Box<serde_json::value::RawValue>,
+    /// ID Twitch assigned to the request, see [`request_id`]
+    pub request_id: Option<String>,
     pd: std::marker::PhantomData<&'d D>,
 }
 
@@ -1188,6 +1450,77 @@ where
     pub fn first(self) -> Option<T> { self.data.into_iter().next() }
 }
 
+impl<R, D> Response<R, D>
+where
+    R: Request,
+    D: serde::de::DeserializeOwned + PartialEq,
+{
+    /// Get the data of this response, discarding pagination and other fields.
+    pub fn into_data(self) -> D { self.data }
+
+    /// Map the data of this response, keeping pagination and other fields.
+    ///
+    /// The returned response can not be used with [`get_next`](Response::get_next), as its data no longer matches the [request](Request::Response).
+    pub fn map_data<D2, F>(self, f: F) -> Response<R, D2>
+    where
+        D2: serde::de::DeserializeOwned + PartialEq,
+        F: FnOnce(D) -> D2, {
+        Response {
+            data: f(self.data),
+            pagination: self.pagination,
+            request: self.request,
+            total: self.total,
+            other: self.other,
+            request_id: self.request_id,
+            diagnostics: self.diagnostics,
+        }
+    }
+
+    /// Serialize this response into the JSON envelope Twitch sends, with `data`, `pagination`, `total` and the [other fields](Response::other).
+    ///
+    /// `pagination` and `total` are only included if they were returned.
+    /// Endpoints that unwrap the single object Twitch returns serialize `data` as that object, not as an array.
+    ///
+    /// ```rust
+    /// # use twitch_api2::helix;
+    /// # fn print(response: helix::Response<helix::users::GetUsersRequest, Vec<helix::users::User>>) -> Result<(), serde_json::Error> {
+    /// println!("{}", response.to_json_pretty()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_json_pretty(&self) -> Result<String, serde_json::Error>
+    where D: serde::Serialize {
+        let mut envelope = serde_json::Map::new();
+        envelope.insert("data".to_string(), serde_json::to_value(&self.data)?);
+        if let Some(cursor) = &self.pagination {
+            envelope.insert(
+                "pagination".to_string(),
+                serde_json::json!({ "cursor": cursor }),
+            );
+        }
+        if let Some(total) = self.total {
+            envelope.insert("total".to_string(), total.into());
+        }
+        if let Some(other) = &self.other {
+            for (key, value) in other {
+                envelope.entry(key.clone()).or_insert_with(|| value.clone());
+            }
+        }
+        serde_json::to_string_pretty(&envelope)
+    }
+}
+
+impl<R, T> IntoIterator for Response<R, Vec<T>>
+where
+    R: Request,
+    T: serde::de::DeserializeOwned + PartialEq,
+{
+    type IntoIter = std::vec::IntoIter<T>;
+    type Item = T;
+
+    fn into_iter(self) -> Self::IntoIter { self.data.into_iter() }
+}
+
 // impl<R, D, T> CustomResponse<'_, R, D>
 // where
 //     R: Request,
@@ -1236,6 +1569,62 @@ where
     }
 }
 
+#[cfg(feature = "client")]
+impl<R, T> Response<R, Vec<T>>
+where
+    R: Request<Response = Vec<T>> + Clone + Paginated + RequestGet + std::fmt::Debug,
+    T: serde::de::DeserializeOwned + std::fmt::Debug + PartialEq,
+{
+    /// Collect the data of this response and all following pages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api2::helix;
+    ///
+    /// let req = helix::moderation::GetModeratorsRequest::builder()
+    ///     .broadcaster_id("1234")
+    ///     .build();
+    /// let moderators: Vec<helix::moderation::Moderator> = client
+    ///     .req_get(req, &token)
+    ///     .await?
+    ///     .try_collect_all(&client, &token)
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn try_collect_all<'a, C: crate::HttpClient<'a>>(
+        self,
+        client: &'a HelixClient<'a, C>,
+        token: &(impl TwitchToken + ?Sized),
+    ) -> Result<Vec<T>, ClientRequestError<<C as crate::HttpClient<'a>>::Error>> {
+        let mut data = vec![];
+        let mut page = self.data;
+        let mut cursor = self.pagination;
+        let mut request = self.request;
+        while let Some(next_cursor) = cursor.take() {
+            let req = request
+                .as_mut()
+                .ok_or_else(|| ClientRequestError::Custom("no source request attached".into()))?;
+            req.set_pagination(Some(next_cursor));
+            let next = client.req_get(req.clone(), token).await?;
+            // FIXME: Workaround for https://github.com/twitchdev/issues/issues/18
+            if next.data == page {
+                break;
+            }
+            data.append(&mut page);
+            page = next.data;
+            cursor = next.pagination;
+        }
+        data.append(&mut page);
+        Ok(data)
+    }
+}
+
 /// A request that can be paginated.
 pub trait Paginated: Request {
     /// Should returns the current pagination cursor.
@@ -1246,6 +1635,15 @@ pub trait Paginated: Request {
     fn set_pagination(&mut self, cursor: Option<Cursor>);
 }
 
+/// A request whose response has fields besides `data` and `pagination`, like `total`.
+///
+/// Use [`Response::extras`] to get them as [`Extras`](ResponseExtras::Extras).
+/// Endpoints returning fields other than `total` also [collect them](Request::COLLECT_OTHER_FIELDS).
+pub trait ResponseExtras: Request {
+    /// The fields returned besides `data` and `pagination`
+    type Extras: serde::de::DeserializeOwned;
+}
+
 /// A cursor for pagination. This is needed because of how pagination is represented in the [New Twitch API](https://dev.twitch.tv/docs/api)
 #[derive(PartialEq, Deserialize, Debug, Clone, Default)]
 struct Pagination {
@@ -1257,6 +1655,203 @@ struct Pagination {
 #[aliri_braid::braid(serde)]
 pub struct Cursor;
 
+#[cfg(feature = "arbitrary")]
+crate::types::arbitrary_braid![Cursor];
+
+/// Pagination parameters of a [`Paginated`] request
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api2::helix::{streams::GetStreamsRequest, PageSize, PaginationParams};
+///
+/// let req = GetStreamsRequest::builder()
+///     .pagination(PaginationParams::default().with_first(PageSize::new(100)?))
+///     .build();
+/// # Ok::<(), twitch_api2::helix::PageSizeError>(())
+/// ```
+#[derive(PartialEq, Eq, Deserialize, serde::Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct PaginationParams {
+    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    pub after: Option<Cursor>,
+    /// Cursor for backward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    ///
+    /// Not supported by all endpoints.
+    pub before: Option<Cursor>,
+    /// Maximum number of objects to return. The maximum and default depend on the endpoint, see [`Request::MAX_PAGE_SIZE`].
+    pub first: Option<PageSize>,
+}
+
+impl PaginationParams {
+    /// Set the forward pagination cursor
+    pub fn with_after(mut self, after: impl Into<Option<Cursor>>) -> Self {
+        self.after = after.into();
+        self
+    }
+
+    /// Set the backward pagination cursor
+    pub fn with_before(mut self, before: impl Into<Option<Cursor>>) -> Self {
+        self.before = before.into();
+        self
+    }
+
+    /// Set the maximum number of objects to return
+    pub fn with_first(mut self, first: impl Into<Option<PageSize>>) -> Self {
+        self.first = first.into();
+        self
+    }
+}
+
+/// The broadcaster a request is for and the user making it, for endpoints a moderator can call on behalf of the broadcaster
+///
+/// The moderator has to be the user of the token, or the broadcaster themselves. [`ModeratorAuth::from_token`] takes the moderator from the token.
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api2::helix::{moderation::UpdateAutoModSettingsRequest, ModeratorAuth};
+///
+/// let req = UpdateAutoModSettingsRequest::builder()
+///     .auth(ModeratorAuth::new("1234", "5678"))
+///     .build();
+/// ```
+#[derive(PartialEq, Eq, Deserialize, serde::Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct ModeratorAuth {
+    /// The ID of the broadcaster the request is for.
+    pub broadcaster_id: crate::types::UserId,
+    /// The ID of the broadcaster or one of their moderators. This ID must match the user ID in the user access token.
+    pub moderator_id: crate::types::UserId,
+}
+
+impl ModeratorAuth {
+    /// Act as `moderator_id` for `broadcaster_id`
+    pub fn new(
+        broadcaster_id: impl Into<crate::types::UserId>,
+        moderator_id: impl Into<crate::types::UserId>,
+    ) -> Self {
+        ModeratorAuth {
+            broadcaster_id: broadcaster_id.into(),
+            moderator_id: moderator_id.into(),
+        }
+    }
+
+    /// Act as the broadcaster themselves
+    pub fn broadcaster(broadcaster_id: impl Into<crate::types::UserId>) -> Self {
+        let broadcaster_id = broadcaster_id.into();
+        ModeratorAuth {
+            moderator_id: broadcaster_id.clone(),
+            broadcaster_id,
+        }
+    }
+
+    /// Act as the user of `token` for `broadcaster_id`, returns `None` if the token has no user
+    #[cfg(feature = "twitch_oauth2")]
+    #[cfg_attr(nightly, doc(cfg(feature = "twitch_oauth2")))]
+    pub fn from_token<T>(
+        broadcaster_id: impl Into<crate::types::UserId>,
+        token: &T,
+    ) -> Option<Self>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        Some(ModeratorAuth::new(broadcaster_id, token.user_id()?))
+    }
+
+    /// Whether the broadcaster is acting themselves
+    pub fn is_broadcaster(&self) -> bool { self.broadcaster_id == self.moderator_id }
+}
+
+impl<B, M> From<(B, M)> for ModeratorAuth
+where
+    B: Into<crate::types::UserId>,
+    M: Into<crate::types::UserId>,
+{
+    fn from((broadcaster_id, moderator_id): (B, M)) -> Self {
+        ModeratorAuth::new(broadcaster_id, moderator_id)
+    }
+}
+
+/// Number of objects to return in a page, the `first` parameter of paginated requests
+///
+/// Page sizes are between `1` and [`PageSize::MAX`], endpoints can have a lower [maximum](Request::MAX_PAGE_SIZE).
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api2::helix::PageSize;
+///
+/// assert_eq!(PageSize::new(100)?.get(), 100);
+/// assert!(PageSize::new(0).is_err());
+/// assert!(PageSize::new(1001).is_err());
+/// # Ok::<(), twitch_api2::helix::PageSizeError>(())
+/// ```
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, serde::Serialize, Clone, Copy, Debug,
+)]
+#[serde(try_from = "u32", into = "u32")]
+pub struct PageSize(std::num::NonZeroU32);
+
+impl PageSize {
+    /// Largest page size accepted by any endpoint
+    pub const MAX: u32 = 1000;
+
+    /// Create a page size, failing if it is `0` or larger than [`PageSize::MAX`]
+    pub fn new(size: u32) -> Result<Self, PageSizeError> {
+        if size > Self::MAX {
+            return Err(PageSizeError::TooLarge {
+                size,
+                max: Self::MAX,
+            });
+        }
+        std::num::NonZeroU32::new(size)
+            .map(PageSize)
+            .ok_or(PageSizeError::Zero)
+    }
+
+    /// The page size
+    pub fn get(self) -> u32 { self.0.get() }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for PageSize {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        PageSize::new(u.int_in_range(1..=PageSize::MAX)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+impl std::convert::TryFrom<u32> for PageSize {
+    type Error = PageSizeError;
+
+    fn try_from(size: u32) -> Result<Self, Self::Error> { PageSize::new(size) }
+}
+
+impl From<PageSize> for u32 {
+    fn from(size: PageSize) -> Self { size.get() }
+}
+
+impl std::fmt::Display for PageSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { self.0.fmt(f) }
+}
+
+/// A [`PageSize`] is out of range
+#[derive(thiserror::Error, Debug, displaydoc::Display, PartialEq, Eq, Clone)]
+pub enum PageSizeError {
+    /// page size must be at least 1
+    Zero,
+    /// page size {size} is larger than the maximum of {max}
+    TooLarge {
+        /// The page size
+        size: u32,
+        /// The largest page size allowed
+        max: u32,
+    },
+}
+
 /// Errors for [`HelixClient::req_get`] and similar functions.
 #[derive(thiserror::Error, Debug)]
 // #[derive(displaydoc::Display)] https://github.com/yaahc/displaydoc/issues/15
@@ -1270,25 +1865,98 @@ pub enum ClientRequestError<RE: std::error::Error + Send + Sync + 'static> {
     /// Could not create request
     #[error("could not create request")]
     CreateRequestError(#[from] CreateRequestError),
-    /// Got error from GET response
-    #[error(transparent)]
-    HelixRequestGetError(#[from] HelixRequestGetError),
-    /// Got error from PUT response
-    #[error(transparent)]
-    HelixRequestPutError(#[from] HelixRequestPutError),
-    /// Got error from POST response
-    #[error(transparent)]
-    HelixRequestPostError(#[from] HelixRequestPostError),
-    /// Got error from PATCH response
-    #[error(transparent)]
-    HelixRequestPatchError(#[from] HelixRequestPatchError),
-    /// Got error from DELETE response
+    /// Could not decompress the response
+    #[error("could not decompress the response")]
+    DecompressError(#[source] std::io::Error),
+    /// Got a redirect, which Helix never sends. Redirects are not followed
+    #[error("unexpected redirect with status {status} to {location:?}")]
+    Redirect {
+        /// Status of the response
+        status: http::StatusCode,
+        /// The `Location` header of the response
+        location: Option<String>,
+    },
+    /// Got error from helix response
     #[error(transparent)]
-    HelixRequestDeleteError(#[from] HelixRequestDeleteError),
+    HelixRequestError(#[from] HelixRequestError),
     /// Custom error
     #[error("{0}")]
     Custom(std::borrow::Cow<'static, str>),
 }
+
+impl<RE: std::error::Error + Send + Sync + 'static> ClientRequestError<RE> {
+    /// ID Twitch assigned to the request that failed, if any. See [`request_id`]
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            ClientRequestError::HelixRequestError(e) => e.request_id(),
+            _ => None,
+        }
+    }
+}
+
+/// How a request should be authorized, used in `create_request` on [`RequestGet`], [`RequestPost`] etc.
+///
+/// A `&str` converts into [`AuthStyle::Bearer`], which is what most endpoints use.
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api2::helix::{self, AuthStyle, RequestGet};
+/// let request = helix::users::GetUsersRequest::builder()
+///     .login(vec!["justintv123".into()])
+///     .build();
+/// // These are equivalent
+/// request.create_request("accesstoken", "client_id")?;
+/// request.create_request(AuthStyle::Bearer("accesstoken"), "client_id")?;
+/// // Extension endpoints authorize with a signed JWT instead
+/// request.create_request(AuthStyle::Extension("jwt"), "client_id")?;
+/// # Ok::<(), helix::CreateRequestError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AuthStyle<'a> {
+    /// OAuth access token, sent as `Authorization: Bearer <token>`
+    Bearer(&'a str),
+    /// Signed extension JWT, sent as `Authorization: Bearer <jwt>` like a token
+    Extension(&'a str),
+    /// No `Authorization` header, for public endpoints that only need a `Client-ID`
+    None,
+}
+
+impl AuthStyle<'_> {
+    /// Create the value of the `Authorization` header, if any.
+    fn header_value(&self) -> Result<Option<http::HeaderValue>, CreateRequestError> {
+        let token = match self {
+            AuthStyle::Bearer(token) | AuthStyle::Extension(token) => token,
+            AuthStyle::None => return Ok(None),
+        };
+        let mut value =
+            http::HeaderValue::from_str(&format!("Bearer {}", token)).map_err(|_| {
+                CreateRequestError::Custom("Could not make token into headervalue".into())
+            })?;
+        value.set_sensitive(true);
+        Ok(Some(value))
+    }
+}
+
+/// How an endpoint is authorized, see [`Request::AUTH`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuthKind {
+    /// OAuth access token, see [`AuthStyle::Bearer`]
+    Bearer,
+    /// Signed extension JWT, see [`AuthStyle::Extension`]
+    Extension,
+}
+
+impl<'a> From<&'a str> for AuthStyle<'a> {
+    fn from(token: &'a str) -> Self { AuthStyle::Bearer(token) }
+}
+
+impl<'a> From<&'a String> for AuthStyle<'a> {
+    fn from(token: &'a String) -> Self { AuthStyle::Bearer(token) }
+}
+
 /// Could not create request
 #[derive(thiserror::Error, Debug, displaydoc::Display)]
 pub enum CreateRequestError {
@@ -1311,48 +1979,14 @@ pub enum InvalidUri {
     UrlError(#[from] url::ParseError),
     /// could not serialize request to query
     QuerySerializeError(#[from] ser::Error),
+    /// {0}
+    PageSize(#[from] PageSizeError),
 }
 
-/// Could not parse GET response
-#[derive(thiserror::Error, Debug, displaydoc::Display)]
-pub enum HelixRequestGetError {
-    /// helix returned error {status:?} - {error}: {message:?} when calling `GET {uri}`
-    Error {
-        /// Error message related to status code
-        error: String,
-        /// Status code of error, usually 400-499
-        status: http::StatusCode,
-        /// Error message from Twitch
-        message: String,
-        /// URI to the endpoint
-        uri: http::Uri,
-    },
-    /// could not parse response as utf8 when calling `GET {2}`
-    Utf8Error(Vec<u8>, #[source] std::str::Utf8Error, http::Uri),
-    /// deserialization failed when processing request response calling `GET {2}` with response: {3} - {0:?}
-    DeserializeError(
-        String,
-        #[source] crate::DeserError,
-        http::Uri,
-        http::StatusCode,
-    ),
-    /// invalid or unexpected response from twitch.
-    InvalidResponse {
-        /// Reason for error
-        reason: &'static str,
-        /// Response text
-        response: String,
-        /// Status Code
-        status: http::StatusCode,
-        /// Uri to endpoint
-        uri: http::Uri,
-    },
-}
-
-/// Could not parse PUT response
+/// Could not parse a response from helix
 #[derive(thiserror::Error, Debug, displaydoc::Display)]
-pub enum HelixRequestPutError {
-    /// helix returned error {status:?} - {error}: {message:?} when calling `PUT {uri}` with a body
+pub enum HelixRequestError {
+    /// helix returned error {status:?} - {error}: {message:?} when calling `{method} {uri}`
     Error {
         /// Error message related to status code
         error: String,
@@ -1360,57 +1994,31 @@ pub enum HelixRequestPutError {
         status: http::StatusCode,
         /// Error message from Twitch
         message: String,
+        /// Method used for the request
+        method: http::Method,
         /// URI to the endpoint
         uri: http::Uri,
-        /// Body sent to PUT response
+        /// Body of the response
         body: Vec<u8>,
+        /// Request ID assigned by Twitch, see [`request_id`]
+        request_id: Option<String>,
     },
-    /// could not parse response as utf8 when calling `PUT {2}`
-    Utf8Error(Vec<u8>, #[source] std::str::Utf8Error, http::Uri),
-    /// deserialization failed when processing request response calling `PUT {2}` with response: {3} - {0:?}
-    DeserializeError(
-        String,
-        #[source] crate::DeserError,
+    /// could not parse response as utf8 when calling `{2} {3}`
+    Utf8Error(
+        Vec<u8>,
+        #[source] std::str::Utf8Error,
+        http::Method,
         http::Uri,
-        http::StatusCode,
+        Option<String>,
     ),
-    /// invalid or unexpected response from twitch.
-    InvalidResponse {
-        /// Reason for error
-        reason: &'static str,
-        /// Response text
-        response: String,
-        /// Status Code
-        status: http::StatusCode,
-        /// Uri to endpoint
-        uri: http::Uri,
-    },
-}
-
-/// Could not parse POST response
-#[derive(thiserror::Error, Debug, displaydoc::Display)]
-pub enum HelixRequestPostError {
-    /// helix returned error {status:?} - {error}: {message:?} when calling `POST {uri}` with a body
-    Error {
-        /// Error message related to status code
-        error: String,
-        /// Status code of error, usually 400-499
-        status: http::StatusCode,
-        /// Error message from Twitch
-        message: String,
-        /// URI to the endpoint
-        uri: http::Uri,
-        /// Body sent to POST response
-        body: Vec<u8>,
-    },
-    /// could not parse response as utf8 when calling `POST {2}`
-    Utf8Error(Vec<u8>, #[source] std::str::Utf8Error, http::Uri),
-    /// deserialization failed when processing request response calling `POST {2}` with response: {3} - {0:?}
+    /// deserialization failed when processing request response calling `{2} {3}` with response: {4} - {0:?}
     DeserializeError(
         String,
         #[source] crate::DeserError,
+        http::Method,
         http::Uri,
         http::StatusCode,
+        Option<String>,
     ),
     /// invalid or unexpected response from twitch.
     InvalidResponse {
@@ -1420,80 +2028,59 @@ pub enum HelixRequestPostError {
         response: String,
         /// Status Code
         status: http::StatusCode,
+        /// Method used for the request
+        method: http::Method,
         /// Uri to endpoint
         uri: http::Uri,
+        /// Request ID assigned by Twitch, see [`request_id`]
+        request_id: Option<String>,
     },
 }
 
-/// Could not parse PATCH response
-#[derive(thiserror::Error, Debug, displaydoc::Display)]
-pub enum HelixRequestPatchError {
-    /// helix returned error {status:?} - {error}: {message:?} when calling `PATCH {uri}` with a body
-    Error {
-        /// Error message related to status code
-        error: String,
-        /// Status code of error, usually 400-499
-        status: http::StatusCode,
-        /// Error message from Twitch
-        message: String,
-        /// URI to the endpoint
-        uri: http::Uri,
-        /// Body sent to POST response
-        body: Vec<u8>,
-    },
-    /// could not parse response as utf8 when calling `POST {2}`
-    Utf8Error(Vec<u8>, #[source] std::str::Utf8Error, http::Uri),
-    /// deserialization failed when processing request response calling `POST {2}` with response: {3} - {0:?}
-    DeserializeError(
-        String,
-        #[source] crate::DeserError,
-        http::Uri,
-        http::StatusCode,
-    ),
-    /// invalid or unexpected response from twitch.
-    InvalidResponse {
-        /// Reason for error
-        reason: &'static str,
-        /// Response text
-        response: String,
-        /// Status Code
-        status: http::StatusCode,
-        /// Uri to endpoint
-        uri: http::Uri,
-    },
-}
+impl HelixRequestError {
+    /// Method used for the request that failed
+    pub fn method(&self) -> &http::Method {
+        match self {
+            HelixRequestError::Error { method, .. }
+            | HelixRequestError::InvalidResponse { method, .. }
+            | HelixRequestError::Utf8Error(_, _, method, ..)
+            | HelixRequestError::DeserializeError(_, _, method, ..) => method,
+        }
+    }
 
-/// Could not parse DELETE response
-#[derive(thiserror::Error, Debug, displaydoc::Display)]
-pub enum HelixRequestDeleteError {
-    /// helix returned error {status:?} - {error}: {message:?} when calling `DELETE {uri}`
-    Error {
-        /// Error message related to status code
-        error: String,
-        /// Status code of error, usually 400-499
-        status: http::StatusCode,
-        /// Error message from Twitch
-        message: String,
-        /// URI to the endpoint
-        uri: http::Uri,
-        /// Body sent to DELETE response
-        body: Vec<u8>,
-    },
-    /// could not parse response as utf8 when calling `DELETE {2}`
-    Utf8Error(Vec<u8>, #[source] std::str::Utf8Error, http::Uri),
-    /// invalid or unexpected response from twitch.
-    InvalidResponse {
-        /// Reason for error
-        reason: &'static str,
-        /// Response text
-        response: String,
-        /// Status Code
-        status: http::StatusCode,
-        /// Uri to endpoint
-        uri: http::Uri,
-    },
+    /// ID Twitch assigned to the request that failed, if any. See [`request_id`]
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            HelixRequestError::Error { request_id, .. }
+            | HelixRequestError::InvalidResponse { request_id, .. }
+            | HelixRequestError::Utf8Error(.., request_id)
+            | HelixRequestError::DeserializeError(.., request_id) => request_id.as_deref(),
+        }
+    }
+
+    /// Set the request ID, used when parsing a response.
+    fn with_request_id(mut self, id: Option<String>) -> Self {
+        match &mut self {
+            HelixRequestError::Error { request_id, .. }
+            | HelixRequestError::InvalidResponse { request_id, .. }
+            | HelixRequestError::Utf8Error(.., request_id)
+            | HelixRequestError::DeserializeError(.., request_id) => *request_id = id,
+        }
+        self
+    }
 }
 
+/// Could not parse GET response, see [`HelixRequestError`]
+pub type HelixRequestGetError = HelixRequestError;
+/// Could not parse PUT response, see [`HelixRequestError`]
+pub type HelixRequestPutError = HelixRequestError;
+/// Could not parse POST response, see [`HelixRequestError`]
+pub type HelixRequestPostError = HelixRequestError;
+/// Could not parse PATCH response, see [`HelixRequestError`]
+pub type HelixRequestPatchError = HelixRequestError;
+/// Could not parse DELETE response, see [`HelixRequestError`]
+pub type HelixRequestDeleteError = HelixRequestError;
+
 /// Errors that can happen when creating a body
 #[derive(thiserror::Error, Debug, displaydoc::Display)]
 pub enum BodyError {
@@ -1503,6 +2090,10 @@ pub enum BodyError {
     QuerySerializeError(#[from] ser::Error),
     /// uri is invalid
     InvalidUri(#[from] InvalidUri),
+    /// no value given for placeholder `{0}` of a prepared body
+    MissingPlaceholder(String),
+    /// {0}
+    Custom(std::borrow::Cow<'static, str>),
 }
 
 /// Create a body. Used for specializing request bodies
@@ -1533,3 +2124,58 @@ where T: serde::Serialize + private::SealedSerialize
 pub(crate) mod private {
     pub trait SealedSerialize {}
 }
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_redirect() {
+    use crate::client::{BoxedFuture, Req, Response};
+
+    /// Client that redirects every request, like a http client not following redirects
+    struct RedirectClient(http::StatusCode);
+
+    impl<'a> crate::HttpClient<'a> for RedirectClient {
+        type Error = crate::DummyHttpClient;
+
+        fn req(&'a self, _: Req) -> BoxedFuture<'a, Result<Response, Self::Error>> {
+            let response = http::Response::builder()
+                .status(self.0)
+                .header(http::header::LOCATION, "https://example.com")
+                .body(vec![])
+                .unwrap();
+            Box::pin(async { Ok(response) })
+        }
+    }
+
+    let token = twitch_oauth2::UserToken::from_existing_unchecked(
+        twitch_oauth2::AccessToken::new("token".to_string()),
+        None,
+        twitch_oauth2::ClientId::new("clientid".to_string()),
+        None,
+        "justintv".to_string(),
+        "1337".to_string(),
+        None,
+        None,
+    );
+    let req = users::GetUsersRequest::builder()
+        .id(vec!["1337".into()])
+        .build();
+
+    let client = HelixClient::with_client(RedirectClient(http::StatusCode::MOVED_PERMANENTLY));
+    assert!(matches!(
+        client.req_get(req.clone(), &token).await,
+        Err(ClientRequestError::Redirect { status, location: Some(location) })
+            if status == http::StatusCode::MOVED_PERMANENTLY && location == "https://example.com"
+    ));
+
+    let client = HelixClient::with_client(RedirectClient(http::StatusCode::NOT_MODIFIED));
+    assert!(!matches!(
+        client.req_get(req.clone(), &token).await,
+        Err(ClientRequestError::Redirect { .. })
+    ));
+
+    let client: HelixClient<crate::DummyHttpClient> = HelixClient::default();
+    assert!(matches!(
+        client.req_get(req, &token).await,
+        Err(ClientRequestError::RequestError(_))
+    ));
+}
@@ -0,0 +1,209 @@
+//! Adds a word or phrase to the broadcaster’s list of blocked terms. These are the terms that broadcasters don’t want used in their chat room.
+//! [`add-blocked-term`](https://dev.twitch.tv/docs/api/reference#add-blocked-term)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [AddBlockedTermRequest]
+//!
+//! To use this endpoint, construct an [`AddBlockedTermRequest`] with the [`AddBlockedTermRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::{moderation::add_blocked_term, ModeratorAuth};
+//! let request = add_blocked_term::AddBlockedTermRequest::builder()
+//!     .auth(ModeratorAuth::new("1234", "5678"))
+//!     .build();
+//! ```
+//!
+//! ## Body: [AddBlockedTermBody]
+//!
+//! We also need to provide a body to the request containing what we want to block.
+//!
+//! ```
+//! # use twitch_api2::helix::moderation::add_blocked_term;
+//! let body = add_blocked_term::AddBlockedTermBody::new("A phrase I'm not fond of");
+//! ```
+//!
+//! ## Response: [BlockedTerm]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, moderation::add_blocked_term};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = add_blocked_term::AddBlockedTermRequest::builder()
+//!     .auth(helix::ModeratorAuth::new("1234", "5678"))
+//!     .build();
+//! let body = add_blocked_term::AddBlockedTermBody::new("A phrase I'm not fond of");
+//! let response: add_blocked_term::BlockedTerm = client.req_post(request, body, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`AddBlockedTermRequest::parse_response(None, &request.get_uri(), response)`](AddBlockedTermRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Add Blocked Term](super::add_blocked_term)
+///
+/// [`add-blocked-term`](https://dev.twitch.tv/docs/api/reference#add-blocked-term)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct AddBlockedTermRequest {
+    /// The broadcaster the request is for, and the broadcaster or one of their moderators making it.
+    #[builder(setter(into))]
+    #[serde(flatten)]
+    pub auth: helix::ModeratorAuth,
+}
+
+/// Body Parameters for [Add Blocked Term](super::add_blocked_term)
+///
+/// [`add-blocked-term`](https://dev.twitch.tv/docs/api/reference#add-blocked-term)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct AddBlockedTermBody {
+    /// The word or phrase to block from being used in the broadcaster’s chat room.
+    ///
+    /// The term must contain a minimum of 2 characters and may contain up to a maximum of 500 characters.
+    /// Terms can use a wildcard character (*). The wildcard character must appear at the beginning or end of a word, or set of characters. For example, \*foo or foo\*.
+    ///
+    /// If the blocked term already exists, the response contains the existing blocked term.
+    #[builder(setter(into))]
+    pub text: String,
+}
+
+impl AddBlockedTermBody {
+    /// Block the word or phrase `text`
+    pub fn new(text: impl Into<String>) -> Self { Self { text: text.into() } }
+}
+
+impl helix::private::SealedSerialize for AddBlockedTermBody {}
+
+/// Return Values for [Add Blocked Term](super::add_blocked_term)
+///
+/// [`add-blocked-term`](https://dev.twitch.tv/docs/api/reference#add-blocked-term)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct BlockedTerm {
+    /// The broadcaster that owns the list of blocked terms.
+    pub broadcaster_id: types::UserId,
+    /// The moderator that blocked the word or phrase from being used in the broadcaster’s chat room.
+    pub moderator_id: types::UserId,
+    /// An ID that uniquely identifies this blocked term.
+    pub id: types::BlockedTermId,
+    /// The blocked word or phrase.
+    pub text: String,
+    /// The UTC date and time of when the term was blocked.
+    pub created_at: types::Timestamp,
+    /// The UTC date and time of when the term was updated.
+    ///
+    /// When the term is added, this timestamp is the same as [`created_at`](BlockedTerm::created_at).
+    pub updated_at: types::Timestamp,
+    /// The UTC date and time of when the blocked term is set to expire. After the block expires, users will be able to use the term in the broadcaster’s chat room.
+    ///
+    /// This field is [`None`] if the term was added manually or was permanently blocked by AutoMod.
+    pub expires_at: Option<types::Timestamp>,
+}
+
+impl Request for AddBlockedTermRequest {
+    type Response = BlockedTerm;
+
+    const METHOD: http::Method = http::Method::POST;
+    const PATH: &'static str = "moderation/blocked_terms";
+    /// Scope needed for this endpoint, not yet known to [`twitch_oauth2`]
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:manage:blocked_terms"),
+    )];
+}
+
+impl RequestPost for AddBlockedTermRequest {
+    type Body = AddBlockedTermBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response_str: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
+    where
+        Self: Sized,
+    {
+        let response: helix::InnerResponse<Vec<Self::Response>> =
+            helix::parse_json_owned(response_str, true).map_err(|e| {
+                helix::HelixRequestError::DeserializeError(
+                    response_str.to_string(),
+                    e,
+                    Self::METHOD,
+                    uri.clone(),
+                    status,
+                    None,
+                )
+            })?;
+        let data = response.data.into_iter().next().ok_or_else(|| {
+            helix::HelixRequestError::InvalidResponse {
+                reason: "response included no data",
+                response: response_str.to_string(),
+                status,
+                method: Self::METHOD,
+                uri: uri.clone(),
+                request_id: None,
+            }
+        })?;
+        Ok(helix::Response {
+            data,
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+            request_id: None,
+            diagnostics: Default::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = AddBlockedTermRequest::builder()
+        .auth(ModeratorAuth::new("713936733", "713936733"))
+        .build();
+
+    let body = AddBlockedTermBody::new("A phrase I'm not fond of");
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = crate::fixtures::helix::moderation::ADD_BLOCKED_TERM
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().status(200).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/moderation/blocked_terms?broadcaster_id=713936733&moderator_id=713936733"
+    );
+
+    let resp = AddBlockedTermRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(resp.text, "A phrase I'm not fond of");
+    assert_eq!(resp.expires_at, None);
+}
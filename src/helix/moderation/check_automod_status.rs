@@ -63,6 +63,7 @@ use helix::RequestPost;
 ///
 /// [`check-automod-status`](https://dev.twitch.tv/docs/api/reference#check-automod-status)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct CheckAutoModStatusRequest {
     /// Must match the User ID in the Bearer token.
@@ -74,6 +75,7 @@ pub struct CheckAutoModStatusRequest {
 ///
 /// [`check-automod-status`](https://dev.twitch.tv/docs/api/reference#check-automod-status)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct CheckAutoModStatusBody {
     /// Developer-generated identifier for mapping messages to results.
@@ -113,6 +115,11 @@ impl helix::HelixRequestBody for Vec<CheckAutoModStatusBody> {
 ///
 /// [`check-automod-status`](https://dev.twitch.tv/docs/api/reference#check-automod-status)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct CheckAutoModStatus {
@@ -125,6 +132,7 @@ pub struct CheckAutoModStatus {
 impl Request for CheckAutoModStatusRequest {
     type Response = Vec<CheckAutoModStatus>;
 
+    const METHOD: http::Method = http::Method::POST;
     const PATH: &'static str = "moderation/enforcements/status";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ModerationRead];
@@ -150,21 +158,9 @@ fn test_request() {
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
     // From twitch docs
-    let data = br#"
-{
-   "data": [
-     {
-       "msg_id": "123",
-       "is_permitted": true
-     },
-     {
-       "msg_id": "393",
-       "is_permitted": false
-     }
-   ]
-}
-"#
-    .to_vec();
+    let data = crate::fixtures::helix::moderation::CHECK_AUTOMOD_STATUS
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
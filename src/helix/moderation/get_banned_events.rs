@@ -45,6 +45,7 @@ use std::collections::HashMap;
 ///
 /// [`get-banned-events`](https://dev.twitch.tv/docs/api/reference#get-banned-events)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetBannedEventsRequest {
     /// Must match the User ID in the Bearer token.
@@ -55,18 +56,23 @@ pub struct GetBannedEventsRequest {
     /// Maximum: 100
     #[builder(default)]
     pub user_id: Vec<types::UserId>,
-    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
+    ///
+    /// Backward pagination with [`before`](helix::PaginationParams::before) is not supported.
     #[builder(default)]
-    pub after: Option<helix::Cursor>,
-    /// Maximum number of objects to return. Maximum: 100. Default: 20.
-    #[builder(default, setter(into))]
-    pub first: Option<usize>,
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
 }
 
 /// Return Values for [Get Banned Events](super::get_banned_events)
 ///
 /// [`get-banned-events`](https://dev.twitch.tv/docs/api/reference#get-banned-events)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct BannedEvent {
@@ -86,15 +92,18 @@ pub struct BannedEvent {
 impl Request for GetBannedEventsRequest {
     type Response = Vec<BannedEvent>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "moderation/banned/events";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ModerationRead];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for GetBannedEventsRequest {}
 
 impl helix::Paginated for GetBannedEventsRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
 }
 
 #[cfg(test)]
@@ -102,57 +111,12 @@ impl helix::Paginated for GetBannedEventsRequest {
 fn test_request() {
     use helix::*;
     let req = GetBannedEventsRequest::builder()
-        .broadcaster_id("198704263".to_string())
+        .broadcaster_id("198704263")
         .build();
 
     // From twitch docs
-    let data = br#"
-{
-  "data": [
-    {
-      "id": "1IPFqAb0p0JncbPSTEPhx8JF1Sa",
-      "event_type": "moderation.user.ban",
-      "event_timestamp": "2019-03-13T15:55:14Z",
-      "version": "1.0",
-      "event_data": {
-        "broadcaster_id": "198704263",
-        "broadcaster_login": "racageneg",
-        "broadcaster_name": "racageneg",
-        "user_id": "424596340",
-        "user_login": "quotrok",
-        "user_name": "quotrok",
-        "expires_at": "",
-        "reason": "Does not like pineapple on pizza.",
-        "moderator_id": "141981764",
-        "moderator_login": "twitchdev",
-        "moderator_name": "TwitchDev"
-      }
-    },
-    {
-      "id": "1IPFsDv5cs4mxfJ1s2O9Q5flf4Y",
-      "event_type": "moderation.user.unban",
-      "event_timestamp": "2019-03-13T15:55:30Z",
-      "version": "1.0",
-      "event_data": {
-        "broadcaster_id": "198704263",
-        "broadcaster_login": "racageneg",
-        "broadcaster_name": "racageneg",
-        "user_id": "424596340",
-        "user_login": "quotrok",
-        "user_name": "quotrok",
-        "expires_at": "",
-        "reason": "Does not like pineapple on pizza.",
-        "moderator_id": "141981764",
-        "moderator_login": "twitchdev",
-        "moderator_name": "TwitchDev"
-      }
-    }
-  ],
-  "pagination": {
-    "cursor": "eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6IjE5OTYwNDI2MzoyMDIxMjA1MzE6MUlQRnFtbHU5VzJxNG1YWGpVTHlNOHpYMHJiIn19"
-  }
-}
-"#
+    let data = crate::fixtures::helix::moderation::GET_BANNED_EVENTS
+        .as_bytes()
         .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
@@ -44,6 +44,7 @@ use helix::RequestGet;
 ///
 /// [`get-banned-users`](https://dev.twitch.tv/docs/api/reference#get-banned-users)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetBannedUsersRequest {
     /// Must match the User ID in the Bearer token.
@@ -54,21 +55,21 @@ pub struct GetBannedUsersRequest {
     /// Maximum: 100
     #[builder(default)]
     pub user_id: Vec<types::UserId>,
-    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
     #[builder(default)]
-    pub after: Option<helix::Cursor>,
-    /// Cursor for backward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
-    #[builder(default)]
-    pub before: Option<helix::Cursor>,
-    /// Number of values to be returned per page. Limit: 100. Default: 20.
-    #[builder(setter(into), default)]
-    pub first: Option<String>,
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
 }
 
 /// Return Values for [Get Banned Users](super::get_banned_users)
 ///
 /// [`get-banned-users`](https://dev.twitch.tv/docs/api/reference#get-banned-users)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct BannedUser {
@@ -95,15 +96,18 @@ pub struct BannedUser {
 impl Request for GetBannedUsersRequest {
     type Response = Vec<BannedUser>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "moderation/banned";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ModerationRead];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for GetBannedUsersRequest {}
 
 impl helix::Paginated for GetBannedUsersRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
 }
 
 #[cfg(test)]
@@ -111,49 +115,12 @@ impl helix::Paginated for GetBannedUsersRequest {
 fn test_request() {
     use helix::*;
     let req = GetBannedUsersRequest::builder()
-        .broadcaster_id("198704263".to_string())
+        .broadcaster_id("198704263")
         .build();
 
     // From twitch docs
-    let data = br#"
-{
-  "data": [
-    {
-      "user_id": "423374343",
-      "user_login": "glowillig",
-      "user_name": "glowillig",
-      "expires_at": "2022-03-15T02:00:28Z",
-      "reason": "Does not like pineapple on pizza.",
-      "moderator_id": "141981764",
-      "moderator_login": "twitchdev",
-      "moderator_name": "TwitchDev"
-    },
-    {
-      "user_id": "424596340",
-      "user_login": "quotrok",
-      "user_name": "quotrok",
-      "expires_at": "2022-08-07T02:07:55Z",
-      "reason": "Inappropriate words.",
-      "moderator_id": "141981764",
-      "moderator_login": "twitchdev",
-      "moderator_name": "TwitchDev"
-    },
-    {
-      "user_id": "424596340",
-      "user_login": "quotrok",
-      "user_name": "quotrok",
-      "expires_at": "",
-      "reason": "",
-      "moderator_id": "141981764",
-      "moderator_login": "twitchdev",
-      "moderator_name": "TwitchDev"
-    }
-  ],
-  "pagination": {
-    "cursor": "eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6IjEwMDQ3MzA2NDo4NjQwNjU3MToxSVZCVDFKMnY5M1BTOXh3d1E0dUdXMkJOMFcifX0"
-  }
-}
-"#
+    let data = crate::fixtures::helix::moderation::GET_BANNED_USERS
+        .as_bytes()
         .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
@@ -0,0 +1,120 @@
+//! Gets a list of channels that the specified user has moderator privileges in.
+//! [`get-moderated-channels`](https://dev.twitch.tv/docs/api/reference#get-moderated-channels)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetModeratedChannelsRequest]
+//!
+//! To use this endpoint, construct a [`GetModeratedChannelsRequest`] with the [`GetModeratedChannelsRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::moderation::get_moderated_channels;
+//! let request = get_moderated_channels::GetModeratedChannelsRequest::builder()
+//!     .user_id("1234")
+//!     .build();
+//! ```
+//!
+//! ## Response: [ModeratedChannel]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, moderation::get_moderated_channels};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = get_moderated_channels::GetModeratedChannelsRequest::builder()
+//!     .user_id("1234")
+//!     .build();
+//! let response: Vec<get_moderated_channels::ModeratedChannel> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetModeratedChannelsRequest::parse_response(None, &request.get_uri(), response)`](GetModeratedChannelsRequest::parse_response)
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Moderated Channels](super::get_moderated_channels)
+///
+/// [`get-moderated-channels`](https://dev.twitch.tv/docs/api/reference#get-moderated-channels)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct GetModeratedChannelsRequest {
+    /// A user’s ID. Returns the list of channels that this user has moderator privileges in. Must match the User ID in the Bearer token.
+    #[builder(setter(into))]
+    pub user_id: types::UserId,
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
+    #[builder(default)]
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
+}
+
+/// Return Values for [Get Moderated Channels](super::get_moderated_channels)
+///
+/// [`get-moderated-channels`](https://dev.twitch.tv/docs/api/reference#get-moderated-channels)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ModeratedChannel {
+    /// An ID that uniquely identifies the channel this user can moderate.
+    pub broadcaster_id: types::UserId,
+    /// The channel’s login name.
+    pub broadcaster_login: types::UserName,
+    /// The channel’s display name.
+    pub broadcaster_name: types::DisplayName,
+}
+
+impl Request for GetModeratedChannelsRequest {
+    type Response = Vec<ModeratedChannel>;
+
+    const METHOD: http::Method = http::Method::GET;
+    const PATH: &'static str = "moderation/channels";
+    /// Scope needed for this endpoint, not yet known to [`twitch_oauth2`]
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("user:read:moderated_channels"),
+    )];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
+}
+
+impl RequestGet for GetModeratedChannelsRequest {}
+
+impl helix::Paginated for GetModeratedChannelsRequest {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetModeratedChannelsRequest::builder()
+        .user_id("931931")
+        .build();
+
+    // From twitch docs
+    let data = crate::fixtures::helix::moderation::GET_MODERATED_CHANNELS
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/moderation/channels?user_id=931931"
+    );
+
+    dbg!(GetModeratedChannelsRequest::parse_response(Some(req), &uri, http_response).unwrap());
+}
@@ -45,6 +45,7 @@ use std::collections::HashMap;
 ///
 /// [`get-moderator-events`](https://dev.twitch.tv/docs/api/reference#get-moderator-events)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetModeratorEventsRequest {
     /// Must match the User ID in the Bearer token.
@@ -55,18 +56,23 @@ pub struct GetModeratorEventsRequest {
     /// Maximum: 100
     #[builder(default)]
     pub user_id: Vec<types::UserId>,
-    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
+    ///
+    /// Backward pagination with [`before`](helix::PaginationParams::before) is not supported.
     #[builder(default)]
-    pub after: Option<helix::Cursor>,
-    /// Number of values to be returned per page. Limit: 100. Default: 20.
-    #[builder(setter(into), default)]
-    pub first: Option<String>,
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
 }
 
 /// Return Values for [Get Moderators Events](super::get_moderator_events)
 ///
 /// [`get-moderator-events`](https://dev.twitch.tv/docs/api/reference#get-moderator-events)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ModeratorEvent {
@@ -86,15 +92,18 @@ pub struct ModeratorEvent {
 impl Request for GetModeratorEventsRequest {
     type Response = Vec<ModeratorEvent>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "moderation/moderators/events";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ModerationRead];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for GetModeratorEventsRequest {}
 
 impl helix::Paginated for GetModeratorEventsRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
 }
 
 #[cfg(test)]
@@ -102,61 +111,12 @@ impl helix::Paginated for GetModeratorEventsRequest {
 fn test_request() {
     use helix::*;
     let req = GetModeratorEventsRequest::builder()
-        .broadcaster_id("198704263".to_string())
+        .broadcaster_id("198704263")
         .build();
 
     // From twitch docs
-    let data = br#"
-{
-    "data": [
-        {
-        "id": "1IVBTnDSUDApiBQW4UBcVTK4hPr",
-        "event_type": "moderation.moderator.remove",
-        "event_timestamp": "2019-03-15T18:18:14Z",
-        "version": "1.0",
-        "event_data": {
-            "broadcaster_id": "198704263",
-            "broadcaster_login": "aan22209",
-            "broadcaster_name": "aan22209",
-            "user_id": "423374343",
-            "user_login": "glowillig",
-            "user_name": "glowillig"
-        }
-        },
-        {
-        "id": "1IVIPQdYIEnD8nJ376qkASDzsj7",
-        "event_type": "moderation.moderator.add",
-        "event_timestamp": "2019-03-15T19:15:13Z",
-        "version": "1.0",
-        "event_data": {
-            "broadcaster_id": "198704263",
-            "broadcaster_login": "aan22209",
-            "broadcaster_name": "aan22209",
-            "user_id": "423374343",
-            "user_login": "glowillig",
-            "user_name": "glowillig"
-        }
-        },
-        {
-        "id": "1IVBTP7gG61oXLMu7fvnRhrpsro",
-        "event_type": "moderation.moderator.remove",
-        "event_timestamp": "2019-03-15T18:18:11Z",
-        "version": "1.0",
-        "event_data": {
-            "broadcaster_id": "198704263",
-            "broadcaster_login": "aan22209",
-            "broadcaster_name": "aan22209",
-            "user_id": "424596340",
-            "user_login": "quotrok",
-            "user_name": "quotrok"
-        }
-        }
-    ],
-    "pagination": {
-        "cursor": "eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6IjEwMDQ3MzA2NDo4NjQwNjU3MToxSVZCVDFKMnY5M1BTOXh3d1E0dUdXMkJOMFcifX0"
-    }
-}
-"#
+    let data = crate::fixtures::helix::moderation::GET_MODERATOR_EVENTS
+        .as_bytes()
         .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
@@ -169,3 +129,20 @@ fn test_request() {
 
     dbg!(GetModeratorEventsRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+#[test]
+fn test_pagination() {
+    use helix::*;
+    let mut req = GetModeratorEventsRequest::builder()
+        .broadcaster_id("198704263")
+        .pagination(PaginationParams::default().with_first(PageSize::new(100).unwrap()))
+        .build();
+    req.set_pagination(Some("eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6IjEwMDQ3MzA2NDo4NjQwNjU3MToxSVZCVDFKMnY5M1BTOXh3d1E0dUdXMkJOMFcifX0".into()));
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/moderation/moderators/events?broadcaster_id=198704263&after=eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6IjEwMDQ3MzA2NDo4NjQwNjU3MToxSVZCVDFKMnY5M1BTOXh3d1E0dUdXMkJOMFcifX0&first=100"
+    );
+}
@@ -45,6 +45,7 @@ use helix::RequestGet;
 ///
 /// [`get-moderators`](https://dev.twitch.tv/docs/api/reference#get-moderators)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetModeratorsRequest {
     /// Must match the User ID in the Bearer token.
@@ -53,18 +54,23 @@ pub struct GetModeratorsRequest {
     /// Filters the results and only returns a status object for users who are moderators in this channel and have a matching user_id.
     #[builder(setter(into), default)]
     pub user_id: Vec<types::UserId>,
-    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
+    ///
+    /// Backward pagination with [`before`](helix::PaginationParams::before) is not supported.
     #[builder(default)]
-    pub after: Option<helix::Cursor>,
-    /// Number of values to be returned per page. Limit: 100. Default: 20.
-    #[builder(setter(into), default)]
-    pub first: Option<String>,
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
 }
 
 /// Return Values for [Get Moderators](super::get_moderators)
 ///
 /// [`get-moderators`](https://dev.twitch.tv/docs/api/reference#get-moderators)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Moderator {
@@ -79,15 +85,18 @@ pub struct Moderator {
 impl Request for GetModeratorsRequest {
     type Response = Vec<Moderator>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "moderation/moderators";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ModerationRead];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for GetModeratorsRequest {}
 
 impl helix::Paginated for GetModeratorsRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
 }
 
 #[cfg(test)]
@@ -95,29 +104,12 @@ impl helix::Paginated for GetModeratorsRequest {
 fn test_request() {
     use helix::*;
     let req = GetModeratorsRequest::builder()
-        .broadcaster_id("198704263".to_string())
+        .broadcaster_id("198704263")
         .build();
 
     // From twitch docs
-    let data = br#"
-{
-    "data": [
-        {
-            "user_id": "424596340",
-            "user_login": "quotrok",
-            "user_name": "quotrok"
-        },
-        {
-            "user_id": "424596340",
-            "user_login": "quotrok",
-            "user_name": "quotrok"
-        }
-    ],
-    "pagination": {
-        "cursor": "eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6IjEwMDQ3MzA2NDo4NjQwNjU3MToxSVZCVDFKMnY5M1BTOXh3d1E0dUdXMkJOMFcifX0"
-    }
-}
-"#
+    let data = crate::fixtures::helix::moderation::GET_MODERATORS
+        .as_bytes()
         .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
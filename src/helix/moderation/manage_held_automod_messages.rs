@@ -59,6 +59,7 @@ use helix::RequestPost;
 ///
 /// [`manage-held-automod-messages`](https://dev.twitch.tv/docs/api/reference#manage-held-automod-messages)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct ManageHeldAutoModMessagesRequest {}
 
@@ -75,6 +76,7 @@ impl Default for ManageHeldAutoModMessagesRequest {
 ///
 /// [`manage-held-automod-messages`](https://dev.twitch.tv/docs/api/reference#manage-held-automod-messages)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct ManageHeldAutoModMessagesBody {
     /// The moderator who is approving or rejecting the held message. Must match the user_id in the user OAuth token.
@@ -90,6 +92,11 @@ pub struct ManageHeldAutoModMessagesBody {
 
 /// Action to take for a message.
 #[derive(PartialEq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[serde(rename_all = "UPPERCASE")]
 #[non_exhaustive]
 pub enum AutoModAction {
@@ -114,6 +121,11 @@ impl helix::private::SealedSerialize for ManageHeldAutoModMessagesBody {}
 ///
 /// [`manage-held-automod-messages`](https://dev.twitch.tv/docs/api/reference#manage-held-automod-messages)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub enum ManageHeldAutoModMessages {
@@ -124,6 +136,7 @@ pub enum ManageHeldAutoModMessages {
 impl Request for ManageHeldAutoModMessagesRequest {
     type Response = ManageHeldAutoModMessages;
 
+    const METHOD: http::Method = http::Method::POST;
     const PATH: &'static str = "moderation/automod/message";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ModerationRead];
@@ -137,7 +150,7 @@ impl RequestPost for ManageHeldAutoModMessagesRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
@@ -148,12 +161,16 @@ impl RequestPost for ManageHeldAutoModMessagesRequest {
                 request,
                 total: None,
                 other: None,
+                request_id: None,
+                diagnostics: Default::default(),
             }),
-            _ => Err(helix::HelixRequestPostError::InvalidResponse {
+            _ => Err(helix::HelixRequestError::InvalidResponse {
                 reason: "unexpected status",
                 response: response.to_string(),
                 status,
+                method: Self::METHOD,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
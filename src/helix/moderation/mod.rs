@@ -7,13 +7,19 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
+pub mod add_blocked_term;
 pub mod check_automod_status;
 pub mod get_banned_events;
 pub mod get_banned_users;
+pub mod get_moderated_channels;
 pub mod get_moderator_events;
 pub mod get_moderators;
 pub mod manage_held_automod_messages;
+pub mod remove_blocked_term;
+pub mod update_automod_settings;
 
+#[doc(inline)]
+pub use add_blocked_term::{AddBlockedTermBody, AddBlockedTermRequest, BlockedTerm};
 #[doc(inline)]
 pub use check_automod_status::{
     CheckAutoModStatus, CheckAutoModStatusBody, CheckAutoModStatusRequest,
@@ -23,6 +29,8 @@ pub use get_banned_events::{BannedEvent, GetBannedEventsRequest};
 #[doc(inline)]
 pub use get_banned_users::{BannedUser, GetBannedUsersRequest};
 #[doc(inline)]
+pub use get_moderated_channels::{GetModeratedChannelsRequest, ModeratedChannel};
+#[doc(inline)]
 pub use get_moderator_events::{GetModeratorEventsRequest, ModeratorEvent};
 #[doc(inline)]
 pub use get_moderators::{GetModeratorsRequest, Moderator};
@@ -31,3 +39,9 @@ pub use manage_held_automod_messages::{
     AutoModAction, ManageHeldAutoModMessages, ManageHeldAutoModMessagesBody,
     ManageHeldAutoModMessagesRequest,
 };
+#[doc(inline)]
+pub use remove_blocked_term::{RemoveBlockedTerm, RemoveBlockedTermRequest};
+#[doc(inline)]
+pub use update_automod_settings::{
+    AutoModLevels, AutoModSettings, UpdateAutoModSettingsBody, UpdateAutoModSettingsRequest,
+};
@@ -0,0 +1,273 @@
+//! Updates the broadcaster’s AutoMod settings, which are used to automatically block inappropriate or harassing messages from appearing in the broadcaster’s chat room.
+//! [`update-automod-settings`](https://dev.twitch.tv/docs/api/reference#update-automod-settings)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [UpdateAutoModSettingsRequest]
+//!
+//! To use this endpoint, construct an [`UpdateAutoModSettingsRequest`] with the [`UpdateAutoModSettingsRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::{moderation::update_automod_settings, ModeratorAuth};
+//! let request = update_automod_settings::UpdateAutoModSettingsRequest::builder()
+//!     .auth(ModeratorAuth::new("1234", "5678"))
+//!     .build();
+//! ```
+//!
+//! ## Body: [UpdateAutoModSettingsBody]
+//!
+//! We also need to provide a body to the request, setting either the overall level or the individual levels.
+//!
+//! ```
+//! # use twitch_api2::{helix::moderation::update_automod_settings, types};
+//! let body =
+//!     update_automod_settings::UpdateAutoModSettingsBody::overall(types::AutoModLevel::Level3);
+//! // or
+//! let body = update_automod_settings::UpdateAutoModSettingsBody::individual(
+//!     update_automod_settings::AutoModLevels::builder()
+//!         .aggression(types::AutoModLevel::Level2)
+//!         .swearing(types::AutoModLevel::Level4)
+//!         .build(),
+//! );
+//! ```
+//!
+//! ## Response: [AutoModSettings]
+//!
+//! Send the request to receive the response with [`HelixClient::req_put()`](helix::HelixClient::req_put).
+//!
+//! ```rust, no_run
+//! use twitch_api2::{helix::{self, moderation::update_automod_settings}, types};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = update_automod_settings::UpdateAutoModSettingsRequest::builder()
+//!     .auth(helix::ModeratorAuth::new("1234", "5678"))
+//!     .build();
+//! let body = update_automod_settings::UpdateAutoModSettingsBody::overall(types::AutoModLevel::Level3);
+//! let response: update_automod_settings::AutoModSettings = client.req_put(request, body, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPut::create_request)
+//! and parse the [`http::Response`] with [`UpdateAutoModSettingsRequest::parse_response(None, &request.get_uri(), response)`](UpdateAutoModSettingsRequest::parse_response)
+
+use super::*;
+use helix::RequestPut;
+
+/// Query Parameters for [Update AutoMod Settings](super::update_automod_settings)
+///
+/// [`update-automod-settings`](https://dev.twitch.tv/docs/api/reference#update-automod-settings)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct UpdateAutoModSettingsRequest {
+    /// The broadcaster the request is for, and the broadcaster or one of their moderators making it.
+    #[builder(setter(into))]
+    #[serde(flatten)]
+    pub auth: helix::ModeratorAuth,
+}
+
+/// Body Parameters for [Update AutoMod Settings](super::update_automod_settings)
+///
+/// Twitch accepts either the overall level or the individual levels, not both.
+/// The levels go from [0](types::AutoModLevel::Level0) (no filtering) to [4](types::AutoModLevel::Level4) (the most aggressive filtering).
+///
+/// [`update-automod-settings`](https://dev.twitch.tv/docs/api/reference#update-automod-settings)
+#[derive(PartialEq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum UpdateAutoModSettingsBody {
+    /// Set the default level for all categories
+    Overall {
+        /// The default AutoMod level for the broadcaster.
+        overall_level: types::AutoModLevel,
+    },
+    /// Set the level of each category, categories not set are set to 0
+    Individual(AutoModLevels),
+}
+
+impl UpdateAutoModSettingsBody {
+    /// Set the default level for all categories
+    pub fn overall(overall_level: types::AutoModLevel) -> Self { Self::Overall { overall_level } }
+
+    /// Set the level of each category
+    pub fn individual(levels: AutoModLevels) -> Self { Self::Individual(levels) }
+}
+
+impl helix::private::SealedSerialize for UpdateAutoModSettingsBody {}
+
+/// Levels of the individual AutoMod categories, see [`UpdateAutoModSettingsBody::Individual`]
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct AutoModLevels {
+    /// The Automod level for hostility involving aggression.
+    #[builder(default)]
+    pub aggression: types::AutoModLevel,
+    /// The Automod level for hostility involving name calling or insults.
+    #[builder(default)]
+    pub bullying: types::AutoModLevel,
+    /// The Automod level for discrimination against disability.
+    #[builder(default)]
+    pub disability: types::AutoModLevel,
+    /// The Automod level for discrimination against women.
+    #[builder(default)]
+    pub misogyny: types::AutoModLevel,
+    /// The Automod level for racial discrimination.
+    #[builder(default)]
+    pub race_ethnicity_or_religion: types::AutoModLevel,
+    /// The Automod level for sexual content.
+    #[builder(default)]
+    pub sex_based_terms: types::AutoModLevel,
+    /// The AutoMod level for discrimination based on sexuality, sex, or gender.
+    #[builder(default)]
+    pub sexuality_sex_or_gender: types::AutoModLevel,
+    /// The Automod level for profanity.
+    #[builder(default)]
+    pub swearing: types::AutoModLevel,
+}
+
+/// Return Values for [Update AutoMod Settings](super::update_automod_settings)
+///
+/// [`update-automod-settings`](https://dev.twitch.tv/docs/api/reference#update-automod-settings)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct AutoModSettings {
+    /// The broadcaster’s ID.
+    pub broadcaster_id: types::UserId,
+    /// The moderator’s ID.
+    pub moderator_id: types::UserId,
+    /// The default AutoMod level for the broadcaster. This field is [`None`] if the broadcaster has set one or more of the individual settings.
+    pub overall_level: Option<types::AutoModLevel>,
+    /// The Automod level for discrimination against disability.
+    pub disability: types::AutoModLevel,
+    /// The Automod level for hostility involving aggression.
+    pub aggression: types::AutoModLevel,
+    /// The AutoMod level for discrimination based on sexuality, sex, or gender.
+    pub sexuality_sex_or_gender: types::AutoModLevel,
+    /// The Automod level for discrimination against women.
+    pub misogyny: types::AutoModLevel,
+    /// The Automod level for hostility involving name calling or insults.
+    pub bullying: types::AutoModLevel,
+    /// The Automod level for profanity.
+    pub swearing: types::AutoModLevel,
+    /// The Automod level for racial discrimination.
+    pub race_ethnicity_or_religion: types::AutoModLevel,
+    /// The Automod level for sexual content.
+    pub sex_based_terms: types::AutoModLevel,
+}
+
+impl Request for UpdateAutoModSettingsRequest {
+    type Response = AutoModSettings;
+
+    const METHOD: http::Method = http::Method::PUT;
+    const PATH: &'static str = "moderation/automod/settings";
+    /// Scope needed for this endpoint, not yet known to [`twitch_oauth2`]
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:manage:automod_settings"),
+    )];
+}
+
+impl RequestPut for UpdateAutoModSettingsRequest {
+    type Body = UpdateAutoModSettingsBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response_str: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
+    where
+        Self: Sized,
+    {
+        let response: helix::InnerResponse<Vec<Self::Response>> =
+            helix::parse_json_owned(response_str, true).map_err(|e| {
+                helix::HelixRequestError::DeserializeError(
+                    response_str.to_string(),
+                    e,
+                    Self::METHOD,
+                    uri.clone(),
+                    status,
+                    None,
+                )
+            })?;
+        let data = response.data.into_iter().next().ok_or_else(|| {
+            helix::HelixRequestError::InvalidResponse {
+                reason: "response included no data",
+                response: response_str.to_string(),
+                status,
+                method: Self::METHOD,
+                uri: uri.clone(),
+                request_id: None,
+            }
+        })?;
+        Ok(helix::Response {
+            data,
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+            request_id: None,
+            diagnostics: Default::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = UpdateAutoModSettingsRequest::builder()
+        .auth(ModeratorAuth::new("1234", "5678"))
+        .build();
+
+    let body = UpdateAutoModSettingsBody::overall(types::AutoModLevel::Level3);
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"overall_level":3}"#
+    );
+    let individual = UpdateAutoModSettingsBody::individual(
+        AutoModLevels::builder()
+            .aggression(types::AutoModLevel::Level2)
+            .swearing(types::AutoModLevel::Level4)
+            .build(),
+    );
+    assert_eq!(
+        serde_json::to_value(&individual).unwrap()["swearing"],
+        serde_json::json!(4)
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = crate::fixtures::helix::moderation::UPDATE_AUTOMOD_SETTINGS
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/moderation/automod/settings?broadcaster_id=1234&moderator_id=5678"
+    );
+
+    let resp = UpdateAutoModSettingsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(resp.overall_level, Some(types::AutoModLevel::Level3));
+    assert!(resp.bullying >= types::AutoModLevel::Level2);
+    assert!(resp.swearing < types::AutoModLevel::Level1);
+}
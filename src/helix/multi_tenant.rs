@@ -0,0 +1,332 @@
+//! Act on behalf of many broadcasters with one client.
+//!
+//! A [`MultiTenantHelix`] keeps the [`UserToken`] of every broadcaster it knows, and [`for_broadcaster`](MultiTenantHelix::for_broadcaster)
+//! makes requests with the token of that broadcaster, refreshing it first if it's about to expire.
+//! The refresh token and client secret needed for refreshing are taken from the token itself.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! use twitch_api2::helix::{self, multi_tenant::MultiTenantHelix};
+//!
+//! let tenants = MultiTenantHelix::new(&client);
+//! tenants.insert(token).await;
+//!
+//! let req = helix::moderation::GetModeratorsRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! let moderators = tenants.for_broadcaster("1234").req_get(req).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use futures::lock::Mutex;
+use twitch_oauth2::{TwitchToken, UserToken};
+
+use super::{
+    ClientRequestError, HelixClient, HelixRequestBody, Request, RequestDelete, RequestGet,
+    RequestPatch, RequestPost, RequestPut, Response,
+};
+use crate::types;
+
+/// Tokens expiring in less than this are refreshed before they are used
+const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// Errors when making a request for a broadcaster with [`MultiTenantHelix`]
+#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[non_exhaustive]
+pub enum MultiTenantError<RE: std::error::Error + Send + Sync + 'static> {
+    /// no token for broadcaster `{0}`
+    UnknownBroadcaster(types::UserId),
+    /// could not refresh token of broadcaster `{0}`
+    Refresh(
+        types::UserId,
+        #[source] twitch_oauth2::tokens::errors::RefreshTokenError<RE>,
+    ),
+    /// request failed
+    Request(#[from] ClientRequestError<RE>),
+}
+
+/// Makes requests with the token of the broadcaster they are for, see the [module documentation](self)
+pub struct MultiTenantHelix<'a, C: crate::HttpClient<'a>> {
+    client: &'a HelixClient<'a, C>,
+    tokens: Mutex<HashMap<types::UserId, UserToken>>,
+}
+
+impl<'a, C: crate::HttpClient<'a>> std::fmt::Debug for MultiTenantHelix<'a, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiTenantHelix").finish_non_exhaustive()
+    }
+}
+
+impl<'a, C> MultiTenantHelix<'a, C>
+where C: crate::HttpClient<'a> + Sync
+{
+    /// Create a router using `client`, without any broadcasters
+    pub fn new(client: &'a HelixClient<'a, C>) -> Self {
+        MultiTenantHelix {
+            client,
+            tokens: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Use `token` for requests for the broadcaster it belongs to, returning the token it replaces
+    pub async fn insert(&self, token: UserToken) -> Option<UserToken> {
+        let broadcaster_id = types::UserId::from(token.user_id.clone());
+        self.tokens.lock().await.insert(broadcaster_id, token)
+    }
+
+    /// Stop acting on behalf of `broadcaster_id`, returning its token
+    pub async fn remove(&self, broadcaster_id: &types::UserIdRef) -> Option<UserToken> {
+        self.tokens.lock().await.remove(broadcaster_id)
+    }
+
+    /// The broadcasters with a token
+    pub async fn broadcasters(&self) -> Vec<types::UserId> {
+        self.tokens.lock().await.keys().cloned().collect()
+    }
+
+    /// Make requests on behalf of `broadcaster_id`
+    pub fn for_broadcaster(&self, broadcaster_id: impl Into<types::UserId>) -> Tenant<'_, 'a, C> {
+        Tenant {
+            helix: self,
+            broadcaster_id: broadcaster_id.into(),
+        }
+    }
+}
+
+/// Requests on behalf of a single broadcaster, made with [`MultiTenantHelix::for_broadcaster`]
+#[derive(Debug)]
+pub struct Tenant<'t, 'a, C: crate::HttpClient<'a>> {
+    helix: &'t MultiTenantHelix<'a, C>,
+    broadcaster_id: types::UserId,
+}
+
+type TenantError<'a, C> = MultiTenantError<<C as crate::HttpClient<'a>>::Error>;
+
+impl<'t, 'a, C> Tenant<'t, 'a, C>
+where C: crate::HttpClient<'a> + Sync
+{
+    /// The broadcaster the requests are made for
+    pub fn broadcaster_id(&self) -> &types::UserIdRef { &self.broadcaster_id }
+
+    /// The token of the broadcaster, refreshed if it's about to expire
+    ///
+    /// The tokens are not locked while refreshing, requests for other broadcasters are not held up by a refresh.
+    pub async fn token(&self) -> Result<UserToken, TenantError<'a, C>> {
+        let mut token = self
+            .helix
+            .tokens
+            .lock()
+            .await
+            .get(&self.broadcaster_id)
+            .cloned()
+            .ok_or_else(|| MultiTenantError::UnknownBroadcaster(self.broadcaster_id.clone()))?;
+        if token.expires_in() < REFRESH_MARGIN {
+            let old = token.access_token.clone();
+            token
+                .refresh_token(self.helix.client)
+                .await
+                .map_err(|e| MultiTenantError::Refresh(self.broadcaster_id.clone(), e))?;
+            // Keep tokens that were replaced or removed while refreshing
+            if let Some(stored) = self.helix.tokens.lock().await.get_mut(&self.broadcaster_id) {
+                if stored.access_token.secret() == old.secret() {
+                    *stored = token.clone();
+                }
+            }
+        }
+        Ok(token)
+    }
+
+    /// Get the [ChannelInformation](super::channels::ChannelInformation) of the broadcaster
+    pub async fn channel(
+        &self,
+    ) -> Result<Option<super::channels::ChannelInformation>, TenantError<'a, C>> {
+        let token = self.token().await?;
+        Ok(self
+            .helix
+            .client
+            .get_channel_from_id(self.broadcaster_id.clone(), &token)
+            .await?)
+    }
+
+    /// Get the [Poll](super::polls::Poll) currently running in the channel of the broadcaster, if any
+    pub async fn active_poll(&self) -> Result<Option<super::polls::Poll>, TenantError<'a, C>> {
+        let token = self.token().await?;
+        Ok(self
+            .helix
+            .client
+            .active_poll(self.broadcaster_id.clone(), &token)
+            .await?)
+    }
+
+    /// Get the [Prediction](super::predictions::Prediction) currently running in the channel of the broadcaster, if any
+    pub async fn active_prediction(
+        &self,
+    ) -> Result<Option<super::predictions::Prediction>, TenantError<'a, C>> {
+        let token = self.token().await?;
+        Ok(self
+            .helix
+            .client
+            .active_prediction(self.broadcaster_id.clone(), &token)
+            .await?)
+    }
+
+    /// Request on a valid [`RequestGet`] endpoint with the token of the broadcaster
+    pub async fn req_get<R, D>(&self, request: R) -> Result<Response<R, D>, TenantError<'a, C>>
+    where
+        R: Request<Response = D> + RequestGet,
+        D: serde::de::DeserializeOwned + PartialEq,
+        C: Send, {
+        let token = self.token().await?;
+        Ok(self.helix.client.req_get(request, &token).await?)
+    }
+
+    /// Request on a valid [`RequestPost`] endpoint with the token of the broadcaster
+    pub async fn req_post<R, B, D>(
+        &self,
+        request: R,
+        body: B,
+    ) -> Result<Response<R, D>, TenantError<'a, C>>
+    where
+        R: Request<Response = D> + RequestPost<Body = B>,
+        B: HelixRequestBody,
+        D: serde::de::DeserializeOwned + PartialEq,
+    {
+        let token = self.token().await?;
+        Ok(self.helix.client.req_post(request, body, &token).await?)
+    }
+
+    /// Request on a valid [`RequestPatch`] endpoint with the token of the broadcaster
+    pub async fn req_patch<R, B, D>(
+        &self,
+        request: R,
+        body: B,
+    ) -> Result<Response<R, D>, TenantError<'a, C>>
+    where
+        R: Request<Response = D> + RequestPatch<Body = B>,
+        B: HelixRequestBody,
+        D: serde::de::DeserializeOwned + PartialEq,
+    {
+        let token = self.token().await?;
+        Ok(self.helix.client.req_patch(request, body, &token).await?)
+    }
+
+    /// Request on a valid [`RequestPut`] endpoint with the token of the broadcaster
+    pub async fn req_put<R, B, D>(
+        &self,
+        request: R,
+        body: B,
+    ) -> Result<Response<R, D>, TenantError<'a, C>>
+    where
+        R: Request<Response = D> + RequestPut<Body = B>,
+        B: HelixRequestBody,
+        D: serde::de::DeserializeOwned + PartialEq,
+    {
+        let token = self.token().await?;
+        Ok(self.helix.client.req_put(request, body, &token).await?)
+    }
+
+    /// Request on a valid [`RequestDelete`] endpoint with the token of the broadcaster
+    pub async fn req_delete<R, D>(&self, request: R) -> Result<Response<R, D>, TenantError<'a, C>>
+    where
+        R: Request<Response = D> + RequestDelete,
+        D: serde::de::DeserializeOwned + PartialEq, {
+        let token = self.token().await?;
+        Ok(self.helix.client.req_delete(request, &token).await?)
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_multi_tenant() {
+    let client: HelixClient<'static, crate::client::DummyHttpClient> = HelixClient::default();
+    let tenants = MultiTenantHelix::new(&client);
+    let token = UserToken::from_existing_unchecked(
+        twitch_oauth2::AccessToken::new("token".to_string()),
+        None,
+        twitch_oauth2::ClientId::new("clientid".to_string()),
+        None,
+        "justintv".to_string(),
+        "1337".to_string(),
+        None,
+        None,
+    );
+    assert!(tenants.insert(token.clone()).await.is_none());
+    assert!(tenants.insert(token).await.is_some());
+    assert_eq!(
+        tenants.broadcasters().await,
+        vec![types::UserId::from("1337")]
+    );
+
+    assert!(matches!(
+        tenants.for_broadcaster("1234").token().await,
+        Err(MultiTenantError::UnknownBroadcaster(id)) if id == "1234"
+    ));
+    assert!(tenants
+        .remove(types::UserIdRef::from_str("1337"))
+        .await
+        .is_some());
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_refresh() {
+    use crate::client::{BoxedFuture, Req, Response};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Client counting token refreshes
+    struct RefreshClient(AtomicUsize);
+
+    impl<'a> crate::HttpClient<'a> for RefreshClient {
+        type Error = crate::DummyHttpClient;
+
+        fn req(&'a self, request: Req) -> BoxedFuture<'a, Result<Response, Self::Error>> {
+            assert_eq!(request.uri().path(), "/oauth2/token");
+            self.0.fetch_add(1, Ordering::SeqCst);
+            let response = http::Response::builder()
+                .body(
+                    br#"{"access_token":"newtoken","refresh_token":"newrefresh","expires_in":14400,"scope":[],"token_type":"bearer"}"#
+                        .to_vec(),
+                )
+                .unwrap();
+            Box::pin(async { Ok(response) })
+        }
+    }
+
+    let client = HelixClient::with_client(RefreshClient(AtomicUsize::new(0)));
+    let tenants = MultiTenantHelix::new(&client);
+    // an expired token
+    tenants
+        .insert(UserToken::from_existing_unchecked(
+            twitch_oauth2::AccessToken::new("token".to_string()),
+            twitch_oauth2::RefreshToken::new("refresh".to_string()),
+            twitch_oauth2::ClientId::new("clientid".to_string()),
+            twitch_oauth2::ClientSecret::new("secret".to_string()),
+            "justintv".to_string(),
+            "1337".to_string(),
+            None,
+            Some(Duration::from_secs(0)),
+        ))
+        .await;
+
+    let tenant = tenants.for_broadcaster("1337");
+    assert_eq!(
+        tenant.token().await.unwrap().access_token.secret(),
+        "newtoken"
+    );
+    // the refreshed token was stored
+    assert_eq!(
+        tenant.token().await.unwrap().access_token.secret(),
+        "newtoken"
+    );
+    assert_eq!(client.client.0.load(Ordering::SeqCst), 1);
+}
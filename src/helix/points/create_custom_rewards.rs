@@ -61,6 +61,7 @@ use helix::RequestPost;
 ///
 /// [`create-custom-rewards`](https://dev.twitch.tv/docs/api/reference#create-custom-rewards)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct CreateCustomRewardRequest {
     /// Provided broadcaster_id must match the user_id in the auth token
@@ -72,13 +73,14 @@ pub struct CreateCustomRewardRequest {
 ///
 /// [`create-custom-rewards`](https://dev.twitch.tv/docs/api/reference#create-custom-rewards)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct CreateCustomRewardBody {
     /// The title of the reward
     #[builder(setter(into))]
     pub title: String,
     /// The prompt for the viewer when they are redeeming the reward
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub prompt: Option<String>,
     /// The cost of the reward
     pub cost: usize,
@@ -86,7 +88,7 @@ pub struct CreateCustomRewardBody {
     #[builder(default, setter(into))]
     pub is_enabled: Option<bool>,
     /// Custom background color for the reward. Format: Hex with # prefix. Example: #00E5CB.
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub background_color: Option<String>,
     /// Does the user need to enter information when redeeming the reward. Defaults false
     #[builder(default, setter(into))]
@@ -124,6 +126,7 @@ pub type CreateCustomRewardResponse = super::CustomReward;
 impl Request for CreateCustomRewardRequest {
     type Response = CreateCustomRewardResponse;
 
+    const METHOD: http::Method = http::Method::POST;
     const PATH: &'static str = "channel_points/custom_rewards";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] =
@@ -138,25 +141,29 @@ impl RequestPost for CreateCustomRewardRequest {
         uri: &http::Uri,
         response_str: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
         let response: helix::InnerResponse<Vec<Self::Response>> =
-            helix::parse_json(response_str, true).map_err(|e| {
-                helix::HelixRequestPostError::DeserializeError(
+            helix::parse_json_owned(response_str, true).map_err(|e| {
+                helix::HelixRequestError::DeserializeError(
                     response_str.to_string(),
                     e,
+                    Self::METHOD,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         let data = response.data.into_iter().next().ok_or_else(|| {
-            helix::HelixRequestPostError::InvalidResponse {
+            helix::HelixRequestError::InvalidResponse {
                 reason: "response included no data",
                 response: response_str.to_string(),
                 status,
+                method: Self::METHOD,
                 uri: uri.clone(),
+                request_id: None,
             }
         })?;
         Ok(helix::Response {
@@ -165,6 +172,8 @@ impl RequestPost for CreateCustomRewardRequest {
             request,
             total: response.total,
             other: None,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
@@ -185,48 +194,9 @@ fn test_request() {
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
     // From twitch docs
-    let data = br##"
-{
-    "data": [
-        {
-            "broadcaster_name": "torpedo09",
-            "broadcaster_login": "torpedo09",
-            "broadcaster_id": "274637212",
-            "id": "afaa7e34-6b17-49f0-a19a-d1e76eaaf673",
-            "image": null,
-            "background_color": "#00E5CB",
-            "is_enabled": true,
-            "cost": 50000,
-            "title": "game analysis 1v1",
-            "prompt": "",
-            "is_user_input_required": false,
-            "max_per_stream_setting": {
-                "is_enabled": false,
-                "max_per_stream": 0
-            },
-            "max_per_user_per_stream_setting": {
-                "is_enabled": false,
-                "max_per_user_per_stream": 0
-            },
-            "global_cooldown_setting": {
-                "is_enabled": false,
-                "global_cooldown_seconds": 0
-            },
-            "is_paused": false,
-            "is_in_stock": true,
-            "default_image": {
-                "url_1x": "https://static-cdn.jtvnw.net/custom-reward-images/default-1.png",
-                "url_2x": "https://static-cdn.jtvnw.net/custom-reward-images/default-2.png",
-                "url_4x": "https://static-cdn.jtvnw.net/custom-reward-images/default-4.png"
-            },
-            "should_redemptions_skip_request_queue": false,
-            "redemptions_redeemed_current_stream": null,
-            "cooldown_expires_at": null
-        }
-    ]
-}
-    "##
-    .to_vec();
+    let data = crate::fixtures::helix::points::CREATE_CUSTOM_REWARDS
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().status(200).body(data).unwrap();
     // This is marked as 204 in twitch docs, but in reality it's 200
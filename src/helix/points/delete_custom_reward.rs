@@ -46,6 +46,7 @@ use helix::RequestDelete;
 ///
 /// [`delete-custom-reward`](https://dev.twitch.tv/docs/api/reference#delete-custom-reward)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct DeleteCustomRewardRequest {
     /// Provided broadcaster_id must match the user_id in the auth token
@@ -60,6 +61,11 @@ pub struct DeleteCustomRewardRequest {
 ///
 /// [`delete-custom-reward`](https://dev.twitch.tv/docs/api/reference#delete-custom-reward)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub enum DeleteCustomReward {
@@ -70,6 +76,7 @@ pub enum DeleteCustomReward {
 impl Request for DeleteCustomRewardRequest {
     type Response = DeleteCustomReward;
 
+    const METHOD: http::Method = http::Method::DELETE;
     const PATH: &'static str = "channel_points/custom_rewards";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] =
@@ -82,7 +89,7 @@ impl RequestDelete for DeleteCustomRewardRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestDeleteError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
@@ -93,12 +100,16 @@ impl RequestDelete for DeleteCustomRewardRequest {
                 request,
                 total: None,
                 other: None,
+                request_id: None,
+                diagnostics: Default::default(),
             }),
-            _ => Err(helix::HelixRequestDeleteError::InvalidResponse {
+            _ => Err(helix::HelixRequestError::InvalidResponse {
                 reason: "unexpected status",
                 response: response.to_string(),
                 status,
+                method: Self::METHOD,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
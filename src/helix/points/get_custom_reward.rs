@@ -12,7 +12,7 @@
 //! ```rust
 //! use twitch_api2::helix::points::GetCustomRewardRequest;
 //! let request = GetCustomRewardRequest::builder()
-//!     .broadcaster_id("274637212".to_string())
+//!     .broadcaster_id("274637212")
 //!     .build();
 //! ```
 //!
@@ -30,7 +30,7 @@
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
 //! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
 //! let request = GetCustomRewardRequest::builder()
-//!     .broadcaster_id("274637212".to_string())
+//!     .broadcaster_id("274637212")
 //!     .build();
 //! let response: Vec<CustomReward> = client.req_get(request, &token).await?.data;
 //! # Ok(())
@@ -47,6 +47,7 @@ use helix::RequestGet;
 ///
 /// [`get-custom-reward`](https://dev.twitch.tv/docs/api/reference#get-custom-reward)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetCustomRewardRequest {
     /// Provided broadcaster_id must match the user_id in the auth token
@@ -64,6 +65,11 @@ pub struct GetCustomRewardRequest {
 ///
 /// [`get-custom-reward`](https://dev.twitch.tv/docs/api/reference#get-custom-reward-redemption)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct CustomReward {
@@ -109,9 +115,45 @@ pub struct CustomReward {
     pub cooldown_expires_at: Option<types::Timestamp>,
 }
 
+impl CustomReward {
+    /// Whether the reward is on cooldown at `now`.
+    pub fn is_in_cooldown(&self, now: &types::TimestampRef) -> bool {
+        self.cooldown_expires_at
+            .as_ref()
+            .map_or(false, |expires_at| now.is_before(expires_at))
+    }
+
+    /// How many more times the reward can be redeemed during the current stream, `None` if there is no [limit](CustomReward::max_per_stream_setting).
+    pub fn remaining_this_stream(&self) -> Option<usize> {
+        self.max_per_stream_setting.limit().map(|max| {
+            (max as usize).saturating_sub(self.redemptions_redeemed_current_stream.unwrap_or(0))
+        })
+    }
+
+    /// How many more times a user that has redeemed the reward `user_redemptions` times during the current stream can redeem it, `None` if there is no [limit](CustomReward::max_per_user_per_stream_setting).
+    pub fn remaining_per_user(&self, user_redemptions: usize) -> Option<usize> {
+        self.max_per_user_per_stream_setting
+            .limit()
+            .map(|max| (max as usize).saturating_sub(user_redemptions))
+    }
+
+    /// Whether a user that has redeemed the reward `user_redemptions` times during the current stream can redeem it at `now`.
+    ///
+    /// The reward has to be enabled, not paused, in stock, not on cooldown and below the stream and user limits.
+    pub fn can_redeem(&self, now: &types::TimestampRef, user_redemptions: usize) -> bool {
+        self.is_enabled
+            && !self.is_paused
+            && self.is_in_stock
+            && !self.is_in_cooldown(now)
+            && self.remaining_this_stream() != Some(0)
+            && self.remaining_per_user(user_redemptions) != Some(0)
+    }
+}
+
 impl Request for GetCustomRewardRequest {
     type Response = Vec<CustomReward>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "channel_points/custom_rewards";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] =
@@ -125,52 +167,13 @@ impl RequestGet for GetCustomRewardRequest {}
 fn test_request() {
     use helix::*;
     let req = GetCustomRewardRequest::builder()
-        .broadcaster_id("274637212".to_string())
+        .broadcaster_id("274637212")
         .build();
 
     // From twitch docs
-    let data = br##"
-{
-     "data": [
-         {
-             "broadcaster_name": "torpedo09",
-             "broadcaster_login": "torpedo09",
-             "broadcaster_id": "274637212",
-             "id": "92af127c-7326-4483-a52b-b0da0be61c01",
-             "image": null,
-             "background_color": "#00E5CB",
-             "is_enabled": true,
-             "cost": 50000,
-             "title": "game analysis",
-             "prompt": "",
-             "is_user_input_required": false,
-             "max_per_stream_setting": {
-                 "is_enabled": false,
-                 "max_per_stream": 0
-             },
-             "max_per_user_per_stream_setting": {
-                 "is_enabled": false,
-                 "max_per_user_per_stream": 0
-             },
-             "global_cooldown_setting": {
-                 "is_enabled": false,
-                 "global_cooldown_seconds": 0
-             },
-             "is_paused": false,
-             "is_in_stock": true,
-             "default_image": {
-                 "url_1x": "https://static-cdn.jtvnw.net/custom-reward-images/default-1.png",
-                 "url_2x": "https://static-cdn.jtvnw.net/custom-reward-images/default-2.png",
-                 "url_4x": "https://static-cdn.jtvnw.net/custom-reward-images/default-4.png"
-             },
-             "should_redemptions_skip_request_queue": false,
-             "redemptions_redeemed_current_stream": null,
-             "cooldown_expires_at": null
-         }
-     ]
-}
-"##
-    .to_vec();
+    let data = crate::fixtures::helix::points::GET_CUSTOM_REWARD
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -182,3 +185,58 @@ fn test_request() {
 
     dbg!(GetCustomRewardRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+#[test]
+fn test_availability() {
+    let mut reward: CustomReward = serde_json::from_str(
+        r##"
+{
+    "broadcaster_name": "torpedo09",
+    "broadcaster_login": "torpedo09",
+    "broadcaster_id": "274637212",
+    "id": "92af127c-7326-4483-a52b-b0da0be61c01",
+    "image": null,
+    "background_color": "#00E5CB",
+    "is_enabled": true,
+    "cost": 50000,
+    "title": "game analysis",
+    "prompt": "",
+    "is_user_input_required": false,
+    "max_per_stream_setting": {
+        "is_enabled": true,
+        "max_per_stream": 5
+    },
+    "max_per_user_per_stream_setting": {
+        "is_enabled": true,
+        "max_per_user_per_stream": 2
+    },
+    "global_cooldown_setting": {
+        "is_enabled": true,
+        "global_cooldown_seconds": 60
+    },
+    "is_paused": false,
+    "is_in_stock": true,
+    "default_image": null,
+    "should_redemptions_skip_request_queue": false,
+    "redemptions_redeemed_current_stream": 3,
+    "cooldown_expires_at": "2021-07-01T13:38:00Z"
+}
+"##,
+    )
+    .unwrap();
+
+    let during_cooldown = types::Timestamp::new("2021-07-01T13:37:30Z").unwrap();
+    let after_cooldown = types::Timestamp::new("2021-07-01T13:38:30Z").unwrap();
+    assert!(reward.is_in_cooldown(&during_cooldown));
+    assert!(!reward.is_in_cooldown(&after_cooldown));
+    assert_eq!(reward.remaining_this_stream(), Some(2));
+    assert_eq!(reward.remaining_per_user(1), Some(1));
+    assert_eq!(reward.remaining_per_user(3), Some(0));
+    assert!(!reward.can_redeem(&during_cooldown, 0));
+    assert!(reward.can_redeem(&after_cooldown, 1));
+    assert!(!reward.can_redeem(&after_cooldown, 2));
+
+    reward.redemptions_redeemed_current_stream = Some(5);
+    assert!(!reward.can_redeem(&after_cooldown, 0));
+}
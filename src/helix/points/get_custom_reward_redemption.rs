@@ -14,8 +14,8 @@
 //!     CustomRewardRedemptionStatus, GetCustomRewardRedemptionRequest,
 //! };
 //! let request = GetCustomRewardRedemptionRequest::builder()
-//!     .broadcaster_id("274637212".to_string())
-//!     .reward_id("92af127c-7326-4483-a52b-b0da0be61c01".to_string())
+//!     .broadcaster_id("274637212")
+//!     .reward_id("92af127c-7326-4483-a52b-b0da0be61c01")
 //!     .status(CustomRewardRedemptionStatus::Canceled)
 //!     .build();
 //! ```
@@ -34,8 +34,8 @@
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
 //! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
 //! let request = GetCustomRewardRedemptionRequest::builder()
-//!     .broadcaster_id("274637212".to_string())
-//!     .reward_id("92af127c-7326-4483-a52b-b0da0be61c01".to_string())
+//!     .broadcaster_id("274637212")
+//!     .reward_id("92af127c-7326-4483-a52b-b0da0be61c01")
 //!     .status(CustomRewardRedemptionStatus::Canceled)
 //!     .build();
 //! let response: Vec<CustomRewardRedemption> = client.req_get(request, &token).await?.data;
@@ -53,6 +53,7 @@ use helix::RequestGet;
 ///
 /// [`get-custom-reward-redemption`](https://dev.twitch.tv/docs/api/reference#get-custom-reward-redemption)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetCustomRewardRedemptionRequest {
     /// Provided broadcaster_id must match the user_id in the auth token
@@ -67,19 +68,23 @@ pub struct GetCustomRewardRedemptionRequest {
     #[builder(default, setter(into))]
     pub status: Option<CustomRewardRedemptionStatus>,
 
-    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. This applies only to queries without ID. If an ID is specified, it supersedes any cursor/offset combinations. The cursor value specified here is from the pagination response field of a prior query.
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 50. Default: 20.
+    ///
+    /// Backward pagination with [`before`](helix::PaginationParams::before) is not supported.
     #[builder(default)]
-    pub after: Option<helix::Cursor>,
-
-    /// Number of results to be returned when getting the paginated Custom Reward Redemption objects for a reward. Limit: 50. Default: 20.
-    #[builder(default, setter(into))]
-    pub first: Option<usize>,
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
 }
 
 /// Return Values for [Get Custom Reward Redemption](super::get_custom_reward_redemption)
 ///
 /// [`get-custom-reward-redemption`](https://dev.twitch.tv/docs/api/reference#get-custom-reward-redemption)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct CustomRewardRedemption {
@@ -119,6 +124,11 @@ pub struct CustomRewardRedemption {
 
 /// Information about the reward involved
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Reward {
@@ -138,16 +148,20 @@ pub struct Reward {
 impl Request for GetCustomRewardRedemptionRequest {
     type Response = Vec<CustomRewardRedemption>;
 
+    const MAX_PAGE_SIZE: u32 = 50;
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "channel_points/custom_rewards/redemptions";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] =
         &[twitch_oauth2::scopes::Scope::ChannelReadRedemptions];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for GetCustomRewardRedemptionRequest {}
 
 impl helix::Paginated for GetCustomRewardRedemptionRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
 }
 
 #[cfg(test)]
@@ -155,39 +169,14 @@ impl helix::Paginated for GetCustomRewardRedemptionRequest {
 fn test_request() {
     use helix::*;
     let req = GetCustomRewardRedemptionRequest::builder()
-        .broadcaster_id("274637212".to_string())
-        .reward_id("92af127c-7326-4483-a52b-b0da0be61c01".to_string())
+        .broadcaster_id("274637212")
+        .reward_id("92af127c-7326-4483-a52b-b0da0be61c01")
         .status(CustomRewardRedemptionStatus::Canceled)
         .build();
 
     // From twitch docs
-    let data = br##"
- {
-    "data": [
-          {
-            "broadcaster_name": "torpedo09",
-            "broadcaster_login": "torpedo09",
-            "broadcaster_id": "274637212",
-            "id": "17fa2df1-ad76-4804-bfa5-a40ef63efe63",
-            "user_login": "torpedo09",
-            "user_id": "274637212",
-            "user_name": "torpedo09",
-            "user_input": "",
-            "status": "CANCELED",
-            "redeemed_at": "2020-07-01T18:37:32Z",
-            "reward": {
-                "id": "92af127c-7326-4483-a52b-b0da0be61c01",
-                "title": "game analysis",
-                "prompt": "",
-                "cost": 50000
-                  }
-        }
-    ],
-    "pagination": {
-        "cursor":      "eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6Ik1UZG1ZVEprWmpFdFlXUTNOaTAwT0RBMExXSm1ZVFV0WVRRd1pXWTJNMlZtWlRZelgxOHlNREl3TFRBM0xUQXhWREU0T2pNM09qTXlMakl6TXpFeU56RTFOMW89In19"
-    }
-}
-"##
+    let data = crate::fixtures::helix::points::GET_CUSTOM_REWARD_REDEMPTION
+        .as_bytes()
         .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
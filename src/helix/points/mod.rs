@@ -12,8 +12,8 @@
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
 //! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
 //! let request = GetCustomRewardRedemptionRequest::builder()
-//!     .broadcaster_id("274637212".to_string())
-//!     .reward_id("92af127c-7326-4483-a52b-b0da0be61c01".to_string())
+//!     .broadcaster_id("274637212")
+//!     .reward_id("92af127c-7326-4483-a52b-b0da0be61c01")
 //!     .status(CustomRewardRedemptionStatus::Canceled)
 //!     .build();
 //!
@@ -36,6 +36,8 @@ pub mod get_custom_reward_redemption;
 pub mod update_custom_reward;
 pub mod update_redemption_status;
 
+#[doc(inline)]
+pub use crate::types::CustomRewardRedemptionStatus;
 #[doc(inline)]
 pub use create_custom_rewards::{
     CreateCustomRewardBody, CreateCustomRewardRequest, CreateCustomRewardResponse,
@@ -52,16 +54,3 @@ pub use update_custom_reward::{UpdateCustomRewardBody, UpdateCustomRewardRequest
 pub use update_redemption_status::{
     UpdateRedemptionStatusBody, UpdateRedemptionStatusInformation, UpdateRedemptionStatusRequest,
 };
-/// Custom reward redemption statuses: UNFULFILLED, FULFILLED or CANCELED
-#[derive(PartialEq, serde::Serialize, serde::Deserialize, Clone, Debug)]
-pub enum CustomRewardRedemptionStatus {
-    /// Unfulfilled reward - the user has claimed it but it is still pending.
-    #[serde(rename = "UNFULFILLED")]
-    Unfulfilled,
-    /// Fulfilled reward - the user has claimed it and the reward has been granted.
-    #[serde(rename = "FULFILLED")]
-    Fulfilled,
-    /// Cancelled reward - the reward has been cancelled before fulfillment, and any spent points have been refunded.
-    #[serde(rename = "CANCELED")]
-    Canceled,
-}
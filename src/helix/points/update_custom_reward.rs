@@ -25,7 +25,7 @@
 //! # use twitch_api2::helix::points::update_custom_reward;
 //! let body = update_custom_reward::UpdateCustomRewardBody::builder()
 //!     .cost(501)
-//!     .title("hydrate but differently now!".to_string())
+//!     .title("hydrate but differently now!")
 //!     .build();
 //! ```
 //!
@@ -49,7 +49,7 @@
 //!     .build();
 //! let body = update_custom_reward::UpdateCustomRewardBody::builder()
 //!     .cost(501)
-//!     .title("hydrate but differently now!".to_string())
+//!     .title("hydrate but differently now!")
 //!     .build();
 //! let response: update_custom_reward::UpdateCustomReward = client.req_patch(request, body, &token).await?.data;
 //! # Ok(())
@@ -59,7 +59,7 @@
 //! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPost::create_request)
 //! and parse the [`http::Response`] with [`UpdateCustomRewardRequest::parse_response(None, &request.get_uri(), response)`](UpdateCustomRewardRequest::parse_response)
 
-use crate::helix::{parse_json, HelixRequestPatchError};
+use crate::helix::{parse_json_owned, HelixRequestError};
 
 use super::*;
 use helix::RequestPatch;
@@ -67,6 +67,7 @@ use helix::RequestPatch;
 ///
 /// [`update-custom-reward`](https://dev.twitch.tv/docs/api/reference#update-custom-reward)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct UpdateCustomRewardRequest {
     /// Provided broadcaster_id must match the user_id in the auth token
@@ -81,19 +82,20 @@ pub struct UpdateCustomRewardRequest {
 ///
 /// [`update-custom-reward`](https://dev.twitch.tv/docs/api/reference#update-custom-reward)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct UpdateCustomRewardBody {
     /// The title of the reward
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub title: Option<String>,
     /// The prompt for the viewer when they are redeeming the reward
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub prompt: Option<String>,
     /// The cost of the reward
     #[builder(default, setter(into))]
     pub cost: Option<usize>,
     /// Custom background color for the reward. Format: Hex with # prefix. Example: #00E5CB.
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub background_color: Option<String>,
     /// Is the reward currently enabled, if false the reward won’t show up to viewers
     #[builder(default, setter(into))]
@@ -133,6 +135,11 @@ impl helix::private::SealedSerialize for UpdateCustomRewardBody {}
 ///
 /// [`update-custom-reward`](https://dev.twitch.tv/docs/api/reference#update-custom-reward)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub enum UpdateCustomReward {
@@ -143,6 +150,7 @@ pub enum UpdateCustomReward {
 impl Request for UpdateCustomRewardRequest {
     type Response = UpdateCustomReward;
 
+    const METHOD: http::Method = http::Method::PATCH;
     const PATH: &'static str = "channel_points/custom_rewards";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] =
@@ -157,36 +165,42 @@ impl RequestPatch for UpdateCustomRewardRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPatchError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
         let resp = match status {
             http::StatusCode::OK => {
-                let resp: helix::InnerResponse<Vec<CustomReward>> = parse_json(response, true)
-                    .map_err(|e| {
-                        HelixRequestPatchError::DeserializeError(
+                let resp: helix::InnerResponse<Vec<CustomReward>> =
+                    parse_json_owned(response, true).map_err(|e| {
+                        HelixRequestError::DeserializeError(
                             response.to_string(),
                             e,
+                            Self::METHOD,
                             uri.clone(),
                             status,
+                            None,
                         )
                     })?;
                 UpdateCustomReward::Success(resp.data.into_iter().next().ok_or(
-                    helix::HelixRequestPatchError::InvalidResponse {
+                    helix::HelixRequestError::InvalidResponse {
                         reason: "expected at least one element in data",
                         response: response.to_string(),
                         status,
+                        method: Self::METHOD,
                         uri: uri.clone(),
+                        request_id: None,
                     },
                 )?)
             }
             _ => {
-                return Err(helix::HelixRequestPatchError::InvalidResponse {
+                return Err(helix::HelixRequestError::InvalidResponse {
                     reason: "unexpected status code",
                     response: response.to_string(),
                     status,
+                    method: Self::METHOD,
                     uri: uri.clone(),
+                    request_id: None,
                 })
             }
         };
@@ -196,6 +210,8 @@ impl RequestPatch for UpdateCustomRewardRequest {
             request,
             total: None,
             other: None,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
@@ -214,48 +230,9 @@ fn test_request() {
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
     // From twitch docs
-    let data = br##"
-{
-    "data": [
-        {
-            "broadcaster_name": "torpedo09",
-            "broadcaster_login": "torpedo09",
-            "broadcaster_id": "274637212",
-            "id": "92af127c-7326-4483-a52b-b0da0be61c01",
-            "image": null,
-            "background_color": "#00E5CB",
-            "is_enabled": false,
-            "cost": 30000,
-            "title": "game analysis 2v2",
-            "prompt": "",
-            "is_user_input_required": false,
-            "max_per_stream_setting": {
-                "is_enabled": true,
-                "max_per_stream": 60
-            },
-            "max_per_user_per_stream_setting": {
-                "is_enabled": false,
-                "max_per_user_per_stream": 0
-            },
-            "global_cooldown_setting": {
-                "is_enabled": false,
-                "global_cooldown_seconds": 0
-            },
-            "is_paused": false,
-            "is_in_stock": false,
-            "default_image": {
-                "url_1x": "https://static-cdn.jtvnw.net/custom-reward-images/default-1.png",
-                "url_2x": "https://static-cdn.jtvnw.net/custom-reward-images/default-2.png",
-                "url_4x": "https://static-cdn.jtvnw.net/custom-reward-images/default-4.png"
-            },
-            "should_redemptions_skip_request_queue": true,
-            "redemptions_redeemed_current_stream": 60,
-            "cooldown_expires_at": null
-        }
-    ]
-}
-    "##
-    .to_vec();
+    let data = crate::fixtures::helix::points::UPDATE_CUSTOM_REWARD
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().status(200).body(data).unwrap();
     // This is marked as 204 in twitch docs, but in reality it's 200
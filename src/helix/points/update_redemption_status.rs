@@ -12,9 +12,9 @@
 //! ```rust
 //! use twitch_api2::helix::points::UpdateRedemptionStatusRequest;
 //! let request = UpdateRedemptionStatusRequest::builder()
-//!     .broadcaster_id("274637212".to_string())
-//!     .reward_id("92af127c-7326-4483-a52b-b0da0be61c01".to_string())
-//!     .id("17fa2df1-ad76-4804-bfa5-a40ef63efe63".to_string())
+//!     .broadcaster_id("274637212")
+//!     .reward_id("92af127c-7326-4483-a52b-b0da0be61c01")
+//!     .id("17fa2df1-ad76-4804-bfa5-a40ef63efe63")
 //!     .build();
 //! ```
 //!
@@ -47,9 +47,9 @@
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
 //! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
 //! let request = UpdateRedemptionStatusRequest::builder()
-//!     .broadcaster_id("274637212".to_string())
-//!     .reward_id("92af127c-7326-4483-a52b-b0da0be61c01".to_string())
-//!     .id("17fa2df1-ad76-4804-bfa5-a40ef63efe63".to_string())
+//!     .broadcaster_id("274637212")
+//!     .reward_id("92af127c-7326-4483-a52b-b0da0be61c01")
+//!     .id("17fa2df1-ad76-4804-bfa5-a40ef63efe63")
 //!     .build();
 //! let body = UpdateRedemptionStatusBody::builder()
 //!     .status(CustomRewardRedemptionStatus::Canceled)
@@ -63,7 +63,7 @@
 //! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPatch::create_request)
 //! and parse the [`http::Response`] with [`UpdateRedemptionStatusRequest::parse_response(None, &request.get_uri(), response)`](UpdateRedemptionStatusRequest::parse_response)
 
-use crate::helix::{parse_json, HelixRequestPatchError};
+use crate::helix::{parse_json_owned, HelixRequestError};
 
 pub use super::CustomRewardRedemption;
 use super::*;
@@ -73,6 +73,7 @@ use helix::RequestPatch;
 ///
 /// [`update-redemption-status`](https://dev.twitch.tv/docs/api/reference#update-redemption-status)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct UpdateRedemptionStatusRequest {
     /// Provided broadcaster_id must match the user_id in the auth token.
@@ -92,6 +93,7 @@ pub struct UpdateRedemptionStatusRequest {
 ///
 /// [`update-redemption-status`](https://dev.twitch.tv/docs/api/reference#update-redemption-status)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct UpdateRedemptionStatusBody {
     /// The new status to set redemptions to. Can be either FULFILLED or CANCELED. Updating to CANCELED will refund the user their points.
@@ -104,6 +106,11 @@ pub struct UpdateRedemptionStatusBody {
 ///
 /// [`update-redemption-status`](https://dev.twitch.tv/docs/api/reference#update-redemption-status)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[non_exhaustive]
 pub enum UpdateRedemptionStatusInformation {
     /// 200 - OK
@@ -113,6 +120,7 @@ pub enum UpdateRedemptionStatusInformation {
 impl Request for UpdateRedemptionStatusRequest {
     type Response = UpdateRedemptionStatusInformation;
 
+    const METHOD: http::Method = http::Method::PATCH;
     const PATH: &'static str = "channel_points/custom_rewards/redemptions";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] =
@@ -127,36 +135,42 @@ impl RequestPatch for UpdateRedemptionStatusRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPatchError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
         let resp = match status {
             http::StatusCode::OK => {
                 let resp: helix::InnerResponse<Vec<CustomRewardRedemption>> =
-                    parse_json(response, true).map_err(|e| {
-                        HelixRequestPatchError::DeserializeError(
+                    parse_json_owned(response, true).map_err(|e| {
+                        HelixRequestError::DeserializeError(
                             response.to_string(),
                             e,
+                            Self::METHOD,
                             uri.clone(),
                             status,
+                            None,
                         )
                     })?;
                 UpdateRedemptionStatusInformation::Success(resp.data.into_iter().next().ok_or(
-                    helix::HelixRequestPatchError::InvalidResponse {
+                    helix::HelixRequestError::InvalidResponse {
                         reason: "expected at least one element in data",
                         response: response.to_string(),
                         status,
+                        method: Self::METHOD,
                         uri: uri.clone(),
+                        request_id: None,
                     },
                 )?)
             }
             _ => {
-                return Err(helix::HelixRequestPatchError::InvalidResponse {
+                return Err(helix::HelixRequestError::InvalidResponse {
                     reason: "unexpected status code",
                     response: response.to_string(),
                     status,
+                    method: Self::METHOD,
                     uri: uri.clone(),
+                    request_id: None,
                 })
             }
         };
@@ -166,6 +180,8 @@ impl RequestPatch for UpdateRedemptionStatusRequest {
             request,
             total: None,
             other: None,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
@@ -177,9 +193,9 @@ impl helix::private::SealedSerialize for UpdateRedemptionStatusBody {}
 fn test_request() {
     use helix::*;
     let req = UpdateRedemptionStatusRequest::builder()
-        .broadcaster_id("274637212".to_string())
-        .reward_id("92af127c-7326-4483-a52b-b0da0be61c01".to_string())
-        .id("17fa2df1-ad76-4804-bfa5-a40ef63efe63".to_string())
+        .broadcaster_id("274637212")
+        .reward_id("92af127c-7326-4483-a52b-b0da0be61c01")
+        .id("17fa2df1-ad76-4804-bfa5-a40ef63efe63")
         .build();
 
     let body = UpdateRedemptionStatusBody::builder()
@@ -188,31 +204,9 @@ fn test_request() {
 
     dbg!(req.create_request(body, "abcd", "client").unwrap());
     // From twitch docs
-    let data = br##"
-{
-    "data": [
-        {
-            "broadcaster_name": "torpedo09",
-            "broadcaster_login": "torpedo09",
-            "broadcaster_id": "274637212",
-            "id": "17fa2df1-ad76-4804-bfa5-a40ef63efe63",
-            "user_id": "274637212",
-            "user_name": "torpedo09",
-            "user_login": "torpedo09",
-            "user_input": "",
-            "status": "CANCELED",
-            "redeemed_at": "2020-07-01T18:37:32Z",
-            "reward": {
-                "id": "92af127c-7326-4483-a52b-b0da0be61c01",
-                "title": "game analysis",
-                "prompt": "",
-                "cost": 50000
-            }
-        }
-    ]
-}
-"##
-    .to_vec();
+    let data = crate::fixtures::helix::points::UPDATE_REDEMPTION_STATUS
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -21,14 +21,12 @@
 //! let body = create_poll::CreatePollBody::builder()
 //!     .broadcaster_id("141981764")
 //!     .title("Heads or Tails?")
-//!     .choices(vec![
-//!         create_poll::NewPollChoice::new("Heads"),
-//!         create_poll::NewPollChoice::new("Tails"),
-//!     ])
+//!     .choices(create_poll::PollChoices::new(vec!["Heads", "Tails"])?)
 //!     .channel_points_voting_enabled(true)
 //!     .channel_points_per_vote(100)
 //!     .duration(1800)
 //!     .build();
+//! # Ok::<(), create_poll::PollChoicesError>(())
 //! ```
 //!
 //! ## Response: [CreatePollResponse]
@@ -50,7 +48,7 @@
 //! let body = create_poll::CreatePollBody::builder()
 //!     .broadcaster_id("141981764")
 //!     .title("Heads or Tails?")
-//!     .choices(vec![create_poll::NewPollChoice::new("Heads"), create_poll::NewPollChoice::new("Tails")])
+//!     .choices(create_poll::PollChoices::new(vec!["Heads", "Tails"])?)
 //!     .channel_points_voting_enabled(true)
 //!     .channel_points_per_vote(100)
 //!     .duration(1800)
@@ -69,6 +67,7 @@ use helix::RequestPost;
 ///
 /// [`create-poll`](https://dev.twitch.tv/docs/api/reference#create-poll)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct CreatePollRequest {}
 
@@ -81,6 +80,7 @@ impl CreatePollRequest {
 ///
 /// [`create-poll`](https://dev.twitch.tv/docs/api/reference#create-poll)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct CreatePollBody {
     /// The broadcaster running polls. Provided broadcaster_id must match the user_id in the user OAuth token.
@@ -92,7 +92,7 @@ pub struct CreatePollBody {
     /// Total duration for the poll (in seconds). Minimum: 15. Maximum: 1800.
     pub duration: i64,
     /// Array of the poll choices. Minimum: 2 choices. Maximum: 5 choices.
-    pub choices: Vec<NewPollChoice>,
+    pub choices: PollChoices,
     /// Indicates if Bits can be used for voting. Default: false
     #[builder(default, setter(into))]
     pub bits_voting_enabled: Option<bool>,
@@ -112,13 +112,22 @@ impl helix::private::SealedSerialize for CreatePollBody {}
 // FIXME: I'd prefer this to be a Vec<String> on CreatePollBody
 /// Choice settings for a poll
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[non_exhaustive]
 pub struct NewPollChoice {
     /// Text displayed for the choice. Maximum: 25 characters.
+    #[builder(setter(into))]
     pub title: String,
 }
 
 impl NewPollChoice {
+    /// Maximum amount of characters in the title of a choice
+    pub const MAX_TITLE_LENGTH: usize = 25;
+
     /// Create a new [`NewPollChoice`]
     pub fn new(title: impl Into<String>) -> Self {
         Self {
@@ -127,6 +136,119 @@ impl NewPollChoice {
     }
 }
 
+/// Choices of a new poll, between [`MIN`](PollChoices::MIN) and [`MAX`](PollChoices::MAX) choices with titles of at most [`MAX_TITLE_LENGTH`](NewPollChoice::MAX_TITLE_LENGTH) characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api2::helix::polls::create_poll::{PollChoices, PollChoicesError};
+///
+/// assert_eq!(PollChoices::new(vec!["Heads", "Tails"])?.len(), 2);
+/// assert!(PollChoices::new(vec!["Heads"]).is_err());
+/// assert!(PollChoices::new(vec!["1", "2", "3", "4", "5", "6"]).is_err());
+/// # Ok::<(), PollChoicesError>(())
+/// ```
+#[derive(PartialEq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[serde(try_from = "Vec<NewPollChoice>", into = "Vec<NewPollChoice>")]
+pub struct PollChoices(Vec<NewPollChoice>);
+
+impl PollChoices {
+    /// Largest amount of choices in a poll
+    pub const MAX: usize = 5;
+    /// Least amount of choices in a poll
+    pub const MIN: usize = 2;
+
+    /// Create the choices of a poll from their titles, checking them against Twitch's limits
+    pub fn new(
+        titles: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<Self, PollChoicesError> {
+        Self::from_choices(titles.into_iter().map(NewPollChoice::new).collect())
+    }
+
+    /// Create the choices of a poll, checking them against Twitch's limits
+    pub fn from_choices(choices: Vec<NewPollChoice>) -> Result<Self, PollChoicesError> {
+        if !(Self::MIN..=Self::MAX).contains(&choices.len()) {
+            return Err(PollChoicesError::Count {
+                count: choices.len(),
+                min: Self::MIN,
+                max: Self::MAX,
+            });
+        }
+        if let Some(choice) = choices
+            .iter()
+            .find(|c| c.title.chars().count() > NewPollChoice::MAX_TITLE_LENGTH)
+        {
+            return Err(PollChoicesError::TitleTooLong {
+                title: choice.title.clone(),
+                max: NewPollChoice::MAX_TITLE_LENGTH,
+            });
+        }
+        Ok(Self(choices))
+    }
+}
+
+impl std::ops::Deref for PollChoices {
+    type Target = [NewPollChoice];
+
+    fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+impl std::convert::TryFrom<Vec<NewPollChoice>> for PollChoices {
+    type Error = PollChoicesError;
+
+    fn try_from(choices: Vec<NewPollChoice>) -> Result<Self, Self::Error> {
+        PollChoices::from_choices(choices)
+    }
+}
+
+impl From<PollChoices> for Vec<NewPollChoice> {
+    fn from(choices: PollChoices) -> Self { choices.0 }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for PollChoices {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let count = u.int_in_range(Self::MIN..=Self::MAX)?;
+        let titles = (0..count)
+            .map(|_| {
+                <String as arbitrary::Arbitrary>::arbitrary(u).map(|title| {
+                    title
+                        .chars()
+                        .take(NewPollChoice::MAX_TITLE_LENGTH)
+                        .collect::<String>()
+                })
+            })
+            .collect::<arbitrary::Result<Vec<_>>>()?;
+        Self::new(titles).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// [`PollChoices`] are out of Twitch's limits
+#[derive(thiserror::Error, Debug, displaydoc::Display, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum PollChoicesError {
+    /// a poll must have between {min} and {max} choices, got {count}
+    Count {
+        /// The amount of choices
+        count: usize,
+        /// The least amount of choices allowed
+        min: usize,
+        /// The largest amount of choices allowed
+        max: usize,
+    },
+    /// choice title {title:?} is longer than {max} characters
+    TitleTooLong {
+        /// The title of the choice
+        title: String,
+        /// The largest amount of characters allowed
+        max: usize,
+    },
+}
+
 /// Return Values for [Create Poll](super::create_poll)
 ///
 /// [`create-poll`](https://dev.twitch.tv/docs/api/reference#create-poll)
@@ -135,6 +257,7 @@ pub type CreatePollResponse = super::Poll;
 impl Request for CreatePollRequest {
     type Response = CreatePollResponse;
 
+    const METHOD: http::Method = http::Method::POST;
     const PATH: &'static str = "polls";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelManagePolls];
@@ -148,25 +271,29 @@ impl RequestPost for CreatePollRequest {
         uri: &http::Uri,
         response_str: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
         let response: helix::InnerResponse<Vec<Self::Response>> =
-            helix::parse_json(response_str, true).map_err(|e| {
-                helix::HelixRequestPostError::DeserializeError(
+            helix::parse_json_owned(response_str, true).map_err(|e| {
+                helix::HelixRequestError::DeserializeError(
                     response_str.to_string(),
                     e,
+                    Self::METHOD,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         let data = response.data.into_iter().next().ok_or_else(|| {
-            helix::HelixRequestPostError::InvalidResponse {
+            helix::HelixRequestError::InvalidResponse {
                 reason: "response included no data",
                 response: response_str.to_string(),
                 status,
+                method: Self::METHOD,
                 uri: uri.clone(),
+                request_id: None,
             }
         })?;
         Ok(helix::Response {
@@ -175,6 +302,8 @@ impl RequestPost for CreatePollRequest {
             request,
             total: None,
             other: None,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
@@ -188,10 +317,7 @@ fn test_request() {
     let body = CreatePollBody::builder()
         .broadcaster_id("141981764")
         .title("Heads or Tails?")
-        .choices(vec![
-            NewPollChoice::new("Heads"),
-            NewPollChoice::new("Tails"),
-        ])
+        .choices(PollChoices::new(vec!["Heads", "Tails"]).unwrap())
         .channel_points_voting_enabled(true)
         .channel_points_per_vote(100)
         .duration(1800)
@@ -200,43 +326,9 @@ fn test_request() {
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
     // From twitch docs
-    let data = br##"
-{
-    "data": [
-        {
-        "id": "ed961efd-8a3f-4cf5-a9d0-e616c590cd2a",
-        "broadcaster_id": "141981764",
-        "broadcaster_name": "TwitchDev",
-        "broadcaster_login": "twitchdev",
-        "title": "Heads or Tails?",
-        "choices": [
-            {
-            "id": "4c123012-1351-4f33-84b7-43856e7a0f47",
-            "title": "Heads",
-            "votes": 0,
-            "channel_points_votes": 0,
-            "bits_votes": 0
-            },
-            {
-            "id": "279087e3-54a7-467e-bcd0-c1393fcea4f0",
-            "title": "Tails",
-            "votes": 0,
-            "channel_points_votes": 0,
-            "bits_votes": 0
-            }
-        ],
-        "bits_voting_enabled": false,
-        "bits_per_vote": 0,
-        "channel_points_voting_enabled": true,
-        "channel_points_per_vote": 100,
-        "status": "ACTIVE",
-        "duration": 1800,
-        "started_at": "2021-03-19T06:08:33.871278372Z"
-        }
-    ]
-}
-    "##
-    .to_vec();
+    let data = crate::fixtures::helix::polls::CREATE_POLL
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().status(200).body(data).unwrap();
     // This is marked as 204 in twitch docs, but in reality it's 200
@@ -54,7 +54,7 @@
 //! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPost::create_request)
 //! and parse the [`http::Response`] with [`EndPollRequest::parse_response(None, &request.get_uri(), response)`](EndPollRequest::parse_response)
 
-use crate::helix::{parse_json, HelixRequestPatchError};
+use crate::helix::{parse_json_owned, HelixRequestError};
 
 use super::*;
 use helix::RequestPatch;
@@ -63,6 +63,7 @@ pub use types::PollStatus;
 ///
 /// [`end-poll`](https://dev.twitch.tv/docs/api/reference#end-poll)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct EndPollRequest {}
 
@@ -75,6 +76,7 @@ impl EndPollRequest {
 ///
 /// [`end-poll`](https://dev.twitch.tv/docs/api/reference#end-poll)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct EndPollBody {
     /// The broadcaster running polls. Provided broadcaster_id must match the user_id in the user OAuth token.
@@ -97,6 +99,11 @@ impl helix::private::SealedSerialize for EndPollBody {}
 ///
 /// [`end-poll`](https://dev.twitch.tv/docs/api/reference#end-poll)
 #[derive(PartialEq, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 #[allow(clippy::large_enum_variant)]
@@ -112,6 +119,7 @@ pub enum EndPoll {
 impl Request for EndPollRequest {
     type Response = EndPoll;
 
+    const METHOD: http::Method = http::Method::PATCH;
     const PATH: &'static str = "polls";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelManagePolls];
@@ -125,38 +133,44 @@ impl RequestPatch for EndPollRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPatchError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
         let resp = match status {
             http::StatusCode::OK => {
-                let resp: helix::InnerResponse<Vec<Poll>> =
-                    parse_json(response, true).map_err(|e| {
-                        HelixRequestPatchError::DeserializeError(
+                let resp: helix::InnerResponse<Vec<Poll>> = parse_json_owned(response, true)
+                    .map_err(|e| {
+                        HelixRequestError::DeserializeError(
                             response.to_string(),
                             e,
+                            Self::METHOD,
                             uri.clone(),
                             status,
+                            None,
                         )
                     })?;
                 EndPoll::Success(resp.data.into_iter().next().ok_or(
-                    helix::HelixRequestPatchError::InvalidResponse {
+                    helix::HelixRequestError::InvalidResponse {
                         reason: "expected at least one element in data",
                         response: response.to_string(),
                         status,
+                        method: Self::METHOD,
                         uri: uri.clone(),
+                        request_id: None,
                     },
                 )?)
             }
             http::StatusCode::BAD_REQUEST => EndPoll::MissingQuery,
             http::StatusCode::UNAUTHORIZED => EndPoll::AuthFailed,
             _ => {
-                return Err(helix::HelixRequestPatchError::InvalidResponse {
+                return Err(helix::HelixRequestError::InvalidResponse {
                     reason: "unexpected status code",
                     response: response.to_string(),
                     status,
+                    method: Self::METHOD,
                     uri: uri.clone(),
+                    request_id: None,
                 })
             }
         };
@@ -166,6 +180,8 @@ impl RequestPatch for EndPollRequest {
             request,
             total: None,
             other: None,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
@@ -185,44 +201,7 @@ fn test_request() {
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
     // From twitch docs
-    let data = br##"
-{
-    "data": [
-        {
-        "id": "ed961efd-8a3f-4cf5-a9d0-e616c590cd2a",
-        "broadcaster_id": "141981764",
-        "broadcaster_name": "TwitchDev",
-        "broadcaster_login": "twitchdev",
-        "title": "Heads or Tails?",
-        "choices": [
-            {
-            "id": "4c123012-1351-4f33-84b7-43856e7a0f47",
-            "title": "Heads",
-            "votes": 0,
-            "channel_points_votes": 0,
-            "bits_votes": 0
-            },
-            {
-            "id": "279087e3-54a7-467e-bcd0-c1393fcea4f0",
-            "title": "Tails",
-            "votes": 0,
-            "channel_points_votes": 0,
-            "bits_votes": 0
-            }
-        ],
-        "bits_voting_enabled": false,
-        "bits_per_vote": 0,
-        "channel_points_voting_enabled": true,
-        "channel_points_per_vote": 100,
-        "status": "TERMINATED",
-        "duration": 1800,
-        "started_at": "2021-03-19T06:08:33.871278372Z",
-        "ended_at": "2021-03-19T06:11:26.746889614Z"
-        }
-    ]
-}
-    "##
-    .to_vec();
+    let data = crate::fixtures::helix::polls::END_POLL.as_bytes().to_vec();
 
     let http_response = http::Response::builder().status(200).body(data).unwrap();
 
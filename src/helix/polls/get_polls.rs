@@ -45,26 +45,34 @@ pub use types::{PollChoice, PollStatus};
 ///
 /// [`get-polls`](https://dev.twitch.tv/docs/api/reference#get-polls)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetPollsRequest {
     /// The broadcaster running polls. Provided broadcaster_id must match the user_id in the user OAuth token.
     #[builder(setter(into))]
     pub broadcaster_id: types::UserId,
     /// ID of a poll. Filters results to one or more specific polls. Not providing one or more IDs will return the full list of polls for the authenticated channel.
+    ///
+    /// Maximum: 20
     #[builder(default, setter(into))]
     pub id: Vec<types::PollId>,
-    /// Cursor for forward pagination
-    #[builder(default, setter(into))]
-    pub after: Option<helix::Cursor>,
-    /// Maximum number of objects to return. Maximum: 20. Default: 20.
-    #[builder(default, setter(into))]
-    pub first: Option<usize>,
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 20. Default: 20.
+    ///
+    /// Backward pagination with [`before`](helix::PaginationParams::before) is not supported.
+    #[builder(default)]
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
 }
 
 /// Return Values for [Get polls](super::get_polls)
 ///
 /// [`get-polls`](https://dev.twitch.tv/docs/api/reference#get-polls)
 #[derive(PartialEq, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Poll {
@@ -79,6 +87,7 @@ pub struct Poll {
     /// Question displayed for the poll.
     pub title: String,
     /// Array of the poll choices.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub choices: Vec<PollChoice>,
     /// Indicates if Bits can be used for voting.
     pub bits_voting_enabled: bool,
@@ -101,15 +110,19 @@ pub struct Poll {
 impl Request for GetPollsRequest {
     type Response = Vec<Poll>;
 
+    const MAX_PAGE_SIZE: u32 = 20;
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "polls";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelReadPolls];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for GetPollsRequest {}
 
 impl helix::Paginated for GetPollsRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor; }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor; }
 }
 
 #[cfg(test)]
@@ -122,44 +135,7 @@ fn test_request() {
         .build();
 
     // From twitch docs
-    let data = br#"
-{
-    "data": [
-        {
-        "id": "ed961efd-8a3f-4cf5-a9d0-e616c590cd2a",
-        "broadcaster_id": "55696719",
-        "broadcaster_name": "TwitchDev",
-        "broadcaster_login": "twitchdev",
-        "title": "Heads or Tails?",
-        "choices": [
-            {
-            "id": "4c123012-1351-4f33-84b7-43856e7a0f47",
-            "title": "Heads",
-            "votes": 0,
-            "channel_points_votes": 0,
-            "bits_votes": 0
-            },
-            {
-            "id": "279087e3-54a7-467e-bcd0-c1393fcea4f0",
-            "title": "Tails",
-            "votes": 0,
-            "channel_points_votes": 0,
-            "bits_votes": 0
-            }
-        ],
-        "bits_voting_enabled": false,
-        "bits_per_vote": 0,
-        "channel_points_voting_enabled": false,
-        "channel_points_per_vote": 0,
-        "status": "ACTIVE",
-        "duration": 1800,
-        "started_at": "2021-03-19T06:08:33.871278372Z"
-        }
-    ],
-    "pagination": {}
-    }
-"#
-    .to_vec();
+    let data = crate::fixtures::helix::polls::GET_POLLS.as_bytes().to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -171,3 +147,31 @@ fn test_request() {
 
     dbg!(GetPollsRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+#[test]
+fn test_page_size() {
+    use helix::*;
+    let req = GetPollsRequest::builder()
+        .broadcaster_id("141981764")
+        .pagination(PaginationParams::default().with_first(PageSize::new(20).unwrap()))
+        .build();
+    assert_eq!(
+        req.get_uri().unwrap().to_string(),
+        "https://api.twitch.tv/helix/polls?broadcaster_id=141981764&first=20"
+    );
+
+    let req = GetPollsRequest::builder()
+        .broadcaster_id("141981764")
+        .pagination(PaginationParams::default().with_first(PageSize::new(21).unwrap()))
+        .build();
+    let error = req.get_uri().unwrap_err();
+    assert!(matches!(
+        error,
+        InvalidUri::PageSize(PageSizeError::TooLarge { size: 21, max: 20 })
+    ));
+    assert_eq!(
+        error.to_string(),
+        "page size 21 is larger than the maximum of 20"
+    );
+}
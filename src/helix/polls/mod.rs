@@ -11,7 +11,7 @@ pub mod end_poll;
 pub mod get_polls;
 
 #[doc(inline)]
-pub use create_poll::{CreatePollBody, CreatePollRequest, NewPollChoice};
+pub use create_poll::{CreatePollBody, CreatePollRequest, NewPollChoice, PollChoices};
 #[doc(inline)]
 pub use end_poll::{EndPollBody, EndPollRequest};
 #[doc(inline)]
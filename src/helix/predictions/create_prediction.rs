@@ -23,12 +23,13 @@
 //! let body = create_prediction::CreatePredictionBody::builder()
 //!     .broadcaster_id("141981764")
 //!     .title("Any leeks in the stream?")
-//!     .outcomes(create_prediction::NewPredictionOutcome::new_tuple(
+//!     .outcomes(create_prediction::PredictionOutcomes::two(
 //!         "Yes, give it time.",
 //!         "Definitely not.",
-//!     ))
+//!     )?)
 //!     .prediction_window(120)
 //!     .build();
+//! # Ok::<(), create_prediction::PredictionOutcomesError>(())
 //! ```
 //!
 //! ## Response: [CreatePredictionResponse]
@@ -50,7 +51,7 @@
 //! let body = create_prediction::CreatePredictionBody::builder()
 //!     .broadcaster_id("141981764")
 //!     .title("Any leeks in the stream?")
-//!     .outcomes(create_prediction::NewPredictionOutcome::new_tuple("Yes, give it time.", "Definitely not."))
+//!     .outcomes(create_prediction::PredictionOutcomes::two("Yes, give it time.", "Definitely not.")?)
 //!     .prediction_window(120)
 //!     .build();
 //! let response: create_prediction::CreatePredictionResponse = client.req_post(request, body, &token).await?.data;
@@ -68,6 +69,7 @@ use helix::RequestPost;
 ///
 /// [`create-prediction`](https://dev.twitch.tv/docs/api/reference#create-prediction)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct CreatePredictionRequest {}
 
@@ -80,6 +82,7 @@ impl CreatePredictionRequest {
 ///
 /// [`create-prediction`](https://dev.twitch.tv/docs/api/reference#create-prediction)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct CreatePredictionBody {
     /// The broadcaster running Predictions. Provided broadcaster_id must match the user_id in the user OAuth token.
@@ -88,36 +91,175 @@ pub struct CreatePredictionBody {
     /// Title for the Prediction. Maximum: 45 characters.
     #[builder(setter(into))]
     pub title: String,
-    /// Array of outcome objects with titles for the Prediction. Array size must be 2.
-    pub outcomes: (NewPredictionOutcome, NewPredictionOutcome),
+    /// Array of outcome objects with titles for the Prediction. Minimum: 2 outcomes. Maximum: 10 outcomes.
+    pub outcomes: PredictionOutcomes,
     /// Total duration for the Prediction (in seconds). Minimum: 1. Maximum: 1800.
     pub prediction_window: i64,
 }
 
 impl helix::private::SealedSerialize for CreatePredictionBody {}
 
-/// Choice settings for a poll
+/// Outcome settings for a prediction
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[non_exhaustive]
 pub struct NewPredictionOutcome {
-    /// Text displayed for the choice. Maximum: 25 characters.
+    /// Text displayed for the outcome. Maximum: 25 characters.
+    #[builder(setter(into))]
     pub title: String,
 }
 
 impl NewPredictionOutcome {
+    /// Maximum amount of characters in the title of an outcome
+    pub const MAX_TITLE_LENGTH: usize = 25;
+
     /// Create a new [`NewPredictionOutcome`]
     pub fn new(title: impl Into<String>) -> Self {
         Self {
             title: title.into(),
         }
     }
+}
+
+/// Outcomes of a new prediction, between [`MIN`](PredictionOutcomes::MIN) and [`MAX`](PredictionOutcomes::MAX) outcomes with titles of at most [`MAX_TITLE_LENGTH`](NewPredictionOutcome::MAX_TITLE_LENGTH) characters.
+///
+/// The first outcome is [blue](types::PredictionOutcomeColor::Blue). In a prediction with two outcomes, the second outcome is [pink](types::PredictionOutcomeColor::Pink)
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api2::helix::predictions::create_prediction::{
+///     PredictionOutcomes, PredictionOutcomesError,
+/// };
+///
+/// assert_eq!(PredictionOutcomes::two("Yes", "No")?.len(), 2);
+/// assert_eq!(
+///     PredictionOutcomes::up_to_ten(vec!["1st", "2nd", "3rd"])?.len(),
+///     3
+/// );
+/// assert!(PredictionOutcomes::up_to_ten(vec!["Only one"]).is_err());
+/// assert!(PredictionOutcomes::two("This outcome is way too long to fit", "No").is_err());
+/// # Ok::<(), PredictionOutcomesError>(())
+/// ```
+#[derive(PartialEq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[serde(
+    try_from = "Vec<NewPredictionOutcome>",
+    into = "Vec<NewPredictionOutcome>"
+)]
+pub struct PredictionOutcomes(Vec<NewPredictionOutcome>);
+
+impl PredictionOutcomes {
+    /// Largest amount of outcomes in a prediction
+    pub const MAX: usize = 10;
+    /// Least amount of outcomes in a prediction
+    pub const MIN: usize = 2;
+
+    /// Create the outcomes of a prediction with two outcomes
+    pub fn two(
+        blue: impl Into<String>,
+        pink: impl Into<String>,
+    ) -> Result<Self, PredictionOutcomesError> {
+        Self::new(vec![
+            NewPredictionOutcome::new(blue),
+            NewPredictionOutcome::new(pink),
+        ])
+    }
 
-    /// Create a two new [`NewPredictionOutcome`]s
-    pub fn new_tuple(blue: impl Into<String>, pink: impl Into<String>) -> (Self, Self) {
-        (Self::new(blue), Self::new(pink))
+    /// Create the outcomes of a prediction with two to ten outcomes
+    pub fn up_to_ten(
+        titles: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<Self, PredictionOutcomesError> {
+        Self::new(titles.into_iter().map(NewPredictionOutcome::new).collect())
+    }
+
+    /// Create the outcomes of a prediction, checking them against Twitch's limits
+    pub fn new(outcomes: Vec<NewPredictionOutcome>) -> Result<Self, PredictionOutcomesError> {
+        if !(Self::MIN..=Self::MAX).contains(&outcomes.len()) {
+            return Err(PredictionOutcomesError::Count {
+                count: outcomes.len(),
+                min: Self::MIN,
+                max: Self::MAX,
+            });
+        }
+        if let Some(outcome) = outcomes
+            .iter()
+            .find(|o| o.title.chars().count() > NewPredictionOutcome::MAX_TITLE_LENGTH)
+        {
+            return Err(PredictionOutcomesError::TitleTooLong {
+                title: outcome.title.clone(),
+                max: NewPredictionOutcome::MAX_TITLE_LENGTH,
+            });
+        }
+        Ok(Self(outcomes))
     }
 }
 
+impl std::ops::Deref for PredictionOutcomes {
+    type Target = [NewPredictionOutcome];
+
+    fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+impl std::convert::TryFrom<Vec<NewPredictionOutcome>> for PredictionOutcomes {
+    type Error = PredictionOutcomesError;
+
+    fn try_from(outcomes: Vec<NewPredictionOutcome>) -> Result<Self, Self::Error> {
+        PredictionOutcomes::new(outcomes)
+    }
+}
+
+impl From<PredictionOutcomes> for Vec<NewPredictionOutcome> {
+    fn from(outcomes: PredictionOutcomes) -> Self { outcomes.0 }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for PredictionOutcomes {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let count = u.int_in_range(Self::MIN..=Self::MAX)?;
+        let titles = (0..count)
+            .map(|_| {
+                <String as arbitrary::Arbitrary>::arbitrary(u).map(|title| {
+                    title
+                        .chars()
+                        .take(NewPredictionOutcome::MAX_TITLE_LENGTH)
+                        .collect::<String>()
+                })
+            })
+            .collect::<arbitrary::Result<Vec<_>>>()?;
+        Self::up_to_ten(titles).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// [`PredictionOutcomes`] are out of Twitch's limits
+#[derive(thiserror::Error, Debug, displaydoc::Display, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum PredictionOutcomesError {
+    /// a prediction must have between {min} and {max} outcomes, got {count}
+    Count {
+        /// The amount of outcomes
+        count: usize,
+        /// The least amount of outcomes allowed
+        min: usize,
+        /// The largest amount of outcomes allowed
+        max: usize,
+    },
+    /// outcome title {title:?} is longer than {max} characters
+    TitleTooLong {
+        /// The title of the outcome
+        title: String,
+        /// The largest amount of characters allowed
+        max: usize,
+    },
+}
+
 /// Return Values for [Create Prediction](super::create_prediction)
 ///
 /// [`create-prediction`](https://dev.twitch.tv/docs/api/reference#create-prediction)
@@ -126,6 +268,7 @@ pub type CreatePredictionResponse = super::Prediction;
 impl Request for CreatePredictionRequest {
     type Response = CreatePredictionResponse;
 
+    const METHOD: http::Method = http::Method::POST;
     const PATH: &'static str = "predictions";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] =
@@ -140,25 +283,29 @@ impl RequestPost for CreatePredictionRequest {
         uri: &http::Uri,
         response_str: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
         let response: helix::InnerResponse<Vec<Self::Response>> =
-            helix::parse_json(response_str, true).map_err(|e| {
-                helix::HelixRequestPostError::DeserializeError(
+            helix::parse_json_owned(response_str, true).map_err(|e| {
+                helix::HelixRequestError::DeserializeError(
                     response_str.to_string(),
                     e,
+                    Self::METHOD,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         let data = response.data.into_iter().next().ok_or_else(|| {
-            helix::HelixRequestPostError::InvalidResponse {
+            helix::HelixRequestError::InvalidResponse {
                 reason: "response included no data",
                 response: response_str.to_string(),
                 status,
+                method: Self::METHOD,
                 uri: uri.clone(),
+                request_id: None,
             }
         })?;
         Ok(helix::Response {
@@ -167,6 +314,8 @@ impl RequestPost for CreatePredictionRequest {
             request,
             total: None,
             other: None,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
@@ -180,54 +329,16 @@ fn test_request() {
     let body = CreatePredictionBody::builder()
         .broadcaster_id("141981764")
         .title("Any leeks in the stream?")
-        .outcomes(NewPredictionOutcome::new_tuple(
-            "Yes, give it time.",
-            "Definitely not.",
-        ))
+        .outcomes(PredictionOutcomes::two("Yes, give it time.", "Definitely not.").unwrap())
         .prediction_window(120)
         .build();
 
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
     // From twitch docs
-    let data = br##"
-{
-    "data": [
-        {
-        "id": "bc637af0-7766-4525-9308-4112f4cbf178",
-        "broadcaster_id": "141981764",
-        "broadcaster_name": "TwitchDev",
-        "broadcaster_login": "twitchdev",
-        "title": "Any leeks in the stream?",
-        "winning_outcome_id": null,
-        "outcomes": [
-            {
-            "id": "73085848-a94d-4040-9d21-2cb7a89374b7",
-            "title": "Yes, give it time.",
-            "users": 0,
-            "channel_points": 0,
-            "top_predictors": null,
-            "color": "BLUE"
-            },
-            {
-            "id": "906b70ba-1f12-47ea-9e95-e5f93d20e9cc",
-            "title": "Definitely not.",
-            "users": 0,
-            "channel_points": 0,
-            "top_predictors": null,
-            "color": "PINK"
-            }
-        ],
-        "prediction_window": 120,
-        "status": "ACTIVE",
-        "created_at": "2021-04-28T17:11:22.595914172Z",
-        "ended_at": null,
-        "locked_at": null
-        }
-    ]
-}
-    "##
-    .to_vec();
+    let data = crate::fixtures::helix::predictions::CREATE_PREDICTION
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().status(200).body(data).unwrap();
     // This is marked as 204 in twitch docs, but in reality it's 200
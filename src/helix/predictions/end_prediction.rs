@@ -54,7 +54,7 @@
 //! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPost::create_request)
 //! and parse the [`http::Response`] with [`EndPredictionRequest::parse_response(None, &request.get_uri(), response)`](EndPredictionRequest::parse_response)
 
-use crate::helix::{parse_json, HelixRequestPatchError};
+use crate::helix::{parse_json_owned, HelixRequestError};
 
 use super::*;
 use helix::RequestPatch;
@@ -63,6 +63,7 @@ pub use types::{PredictionId, PredictionStatus};
 ///
 /// [`end-prediction`](https://dev.twitch.tv/docs/api/reference#end-prediction)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct EndPredictionRequest {}
 
@@ -75,6 +76,7 @@ impl EndPredictionRequest {
 ///
 /// [`end-prediction`](https://dev.twitch.tv/docs/api/reference#end-prediction)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct EndPredictionBody {
     /// The broadcaster running predictions. Provided broadcaster_id must match the user_id in the user OAuth token.
@@ -100,6 +102,11 @@ impl helix::private::SealedSerialize for EndPredictionBody {}
 ///
 /// [`end-prediction`](https://dev.twitch.tv/docs/api/reference#end-prediction)
 #[derive(PartialEq, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 #[allow(clippy::large_enum_variant)]
@@ -115,6 +122,7 @@ pub enum EndPrediction {
 impl Request for EndPredictionRequest {
     type Response = EndPrediction;
 
+    const METHOD: http::Method = http::Method::PATCH;
     const PATH: &'static str = "predictions";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] =
@@ -129,38 +137,44 @@ impl RequestPatch for EndPredictionRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPatchError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
         let resp = match status {
             http::StatusCode::OK => {
-                let resp: helix::InnerResponse<Vec<Prediction>> = parse_json(response, true)
+                let resp: helix::InnerResponse<Vec<Prediction>> = parse_json_owned(response, true)
                     .map_err(|e| {
-                        HelixRequestPatchError::DeserializeError(
+                        HelixRequestError::DeserializeError(
                             response.to_string(),
                             e,
+                            Self::METHOD,
                             uri.clone(),
                             status,
+                            None,
                         )
                     })?;
                 EndPrediction::Success(resp.data.into_iter().next().ok_or(
-                    helix::HelixRequestPatchError::InvalidResponse {
+                    helix::HelixRequestError::InvalidResponse {
                         reason: "expected at least one element in data",
                         response: response.to_string(),
                         status,
+                        method: Self::METHOD,
                         uri: uri.clone(),
+                        request_id: None,
                     },
                 )?)
             }
             http::StatusCode::BAD_REQUEST => EndPrediction::MissingQuery,
             http::StatusCode::UNAUTHORIZED => EndPrediction::AuthFailed,
             _ => {
-                return Err(helix::HelixRequestPatchError::InvalidResponse {
+                return Err(helix::HelixRequestError::InvalidResponse {
                     reason: "unexpected status code",
                     response: response.to_string(),
                     status,
+                    method: Self::METHOD,
                     uri: uri.clone(),
+                    request_id: None,
                 })
             }
         };
@@ -170,6 +184,8 @@ impl RequestPatch for EndPredictionRequest {
             request,
             total: None,
             other: None,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
@@ -189,44 +205,9 @@ fn test_request() {
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
     // From twitch docs
-    let data = br##"
-{
-    "data": [
-        {
-        "id": "bc637af0-7766-4525-9308-4112f4cbf178",
-        "broadcaster_id": "141981764",
-        "broadcaster_name": "TwitchDev",
-        "broadcaster_login": "twitchdev",
-        "title": "Will we win all the games?",
-        "winning_outcome_id": "73085848-a94d-4040-9d21-2cb7a89374b7",
-        "outcomes": [
-            {
-            "id": "73085848-a94d-4040-9d21-2cb7a89374b7",
-            "title": "yes",
-            "users": 0,
-            "channel_points": 0,
-            "top_predictors": null,
-            "color": "BLUE"
-            },
-            {
-            "id": "86010b2e-9764-4136-9359-fd1c9c5a8033",
-            "title": "no",
-            "users": 0,
-            "channel_points": 0,
-            "top_predictors": null,
-            "color": "PINK"
-            }
-        ],
-        "prediction_window": 120,
-        "status": "RESOLVED",
-        "created_at": "2021-04-28T21:48:19.480371331Z",
-        "ended_at": "2021-04-28T21:54:24.026833954Z",
-        "locked_at": "2021-04-28T21:48:34.636685705Z"
-        }
-    ]
-}
-    "##
-    .to_vec();
+    let data = crate::fixtures::helix::predictions::END_PREDICTION
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().status(200).body(data).unwrap();
 
@@ -39,12 +39,13 @@
 
 use super::*;
 use helix::RequestGet;
-pub use types::{PredictionOutcome, PredictionOutcomeId, PredictionStatus};
+pub use types::{PredictionOutcome, PredictionOutcomeColor, PredictionOutcomeId, PredictionStatus};
 
 /// Query Parameters for [Get predictions](super::get_predictions)
 ///
 /// [`get-predictions`](https://dev.twitch.tv/docs/api/reference#get-predictions)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetPredictionsRequest {
     /// The broadcaster running Predictions. Provided broadcaster_id must match the user_id in the user OAuth token.
@@ -56,18 +57,23 @@ pub struct GetPredictionsRequest {
     /// Maximum: 100
     #[builder(default, setter(into))]
     pub id: Vec<types::PredictionId>,
-    /// Cursor for forward pagination
-    #[builder(default, setter(into))]
-    pub after: Option<helix::Cursor>,
-    /// Maximum number of objects to return. Maximum: 20. Default: 20.
-    #[builder(default, setter(into))]
-    pub first: Option<usize>,
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 20. Default: 20.
+    ///
+    /// Backward pagination with [`before`](helix::PaginationParams::before) is not supported.
+    #[builder(default)]
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
 }
 
 /// Return Values for [Get predictions](super::get_predictions)
 ///
 /// [`get-predictions`](https://dev.twitch.tv/docs/api/reference#get-predictions)
 #[derive(PartialEq, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Prediction {
@@ -84,6 +90,7 @@ pub struct Prediction {
     /// ID of the winning outcome. If the status is ACTIVE, this is set to null.
     pub winning_outcome_id: Option<PredictionOutcomeId>,
     /// Array of possible outcomes for the Prediction.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub outcomes: Vec<PredictionOutcome>,
     /// Total duration for the Prediction (in seconds).
     pub prediction_window: i64,
@@ -100,15 +107,19 @@ pub struct Prediction {
 impl Request for GetPredictionsRequest {
     type Response = Vec<Prediction>;
 
+    const MAX_PAGE_SIZE: u32 = 20;
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "predictions";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelReadPredictions];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for GetPredictionsRequest {}
 
 impl helix::Paginated for GetPredictionsRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor; }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor; }
 }
 
 #[cfg(test)]
@@ -121,45 +132,9 @@ fn test_request() {
         .build();
 
     // From twitch docs
-    let data = br#"
-{
-    "data": [
-        {
-        "id": "d6676d5c-c86e-44d2-bfc4-100fb48f0656",
-        "broadcaster_id": "55696719",
-        "broadcaster_name": "TwitchDev",
-        "broadcaster_login": "twitchdev",
-        "title": "Will there be any leaks today?",
-        "winning_outcome_id": null,
-        "outcomes": [
-            {
-            "id": "021e9234-5893-49b4-982e-cfe9a0aaddd9",
-            "title": "Yes",
-            "users": 0,
-            "channel_points": 0,
-            "top_predictors": null,
-            "color": "BLUE"
-            },
-            {
-            "id": "ded84c26-13cb-4b48-8cb5-5bae3ec3a66e",
-            "title": "No",
-            "users": 0,
-            "channel_points": 0,
-            "top_predictors": null,
-            "color": "PINK"
-            }
-        ],
-        "prediction_window": 600,
-        "status": "ACTIVE",
-        "created_at": "2021-04-28T16:03:06.320848689Z",
-        "ended_at": null,
-        "locked_at": null
-        }
-    ],
-    "pagination": {}
-    }
-"#
-    .to_vec();
+    let data = crate::fixtures::helix::predictions::GET_PREDICTIONS
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -0,0 +1,262 @@
+//! Request bodies serialized once and reused with different values.
+//!
+//! Sending the same kind of body many times, e.g. checking messages with [Check AutoMod Status](super::moderation::check_automod_status) in a tight loop,
+//! serializes the whole body for every request. A [`PreparedBody`] is serialized once, with placeholders where the values change.
+//! A placeholder is a string field set to `{name}`, where `name` consists of ASCII letters, digits and `_`.
+//! [Rendering](PreparedBody::render) the body only substitutes the placeholders.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! use twitch_api2::helix::{self, moderation::check_automod_status, prepared::PreparedBody};
+//!
+//! let body = PreparedBody::new(&vec![check_automod_status::CheckAutoModStatusBody::builder()
+//!     .msg_id("{msg_id}")
+//!     .msg_text("{text}")
+//!     .user_id("1234")
+//!     .build()])?;
+//!
+//! for (i, text) in ["hello", "world"].iter().enumerate() {
+//!     let req = check_automod_status::CheckAutoModStatusRequest::builder()
+//!         .broadcaster_id("1234")
+//!         .build();
+//!     let msg_id = i.to_string();
+//!     let values = [("msg_id", msg_id.as_str()), ("text", *text)];
+//!     let response = client.req_post_prepared(req, &body, &values, &token).await?;
+//!     println!("{:?}", response.data);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{AuthStyle, BodyError, CreateRequestError, HelixRequestBody, RequestPost};
+
+/// A body for `B` serialized once, with placeholders substituted on every [`render`](PreparedBody::render), see the [module documentation](self)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreparedBody<B> {
+    segments: Vec<Segment>,
+    _body: std::marker::PhantomData<fn() -> B>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Segment {
+    Bytes(Vec<u8>),
+    Placeholder(String),
+}
+
+impl<B: HelixRequestBody> PreparedBody<B> {
+    /// Serialize `body`, with string fields set to `{name}` as placeholders
+    pub fn new(body: &B) -> Result<Self, BodyError> {
+        let bytes = body.try_to_body()?;
+        let mut segments = vec![];
+        let mut start = 0;
+        let mut i = 0;
+        // Only an opening quote can start a placeholder, quotes escaped inside a string can't
+        let mut in_string = false;
+        while i < bytes.len() {
+            if in_string {
+                match bytes[i] {
+                    b'\\' => i += 2,
+                    b'"' => {
+                        in_string = false;
+                        i += 1;
+                    }
+                    _ => i += 1,
+                }
+            } else if let Some(len) = placeholder_len(&bytes[i..]) {
+                if start < i {
+                    segments.push(Segment::Bytes(bytes[start..i].to_vec()));
+                }
+                let name = std::str::from_utf8(&bytes[i + 2..i + len - 2])
+                    .expect("placeholder names are ascii");
+                segments.push(Segment::Placeholder(name.to_owned()));
+                i += len;
+                start = i;
+            } else {
+                in_string = bytes[i] == b'"';
+                i += 1;
+            }
+        }
+        if start < bytes.len() {
+            segments.push(Segment::Bytes(bytes[start..].to_vec()));
+        }
+        Ok(PreparedBody {
+            segments,
+            _body: std::marker::PhantomData,
+        })
+    }
+
+    /// Names of the placeholders in the body, in order of appearance
+    pub fn placeholders(&self) -> impl Iterator<Item = &str> {
+        self.segments.iter().filter_map(|segment| match segment {
+            Segment::Placeholder(name) => Some(name.as_str()),
+            Segment::Bytes(_) => None,
+        })
+    }
+
+    /// Create the body, substituting every placeholder with its value in `values`
+    pub fn render(&self, values: &[(&str, &str)]) -> Result<Vec<u8>, BodyError> {
+        let mut body = Vec::with_capacity(
+            self.segments
+                .iter()
+                .map(|segment| match segment {
+                    Segment::Bytes(bytes) => bytes.len(),
+                    Segment::Placeholder(_) => 16,
+                })
+                .sum(),
+        );
+        for segment in &self.segments {
+            match segment {
+                Segment::Bytes(bytes) => body.extend_from_slice(bytes),
+                Segment::Placeholder(name) => {
+                    let value = values
+                        .iter()
+                        .find(|(key, _)| key == name)
+                        .ok_or_else(|| BodyError::MissingPlaceholder(name.clone()))?
+                        .1;
+                    serde_json::to_writer(&mut body, value)?;
+                }
+            }
+        }
+        Ok(body)
+    }
+
+    /// Create a [`http::Request`] for `request` with the rendered body, authorized as described by [`AuthStyle`]
+    pub fn create_request<'t, R>(
+        &self,
+        request: &R,
+        values: &[(&str, &str)],
+        auth: impl Into<AuthStyle<'t>>,
+        client_id: &str,
+    ) -> Result<http::Request<Vec<u8>>, CreateRequestError>
+    where
+        R: RequestPost<Body = B>,
+    {
+        super::create_request(request, Some(self.render(values)?), auth.into(), client_id)
+    }
+}
+
+/// Length of the quoted placeholder `"{name}"` at the start of `bytes`, if there is one
+///
+/// `bytes` must start outside of a JSON string.
+fn placeholder_len(bytes: &[u8]) -> Option<usize> {
+    let name_len = bytes
+        .strip_prefix(b"\"{")?
+        .iter()
+        .take_while(|b| b.is_ascii_alphanumeric() || **b == b'_')
+        .count();
+    if name_len > 0 && bytes[2 + name_len..].starts_with(b"}\"") {
+        Some(name_len + 4)
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "client")]
+impl<'a, C: crate::HttpClient<'a>> super::HelixClient<'a, C> {
+    /// Request on a valid [`RequestPost`] endpoint with a [`PreparedBody`], substituting its placeholders with `values`
+    pub async fn req_post_prepared<R, B, D, T>(
+        &'a self,
+        request: R,
+        body: &PreparedBody<B>,
+        values: &[(&str, &str)],
+        token: &T,
+    ) -> Result<super::Response<R, D>, super::ClientRequestError<<C as crate::HttpClient<'a>>::Error>>
+    where
+        R: super::Request<Response = D> + RequestPost<Body = B>,
+        B: HelixRequestBody,
+        D: serde::de::DeserializeOwned + PartialEq,
+        T: twitch_oauth2::TwitchToken + ?Sized,
+    {
+        let req = body.create_request(
+            &request,
+            values,
+            token.token().secret(),
+            token.client_id().as_str(),
+        )?;
+        let uri = req.uri().clone();
+        let response = self.send::<R>(req).await?;
+        <R>::parse_response(Some(request), &uri, response).map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_prepared_body() {
+    use super::moderation::check_automod_status::{
+        CheckAutoModStatusBody, CheckAutoModStatusRequest,
+    };
+
+    let body = PreparedBody::new(&vec![CheckAutoModStatusBody::new(
+        "{msg_id}".into(),
+        "{text}".to_string(),
+        "1234".into(),
+    )])
+    .unwrap();
+    assert_eq!(
+        body.placeholders().collect::<Vec<_>>(),
+        vec!["msg_id", "text"]
+    );
+
+    let text = "a \"quoted\" {text}";
+    let rendered = body.render(&[("text", text), ("msg_id", "1")]).unwrap();
+    let expected = vec![CheckAutoModStatusBody::new(
+        "1".into(),
+        text.to_string(),
+        "1234".into(),
+    )]
+    .try_to_body()
+    .unwrap();
+    assert_eq!(rendered, expected);
+
+    assert!(matches!(
+        body.render(&[("msg_id", "1")]),
+        Err(BodyError::MissingPlaceholder(name)) if name == "text"
+    ));
+
+    let req = CheckAutoModStatusRequest::builder()
+        .broadcaster_id("198704263")
+        .build();
+    let request = body
+        .create_request(
+            &req,
+            &[("text", text), ("msg_id", "1")],
+            "token",
+            "clientid",
+        )
+        .unwrap();
+    assert_eq!(
+        request.uri().to_string(),
+        "https://api.twitch.tv/helix/moderation/enforcements/status?broadcaster_id=198704263"
+    );
+    assert_eq!(request.body(), &expected);
+}
+
+#[cfg(test)]
+#[test]
+fn test_prepared_body_escaped_quote() {
+    use super::moderation::check_automod_status::CheckAutoModStatusBody;
+
+    let body = PreparedBody::new(&vec![CheckAutoModStatusBody::new(
+        "{msg_id}".into(),
+        "say \"{x}\" or \"{y}".to_string(),
+        "1234".into(),
+    )])
+    .unwrap();
+    assert_eq!(body.placeholders().collect::<Vec<_>>(), vec!["msg_id"]);
+
+    let rendered = body.render(&[("msg_id", "1")]).unwrap();
+    let expected = vec![CheckAutoModStatusBody::new(
+        "1".into(),
+        "say \"{x}\" or \"{y}".to_string(),
+        "1234".into(),
+    )]
+    .try_to_body()
+    .unwrap();
+    assert_eq!(rendered, expected);
+}
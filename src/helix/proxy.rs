@@ -0,0 +1,317 @@
+//! Forward requests to Helix with credentials held by a server.
+//!
+//! A [`Proxy`] only forwards requests to endpoints that were [allowed](Proxy::allow) by their [`Request`] type,
+//! replacing the credentials of the incoming request with the token of the server.
+//! This lets an untrusted frontend use a limited set of endpoints without ever seeing the token.
+//!
+//! Endpoints authorized with a [signed extension JWT](super::AuthKind::Extension) are forwarded with the JWT set by [`Proxy::set_extension_jwt`] instead.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! # let incoming: http::Request<Vec<u8>> = http::Request::default();
+//! use twitch_api2::helix::{self, proxy::Proxy};
+//!
+//! let proxy = Proxy::new("/api/twitch/")
+//!     .allow::<helix::users::GetUsersRequest>()
+//!     .allow::<helix::streams::GetStreamsRequest>();
+//! // `GET /api/twitch/users?login=justintv` is sent to `GET https://api.twitch.tv/helix/users?login=justintv`
+//! let response: http::Response<Vec<u8>> = proxy.forward(&client, incoming, &token).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{AuthKind, AuthStyle, ClientRequestError, CreateRequestError, HelixClient, Request};
+use twitch_oauth2::TwitchToken;
+
+/// Headers of the incoming request that are forwarded
+const FORWARDED_HEADERS: &[http::header::HeaderName] =
+    &[http::header::CONTENT_TYPE, http::header::IF_NONE_MATCH];
+
+/// Errors when forwarding a request with a [`Proxy`]
+#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[non_exhaustive]
+pub enum ProxyError {
+    /// endpoint `{method} {path}` is not allowed
+    NotAllowed {
+        /// Method of the request
+        method: http::Method,
+        /// Path of the request
+        path: String,
+    },
+    /// request is not below the prefix of the proxy
+    WrongPrefix,
+    /// endpoint `{path}` needs a signed extension JWT, but none was set
+    NoExtensionJwt {
+        /// Path of the request
+        path: String,
+    },
+    /// could not create request
+    CreateRequestError(#[from] CreateRequestError),
+}
+
+/// Errors when [forwarding](Proxy::forward) a request
+#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[non_exhaustive]
+pub enum ForwardError<RE: std::error::Error + Send + Sync + 'static> {
+    /// could not forward request
+    ProxyError(#[from] ProxyError),
+    /// request failed
+    RequestError(#[source] ClientRequestError<RE>),
+}
+
+/// Forwards requests to allowed Helix endpoints, see the [module documentation](self)
+#[derive(Clone)]
+pub struct Proxy {
+    prefix: String,
+    endpoints: Vec<(http::Method, &'static str, AuthKind)>,
+    extension_jwt: Option<String>,
+}
+
+impl std::fmt::Debug for Proxy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Proxy")
+            .field("prefix", &self.prefix)
+            .field("endpoints", &self.endpoints)
+            .field(
+                "extension_jwt",
+                &self.extension_jwt.as_ref().map(|_| "[redacted]"),
+            )
+            .finish()
+    }
+}
+
+impl Proxy {
+    /// Create a proxy for requests with paths starting with `prefix`, allowing no endpoints.
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Proxy {
+            prefix: prefix.into(),
+            endpoints: vec![],
+            extension_jwt: None,
+        }
+    }
+
+    /// Allow requests to the endpoint of `R`, authorized as described by [`Request::AUTH`]
+    pub fn allow<R: Request>(mut self) -> Self {
+        self.endpoints.push((R::METHOD, R::PATH, R::AUTH));
+        self
+    }
+
+    /// Set the signed JWT used for endpoints authorized with [`AuthKind::Extension`].
+    ///
+    /// These JWTs expire, so this should be called again with a new one before that.
+    pub fn set_extension_jwt(&mut self, jwt: impl Into<String>) {
+        self.extension_jwt = Some(jwt.into())
+    }
+
+    /// Whether a request with `method` to `path`, relative to the Helix root, is allowed
+    pub fn is_allowed(&self, method: &http::Method, path: &str) -> bool {
+        self.endpoint(method, path).is_some()
+    }
+
+    /// The allowed endpoint with `method` at `path`, relative to the Helix root
+    fn endpoint(
+        &self,
+        method: &http::Method,
+        path: &str,
+    ) -> Option<&(http::Method, &'static str, AuthKind)> {
+        self.endpoints
+            .iter()
+            .find(|(allowed_method, allowed_path, _)| {
+                allowed_method == method && *allowed_path == path
+            })
+    }
+
+    /// Make the request to Helix for `request`, authorized with `token`, or the [extension JWT](Proxy::set_extension_jwt) if the endpoint needs one.
+    ///
+    /// Only the method, path, query, body and some headers like `Content-Type` of `request` are used.
+    pub fn to_helix_request<T>(
+        &self,
+        request: http::Request<Vec<u8>>,
+        token: &T,
+    ) -> Result<http::Request<Vec<u8>>, ProxyError>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        self.prepare(request, token).map(|(request, _)| request)
+    }
+
+    /// Make the request to Helix for `request`, and return the path of its endpoint
+    fn prepare<T>(
+        &self,
+        request: http::Request<Vec<u8>>,
+        token: &T,
+    ) -> Result<(http::Request<Vec<u8>>, &'static str), ProxyError>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        let (parts, body) = request.into_parts();
+        let path = parts
+            .uri
+            .path()
+            .strip_prefix(self.prefix.as_str())
+            .ok_or(ProxyError::WrongPrefix)?
+            .trim_matches('/');
+        let (path, auth) = match self.endpoint(&parts.method, path) {
+            Some((_, path, AuthKind::Bearer)) => (*path, AuthStyle::Bearer(token.token().secret())),
+            Some((_, path, AuthKind::Extension)) => match &self.extension_jwt {
+                Some(jwt) => (*path, AuthStyle::Extension(jwt)),
+                None => {
+                    return Err(ProxyError::NoExtensionJwt {
+                        path: path.to_string(),
+                    })
+                }
+            },
+            None => {
+                return Err(ProxyError::NotAllowed {
+                    method: parts.method,
+                    path: path.to_string(),
+                })
+            }
+        };
+        let mut url = crate::TWITCH_HELIX_URL
+            .join(path)
+            .map_err(|e| CreateRequestError::from(super::InvalidUri::from(e)))?;
+        url.set_query(parts.uri.query());
+        let mut helix_request = http::Request::builder()
+            .method(parts.method)
+            .uri(url.as_str())
+            .header("Client-ID", token.client_id().as_str());
+        for name in FORWARDED_HEADERS {
+            if let Some(value) = parts.headers.get(name) {
+                helix_request = helix_request.header(name, value);
+            }
+        }
+        if let Some(accept_encoding) = super::encoding::ACCEPT_ENCODING {
+            helix_request = helix_request.header(http::header::ACCEPT_ENCODING, accept_encoding);
+        }
+        if let Some(authorization) = auth.header_value()? {
+            helix_request = helix_request.header(http::header::AUTHORIZATION, authorization);
+        }
+        let helix_request = helix_request.body(body).map_err(CreateRequestError::from)?;
+        Ok((helix_request, path))
+    }
+
+    /// Forward `request` to Helix with `client`, authorized with `token`, returning the response of Twitch.
+    ///
+    /// The request is sent like the requests of the client, see [`HelixClient::set_compression`] for how the response is decompressed.
+    /// Redirects are returned as [`ClientRequestError::Redirect`].
+    pub async fn forward<'a, C, T>(
+        &self,
+        client: &'a HelixClient<'a, C>,
+        request: http::Request<Vec<u8>>,
+        token: &T,
+    ) -> Result<http::Response<Vec<u8>>, ForwardError<<C as crate::HttpClient<'a>>::Error>>
+    where
+        C: crate::HttpClient<'a>,
+        T: TwitchToken + ?Sized,
+    {
+        let (request, path) = self.prepare(request, token)?;
+        let method = request.method().clone();
+        client
+            .send_to(path, &method, request)
+            .await
+            .map_err(ForwardError::RequestError)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_proxy() {
+    let token = twitch_oauth2::UserToken::from_existing_unchecked(
+        twitch_oauth2::AccessToken::new("servertoken".to_string()),
+        None,
+        twitch_oauth2::ClientId::new("clientid".to_string()),
+        None,
+        "justintv".to_string(),
+        "1337".to_string(),
+        None,
+        None,
+    );
+    let proxy = Proxy::new("/api/").allow::<super::users::GetUsersRequest>();
+
+    let request = http::Request::get("https://example.com/api/users?login=justintv")
+        .header(http::header::AUTHORIZATION, "Bearer usertoken")
+        .body(vec![])
+        .unwrap();
+    let request = proxy.to_helix_request(request, &token).unwrap();
+    assert_eq!(
+        request.uri().to_string(),
+        "https://api.twitch.tv/helix/users?login=justintv"
+    );
+    assert_eq!(
+        request.headers()[http::header::AUTHORIZATION],
+        "Bearer servertoken"
+    );
+
+    let request = http::Request::delete("https://example.com/api/users/blocks?target_user_id=1")
+        .body(vec![])
+        .unwrap();
+    assert!(matches!(
+        proxy.to_helix_request(request, &token),
+        Err(ProxyError::NotAllowed { .. })
+    ));
+
+    let mut proxy = proxy.allow::<super::extensions::GetExtensionSecretsRequest>();
+    let secrets = || {
+        http::Request::get("https://example.com/api/extensions/jwt/secrets?extension_id=1")
+            .body(vec![])
+            .unwrap()
+    };
+    assert!(matches!(
+        proxy.to_helix_request(secrets(), &token),
+        Err(ProxyError::NoExtensionJwt { .. })
+    ));
+    proxy.set_extension_jwt("jwt");
+    let request = proxy.to_helix_request(secrets(), &token).unwrap();
+    assert_eq!(request.headers()[http::header::AUTHORIZATION], "Bearer jwt");
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_forward() {
+    use crate::client::{BoxedFuture, Req, Response};
+
+    /// Client that redirects every request
+    struct RedirectClient;
+
+    impl<'a> crate::HttpClient<'a> for RedirectClient {
+        type Error = crate::DummyHttpClient;
+
+        fn req(&'a self, _: Req) -> BoxedFuture<'a, Result<Response, Self::Error>> {
+            let response = http::Response::builder()
+                .status(http::StatusCode::FOUND)
+                .header(http::header::LOCATION, "https://example.com")
+                .body(vec![])
+                .unwrap();
+            Box::pin(async { Ok(response) })
+        }
+    }
+
+    let token = twitch_oauth2::UserToken::from_existing_unchecked(
+        twitch_oauth2::AccessToken::new("servertoken".to_string()),
+        None,
+        twitch_oauth2::ClientId::new("clientid".to_string()),
+        None,
+        "justintv".to_string(),
+        "1337".to_string(),
+        None,
+        None,
+    );
+    let proxy = Proxy::new("/api/").allow::<super::users::GetUsersRequest>();
+    let client = HelixClient::with_client(RedirectClient);
+    let request = http::Request::get("https://example.com/api/users?login=justintv")
+        .body(vec![])
+        .unwrap();
+    assert!(matches!(
+        proxy.forward(&client, request, &token).await,
+        Err(ForwardError::RequestError(
+            ClientRequestError::Redirect { .. }
+        ))
+    ));
+}
@@ -27,9 +27,9 @@
 //!         .start_time(twitch_api2::types::Timestamp::new("2021-07-01T18:00:00Z").unwrap())
 //!         .timezone("America/New_York")
 //!         .is_recurring(false)
-//!         .duration("60".to_string())
+//!         .duration("60")
 //!         .category_id(Some("509670".into()))
-//!         .title("TwitchDev Monthly Update // July 1, 2021".to_string())
+//!         .title("TwitchDev Monthly Update // July 1, 2021")
 //!         .build();
 //! ```
 //!
@@ -54,9 +54,9 @@
 //!     .start_time(twitch_api2::types::Timestamp::new("2021-07-01T18:00:00Z").unwrap())
 //!     .timezone("America/New_York")
 //!     .is_recurring(false)
-//!     .duration("60".to_string())
+//!     .duration("60")
 //!     .category_id(Some("509670".into()))
-//!     .title("TwitchDev Monthly Update // July 1, 2021".to_string())
+//!     .title("TwitchDev Monthly Update // July 1, 2021")
 //!     .build();
 //! let response: create_channel_stream_schedule_segment::CreateChannelStreamScheduleSegmentResponse = client.req_post(request, body, &token).await?.data;
 //! # Ok(())
@@ -72,6 +72,7 @@ use helix::RequestPost;
 ///
 /// [`create-channel-stream-schedule-segment`](https://dev.twitch.tv/docs/api/reference#create-channel-stream-schedule-segment)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct CreateChannelStreamScheduleSegmentRequest {
     /// User ID of the broadcaster who owns the channel streaming schedule. Provided broadcaster_id must match the user_id in the user OAuth token.
@@ -83,6 +84,7 @@ pub struct CreateChannelStreamScheduleSegmentRequest {
 ///
 /// [`create-channel-stream-schedule-segment`](https://dev.twitch.tv/docs/api/reference#create-channel-stream-schedule-segment)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct CreateChannelStreamScheduleSegmentBody {
     /// Start time for the scheduled broadcast specified in RFC3339 format.
@@ -95,13 +97,13 @@ pub struct CreateChannelStreamScheduleSegmentBody {
     /// Indicates if the scheduled broadcast is recurring weekly.
     pub is_recurring: bool,
     /// Duration of the scheduled broadcast in minutes from the start_time. Default: 240.
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub duration: Option<String>,
     /// Game/Category ID for the scheduled broadcast.
     #[builder(default, setter(into))]
     pub category_id: Option<types::CategoryId>,
     /// Title for the scheduled broadcast. Maximum: 140 characters.
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub title: Option<String>,
 }
 
@@ -115,6 +117,7 @@ pub type CreateChannelStreamScheduleSegmentResponse = ScheduledBroadcasts;
 impl Request for CreateChannelStreamScheduleSegmentRequest {
     type Response = CreateChannelStreamScheduleSegmentResponse;
 
+    const METHOD: http::Method = http::Method::POST;
     const PATH: &'static str = "schedule/segment";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelManageSchedule];
@@ -136,38 +139,17 @@ fn test_request() {
         .start_time(types::Timestamp::new("2021-07-01T18:00:00Z").unwrap())
         .timezone("America/New_York")
         .is_recurring(false)
-        .duration("60".to_string())
+        .duration("60")
         .category_id(Some("509670".into()))
-        .title("TwitchDev Monthly Update // July 1, 2021".to_string())
+        .title("TwitchDev Monthly Update // July 1, 2021")
         .build();
 
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
     // From twitch docs
-    let data = br#"
-    {
-        "data": {
-          "segments": [
-            {
-              "id": "eyJzZWdtZW50SUQiOiJlNGFjYzcyNC0zNzFmLTQwMmMtODFjYS0yM2FkYTc5NzU5ZDQiLCJpc29ZZWFyIjoyMDIxLCJpc29XZWVrIjoyNn0=",
-              "start_time": "2021-07-01T18:00:00Z",
-              "end_time": "2021-07-01T19:00:00Z",
-              "title": "TwitchDev Monthly Update // July 1, 2021",
-              "canceled_until": null,
-              "category": {
-                  "id": "509670",
-                  "name": "Science & Technology"
-              },
-              "is_recurring": false
-            }
-          ],
-          "broadcaster_id": "141981764",
-          "broadcaster_name": "TwitchDev",
-          "broadcaster_login": "twitchdev",
-          "vacation": null
-        }
-      }
-    "#.to_vec();
+    let data = crate::fixtures::helix::schedule::CREATE_CHANNEL_STREAM_SCHEDULE_SEGMENT
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().status(204).body(data).unwrap();
 
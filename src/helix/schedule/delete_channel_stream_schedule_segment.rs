@@ -13,8 +13,11 @@
 //!
 //! ```rust
 //! use twitch_api2::helix::schedule::delete_channel_stream_schedule_segment;
-//! let request = delete_channel_stream_schedule_segment::DeleteChannelStreamScheduleSegmentRequest::builder()
-//!     .from_id("1234").to_id("4321")
+//! let request =
+//!     delete_channel_stream_schedule_segment::DeleteChannelStreamScheduleSegmentRequest::builder(
+//!     )
+//!     .from_id("1234")
+//!     .to_id("4321")
 //!     .build();
 //! ```
 //!
@@ -48,6 +51,7 @@ use helix::RequestDelete;
 ///
 /// [`delete-channel-stream-schedule-segment`](https://dev.twitch.tv/docs/api/reference#delete-channel-stream-schedule-segment)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct DeleteChannelStreamScheduleSegmentRequest {
     /// User ID of the follower
@@ -61,6 +65,11 @@ pub struct DeleteChannelStreamScheduleSegmentRequest {
 ///
 /// [`delete-channel-stream-schedule-segment`](https://dev.twitch.tv/docs/api/reference#delete-channel-stream-schedule-segment)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[non_exhaustive]
 pub enum DeleteChannelStreamScheduleSegment {
     /// 204 - User successfully deleted from list of channel followers
@@ -70,6 +79,7 @@ pub enum DeleteChannelStreamScheduleSegment {
 impl Request for DeleteChannelStreamScheduleSegmentRequest {
     type Response = DeleteChannelStreamScheduleSegment;
 
+    const METHOD: http::Method = http::Method::DELETE;
     const PATH: &'static str = "schedule/segment";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelManageSchedule];
@@ -82,8 +92,8 @@ impl RequestDelete for DeleteChannelStreamScheduleSegmentRequest {}
 fn test_request() {
     use helix::*;
     let req = DeleteChannelStreamScheduleSegmentRequest::builder()
-        .to_id("41245072".to_string())
-        .from_id("41245071".to_string())
+        .to_id("41245072")
+        .from_id("41245071")
         .build();
 
     // From twitch docs
@@ -45,6 +45,7 @@ use helix::RequestGet;
 ///
 /// [`get-channel-stream-schedule`](https://dev.twitch.tv/docs/api/reference#get-channel-stream-schedule)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetChannelStreamScheduleRequest {
     /// User ID of the broadcaster who owns the channel streaming schedule. Provided broadcaster_id must match the user_id in the user OAuth token.
@@ -57,14 +58,14 @@ pub struct GetChannelStreamScheduleRequest {
     #[builder(default, setter(into))]
     pub start_time: Option<types::Timestamp>,
     /// A timezone offset for the requester specified in minutes. This is recommended to ensure stream segments are returned for the correct week. For example, a timezone that is +4 hours from GMT would be “240.” If not specified, “0” is used for GMT.
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub utc_offset: Option<String>,
-    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 25. Default: 20.
+    ///
+    /// Backward pagination with [`before`](helix::PaginationParams::before) is not supported.
     #[builder(default)]
-    pub after: Option<helix::Cursor>,
-    /// Maximum number of stream segments to return. Maximum: 25. Default: 20.
-    #[builder(default, setter(into))]
-    pub first: Option<usize>,
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
 }
 
 /// Return Values for [Get Channel Stream Schedule](super::get_channel_stream_schedule)
@@ -75,15 +76,19 @@ pub type GetChannelStreamScheduleResponse = ScheduledBroadcasts;
 impl Request for GetChannelStreamScheduleRequest {
     type Response = ScheduledBroadcasts;
 
+    const MAX_PAGE_SIZE: u32 = 25;
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "schedule";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for GetChannelStreamScheduleRequest {}
 
 impl helix::Paginated for GetChannelStreamScheduleRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor; }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor; }
 }
 
 #[cfg(test)]
@@ -95,15 +100,65 @@ fn test_request() {
         .build();
 
     // From twitch docs. FIXME: Docs has ...
+    let data = crate::fixtures::helix::schedule::GET_CHANNEL_STREAM_SCHEDULE
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/schedule?broadcaster_id=141981764"
+    );
+
+    dbg!(GetChannelStreamScheduleRequest::parse_response(Some(req), &uri, http_response).unwrap());
+}
+
+#[cfg(test)]
+#[test]
+fn test_next_segment_after() {
+    use helix::*;
+    let req = GetChannelStreamScheduleRequest::builder()
+        .broadcaster_id("141981764")
+        .build();
+
     let data = br#"
     {
         "data": {
           "segments": [
             {
-              "id": "eyJzZWdtZW50SUQiOiJlNGFjYzcyNC0zNzFmLTQwMmMtODFjYS0yM2FkYTc5NzU5ZDQiLCJpc29ZZWFyIjoyMDIxLCJpc29XZWVrIjoyNn0=",
+              "id": "1",
               "start_time": "2021-07-01T18:00:00Z",
               "end_time": "2021-07-01T19:00:00Z",
-              "title": "TwitchDev Monthly Update // July 1, 2021",
+              "title": "Already started",
+              "canceled_until": null,
+              "category": null,
+              "is_recurring": false
+            },
+            {
+              "id": "2",
+              "start_time": "2021-07-02T18:00:00Z",
+              "end_time": "2021-07-02T19:00:00Z",
+              "title": "Canceled",
+              "canceled_until": "2021-07-09T18:00:00Z",
+              "category": null,
+              "is_recurring": true
+            },
+            {
+              "id": "3",
+              "start_time": "2021-07-03T18:00:00Z",
+              "end_time": "2021-07-03T19:00:00Z",
+              "title": "On vacation",
+              "canceled_until": null,
+              "category": null,
+              "is_recurring": false
+            },
+            {
+              "id": "4",
+              "start_time": "2021-07-10T18:00:00Z",
+              "end_time": "2021-07-10T19:00:00Z",
+              "title": "Next",
               "canceled_until": null,
               "category": {
                   "id": "509670",
@@ -115,7 +170,10 @@ fn test_request() {
           "broadcaster_id": "141981764",
           "broadcaster_name": "TwitchDev",
           "broadcaster_login": "twitchdev",
-          "vacation": null
+          "vacation": {
+            "start_time": "2021-07-03T00:00:00Z",
+            "end_time": "2021-07-05T00:00:00Z"
+          }
         },
         "pagination": {}
       }
@@ -125,10 +183,13 @@ fn test_request() {
     let http_response = http::Response::builder().body(data).unwrap();
 
     let uri = req.get_uri().unwrap();
-    assert_eq!(
-        uri.to_string(),
-        "https://api.twitch.tv/helix/schedule?broadcaster_id=141981764"
-    );
+    let schedule = GetChannelStreamScheduleRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
 
-    dbg!(GetChannelStreamScheduleRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    let now = types::Timestamp::new("2021-07-01T18:30:00Z").unwrap();
+    let next = schedule.next_segment_after(&now).unwrap();
+    assert_eq!(next.title, "Next");
+    let later = types::Timestamp::new("2021-07-11T00:00:00Z").unwrap();
+    assert!(schedule.next_segment_after(&later).is_none());
 }
@@ -23,10 +23,16 @@ pub use update_channel_stream_schedule::{
 
 /// Scheduled broadcasts for a specific channel given by [Get Channel Stream Schedule](self)
 #[derive(PartialEq, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ScheduledBroadcasts {
     /// Scheduled broadcasts for this stream schedule.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub segments: Vec<Segment>,
     /// User ID of the broadcaster.
     pub broadcaster_id: types::UserId,
@@ -38,8 +44,50 @@ pub struct ScheduledBroadcasts {
     pub vacation: Option<Vacation>,
 }
 
+impl ScheduledBroadcasts {
+    /// Get the next [segment](Segment) that starts after `now`.
+    ///
+    /// Segments that fall within the broadcasters [vacation](ScheduledBroadcasts::vacation) or that are [canceled](Segment::canceled_until) are skipped.
+    ///
+    /// # Notes
+    ///
+    /// Segments are assumed to be ordered by their start time, as returned by [Get Channel Stream Schedule](get_channel_stream_schedule).
+    /// Timestamps are compared in UTC, see [`Timestamp`](types::Timestamp).
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # use twitch_api2::{helix::schedule::ScheduledBroadcasts, types};
+    /// # fn get_schedule() -> ScheduledBroadcasts { todo!() }
+    /// let schedule: ScheduledBroadcasts = get_schedule();
+    /// let now = types::Timestamp::new("2021-07-01T18:30:00Z").unwrap();
+    /// if let Some(segment) = schedule.next_segment_after(&now) {
+    ///     println!(
+    ///         "next stream is {:?} at {}",
+    ///         segment.title, segment.start_time
+    ///     );
+    /// }
+    /// ```
+    pub fn next_segment_after(&self, now: &types::TimestampRef) -> Option<&Segment> {
+        self.segments.iter().find(|segment| {
+            now.is_before(&segment.start_time)
+                && !segment.is_canceled()
+                && !self
+                    .vacation
+                    .as_ref()
+                    .map(|v| v.contains(&segment.start_time))
+                    .unwrap_or_default()
+        })
+    }
+}
+
 /// Scheduled broadcast for a stream schedule.
 #[derive(PartialEq, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Segment {
@@ -59,9 +107,24 @@ pub struct Segment {
     pub is_recurring: bool,
 }
 
+impl Segment {
+    /// Returns `true` if this occurrence of the segment has been canceled, i.e it starts before [`canceled_until`](Segment::canceled_until)
+    pub fn is_canceled(&self) -> bool {
+        self.canceled_until
+            .as_ref()
+            .map(|until| self.start_time.is_before(until))
+            .unwrap_or_default()
+    }
+}
+
 // FIXME: Similar to types::TwitchCategory, missing box_art
 /// The category for a scheduled broadcast.
 #[derive(PartialEq, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Category {
@@ -73,6 +136,11 @@ pub struct Category {
 
 /// Information on Vacation Mode
 #[derive(PartialEq, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Vacation {
@@ -81,3 +149,10 @@ pub struct Vacation {
     /// End time for vacation specified in RFC3339 format.
     pub end_time: types::Timestamp,
 }
+
+impl Vacation {
+    /// Returns `true` if `time` is within this vacation.
+    pub fn contains(&self, time: &types::TimestampRef) -> bool {
+        !time.is_before(&self.start_time) && time.is_before(&self.end_time)
+    }
+}
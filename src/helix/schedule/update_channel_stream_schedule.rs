@@ -47,6 +47,7 @@ use helix::RequestPatch;
 ///
 /// [`update-channel-stream-schedule`](https://dev.twitch.tv/docs/api/reference#update-channel-stream-schedule)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct UpdateChannelStreamScheduleRequest {
     /// User ID of the broadcaster who owns the channel streaming schedule. Provided broadcaster_id must match the user_id in the user OAuth token.
@@ -62,7 +63,7 @@ pub struct UpdateChannelStreamScheduleRequest {
     #[builder(default, setter(into))]
     pub vacation_end_time: Option<types::Timestamp>,
     /// The timezone for when the vacation is being scheduled using the IANA time zone database format. Required if is_vacation_enabled is set to true.
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub timezone: Option<String>,
 }
 
@@ -70,6 +71,11 @@ pub struct UpdateChannelStreamScheduleRequest {
 ///
 /// [`update-channel-stream-schedule`](https://dev.twitch.tv/docs/api/reference#update-channel-stream-schedule)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub enum UpdateChannelStreamSchedule {
@@ -80,6 +86,7 @@ pub enum UpdateChannelStreamSchedule {
 impl Request for UpdateChannelStreamScheduleRequest {
     type Response = UpdateChannelStreamSchedule;
 
+    const METHOD: http::Method = http::Method::PATCH;
     const PATH: &'static str = "schedule/settings";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelManageSchedule];
@@ -93,7 +100,7 @@ impl RequestPatch for UpdateChannelStreamScheduleRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPatchError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
@@ -102,11 +109,13 @@ impl RequestPatch for UpdateChannelStreamScheduleRequest {
                 UpdateChannelStreamSchedule::Success
             }
             _ => {
-                return Err(helix::HelixRequestPatchError::InvalidResponse {
+                return Err(helix::HelixRequestError::InvalidResponse {
                     reason: "unexpected status code",
                     response: response.to_string(),
                     status,
+                    method: Self::METHOD,
                     uri: uri.clone(),
+                    request_id: None,
                 })
             }
         };
@@ -116,6 +125,8 @@ impl RequestPatch for UpdateChannelStreamScheduleRequest {
             request,
             total: None,
             other: None,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
@@ -129,7 +140,7 @@ fn test_request() {
         .is_vacation_enabled(true)
         .vacation_start_time(Some("2021-05-16T00:00:00Z".try_into().unwrap()))
         .vacation_end_time(Some("2021-05-23T00:00:00Z".try_into().unwrap()))
-        .timezone("America/New_York".to_string())
+        .timezone("America/New_York")
         .build();
 
     let body = helix::EmptyBody;
@@ -23,7 +23,7 @@
 //! # use twitch_api2::helix::schedule::update_channel_stream_schedule_segment;
 //! let body =
 //!     update_channel_stream_schedule_segment::UpdateChannelStreamScheduleSegmentBody::builder()
-//!         .duration("120".to_string())
+//!         .duration("120")
 //!         .build();
 //! ```
 //!
@@ -46,7 +46,7 @@
 //!     .id("eyJzZWdtZW50SUQiOiJlNGFjYzcyNC0zNzFmLTQwMmMtODFjYS0yM2FkYTc5NzU5ZDQiLCJpc29ZZWFyIjoyMDIxLCJpc29XZWVrIjoyNn0=")
 //!     .build();
 //! let body = update_channel_stream_schedule_segment::UpdateChannelStreamScheduleSegmentBody::builder()
-//!     .duration("120".to_string())
+//!     .duration("120")
 //!     .build();
 //! let response: update_channel_stream_schedule_segment::UpdateChannelStreamScheduleSegmentResponse = client.req_patch(request, body, &token).await?.data;
 //! # Ok(())
@@ -62,6 +62,7 @@ use helix::RequestPatch;
 ///
 /// [`update-channel-stream-schedule-segment`](https://dev.twitch.tv/docs/api/reference#update-channel-stream-schedule-segment)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct UpdateChannelStreamScheduleSegmentRequest {
     /// User ID of the broadcaster who owns the channel streaming schedule. Provided broadcaster_id must match the user_id in the user OAuth token.
@@ -76,26 +77,27 @@ pub struct UpdateChannelStreamScheduleSegmentRequest {
 ///
 /// [`update-channel-stream-schedule-segment`](https://dev.twitch.tv/docs/api/reference#update-channel-stream-schedule-segment)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct UpdateChannelStreamScheduleSegmentBody {
     /// Start time for the scheduled broadcast specified in RFC3339 format.
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub start_time: Option<String>,
     /// Duration of the scheduled broadcast in minutes from the start_time. Default: 240.
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub duration: Option<String>,
     /// Game/Category ID for the scheduled broadcast.
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub category_id: Option<String>,
     /// Title for the scheduled broadcast. Maximum: 140 characters.
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub title: Option<String>,
     /// Indicated if the scheduled broadcast is canceled.
     #[builder(default, setter(into))]
     pub is_canceled: Option<bool>,
     // FIXME: Enum?
     /// The timezone of the application creating the scheduled broadcast using the IANA time zone database format.
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub timezone: Option<String>,
 }
 
@@ -109,6 +111,7 @@ pub type UpdateChannelStreamScheduleSegmentResponse = ScheduledBroadcasts;
 impl Request for UpdateChannelStreamScheduleSegmentRequest {
     type Response = UpdateChannelStreamScheduleSegmentResponse;
 
+    const METHOD: http::Method = http::Method::PATCH;
     const PATH: &'static str = "schedule/segment";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelManageSchedule];
@@ -122,17 +125,19 @@ impl RequestPatch for UpdateChannelStreamScheduleSegmentRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPatchError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
         let response: helix::InnerResponse<<Self as Request>::Response> =
-            helix::parse_json(response, true).map_err(|e| {
-                helix::HelixRequestPatchError::DeserializeError(
+            helix::parse_json_owned(response, true).map_err(|e| {
+                helix::HelixRequestError::DeserializeError(
                     response.to_string(),
                     e,
+                    Self::METHOD,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         Ok(helix::Response {
@@ -141,6 +146,8 @@ impl RequestPatch for UpdateChannelStreamScheduleSegmentRequest {
             request,
             total: response.total,
             other: None,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
@@ -155,36 +162,15 @@ fn test_request() {
         .build();
 
     let body = UpdateChannelStreamScheduleSegmentBody::builder()
-        .duration("120".to_string())
+        .duration("120")
         .build();
 
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
     // From twitch docs
-    let data = br#"
-    {
-        "data": {
-          "segments": [
-            {
-              "id": "eyJzZWdtZW50SUQiOiJlNGFjYzcyNC0zNzFmLTQwMmMtODFjYS0yM2FkYTc5NzU5ZDQiLCJpc29ZZWFyIjoyMDIxLCJpc29XZWVrIjoyNn0=",
-              "start_time": "2021-07-01T18:00:00Z",
-              "end_time": "2021-07-01T20:00:00Z",
-              "title": "TwitchDev Monthly Update // July 1, 2021",
-              "canceled_until": null,
-              "category": {
-                  "id": "509670",
-                  "name": "Science & Technology"
-              },
-              "is_recurring": false
-            }
-          ],
-          "broadcaster_id": "141981764",
-          "broadcaster_name": "TwitchDev",
-          "broadcaster_login": "twitchdev",
-          "vacation": null
-        }
-      }
-    "#.to_vec();
+    let data = crate::fixtures::helix::schedule::UPDATE_CHANNEL_STREAM_SCHEDULE_SEGMENT
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().status(200).body(data).unwrap();
 
@@ -0,0 +1,562 @@
+//! Check the scopes of a token at compile time.
+//!
+//! This is opt-in, [`HelixClient::req_get`] and friends keep accepting any token. A [`ScopedToken`] carries the scopes it was checked to have in its type,
+//! and [`HelixClient::req_get_scoped`] and friends only accept it for endpoints whose [required scope](ScopedRequest::RequiredScope) is one of them.
+//! Forgetting a scope is then a compile error mentioning the scope, instead of a `401` from Twitch.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! use twitch_api2::helix::{
+//!     self,
+//!     scoped::{scopes, ScopedToken},
+//! };
+//!
+//! // Checks once that the token has these scopes
+//! let token: ScopedToken<_, (scopes::ModerationRead, scopes::ChannelReadPolls)> =
+//!     ScopedToken::new(token)?;
+//!
+//! let req = helix::moderation::GetModeratorsRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! let moderators = client.req_get_scoped(req, &token).await?;
+//! // This would not compile, as `channel:manage:polls` is not a scope of the token
+//! // let req = helix::polls::EndPollRequest::builder().build();
+//! // client.req_patch_scoped(req, body, &token).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{
+    ClientRequestError, HelixClient, HelixRequestBody, Request, RequestDelete, RequestGet,
+    RequestPatch, RequestPost, RequestPut, Response,
+};
+use std::marker::PhantomData;
+use twitch_oauth2::{Scope, TwitchToken};
+
+/// A [`Scope`] as a type
+pub trait ScopeMarker {
+    /// The scope
+    const SCOPE: Scope;
+}
+
+/// Marker for endpoints that don't require a scope
+#[derive(Debug, Clone, Copy)]
+pub enum NoScope {}
+
+/// Endpoint that declares its required scope as a type, see [`Request::SCOPE`]
+pub trait ScopedRequest: Request {
+    /// The scope required to call this endpoint, a [`ScopeMarker`] or [`NoScope`]
+    type RequiredScope;
+}
+
+/// Endpoint with fields in its response that are only populated if the token has a scope, see [`Request::OPT_SCOPE`]
+pub trait OptScopedRequest: Request {
+    /// The scope the response fields depend on, a [`ScopeMarker`]
+    type OptionalScope;
+}
+
+/// Set of scopes, implemented for tuples of [`ScopeMarker`]s
+pub trait ScopeSet {
+    /// The scopes in this set
+    fn scopes() -> Vec<Scope>;
+}
+
+/// The scope `S` is in this set, `I` is its position and is inferred.
+pub trait HasScope<S, I> {}
+
+/// Positions of a scope in a [`ScopeSet`], used to disambiguate [`HasScope`]
+#[doc(hidden)]
+pub mod index {
+    /// Any position, for [`NoScope`](super::NoScope)
+    pub enum Any {}
+    /// First position
+    pub enum I0 {}
+    /// Second position
+    pub enum I1 {}
+    /// Third position
+    pub enum I2 {}
+    /// Fourth position
+    pub enum I3 {}
+    /// Fifth position
+    pub enum I4 {}
+    /// Sixth position
+    pub enum I5 {}
+}
+
+impl<T> HasScope<NoScope, index::Any> for T {}
+
+macro_rules! scope_set {
+    ($($scope:ident: $index:ident),*) => {
+        impl<$($scope: ScopeMarker),*> ScopeSet for ($($scope,)*) {
+            fn scopes() -> Vec<Scope> { vec![$($scope::SCOPE),*] }
+        }
+
+        scope_set!(@has ($($scope),*) $($scope: $index),*);
+    };
+    (@has $all:tt $($scope:ident: $index:ident),*) => {
+        $(scope_set!(@impl $all $scope $index);)*
+    };
+    (@impl ($($all:ident),*) $scope:ident $index:ident) => {
+        impl<$($all),*> HasScope<$scope, index::$index> for ($($all,)*) {}
+    };
+}
+
+scope_set!(S0: I0);
+scope_set!(S0: I0, S1: I1);
+scope_set!(S0: I0, S1: I1, S2: I2);
+scope_set!(S0: I0, S1: I1, S2: I2, S3: I3);
+scope_set!(S0: I0, S1: I1, S2: I2, S3: I3, S4: I4);
+scope_set!(S0: I0, S1: I1, S2: I2, S3: I3, S4: I4, S5: I5);
+
+/// The token is missing a scope of the [`ScopeSet`] it should have
+#[derive(Debug, thiserror::Error, displaydoc::Display)]
+pub enum MissingScopeError {
+    /// token is missing scope {0:?}
+    MissingScope(Scope),
+}
+
+/// A token that has been checked to have the scopes in `S`, a tuple of [`ScopeMarker`]s
+#[derive(Debug, Clone)]
+pub struct ScopedToken<T, S> {
+    token: T,
+    _scopes: PhantomData<fn() -> S>,
+}
+
+impl<T: TwitchToken, S: ScopeSet> ScopedToken<T, S> {
+    /// Check that the token has all scopes in `S`
+    pub fn new(token: T) -> Result<Self, MissingScopeError> {
+        if let Some(scope) = S::scopes()
+            .into_iter()
+            .find(|scope| !token.scopes().contains(scope))
+        {
+            return Err(MissingScopeError::MissingScope(scope));
+        }
+        Ok(ScopedToken {
+            token,
+            _scopes: PhantomData,
+        })
+    }
+}
+
+impl<T, S> ScopedToken<T, S> {
+    /// The checked token
+    pub fn token(&self) -> &T { &self.token }
+
+    /// Get the checked token back
+    pub fn into_inner(self) -> T { self.token }
+}
+
+impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
+    /// Request on a valid [`RequestGet`] endpoint with a token that is known to have the required scope
+    pub async fn req_get_scoped<R, D, T, S, I>(
+        &'a self,
+        request: R,
+        token: &ScopedToken<T, S>,
+    ) -> Result<Response<R, D>, ClientRequestError<<C as crate::HttpClient<'a>>::Error>>
+    where
+        R: Request<Response = D> + RequestGet + ScopedRequest,
+        D: serde::de::DeserializeOwned + PartialEq,
+        T: TwitchToken,
+        S: HasScope<R::RequiredScope, I>,
+        C: Send,
+    {
+        self.req_get(request, token.token()).await
+    }
+
+    /// Request on a valid [`RequestPost`] endpoint with a token that is known to have the required scope
+    pub async fn req_post_scoped<R, B, D, T, S, I>(
+        &'a self,
+        request: R,
+        body: B,
+        token: &ScopedToken<T, S>,
+    ) -> Result<Response<R, D>, ClientRequestError<<C as crate::HttpClient<'a>>::Error>>
+    where
+        R: Request<Response = D> + RequestPost<Body = B> + ScopedRequest,
+        B: HelixRequestBody,
+        D: serde::de::DeserializeOwned + PartialEq,
+        T: TwitchToken,
+        S: HasScope<R::RequiredScope, I>,
+    {
+        self.req_post(request, body, token.token()).await
+    }
+
+    /// Request on a valid [`RequestPatch`] endpoint with a token that is known to have the required scope
+    pub async fn req_patch_scoped<R, B, D, T, S, I>(
+        &'a self,
+        request: R,
+        body: B,
+        token: &ScopedToken<T, S>,
+    ) -> Result<Response<R, D>, ClientRequestError<<C as crate::HttpClient<'a>>::Error>>
+    where
+        R: Request<Response = D> + RequestPatch<Body = B> + ScopedRequest,
+        B: HelixRequestBody,
+        D: serde::de::DeserializeOwned + PartialEq,
+        T: TwitchToken,
+        S: HasScope<R::RequiredScope, I>,
+    {
+        self.req_patch(request, body, token.token()).await
+    }
+
+    /// Request on a valid [`RequestDelete`] endpoint with a token that is known to have the required scope
+    pub async fn req_delete_scoped<R, D, T, S, I>(
+        &'a self,
+        request: R,
+        token: &ScopedToken<T, S>,
+    ) -> Result<Response<R, D>, ClientRequestError<<C as crate::HttpClient<'a>>::Error>>
+    where
+        R: Request<Response = D> + RequestDelete + ScopedRequest,
+        D: serde::de::DeserializeOwned + PartialEq,
+        T: TwitchToken,
+        S: HasScope<R::RequiredScope, I>,
+    {
+        self.req_delete(request, token.token()).await
+    }
+
+    /// Request on a valid [`RequestPut`] endpoint with a token that is known to have the required scope
+    pub async fn req_put_scoped<R, B, D, T, S, I>(
+        &'a self,
+        request: R,
+        body: B,
+        token: &ScopedToken<T, S>,
+    ) -> Result<Response<R, D>, ClientRequestError<<C as crate::HttpClient<'a>>::Error>>
+    where
+        R: Request<Response = D> + RequestPut<Body = B> + ScopedRequest,
+        B: HelixRequestBody,
+        D: serde::de::DeserializeOwned + PartialEq,
+        T: TwitchToken,
+        S: HasScope<R::RequiredScope, I>,
+    {
+        self.req_put(request, body, token.token()).await
+    }
+
+    /// Request on a valid [`RequestGet`] endpoint with a token that is known to have the [optional scope](OptScopedRequest::OptionalScope),
+    /// so that the fields depending on it are populated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api2::helix::{
+    ///     self,
+    ///     scoped::{scopes, ScopedToken},
+    /// };
+    ///
+    /// let token: ScopedToken<_, (scopes::UserReadEmail,)> = ScopedToken::new(token)?;
+    /// let req = helix::users::GetUsersRequest::builder().build();
+    /// let me = client.req_get_opt_scoped(req, &token).await?.data;
+    /// println!("{:?}", me.first().and_then(|user| user.email.as_ref()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn req_get_opt_scoped<R, D, T, S, I, J>(
+        &'a self,
+        request: R,
+        token: &ScopedToken<T, S>,
+    ) -> Result<Response<R, D>, ClientRequestError<<C as crate::HttpClient<'a>>::Error>>
+    where
+        R: Request<Response = D> + RequestGet + ScopedRequest + OptScopedRequest,
+        D: serde::de::DeserializeOwned + PartialEq,
+        T: TwitchToken,
+        S: HasScope<R::RequiredScope, I> + HasScope<R::OptionalScope, J>,
+        C: Send,
+    {
+        self.req_get(request, token.token()).await
+    }
+}
+
+/// Scopes as types, for use in a [`ScopedToken`]
+pub mod scopes {
+    use super::ScopeMarker;
+    use twitch_oauth2::Scope;
+
+    macro_rules! scopes {
+        ($($scope:ident),* $(,)?; $($other:ident => $name:literal),* $(,)?) => {
+            $(
+                #[doc = concat!("Marker for [`Scope::", stringify!($scope), "`]")]
+                #[derive(Debug, Clone, Copy)]
+                pub enum $scope {}
+
+                impl ScopeMarker for $scope {
+                    const SCOPE: Scope = Scope::$scope;
+                }
+            )*
+            $(
+                #[doc = concat!("Marker for `", $name, "`, not yet known to [`twitch_oauth2`]")]
+                #[derive(Debug, Clone, Copy)]
+                pub enum $other {}
+
+                impl ScopeMarker for $other {
+                    const SCOPE: Scope = Scope::Other(std::borrow::Cow::Borrowed($name));
+                }
+            )*
+        };
+    }
+
+    scopes![
+        ChannelEditCommercial,
+        ChannelManageBroadcast,
+        ChannelManagePolls,
+        ChannelManagePredictions,
+        ChannelManageRedemptions,
+        ChannelManageSchedule,
+        ChannelManageVideos,
+        ChannelReadEditors,
+        ChannelReadGoals,
+        ChannelReadPolls,
+        ChannelReadPredictions,
+        ChannelReadRedemptions,
+        ChannelReadSubscriptions,
+        ModerationRead,
+        UserEditBroadcast,
+        UserManageBlockedUsers,
+        UserReadBroadcast,
+        UserReadEmail,
+        UserReadFollows,
+        UserReadSubscriptions;
+        ChannelManageVips => "channel:manage:vips",
+        ChannelReadCharity => "channel:read:charity",
+        ChannelReadVips => "channel:read:vips",
+        ModeratorManageAnnouncements => "moderator:manage:announcements",
+        ModeratorManageAutomodSettings => "moderator:manage:automod_settings",
+        ModeratorManageBlockedTerms => "moderator:manage:blocked_terms",
+        ModeratorManageShoutouts => "moderator:manage:shoutouts",
+        ModeratorReadChatters => "moderator:read:chatters",
+        ModeratorReadFollowers => "moderator:read:followers",
+        UserManageWhispers => "user:manage:whispers",
+        UserReadModeratedChannels => "user:read:moderated_channels",
+    ];
+}
+
+macro_rules! scoped_requests {
+    ($($request:path => $scope:ident),* $(,)?) => {
+        $(
+            #[allow(deprecated)]
+            impl ScopedRequest for $request {
+                type RequiredScope = scopes::$scope;
+            }
+        )*
+
+        #[cfg(test)]
+        #[test]
+        #[allow(deprecated)]
+        fn required_scope_matches_scope() {
+            $(
+                assert_eq!(
+                    <$request as Request>::SCOPE,
+                    [<scopes::$scope as ScopeMarker>::SCOPE],
+                    "{}",
+                    stringify!($request)
+                );
+            )*
+        }
+
+        /// Names of the requests implementing [`ScopedRequest`] with a scope
+        #[cfg(test)]
+        fn scoped_requests() -> Vec<&'static str> { vec![$(stringify!($request)),*] }
+    };
+}
+
+macro_rules! unscoped_requests {
+    ($($(#[$meta:meta])* $request:path),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            #[allow(deprecated)]
+            impl ScopedRequest for $request {
+                type RequiredScope = NoScope;
+            }
+        )*
+
+        #[cfg(test)]
+        #[test]
+        #[allow(deprecated)]
+        fn unscoped_requests_have_no_scope() {
+            $(
+                $(#[$meta])*
+                assert!(<$request as Request>::SCOPE.is_empty(), "{}", stringify!($request));
+            )*
+        }
+
+        /// Names of the requests implementing [`ScopedRequest`] with [`NoScope`]
+        #[cfg(test)]
+        fn unscoped_requests() -> Vec<&'static str> {
+            let mut requests = vec![];
+            $(
+                $(#[$meta])*
+                requests.push(stringify!($request));
+            )*
+            requests
+        }
+    };
+}
+
+scoped_requests! {
+    super::channels::add_channel_vip::AddChannelVipRequest => ChannelManageVips,
+    super::channels::get_channel_editors::GetChannelEditorsRequest => ChannelReadEditors,
+    super::channels::get_vips::GetVipsRequest => ChannelReadVips,
+    super::channels::modify_channel_information::ModifyChannelInformationRequest => UserEditBroadcast,
+    super::channels::remove_channel_vip::RemoveChannelVipRequest => ChannelManageVips,
+    super::channels::start_commercial::StartCommercialRequest => ChannelEditCommercial,
+    super::charity::get_charity_campaign_donations::GetCharityCampaignDonationsRequest => ChannelReadCharity,
+    super::chat::get_chatters::GetChattersRequest => ModeratorReadChatters,
+    super::chat::send_a_shoutout::SendAShoutoutRequest => ModeratorManageShoutouts,
+    super::chat::send_chat_announcement::SendChatAnnouncementRequest => ModeratorManageAnnouncements,
+    super::goals::get_creator_goals::GetCreatorGoalsRequest => ChannelReadGoals,
+    super::moderation::add_blocked_term::AddBlockedTermRequest => ModeratorManageBlockedTerms,
+    super::moderation::check_automod_status::CheckAutoModStatusRequest => ModerationRead,
+    super::moderation::get_banned_events::GetBannedEventsRequest => ModerationRead,
+    super::moderation::get_banned_users::GetBannedUsersRequest => ModerationRead,
+    super::moderation::get_moderated_channels::GetModeratedChannelsRequest => UserReadModeratedChannels,
+    super::moderation::get_moderator_events::GetModeratorEventsRequest => ModerationRead,
+    super::moderation::get_moderators::GetModeratorsRequest => ModerationRead,
+    super::moderation::manage_held_automod_messages::ManageHeldAutoModMessagesRequest => ModerationRead,
+    super::moderation::remove_blocked_term::RemoveBlockedTermRequest => ModeratorManageBlockedTerms,
+    super::moderation::update_automod_settings::UpdateAutoModSettingsRequest => ModeratorManageAutomodSettings,
+    super::points::create_custom_rewards::CreateCustomRewardRequest => ChannelManageRedemptions,
+    super::points::delete_custom_reward::DeleteCustomRewardRequest => ChannelManageRedemptions,
+    super::points::get_custom_reward::GetCustomRewardRequest => ChannelReadRedemptions,
+    super::points::get_custom_reward_redemption::GetCustomRewardRedemptionRequest => ChannelReadRedemptions,
+    super::points::update_custom_reward::UpdateCustomRewardRequest => ChannelManageRedemptions,
+    super::points::update_redemption_status::UpdateRedemptionStatusRequest => ChannelManageBroadcast,
+    super::polls::create_poll::CreatePollRequest => ChannelManagePolls,
+    super::polls::end_poll::EndPollRequest => ChannelManagePolls,
+    super::polls::get_polls::GetPollsRequest => ChannelReadPolls,
+    super::predictions::create_prediction::CreatePredictionRequest => ChannelManagePredictions,
+    super::predictions::end_prediction::EndPredictionRequest => ChannelManagePredictions,
+    super::predictions::get_predictions::GetPredictionsRequest => ChannelReadPredictions,
+    super::schedule::create_channel_stream_schedule_segment::CreateChannelStreamScheduleSegmentRequest => ChannelManageSchedule,
+    super::schedule::update_channel_stream_schedule::UpdateChannelStreamScheduleRequest => ChannelManageSchedule,
+    super::schedule::update_channel_stream_schedule_segment::UpdateChannelStreamScheduleSegmentRequest => ChannelManageSchedule,
+    super::streams::get_followed_streams::GetFollowedStreamsRequest => UserReadFollows,
+    super::streams::get_stream_markers::GetStreamMarkersRequest => UserReadBroadcast,
+    super::streams::replace_stream_tags::ReplaceStreamTagsRequest => ChannelManageBroadcast,
+    super::subscriptions::check_user_subscription::CheckUserSubscriptionRequest => UserReadSubscriptions,
+    super::subscriptions::get_broadcaster_subscriptions::GetBroadcasterSubscriptionsRequest => ChannelReadSubscriptions,
+    super::subscriptions::get_broadcaster_subscriptions_events::GetBroadcasterSubscriptionsEventsRequest => ChannelReadSubscriptions,
+    super::users::block_user::BlockUserRequest => UserManageBlockedUsers,
+    super::users::get_user_extensions::GetUserExtensionsRequest => UserReadBroadcast,
+    super::users::unblock_user::UnblockUserRequest => UserManageBlockedUsers,
+    super::videos::delete_videos::DeleteVideosRequest => ChannelManageVideos,
+    super::whispers::send_whisper::SendWhisperRequest => UserManageWhispers,
+}
+
+unscoped_requests! {
+    super::bits::get_bits_leaderboard::GetBitsLeaderboardRequest,
+    super::bits::get_cheermotes::GetCheermotesRequest,
+    super::channels::get_channel_followers::GetChannelFollowersRequest,
+    super::channels::get_channel_information::GetChannelInformationRequest,
+    super::chat::get_channel_chat_badges::GetChannelChatBadgesRequest,
+    super::chat::get_channel_emotes::GetChannelEmotesRequest,
+    super::chat::get_chat_settings::GetChatSettingsRequest,
+    super::chat::get_emote_sets::GetEmoteSetsRequest,
+    super::chat::get_global_chat_badges::GetGlobalChatBadgesRequest,
+    super::chat::get_global_emotes::GetGlobalEmotesRequest,
+    super::clips::get_clips::GetClipsRequest,
+    #[cfg(feature = "eventsub")]
+    super::eventsub::delete_eventsub_subscription::DeleteEventSubSubscriptionRequest,
+    #[cfg(feature = "eventsub")]
+    super::eventsub::get_eventsub_subscriptions::GetEventSubSubscriptionsRequest,
+    super::extensions::create_extension_secret::CreateExtensionSecretRequest,
+    super::extensions::get_extension_bits_products::GetExtensionBitsProductsRequest,
+    super::extensions::get_extension_configuration_segment::GetExtensionConfigurationSegmentRequest,
+    super::extensions::get_extension_secrets::GetExtensionSecretsRequest,
+    super::extensions::get_extension_transactions::GetExtensionTransactionsRequest,
+    super::extensions::set_extension_configuration_segment::SetExtensionConfigurationSegmentRequest,
+    super::extensions::set_extension_required_configuration::SetExtensionRequiredConfigurationRequest,
+    super::extensions::update_extension_bits_product::UpdateExtensionBitsProductRequest,
+    super::games::get_games::GetGamesRequest,
+    super::games::get_top_games::GetTopGamesRequest,
+    super::hypetrain::get_hypetrain_events::GetHypeTrainEventsRequest,
+    super::schedule::get_channel_stream_schedule::GetChannelStreamScheduleRequest,
+    super::search::search_categories::SearchCategoriesRequest,
+    super::search::search_channels::SearchChannelsRequest,
+    super::streams::get_stream_tags::GetStreamTagsRequest,
+    super::streams::get_streams::GetStreamsRequest,
+    super::tags::get_all_stream_tags::GetAllStreamTagsRequest,
+    super::teams::get_channel_teams::GetChannelTeamsRequest,
+    super::teams::get_teams::GetTeamsRequest,
+    super::users::get_user_block_list::GetUserBlockListRequest,
+    super::users::get_users::GetUsersRequest,
+    super::users::get_users_follows::GetUsersFollowsRequest,
+    super::videos::get_videos::GetVideosRequest,
+}
+
+#[cfg(feature = "eventsub")]
+impl<E: crate::eventsub::EventSubscription> ScopedRequest
+    for super::eventsub::create_eventsub_subscription::CreateEventSubSubscriptionRequest<E>
+{
+    type RequiredScope = NoScope;
+}
+
+impl OptScopedRequest for super::users::get_users::GetUsersRequest {
+    type OptionalScope = scopes::UserReadEmail;
+}
+
+impl OptScopedRequest for super::channels::get_channel_followers::GetChannelFollowersRequest {
+    type OptionalScope = scopes::ModeratorReadFollowers;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_has_scope<S: HasScope<R, I>, R, I>() {}
+
+    #[test]
+    fn has_scope() {
+        assert_has_scope::<(scopes::ModerationRead,), scopes::ModerationRead, _>();
+        assert_has_scope::<
+            (
+                scopes::UserReadFollows,
+                scopes::ModerationRead,
+                scopes::ChannelReadPolls,
+            ),
+            scopes::ChannelReadPolls,
+            _,
+        >();
+        assert_has_scope::<(scopes::UserReadFollows,), NoScope, _>();
+        assert_eq!(
+            <(scopes::UserReadFollows, scopes::ModerationRead)>::scopes(),
+            [Scope::UserReadFollows, Scope::ModerationRead]
+        );
+    }
+
+    #[test]
+    fn optional_scope_matches_opt_scope() {
+        use crate::helix::{channels::GetChannelFollowersRequest, users::GetUsersRequest};
+
+        assert!(GetUsersRequest::OPT_SCOPE
+            .contains(&<GetUsersRequest as OptScopedRequest>::OptionalScope::SCOPE));
+        assert!(GetChannelFollowersRequest::OPT_SCOPE
+            .contains(&<GetChannelFollowersRequest as OptScopedRequest>::OptionalScope::SCOPE));
+    }
+
+    #[test]
+    fn all_endpoints_are_scoped() {
+        let mut requests: Vec<_> = scoped_requests()
+            .into_iter()
+            .chain(unscoped_requests())
+            .map(|path| path.rsplit("::").next().unwrap().trim())
+            .collect();
+        #[cfg(feature = "eventsub")]
+        requests.push("CreateEventSubSubscriptionRequest");
+
+        for endpoint in crate::helix::endpoints::ALL {
+            assert!(
+                requests.contains(&endpoint.request),
+                "{} ({}) is missing from scoped_requests! or unscoped_requests!",
+                endpoint.name,
+                endpoint.request
+            );
+        }
+    }
+}
@@ -44,20 +44,16 @@ use helix::RequestGet;
 ///
 /// [`search-categories`](https://dev.twitch.tv/docs/api/reference#search-categories)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct SearchCategoriesRequest {
     /// URI encoded search query
     #[builder(setter(into))]
     pub query: String,
-    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
     #[builder(default)]
-    pub after: Option<helix::Cursor>,
-    /// Cursor for backward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
-    #[builder(default)]
-    pub before: Option<helix::Cursor>,
-    /// Number of values to be returned per page. Limit: 100. Default: 20.
-    #[builder(setter(into), default)]
-    pub first: Option<String>,
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
 }
 
 /// Return Values for [Search Categories](super::search_categories)
@@ -68,9 +64,12 @@ pub type Category = types::TwitchCategory;
 impl Request for SearchCategoriesRequest {
     type Response = Vec<Category>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "search/categories";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for SearchCategoriesRequest {
@@ -79,17 +78,19 @@ impl RequestGet for SearchCategoriesRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestGetError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
         let response: helix::InnerResponse<Option<Self::Response>> =
-            helix::parse_json(response, true).map_err(|e| {
-                helix::HelixRequestGetError::DeserializeError(
+            helix::parse_json_owned(response, true).map_err(|e| {
+                helix::HelixRequestError::DeserializeError(
                     response.to_string(),
                     e,
+                    Self::METHOD,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         Ok(helix::Response {
@@ -98,12 +99,14 @@ impl RequestGet for SearchCategoriesRequest {
             request,
             total: response.total,
             other: None,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
 
 impl helix::Paginated for SearchCategoriesRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
 }
 
 #[cfg(test)]
@@ -113,26 +116,9 @@ fn test_request() {
     let req = SearchCategoriesRequest::builder().query("fort").build();
 
     // From twitch docs
-    let data = br#"
-{
-    "data": [
-        {
-            "id": "33214",
-            "name": "Fortnite",
-            "box_art_url": "https://static-cdn.jtvnw.net/ttv-boxart/Fortnite-{width}x{height}.jpg"
-        },
-        {
-            "id": "33214",
-            "name": "Fortnite",
-            "box_art_url": "https://static-cdn.jtvnw.net/ttv-boxart/Fortnite-{width}x{height}.jpg"
-        }
-    ],
-    "pagination": {
-        "cursor": "eyJiIjpudWxsLCJhIjp7IkN"
-    }
-}
-"#
-    .to_vec();
+    let data = crate::fixtures::helix::search::SEARCH_CATEGORIES
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -44,17 +44,18 @@ use helix::RequestGet;
 ///
 /// [`search-channels`](https://dev.twitch.tv/docs/api/reference#search-channels)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct SearchChannelsRequest {
     /// URL encoded search query
     #[builder(setter(into))]
     pub query: String,
-    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
+    ///
+    /// Backward pagination with [`before`](helix::PaginationParams::before) is not supported.
     #[builder(default)]
-    pub after: Option<helix::Cursor>,
-    /// Maximum number of objects to return. Maximum: 100 Default: 20
-    #[builder(default)] // FIXME: No setter because int
-    pub first: Option<usize>,
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
     /// Filter results for live streams only. Default: false
     #[builder(default, setter(into))]
     pub live_only: Option<bool>,
@@ -64,6 +65,11 @@ pub struct SearchChannelsRequest {
 ///
 /// [`search-channels`](https://dev.twitch.tv/docs/api/reference#search-channels)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Channel {
@@ -76,7 +82,7 @@ pub struct Channel {
     /// Display name corresponding to user_id
     pub display_name: types::DisplayName,
     /// Channel language (Broadcaster Language field from the [Channels service][crate::helix::channels])
-    pub broadcaster_language: String,
+    pub broadcaster_language: types::RawLanguage,
     /// Login of the broadcaster.
     pub broadcaster_login: types::UserName,
     /// channel title
@@ -93,21 +99,25 @@ pub struct Channel {
     pub started_at: Option<types::Timestamp>,
     // FIXME: Twitch doc say tag_ids
     /// Shows tag IDs that apply to the stream (live only).See <https://www.twitch.tv/directory/all/tags> for tag types
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub tag_ids: Vec<types::TagId>,
 }
 
 impl Request for SearchChannelsRequest {
     type Response = Vec<Channel>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "search/channels";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for SearchChannelsRequest {}
 
 impl helix::Paginated for SearchChannelsRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
 }
 
 #[cfg(test)]
@@ -117,23 +127,42 @@ fn test_request() {
     let req = SearchChannelsRequest::builder().query("fort").build();
 
     // From twitch docs
+    let data = crate::fixtures::helix::search::SEARCH_CHANNELS
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/search/channels?query=fort"
+    );
+
+    dbg!(SearchChannelsRequest::parse_response(Some(req), &uri, http_response).unwrap());
+}
+
+#[cfg(test)]
+#[test]
+fn test_request_null_tag_ids() {
+    use helix::*;
+    let req = SearchChannelsRequest::builder().query("fort").build();
+
     let data = br#"
     {
         "data": [
           {
-            "broadcaster_language": "en",
+            "broadcaster_language": "",
             "broadcaster_login": "a_seagull",
             "display_name": "A_Seagull",
             "game_id": "506442",
             "game_name": "DOOM Eternal",
             "id": "19070311",
-            "is_live": true,
-            "tag_ids": [
-              "6ea6bca4-4712-4ab9-a906-e3336a9d8039"
-            ],
+            "is_live": false,
+            "tag_ids": null,
             "thumbnail_url": "https://static-cdn.jtvnw.net/jtv_user_pictures/a_seagull-profile_image-4d2d235688c7dc66-300x300.png",
             "title": "a_seagull",
-            "started_at": "2020-03-18T17:56:00Z"
+            "started_at": ""
           }
         ],
         "pagination": {}
@@ -144,10 +173,8 @@ fn test_request() {
     let http_response = http::Response::builder().body(data).unwrap();
 
     let uri = req.get_uri().unwrap();
-    assert_eq!(
-        uri.to_string(),
-        "https://api.twitch.tv/helix/search/channels?query=fort"
-    );
-
-    dbg!(SearchChannelsRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    let response =
+        dbg!(SearchChannelsRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    assert!(response.data[0].tag_ids.is_empty());
+    assert_eq!(response.data[0].broadcaster_language.as_str(), "");
 }
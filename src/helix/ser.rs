@@ -272,10 +272,10 @@ impl<'input, 'output> ser::SerializeMap for MapSerializer<'input, 'output> {
         K: serde::Serialize,
         V: serde::Serialize,
     {
-        self.urlencoder.append_pair(
-            &key.serialize(ValueSerializer)?,
-            &value.serialize(ValueSerializer)?,
-        );
+        value.serialize(FieldSerializer {
+            key: &key.serialize(ValueSerializer)?,
+            urlencoder: self.urlencoder,
+        })?;
         Ok(())
     }
 
@@ -318,12 +318,12 @@ impl<'input, 'output> ser::SerializeStruct for StructSerializer<'input, 'output>
     fn end(self) -> Result<Self::Ok, Self::Error> { Ok(self.urlencoder) }
 }
 
-pub struct FieldSerializer<'input, 'output> {
-    key: &'static str,
+pub struct FieldSerializer<'key, 'input, 'output> {
+    key: &'key str,
     urlencoder: &'output mut UrlEncodedSerializer<'input, String>,
 }
 
-impl<'input, 'output> ser::Serializer for FieldSerializer<'input, 'output> {
+impl<'key, 'input, 'output> ser::Serializer for FieldSerializer<'key, 'input, 'output> {
     type Error = Error;
     type Ok = &'output mut UrlEncodedSerializer<'input, String>;
     type SerializeMap = MapSerializer<'input, 'output>;
@@ -539,7 +539,7 @@ impl<'input, 'output> ser::Serializer for FieldSerializer<'input, 'output> {
     }
 }
 
-impl<'input, 'output> ser::SerializeSeq for FieldSerializer<'input, 'output> {
+impl<'key, 'input, 'output> ser::SerializeSeq for FieldSerializer<'key, 'input, 'output> {
     type Error = Error;
     type Ok = &'output mut UrlEncodedSerializer<'input, String>;
 
@@ -555,7 +555,7 @@ impl<'input, 'output> ser::SerializeSeq for FieldSerializer<'input, 'output> {
     fn end(self) -> Result<Self::Ok, Self::Error> { Ok(self.urlencoder) }
 }
 
-impl<'input, 'output> ser::SerializeTuple for FieldSerializer<'input, 'output> {
+impl<'key, 'input, 'output> ser::SerializeTuple for FieldSerializer<'key, 'input, 'output> {
     type Error = Error;
     type Ok = &'output mut UrlEncodedSerializer<'input, String>;
 
@@ -571,12 +571,12 @@ impl<'input, 'output> ser::SerializeTuple for FieldSerializer<'input, 'output> {
     fn end(self) -> Result<Self::Ok, Self::Error> { Ok(self.urlencoder) }
 }
 
-struct PairSerializer<'input, 'output> {
-    key: &'static str,
+struct PairSerializer<'key, 'input, 'output> {
+    key: &'key str,
     urlencoder: &'output mut UrlEncodedSerializer<'input, String>,
 }
 
-impl<'input, 'output> ser::Serializer for PairSerializer<'input, 'output> {
+impl<'key, 'input, 'output> ser::Serializer for PairSerializer<'key, 'input, 'output> {
     type Error = Error;
     type Ok = &'output mut UrlEncodedSerializer<'input, String>;
     type SerializeMap = Impossible<Self::Ok, Error>;
@@ -763,7 +763,7 @@ impl<'input, 'output> ser::Serializer for PairSerializer<'input, 'output> {
     }
 }
 
-impl<'input, 'output> ser::SerializeTuple for PairSerializer<'input, 'output> {
+impl<'key, 'input, 'output> ser::SerializeTuple for PairSerializer<'key, 'input, 'output> {
     type Error = Error;
     type Ok = &'output mut UrlEncodedSerializer<'input, String>;
 
@@ -980,3 +980,88 @@ fn serialize_query() {
         "filter=1&possibly=sure+thing&ids=2&ids=3&ids2=4&stuff=32&stuff=-35&stuff=ha&1=one&2=two&username=justintv&variant=hello&variant2=world&num=123"
     )
 }
+
+#[cfg(test)]
+#[test]
+fn serialize_flattened_query() {
+    #[derive(serde::Serialize)]
+    struct Request {
+        ids: Vec<crate::types::UserId>,
+        #[serde(flatten)]
+        pagination: super::PaginationParams,
+        maybe: Option<String>,
+    }
+
+    let req = Request {
+        ids: vec!["2".into(), "3".into()],
+        pagination: super::PaginationParams::default()
+            .with_before(crate::helix::Cursor::from("abc"))
+            .with_first(super::PageSize::new(50).unwrap()),
+        maybe: None,
+    };
+    assert_eq!(to_string(req).unwrap(), "ids=2&ids=3&before=abc&first=50")
+}
+
+#[cfg(all(test, feature = "arbitrary", feature = "helix"))]
+mod arbitrary_tests {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    /// Deterministic input for [`Arbitrary`], so failures are reproducible
+    fn bytes(seed: u64) -> Vec<u8> {
+        let mut state = seed;
+        (0..4096)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1_442_695_040_888_963_407);
+                (state >> 56) as u8
+            })
+            .collect()
+    }
+
+    fn values<T: for<'a> Arbitrary<'a>>() -> Vec<T> {
+        (0..100)
+            .map(|seed| T::arbitrary(&mut Unstructured::new(&bytes(seed))).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn query_roundtrip() {
+        use crate::helix::users::GetUsersRequest;
+        for req in values::<GetUsersRequest>() {
+            let query = super::to_string(&req).unwrap();
+            let (mut id, mut login) = (vec![], vec![]);
+            for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+                match &*key {
+                    "id" => id.push(crate::types::UserId::from(value.into_owned())),
+                    "login" => login.push(crate::types::UserName::from(value.into_owned())),
+                    _ => panic!("unexpected key {:?} in {:?}", key, query),
+                }
+            }
+            assert_eq!((id, login), (req.id, req.login), "{}", query);
+        }
+        for req in values::<crate::helix::streams::GetStreamsRequest>() {
+            super::to_string(&req).unwrap();
+        }
+    }
+
+    fn json_roundtrip<T>()
+    where T: for<'a> Arbitrary<'a>
+            + serde::Serialize
+            + serde::de::DeserializeOwned
+            + PartialEq
+            + std::fmt::Debug {
+        for value in values::<T>() {
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(serde_json::from_str::<T>(&json).unwrap(), value, "{}", json);
+        }
+    }
+
+    #[test]
+    fn json() {
+        json_roundtrip::<crate::helix::users::User>();
+        json_roundtrip::<crate::helix::users::GetUsersRequest>();
+        json_roundtrip::<crate::helix::streams::Stream>();
+        json_roundtrip::<crate::helix::streams::GetStreamsRequest>();
+    }
+}
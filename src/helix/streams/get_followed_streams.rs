@@ -44,20 +44,16 @@ use helix::RequestGet;
 ///
 /// [`get-followed-streams`](https://dev.twitch.tv/docs/api/reference#get-followed-streams)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetFollowedStreamsRequest {
     /// Returns streams broadcast by one or more specified user IDs. You can specify up to 100 IDs.
     #[builder(setter(into))]
     pub user_id: types::UserId,
-    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
     #[builder(default)]
-    pub after: Option<helix::Cursor>,
-    /// Cursor for backward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
-    #[builder(default)]
-    pub before: Option<helix::Cursor>,
-    /// Maximum number of objects to return. Maximum: 100. Default: 20.
-    #[builder(default)]
-    pub first: Option<usize>,
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
 }
 
 /// Return Values for [Get Followed Streams](super::get_followed_streams)
@@ -68,15 +64,18 @@ pub type GetFollowedStreamsResponse = Stream;
 impl Request for GetFollowedStreamsRequest {
     type Response = Vec<GetFollowedStreamsResponse>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "streams/followed";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserReadFollows];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for GetFollowedStreamsRequest {}
 
 impl helix::Paginated for GetFollowedStreamsRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
 }
 
 #[cfg(test)]
@@ -88,51 +87,8 @@ fn test_request() {
         .build();
 
     // From twitch docs.
-    let data = br#"
-{
-    "data": [
-        {
-            "id": "26007494656",
-            "user_id": "23161357",
-            "user_name": "LIRIK",
-            "user_login": "lirik",
-            "game_id": "417752",
-            "game_name": "Talk Shows & Podcasts",
-            "type": "live",
-            "title": "Hey Guys, It's Monday - Twitter: @Lirik",
-            "viewer_count": 32575,
-            "started_at": "2017-08-14T16:08:32Z",
-            "language": "en",
-            "thumbnail_url": "https://static-cdn.jtvnw.net/previews-ttv/live_user_lirik-{width}x{height}.jpg",
-            "tag_ids":  [
-                "6ea6bca4-4712-4ab9-a906-e3336a9d8039"
-            ],
-            "is_mature": false
-        },
-        {
-            "id": "26007494656",
-            "user_id": "23161357",
-            "user_name": "LIRIK",
-            "user_login": "lirik",
-            "game_id": "417752",
-            "game_name": "Talk Shows & Podcasts",
-            "type": "live",
-            "title": "Hey Guys, It's Monday - Twitter: @Lirik",
-            "viewer_count": 32575,
-            "started_at": "2017-08-14T16:08:32Z",
-            "language": "en",
-            "thumbnail_url": "https://static-cdn.jtvnw.net/previews-ttv/live_user_lirik-{width}x{height}.jpg",
-            "tag_ids":  [
-                "6ea6bca4-4712-4ab9-a906-e3336a9d8039"
-            ],
-            "is_mature": false
-        }
-    ],
-    "pagination": {
-        "cursor": "eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6MjB9fQ=="
-    }
-}
-"#
+    let data = crate::fixtures::helix::streams::GET_FOLLOWED_STREAMS
+        .as_bytes()
         .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
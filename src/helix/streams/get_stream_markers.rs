@@ -0,0 +1,183 @@
+//! Gets a list of markers from the user’s most recent stream or from the specified VOD/video.
+//! [`get-stream-markers`](https://dev.twitch.tv/docs/api/reference#get-stream-markers)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetStreamMarkersRequest]
+//!
+//! To use this endpoint, construct a [`GetStreamMarkersRequest`] with the [`GetStreamMarkersRequest::user_id()`] or [`GetStreamMarkersRequest::video_id()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::streams::get_stream_markers;
+//! let request = get_stream_markers::GetStreamMarkersRequest::video_id("1234");
+//! ```
+//!
+//! ## Response: [StreamMarkers]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, streams::get_stream_markers};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = get_stream_markers::GetStreamMarkersRequest::user_id("1234");
+//! let response: Vec<get_stream_markers::StreamMarkers> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetStreamMarkersRequest::parse_response(None, &request.get_uri(), response)`](GetStreamMarkersRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Stream Markers](super::get_stream_markers)
+///
+/// [`get-stream-markers`](https://dev.twitch.tv/docs/api/reference#get-stream-markers)
+///
+/// Exactly one of `user_id` and `video_id` has to be set, use [`GetStreamMarkersRequest::user_id`] or [`GetStreamMarkersRequest::video_id`].
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct GetStreamMarkersRequest {
+    /// ID of the broadcaster from whose stream markers are returned.
+    #[builder(default, setter(into))]
+    pub user_id: Option<types::UserId>,
+    /// ID of the VOD/video whose stream markers are returned.
+    #[builder(default, setter(into))]
+    pub video_id: Option<types::VideoId>,
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
+    #[builder(default)]
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
+}
+
+impl GetStreamMarkersRequest {
+    /// Get the markers of the most recent stream of a broadcaster
+    pub fn user_id(user_id: impl Into<types::UserId>) -> Self {
+        Self {
+            user_id: Some(user_id.into()),
+            video_id: None,
+            pagination: Default::default(),
+        }
+    }
+
+    /// Get the markers of a VOD/video
+    pub fn video_id(video_id: impl Into<types::VideoId>) -> Self {
+        Self {
+            user_id: None,
+            video_id: Some(video_id.into()),
+            pagination: Default::default(),
+        }
+    }
+}
+
+/// Return Values for [Get Stream Markers](super::get_stream_markers)
+///
+/// [`get-stream-markers`](https://dev.twitch.tv/docs/api/reference#get-stream-markers)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct StreamMarkers {
+    /// ID of the user whose markers are returned.
+    pub user_id: types::UserId,
+    /// Display name corresponding to user_id.
+    pub user_name: types::DisplayName,
+    /// Login corresponding to user_id.
+    pub user_login: types::UserName,
+    /// The videos containing markers.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
+    pub videos: Vec<VideoMarkers>,
+}
+
+/// Markers of a video, see [`StreamMarkers`]
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct VideoMarkers {
+    /// ID of the video.
+    pub video_id: types::VideoId,
+    /// The markers in the video.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
+    pub markers: Vec<StreamMarker>,
+}
+
+/// A marker in a stream or video
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct StreamMarker {
+    /// ID of the marker.
+    pub id: types::StreamMarkerId,
+    /// RFC3339 timestamp of the marker.
+    pub created_at: types::Timestamp,
+    /// Description of the marker.
+    pub description: String,
+    /// Relative offset (in seconds) of the marker, from the beginning of the stream.
+    pub position_seconds: i64,
+    /// A link to the stream with a query parameter that is a timestamp of the marker’s location.
+    #[serde(rename = "URL")]
+    pub url: String,
+}
+
+impl Request for GetStreamMarkersRequest {
+    type Response = Vec<StreamMarkers>;
+
+    const METHOD: http::Method = http::Method::GET;
+    const PATH: &'static str = "streams/markers";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserReadBroadcast];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
+}
+
+impl RequestGet for GetStreamMarkersRequest {}
+
+impl helix::Paginated for GetStreamMarkersRequest {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetStreamMarkersRequest::user_id("123");
+
+    // From twitch docs
+    let data = crate::fixtures::helix::streams::GET_STREAM_MARKERS
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/streams/markers?user_id=123"
+    );
+
+    let resp = GetStreamMarkersRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    let marker = &resp.data[0].videos[0].markers[0];
+    assert_eq!(marker.position_seconds, 244);
+    assert_eq!(marker.url, "https://twitch.tv/videos/456?t=0h4m06s");
+}
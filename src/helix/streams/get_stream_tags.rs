@@ -1,6 +1,11 @@
 //! Gets the list of tags for a specified stream (channel).
 //! [`get-stream-tags`](https://dev.twitch.tv/docs/api/reference#get-stream-tags)
 //!
+//! # Deprecated
+//!
+//! Tag IDs are deprecated by Twitch, channels now have freeform tags.
+//! Use [`ChannelInformation::tags`](helix::channels::ChannelInformation::tags) or [`HelixClient::get_channel_tags`](helix::HelixClient::get_channel_tags) instead.
+//!
 //! # Accessing the endpoint
 //!
 //! ## Request: [GetStreamTagsRequest]
@@ -44,7 +49,12 @@ use helix::RequestGet;
 ///
 /// [`get-stream-tags`](https://dev.twitch.tv/docs/api/reference#get-stream-tags)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
+#[deprecated(
+    since = "0.6.0",
+    note = "tag IDs are deprecated by Twitch, use `ChannelInformation::tags` or `HelixClient::get_channel_tags` instead"
+)]
 pub struct GetStreamTagsRequest {
     // FIXME: twitch docs sucks
     /// ID of the stream whose tags are going to be fetched
@@ -57,59 +67,32 @@ pub struct GetStreamTagsRequest {
 /// [`get-stream-tags`](https://dev.twitch.tv/docs/api/reference#get-stream-tags)
 pub type Tag = helix::tags::TwitchTag;
 
+#[allow(deprecated)]
 impl Request for GetStreamTagsRequest {
     type Response = Vec<Tag>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "streams/tags";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
 }
 
+#[allow(deprecated)]
 impl RequestGet for GetStreamTagsRequest {}
 
 #[cfg(test)]
 #[test]
+#[allow(deprecated)]
 fn test_request() {
     use helix::*;
     let req = GetStreamTagsRequest::builder()
-        .broadcaster_id("198704263".to_string())
+        .broadcaster_id("198704263")
         .build();
 
     // From twitch docs
-    let data = "\
-{\n\
-    \"data\": [\n\
-        {\n\
-            \"tag_id\": \"621fb5bf-5498-4d8f-b4ac-db4d40d401bf\",\n\
-            \"is_auto\": false,\n\
-            \"localization_names\": {\n\
-                \"bg-bg\": \"Завършване без продължаване\",\n\
-                \"cs-cz\": \"Na jeden z&aacute;tah\",\n\
-                \"da-dk\": \"Continue klaret\"\n\
-            },\n\
-            \"localization_descriptions\": {\n\
-                \"bg-bg\": \"За потоци с акцент върху завършване на аркадна игра с монети, в която не се използва продължаване\",\n\
-                \"cs-cz\": \"Pro vys&iacute;l&aacute;n&iacute; s důrazem na plněn&iacute; mincov&yacute;ch ark&aacute;dov&yacute;ch her bez použit&iacute; pokračov&aacute;n&iacute;.\",\n\
-                \"da-dk\": \"Til streams med v&aelig;gt p&aring; at gennemf&oslash;re et arkadespil uden at bruge continues\"\n\
-            }\n\
-        },\n\
-        {\n\
-            \"tag_id\": \"79977fb9-f106-4a87-a386-f1b0f99783dd\",\n\
-            \"is_auto\": false,\n\
-            \"localization_names\": {\n\
-                \"bg-bg\": \"PvE\",\n\
-                \"cs-cz\": \"PvE\"\n\
-            },\n\
-            \"localization_descriptions\": {\n\
-                \"bg-bg\": \"За потоци с акцент върху PVE геймплей\",\n\
-                \"cs-cz\": \"Pro vys&iacute;l&aacute;n&iacute; s důrazem na hratelnost \\\"hr&aacute;č vs. prostřed&iacute;\\\".\",\n\
-                \"da-dk\": \"Til streams med v&aelig;gt p&aring; spil, hvor det er spilleren mod omgivelserne.\"\n\
-            }\n\
-        }\n\
-    ]\n\
-}\n\
-"
-        .as_bytes().to_vec();
+    let data = crate::fixtures::helix::streams::GET_STREAM_TAGS
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
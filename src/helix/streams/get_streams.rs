@@ -44,23 +44,19 @@ use helix::RequestGet;
 ///
 /// [`get-streams`](https://dev.twitch.tv/docs/api/reference#get-streams)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetStreamsRequest {
-    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
     #[builder(default)]
-    pub after: Option<helix::Cursor>,
-    /// Cursor for backward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
-    #[builder(default)]
-    pub before: Option<helix::Cursor>,
-    /// Maximum number of objects to return. Maximum: 100. Default: 20.
-    #[builder(default)]
-    pub first: Option<usize>,
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
     /// Returns streams broadcasting a specified game ID. You can specify up to 10 IDs.
     #[builder(default)]
     pub game_id: Vec<types::CategoryId>,
     /// Stream language. You can specify up to 100 languages.
-    #[builder(default)]
-    pub language: Option<String>,
+    #[builder(default, setter(into))]
+    pub language: Option<types::Language>,
     /// Returns streams broadcast by one or more specified user IDs. You can specify up to 100 IDs.
     #[builder(default, setter(into))]
     pub user_id: Vec<types::UserId>,
@@ -73,6 +69,11 @@ pub struct GetStreamsRequest {
 ///
 /// [`get-streams`](https://dev.twitch.tv/docs/api/reference#get-streams)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Stream {
@@ -83,16 +84,21 @@ pub struct Stream {
     /// Stream ID.
     pub id: types::StreamId,
     /// Stream language.
-    pub language: String,
+    pub language: types::RawLanguage,
     /// Indicates if the broadcaster has specified their channel contains mature content that may be inappropriate for younger audiences.
     pub is_mature: bool,
     /// UTC timestamp.
     pub started_at: types::Timestamp,
     /// Shows tag IDs that apply to the stream.
-    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
+    ///
+    /// Twitch is replacing tag IDs with [`tags`](Stream::tags), this is empty if they are not returned.
+    #[serde(default, deserialize_with = "helix::deserialize_default_from_null")]
     pub tag_ids: Vec<types::TagId>,
+    /// Tags that apply to the stream, empty if they are not returned.
+    #[serde(default, deserialize_with = "helix::deserialize_default_from_null")]
+    pub tags: Vec<String>,
     /// Thumbnail URL of the stream. All image URLs have variable width and height. You can replace {width} and {height} with any values to get that size image
-    pub thumbnail_url: String,
+    pub thumbnail_url: types::TemplatedImageUrl,
     /// Stream title.
     pub title: String,
     /// Stream type: "live" or "" (in case of error).
@@ -108,18 +114,38 @@ pub struct Stream {
     pub viewer_count: usize,
 }
 
+impl Stream {
+    /// URL of the thumbnail of the stream in this size
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # let stream: twitch_api2::helix::streams::Stream = serde_json::from_str(r#"{"id":"26007494656","user_id":"23161357","user_name":"LIRIK","user_login":"lirik","game_id":"417752","game_name":"Talk Shows & Podcasts","type":"live","title":"Hey Guys, It's Monday - Twitter: @Lirik","viewer_count":32575,"started_at":"2017-08-14T16:08:32Z","language":"en","thumbnail_url":"https://static-cdn.jtvnw.net/previews-ttv/live_user_lirik-{width}x{height}.jpg","tag_ids":[],"is_mature":false}"#).unwrap();
+    /// assert_eq!(
+    ///     stream.thumbnail(1280, 720),
+    ///     "https://static-cdn.jtvnw.net/previews-ttv/live_user_lirik-1280x720.jpg"
+    /// );
+    /// ```
+    pub fn thumbnail(&self, width: u32, height: u32) -> String {
+        self.thumbnail_url.render(width, height)
+    }
+}
+
 impl Request for GetStreamsRequest {
     type Response = Vec<Stream>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "streams";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for GetStreamsRequest {}
 
 impl helix::Paginated for GetStreamsRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
 }
 
 #[cfg(test)]
@@ -128,6 +154,25 @@ fn test_request() {
     use helix::*;
     let req = GetStreamsRequest::builder().build();
 
+    // From twitch docs, kinda. example 1 in https://dev.twitch.tv/docs/api/reference#get-streams is malformed
+    let data = crate::fixtures::helix::streams::GET_STREAMS
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(uri.to_string(), "https://api.twitch.tv/helix/streams?");
+
+    dbg!(GetStreamsRequest::parse_response(Some(req), &uri, http_response).unwrap());
+}
+
+#[cfg(test)]
+#[test]
+fn test_request_null_tags_issue184() {
+    use helix::*;
+    let req = GetStreamsRequest::builder().build();
+
     // From twitch docs, kinda. example 1 in https://dev.twitch.tv/docs/api/reference#get-streams is malformed
     let data = br#"
 {
@@ -145,27 +190,7 @@ fn test_request() {
             "started_at": "2017-08-14T16:08:32Z",
             "language": "en",
             "thumbnail_url": "https://static-cdn.jtvnw.net/previews-ttv/live_user_lirik-{width}x{height}.jpg",
-            "tag_ids":  [
-                "6ea6bca4-4712-4ab9-a906-e3336a9d8039"
-            ],
-            "is_mature": false
-        },
-        {
-            "id": "26007494656",
-            "user_id": "23161357",
-            "user_name": "LIRIK",
-            "user_login": "lirik",
-            "game_id": "417752",
-            "game_name": "Talk Shows & Podcasts",
-            "type": "live",
-            "title": "Hey Guys, It's Monday - Twitter: @Lirik",
-            "viewer_count": 32575,
-            "started_at": "2017-08-14T16:08:32Z",
-            "language": "en",
-            "thumbnail_url": "https://static-cdn.jtvnw.net/previews-ttv/live_user_lirik-{width}x{height}.jpg",
-            "tag_ids":  [
-                "6ea6bca4-4712-4ab9-a906-e3336a9d8039"
-            ],
+            "tag_ids":  null,
             "is_mature": false
         }
     ],
@@ -186,11 +211,10 @@ fn test_request() {
 
 #[cfg(test)]
 #[test]
-fn test_request_null_tags_issue184() {
+fn test_request_tags() {
     use helix::*;
     let req = GetStreamsRequest::builder().build();
 
-    // From twitch docs, kinda. example 1 in https://dev.twitch.tv/docs/api/reference#get-streams is malformed
     let data = br#"
 {
     "data": [
@@ -207,21 +231,24 @@ fn test_request_null_tags_issue184() {
             "started_at": "2017-08-14T16:08:32Z",
             "language": "en",
             "thumbnail_url": "https://static-cdn.jtvnw.net/previews-ttv/live_user_lirik-{width}x{height}.jpg",
-            "tag_ids":  null,
+            "tags": ["English", "Podcast"],
             "is_mature": false
         }
     ],
-    "pagination": {
-        "cursor": "eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6MjB9fQ=="
-    }
+    "pagination": {}
 }
 "#
-        .to_vec();
+    .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
     let uri = req.get_uri().unwrap();
-    assert_eq!(uri.to_string(), "https://api.twitch.tv/helix/streams?");
-
-    dbg!(GetStreamsRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    let response = GetStreamsRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    let stream = &response.data[0];
+    assert!(stream.tag_ids.is_empty());
+    assert_eq!(stream.tags, ["English", "Podcast"]);
+    assert_eq!(
+        stream.thumbnail(440, 248),
+        "https://static-cdn.jtvnw.net/previews-ttv/live_user_lirik-440x248.jpg"
+    );
 }
@@ -1,4 +1,5 @@
 #![doc(alias = "live")]
+#![allow(deprecated)]
 //! Helix endpoints regarding streams
 //!
 //! # Examples
@@ -29,6 +30,8 @@ use serde::{Deserialize, Serialize};
 #[doc(inline)]
 pub use get_followed_streams::GetFollowedStreamsRequest;
 #[doc(inline)]
+pub use get_stream_markers::{GetStreamMarkersRequest, StreamMarker, StreamMarkers, VideoMarkers};
+#[doc(inline)]
 pub use get_stream_tags::{GetStreamTagsRequest, Tag};
 #[doc(inline)]
 pub use get_streams::{GetStreamsRequest, Stream};
@@ -36,12 +39,22 @@ pub use get_streams::{GetStreamsRequest, Stream};
 pub use replace_stream_tags::{ReplaceStreamTags, ReplaceStreamTagsBody, ReplaceStreamTagsRequest};
 
 pub mod get_followed_streams;
+pub mod get_stream_markers;
 pub mod get_stream_tags;
 pub mod get_streams;
 pub mod replace_stream_tags;
+pub mod session;
+#[cfg(feature = "client")]
+#[cfg_attr(nightly, doc(cfg(feature = "client")))]
+pub mod watcher;
 
 /// Gotten from [`Stream.type_`](get_streams::Stream#structfield.type_)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[non_exhaustive]
 pub enum StreamType {
     /// Stream is live.
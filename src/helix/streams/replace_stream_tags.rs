@@ -65,6 +65,7 @@ use helix::RequestPut;
 ///
 /// [`replace-stream-tags`](https://dev.twitch.tv/docs/api/reference#replace-stream-tags)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct ReplaceStreamTagsRequest {
     /// ID of the stream for which tags are to be replaced.
@@ -78,29 +79,61 @@ pub struct ReplaceStreamTagsRequest {
 ///
 /// # Notes
 ///
-/// Up to five tags can be applied to a stream. If no `tag_ids` is provided, all tags are removed from the stream.
+/// Up to [five](MAX_TAGS) tags can be applied to a stream, more fail when creating the request. If no `tag_ids` is provided, all tags are removed from the stream and the body is sent empty, see [`ReplaceStreamTagsBody::clear`].
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct ReplaceStreamTagsBody {
     /// IDs of tags to be applied to the stream.
     #[builder(default, setter(into))]
     pub tag_ids: Vec<types::TagId>,
 }
+
+/// Maximum amount of tags that can be applied to a stream
+pub const MAX_TAGS: usize = 5;
+
+impl ReplaceStreamTagsBody {
+    /// Remove all tags from the stream
+    pub fn clear() -> Self { Self { tag_ids: vec![] } }
+}
+
+impl helix::HelixRequestBody for ReplaceStreamTagsBody {
+    fn try_to_body(&self) -> Result<Vec<u8>, helix::BodyError> {
+        if self.tag_ids.len() > MAX_TAGS {
+            return Err(helix::BodyError::Custom(
+                format!(
+                    "at most {} tags can be applied to a stream, got {}",
+                    MAX_TAGS,
+                    self.tag_ids.len()
+                )
+                .into(),
+            ));
+        }
+        if self.tag_ids.is_empty() {
+            return Ok(vec![]);
+        }
+        serde_json::to_vec(self).map_err(Into::into)
+    }
+}
 /// Return Values for [Replace Stream Tags](super::replace_stream_tags)
 ///
 /// [`replace-stream-tags`](https://dev.twitch.tv/docs/api/reference#replace-stream-tags)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[non_exhaustive]
 pub enum ReplaceStreamTags {
     /// 204 - Stream Tags replaced successfully
     Success,
 }
 
-impl helix::private::SealedSerialize for ReplaceStreamTagsBody {}
-
 impl Request for ReplaceStreamTagsRequest {
     type Response = ReplaceStreamTags;
 
+    const METHOD: http::Method = http::Method::PUT;
     const PATH: &'static str = "streams/tags";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelManageBroadcast];
@@ -114,7 +147,7 @@ impl RequestPut for ReplaceStreamTagsRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPutError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
@@ -125,12 +158,16 @@ impl RequestPut for ReplaceStreamTagsRequest {
                 request,
                 total: None,
                 other: <_>::default(),
+                request_id: None,
+                diagnostics: Default::default(),
             }),
-            _ => Err(helix::HelixRequestPutError::InvalidResponse {
+            _ => Err(helix::HelixRequestError::InvalidResponse {
                 reason: "unexpected status",
                 response: response.to_string(),
                 status,
+                method: Self::METHOD,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
@@ -165,3 +202,30 @@ fn test_request() {
 
     dbg!(ReplaceStreamTagsRequest::parse_response(Some(req), &uri, http_response).unwrap());
 }
+
+#[cfg(test)]
+#[test]
+fn test_body() {
+    use helix::*;
+    let req = ReplaceStreamTagsRequest::builder()
+        .broadcaster_id("0")
+        .build();
+
+    let request = req
+        .create_request(ReplaceStreamTagsBody::clear(), "token", "clientid")
+        .unwrap();
+    assert!(request.body().is_empty());
+
+    let body = ReplaceStreamTagsBody::builder()
+        .tag_ids(vec![
+            types::TagId::from(
+                "621fb5bf-5498-4d8f-b4ac-db4d40d401bf"
+            );
+            MAX_TAGS + 1
+        ])
+        .build();
+    assert!(matches!(
+        req.create_request(body, "token", "clientid"),
+        Err(CreateRequestError::SerializeError(BodyError::Custom(_)))
+    ));
+}
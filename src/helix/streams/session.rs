@@ -0,0 +1,205 @@
+//! A single broadcast of a channel, with its VOD and markers.
+//!
+//! A [`StreamSession`] is started by a [`stream.online`](crate::eventsub::stream::StreamOnlineV1) notification and ended by a [`stream.offline`](crate::eventsub::stream::StreamOfflineV1) notification.
+//! [`HelixClient::complete_stream_session`] finds the VOD archived from the stream and the markers in it.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! # let online: twitch_api2::eventsub::stream::StreamOnlineV1Payload = todo!();
+//! # let offline: twitch_api2::eventsub::stream::StreamOfflineV1Payload = todo!();
+//! # let message_timestamp: twitch_api2::types::Timestamp = todo!();
+//! use twitch_api2::helix::{self, streams::session::StreamSession};
+//!
+//! let mut session = StreamSession::from_online(&online);
+//! // ...
+//! session.end(&offline, message_timestamp);
+//! client.complete_stream_session(&mut session, &token).await?;
+//! println!("{:?} has {} markers", session.video_id, session.markers.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use super::*;
+
+/// A single broadcast of a channel, see the [module documentation](self)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct StreamSession {
+    /// ID of the broadcaster.
+    pub broadcaster_id: types::UserId,
+    /// ID of the stream.
+    pub stream_id: types::StreamId,
+    /// When the stream went online.
+    pub started_at: types::Timestamp,
+    /// When the stream went offline, `None` while it is live.
+    pub ended_at: Option<types::Timestamp>,
+    /// ID of the VOD archived from the stream, if it was found.
+    pub video_id: Option<types::VideoId>,
+    /// Markers in the VOD.
+    pub markers: Vec<StreamMarker>,
+}
+
+impl StreamSession {
+    /// Start a session for a stream
+    pub fn new(
+        broadcaster_id: impl Into<types::UserId>,
+        stream_id: impl Into<types::StreamId>,
+        started_at: impl Into<types::Timestamp>,
+    ) -> Self {
+        StreamSession {
+            broadcaster_id: broadcaster_id.into(),
+            stream_id: stream_id.into(),
+            started_at: started_at.into(),
+            ended_at: None,
+            video_id: None,
+            markers: vec![],
+        }
+    }
+
+    /// Start a session from a [`stream.online`](crate::eventsub::stream::StreamOnlineV1) notification
+    #[cfg(feature = "eventsub")]
+    #[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
+    pub fn from_online(online: &crate::eventsub::stream::StreamOnlineV1Payload) -> Self {
+        StreamSession::new(
+            online.broadcaster_user_id.clone(),
+            online.id.clone(),
+            online.started_at.clone(),
+        )
+    }
+
+    /// End the session with a [`stream.offline`](crate::eventsub::stream::StreamOfflineV1) notification, received at `ended_at`
+    ///
+    /// The notification itself has no timestamp, use the timestamp of the message. Returns `false` and does nothing if the notification is for another broadcaster.
+    #[cfg(feature = "eventsub")]
+    #[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
+    pub fn end(
+        &mut self,
+        offline: &crate::eventsub::stream::StreamOfflineV1Payload,
+        ended_at: impl Into<types::Timestamp>,
+    ) -> bool {
+        if offline.broadcaster_user_id != self.broadcaster_id {
+            return false;
+        }
+        self.ended_at = Some(ended_at.into());
+        true
+    }
+
+    /// Whether the stream is still live
+    pub fn is_live(&self) -> bool { self.ended_at.is_none() }
+
+    /// Associate the VOD archived from this stream, returns `false` and does nothing if `video` is from another stream
+    pub fn set_video(&mut self, video: &helix::videos::Video) -> bool {
+        if video.stream_id.as_ref() != Some(&self.stream_id) {
+            return false;
+        }
+        self.video_id = Some(video.id.clone());
+        true
+    }
+}
+
+#[cfg(feature = "client")]
+impl<'a, C: crate::HttpClient<'a>> helix::HelixClient<'a, C> {
+    /// Find the VOD of a [`StreamSession`] and the markers in it
+    ///
+    /// The VOD is searched in the 20 most recent archives of the broadcaster, and the first 100 markers are returned.
+    /// Does nothing if the broadcaster does not archive their streams. The token needs the `user:read:broadcast` scope for the markers.
+    pub async fn complete_stream_session<T>(
+        &'a self,
+        session: &mut StreamSession,
+        token: &T,
+    ) -> Result<(), helix::ClientRequestError<<C as crate::HttpClient<'a>>::Error>>
+    where
+        T: twitch_oauth2::TwitchToken + ?Sized,
+    {
+        if session.video_id.is_none() {
+            let req = helix::videos::GetVideosRequest::builder()
+                .user_id(session.broadcaster_id.clone())
+                .type_(helix::videos::VideoTypeFilter::Archive)
+                .build();
+            for video in self.req_get(req, token).await? {
+                if session.set_video(&video) {
+                    break;
+                }
+            }
+        }
+        if let Some(video_id) = session.video_id.clone() {
+            let mut req = GetStreamMarkersRequest::video_id(video_id.clone());
+            req.pagination.first = helix::PageSize::new(100).ok();
+            session.markers = self
+                .req_get(req, token)
+                .await?
+                .into_iter()
+                .flat_map(|markers| markers.videos)
+                .filter(|video| video.video_id == video_id)
+                .flat_map(|video| video.markers)
+                .collect();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "eventsub"))]
+#[test]
+fn test_stream_session() {
+    let online: crate::eventsub::stream::StreamOnlineV1Payload = serde_json::from_str(
+        r#"{
+            "id": "9001",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "type": "live",
+            "started_at": "2020-10-11T10:11:12.123Z"
+        }"#,
+    )
+    .unwrap();
+    let mut session = StreamSession::from_online(&online);
+    assert!(session.is_live());
+
+    let offline: crate::eventsub::stream::StreamOfflineV1Payload = serde_json::from_str(
+        r#"{
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User"
+        }"#,
+    )
+    .unwrap();
+    assert!(session.end(
+        &offline,
+        types::Timestamp::new("2020-10-11T12:11:12Z").unwrap()
+    ));
+    assert!(!session.is_live());
+
+    let video: helix::videos::Video = serde_json::from_str(
+        r#"{
+            "id": "335921245",
+            "stream_id": "9001",
+            "user_id": "1337",
+            "user_login": "cool_user",
+            "user_name": "Cool_User",
+            "title": "Stream",
+            "description": "",
+            "created_at": "2020-10-11T10:11:12Z",
+            "published_at": "2020-10-11T10:11:12Z",
+            "url": "https://www.twitch.tv/videos/335921245",
+            "thumbnail_url": "https://static-cdn.jtvnw.net/cf_vods/d2nvs31859zcd8/twitchdev/335921245/ce0f3a7f-57a3-4152-bc06-0c6610189fb3/thumb/index-0000000000-%{width}x%{height}.jpg",
+            "viewable": "public",
+            "view_count": 1863062,
+            "language": "en",
+            "type": "archive",
+            "duration": "2h0m0s",
+            "muted_segments": null
+        }"#,
+    )
+    .unwrap();
+    assert!(session.set_video(&video));
+    assert_eq!(
+        session.video_id.as_deref(),
+        Some(types::VideoIdRef::from_str("335921245"))
+    );
+}
@@ -0,0 +1,314 @@
+//! Watch channels for changes to their streams by polling [Get Streams](super::get_streams)
+//!
+//! This is a fallback for when [EventSub](https://dev.twitch.tv/docs/eventsub) can't be used, e.g. when there is no way to receive webhooks.
+//! Changes are found by comparing the streams of two polls, so changes that are undone between two polls are not noticed.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! use futures::TryStreamExt;
+//! use twitch_api2::helix::{self, streams::watcher::{StreamEvent, StreamWatcher}};
+//!
+//! let watcher = StreamWatcher::new(vec!["44445592".into(), "27620241".into()])
+//!     .with_interval(std::time::Duration::from_secs(120));
+//! let mut events = watcher.watch(&client, &token, tokio::time::sleep);
+//! while let Some(event) = events.try_next().await? {
+//!     match event {
+//!         StreamEvent::WentLive(stream) => println!("{} went live", stream.user_name),
+//!         StreamEvent::WentOffline(stream) => println!("{} went offline", stream.user_name),
+//!         _ => {}
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::Stream;
+use crate::{helix::HelixClient, types};
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Duration,
+};
+use twitch_oauth2::TwitchToken;
+
+type ClientError<'a, C> = crate::helix::ClientRequestError<<C as crate::HttpClient<'a>>::Error>;
+
+/// Default interval between polls
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Requests per minute a watcher is allowed to make, half of the default helix rate limit
+const REQUESTS_PER_MINUTE: u64 = 400;
+
+/// A change to a stream of a watched channel
+#[derive(PartialEq, Debug, Clone)]
+#[non_exhaustive]
+pub enum StreamEvent {
+    /// The channel went live
+    WentLive(Stream),
+    /// The channel went offline, holds the stream as it was last seen
+    WentOffline(Stream),
+    /// The title of the stream changed
+    TitleChanged {
+        /// Title before the change
+        old_title: String,
+        /// The stream after the change
+        stream: Stream,
+    },
+    /// The category of the stream changed
+    CategoryChanged {
+        /// ID of the category before the change
+        old_game_id: types::CategoryId,
+        /// Name of the category before the change
+        old_game_name: types::CategoryId,
+        /// The stream after the change
+        stream: Stream,
+    },
+}
+
+impl StreamEvent {
+    /// The stream this event is about
+    pub fn stream(&self) -> &Stream {
+        match self {
+            StreamEvent::WentLive(stream)
+            | StreamEvent::WentOffline(stream)
+            | StreamEvent::TitleChanged { stream, .. }
+            | StreamEvent::CategoryChanged { stream, .. } => stream,
+        }
+    }
+}
+
+/// Polls [Get Streams](super::get_streams) for a set of channels and yields [`StreamEvent`]s for changes.
+///
+/// The first poll only records which channels are live, changes are reported from the second poll onwards.
+#[derive(Debug, Clone)]
+pub struct StreamWatcher {
+    ids: Vec<types::UserId>,
+    interval: Duration,
+    streams: Option<HashMap<types::UserId, Option<Stream>>>,
+}
+
+impl StreamWatcher {
+    /// Watch the channels of these broadcaster ids, polling every [`DEFAULT_INTERVAL`]
+    pub fn new(ids: Vec<types::UserId>) -> Self {
+        StreamWatcher {
+            ids,
+            interval: DEFAULT_INTERVAL,
+            streams: None,
+        }
+    }
+
+    /// Set the interval between polls
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Interval between polls.
+    ///
+    /// This is the interval set with [`with_interval`](StreamWatcher::with_interval), but never so short that the watcher uses more than half of the default rate limit of helix.
+    pub fn interval(&self) -> Duration {
+        let requests = self.ids.len().saturating_sub(1) as u64 / 100 + 1;
+        self.interval
+            .max(Duration::from_secs(60 * requests) / REQUESTS_PER_MINUTE as u32)
+    }
+
+    /// Streams of the watched channels that were live at the last poll
+    pub fn live(&self) -> impl Iterator<Item = &Stream> + '_ {
+        self.streams
+            .iter()
+            .flat_map(|streams| streams.values())
+            .flatten()
+    }
+
+    /// Poll the streams of the watched channels once, returning the changes since the last poll.
+    pub async fn poll<'a, C, T>(
+        &mut self,
+        client: &'a HelixClient<'a, C>,
+        token: &T,
+    ) -> Result<Vec<StreamEvent>, ClientError<'a, C>>
+    where
+        C: crate::HttpClient<'a> + Sync,
+        T: TwitchToken + ?Sized,
+    {
+        let streams = client.are_live(&self.ids, token).await?;
+        let events = match &self.streams {
+            Some(old) => diff(&self.ids, old, &streams),
+            None => vec![],
+        };
+        self.streams = Some(streams);
+        Ok(events)
+    }
+
+    /// Poll continuously, sleeping for [`interval`](StreamWatcher::interval) between polls with the given `sleep` function.
+    ///
+    /// A failed poll yields the error, polling continues after it.
+    pub fn watch<'a, C, T, S, F>(
+        self,
+        client: &'a HelixClient<'a, C>,
+        token: &'a T,
+        sleep: S,
+    ) -> std::pin::Pin<Box<dyn futures::Stream<Item = Result<StreamEvent, ClientError<'a, C>>> + 'a>>
+    where
+        C: crate::HttpClient<'a> + Sync,
+        T: TwitchToken + ?Sized,
+        S: Fn(Duration) -> F + 'a,
+        F: std::future::Future<Output = ()> + 'a,
+    {
+        let state = (self, sleep, VecDeque::new(), true);
+        Box::pin(futures::stream::unfold(
+            state,
+            move |(mut watcher, sleep, mut pending, mut first)| async move {
+                loop {
+                    if let Some(event) = pending.pop_front() {
+                        return Some((Ok(event), (watcher, sleep, pending, first)));
+                    }
+                    if !first {
+                        sleep(watcher.interval()).await;
+                    }
+                    first = false;
+                    match watcher.poll(client, token).await {
+                        Ok(events) => pending.extend(events),
+                        Err(e) => return Some((Err(e), (watcher, sleep, pending, first))),
+                    }
+                }
+            },
+        ))
+    }
+}
+
+/// Find the changes between two polls
+fn diff(
+    ids: &[types::UserId],
+    old: &HashMap<types::UserId, Option<Stream>>,
+    new: &HashMap<types::UserId, Option<Stream>>,
+) -> Vec<StreamEvent> {
+    let mut events = vec![];
+    for id in ids {
+        match (
+            old.get(id).and_then(Option::as_ref),
+            new.get(id).and_then(Option::as_ref),
+        ) {
+            (None, Some(stream)) => events.push(StreamEvent::WentLive(stream.clone())),
+            (Some(stream), None) => events.push(StreamEvent::WentOffline(stream.clone())),
+            // the channel went offline and live again between polls
+            (Some(old), Some(stream)) if old.id != stream.id => {
+                events.push(StreamEvent::WentOffline(old.clone()));
+                events.push(StreamEvent::WentLive(stream.clone()));
+            }
+            (Some(old), Some(stream)) => {
+                if old.title != stream.title {
+                    events.push(StreamEvent::TitleChanged {
+                        old_title: old.title.clone(),
+                        stream: stream.clone(),
+                    });
+                }
+                if old.game_id != stream.game_id {
+                    events.push(StreamEvent::CategoryChanged {
+                        old_game_id: old.game_id.clone(),
+                        old_game_name: old.game_name.clone(),
+                        stream: stream.clone(),
+                    });
+                }
+            }
+            (None, None) => {}
+        }
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stream(id: &str, title: &str, game_id: &str) -> Stream {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "user_id": "23161357",
+            "user_name": "LIRIK",
+            "user_login": "lirik",
+            "game_id": game_id,
+            "game_name": "Talk Shows & Podcasts",
+            "type": "live",
+            "title": title,
+            "viewer_count": 32575,
+            "started_at": "2017-08-14T16:08:32Z",
+            "language": "en",
+            "thumbnail_url": "https://static-cdn.jtvnw.net/previews-ttv/live_user_lirik-{width}x{height}.jpg",
+            "tag_ids": [],
+            "is_mature": false
+        }))
+        .unwrap()
+    }
+
+    fn poll(stream: Option<Stream>) -> HashMap<types::UserId, Option<Stream>> {
+        vec![(types::UserId::from("23161357"), stream)]
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn diff_polls() {
+        let ids = [types::UserId::from("23161357")];
+        let live = stream("1", "title", "417752");
+
+        assert_eq!(
+            diff(&ids, &poll(None), &poll(Some(live.clone()))),
+            [StreamEvent::WentLive(live.clone())]
+        );
+        assert_eq!(
+            diff(&ids, &poll(Some(live.clone())), &poll(None)),
+            [StreamEvent::WentOffline(live.clone())]
+        );
+        assert!(diff(&ids, &poll(Some(live.clone())), &poll(Some(live.clone()))).is_empty());
+
+        let changed = stream("1", "new title", "509658");
+        let events = diff(
+            &ids,
+            &poll(Some(live.clone())),
+            &poll(Some(changed.clone())),
+        );
+        assert_eq!(
+            events,
+            [
+                StreamEvent::TitleChanged {
+                    old_title: "title".to_string(),
+                    stream: changed.clone(),
+                },
+                StreamEvent::CategoryChanged {
+                    old_game_id: "417752".into(),
+                    old_game_name: "Talk Shows & Podcasts".into(),
+                    stream: changed,
+                },
+            ]
+        );
+
+        let restarted = stream("2", "title", "417752");
+        assert_eq!(
+            diff(
+                &ids,
+                &poll(Some(live.clone())),
+                &poll(Some(restarted.clone()))
+            ),
+            [
+                StreamEvent::WentOffline(live),
+                StreamEvent::WentLive(restarted)
+            ]
+        );
+    }
+
+    #[test]
+    fn interval() {
+        let watcher = StreamWatcher::new(vec![]).with_interval(Duration::from_secs(1));
+        assert_eq!(watcher.interval(), Duration::from_secs(1));
+        let ids = (0..100_000)
+            .map(|i| types::UserId::from(i.to_string()))
+            .collect();
+        let watcher = StreamWatcher::new(ids).with_interval(Duration::from_secs(1));
+        assert_eq!(watcher.interval(), Duration::from_secs(150));
+    }
+}
@@ -43,6 +43,7 @@ use helix::RequestGet;
 ///
 /// [`check-user-subscription`](https://dev.twitch.tv/docs/api/reference#check-user-subscription)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct CheckUserSubscriptionRequest {
     /// User ID of the broadcaster. Must match the User ID in the Bearer token.
@@ -57,6 +58,11 @@ pub struct CheckUserSubscriptionRequest {
 ///
 /// [`check-user-subscription`](https://dev.twitch.tv/docs/api/reference#check-user-subscription)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct UserSubscription {
@@ -79,6 +85,7 @@ pub struct UserSubscription {
 impl Request for CheckUserSubscriptionRequest {
     type Response = UserSubscription;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "subscriptions/user";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserReadSubscriptions];
@@ -90,32 +97,38 @@ impl RequestGet for CheckUserSubscriptionRequest {
         uri: &http::Uri,
         text: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestGetError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
         let inner_response: helix::InnerResponse<Vec<_>> =
-            helix::parse_json(text, true).map_err(|e| {
-                helix::HelixRequestGetError::DeserializeError(
+            helix::InnerResponse::parse::<Self>(text).map_err(|e| {
+                helix::HelixRequestError::DeserializeError(
                     text.to_string(),
                     e,
+                    Self::METHOD,
                     uri.clone(),
                     status,
+                    None,
                 )
             })?;
         Ok(helix::Response {
             data: inner_response.data.into_iter().next().ok_or(
-                helix::HelixRequestGetError::InvalidResponse {
+                helix::HelixRequestError::InvalidResponse {
                     reason: "expected an entry in `data`",
                     response: text.to_string(),
                     status,
+                    method: Self::METHOD,
                     uri: uri.clone(),
+                    request_id: None,
                 },
             )?,
             pagination: inner_response.pagination.cursor,
             request,
             total: inner_response.total,
             other: inner_response.other,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
@@ -125,24 +138,13 @@ impl RequestGet for CheckUserSubscriptionRequest {
 fn test_request1() {
     use helix::*;
     let req = CheckUserSubscriptionRequest::builder()
-        .broadcaster_id("123".to_string())
+        .broadcaster_id("123")
         .build();
 
     // From twitch docs.
-    let data = br#"
-    {
-        "data": [
-          {
-            "broadcaster_id": "149747285",
-            "broadcaster_name": "TwitchPresents",
-            "broadcaster_login": "twitchpresents",
-            "is_gift": false,
-            "tier": "1000"
-          }
-        ]
-      }
-"#
-    .to_vec();
+    let data = crate::fixtures::helix::subscriptions::CHECK_USER_SUBSCRIPTION
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -160,7 +162,7 @@ fn test_request1() {
 fn test_request2() {
     use helix::*;
     let req = CheckUserSubscriptionRequest::builder()
-        .broadcaster_id("123".to_string())
+        .broadcaster_id("123")
         .build();
 
     // From twitch docs.
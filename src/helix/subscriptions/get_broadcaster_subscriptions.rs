@@ -43,6 +43,7 @@ use helix::RequestGet;
 ///
 /// [`get-broadcaster-subscriptions`](https://dev.twitch.tv/docs/api/reference#get-broadcaster-subscriptions)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetBroadcasterSubscriptionsRequest {
     /// User ID of the broadcaster. Must match the User ID in the Bearer token.
@@ -51,18 +52,23 @@ pub struct GetBroadcasterSubscriptionsRequest {
     /// Unique identifier of account to get subscription status of. Accepts up to 100 values.
     #[builder(default)]
     pub user_id: Vec<types::UserId>,
-    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
+    ///
+    /// Backward pagination with [`before`](helix::PaginationParams::before) is not supported.
     #[builder(default)]
-    pub after: Option<helix::Cursor>,
-    /// Number of values to be returned per page. Limit: 100. Default: 20.
-    #[builder(setter(into), default)]
-    pub first: Option<String>,
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
 }
 
 /// Return Values for [Get Broadcaster Subscriptions](super::get_broadcaster_subscriptions)
 ///
 /// [`get-broadcaster-subscriptions`](https://dev.twitch.tv/docs/api/reference#get-broadcaster-subscriptions)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct BroadcasterSubscription {
@@ -107,16 +113,34 @@ pub struct BroadcasterSubscription {
 impl Request for GetBroadcasterSubscriptionsRequest {
     type Response = Vec<BroadcasterSubscription>;
 
+    const COLLECT_OTHER_FIELDS: bool = true;
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "subscriptions";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] =
         &[twitch_oauth2::Scope::ChannelReadSubscriptions];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for GetBroadcasterSubscriptionsRequest {}
 
+impl helix::ResponseExtras for GetBroadcasterSubscriptionsRequest {
+    type Extras = BroadcasterSubscriptionsExtras;
+}
+
+/// Fields returned by [Get Broadcaster Subscriptions](super::get_broadcaster_subscriptions) besides `data`, see [`Response::extras`](helix::Response::extras)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct BroadcasterSubscriptionsExtras {
+    /// The number of users that subscribe to the broadcaster.
+    pub total: i64,
+    /// The current number of subscriber points earned by this broadcaster.
+    pub points: i64,
+}
+
 impl helix::Paginated for GetBroadcasterSubscriptionsRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
 }
 
 impl helix::Response<GetBroadcasterSubscriptionsRequest, Vec<BroadcasterSubscription>> {
@@ -129,6 +153,57 @@ impl helix::Response<GetBroadcasterSubscriptionsRequest, Vec<BroadcasterSubscrip
             Err(BroadcasterSubscriptionPointsError::PointsNotFound)
         }
     }
+
+    /// Number of subscriptions in this page per [tier](types::SubscriptionTier).
+    pub fn count_by_tier(&self) -> TierCounts {
+        self.data
+            .iter()
+            .fold(TierCounts::default(), |mut counts, subscription| {
+                match subscription.tier {
+                    types::SubscriptionTier::Tier1 => counts.tier1 += 1,
+                    types::SubscriptionTier::Tier2 => counts.tier2 += 1,
+                    types::SubscriptionTier::Tier3 => counts.tier3 += 1,
+                    types::SubscriptionTier::Prime => counts.prime += 1,
+                    types::SubscriptionTier::Other(_) => counts.other += 1,
+                }
+                counts
+            })
+    }
+
+    /// Number of gifted subscriptions in this page.
+    pub fn gifted_count(&self) -> usize { self.data.iter().filter(|s| s.is_gift).count() }
+
+    /// Gifters in this page with the number of subscriptions they gifted, most gifts first.
+    ///
+    /// Gifters with the same number of gifts are ordered lexicographically by their id.
+    pub fn gifters_ranked(&self) -> Vec<(&types::UserIdRef, usize)> {
+        let mut gifters: std::collections::HashMap<&types::UserIdRef, usize> =
+            std::collections::HashMap::new();
+        for gifter_id in self.data.iter().filter_map(|s| s.gifter_id.as_deref()) {
+            *gifters.entry(gifter_id).or_default() += 1;
+        }
+        let mut gifters: Vec<_> = gifters.into_iter().collect();
+        gifters.sort_unstable_by(|(a_id, a), (b_id, b)| {
+            b.cmp(a).then_with(|| a_id.as_str().cmp(b_id.as_str()))
+        });
+        gifters
+    }
+}
+
+/// Number of subscriptions per tier, see [`count_by_tier`](helix::Response::count_by_tier)
+#[derive(PartialEq, Eq, Default, Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct TierCounts {
+    /// Tier 1 subscriptions
+    pub tier1: usize,
+    /// Tier 2 subscriptions
+    pub tier2: usize,
+    /// Tier 3 subscriptions
+    pub tier3: usize,
+    /// Prime subscriptions
+    pub prime: usize,
+    /// Subscriptions of other tiers
+    pub other: usize,
 }
 
 /// Errors when retrieving `points` in [Get Broadcaster Subscriptions](self)
@@ -147,10 +222,50 @@ pub enum BroadcasterSubscriptionPointsError {
 fn test_request() {
     use helix::*;
     let req = GetBroadcasterSubscriptionsRequest::builder()
-        .broadcaster_id("123".to_string())
+        .broadcaster_id("123")
         .build();
 
     // From twitch docs. Example has ...
+    let data = crate::fixtures::helix::subscriptions::GET_BROADCASTER_SUBSCRIPTIONS
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/subscriptions?broadcaster_id=123"
+    );
+
+    let resp =
+        dbg!(
+            GetBroadcasterSubscriptionsRequest::parse_response(Some(req), &uri, http_response)
+                .unwrap()
+        );
+    assert_eq!(resp.total, Some(13));
+    assert_eq!(resp.points().unwrap(), 13);
+    let extras = resp.extras().unwrap();
+    assert_eq!(extras.total, 13);
+    assert_eq!(extras.points, 13);
+    assert_eq!(resp.get_other::<_, i64>("points").unwrap(), Some(13));
+    assert_eq!(resp.count_by_tier().tier1, 1);
+    assert_eq!(resp.gifted_count(), 1);
+    assert_eq!(
+        resp.gifters_ranked(),
+        vec![(types::UserIdRef::from_str("12826"), 1)]
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_request_next_pages() {
+    use helix::*;
+    let mut req = GetBroadcasterSubscriptionsRequest::builder()
+        .broadcaster_id("141981764")
+        .build();
+    req.set_pagination(Some("eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6MX19".into()));
+
     let data = br#"
     {
         "data": [
@@ -158,22 +273,22 @@ fn test_request() {
             "broadcaster_id": "141981764",
             "broadcaster_login": "twitchdev",
             "broadcaster_name": "TwitchDev",
-            "gifter_id": "12826",
-            "gifter_login": "twitch",
-            "gifter_name": "Twitch",
-            "is_gift": true,
-            "tier": "1000",
-            "plan_name": "Channel Subscription (twitchdev)",
-            "user_id": "527115020",
-            "user_name": "twitchgaming",
-            "user_login": "twitchgaming"
+            "gifter_id": "",
+            "gifter_login": "",
+            "gifter_name": "",
+            "is_gift": false,
+            "tier": "2000",
+            "plan_name": "Channel Subscription (twitchdev): $9.99 Sub",
+            "user_id": "527115021",
+            "user_name": "twitchgaming2",
+            "user_login": "twitchgaming2"
           }
         ],
         "pagination": {
-          "cursor": "xxxx"
+          "cursor": "eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6Mn19"
         },
         "total": 13,
-        "points": 13
+        "points": 15
       }
 "#
     .to_vec();
@@ -183,14 +298,142 @@ fn test_request() {
     let uri = req.get_uri().unwrap();
     assert_eq!(
         uri.to_string(),
-        "https://api.twitch.tv/helix/subscriptions?broadcaster_id=123"
+        "https://api.twitch.tv/helix/subscriptions?broadcaster_id=141981764&after=eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6MX19"
     );
 
     let resp =
-        dbg!(
-            GetBroadcasterSubscriptionsRequest::parse_response(Some(req), &uri, http_response)
-                .unwrap()
-        );
+        GetBroadcasterSubscriptionsRequest::parse_response(Some(req.clone()), &uri, http_response)
+            .unwrap();
     assert_eq!(resp.total, Some(13));
-    assert_eq!(resp.points().unwrap(), 13);
+    assert_eq!(resp.points().unwrap(), 15);
+    assert_eq!(resp.data[0].gifter_id, None);
+    assert_eq!(resp.count_by_tier().tier2, 1);
+    assert_eq!(
+        resp.pagination.as_ref().map(|c| c.as_str()),
+        Some("eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6Mn19")
+    );
+
+    // the last page has no cursor, but still has the totals
+    let data = br#"
+    {
+        "data": [],
+        "pagination": {},
+        "total": 13,
+        "points": 15
+      }
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+    let resp =
+        GetBroadcasterSubscriptionsRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert!(resp.data.is_empty());
+    assert_eq!(resp.pagination, None);
+    let extras = resp.extras().unwrap();
+    assert_eq!(extras.total, 13);
+    assert_eq!(extras.points, 15);
+}
+
+#[cfg(test)]
+#[test]
+fn test_gifters_ranked() {
+    use helix::*;
+    let req = GetBroadcasterSubscriptionsRequest::builder()
+        .broadcaster_id("141981764")
+        .build();
+
+    let data = br#"
+{
+    "data": [
+        {
+            "broadcaster_id": "141981764",
+            "broadcaster_login": "twitchdev",
+            "broadcaster_name": "TwitchDev",
+            "gifter_id": "9001",
+            "gifter_login": "gifter9001",
+            "gifter_name": "Gifter9001",
+            "is_gift": true,
+            "tier": "1000",
+            "plan_name": "Channel Subscription (twitchdev)",
+            "user_id": "1",
+            "user_name": "user1",
+            "user_login": "user1"
+        },
+        {
+            "broadcaster_id": "141981764",
+            "broadcaster_login": "twitchdev",
+            "broadcaster_name": "TwitchDev",
+            "gifter_id": "12826",
+            "gifter_login": "gifter12826",
+            "gifter_name": "Gifter12826",
+            "is_gift": true,
+            "tier": "1000",
+            "plan_name": "Channel Subscription (twitchdev)",
+            "user_id": "2",
+            "user_name": "user2",
+            "user_login": "user2"
+        },
+        {
+            "broadcaster_id": "141981764",
+            "broadcaster_login": "twitchdev",
+            "broadcaster_name": "TwitchDev",
+            "gifter_id": "",
+            "gifter_login": "gifter",
+            "gifter_name": "Gifter",
+            "is_gift": false,
+            "tier": "1000",
+            "plan_name": "Channel Subscription (twitchdev)",
+            "user_id": "3",
+            "user_name": "user3",
+            "user_login": "user3"
+        },
+        {
+            "broadcaster_id": "141981764",
+            "broadcaster_login": "twitchdev",
+            "broadcaster_name": "TwitchDev",
+            "gifter_id": "141981764",
+            "gifter_login": "gifter141981764",
+            "gifter_name": "Gifter141981764",
+            "is_gift": true,
+            "tier": "1000",
+            "plan_name": "Channel Subscription (twitchdev)",
+            "user_id": "4",
+            "user_name": "user4",
+            "user_login": "user4"
+        },
+        {
+            "broadcaster_id": "141981764",
+            "broadcaster_login": "twitchdev",
+            "broadcaster_name": "TwitchDev",
+            "gifter_id": "12826",
+            "gifter_login": "gifter12826",
+            "gifter_name": "Gifter12826",
+            "is_gift": true,
+            "tier": "1000",
+            "plan_name": "Channel Subscription (twitchdev)",
+            "user_id": "5",
+            "user_name": "user5",
+            "user_login": "user5"
+        }
+    ],
+    "pagination": {},
+    "total": 5,
+    "points": 5
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+    let uri = req.get_uri().unwrap();
+    let resp =
+        GetBroadcasterSubscriptionsRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(resp.gifted_count(), 4);
+    assert_eq!(
+        resp.gifters_ranked(),
+        vec![
+            (types::UserIdRef::from_str("12826"), 2),
+            (types::UserIdRef::from_str("141981764"), 1),
+            (types::UserIdRef::from_str("9001"), 1),
+        ]
+    );
 }
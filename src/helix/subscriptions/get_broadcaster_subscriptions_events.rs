@@ -51,6 +51,7 @@ use helix::RequestGet;
 ///
 /// [`get-broadcaster-subscriptions-events`](https://dev.twitch.tv/docs/api/reference#get-broadcaster-subscriptions-events)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetBroadcasterSubscriptionsEventsRequest {
     /// Must match the User ID in the Bearer token.
@@ -60,14 +61,14 @@ pub struct GetBroadcasterSubscriptionsEventsRequest {
     /// Maximum: 100
     #[builder(default)]
     pub user_id: Vec<types::UserId>,
-    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
+    ///
+    /// Backward pagination with [`before`](helix::PaginationParams::before) is not supported.
     #[builder(default)]
-    pub after: Option<helix::Cursor>,
-    /// Maximum number of objects to return. Maximum: 100. Default: 20.
-    #[builder(default, setter(into))]
-    pub first: Option<usize>,
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
     /// Retreive a single event by event ID
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub id: Option<String>,
 }
 
@@ -75,6 +76,11 @@ pub struct GetBroadcasterSubscriptionsEventsRequest {
 ///
 /// [`get-broadcaster-subscriptions-events`](https://dev.twitch.tv/docs/api/reference#get-broadcaster-subscriptions-events)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct BroadcasterSubscriptionEvent {
@@ -92,6 +98,11 @@ pub struct BroadcasterSubscriptionEvent {
 
 /// Type of event
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[non_exhaustive]
 pub enum BroadcasterSubscriptionEventType {
     /// User has subscribed
@@ -107,6 +118,11 @@ pub enum BroadcasterSubscriptionEventType {
 
 /// Event data for broadcaster events.
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct BroadcasterSubscriptionEventData {
@@ -155,16 +171,19 @@ where D: serde::de::Deserializer<'de> {
 impl Request for GetBroadcasterSubscriptionsEventsRequest {
     type Response = Vec<BroadcasterSubscriptionEvent>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "subscriptions/events";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] =
         &[twitch_oauth2::Scope::ChannelReadSubscriptions];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for GetBroadcasterSubscriptionsEventsRequest {}
 
 impl helix::Paginated for GetBroadcasterSubscriptionsEventsRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
 }
 
 #[cfg(test)]
@@ -172,67 +191,13 @@ impl helix::Paginated for GetBroadcasterSubscriptionsEventsRequest {
 fn test_request() {
     use helix::*;
     let req = GetBroadcasterSubscriptionsEventsRequest::builder()
-        .broadcaster_id("1337".to_string())
+        .broadcaster_id("1337")
         .build();
 
     // From twitch docs
-    let data = br#"
-    {
-        "data": [
-          {
-            "id": "1mZCpIomSWc9PR2Ldeadbeef",
-            "event_type": "subscriptions.subscribe",
-            "event_timestamp": "2021-01-03T16:38:27Z",
-            "version": "1.0",
-            "event_data": {
-              "broadcaster_id": "1337",
-              "broadcaster_name": "justintv",
-              "gifter_id": "",
-              "gifter_name": "",
-              "is_gift": false,
-              "plan_name": "Channel Subscription (justintv)",
-              "tier": "1000",
-              "user_id": "1336",
-              "user_name": "twitchuser"
-            }
-          },
-          {
-            "id": "1mY9qZVbbl77PpGydeadbeef",
-            "event_type": "subscriptions.unsubscribe",
-            "event_timestamp": "2021-01-03T07:44:08Z",
-            "version": "1.0",
-            "event_data": {
-              "broadcaster_id": "1337",
-              "broadcaster_name": "justintv",
-              "gifter_id": "",
-              "gifter_name": "",
-              "is_gift": false,
-              "plan_name": "",
-              "tier": "",
-              "user_id": "1336",
-              "user_name": "twitchuser"
-            }
-          },    {
-            "id": "1mRxcgkkAVfej5n7deadbeef",
-            "event_type": "subscriptions.notification",
-            "event_timestamp": "2021-01-01T03:04:45Z",
-            "version": "1.0",
-            "event_data": {
-              "broadcaster_id": "1337",
-              "broadcaster_name": "justintv",
-              "gifter_id": "",
-              "gifter_name": "",
-              "is_gift": false,
-              "plan_name": "Channel Subscription (justintv)",
-              "tier": "1000",
-              "user_id": "1336",
-              "user_name": "twitchuser"
-            }
-          }
-        ]
-    }
-"#
-    .to_vec();
+    let data = crate::fixtures::helix::subscriptions::GET_BROADCASTER_SUBSCRIPTIONS_EVENTS
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
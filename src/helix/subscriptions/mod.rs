@@ -33,7 +33,8 @@ pub mod get_broadcaster_subscriptions_events;
 
 #[doc(inline)]
 pub use get_broadcaster_subscriptions::{
-    BroadcasterSubscription, GetBroadcasterSubscriptionsRequest,
+    BroadcasterSubscription, BroadcasterSubscriptionsExtras, GetBroadcasterSubscriptionsRequest,
+    TierCounts,
 };
 #[doc(inline)]
 pub use get_broadcaster_subscriptions_events::{
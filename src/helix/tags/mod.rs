@@ -38,8 +38,19 @@ pub use get_all_stream_tags::{GetAllStreamTagsRequest, Tag};
 #[aliri_braid::braid(serde)]
 pub struct TagLanguage;
 
+#[cfg(feature = "arbitrary")]
+crate::types::arbitrary_braid![TagLanguage];
+
+#[cfg(feature = "rkyv")]
+crate::types::rkyv_braid![TagLanguage];
+
 /// Tag is auto-generated or not.
 #[derive(Clone, Debug, PartialOrd, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[serde(from = "bool")]
 #[serde(into = "bool")]
 pub enum AutoGenerated {
@@ -68,6 +79,11 @@ impl From<AutoGenerated> for bool {
 }
 /// A stream tag as defined by Twitch.
 #[derive(PartialEq, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct TwitchTag {
     /// ID of the tag.
     #[serde(alias = "tag_id")]
@@ -42,6 +42,7 @@ use helix::RequestGet;
 ///
 /// [`get-teams`](https://dev.twitch.tv/docs/api/reference#get-teams)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetChannelTeamsRequest {
     /// Team ID.
@@ -53,6 +54,11 @@ pub struct GetChannelTeamsRequest {
 ///
 /// [`get-teams`](https://dev.twitch.tv/docs/api/reference#get-teams)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct BroadcasterTeam {
@@ -70,6 +76,7 @@ pub struct BroadcasterTeam {
 impl Request for GetChannelTeamsRequest {
     type Response = Vec<BroadcasterTeam>;
 
+    const METHOD: http::Method = http::Method::GET;
     #[cfg(feature = "twitch_oauth2")]
     const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserReadEmail];
     const PATH: &'static str = "teams/channel";
@@ -84,30 +91,12 @@ impl RequestGet for GetChannelTeamsRequest {}
 fn test_request() {
     use helix::*;
     let req = GetChannelTeamsRequest::builder()
-        .broadcaster_id("44322889".to_string())
+        .broadcaster_id("44322889")
         .build();
 
     // From twitch docs
-    let data = br#"
-{
-    "data": [
-    {
-        "broadcaster_id": "96909659",
-        "broadcaster_name": "CSharpFritz",
-        "broadcaster_login": "csharpfritz",
-        "background_image_url": null,
-        "banner": null,
-        "created_at": "2019-02-11T12:09:22Z",
-        "updated_at": "2020-11-18T15:56:41Z",
-        "info": "<p>An outgoing and enthusiastic group of friendly channels that write code, teach about technology, and promote the technical community.</p>",
-        "thumbnail_url": "https://static-cdn.jtvnw.net/jtv_user_pictures/team-livecoders-team_logo_image-bf1d9a87ca81432687de60e24ad9593d-600x600.png",
-        "team_name": "livecoders",
-        "team_display_name": "Live Coders",
-        "id": "6358"
-    }
-    ]
-}
-"#
+    let data = crate::fixtures::helix::teams::GET_CHANNEL_TEAMS
+        .as_bytes()
         .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
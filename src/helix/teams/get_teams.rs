@@ -8,7 +8,7 @@
 //! ```rust
 //! use twitch_api2::helix::teams::get_teams;
 //! let request = get_teams::GetTeamsRequest::builder()
-//!     .name("coolteam".to_string())
+//!     .name("coolteam")
 //!     .build();
 //! ```
 //!
@@ -25,7 +25,7 @@
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
 //! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
 //! let request = get_teams::GetTeamsRequest::builder()
-//!     .name("coolteam".to_string())
+//!     .name("coolteam")
 //!     .build();
 //! let response: Vec<get_teams::Team> = client.req_get(request, &token).await?.data;
 //! # Ok(())
@@ -42,13 +42,14 @@ use helix::RequestGet;
 ///
 /// [`get-teams`](https://dev.twitch.tv/docs/api/reference#get-teams)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetTeamsRequest {
     /// Team ID.
     #[builder(default, setter(into))]
     pub id: Option<types::TeamId>,
     /// Team name.
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub name: Option<String>,
 }
 
@@ -56,10 +57,16 @@ pub struct GetTeamsRequest {
 ///
 /// [`get-teams`](https://dev.twitch.tv/docs/api/reference#get-teams)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Team {
     /// Users in the team.
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub users: Vec<types::User>,
     /// Information about the team.
     #[serde(flatten)]
@@ -69,6 +76,7 @@ pub struct Team {
 impl Request for GetTeamsRequest {
     type Response = Vec<Team>;
 
+    const METHOD: http::Method = http::Method::GET;
     #[cfg(feature = "twitch_oauth2")]
     const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserReadEmail];
     const PATH: &'static str = "teams";
@@ -85,36 +93,7 @@ fn test_request() {
     let req = GetTeamsRequest::builder().id(Some("6358".into())).build();
 
     // From twitch docs
-    let data = br#"
-    {
-        "data": [
-          {
-            "users": [
-              {
-                "user_id": "278217731",
-                "user_name": "mastermndio",
-                "user_login": "mastermndio"
-              },
-              {
-                "user_id": "41284990",
-                "user_name": "jenninexus",
-                "user_login": "jenninexus"
-              }
-            ],
-            "background_image_url": null,
-            "banner": null,
-            "created_at": "2019-02-11T12:09:22Z",
-            "updated_at": "2020-11-18T15:56:41Z",
-            "info": "<p>An outgoing and enthusiastic group of friendly channels that write code, teach about technology, and promote the technical community.</p>",
-            "thumbnail_url": "https://static-cdn.jtvnw.net/jtv_user_pictures/team-livecoders-team_logo_image-bf1d9a87ca81432687de60e24ad9593d-600x600.png",
-            "team_name": "livecoders",
-            "team_display_name": "Live Coders",
-            "id": "6358"
-          }
-        ]
-      }
-"#
-        .to_vec();
+    let data = crate::fixtures::helix::teams::GET_TEAMS.as_bytes().to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
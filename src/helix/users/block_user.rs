@@ -50,6 +50,7 @@ use helix::RequestPut;
 ///
 /// [`block-user`](https://dev.twitch.tv/docs/api/reference#block-user)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct BlockUserRequest {
     /// User ID of the follower
@@ -65,6 +66,11 @@ pub struct BlockUserRequest {
 
 /// Source context for blocking the user.
 #[derive(PartialEq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum SourceContext {
@@ -76,6 +82,11 @@ pub enum SourceContext {
 
 /// Reason for blocking the user.
 #[derive(PartialEq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum Reason {
@@ -91,6 +102,11 @@ pub enum Reason {
 ///
 /// [`block-user`](https://dev.twitch.tv/docs/api/reference#block-user)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[non_exhaustive]
 pub enum BlockUser {
     /// 204 - User blocked successfully.
@@ -100,6 +116,7 @@ pub enum BlockUser {
 impl Request for BlockUserRequest {
     type Response = BlockUser;
 
+    const METHOD: http::Method = http::Method::PUT;
     #[cfg(feature = "twitch_oauth2")]
     const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[];
     const PATH: &'static str = "users/blocks";
@@ -115,7 +132,7 @@ impl RequestPut for BlockUserRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPutError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
@@ -126,12 +143,16 @@ impl RequestPut for BlockUserRequest {
                 request,
                 total: None,
                 other: None,
+                request_id: None,
+                diagnostics: Default::default(),
             }),
-            _ => Err(helix::HelixRequestPutError::InvalidResponse {
+            _ => Err(helix::HelixRequestError::InvalidResponse {
                 reason: "unexpected status",
                 response: response.to_string(),
                 status,
+                method: Self::METHOD,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
@@ -142,7 +163,7 @@ impl RequestPut for BlockUserRequest {
 fn test_request() {
     use helix::*;
     let req = BlockUserRequest::builder()
-        .target_user_id("41245071".to_string())
+        .target_user_id("41245071")
         .build();
 
     dbg!(req.create_request(EmptyBody, "token", "clientid").unwrap());
@@ -8,7 +8,7 @@
 //! ```rust
 //! use twitch_api2::helix::users::get_user_block_list;
 //! let request = get_user_block_list::GetUserBlockListRequest::builder()
-//!     .broadcaster_id("1234".to_string())
+//!     .broadcaster_id("1234")
 //!     .build();
 //! ```
 //!
@@ -25,7 +25,7 @@
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
 //! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
 //! let request = get_user_block_list::GetUserBlockListRequest::builder()
-//!     .broadcaster_id("1234".to_string())
+//!     .broadcaster_id("1234")
 //!     .build();
 //! let response: Vec<get_user_block_list::UserBlock> = client.req_get(request, &token).await?.data;
 //! # Ok(())
@@ -42,14 +42,15 @@ use helix::RequestGet;
 ///
 /// [`get-user-block-list`](https://dev.twitch.tv/docs/api/reference#get-user-block-list)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetUserBlockListRequest {
-    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
+    ///
+    /// Backward pagination with [`before`](helix::PaginationParams::before) is not supported.
     #[builder(default)]
-    pub after: Option<helix::Cursor>,
-    /// Maximum number of objects to return. Maximum: 100. Default: 20.
-    #[builder(default)]
-    pub first: Option<usize>,
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
     ///  User ID for a Twitch user.
     #[builder(setter(into))]
     pub broadcaster_id: types::UserId,
@@ -59,6 +60,11 @@ pub struct GetUserBlockListRequest {
 ///
 /// [`get-user-block-list`](https://dev.twitch.tv/docs/api/reference#get-user-block-list)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct UserBlock {
@@ -73,18 +79,21 @@ pub struct UserBlock {
 impl Request for GetUserBlockListRequest {
     type Response = Vec<UserBlock>;
 
+    const METHOD: http::Method = http::Method::GET;
     #[cfg(feature = "twitch_oauth2")]
     const OPT_SCOPE: &'static [twitch_oauth2::Scope] =
         &[twitch_oauth2::Scope::UserReadBlockedUsers];
     const PATH: &'static str = "users/blocks";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for GetUserBlockListRequest {}
 
 impl helix::Paginated for GetUserBlockListRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
 }
 
 #[cfg(test)]
@@ -96,23 +105,9 @@ fn test_request() {
         .build();
 
     // From twitch docs // FIXME: twitch docs say id, not user_id
-    let data = br#"
-{
-    "data": [
-        {
-        "user_id": "135093069",
-        "user_login": "bluelava",
-        "display_name": "BlueLava"
-        },
-        {
-        "user_id": "27419011",
-        "user_login": "travistyoj",
-        "display_name": "TravistyOJ"
-        }
-    ]
-    }
-"#
-    .to_vec();
+    let data = crate::fixtures::helix::users::GET_USER_BLOCK_LIST
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
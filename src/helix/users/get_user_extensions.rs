@@ -0,0 +1,217 @@
+//! Gets a list of all extensions (both active and inactive) that the broadcaster has installed.
+//! [`get-user-extensions`](https://dev.twitch.tv/docs/api/reference#get-user-extensions)
+//!
+//! The user ID in the access token identifies the broadcaster.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetUserExtensionsRequest]
+//!
+//! To use this endpoint, construct a [`GetUserExtensionsRequest`] with the [`GetUserExtensionsRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::users::get_user_extensions;
+//! let request = get_user_extensions::GetUserExtensionsRequest::default();
+//! ```
+//!
+//! ## Response: [UserExtension]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, users::get_user_extensions};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = get_user_extensions::GetUserExtensionsRequest::default();
+//! let response: Vec<get_user_extensions::UserExtension> = client.req_get(request, &token).await?.data;
+//! let overlays = response.iter().filter(|e| e.type_.supports_overlay()).count();
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetUserExtensionsRequest::parse_response(None, &request.get_uri(), response)`](GetUserExtensionsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get User Extensions](super::get_user_extensions)
+///
+/// [`get-user-extensions`](https://dev.twitch.tv/docs/api/reference#get-user-extensions)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct GetUserExtensionsRequest {}
+
+/// Return Values for [Get User Extensions](super::get_user_extensions)
+///
+/// [`get-user-extensions`](https://dev.twitch.tv/docs/api/reference#get-user-extensions)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct UserExtension {
+    /// An ID that identifies the extension.
+    pub id: String,
+    /// The extension's version.
+    pub version: String,
+    /// The extension's name.
+    pub name: String,
+    /// A Boolean value that determines whether the extension is configured and can be activated.
+    pub can_activate: bool,
+    /// The extension types that you can activate for this extension.
+    #[serde(rename = "type")]
+    pub type_: ExtensionTypes,
+}
+
+/// Type of an extension, see [`ExtensionTypes`]
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Copy, Debug, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[serde(rename_all = "lowercase")]
+pub enum ExtensionType {
+    /// Component extension, shown on top of the video
+    Component,
+    /// Mobile extension
+    Mobile,
+    /// Overlay extension, covering the whole video
+    Overlay,
+    /// Panel extension, shown below the video
+    Panel,
+}
+
+impl ExtensionType {
+    const ALL: [ExtensionType; 4] = [
+        ExtensionType::Component,
+        ExtensionType::Mobile,
+        ExtensionType::Overlay,
+        ExtensionType::Panel,
+    ];
+
+    const fn bit(self) -> u8 {
+        match self {
+            ExtensionType::Component => 1 << 0,
+            ExtensionType::Mobile => 1 << 1,
+            ExtensionType::Overlay => 1 << 2,
+            ExtensionType::Panel => 1 << 3,
+        }
+    }
+}
+
+/// Set of [extension types](ExtensionType)
+///
+/// (De)serializes as a list of types, e.g. `["component", "overlay"]`.
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Copy, Debug, Default, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[serde(from = "Vec<ExtensionType>", into = "Vec<ExtensionType>")]
+pub struct ExtensionTypes(u8);
+
+impl ExtensionTypes {
+    /// The set contains `type_`
+    pub const fn contains(&self, type_: ExtensionType) -> bool { self.0 & type_.bit() != 0 }
+
+    /// Add `type_` to the set
+    pub fn insert(&mut self, type_: ExtensionType) { self.0 |= type_.bit() }
+
+    /// The set contains no types
+    pub const fn is_empty(&self) -> bool { self.0 == 0 }
+
+    /// The extension can be activated as a [component](ExtensionType::Component)
+    pub const fn supports_component(&self) -> bool { self.contains(ExtensionType::Component) }
+
+    /// The extension can be activated as a [mobile](ExtensionType::Mobile) extension
+    pub const fn supports_mobile(&self) -> bool { self.contains(ExtensionType::Mobile) }
+
+    /// The extension can be activated as an [overlay](ExtensionType::Overlay)
+    pub const fn supports_overlay(&self) -> bool { self.contains(ExtensionType::Overlay) }
+
+    /// The extension can be activated as a [panel](ExtensionType::Panel)
+    pub const fn supports_panel(&self) -> bool { self.contains(ExtensionType::Panel) }
+
+    /// Iterate over the types in the set
+    pub fn iter(&self) -> impl Iterator<Item = ExtensionType> + '_ {
+        ExtensionType::ALL
+            .iter()
+            .copied()
+            .filter(move |t| self.contains(*t))
+    }
+}
+
+impl std::iter::FromIterator<ExtensionType> for ExtensionTypes {
+    fn from_iter<I: IntoIterator<Item = ExtensionType>>(iter: I) -> Self {
+        let mut types = ExtensionTypes::default();
+        for type_ in iter {
+            types.insert(type_);
+        }
+        types
+    }
+}
+
+impl From<Vec<ExtensionType>> for ExtensionTypes {
+    fn from(types: Vec<ExtensionType>) -> Self { types.into_iter().collect() }
+}
+
+impl From<ExtensionTypes> for Vec<ExtensionType> {
+    fn from(types: ExtensionTypes) -> Self { types.iter().collect() }
+}
+
+impl Request for GetUserExtensionsRequest {
+    type Response = Vec<UserExtension>;
+
+    const METHOD: http::Method = http::Method::GET;
+    const PATH: &'static str = "users/extensions/list";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserReadBroadcast];
+}
+
+impl RequestGet for GetUserExtensionsRequest {}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetUserExtensionsRequest::default();
+
+    // From twitch docs
+    let data = crate::fixtures::helix::users::GET_USER_EXTENSIONS
+        .as_bytes()
+        .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/users/extensions/list?"
+    );
+
+    let resp = GetUserExtensionsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert!(resp[0].type_.supports_panel());
+    assert!(!resp[0].type_.supports_overlay());
+    assert!(resp[1].type_.supports_overlay());
+    assert_eq!(
+        resp[2].type_.iter().collect::<Vec<_>>(),
+        vec![ExtensionType::Mobile, ExtensionType::Panel]
+    );
+    assert_eq!(
+        serde_json::to_value(&resp[2].type_).unwrap(),
+        serde_json::json!(["mobile", "panel"])
+    );
+}
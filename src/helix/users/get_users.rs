@@ -44,6 +44,7 @@ use helix::RequestGet;
 ///
 /// [`get-users`](https://dev.twitch.tv/docs/api/reference#get-users)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetUsersRequest {
     /// User ID. Multiple user IDs can be specified. Limit: 100.
@@ -58,6 +59,11 @@ pub struct GetUsersRequest {
 ///
 /// [`get-users`](https://dev.twitch.tv/docs/api/reference#get-users)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct User {
@@ -69,16 +75,19 @@ pub struct User {
     pub description: Option<String>,
     /// User’s display name.
     pub display_name: types::DisplayName,
-    /// User’s email address. Returned if the request includes the [`user:read:email` scope](twitch_oauth2::Scope::UserReadEmail).
-    pub email: Option<String>,
+    /// User’s verified email address.
+    ///
+    /// Only returned for the user of the token, and only if the token has the [`user:read:email` scope](twitch_oauth2::Scope::UserReadEmail).
+    /// `None` does not mean the user has no email, use [`HelixClient::req_get_opt_scoped`](crate::helix::HelixClient::req_get_opt_scoped) to make sure the scope is present.
+    pub email: Option<types::Email>,
     /// User’s ID.
     pub id: types::UserId,
     /// User’s login name.
     pub login: types::UserName,
     /// URL of the user’s offline image.
-    pub offline_image_url: Option<String>,
+    pub offline_image_url: Option<types::TemplatedImageUrl>,
     /// URL of the user’s profile image.
-    pub profile_image_url: Option<String>,
+    pub profile_image_url: Option<types::TemplatedImageUrl>,
     /// User’s type: "staff", "admin", "global_mod", or "".
     #[serde(rename = "type")]
     pub type_: Option<types::UserType>,
@@ -89,6 +98,7 @@ pub struct User {
 impl Request for GetUsersRequest {
     type Response = Vec<User>;
 
+    const METHOD: http::Method = http::Method::GET;
     #[cfg(feature = "twitch_oauth2")]
     const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserReadEmail];
     const PATH: &'static str = "users";
@@ -108,26 +118,7 @@ fn test_request() {
 
     // From twitch docs
     // FIXME: This is not valid anymore. Twitch....
-    let data = br#"
-{
-    "data": [
-        {
-        "id": "141981764",
-        "login": "twitchdev",
-        "display_name": "TwitchDev",
-        "type": "",
-        "broadcaster_type": "partner",
-        "description": "Supporting third-party developers building Twitch integrations from chatbots to game integrations.",
-        "profile_image_url": "https://static-cdn.jtvnw.net/jtv_user_pictures/8a6381c7-d0c0-4576-b179-38bd5ce1d6af-profile_image-300x300.png",
-        "offline_image_url": "https://static-cdn.jtvnw.net/jtv_user_pictures/3f13ab61-ec78-4fe6-8481-8682cb3b0ac2-channel_offline_image-1920x1080.png",
-        "view_count": 5980557,
-        "email": "not-real@email.com",
-        "created_at": "2016-12-14T20:32:28.894263Z"
-        }
-    ]
-    }
-"#
-        .to_vec();
+    let data = crate::fixtures::helix::users::GET_USERS.as_bytes().to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
@@ -137,5 +128,73 @@ fn test_request() {
         "https://api.twitch.tv/helix/users?id=44322889"
     );
 
-    dbg!(GetUsersRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    let user = dbg!(GetUsersRequest::parse_response(Some(req), &uri, http_response).unwrap())
+        .data
+        .remove(0);
+    let profile_image_url = user.profile_image_url.unwrap();
+    assert!(!profile_image_url.is_templated());
+    assert_eq!(profile_image_url.render(70, 70), profile_image_url.as_str());
+}
+
+#[cfg(test)]
+#[test]
+fn test_auth_style() {
+    use helix::*;
+    let req = GetUsersRequest::builder()
+        .id(vec!["44322889".into()])
+        .build();
+
+    let request = req.create_request("token", "clientid").unwrap();
+    assert_eq!(request.method(), http::Method::GET);
+    assert_eq!(
+        request.headers()[http::header::AUTHORIZATION],
+        "Bearer token"
+    );
+    let request = req
+        .create_request(AuthStyle::Extension("jwt"), "clientid")
+        .unwrap();
+    assert_eq!(request.headers()[http::header::AUTHORIZATION], "Bearer jwt");
+    let request = req.create_request(AuthStyle::None, "clientid").unwrap();
+    assert!(request.headers().get(http::header::AUTHORIZATION).is_none());
+    assert_eq!(request.headers()["Client-ID"], "clientid");
+}
+
+#[cfg(test)]
+#[test]
+fn test_request_id() {
+    use helix::*;
+    let req = GetUsersRequest::builder()
+        .id(vec!["44322889".into()])
+        .build();
+    let uri = req.get_uri().unwrap();
+
+    let data = crate::fixtures::helix::users::GET_USERS.as_bytes().to_vec();
+    let http_response = http::Response::builder()
+        .header("Twitch-Request-Id", "abc-123")
+        .body(data)
+        .unwrap();
+    let response = GetUsersRequest::parse_response(Some(req.clone()), &uri, http_response).unwrap();
+    assert_eq!(response.request_id.as_deref(), Some("abc-123"));
+
+    let data = br#"{"error":"Unauthorized","status":401,"message":"Invalid OAuth token"}"#.to_vec();
+    let http_response = http::Response::builder()
+        .status(401)
+        .header("Twitch-Trace-Id", "def-456")
+        .body(data)
+        .unwrap();
+    let error =
+        GetUsersRequest::parse_response(Some(req.clone()), &uri, http_response).unwrap_err();
+    assert_eq!(error.request_id(), Some("def-456"));
+    assert_eq!(error.method(), http::Method::GET);
+    assert!(error
+        .to_string()
+        .starts_with("helix returned error 401 - Unauthorized: \"Invalid OAuth token\" when calling `GET https://api.twitch.tv/helix/users?"));
+
+    let http_response = http::Response::builder()
+        .header("Twitch-Request-Id", "ghi-789")
+        .body(b"{}".to_vec())
+        .unwrap();
+    let error = GetUsersRequest::parse_response(Some(req), &uri, http_response).unwrap_err();
+    assert!(matches!(error, HelixRequestError::DeserializeError(..)));
+    assert_eq!(error.request_id(), Some("ghi-789"));
 }
@@ -41,14 +41,15 @@ use helix::RequestGet;
 ///
 /// [`get-users-follows`](https://dev.twitch.tv/docs/api/reference#get-users-follows)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetUsersFollowsRequest {
-    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
-    #[builder(default)]
-    pub after: Option<helix::Cursor>,
-    /// Maximum number of objects to return. Maximum: 100. Default: 20.
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
+    ///
+    /// Backward pagination with [`before`](helix::PaginationParams::before) is not supported.
     #[builder(default)]
-    pub first: Option<usize>,
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
     /// User ID. The request returns information about users who are being followed by the from_id user.
     #[builder(default, setter(into))]
     pub from_id: Option<types::UserId>,
@@ -61,6 +62,11 @@ pub struct GetUsersFollowsRequest {
 ///
 /// [`get-users-follows`](https://dev.twitch.tv/docs/api/reference#get-users-follows)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct UsersFollows {
@@ -71,12 +77,18 @@ pub struct UsersFollows {
     /// * If both `from_id` and `to_id` were in the request, this is 1 (if the "from" user follows the "to" user) or 0.
     pub total: i64,
     /// The follow relationships returned by this endpoint on this page. See [Response::get_next](helix::Response::get_next) for getting more pages
+    #[serde(deserialize_with = "helix::deserialize_default_from_null")]
     pub follow_relationships: Vec<FollowRelationship>,
 }
 /// Describes a follow relationship
 ///
 /// Used in [UsersFollows]
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct FollowRelationship {
@@ -99,11 +111,14 @@ pub struct FollowRelationship {
 impl Request for GetUsersFollowsRequest {
     type Response = UsersFollows;
 
+    const METHOD: http::Method = http::Method::GET;
     #[cfg(feature = "twitch_oauth2")]
     const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[];
     const PATH: &'static str = "users/follows";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for GetUsersFollowsRequest {
@@ -112,7 +127,7 @@ impl RequestGet for GetUsersFollowsRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestGetError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
@@ -124,12 +139,14 @@ impl RequestGet for GetUsersFollowsRequest {
             pagination: helix::Pagination,
         }
 
-        let response: InnerResponse = helix::parse_json(response, true).map_err(|e| {
-            helix::HelixRequestGetError::DeserializeError(
+        let response: InnerResponse = helix::parse_json_owned(response, true).map_err(|e| {
+            helix::HelixRequestError::DeserializeError(
                 response.to_string(),
                 e,
+                Self::METHOD,
                 uri.clone(),
                 status,
+                None,
             )
         })?;
         Ok(helix::Response {
@@ -141,12 +158,14 @@ impl RequestGet for GetUsersFollowsRequest {
             request,
             total: Some(response.total),
             other: None,
+            request_id: None,
+            diagnostics: Default::default(),
         })
     }
 }
 
 impl helix::Paginated for GetUsersFollowsRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
 }
 
 #[cfg(test)]
@@ -158,36 +177,9 @@ fn test_request() {
         .build();
 
     // From twitch docs
-    let data = br#"
-{
-    "total": 12345,
-    "data":
-    [
-        {
-            "from_id": "171003792",
-            "from_login": "iiisutha067iii",
-            "from_name": "IIIsutha067III",
-            "to_id": "23161357",
-            "to_name": "LIRIK",
-            "to_login": "lirik",
-            "followed_at": "2017-08-22T22:55:24Z"
-        },
-        {
-            "from_id": "113627897",
-            "from_login": "birdman616",
-            "from_name": "Birdman616",
-            "to_id": "23161357",
-            "to_name": "LIRIK",
-            "to_login": "lirik",
-            "followed_at": "2017-08-22T22:55:04Z"
-        }
-    ],
-    "pagination":{
-        "cursor": "eyJiIjpudWxsLCJhIjoiMTUwMzQ0MTc3NjQyNDQyMjAwMCJ9"
-    }
-}
-"#
-    .to_vec();
+    let data = crate::fixtures::helix::users::GET_USERS_FOLLOWS
+        .as_bytes()
+        .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
 
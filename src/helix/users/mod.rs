@@ -28,6 +28,7 @@ use serde::{Deserialize, Serialize};
 
 pub mod block_user;
 pub mod get_user_block_list;
+pub mod get_user_extensions;
 pub mod get_users;
 pub mod get_users_follows;
 pub mod unblock_user;
@@ -37,6 +38,10 @@ pub use block_user::{BlockUser, BlockUserRequest};
 #[doc(inline)]
 pub use get_user_block_list::{GetUserBlockListRequest, UserBlock};
 #[doc(inline)]
+pub use get_user_extensions::{
+    ExtensionType, ExtensionTypes, GetUserExtensionsRequest, UserExtension,
+};
+#[doc(inline)]
 pub use get_users::{GetUsersRequest, User};
 #[doc(inline)]
 pub use get_users_follows::{FollowRelationship, GetUsersFollowsRequest, UsersFollows};
@@ -43,6 +43,7 @@ use helix::RequestDelete;
 ///
 /// [`unblock-user`](https://dev.twitch.tv/docs/api/reference#unblock-user)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct UnblockUserRequest {
     /// User ID of the follower
@@ -54,6 +55,11 @@ pub struct UnblockUserRequest {
 ///
 /// [`unblock-user`](https://dev.twitch.tv/docs/api/reference#unblock-user)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[non_exhaustive]
 pub enum UnblockUser {
     /// 204 - User unblocked successfully.
@@ -63,6 +69,7 @@ pub enum UnblockUser {
 impl Request for UnblockUserRequest {
     type Response = UnblockUser;
 
+    const METHOD: http::Method = http::Method::DELETE;
     #[cfg(feature = "twitch_oauth2")]
     const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[];
     const PATH: &'static str = "users/blocks";
@@ -76,7 +83,7 @@ impl RequestDelete for UnblockUserRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestDeleteError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
@@ -87,12 +94,16 @@ impl RequestDelete for UnblockUserRequest {
                 request,
                 total: None,
                 other: None,
+                request_id: None,
+                diagnostics: Default::default(),
             }),
-            _ => Err(helix::HelixRequestDeleteError::InvalidResponse {
+            _ => Err(helix::HelixRequestError::InvalidResponse {
                 reason: "unexpected status",
                 response: response.to_string(),
                 status,
+                method: Self::METHOD,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
@@ -103,7 +114,7 @@ impl RequestDelete for UnblockUserRequest {
 fn test_request() {
     use helix::*;
     let req = UnblockUserRequest::builder()
-        .target_user_id("41245071".to_string())
+        .target_user_id("41245071")
         .build();
 
     // From twitch docs
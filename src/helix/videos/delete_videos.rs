@@ -45,6 +45,7 @@ use helix::RequestDelete;
 ///
 /// [`delete-videos`](https://dev.twitch.tv/docs/api/reference#delete-videos)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct DeleteVideosRequest {
     /// ID of the video(s) to be deleted. Limit: 5.
@@ -65,6 +66,7 @@ pub enum DeleteVideo {
 impl Request for DeleteVideosRequest {
     type Response = DeleteVideo;
 
+    const METHOD: http::Method = http::Method::DELETE;
     const PATH: &'static str = "videos";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelManageVideos];
@@ -76,7 +78,7 @@ impl RequestDelete for DeleteVideosRequest {
         uri: &http::Uri,
         response: &str,
         status: http::StatusCode,
-    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestDeleteError>
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
     where
         Self: Sized,
     {
@@ -87,12 +89,16 @@ impl RequestDelete for DeleteVideosRequest {
                 request,
                 total: None,
                 other: None,
+                request_id: None,
+                diagnostics: Default::default(),
             }),
-            _ => Err(helix::HelixRequestDeleteError::InvalidResponse {
+            _ => Err(helix::HelixRequestError::InvalidResponse {
                 reason: "unexpected status",
                 response: response.to_string(),
                 status,
+                method: Self::METHOD,
                 uri: uri.clone(),
+                request_id: None,
             }),
         }
     }
@@ -45,6 +45,7 @@ use helix::RequestGet;
 ///
 /// [`get-videos`](https://dev.twitch.tv/docs/api/reference#get-videos)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct GetVideosRequest {
     /// ID of the video being queried. Limit: 100. If this is specified, you cannot use any of the optional query parameters below.
@@ -56,17 +57,12 @@ pub struct GetVideosRequest {
     /// ID of the game the video is of.
     #[builder(default, setter(into))]
     pub game_id: Option<types::CategoryId>,
-    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    /// Pagination parameters. Maximum of [`first`](helix::PaginationParams::first): 100. Default: 20.
     #[builder(default)]
-    pub after: Option<helix::Cursor>,
-    /// Cursor for backward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
-    #[builder(default)]
-    pub before: Option<helix::Cursor>,
-    /// Number of values to be returned when getting videos by user or game ID. Limit: 100. Default: 20.
-    #[builder(default)]
-    pub first: Option<usize>,
+    #[serde(flatten)]
+    pub pagination: helix::PaginationParams,
     /// Language of the video being queried. Limit: 1.
-    #[builder(default, setter(into))]
+    #[builder(default, setter(into, strip_option))]
     pub language: Option<String>,
     /// Period during which the video was created. Valid values: "all", "day", "week", "month". Default: "all".
     #[builder(default, setter(into))]
@@ -84,6 +80,11 @@ pub struct GetVideosRequest {
 ///
 /// [`get-videos`](https://dev.twitch.tv/docs/api/reference#get-videos)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Video {
@@ -105,7 +106,7 @@ pub struct Video {
     /// ID of the stream that the video originated from if the type is "archive". Otherwise set to null.
     pub stream_id: Option<types::StreamId>,
     /// Template URL for the thumbnail of the video.
-    pub thumbnail_url: String,
+    pub thumbnail_url: types::TemplatedImageUrl,
     /// Title of the video.
     pub title: String,
     /// Type of video. Valid values: "upload", "archive", "highlight".
@@ -127,6 +128,11 @@ pub struct Video {
 
 /// muted segment in a video.
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct MutedSegment {
@@ -139,15 +145,18 @@ pub struct MutedSegment {
 impl Request for GetVideosRequest {
     type Response = Vec<Video>;
 
+    const METHOD: http::Method = http::Method::GET;
     const PATH: &'static str = "videos";
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+
+    fn page_size(&self) -> Option<helix::PageSize> { self.pagination.first }
 }
 
 impl RequestGet for GetVideosRequest {}
 
 impl helix::Paginated for GetVideosRequest {
-    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.pagination.after = cursor }
 }
 
 #[cfg(test)]
@@ -159,37 +168,8 @@ fn test_request() {
         .build();
 
     // From twitch docs
-    let data = br#"
-{
-  "data": [
-    {
-      "id": "335921245",
-      "stream_id": null,
-      "user_id": "141981764",
-      "user_login": "twitchdev",
-      "user_name": "TwitchDev",
-      "title": "Twitch Developers 101",
-      "description": "Welcome to Twitch development! Here is a quick overview of our products and information to help you get started.",
-      "created_at": "2018-11-14T21:30:18Z",
-      "published_at": "2018-11-14T22:04:30Z",
-      "url": "https://www.twitch.tv/videos/335921245",
-      "thumbnail_url": "https://static-cdn.jtvnw.net/cf_vods/d2nvs31859zcd8/twitchdev/335921245/ce0f3a7f-57a3-4152-bc06-0c6610189fb3/thumb/index-0000000000-%{width}x%{height}.jpg",
-      "viewable": "public",
-      "view_count": 1863062,
-      "language": "en",
-      "type": "upload",
-      "duration": "3m21s",
-      "muted_segments": [
-        {
-          "duration": 30,
-          "offset": 120
-        }
-      ]
-    }
-  ],
-  "pagination": {}
-}
-"#
+    let data = crate::fixtures::helix::videos::GET_VIDEOS
+        .as_bytes()
         .to_vec();
 
     let http_response = http::Response::builder().body(data).unwrap();
@@ -31,6 +31,11 @@ pub use get_videos::{GetVideosRequest, Video};
 
 /// Sort order of the videos
 #[derive(PartialEq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[serde(rename_all = "lowercase")]
 pub enum Sort {
     /// Sort by time
@@ -43,6 +48,11 @@ pub enum Sort {
 
 /// Period during which the video was created
 #[derive(PartialEq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[serde(rename_all = "lowercase")]
 pub enum VideoPeriod {
     /// Filter by all. Effectively a no-op
@@ -57,6 +67,11 @@ pub enum VideoPeriod {
 
 /// Type of video.
 #[derive(PartialEq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[serde(rename_all = "lowercase")]
 pub enum VideoTypeFilter {
     /// All video types
@@ -0,0 +1,20 @@
+//! Helix endpoints regarding whispers
+use crate::{
+    helix::{self, Request},
+    types,
+};
+
+use serde::{Deserialize, Serialize};
+
+pub mod send_whisper;
+
+#[doc(inline)]
+pub use send_whisper::{SendWhisper, SendWhisperBody, SendWhisperRequest};
+
+/// Maximum length of a whisper, in characters
+///
+/// Whispers to a user that hasn't whispered the sender before are truncated to [`MAX_NEW_RECIPIENT_WHISPER_LENGTH`] characters.
+pub const MAX_WHISPER_LENGTH: usize = 10_000;
+
+/// Maximum length of a whisper to a user that hasn't whispered the sender before, in characters
+pub const MAX_NEW_RECIPIENT_WHISPER_LENGTH: usize = 500;
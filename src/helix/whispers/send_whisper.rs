@@ -0,0 +1,207 @@
+//! Sends a whisper message to the specified user.
+//! [`send-whisper`](https://dev.twitch.tv/docs/api/reference#send-whisper)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [SendWhisperRequest]
+//!
+//! To use this endpoint, construct a [`SendWhisperRequest`] with the [`SendWhisperRequest::builder()`] method.
+//!
+//! ```rust
+//! use twitch_api2::helix::whispers::send_whisper;
+//! let request = send_whisper::SendWhisperRequest::builder()
+//!     .from_user_id("1234")
+//!     .to_user_id("5678")
+//!     .build();
+//! ```
+//!
+//! ## Body: [SendWhisperBody]
+//!
+//! We also need to provide a body to the request containing the message.
+//!
+//! Messages longer than [`MAX_WHISPER_LENGTH`](super::MAX_WHISPER_LENGTH) fail when creating the request,
+//! see [`split_message_at`](crate::helix::chat::split_message_at) for sending longer texts.
+//!
+//! ```
+//! # use twitch_api2::helix::whispers::send_whisper;
+//! let body = send_whisper::SendWhisperBody::new("hello there");
+//! ```
+//!
+//! ## Response: [SendWhisper]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, whispers::send_whisper};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = send_whisper::SendWhisperRequest::builder()
+//!     .from_user_id("1234")
+//!     .to_user_id("5678")
+//!     .build();
+//! let body = send_whisper::SendWhisperBody::new("hello there");
+//! let response: send_whisper::SendWhisper = client.req_post(request, body, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`SendWhisperRequest::parse_response(None, &request.get_uri(), response)`](SendWhisperRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Send Whisper](super::send_whisper)
+///
+/// [`send-whisper`](https://dev.twitch.tv/docs/api/reference#send-whisper)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct SendWhisperRequest {
+    /// The ID of the user sending the whisper. This user must have a verified phone number. This ID must match the user ID in the user access token.
+    #[builder(setter(into))]
+    pub from_user_id: types::UserId,
+    /// The ID of the user to receive the whisper.
+    #[builder(setter(into))]
+    pub to_user_id: types::UserId,
+}
+
+/// Body Parameters for [Send Whisper](super::send_whisper)
+///
+/// [`send-whisper`](https://dev.twitch.tv/docs/api/reference#send-whisper)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct SendWhisperBody {
+    /// The whisper message to send. The message must not be empty.
+    ///
+    /// The maximum length is [500](super::MAX_NEW_RECIPIENT_WHISPER_LENGTH) characters if the user hasn't whispered the sender before,
+    /// and [10,000](super::MAX_WHISPER_LENGTH) characters otherwise. Longer messages are truncated by Twitch.
+    #[builder(setter(into))]
+    pub message: String,
+}
+
+impl SendWhisperBody {
+    /// Send `message` as a whisper
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl helix::HelixRequestBody for SendWhisperBody {
+    fn try_to_body(&self) -> Result<Vec<u8>, helix::BodyError> {
+        if self.message.is_empty() {
+            return Err(helix::BodyError::Custom("whisper must not be empty".into()));
+        }
+        helix::chat::check_message_length(&self.message, MAX_WHISPER_LENGTH)?;
+        serde_json::to_vec(self).map_err(Into::into)
+    }
+}
+
+/// Return Values for [Send Whisper](super::send_whisper)
+///
+/// [`send-whisper`](https://dev.twitch.tv/docs/api/reference#send-whisper)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[non_exhaustive]
+pub enum SendWhisper {
+    /// 204 - Successfully sent the whisper message or the message was silently dropped.
+    Success,
+}
+
+impl Request for SendWhisperRequest {
+    type Response = SendWhisper;
+
+    const METHOD: http::Method = http::Method::POST;
+    const PATH: &'static str = "whispers";
+    /// Scope needed for this endpoint, not yet known to [`twitch_oauth2`]
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("user:manage:whispers"),
+    )];
+}
+
+impl RequestPost for SendWhisperRequest {
+    type Body = SendWhisperBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestError>
+    where
+        Self: Sized,
+    {
+        match status {
+            http::StatusCode::NO_CONTENT => Ok(helix::Response {
+                data: SendWhisper::Success,
+                pagination: None,
+                request,
+                total: None,
+                other: None,
+                request_id: None,
+                diagnostics: Default::default(),
+            }),
+            _ => Err(helix::HelixRequestError::InvalidResponse {
+                reason: "unexpected status",
+                response: response.to_string(),
+                status,
+                method: Self::METHOD,
+                uri: uri.clone(),
+                request_id: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = SendWhisperRequest::builder()
+        .from_user_id("123")
+        .to_user_id("456")
+        .build();
+
+    let body = SendWhisperBody::new("hello");
+
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"message":"hello"}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/whispers?from_user_id=123&to_user_id=456"
+    );
+
+    dbg!(SendWhisperRequest::parse_response(Some(req), &uri, http_response).unwrap());
+
+    assert!(matches!(
+        SendWhisperBody::new("a".repeat(MAX_WHISPER_LENGTH + 1)).try_to_body(),
+        Err(BodyError::Custom(_))
+    ));
+    assert!(matches!(
+        SendWhisperBody::new("").try_to_body(),
+        Err(BodyError::Custom(_))
+    ));
+}
@@ -83,7 +83,19 @@
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>reqwest_client</code></span> | Enables reqwest for [`HttpClient`]. Note that this does not enable any default TLS backend, if you get `invalid URL, scheme is not http`, specify `reqwest` in your Cargo.toml. By default, `reqwest` uses feature `default-tls` |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>unsupported</code></span> | Enables undocumented or experimental endpoints, topics or features. Breakage may occur, semver compatibility not guaranteed. |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>trace_unknown_fields</code></span> | Logs ignored fields as `WARN` log messages where  applicable. Please consider using this and filing an issue or PR when a new field has been added to the endpoint but not added to this library. |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>diagnostics</code></span> | Collects [diagnostics](helix::diagnostics) on Helix responses, like ignored fields and odd pagination. A structured alternative to `trace_unknown_fields` for reporting changes in the API. Fields collected with `collect_other_fields` are not reported. |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>deny_unknown_fields</code></span> | Adds `#[serde(deny_unknown_fields)]` on all applicable structs/enums. Please consider using this and filing an issue or PR when a new field has been added to the endpoint but not added to this library. |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>collect_other_fields</code></span> | Collects fields returned by Helix besides `data`, `pagination` and `total` into [`helix::Response::other`] for all endpoints, not only the ones known to return them. Makes parsing large responses slower. |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>fixtures</code></span> | Enables [`fixtures`], example payloads for endpoints that can be used to mock responses. |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>mock_data</code></span> | Enables [`mock`], generating realistic responses and EventSub payloads for tests. |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>metrics</code></span> | Emits [metrics](https://docs.rs/metrics) for requests made with [`HelixClient`], see `helix::metrics` for what is recorded. |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>arbitrary</code></span> | Implements [`arbitrary::Arbitrary`](https://docs.rs/arbitrary) for requests, responses and [`types`], for property-based testing. |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>rkyv</code></span> | Implements [`rkyv`](https://docs.rs/rkyv) archiving for responses, EventSub payloads and [`types`], for persisting raw Twitch data. |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>sqlx</code></span> | Implements [`sqlx`](https://docs.rs/sqlx) `Type`, `Encode` and `Decode` for braid types like [`types::UserId`], storing them as strings. sqlx needs a runtime, enable one of its `runtime-*` features too, e.g. `sqlx/runtime-tokio-rustls`. |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>simd_json</code></span> | Parses Helix responses with [`simd-json`](https://docs.rs/simd-json), see [`parse_json_owned`]. Has no effect together with `diagnostics` or `trace_unknown_fields`, which need `serde_json` to find ignored fields. |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>gzip</code></span> | Requests gzip compressed Helix responses and decompresses them, see [`helix::encoding`]. |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>brotli</code></span> | Requests brotli compressed Helix responses and decompresses them, see [`helix::encoding`]. |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>bot</code></span> | Enables [`bot`], a small framework wiring [`HelixClient`], token refresh and EventSub websockets together. |
 
 // FIXME: This is a hack to prevent early pass failing on
 // `arbitrary expressions in key-value attributes are unstable` on stable rust pre 1.54.
@@ -111,6 +123,20 @@ pub mod pubsub;
 #[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
 pub mod eventsub;
 
+#[cfg(feature = "bot")]
+#[cfg_attr(nightly, doc(cfg(feature = "bot")))]
+pub mod bot;
+
+pub mod prelude;
+
+#[cfg(any(test, feature = "fixtures"))]
+#[cfg_attr(nightly, doc(cfg(feature = "fixtures")))]
+pub mod fixtures;
+
+#[cfg(any(test, feature = "mock_data"))]
+#[cfg_attr(nightly, doc(cfg(feature = "mock_data")))]
+pub mod mock;
+
 #[cfg(all(feature = "helix", feature = "client"))]
 #[doc(inline)]
 pub use crate::helix::HelixClient;
@@ -122,6 +148,12 @@ pub use crate::tmi::TmiClient;
 #[doc(no_inline)]
 pub use twitch_oauth2;
 
+#[cfg(feature = "twitch_oauth2")]
+mod scopes;
+#[cfg(feature = "twitch_oauth2")]
+#[cfg_attr(nightly, doc(cfg(feature = "twitch_oauth2")))]
+pub use scopes::{scopes_for, ScopeRequirement};
+
 #[cfg(feature = "client")]
 #[cfg_attr(nightly, doc(cfg(feature = "client")))]
 pub mod client;
@@ -133,7 +165,12 @@ pub use client::Client as HttpClient;
 #[cfg(feature = "client")]
 pub use client::DummyHttpClient;
 
-#[cfg(any(feature = "helix", feature = "tmi", feature = "pubsub"))]
+#[cfg(any(
+    feature = "helix",
+    feature = "tmi",
+    feature = "pubsub",
+    feature = "eventsub"
+))]
 /// Generate a url with a default if `mock_api` feature is disabled, or env var is not defined or is invalid utf8
 macro_rules! mock_env_url {
     ($var:literal, $default:expr $(,)?) => {
@@ -176,6 +213,15 @@ pub static TWITCH_TMI_URL: once_cell::sync::Lazy<url::Url> =
 #[cfg_attr(nightly, doc(cfg(feature = "pubsub")))]
 pub static TWITCH_PUBSUB_URL: once_cell::sync::Lazy<url::Url> =
     mock_env_url!("TWITCH_PUBSUB_URL", "wss://pubsub-edge.twitch.tv");
+/// Location to twitch EventSub websockets
+///
+/// Can be overriden when feature `mock_api` is enabled with environment variable `TWITCH_EVENTSUB_WEBSOCKET_URL`.
+#[cfg(feature = "eventsub")]
+#[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
+pub static TWITCH_EVENTSUB_WEBSOCKET_URL: once_cell::sync::Lazy<url::Url> = mock_env_url!(
+    "TWITCH_EVENTSUB_WEBSOCKET_URL",
+    "wss://eventsub.wss.twitch.tv/ws"
+);
 
 /// Client for Twitch APIs.
 ///
@@ -273,6 +319,17 @@ pub enum DeserError {
     },
 }
 
+/// Log or record a key ignored while parsing
+#[cfg(any(feature = "trace_unknown_fields", feature = "diagnostics"))]
+fn ignored_key(path: serde_ignored::Path) {
+    #[cfg(feature = "trace_unknown_fields")]
+    tracing::warn!(key=%path,"Found ignored key");
+    #[cfg(feature = "diagnostics")]
+    helix::diagnostics::record(|| helix::diagnostics::Diagnostic::UnknownField {
+        path: path.to_string(),
+    });
+}
+
 /// Parse a string as `T`, logging ignored fields and giving a more detailed error message on parse errors
 ///
 /// The log_ignored argument decides if a trace of ignored value should be emitted
@@ -281,15 +338,13 @@ pub fn parse_json<'a, T: serde::Deserialize<'a>>(
     s: &'a str,
     #[allow(unused_variables)] log_ignored: bool,
 ) -> Result<T, DeserError> {
-    #[cfg(feature = "trace_unknown_fields")]
+    #[cfg(any(feature = "trace_unknown_fields", feature = "diagnostics"))]
     {
         let jd = &mut serde_json::Deserializer::from_str(s);
         let mut track = serde_path_to_error::Track::new();
         let pathd = serde_path_to_error::Deserializer::new(jd, &mut track);
         if log_ignored {
-            let mut fun = |path: serde_ignored::Path| {
-                tracing::warn!(key=%path,"Found ignored key");
-            };
+            let mut fun = |path: serde_ignored::Path| ignored_key(path);
             serde_ignored::deserialize(pathd, &mut fun).map_err(|e| DeserError::PathError {
                 path: track.path().to_string(),
                 error: e,
@@ -301,7 +356,7 @@ pub fn parse_json<'a, T: serde::Deserialize<'a>>(
             })
         }
     }
-    #[cfg(not(feature = "trace_unknown_fields"))]
+    #[cfg(not(any(feature = "trace_unknown_fields", feature = "diagnostics")))]
     {
         let jd = &mut serde_json::Deserializer::from_str(s);
         serde_path_to_error::deserialize(jd).map_err(|e| DeserError::PathError {
@@ -311,21 +366,44 @@ pub fn parse_json<'a, T: serde::Deserialize<'a>>(
     }
 }
 
+/// Parse a string as an owned `T`, like [`parse_json`]
+///
+/// With the `simd_json` feature, this uses [`simd-json`](https://docs.rs/simd-json), which picks the fastest implementation the CPU supports.
+/// If parsing fails, it falls back to [`parse_json`], so errors are the same with or without the feature.
+/// Ignored fields can only be found with `serde_json`, so the `trace_unknown_fields` and `diagnostics` features always use [`parse_json`].
+#[cfg(all(feature = "serde_json", feature = "serde_path_to_error"))]
+pub fn parse_json_owned<T: serde::de::DeserializeOwned>(
+    s: &str,
+    log_ignored: bool,
+) -> Result<T, DeserError> {
+    // ignored fields can only be traced by serde_json
+    #[cfg(all(
+        feature = "simd_json",
+        not(feature = "trace_unknown_fields"),
+        not(feature = "diagnostics"),
+    ))]
+    {
+        let mut bytes = s.as_bytes().to_vec();
+        if let Ok(t) = simd_json::serde::from_slice(&mut bytes) {
+            return Ok(t);
+        }
+    }
+    parse_json(s, log_ignored)
+}
+
 /// Parse a json Value as `T`, logging ignored fields and giving a more detailed error message on parse errors
 #[cfg(all(feature = "serde_json", feature = "serde_path_to_error"))]
 pub fn parse_json_value<'a, T: serde::Deserialize<'a>>(
     value: serde_json::Value,
     #[allow(unused_variables)] log_ignored: bool,
 ) -> Result<T, DeserError> {
-    #[cfg(feature = "trace_unknown_fields")]
+    #[cfg(any(feature = "trace_unknown_fields", feature = "diagnostics"))]
     {
         let de = serde::de::IntoDeserializer::into_deserializer(value);
         let mut track = serde_path_to_error::Track::new();
         let pathd = serde_path_to_error::Deserializer::new(de, &mut track);
         if log_ignored {
-            let mut fun = |path: serde_ignored::Path| {
-                tracing::warn!(key=%path,"Found ignored key");
-            };
+            let mut fun = |path: serde_ignored::Path| ignored_key(path);
             serde_ignored::deserialize(pathd, &mut fun).map_err(|e| DeserError::PathError {
                 path: track.path().to_string(),
                 error: e,
@@ -337,7 +415,7 @@ pub fn parse_json_value<'a, T: serde::Deserialize<'a>>(
             })
         }
     }
-    #[cfg(not(feature = "trace_unknown_fields"))]
+    #[cfg(not(any(feature = "trace_unknown_fields", feature = "diagnostics")))]
     {
         let de = serde::de::IntoDeserializer::into_deserializer(value);
         serde_path_to_error::deserialize(de).map_err(|e| DeserError::PathError {
@@ -358,6 +436,9 @@ where
     Ok(Option::deserialize(deserializer)?.unwrap_or_default())
 }
 
+#[cfg(test)]
+mod public_fields;
+
 #[cfg(test)]
 pub mod tests {
     #[track_caller]
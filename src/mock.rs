@@ -0,0 +1,371 @@
+//! Generate realistic response data for tests.
+//!
+//! Types implementing [`MockData`] can be created from a seed, the same seed always gives the same value.
+//! Use this instead of handcrafting JSON when testing code that handles responses from Twitch.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use twitch_api2::{helix::users::User, mock::MockData};
+//!
+//! let user = User::mock(1);
+//! assert_eq!(user, User::mock(1));
+//! assert_ne!(user.id, User::mock(2).id);
+//!
+//! let users = User::mock_many(10);
+//! assert_eq!(users.len(), 10);
+//! ```
+// The generators are only used by implementations for enabled modules
+#![cfg_attr(not(any(feature = "helix", feature = "eventsub")), allow(dead_code))]
+
+use crate::types;
+
+/// Types that can be generated for tests
+pub trait MockData: Sized {
+    /// Generate a value from a seed
+    fn mock(seed: u64) -> Self;
+
+    /// Generate `n` values, from the seeds `0..n`
+    fn mock_many(n: usize) -> Vec<Self> { (0..n as u64).map(Self::mock).collect() }
+}
+
+/// Placeholder logins, a number is appended. Don't use the names of real accounts.
+const NAMES: &[&str] = &[
+    "mockuser",
+    "testuser",
+    "exampleuser",
+    "mockstreamer",
+    "mockviewer",
+    "mockmod",
+    "mockchatter",
+    "mockbot",
+];
+
+const TITLES: &[&str] = &[
+    "Chill stream, come hang out",
+    "Ranked grind until top 500",
+    "First playthrough, no spoilers!",
+    "Speedrun practice",
+    "Just Chatting and coffee",
+];
+
+/// Categories as `(id, name)`
+const CATEGORIES: &[(&str, &str)] = &[
+    ("509658", "Just Chatting"),
+    ("33214", "Fortnite"),
+    ("21779", "League of Legends"),
+    ("27471", "Minecraft"),
+    ("417752", "Talk Shows & Podcasts"),
+];
+
+/// Deterministic pseudo-random numbers, see [SplitMix64](https://prng.di.unimi.it/splitmix64.c)
+struct Gen(u64);
+
+impl Gen {
+    fn new(seed: u64) -> Self { Gen(seed) }
+
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: u64) -> u64 { self.next() % n }
+
+    fn bool(&mut self) -> bool { self.next() & 1 == 1 }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    fn id(&mut self) -> String { (10_000_000 + self.below(900_000_000)).to_string() }
+
+    /// A user as `(id, login, display name)`
+    fn user(&mut self) -> (types::UserId, types::UserName, types::DisplayName) {
+        let login = format!("{}{}", self.pick(NAMES), self.below(1000));
+        let mut display_name = login.clone();
+        display_name[..1].make_ascii_uppercase();
+        (self.id().into(), login.into(), display_name.into())
+    }
+
+    fn timestamp(&mut self) -> types::Timestamp {
+        types::Timestamp::new(format!(
+            "2021-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            1 + self.below(12),
+            1 + self.below(28),
+            self.below(24),
+            self.below(60),
+            self.below(60)
+        ))
+        .expect("generated timestamp should be valid")
+    }
+
+    fn tier(&mut self) -> types::SubscriptionTier {
+        use types::SubscriptionTier::*;
+        self.pick(&[Tier1, Tier1, Tier1, Tier2, Tier3, Prime])
+            .clone()
+    }
+}
+
+#[cfg(feature = "helix")]
+impl MockData for crate::helix::users::User {
+    fn mock(seed: u64) -> Self {
+        let mut gen = Gen::new(seed);
+        let (id, login, display_name) = gen.user();
+        crate::helix::users::User {
+            broadcaster_type: Some(
+                gen.pick(&[
+                    types::BroadcasterType::None,
+                    types::BroadcasterType::Affiliate,
+                    types::BroadcasterType::Partner,
+                ])
+                .clone(),
+            ),
+            created_at: gen.timestamp(),
+            description: Some(format!("Hi, I'm {}!", display_name)),
+            email: None,
+            offline_image_url: None,
+            profile_image_url: Some(
+                format!(
+                    "https://static-cdn.jtvnw.net/jtv_user_pictures/{}-profile_image-300x300.png",
+                    login
+                )
+                .into(),
+            ),
+            type_: Some(types::UserType::None),
+            view_count: gen.below(1_000_000) as usize,
+            id,
+            login,
+            display_name,
+        }
+    }
+}
+
+#[cfg(feature = "helix")]
+impl MockData for crate::helix::streams::Stream {
+    fn mock(seed: u64) -> Self {
+        let mut gen = Gen::new(seed);
+        let (user_id, user_login, user_name) = gen.user();
+        let (game_id, game_name) = *gen.pick(CATEGORIES);
+        crate::helix::streams::Stream {
+            game_id: game_id.into(),
+            game_name: game_name.into(),
+            id: gen.id().into(),
+            language: "en".into(),
+            is_mature: gen.bool(),
+            started_at: gen.timestamp(),
+            tag_ids: vec![],
+            tags: vec!["English".to_owned()],
+            thumbnail_url: format!(
+                "https://static-cdn.jtvnw.net/previews-ttv/live_user_{}-{{width}}x{{height}}.jpg",
+                user_login
+            )
+            .into(),
+            title: gen.pick(TITLES).to_string(),
+            type_: crate::helix::streams::StreamType::Live,
+            viewer_count: gen.below(50_000) as usize,
+            user_id,
+            user_name,
+            user_login,
+        }
+    }
+}
+
+#[cfg(feature = "helix")]
+impl MockData for crate::helix::subscriptions::BroadcasterSubscription {
+    fn mock(seed: u64) -> Self {
+        let mut gen = Gen::new(seed);
+        let (broadcaster_id, broadcaster_login, broadcaster_name) = gen.user();
+        let (user_id, user_login, user_name) = gen.user();
+        let tier = gen.tier();
+        let is_gift = tier != types::SubscriptionTier::Prime && gen.below(4) == 0;
+        let (gifter_id, gifter_login, gifter_name) = if is_gift {
+            let (id, login, name) = gen.user();
+            (Some(id), Some(login), Some(name))
+        } else {
+            (None, None, None)
+        };
+        crate::helix::subscriptions::BroadcasterSubscription {
+            plan_name: format!("Channel Subscription ({})", broadcaster_login),
+            broadcaster_id,
+            broadcaster_login,
+            broadcaster_name,
+            gifter_id,
+            gifter_login,
+            gifter_name,
+            is_gift,
+            tier,
+            user_id,
+            user_login,
+            user_name,
+        }
+    }
+}
+
+#[cfg(feature = "eventsub")]
+impl MockData for crate::eventsub::channel::ChannelFollowV1Payload {
+    fn mock(seed: u64) -> Self {
+        let mut gen = Gen::new(seed);
+        let (broadcaster_user_id, broadcaster_user_login, broadcaster_user_name) = gen.user();
+        let (user_id, user_login, user_name) = gen.user();
+        crate::eventsub::channel::ChannelFollowV1Payload {
+            broadcaster_user_id,
+            broadcaster_user_login,
+            broadcaster_user_name,
+            user_id,
+            user_name,
+            user_login,
+            followed_at: gen.timestamp(),
+        }
+    }
+}
+
+#[cfg(feature = "eventsub")]
+impl MockData for crate::eventsub::channel::ChannelSubscribeV1Payload {
+    fn mock(seed: u64) -> Self {
+        let mut gen = Gen::new(seed);
+        let (broadcaster_user_id, broadcaster_user_login, broadcaster_user_name) = gen.user();
+        let (user_id, user_login, user_name) = gen.user();
+        let tier = gen.tier();
+        crate::eventsub::channel::ChannelSubscribeV1Payload {
+            broadcaster_user_id,
+            broadcaster_user_login,
+            broadcaster_user_name,
+            is_gift: tier != types::SubscriptionTier::Prime && gen.below(4) == 0,
+            tier,
+            user_id,
+            user_login,
+            user_name,
+        }
+    }
+}
+
+#[cfg(feature = "eventsub")]
+impl MockData for crate::eventsub::channel::ChannelCheerV1Payload {
+    fn mock(seed: u64) -> Self {
+        let mut gen = Gen::new(seed);
+        let (broadcaster_user_id, broadcaster_user_login, broadcaster_user_name) = gen.user();
+        let is_anonymous = gen.below(10) == 0;
+        let (user_id, user_login, user_name) = if is_anonymous {
+            (None, None, None)
+        } else {
+            let (id, login, name) = gen.user();
+            (Some(id), Some(login), Some(name))
+        };
+        let bits = *gen.pick(&[1, 10, 100, 500, 1000, 5000]);
+        crate::eventsub::channel::ChannelCheerV1Payload {
+            bits,
+            broadcaster_user_id,
+            broadcaster_user_login,
+            broadcaster_user_name,
+            is_anonymous,
+            message: format!("Cheer{} great stream!", bits),
+            user_id,
+            user_login,
+            user_name,
+        }
+    }
+}
+
+#[cfg(feature = "eventsub")]
+impl MockData for crate::eventsub::stream::StreamOnlineV1Payload {
+    fn mock(seed: u64) -> Self {
+        let mut gen = Gen::new(seed);
+        let (broadcaster_user_id, broadcaster_user_login, broadcaster_user_name) = gen.user();
+        crate::eventsub::stream::StreamOnlineV1Payload {
+            broadcaster_user_id,
+            broadcaster_user_login,
+            broadcaster_user_name,
+            id: gen.id(),
+            type_: types::VideoType::Live,
+            started_at: gen.timestamp(),
+        }
+    }
+}
+
+#[cfg(feature = "eventsub")]
+impl MockData for crate::eventsub::stream::StreamOfflineV1Payload {
+    fn mock(seed: u64) -> Self {
+        let (broadcaster_user_id, broadcaster_user_login, broadcaster_user_name) =
+            Gen::new(seed).user();
+        crate::eventsub::stream::StreamOfflineV1Payload {
+            broadcaster_user_id,
+            broadcaster_user_login,
+            broadcaster_user_name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mocked values should survive a round trip through JSON, like real responses
+    fn roundtrip<T>()
+    where T: MockData + serde::Serialize + serde::de::DeserializeOwned + PartialEq + std::fmt::Debug
+    {
+        for value in T::mock_many(50) {
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(serde_json::from_str::<T>(&json).unwrap(), value, "{}", json);
+        }
+    }
+
+    /// Mocked values should survive being archived with rkyv
+    #[cfg(feature = "rkyv")]
+    fn rkyv_roundtrip<T>()
+    where
+        T: MockData
+            + rkyv::Archive
+            + rkyv::Serialize<rkyv::ser::serializers::AllocSerializer<256>>
+            + PartialEq
+            + std::fmt::Debug,
+        T::Archived: rkyv::Deserialize<T, rkyv::Infallible>, {
+        use rkyv::Deserialize;
+        for value in T::mock_many(50) {
+            let bytes = rkyv::to_bytes::<_, 256>(&value).unwrap();
+            // SAFETY: the bytes were just serialized from a `T`
+            let archived = unsafe { rkyv::archived_root::<T>(&bytes) };
+            assert_eq!(archived.deserialize(&mut rkyv::Infallible).unwrap(), value);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "helix", feature = "rkyv"))]
+    fn helix_rkyv() {
+        rkyv_roundtrip::<crate::helix::users::User>();
+        rkyv_roundtrip::<crate::helix::streams::Stream>();
+        rkyv_roundtrip::<crate::helix::subscriptions::BroadcasterSubscription>();
+    }
+
+    #[test]
+    #[cfg(all(feature = "eventsub", feature = "rkyv"))]
+    fn eventsub_rkyv() {
+        rkyv_roundtrip::<crate::eventsub::channel::ChannelFollowV1Payload>();
+        rkyv_roundtrip::<crate::eventsub::channel::ChannelCheerV1Payload>();
+        rkyv_roundtrip::<crate::eventsub::stream::StreamOnlineV1Payload>();
+    }
+
+    #[test]
+    #[cfg(feature = "helix")]
+    fn helix() {
+        roundtrip::<crate::helix::users::User>();
+        roundtrip::<crate::helix::streams::Stream>();
+        roundtrip::<crate::helix::subscriptions::BroadcasterSubscription>();
+        assert_eq!(
+            crate::helix::users::User::mock(7),
+            crate::helix::users::User::mock(7)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "eventsub")]
+    fn eventsub() {
+        roundtrip::<crate::eventsub::channel::ChannelFollowV1Payload>();
+        roundtrip::<crate::eventsub::channel::ChannelSubscribeV1Payload>();
+        roundtrip::<crate::eventsub::channel::ChannelCheerV1Payload>();
+        roundtrip::<crate::eventsub::stream::StreamOnlineV1Payload>();
+        roundtrip::<crate::eventsub::stream::StreamOfflineV1Payload>();
+    }
+}
@@ -0,0 +1,55 @@
+//! Commonly used traits and types, for glob importing.
+//!
+//! ```rust
+//! use twitch_api2::prelude::*;
+//! ```
+//!
+//! This brings the request traits into scope, together with the clients and the request types of the most used endpoints.
+//! Everything else is still reachable through the re-exported modules, e.g. [`helix::points`](crate::helix::points).
+//! To find the request type of an endpoint by its name, see [`helix::endpoints`](crate::helix::endpoints).
+
+#[doc(no_inline)]
+pub use crate::types;
+
+#[cfg(feature = "helix")]
+#[doc(no_inline)]
+pub use crate::helix::{
+    self, ModeratorAuth, Paginated, Request, RequestDelete, RequestGet, RequestPatch, RequestPost,
+    RequestPut, Response,
+};
+
+#[cfg(feature = "helix")]
+#[doc(no_inline)]
+pub use crate::helix::{
+    channels::{
+        GetChannelEditorsRequest, GetChannelInformationRequest, GetVipsRequest,
+        ModifyChannelInformationRequest,
+    },
+    chat::{GetChannelEmotesRequest, GetChattersRequest},
+    moderation::{GetBannedUsersRequest, GetModeratorsRequest},
+    search::{SearchCategoriesRequest, SearchChannelsRequest},
+    streams::{GetFollowedStreamsRequest, GetStreamsRequest},
+    subscriptions::GetBroadcasterSubscriptionsRequest,
+    users::{GetUsersFollowsRequest, GetUsersRequest},
+    videos::GetVideosRequest,
+};
+
+#[cfg(all(feature = "helix", feature = "client"))]
+#[doc(no_inline)]
+pub use crate::helix::{ClientRequestError, HelixClient};
+
+#[cfg(feature = "client")]
+#[doc(no_inline)]
+pub use crate::HttpClient;
+
+#[cfg(all(feature = "client", any(feature = "helix", feature = "tmi")))]
+#[doc(no_inline)]
+pub use crate::TwitchClient;
+
+#[cfg(feature = "twitch_oauth2")]
+#[doc(no_inline)]
+pub use twitch_oauth2::{AppAccessToken, TwitchToken, UserToken};
+
+#[cfg(feature = "eventsub")]
+#[doc(no_inline)]
+pub use crate::eventsub::{self, Event, EventSubscription};
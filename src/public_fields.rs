@@ -0,0 +1,756 @@
+//! Compile-time check that response types expose all their fields.
+//!
+//! A response with a private field can be deserialized, but not used outside this crate.
+//! Destructuring a struct without `..` outside of its module only compiles if every field is listed and visible,
+//! so a new private field, or a new field that's missing from the list below, fails the build.
+//!
+//! Add new response and payload types to the matching list.
+#![allow(dead_code)]
+
+#[cfg(feature = "eventsub")]
+use crate::eventsub::channel::ChannelFollowV1;
+
+/// Assert that the listed fields are all the fields of the type, and that all of them are public.
+///
+/// Generic types are checked with concrete parameters, e.g. `eventsub::Payload<ChannelFollowV1> { .. }`
+macro_rules! assert_fields_public {
+    ($($(#[$meta:meta])* $($segment:ident)::+ $(<$($generic:ty),+>)? { $($field:ident),* $(,)? })*) => {
+        $(
+            $(#[$meta])*
+            const _: () = {
+                fn destructure(value: crate::$($segment)::+ $(<$($generic),+>)?) {
+                    let crate::$($segment)::+ { $($field: _),* } = value;
+                }
+            };
+        )*
+    };
+}
+
+assert_fields_public! {
+    types::ResubscriptionEmote { begin, end, id }
+    types::TwitchCategory { box_art_url, id, name }
+    types::User { id, login, display_name, profile_image_url }
+    types::Image { url_1x, url_2x, url_4x }
+    types::GlobalCooldown { is_enabled, global_cooldown_seconds }
+    types::PollChoice { id, title, votes, channel_points_votes, bits_votes }
+    types::PredictionOutcome { id, title, users, channel_points, top_predictors, color }
+    types::PredictionTopPredictors { id, name, login, channel_points_used, channel_points_won }
+}
+
+#[cfg(feature = "helix")]
+assert_fields_public! {
+    helix::bits::get_bits_leaderboard::BitsLeaderboard { leaderboard, date_range, total }
+    helix::bits::get_bits_leaderboard::DateRange { started_at, ended_at }
+    helix::bits::get_bits_leaderboard::LeaderboardUser {
+        rank, score, user_id, user_name, user_login,
+    }
+    helix::bits::get_cheermotes::Cheermote {
+        is_charitable, last_updated, order, prefix, tiers, type_,
+    }
+    helix::bits::get_cheermotes::Tiers { can_cheer, color, id, images, min_bits, show_in_bits_card }
+    helix::bits::get_cheermotes::CheermoteImages { dark, light }
+    helix::bits::get_cheermotes::CheermoteImage { animated, static_ }
+    helix::bits::get_cheermotes::CheermoteImageArray { url_1x, url_1_5x, url_2x, url_3x, url_4x }
+    helix::channels::get_channel_editors::Editor { user_id, user_name, created_at }
+    helix::channels::get_channel_followers::Follower { followed_at, user_id, user_login, user_name }
+    helix::channels::get_channel_information::ChannelInformation {
+        broadcaster_id, broadcaster_login, broadcaster_name, game_id, game_name,
+        broadcaster_language, title, description, delay, tags,
+    }
+    helix::channels::get_vips::Vip { user_id, user_name, user_login }
+    helix::channels::start_commercial::StartCommercial { length, message, retry_after }
+    helix::charity::CharityAmount { value, decimal_places, currency }
+    helix::charity::get_charity_campaign_donations::CharityDonation {
+        id, campaign_id, user_id, user_login, user_name, amount,
+    }
+    helix::chat::BadgeSet { set_id, versions }
+    helix::chat::ChatBadge { id, image_url_1x, image_url_2x, image_url_4x }
+    helix::chat::EmotesExtras { template }
+    helix::chat::ChannelEmote {
+        id, name, images, tier, emote_type, emote_set_id, format, scale, theme_mode,
+    }
+    helix::chat::GlobalEmote { id, name, images, format, scale, theme_mode }
+    helix::chat::get_chat_settings::ChatSettings {
+        broadcaster_id, emote_mode, follower_mode, follower_mode_duration, moderator_id,
+        non_moderator_chat_delay, non_moderator_chat_delay_duration, slow_mode, slow_mode_wait_time,
+        subscriber_mode, unique_chat_mode,
+    }
+    helix::chat::get_chatters::Chatter { user_id, user_login, user_name }
+    helix::chat::get_chatters::ChattersExtras { total }
+    helix::chat::get_emote_sets::Emote {
+        id, name, images, emote_type, emote_set_id, owner_id, format, scale, theme_mode,
+    }
+    helix::clips::get_clips::Clip {
+        broadcaster_id, broadcaster_name, created_at, creator_id, creator_name, duration, embed_url,
+        game_id, id, language, thumbnail_url, title, url, video_id, view_count,
+    }
+    helix::extensions::BitsProductCost { amount, type_ }
+    helix::extensions::ExtensionSecrets { format_version, secrets }
+    helix::extensions::ExtensionSecret { content, active_at, expires_at }
+    helix::extensions::ExtensionConfigurationSegment { segment, broadcaster_id, content, version }
+    helix::extensions::get_extension_bits_products::ExtensionBitsProduct {
+        sku, cost, in_development, display_name, expiration, is_broadcast,
+    }
+    helix::extensions::get_extension_transactions::ExtensionTransaction {
+        id, timestamp, broadcaster_id, broadcaster_login, broadcaster_name, user_id, user_login,
+        user_name, product_type, product_data,
+    }
+    helix::extensions::get_extension_transactions::TransactionProductData {
+        domain, sku, cost, in_development, display_name, expiration, broadcast,
+    }
+    helix::goals::get_creator_goals::CreatorGoal {
+        id, broadcaster_id, broadcaster_name, broadcaster_login, type_, description, current_amount,
+        target_amount, created_at,
+    }
+    helix::hypetrain::Contribution { total, type_, user }
+    helix::hypetrain::get_hypetrain_events::HypeTrainEvent {
+        id, event_type, event_timestamp, version, event_data,
+    }
+    helix::hypetrain::get_hypetrain_events::HypeTrainEventData {
+        broadcaster_id, expires_at, cooldown_end_time, goal, last_contribution, level, started_at,
+        top_contributions, total, id,
+    }
+    helix::moderation::add_blocked_term::BlockedTerm {
+        broadcaster_id, moderator_id, id, text, created_at, updated_at, expires_at,
+    }
+    helix::moderation::check_automod_status::CheckAutoModStatus { msg_id, is_permitted }
+    helix::moderation::get_banned_events::BannedEvent {
+        id, event_type, event_timestamp, version, event_data,
+    }
+    helix::moderation::get_banned_users::BannedUser {
+        user_id, user_name, user_login, expires_at, reason, moderator_id, moderator_login,
+        moderator_name,
+    }
+    helix::moderation::get_moderated_channels::ModeratedChannel {
+        broadcaster_id, broadcaster_login, broadcaster_name,
+    }
+    helix::moderation::get_moderator_events::ModeratorEvent {
+        id, event_type, event_timestamp, version, event_data,
+    }
+    helix::moderation::get_moderators::Moderator { user_id, user_name, user_login }
+    helix::moderation::update_automod_settings::AutoModLevels {
+        aggression, bullying, disability, misogyny, race_ethnicity_or_religion, sex_based_terms,
+        sexuality_sex_or_gender, swearing,
+    }
+    helix::moderation::update_automod_settings::AutoModSettings {
+        broadcaster_id, moderator_id, overall_level, disability, aggression,
+        sexuality_sex_or_gender, misogyny, bullying, swearing, race_ethnicity_or_religion,
+        sex_based_terms,
+    }
+    helix::points::get_custom_reward::CustomReward {
+        broadcaster_id, broadcaster_login, broadcaster_name, id, title, prompt, cost, image,
+        default_image, background_color, is_enabled, is_user_input_required, max_per_stream_setting,
+        max_per_user_per_stream_setting, global_cooldown_setting, is_paused, is_in_stock,
+        should_redemptions_skip_request_queue, redemptions_redeemed_current_stream,
+        cooldown_expires_at,
+    }
+    helix::points::get_custom_reward_redemption::CustomRewardRedemption {
+        broadcaster_id, broadcaster_name, broadcaster_login, id, user_id, user_name, user_login,
+        reward, user_input, status, redeemed_at,
+    }
+    helix::points::get_custom_reward_redemption::Reward { id, title, prompt, cost }
+    helix::polls::create_poll::NewPollChoice { title }
+    helix::polls::get_polls::Poll {
+        id, broadcaster_id, broadcaster_name, broadcaster_login, title, choices,
+        bits_voting_enabled, bits_per_vote, channel_points_voting_enabled, channel_points_per_vote,
+        status, duration, started_at, ended_at,
+    }
+    helix::predictions::create_prediction::NewPredictionOutcome { title }
+    helix::predictions::get_predictions::Prediction {
+        id, broadcaster_id, broadcaster_name, broadcaster_login, title, winning_outcome_id,
+        outcomes, prediction_window, status, created_at, ended_at, locked_at,
+    }
+    helix::schedule::ScheduledBroadcasts {
+        segments, broadcaster_id, broadcaster_name, broadcaster_login, vacation,
+    }
+    helix::schedule::Segment {
+        id, start_time, end_time, title, canceled_until, category, is_recurring,
+    }
+    helix::schedule::Category { id, name }
+    helix::schedule::Vacation { start_time, end_time }
+    helix::search::search_channels::Channel {
+        game_id, game_name, id, display_name, broadcaster_language, broadcaster_login, title,
+        thumbnail_url, is_live, started_at, tag_ids,
+    }
+    helix::streams::get_stream_markers::StreamMarkers { user_id, user_name, user_login, videos }
+    helix::streams::get_stream_markers::VideoMarkers { video_id, markers }
+    helix::streams::get_stream_markers::StreamMarker {
+        id, created_at, description, position_seconds, url,
+    }
+    helix::streams::get_streams::Stream {
+        game_id, game_name, id, language, is_mature, started_at, tag_ids, tags, thumbnail_url,
+        title, type_, user_id, user_name, user_login, viewer_count,
+    }
+    helix::streams::session::StreamSession {
+        broadcaster_id, stream_id, started_at, ended_at, video_id, markers,
+    }
+    helix::subscriptions::check_user_subscription::UserSubscription {
+        broadcaster_id, broadcaster_login, broadcaster_name, is_gift, gifter_login, gifter_name,
+        tier,
+    }
+    helix::subscriptions::get_broadcaster_subscriptions::BroadcasterSubscription {
+        broadcaster_id, broadcaster_login, broadcaster_name, gifter_id, gifter_login, gifter_name,
+        is_gift, tier, plan_name, user_id, user_login, user_name,
+    }
+    helix::subscriptions::get_broadcaster_subscriptions::BroadcasterSubscriptionsExtras {
+        total, points,
+    }
+    helix::subscriptions::get_broadcaster_subscriptions_events::BroadcasterSubscriptionEvent {
+        id, event_type, event_timestamp, version, event_data,
+    }
+    helix::subscriptions::get_broadcaster_subscriptions_events::BroadcasterSubscriptionEventData {
+        broadcaster_id, broadcaster_name, is_gift, tier, plan_name, user_id, user_name, gifter_id,
+        gifter_name,
+    }
+    helix::tags::TwitchTag { id, is_auto, localization_names, localization_descriptions }
+    helix::teams::TeamInformation {
+        background_image_url, banner, created_at, updated_at, info, thumbnail_url, team_name,
+        team_display_name, id,
+    }
+    helix::teams::get_channel_teams::BroadcasterTeam {
+        broadcaster_id, broadcaster_login, broadcaster_name, team,
+    }
+    helix::teams::get_teams::Team { users, team }
+    helix::users::get_user_block_list::UserBlock { user_id, user_login, display_name }
+    helix::users::get_user_extensions::UserExtension { id, version, name, can_activate, type_ }
+    helix::users::get_users::User {
+        broadcaster_type, created_at, description, display_name, email, id, login,
+        offline_image_url, profile_image_url, type_, view_count,
+    }
+    helix::users::get_users_follows::UsersFollows { total, follow_relationships }
+    helix::users::get_users_follows::FollowRelationship {
+        followed_at, from_id, from_name, from_login, to_id, to_name, to_login,
+    }
+    helix::videos::get_videos::Video {
+        created_at, description, duration, id, language, muted_segments, published_at, stream_id,
+        thumbnail_url, title, type_, url, user_id, user_name, user_login, view_count, viewable,
+    }
+    helix::videos::get_videos::MutedSegment { duration, offset }
+}
+
+#[cfg(all(feature = "helix", feature = "eventsub"))]
+assert_fields_public! {
+    helix::eventsub::create_eventsub_subscription::CreateEventSubSubscription<ChannelFollowV1> {
+        id, status, type_, version, condition, created_at, transport, limit, total, total_cost,
+        max_total_cost, cost,
+    }
+    helix::eventsub::get_eventsub_subscriptions::EventSubSubscriptions {
+        total, total_cost, max_total_cost, limit, subscriptions,
+    }
+}
+
+#[cfg(feature = "tmi")]
+assert_fields_public! {
+    tmi::GetChatters { chatter_count, chatters }
+    tmi::Chatters { broadcaster, vips, moderators, staff, admins, global_mods, viewers }
+    tmi::GetHosts { hosts }
+    tmi::Host {
+        host_id, target_id, host_login, target_login, host_display_name, target_display_name,
+    }
+}
+
+#[cfg(feature = "pubsub")]
+assert_fields_public! {
+    pubsub::TwitchResponse { nonce, error }
+    pubsub::automod_queue::AutoModQueue { moderator_id, channel_id }
+    pubsub::automod_queue::AutoModCaughtMessage {
+        content_classification, message, reason_code, resolver_id, resolver_login, status,
+    }
+    pubsub::automod_queue::ContentClassification { category, level }
+    pubsub::automod_queue::Message { content, id, sender, sent_at, non_broadcaster_language }
+    pubsub::automod_queue::MessageUser { user_id, login, display_name, badges, chat_color }
+    pubsub::automod_queue::MessageUserBadges { id, version }
+    pubsub::automod_queue::Content { fragments, text }
+    pubsub::automod_queue::FragmentUserMention { user_id, login, display_name }
+    pubsub::automod_queue::Automod { topics }
+    pubsub::channel_bits::ChannelBitsEventsV2 { channel_id }
+    pubsub::channel_bits::BitsEventData {
+        badge_entitlement, bits_used, channel_id, channel_name, chat_message, context, is_anonymous,
+        time, total_bits_used, user_id, user_name,
+    }
+    pubsub::channel_bits::BadgeEntitlement { new_version, previous_version }
+    pubsub::channel_bits_badge::ChannelBitsBadgeUnlocks { channel_id }
+    pubsub::channel_bits_badge::ChannelBitsBadgeUnlocksReply {
+        badge_tier, channel_id, channel_name, chat_message, time, user_id, user_name,
+    }
+    pubsub::channel_points::ChannelPointsChannelV1 { channel_id }
+    pubsub::channel_points::Redemption {
+        channel_id, id, redeemed_at, reward, status, user, user_input, cursor,
+    }
+    pubsub::channel_points::Reward {
+        background_color, channel_id, cooldown_expires_at, cost, default_image, global_cooldown, id,
+        image, is_enabled, is_in_stock, is_paused, is_sub_only, is_user_input_required,
+        max_per_stream, max_per_user_per_stream, prompt, redemptions_redeemed_current_stream,
+        should_redemptions_skip_request_queue, template_id, title, updated_for_indicator_at,
+    }
+    pubsub::channel_points::Progress { channel_id, id, method, new_status, processed, total }
+    pubsub::channel_subscriptions::ChannelSubscribeEventsV1 { channel_id }
+    pubsub::channel_subscriptions::Sub {
+        benefit_end_month, channel_id, channel_name, cumulative_months, is_gift, months,
+        multi_month_duration, sub_message, sub_plan, sub_plan_name, time, user_id, user_name,
+        display_name,
+    }
+    pubsub::channel_subscriptions::ReSub {
+        benefit_end_month, channel_id, channel_name, cumulative_months, is_gift, streak_months,
+        months, multi_month_duration, sub_message, sub_plan, sub_plan_name, time, user_id,
+        user_name, display_name,
+    }
+    pubsub::channel_subscriptions::SubGift {
+        benefit_end_month, channel_id, channel_name, is_gift, months, multi_month_duration,
+        recipient_display_name, recipient_id, recipient_user_name, sub_message, sub_plan,
+        sub_plan_name, time, user_id, user_name, display_name,
+    }
+    pubsub::channel_subscriptions::ResubGift {
+        benefit_end_month, channel_id, channel_name, cumulative_months, is_gift, months,
+        multi_month_duration, recipient_display_name, recipient_user_name, streak_months,
+        sub_message, sub_plan, sub_plan_name, time, user_id, user_name, display_name,
+    }
+    pubsub::channel_subscriptions::ExtendSub {
+        benefit_end_month, channel_id, channel_name, cumulative_months, is_gift, months,
+        multi_month_duration, sub_message, sub_plan, sub_plan_name, time, user_id, user_name,
+        display_name,
+    }
+    pubsub::channel_subscriptions::Emote { id, start, end }
+    pubsub::channel_subscriptions::SubMessage { emotes, message }
+    pubsub::low_trust_users::LowTrustUsers { moderator_id, channel_id }
+    pubsub::low_trust_users::LowTrustUserNewMessage {
+        low_trust_user, message_content, message_id, sent_at,
+    }
+    pubsub::low_trust_users::LowTrustUser {
+        id, low_trust_id, channel_id, sender, evaluated_at, updated_at, ban_evasion_evaluation,
+        treatment, updated_by, types, shared_ban_channel_ids,
+    }
+    pubsub::low_trust_users::LowTrustSender { user_id, login, display_name, chat_color, badges }
+    pubsub::low_trust_users::Badge { id, version }
+    pubsub::low_trust_users::LowTrustModerator { id, login, display_name }
+    pubsub::low_trust_users::MessageContent { text, fragments }
+    pubsub::low_trust_users::MessageFragment { text }
+    pubsub::low_trust_users::LowTrustUserTreatmentUpdate {
+        low_trust_id, channel_id, updated_by, updated_at, target_user_id, target_user, treatment,
+        types, ban_evasion_evaluation, evaluated_at,
+    }
+    pubsub::moderation::ChatModeratorActions { user_id, channel_id }
+    pubsub::moderation::ModerationAction {
+        args, created_by, created_by_user_id, from_automod, moderation_action, msg_id,
+        target_user_id, type_, target_user_login, created_at,
+    }
+    pubsub::moderation::ModeratorAdded {
+        channel_id, target_user_id, moderation_action, target_user_login, created_by_user_id,
+        created_by,
+    }
+    pubsub::moderation::ModeratorRemoved {
+        channel_id, target_user_id, moderation_action, target_user_login, created_by_user_id,
+        created_by,
+    }
+    pubsub::moderation::ChannelTermsAction {
+        channel_id, expires_at, from_automod, id, requester_id, requester_login, text, type_,
+        updated_at,
+    }
+    pubsub::moderation::VipAdded {
+        channel_id, created_by, created_by_user_id, target_user_id, target_user_login,
+    }
+    pubsub::user_moderation_notifications::UserModerationNotifications {
+        current_user_id, channel_id,
+    }
+}
+
+#[cfg(all(feature = "pubsub", feature = "unsupported"))]
+assert_fields_public! {
+    pubsub::ads::Ads { channel_id }
+    pubsub::channel_cheer::ChannelCheerEventsPublicV1 { channel_id }
+    pubsub::channel_sub_gifts::ChannelSubGiftsV1 { channel_id }
+    pubsub::channel_sub_gifts::MysteryGiftPurchase {
+        channel_id, count, tier, user_id, user_name, display_name, uuid,
+    }
+    pubsub::community_boost::CommunityBoostEventsV1 { channel_id }
+    pubsub::community_boost::CommunityBoost {
+        channel_id, boost_orders, total_goal_target, total_goal_progress,
+    }
+    pubsub::community_boost::BoostOrder { id, state, goal_target, goal_progress }
+    pubsub::community_points::CommunityPointsChannelV1 { channel_id }
+    pubsub::following::Following { channel_id }
+    pubsub::following::FollowingReply { display_name, user_id, username }
+    pubsub::hypetrain::HypeTrainEventsV1 { channel_id }
+    pubsub::hypetrain::HypeTrainEventsV1Rewards { channel_id }
+    pubsub::hypetrain::HypeTrainRewards { channel_id, completed_level, rewards }
+    pubsub::hypetrain::HypeTrainStart {
+        channel_id, conductors, config, ended_at, ending_reason, expires_at, id, participations,
+        progress, started_at, updated_at,
+    }
+    pubsub::hypetrain::HypeTrainEnd { ended_at, ending_reason }
+    pubsub::hypetrain::HypeTrainConductorUpdate { source, user, participations }
+    pubsub::hypetrain::HypeTrainProgression {
+        sequence_id, source, action, quantity, progress, user_id, user_login, user_display_name,
+        user_profile_image_url,
+    }
+    pubsub::hypetrain::HypeTrainLevelUp { time_to_expire, progress }
+    pubsub::hypetrain::HypeTrainApproaching {
+        channel_id, creator_color, goal, participants, events_remaining_durations,
+        level_one_rewards, approaching_hype_train_id,
+    }
+    pubsub::hypetrain::Config {
+        callout_emote_id, callout_emote_token, channel_id, conductor_rewards, cooldown_duration,
+        difficulty, difficulty_settings, has_conductor_badges, is_enabled, is_whitelisted, kickoff,
+        level_duration, notification_thresholds, participation_conversion_rates, reward_end_date,
+        theme_color, primary_hex_color, use_personalized_settings, use_theme_color,
+        use_creator_color,
+    }
+    pubsub::hypetrain::Kickoff { duration, min_points, num_of_events }
+    pubsub::hypetrain::ParticipationConversionRates {
+        bits_cheer, bits_extension, bits_poll, subs_tier_1_gifted_sub, subs_tier_1_sub,
+        subs_tier_2_gifted_sub, subs_tier_2_sub, subs_tier_3_gifted_sub, subs_tier_3_sub,
+    }
+    pubsub::hypetrain::NotificationThresholds {
+        bits_cheer, bits_extension, bits_poll, subs_tier_1_gifted_sub, subs_tier_1_sub,
+        subs_tier_2_gifted_sub, subs_tier_2_sub, subs_tier_3_gifted_sub, subs_tier_3_sub,
+    }
+    pubsub::hypetrain::ConductorRewards { bits, subs }
+    pubsub::hypetrain::BitsRewards { current, former }
+    pubsub::hypetrain::SubsRewards { current, former }
+    pubsub::hypetrain::Participations {
+        bits_cheer, bits_extension, bits_poll, subs_tier_1_gifted_sub, subs_tier_1_sub,
+        subs_tier_2_gifted_sub, subs_tier_2_sub, subs_tier_3_gifted_sub, subs_tier_3_sub,
+    }
+    pubsub::hypetrain::HypeTrainProgress { goal, level, remaining_seconds, total, value }
+    pubsub::hypetrain::Level { goal, rewards, value }
+    pubsub::predictions::PredictionsChannelV1 { channel_id }
+    pubsub::predictions::PredictionEventMessage { timestamp, event }
+    pubsub::predictions::PredictionEvent {
+        id, channel_id, created_at, created_by, ended_at, ended_by, locked_at, locked_by, outcomes,
+        prediction_window_seconds, status, title, winning_outcome_id,
+    }
+    pubsub::predictions::PredictionActor { type_, user_id, user_display_name, extension_client_id }
+    pubsub::predictions::PredictionOutcome {
+        id, color, title, total_points, total_users, top_predictors, badge,
+    }
+    pubsub::predictions::Predictor {
+        id, event_id, outcome_id, channel_id, points, predicted_at, updated_at, user_id, result,
+        user_display_name,
+    }
+    pubsub::predictions::PredictionResult { type_, points_won, is_acknowledged }
+    pubsub::predictions::PredictionBadge { version, set_id }
+    pubsub::raid::Raid { channel_id }
+    pubsub::raid::RaidGoV2 {
+        creator_id, force_raid_now_seconds, id, source_id, target_display_name, target_id,
+        target_login, target_profile_image, transition_jitter_seconds, viewer_count,
+    }
+    pubsub::raid::RaidUpdateV2 {
+        creator_id, force_raid_now_seconds, id, source_id, target_display_name, target_id,
+        target_login, target_profile_image, transition_jitter_seconds, viewer_count,
+    }
+    pubsub::raid::RaidCancelV2 {
+        creator_id, force_raid_now_seconds, id, source_id, target_display_name, target_id,
+        target_login, target_profile_image, transition_jitter_seconds, viewer_count,
+    }
+    pubsub::video_playback::VideoPlayback { channel_login }
+    pubsub::video_playback::VideoPlaybackById { channel_id }
+    pubsub::video_playback::Vod {
+        broadcast_type, increment_url, title, viewable, vod_id, wp_id, wp_type,
+    }
+}
+
+#[cfg(feature = "eventsub")]
+assert_fields_public! {
+    eventsub::Payload<ChannelFollowV1> { subscription, message }
+    eventsub::EventSubscriptionInformation<ChannelFollowV1> {
+        id, status, cost, condition, created_at, transport, type_, version,
+    }
+    eventsub::Transport { method, callback, secret, session_id }
+    eventsub::TransportResponse { method, callback, session_id, connected_at, disconnected_at }
+    eventsub::EventSubSubscription {
+        cost, condition, created_at, id, status, transport, type_, version,
+    }
+    eventsub::channel::ban::ChannelBanV1 { broadcaster_user_id }
+    eventsub::channel::ban::ChannelBanV1Payload {
+        user_id, user_login, user_name, broadcaster_user_id, broadcaster_user_login,
+        broadcaster_user_name, moderator_user_id, moderator_user_login, moderator_user_name, reason,
+        ends_at, is_permanent,
+    }
+    eventsub::channel::channel_points_custom_reward::add::ChannelPointsCustomRewardAddV1 {
+        broadcaster_user_id,
+    }
+    eventsub::channel::channel_points_custom_reward::add::ChannelPointsCustomRewardAddV1Payload {
+        background_color, broadcaster_user_id, broadcaster_user_login, broadcaster_user_name,
+        cooldown_expires_at, cost, default_image, global_cooldown, id, image, is_enabled,
+        is_in_stock, is_paused, is_user_input_required, max_per_stream, max_per_user_per_stream,
+        prompt, redemptions_redeemed_current_stream, should_redemptions_skip_request_queue, title,
+    }
+    eventsub::channel::channel_points_custom_reward::remove::ChannelPointsCustomRewardRemoveV1 {
+        broadcaster_user_id, reward_id,
+    }
+    eventsub::channel::channel_points_custom_reward::remove::ChannelPointsCustomRewardRemoveV1Payload {
+        background_color, broadcaster_user_id, broadcaster_user_login, broadcaster_user_name,
+        cooldown_expires_at, cost, default_image, global_cooldown, id, image, is_enabled,
+        is_in_stock, is_paused, is_user_input_required, max_per_stream, max_per_user_per_stream,
+        prompt, redemptions_redeemed_current_stream, should_redemptions_skip_request_queue, title,
+    }
+    eventsub::channel::channel_points_custom_reward::update::ChannelPointsCustomRewardUpdateV1 {
+        broadcaster_user_id, reward_id,
+    }
+    eventsub::channel::channel_points_custom_reward::update::ChannelPointsCustomRewardUpdateV1Payload {
+        background_color, broadcaster_user_id, broadcaster_user_login, broadcaster_user_name,
+        cooldown_expires_at, cost, default_image, global_cooldown, id, image, is_enabled,
+        is_in_stock, is_paused, is_user_input_required, max_per_stream, max_per_user_per_stream,
+        prompt, redemptions_redeemed_current_stream, should_redemptions_skip_request_queue, title,
+    }
+    eventsub::channel::channel_points_custom_reward_redemption::Reward { cost, id, prompt, title }
+    eventsub::channel::channel_points_custom_reward_redemption::add::ChannelPointsCustomRewardRedemptionAddV1 {
+        broadcaster_user_id, reward_id,
+    }
+    eventsub::channel::channel_points_custom_reward_redemption::add::ChannelPointsCustomRewardRedemptionAddV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, id, redeemed_at, reward,
+        status, user_input, user_id, user_login, user_name,
+    }
+    eventsub::channel::channel_points_custom_reward_redemption::update::ChannelPointsCustomRewardRedemptionUpdateV1 {
+        broadcaster_user_id, reward_id,
+    }
+    eventsub::channel::channel_points_custom_reward_redemption::update::ChannelPointsCustomRewardRedemptionUpdateV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, id, redeemed_at, reward,
+        status, user_id, user_login, user_name, user_input,
+    }
+    eventsub::channel::chat::ChatMessage { text, fragments }
+    eventsub::channel::chat::Fragment { type_, text, cheermote, emote, mention }
+    eventsub::channel::chat::Cheermote { prefix, bits, tier }
+    eventsub::channel::chat::Emote { id, emote_set_id, owner_id, format }
+    eventsub::channel::chat::Mention { user_id, user_name, user_login }
+    eventsub::channel::chat::Badge { set_id, id, info }
+    eventsub::channel::chat::Cheer { bits }
+    eventsub::channel::chat::Reply {
+        parent_message_id, parent_message_body, parent_user_id, parent_user_name,
+        parent_user_login, thread_message_id, thread_user_id, thread_user_name, thread_user_login,
+    }
+    eventsub::channel::chat::message::ChannelChatMessageV1 { broadcaster_user_id, user_id }
+    eventsub::channel::chat::message::ChannelChatMessageV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, chatter_user_id,
+        chatter_user_login, chatter_user_name, message_id, message, message_type, badges, cheer,
+        color, reply, channel_points_custom_reward_id, channel_points_animation_id,
+    }
+    eventsub::channel::cheer::ChannelCheerV1 { broadcaster_user_id }
+    eventsub::channel::cheer::ChannelCheerV1Payload {
+        bits, broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, is_anonymous,
+        message, user_id, user_login, user_name,
+    }
+    eventsub::channel::follow::ChannelFollowV1 { broadcaster_user_id }
+    eventsub::channel::follow::ChannelFollowV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, user_id, user_name,
+        user_login, followed_at,
+    }
+    eventsub::channel::goal::begin::ChannelGoalBeginV1 { broadcaster_user_id }
+    eventsub::channel::goal::begin::ChannelGoalBeginV1Payload {
+        id, broadcaster_user_id, broadcaster_user_name, broadcaster_user_login, type_, description,
+        current_amount, target_amount, started_at,
+    }
+    eventsub::channel::goal::end::ChannelGoalEndV1 { broadcaster_user_id }
+    eventsub::channel::goal::end::ChannelGoalEndV1Payload {
+        id, broadcaster_user_id, broadcaster_user_name, broadcaster_user_login, type_, description,
+        is_achieved, current_amount, target_amount, started_at, ended_at,
+    }
+    eventsub::channel::goal::progress::ChannelGoalProgressV1 { broadcaster_user_id }
+    eventsub::channel::goal::progress::ChannelGoalProgressV1Payload {
+        id, broadcaster_user_id, broadcaster_user_name, broadcaster_user_login, type_, description,
+        current_amount, target_amount, started_at,
+    }
+    eventsub::channel::hypetrain::Contribution { total, type_, user_id, user_login, user_name }
+    eventsub::channel::hypetrain::SharedTrainParticipant {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name,
+    }
+    eventsub::channel::hypetrain::begin::ChannelHypeTrainBeginV1 { broadcaster_user_id }
+    eventsub::channel::hypetrain::begin::ChannelHypeTrainBeginV1Payload {
+        id, broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, expires_at, goal,
+        last_contribution, progress, started_at, top_contributions, total,
+    }
+    eventsub::channel::hypetrain::begin::ChannelHypeTrainBeginV2 { broadcaster_user_id }
+    eventsub::channel::hypetrain::begin::ChannelHypeTrainBeginV2Payload {
+        id, broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, expires_at, goal,
+        level, all_time_high_level, all_time_high_total, progress, started_at, top_contributions,
+        total, type_, is_shared_train, shared_train_participants,
+    }
+    eventsub::channel::hypetrain::end::ChannelHypeTrainEndV1 { broadcaster_user_id }
+    eventsub::channel::hypetrain::end::ChannelHypeTrainEndV1Payload {
+        id, broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, cooldown_ends_at,
+        ended_at, level, started_at, top_contributions, total,
+    }
+    eventsub::channel::hypetrain::end::ChannelHypeTrainEndV2 { broadcaster_user_id }
+    eventsub::channel::hypetrain::end::ChannelHypeTrainEndV2Payload {
+        id, broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, cooldown_ends_at,
+        ended_at, level, started_at, top_contributions, total, type_, is_shared_train,
+        shared_train_participants,
+    }
+    eventsub::channel::hypetrain::progress::ChannelHypeTrainProgressV1 { broadcaster_user_id }
+    eventsub::channel::hypetrain::progress::ChannelHypeTrainProgressV1Payload {
+        id, broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, expires_at, goal,
+        last_contribution, level, progress, started_at, top_contributions, total,
+    }
+    eventsub::channel::hypetrain::progress::ChannelHypeTrainProgressV2 { broadcaster_user_id }
+    eventsub::channel::hypetrain::progress::ChannelHypeTrainProgressV2Payload {
+        id, broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, expires_at, goal,
+        level, progress, started_at, top_contributions, total, type_, is_shared_train,
+        shared_train_participants,
+    }
+    eventsub::channel::poll::BitsVoting { amount_per_vote, is_enabled }
+    eventsub::channel::poll::ChannelPointsVoting { amount_per_vote, is_enabled }
+    eventsub::channel::poll::begin::ChannelPollBeginV1 { broadcaster_user_id }
+    eventsub::channel::poll::begin::ChannelPollBeginV1Payload {
+        bits_voting, broadcaster_user_id, broadcaster_user_login, broadcaster_user_name,
+        channel_points_voting, choices, ends_at, id, started_at, title,
+    }
+    eventsub::channel::poll::end::ChannelPollEndV1 { broadcaster_user_id }
+    eventsub::channel::poll::end::ChannelPollEndV1Payload {
+        bits_voting, broadcaster_user_id, broadcaster_user_login, broadcaster_user_name,
+        channel_points_voting, choices, ended_at, id, started_at, status, title,
+    }
+    eventsub::channel::poll::progress::ChannelPollProgressV1 { broadcaster_user_id }
+    eventsub::channel::poll::progress::ChannelPollProgressV1Payload {
+        bits_voting, broadcaster_user_id, broadcaster_user_login, broadcaster_user_name,
+        channel_points_voting, choices, ends_at, id, started_at, title,
+    }
+    eventsub::channel::prediction::begin::ChannelPredictionBeginV1 { broadcaster_user_id }
+    eventsub::channel::prediction::begin::ChannelPredictionBeginV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, id, locks_at, outcomes,
+        started_at, title,
+    }
+    eventsub::channel::prediction::end::ChannelPredictionEndV1 { broadcaster_user_id }
+    eventsub::channel::prediction::end::ChannelPredictionEndV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, ended_at, id, outcomes,
+        started_at, status, title, winning_outcome_id,
+    }
+    eventsub::channel::prediction::lock::ChannelPredictionLockV1 { broadcaster_user_id }
+    eventsub::channel::prediction::lock::ChannelPredictionLockV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, id, locked_at, outcomes,
+        started_at, title,
+    }
+    eventsub::channel::prediction::progress::ChannelPredictionProgressV1 { broadcaster_user_id }
+    eventsub::channel::prediction::progress::ChannelPredictionProgressV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, id, locks_at, outcomes,
+        started_at, title,
+    }
+    eventsub::channel::raid::ChannelRaidV1 { from_broadcaster_user_id, to_broadcaster_user_id }
+    eventsub::channel::raid::ChannelRaidV1Payload {
+        from_broadcaster_user_id, from_broadcaster_user_login, from_broadcaster_user_name,
+        to_broadcaster_user_id, to_broadcaster_user_login, to_broadcaster_user_name, viewers,
+    }
+    eventsub::channel::subscribe::ChannelSubscribeV1 { broadcaster_user_id }
+    eventsub::channel::subscribe::ChannelSubscribeV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, is_gift, tier, user_id,
+        user_login, user_name,
+    }
+    eventsub::channel::subscription::end::ChannelSubscriptionEndV1 { broadcaster_user_id }
+    eventsub::channel::subscription::end::ChannelSubscriptionEndV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, user_id, user_login,
+        user_name, tier, is_gift,
+    }
+    eventsub::channel::subscription::gift::ChannelSubscriptionGiftV1 { broadcaster_user_id }
+    eventsub::channel::subscription::gift::ChannelSubscriptionGiftV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, cumulative_total,
+        is_anonymous, tier, total, user_id, user_login, user_name,
+    }
+    eventsub::channel::subscription::message::ChannelSubscriptionMessageV1 { broadcaster_user_id }
+    eventsub::channel::subscription::message::ChannelSubscriptionMessageV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, cumulative_months,
+        duration_months, message, streak_months, tier, user_id, user_login, user_name,
+    }
+    eventsub::channel::subscription::message::SubscriptionMessage { text, emotes }
+    eventsub::channel::suspicious_user::SuspiciousUserMessage { message_id, text, fragments }
+    eventsub::channel::suspicious_user::MessageFragment { type_, text, cheermote, emote }
+    eventsub::channel::suspicious_user::Cheermote { prefix, bits, tier }
+    eventsub::channel::suspicious_user::Emote { id, emote_set_id }
+    eventsub::channel::suspicious_user::message::ChannelSuspiciousUserMessageV1 {
+        broadcaster_user_id, moderator_user_id,
+    }
+    eventsub::channel::suspicious_user::message::ChannelSuspiciousUserMessageV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, user_id, user_login,
+        user_name, low_trust_status, shared_ban_channel_ids, types, ban_evasion_evaluation, message,
+    }
+    eventsub::channel::suspicious_user::update::ChannelSuspiciousUserUpdateV1 {
+        broadcaster_user_id, moderator_user_id,
+    }
+    eventsub::channel::suspicious_user::update::ChannelSuspiciousUserUpdateV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, moderator_user_id,
+        moderator_user_login, moderator_user_name, user_id, user_login, user_name, low_trust_status,
+    }
+    eventsub::channel::unban::ChannelUnbanV1 { broadcaster_user_id }
+    eventsub::channel::unban::ChannelUnbanV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, user_id, user_login,
+        user_name, moderator_user_id, moderator_user_login, moderator_user_name,
+    }
+    eventsub::channel::unban_request::create::ChannelUnbanRequestCreateV1 {
+        broadcaster_user_id, moderator_user_id,
+    }
+    eventsub::channel::unban_request::create::ChannelUnbanRequestCreateV1Payload {
+        id, broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, user_id, user_login,
+        user_name, text, created_at,
+    }
+    eventsub::channel::unban_request::resolve::ChannelUnbanRequestResolveV1 {
+        broadcaster_user_id, moderator_user_id,
+    }
+    eventsub::channel::unban_request::resolve::ChannelUnbanRequestResolveV1Payload {
+        id, broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, moderator_user_id,
+        moderator_user_login, moderator_user_name, user_id, user_login, user_name, resolution_text,
+        status,
+    }
+    eventsub::channel::update::ChannelUpdateV1 { broadcaster_user_id }
+    eventsub::channel::update::ChannelUpdateV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, title, language,
+        category_id, category_name, is_mature,
+    }
+    eventsub::channel::warning::acknowledge::ChannelWarningAcknowledgeV1 {
+        broadcaster_user_id, moderator_user_id,
+    }
+    eventsub::channel::warning::acknowledge::ChannelWarningAcknowledgeV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, user_id, user_login,
+        user_name,
+    }
+    eventsub::channel::warning::send::ChannelWarningSendV1 {
+        broadcaster_user_id, moderator_user_id,
+    }
+    eventsub::channel::warning::send::ChannelWarningSendV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, moderator_user_id,
+        moderator_user_login, moderator_user_name, user_id, user_login, user_name, reason,
+        chat_rules_cited,
+    }
+    eventsub::drop::entitlement::grant::DropEntitlementGrantV1 {
+        organization_id, category_id, campaign_id,
+    }
+    eventsub::drop::entitlement::grant::DropEntitlementGrantV1Payload { id, data }
+    eventsub::drop::entitlement::grant::DropEntitlement {
+        organization_id, category_id, category_name, campaign_id, user_id, user_name, user_login,
+        entitlement_id, benefit_id, created_at,
+    }
+    eventsub::event::UnknownEvent { event_type, version, message_type, payload }
+    eventsub::extension::bits_transaction::create::ExtensionBitsTransactionCreateV1 {
+        extension_client_id,
+    }
+    eventsub::extension::bits_transaction::create::ExtensionBitsTransactionCreateV1Payload {
+        extension_client_id, id, broadcaster_user_id, broadcaster_user_login, broadcaster_user_name,
+        user_id, user_login, user_name, product,
+    }
+    eventsub::extension::bits_transaction::create::ExtensionProduct {
+        name, bits, sku, in_development,
+    }
+    eventsub::stream::offline::StreamOfflineV1 { broadcaster_user_id }
+    eventsub::stream::offline::StreamOfflineV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name,
+    }
+    eventsub::stream::online::StreamOnlineV1 { broadcaster_user_id }
+    eventsub::stream::online::StreamOnlineV1Payload {
+        broadcaster_user_id, broadcaster_user_login, broadcaster_user_name, id, type_, started_at,
+    }
+    eventsub::user::authorization::grant::UserAuthorizationGrantV1 { client_id }
+    eventsub::user::authorization::grant::UserAuthorizationGrantV1Payload {
+        client_id, user_id, user_login, user_name,
+    }
+    eventsub::user::authorization::revoke::UserAuthorizationRevokeV1 { client_id }
+    eventsub::user::authorization::revoke::UserAuthorizationRevokeV1Payload {
+        client_id, user_id, user_login, user_name,
+    }
+    eventsub::user::update::UserUpdateV1 { user_id }
+    eventsub::user::update::UserUpdateV1Payload {
+        description, email, user_id, user_login, user_name,
+    }
+    eventsub::websocket::MessageMetadata {
+        message_id, message_type, message_timestamp, subscription_type, subscription_version,
+    }
+    eventsub::websocket::SessionData {
+        id, status, connected_at, keepalive_timeout_seconds, reconnect_url, recovery_url,
+    }
+}
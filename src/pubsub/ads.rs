@@ -0,0 +1,91 @@
+#![doc(alias = "commercial")]
+//! PubSub messages for ads
+use crate::{pubsub, types};
+use serde::{Deserialize, Serialize};
+
+/// An ad break is requested in the channel.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(into = "String", try_from = "String")]
+pub struct Ads {
+    /// The channel_id to watch. Can be fetched with the [Get Users](crate::helix::users::get_users) endpoint
+    pub channel_id: u32,
+}
+
+impl_de_ser!(Ads, "ads", channel_id);
+
+impl pubsub::Topic for Ads {
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+
+    fn into_topic(self) -> pubsub::Topics { super::Topics::Ads(self) }
+}
+
+/// Reply from [Ads]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[serde(tag = "type", content = "data")]
+#[non_exhaustive]
+pub enum AdsReply {
+    /// A midroll ad break was requested
+    #[serde(rename = "midroll_request")]
+    MidrollRequest(MidrollRequest),
+}
+
+/// A midroll ad break was requested
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct MidrollRequest {
+    /// ID of the commercial
+    pub commercial_id: String,
+    /// Length of the ad break in seconds
+    pub length: i64,
+    /// Time when the ad break was requested
+    pub requested_at: Option<types::Timestamp>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Response, TopicData};
+    use super::*;
+
+    #[test]
+    fn midroll_request() {
+        let message = r##"
+{
+    "type": "midroll_request",
+    "data": {
+        "commercial_id": "8b5b2c0e-7f1a-4d5e-9c3b-2a1b0c9d8e7f",
+        "length": 90,
+        "requested_at": "2021-09-30T10:00:00Z"
+    }
+}
+"##;
+
+        let source = format!(
+            r#"{{"type": "MESSAGE", "data": {{ "topic": "ads.27620241", "message": {:?} }}}}"#,
+            message
+        );
+        let actual = dbg!(Response::parse(&source).unwrap());
+        assert!(matches!(
+            actual,
+            Response::Message {
+                data: TopicData::Ads { .. },
+            }
+        ));
+    }
+
+    #[test]
+    fn check_deser() {
+        use std::convert::TryInto as _;
+        let s = "ads.1234";
+        assert_eq!(Ads { channel_id: 1234 }, s.to_string().try_into().unwrap());
+    }
+
+    #[test]
+    fn check_ser() {
+        let s = "ads.1234";
+        let right: String = Ads { channel_id: 1234 }.into();
+        assert_eq!(s.to_string(), right);
+    }
+}
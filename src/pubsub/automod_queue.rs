@@ -129,7 +129,7 @@ pub struct MessageUser {
     /// Display name of user
     pub display_name: types::DisplayName,
     /// Senders badges
-    #[serde(default)]
+    #[serde(default, deserialize_with = "pubsub::deserialize_default_from_null")]
     pub badges: Vec<MessageUserBadges>,
     /// Color of the user
     pub chat_color: Option<String>,
@@ -157,6 +157,7 @@ pub struct Content {
     /// The message split up in fragments.
     ///
     /// The message can be retrieved in full with [`text`](Self::text)
+    #[serde(deserialize_with = "pubsub::deserialize_default_from_null")]
     pub fragments: Vec<Fragment>,
     /// The full message that was sent
     pub text: String,
@@ -220,6 +221,7 @@ pub struct Automod {
     ///     "vulgar": 6
     /// }
     /// ```
+    #[serde(deserialize_with = "pubsub::deserialize_default_from_null")]
     pub topics: std::collections::HashMap<String, i64>,
 }
 
@@ -78,9 +78,9 @@ pub struct BitsEventData {
 #[non_exhaustive]
 pub struct BadgeEntitlement {
     /// New version of badge
-    new_version: u64,
+    pub new_version: u64,
     /// Previous version of badge
-    previous_version: u64,
+    pub previous_version: u64,
 }
 
 /// Context that triggered pubsub message
@@ -0,0 +1,132 @@
+#![doc(alias = "community-boost-events-v1")]
+//! PubSub messages for community boosts
+use crate::{pubsub, types};
+use serde::{Deserialize, Serialize};
+
+/// A community boost is started or progresses in the channel.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(into = "String", try_from = "String")]
+pub struct CommunityBoostEventsV1 {
+    /// The channel_id to watch. Can be fetched with the [Get Users](crate::helix::users::get_users) endpoint
+    pub channel_id: u32,
+}
+
+impl_de_ser!(
+    CommunityBoostEventsV1,
+    "community-boost-events-v1",
+    channel_id
+);
+
+impl pubsub::Topic for CommunityBoostEventsV1 {
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+
+    fn into_topic(self) -> pubsub::Topics { super::Topics::CommunityBoostEventsV1(self) }
+}
+
+/// Reply from [CommunityBoostEventsV1]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[serde(tag = "type", content = "data")]
+#[non_exhaustive]
+pub enum CommunityBoostEventsV1Reply {
+    /// A community boost has progressed
+    #[serde(rename = "community-boost-progression")]
+    Progression(CommunityBoost),
+    /// A community boost has started
+    #[serde(rename = "community-boost-start")]
+    Start(CommunityBoost),
+    /// A community boost has ended
+    #[serde(rename = "community-boost-end")]
+    End(CommunityBoost),
+}
+
+/// State of a community boost
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct CommunityBoost {
+    /// ID of the channel
+    pub channel_id: types::UserId,
+    /// Boost orders that make up this community boost
+    #[serde(deserialize_with = "pubsub::deserialize_default_from_null")]
+    pub boost_orders: Vec<BoostOrder>,
+    /// Total goal of all boost orders
+    pub total_goal_target: i64,
+    /// Total progress of all boost orders
+    pub total_goal_progress: i64,
+}
+
+/// A boost order made by viewers
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[serde(rename_all = "PascalCase")]
+#[non_exhaustive]
+pub struct BoostOrder {
+    /// ID of the boost order
+    #[serde(rename = "ID")]
+    pub id: String,
+    /// State of the boost order, e.g `ORDER_STATE_DELIVERING`
+    pub state: String,
+    /// Amount of impressions targeted by this order
+    pub goal_target: i64,
+    /// Amount of impressions delivered so far
+    pub goal_progress: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Response, TopicData};
+    use super::*;
+
+    #[test]
+    fn progression() {
+        let message = r##"
+{
+    "type": "community-boost-progression",
+    "data": {
+        "channel_id": "27620241",
+        "boost_orders": [
+            {
+                "ID": "2b6a1c3d-4e5f-6a7b-8c9d-0e1f2a3b4c5d",
+                "State": "ORDER_STATE_DELIVERING",
+                "GoalTarget": 2000,
+                "GoalProgress": 120
+            }
+        ],
+        "total_goal_target": 2000,
+        "total_goal_progress": 120
+    }
+}
+"##;
+
+        let source = format!(
+            r#"{{"type": "MESSAGE", "data": {{ "topic": "community-boost-events-v1.27620241", "message": {:?} }}}}"#,
+            message
+        );
+        let actual = dbg!(Response::parse(&source).unwrap());
+        assert!(matches!(
+            actual,
+            Response::Message {
+                data: TopicData::CommunityBoostEventsV1 { .. },
+            }
+        ));
+    }
+
+    #[test]
+    fn check_deser() {
+        use std::convert::TryInto as _;
+        let s = "community-boost-events-v1.1234";
+        assert_eq!(
+            CommunityBoostEventsV1 { channel_id: 1234 },
+            s.to_string().try_into().unwrap()
+        );
+    }
+
+    #[test]
+    fn check_ser() {
+        let s = "community-boost-events-v1.1234";
+        let right: String = CommunityBoostEventsV1 { channel_id: 1234 }.into();
+        assert_eq!(s.to_string(), right);
+    }
+}
@@ -58,6 +58,7 @@ pub struct HypeTrainRewards {
     /// Level of hype-train that was initiated
     pub completed_level: i64,
     /// Rewards
+    #[serde(deserialize_with = "pubsub::deserialize_default_from_null")]
     pub rewards: Vec<Reward>,
 }
 
@@ -180,12 +181,15 @@ pub struct HypeTrainApproaching {
     /// Participation points needed for this level
     pub goal: i64,
     /// Participators
+    #[serde(deserialize_with = "pubsub::deserialize_default_from_null")]
     pub participants: Vec<types::UserId>,
     /// remaining events
     // FIXME: example: "1": 258708980297
     // What does 258708980297 stand for? is "1" perhaps BADGE type? just not stringified
+    #[serde(deserialize_with = "pubsub::deserialize_default_from_null")]
     pub events_remaining_durations: std::collections::HashMap<String, i64>,
     /// Possible rewards for level one
+    #[serde(deserialize_with = "pubsub::deserialize_default_from_null")]
     pub level_one_rewards: Vec<Reward>,
     /// ID of the approaching hype train
     pub approaching_hype_train_id: types::HypeTrainId,
@@ -246,6 +250,7 @@ pub struct Config {
     /// Difficulty of hype train
     pub difficulty: HypeTrainDifficulty,
     /// Difficulty settings
+    #[serde(deserialize_with = "pubsub::deserialize_default_from_null")]
     pub difficulty_settings: std::collections::HashMap<HypeTrainDifficulty, Vec<Level>>,
     #[doc(hidden)]
     #[serde(default = "bool_true")]
@@ -417,10 +422,16 @@ pub struct ConductorRewards {
 #[non_exhaustive]
 pub struct BitsRewards {
     /// Rewards to bits conductor
-    #[serde(rename = "CURRENT")]
+    #[serde(
+        rename = "CURRENT",
+        deserialize_with = "pubsub::deserialize_default_from_null"
+    )]
     pub current: Vec<Reward>,
     /// Rewards to former bits conductor
-    #[serde(rename = "FORMER")]
+    #[serde(
+        rename = "FORMER",
+        deserialize_with = "pubsub::deserialize_default_from_null"
+    )]
     pub former: Vec<Reward>,
 }
 
@@ -430,10 +441,16 @@ pub struct BitsRewards {
 #[non_exhaustive]
 pub struct SubsRewards {
     /// Rewards to subscriptions conductor
-    #[serde(rename = "CURRENT")]
+    #[serde(
+        rename = "CURRENT",
+        deserialize_with = "pubsub::deserialize_default_from_null"
+    )]
     pub current: Vec<Reward>,
     /// Rewards to former subscriptions conductor
-    #[serde(rename = "FORMER")]
+    #[serde(
+        rename = "FORMER",
+        deserialize_with = "pubsub::deserialize_default_from_null"
+    )]
     pub former: Vec<Reward>,
 }
 
@@ -511,6 +528,7 @@ pub struct Level {
     /// Participation points needed for this level
     pub goal: i64,
     /// Description of level rewards
+    #[serde(deserialize_with = "pubsub::deserialize_default_from_null")]
     pub rewards: Vec<Reward>,
     // FIXME: Should maybe be an enum
     /// Integer value of reward. 1-5
@@ -0,0 +1,335 @@
+#![doc(alias = "low-trust-users")]
+#![doc(alias = "suspicious users")]
+//! PubSub messages for low trust (suspicious) users
+use crate::{pubsub, types};
+use serde::{Deserialize, Serialize};
+
+/// A suspicious user sends a message or a moderator updates the treatment of a suspicious user.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(into = "String", try_from = "String")]
+pub struct LowTrustUsers {
+    /// The user_id of the moderator watching the channel
+    pub moderator_id: u32,
+    /// The channel_id to watch. Can be fetched with the [Get Users](crate::helix::users::get_users) endpoint
+    pub channel_id: u32,
+}
+
+impl_de_ser!(LowTrustUsers, "low-trust-users", moderator_id, channel_id);
+
+impl pubsub::Topic for LowTrustUsers {
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelModerate];
+
+    fn into_topic(self) -> pubsub::Topics { super::Topics::LowTrustUsers(self) }
+}
+
+/// Reply from [LowTrustUsers]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[serde(tag = "type", content = "data")]
+#[non_exhaustive]
+pub enum LowTrustUsersReply {
+    /// A message was sent by a suspicious user
+    #[serde(rename = "low_trust_user_new_message")]
+    NewMessage(Box<LowTrustUserNewMessage>),
+    /// The treatment of a suspicious user was updated
+    #[serde(rename = "low_trust_user_treatment_update")]
+    TreatmentUpdate(Box<LowTrustUserTreatmentUpdate>),
+}
+
+/// A message was sent by a suspicious user
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct LowTrustUserNewMessage {
+    /// Information about the suspicious user
+    pub low_trust_user: LowTrustUser,
+    /// Content of the message
+    pub message_content: MessageContent,
+    /// ID of the message
+    pub message_id: types::MsgId,
+    /// Time at which the message was sent
+    pub sent_at: types::Timestamp,
+}
+
+/// A suspicious user
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct LowTrustUser {
+    /// ID of the user
+    pub id: types::UserId,
+    /// Identifier of the low trust entry
+    pub low_trust_id: String,
+    /// ID of the channel
+    pub channel_id: types::UserId,
+    /// The user that sent the message
+    pub sender: LowTrustSender,
+    /// Time at which the user was last evaluated
+    pub evaluated_at: Option<types::Timestamp>,
+    /// Time at which the treatment was last updated
+    pub updated_at: types::Timestamp,
+    /// Likelihood that the user is evading a ban
+    pub ban_evasion_evaluation: BanEvasionEvaluation,
+    /// How messages from this user are treated
+    pub treatment: LowTrustTreatment,
+    /// Moderator that last updated the treatment
+    pub updated_by: LowTrustModerator,
+    /// Reasons for the user being treated as suspicious
+    #[serde(deserialize_with = "pubsub::deserialize_default_from_null")]
+    pub types: Vec<LowTrustType>,
+    /// Channels that have shared a ban of this user
+    #[serde(default, deserialize_with = "pubsub::deserialize_default_from_null")]
+    pub shared_ban_channel_ids: Vec<types::UserId>,
+}
+
+/// Sender of a message by a suspicious user
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct LowTrustSender {
+    /// ID of the user
+    pub user_id: types::UserId,
+    /// Login of the user
+    pub login: types::UserName,
+    /// Display name of the user
+    pub display_name: types::DisplayName,
+    /// Chat color of the user
+    pub chat_color: Option<String>,
+    /// Badges of the user
+    #[serde(deserialize_with = "pubsub::deserialize_default_from_null")]
+    pub badges: Vec<Badge>,
+}
+
+/// Chat badge
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Badge {
+    /// ID of the badge
+    pub id: String,
+    /// Version of the badge
+    pub version: String,
+}
+
+/// Moderator updating a suspicious user
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct LowTrustModerator {
+    /// ID of the moderator
+    pub id: types::UserId,
+    /// Login of the moderator
+    pub login: types::UserName,
+    /// Display name of the moderator
+    pub display_name: types::DisplayName,
+}
+
+/// Content of a message
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct MessageContent {
+    /// Text of the message
+    pub text: String,
+    /// Fragments of the message
+    #[serde(deserialize_with = "pubsub::deserialize_default_from_null")]
+    pub fragments: Vec<MessageFragment>,
+}
+
+/// A fragment of a message
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct MessageFragment {
+    /// Text of the fragment
+    pub text: String,
+}
+
+/// The treatment of a suspicious user was updated
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct LowTrustUserTreatmentUpdate {
+    /// Identifier of the low trust entry
+    pub low_trust_id: String,
+    /// ID of the channel
+    pub channel_id: types::UserId,
+    /// Moderator that updated the treatment
+    pub updated_by: LowTrustModerator,
+    /// Time at which the treatment was updated
+    pub updated_at: types::Timestamp,
+    /// ID of the suspicious user
+    pub target_user_id: types::UserId,
+    /// Login of the suspicious user
+    pub target_user: types::UserName,
+    /// How messages from this user are treated
+    pub treatment: LowTrustTreatment,
+    /// Reasons for the user being treated as suspicious
+    #[serde(deserialize_with = "pubsub::deserialize_default_from_null")]
+    pub types: Vec<LowTrustType>,
+    /// Likelihood that the user is evading a ban
+    pub ban_evasion_evaluation: BanEvasionEvaluation,
+    /// Time at which the user was last evaluated
+    pub evaluated_at: Option<types::Timestamp>,
+}
+
+/// How messages from a suspicious user are treated
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum LowTrustTreatment {
+    /// Not treated as suspicious
+    NoTreatment,
+    /// Messages are shown, but highlighted to moderators
+    ActiveMonitoring,
+    /// Messages are only shown to moderators
+    Restricted,
+}
+
+/// Reason for a user being treated as suspicious
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum LowTrustType {
+    /// Added by a moderator
+    ManuallyAdded,
+    /// Detected as a possible ban evader
+    BanEvader,
+    /// Banned in a channel sharing bans with this channel
+    BannedInSharedChannel,
+}
+
+/// Likelihood that a suspicious user is evading a ban
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum BanEvasionEvaluation {
+    /// User is not evaluated
+    Unknown,
+    /// User is unlikely to evade a ban
+    UnlikelyEvader,
+    /// User is possibly evading a ban
+    PossibleEvader,
+    /// User is likely evading a ban
+    LikelyEvader,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Response, TopicData};
+    use super::*;
+
+    #[test]
+    fn new_message() {
+        let message = r##"
+{
+    "type": "low_trust_user_new_message",
+    "data": {
+        "low_trust_user": {
+            "id": "1234",
+            "low_trust_id": "MTIzNDoyNzYyMDI0MQ==",
+            "channel_id": "27620241",
+            "sender": {
+                "user_id": "1234",
+                "login": "tmi",
+                "display_name": "TMI",
+                "chat_color": "#FF0000",
+                "badges": [{ "id": "subscriber", "version": "0" }]
+            },
+            "evaluated_at": "2021-09-30T10:00:00Z",
+            "updated_at": "2021-09-30T10:00:00Z",
+            "ban_evasion_evaluation": "LIKELY_EVADER",
+            "treatment": "RESTRICTED",
+            "updated_by": {
+                "id": "27620241",
+                "login": "emilgardis",
+                "display_name": "emilgardis"
+            },
+            "types": ["BAN_EVADER"],
+            "shared_ban_channel_ids": null
+        },
+        "message_content": {
+            "text": "hello",
+            "fragments": [{ "text": "hello" }]
+        },
+        "message_id": "d6f608f8-8f34-4f65-947c-0a92e31b0bfc",
+        "sent_at": "2021-09-30T10:01:00Z"
+    }
+}
+"##;
+
+        let source = format!(
+            r#"{{"type": "MESSAGE", "data": {{ "topic": "low-trust-users.27620241.27620241", "message": {:?} }}}}"#,
+            message
+        );
+        let actual = dbg!(Response::parse(&source).unwrap());
+        assert!(matches!(
+            actual,
+            Response::Message {
+                data: TopicData::LowTrustUsers { .. },
+            }
+        ));
+    }
+
+    #[test]
+    fn treatment_update() {
+        let message = r##"
+{
+    "type": "low_trust_user_treatment_update",
+    "data": {
+        "low_trust_id": "MTIzNDoyNzYyMDI0MQ==",
+        "channel_id": "27620241",
+        "updated_by": {
+            "id": "27620241",
+            "login": "emilgardis",
+            "display_name": "emilgardis"
+        },
+        "updated_at": "2021-09-30T10:02:00Z",
+        "target_user_id": "1234",
+        "target_user": "tmi",
+        "treatment": "ACTIVE_MONITORING",
+        "types": ["MANUALLY_ADDED"],
+        "ban_evasion_evaluation": "UNKNOWN",
+        "evaluated_at": null
+    }
+}
+"##;
+
+        let source = format!(
+            r#"{{"type": "MESSAGE", "data": {{ "topic": "low-trust-users.27620241.27620241", "message": {:?} }}}}"#,
+            message
+        );
+        let actual = dbg!(Response::parse(&source).unwrap());
+        assert!(matches!(
+            actual,
+            Response::Message {
+                data: TopicData::LowTrustUsers { .. },
+            }
+        ));
+    }
+
+    #[test]
+    fn check_deser() {
+        use std::convert::TryInto as _;
+        let s = "low-trust-users.123.1234";
+        assert_eq!(
+            LowTrustUsers {
+                moderator_id: 123,
+                channel_id: 1234,
+            },
+            s.to_string().try_into().unwrap()
+        );
+    }
+
+    #[test]
+    fn check_ser() {
+        let s = "low-trust-users.123.1234";
+        let right: String = LowTrustUsers {
+            moderator_id: 123,
+            channel_id: 1234,
+        }
+        .into();
+        assert_eq!(s.to_string(), right);
+    }
+}
@@ -1,6 +1,7 @@
 //! Holds serializable pubsub stuff
 //!
-//! Use [`listen_command()`] to send subscription listen and parse the responses with [`Response::parse`]
+//! Use [`listen_command()`] to send subscription listen and parse the responses with [`Response::parse`].
+//! To keep the connection alive, send [`ping_command()`] at least once every five minutes.
 //!
 //! # Undocumented features
 //!
@@ -82,6 +83,9 @@ macro_rules! impl_de_ser {
 
 use serde::{Deserialize, Deserializer, Serialize};
 
+#[cfg(feature = "unsupported")]
+#[cfg_attr(nightly, doc(cfg(feature = "unsupported")))]
+pub mod ads;
 pub mod automod_queue;
 pub mod channel_bits;
 pub mod channel_bits_badge;
@@ -95,6 +99,9 @@ pub mod channel_sub_gifts;
 pub mod channel_subscriptions;
 #[cfg(feature = "unsupported")]
 #[cfg_attr(nightly, doc(cfg(feature = "unsupported")))]
+pub mod community_boost;
+#[cfg(feature = "unsupported")]
+#[cfg_attr(nightly, doc(cfg(feature = "unsupported")))]
 pub mod community_points;
 #[cfg(feature = "unsupported")]
 #[cfg_attr(nightly, doc(cfg(feature = "unsupported")))]
@@ -102,9 +109,13 @@ pub mod following;
 #[cfg(feature = "unsupported")]
 #[cfg_attr(nightly, doc(cfg(feature = "unsupported")))]
 pub mod hypetrain;
+pub mod low_trust_users;
 pub mod moderation;
 #[cfg(feature = "unsupported")]
 #[cfg_attr(nightly, doc(cfg(feature = "unsupported")))]
+pub mod predictions;
+#[cfg(feature = "unsupported")]
+#[cfg_attr(nightly, doc(cfg(feature = "unsupported")))]
 pub mod raid;
 pub mod user_moderation_notifications;
 #[cfg(feature = "unsupported")]
@@ -187,6 +198,20 @@ pub enum Topics {
     Raid(raid::Raid),
     /// A user’s message held by AutoMod has been approved or denied.
     UserModerationNotifications(user_moderation_notifications::UserModerationNotifications),
+    /// A suspicious user sends a message or their treatment is updated.
+    LowTrustUsers(low_trust_users::LowTrustUsers),
+    /// A prediction is created or updated in the channel
+    #[cfg(feature = "unsupported")]
+    #[cfg_attr(nightly, doc(cfg(feature = "unsupported")))]
+    PredictionsChannelV1(predictions::PredictionsChannelV1),
+    /// A community boost is started or progresses in the channel
+    #[cfg(feature = "unsupported")]
+    #[cfg_attr(nightly, doc(cfg(feature = "unsupported")))]
+    CommunityBoostEventsV1(community_boost::CommunityBoostEventsV1),
+    /// An ad break is requested in the channel
+    #[cfg(feature = "unsupported")]
+    #[cfg_attr(nightly, doc(cfg(feature = "unsupported")))]
+    Ads(ads::Ads),
 }
 
 impl std::fmt::Display for Topics {
@@ -218,11 +243,51 @@ impl std::fmt::Display for Topics {
             #[cfg(feature = "unsupported")]
             Raid(t) => t.to_string(),
             UserModerationNotifications(t) => t.to_string(),
+            LowTrustUsers(t) => t.to_string(),
+            #[cfg(feature = "unsupported")]
+            PredictionsChannelV1(t) => t.to_string(),
+            #[cfg(feature = "unsupported")]
+            CommunityBoostEventsV1(t) => t.to_string(),
+            #[cfg(feature = "unsupported")]
+            Ads(t) => t.to_string(),
         };
         f.write_str(&s)
     }
 }
 
+impl Topics {
+    /// Get the topic string, as used in [`listen_command`] and [`unlisten_command`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use twitch_api2::pubsub::{self, Topic as _};
+    /// let topic = pubsub::channel_bits::ChannelBitsEventsV2 { channel_id: 1234 }.into_topic();
+    /// assert_eq!(topic.as_topic_string(), "channel-bits-events-v2.1234");
+    /// ```
+    pub fn as_topic_string(&self) -> String { self.to_string() }
+
+    /// Parse a topic string, e.g `channel-bits-events-v2.1234`, into a topic.
+    ///
+    /// This is the inverse of [`Topics::as_topic_string`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use twitch_api2::pubsub;
+    /// let topic = pubsub::Topics::from_topic_string("channel-bits-events-v2.1234").unwrap();
+    /// assert_eq!(
+    ///     topic,
+    ///     pubsub::Topics::ChannelBitsEventsV2(pubsub::channel_bits::ChannelBitsEventsV2 {
+    ///         channel_id: 1234
+    ///     })
+    /// );
+    /// ```
+    pub fn from_topic_string(topic: &str) -> Result<Topics, serde_json::Error> {
+        serde_json::from_value(serde_json::Value::String(topic.to_owned()))
+    }
+}
+
 #[derive(Serialize)]
 struct ITopicSubscribeData<'a> {
     topics: &'a [String],
@@ -237,8 +302,31 @@ struct ITopicSubscribe<'a> {
     data: ITopicSubscribeData<'a>,
 }
 
+/// Maximum amount of topics a single connection can listen to.
+///
+/// Twitch rejects topics beyond this limit, open more connections to listen to more topics.
+pub const MAX_TOPICS_PER_CONNECTION: usize = 50;
+
+/// Errors when creating a [listen command](listen_command)
+#[derive(Debug, thiserror::Error, displaydoc::Display)]
+#[non_exhaustive]
+pub enum ListenCommandError {
+    /// listening to {count} topics, but a connection can only listen to {max} topics
+    TooManyTopics {
+        /// Amount of topics passed
+        count: usize,
+        /// The limit, [`MAX_TOPICS_PER_CONNECTION`]
+        max: usize,
+    },
+    /// could not serialize the command
+    SerializeError(#[from] serde_json::Error),
+}
+
 /// Create a listen command.
 ///
+/// Fails if more than [`MAX_TOPICS_PER_CONNECTION`] topics are passed.
+/// Topics listened to in earlier commands on the same connection also count towards this limit.
+///
 /// # Examples
 ///
 /// Create a listen message for moderator actions
@@ -271,11 +359,17 @@ pub fn listen_command<'t, T, N>(
     topics: &'t [Topics],
     auth_token: T,
     nonce: N,
-) -> Result<String, serde_json::Error>
+) -> Result<String, ListenCommandError>
 where
     T: Into<Option<&'t str>>,
     N: Into<Option<&'t str>>,
 {
+    if topics.len() > MAX_TOPICS_PER_CONNECTION {
+        return Err(ListenCommandError::TooManyTopics {
+            count: topics.len(),
+            max: MAX_TOPICS_PER_CONNECTION,
+        });
+    }
     let topics = topics.iter().map(|t| t.to_string()).collect::<Vec<_>>();
     serde_json::to_string(&ITopicSubscribe {
         _type: "LISTEN",
@@ -285,6 +379,7 @@ where
             auth_token: auth_token.into(),
         },
     })
+    .map_err(Into::into)
 }
 
 /// Create a unlisten command.
@@ -334,6 +429,20 @@ where
     })
 }
 
+/// Create a ping command.
+///
+/// Twitch expects a ping at least once every five minutes, and will answer with [`Response::Pong`].
+/// If no pong is received within ten seconds, the client should reconnect.
+///
+/// # Examples
+///
+/// ```rust
+/// # use twitch_api2::pubsub;
+/// let command = pubsub::ping_command();
+/// assert_eq!(command, r#"{"type":"PING"}"#);
+/// ```
+pub fn ping_command() -> String { r#"{"type":"PING"}"#.to_owned() }
+
 /// Response from twitch PubSub
 #[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
 pub struct TwitchResponse {
@@ -501,6 +610,44 @@ pub enum TopicData {
         #[serde(rename = "message")]
         reply: Box<user_moderation_notifications::UserModerationNotificationsReply>,
     },
+    /// Response from the [low_trust_users::LowTrustUsers] topic.
+    LowTrustUsers {
+        /// Topic message
+        topic: low_trust_users::LowTrustUsers,
+        /// Message reply from topic subscription
+        #[serde(rename = "message")]
+        reply: Box<low_trust_users::LowTrustUsersReply>,
+    },
+    /// Response from the [predictions::PredictionsChannelV1] topic.
+    #[cfg(feature = "unsupported")]
+    #[cfg_attr(nightly, doc(cfg(feature = "unsupported")))]
+    PredictionsChannelV1 {
+        /// Topic message
+        topic: predictions::PredictionsChannelV1,
+        /// Message reply from topic subscription
+        #[serde(rename = "message")]
+        reply: Box<predictions::PredictionsChannelV1Reply>,
+    },
+    /// Response from the [community_boost::CommunityBoostEventsV1] topic.
+    #[cfg(feature = "unsupported")]
+    #[cfg_attr(nightly, doc(cfg(feature = "unsupported")))]
+    CommunityBoostEventsV1 {
+        /// Topic message
+        topic: community_boost::CommunityBoostEventsV1,
+        /// Message reply from topic subscription
+        #[serde(rename = "message")]
+        reply: Box<community_boost::CommunityBoostEventsV1Reply>,
+    },
+    /// Response from the [ads::Ads] topic.
+    #[cfg(feature = "unsupported")]
+    #[cfg_attr(nightly, doc(cfg(feature = "unsupported")))]
+    Ads {
+        /// Topic message
+        topic: ads::Ads,
+        /// Message reply from topic subscription
+        #[serde(rename = "message")]
+        reply: Box<ads::AdsReply>,
+    },
 }
 
 // This impl is here because otherwise we hide the errors from deser
@@ -590,6 +737,25 @@ impl<'de> Deserialize<'de> for TopicData {
                 topic,
                 reply: parse_json(&reply.message, true).map_err(serde::de::Error::custom)?,
             },
+            Topics::LowTrustUsers(topic) => TopicData::LowTrustUsers {
+                topic,
+                reply: parse_json(&reply.message, true).map_err(serde::de::Error::custom)?,
+            },
+            #[cfg(feature = "unsupported")]
+            Topics::PredictionsChannelV1(topic) => TopicData::PredictionsChannelV1 {
+                topic,
+                reply: parse_json(&reply.message, true).map_err(serde::de::Error::custom)?,
+            },
+            #[cfg(feature = "unsupported")]
+            Topics::CommunityBoostEventsV1(topic) => TopicData::CommunityBoostEventsV1 {
+                topic,
+                reply: parse_json(&reply.message, true).map_err(serde::de::Error::custom)?,
+            },
+            #[cfg(feature = "unsupported")]
+            Topics::Ads(topic) => TopicData::Ads {
+                topic,
+                reply: parse_json(&reply.message, true).map_err(serde::de::Error::custom)?,
+            },
         })
     }
 }
@@ -617,8 +783,24 @@ pub enum Response {
 }
 
 impl Response {
-    // FIXME: Add example
     /// Parse string slice as a response.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use twitch_api2::pubsub;
+    /// let source = r#"{"type":"RESPONSE","nonce":"my nonce","error":"ERR_BADAUTH"}"#;
+    /// match pubsub::Response::parse(source)? {
+    ///     pubsub::Response::Response(response) if !response.is_successful() => {
+    ///         println!("listen failed: {:?}", response.error)
+    ///     }
+    ///     pubsub::Response::Response(_) => println!("listening"),
+    ///     pubsub::Response::Message { data } => println!("got message: {:?}", data),
+    ///     pubsub::Response::Pong => println!("pong"),
+    ///     pubsub::Response::Reconnect => println!("reconnect"),
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error + 'static>>(())
+    /// ```
     pub fn parse(source: &str) -> Result<Response, crate::DeserError> { parse_json(source, true) }
 }
 
@@ -662,6 +844,23 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn frames() {
+        assert_eq!(
+            Response::parse(r#"{"type": "PONG"}"#).unwrap(),
+            Response::Pong
+        );
+        assert_eq!(
+            Response::parse(r#"{"type": "RECONNECT"}"#).unwrap(),
+            Response::Reconnect
+        );
+        let actual =
+            Response::parse(r#"{"type": "RESPONSE", "nonce": "a", "error": "ERR_BADAUTH"}"#)
+                .unwrap();
+        assert!(matches!(actual, Response::Response(r) if !r.is_successful()));
+        assert!(Response::parse(r#"{"type": "PING"}"#).is_err());
+    }
+
     #[test]
     fn listen() {
         let topic =
@@ -671,6 +870,22 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn listen_too_many_topics() {
+        let topics = (0..=MAX_TOPICS_PER_CONNECTION as u32)
+            .map(|channel_id| {
+                Topics::ChannelBitsEventsV2(channel_bits::ChannelBitsEventsV2 { channel_id })
+            })
+            .collect::<Vec<_>>();
+        assert!(
+            listen_command(&topics[..MAX_TOPICS_PER_CONNECTION], "my token", "my nonce").is_ok()
+        );
+        assert!(matches!(
+            listen_command(&topics, "my token", "my nonce"),
+            Err(ListenCommandError::TooManyTopics { count: 51, max: 50 })
+        ));
+    }
+
     #[test]
     fn unlisten() {
         let topic =
@@ -679,4 +894,39 @@ mod tests {
         let actual = unlisten_command(&[topic], "my nonce").expect("should serialize");
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn topic_string_roundtrip() {
+        let mut topics = vec![
+            "automod-queue.123.1234",
+            "channel-bits-events-v2.1234",
+            "channel-bits-badge-unlocks.1234",
+            "chat_moderator_actions.123.1234",
+            "channel-points-channel-v1.1234",
+            "channel-subscribe-events-v1.1234",
+            "user-moderation-notifications.123.1234",
+            "low-trust-users.123.1234",
+        ];
+        if cfg!(feature = "unsupported") {
+            topics.extend(&[
+                "community-points-channel-v1.1234",
+                "channel-cheer-events-public-v1.1234",
+                "channel-sub-gifts-v1.1234",
+                "video-playback.tmi",
+                "video-playback-by-id.1234",
+                "hype-train-events-v1.1234",
+                "hype-train-events-v1.rewards.1234",
+                "following.1234",
+                "raid.1234",
+                "predictions-channel-v1.1234",
+                "community-boost-events-v1.1234",
+                "ads.1234",
+            ]);
+        }
+        for s in topics {
+            let topic = Topics::from_topic_string(s).expect(s);
+            assert_eq!(topic.as_topic_string(), s);
+        }
+        assert!(Topics::from_topic_string("not-a-topic.1234").is_err());
+    }
 }
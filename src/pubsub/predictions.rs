@@ -0,0 +1,351 @@
+#![doc(alias = "predictions-channel-v1")]
+//! PubSub messages for predictions
+use crate::{pubsub, types};
+use serde::{Deserialize, Serialize};
+
+/// A prediction is created, updated, locked or resolved in the channel.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(into = "String", try_from = "String")]
+pub struct PredictionsChannelV1 {
+    /// The channel_id to watch. Can be fetched with the [Get Users](crate::helix::users::get_users) endpoint
+    pub channel_id: u32,
+}
+
+impl_de_ser!(PredictionsChannelV1, "predictions-channel-v1", channel_id);
+
+impl pubsub::Topic for PredictionsChannelV1 {
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+
+    fn into_topic(self) -> pubsub::Topics { super::Topics::PredictionsChannelV1(self) }
+}
+
+/// Reply from [PredictionsChannelV1]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[serde(tag = "type", content = "data")]
+#[non_exhaustive]
+pub enum PredictionsChannelV1Reply {
+    /// A prediction was created
+    #[serde(rename = "event-created")]
+    EventCreated(PredictionEventMessage),
+    /// A prediction was updated
+    #[serde(rename = "event-updated")]
+    EventUpdated(PredictionEventMessage),
+}
+
+/// Message for a prediction
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct PredictionEventMessage {
+    /// Time when message was sent
+    pub timestamp: types::Timestamp,
+    /// The prediction
+    pub event: PredictionEvent,
+}
+
+/// A prediction
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct PredictionEvent {
+    /// ID of the prediction
+    pub id: String,
+    /// ID of the channel
+    pub channel_id: types::UserId,
+    /// Time when prediction was created
+    pub created_at: types::Timestamp,
+    /// Creator of the prediction
+    pub created_by: PredictionActor,
+    /// Time when prediction ended
+    pub ended_at: Option<types::Timestamp>,
+    /// Who ended the prediction
+    pub ended_by: Option<PredictionActor>,
+    /// Time when prediction was locked
+    pub locked_at: Option<types::Timestamp>,
+    /// Who locked the prediction
+    pub locked_by: Option<PredictionActor>,
+    /// Possible outcomes of the prediction
+    #[serde(deserialize_with = "pubsub::deserialize_default_from_null")]
+    pub outcomes: Vec<PredictionOutcome>,
+    /// Time in seconds that viewers have to make predictions
+    pub prediction_window_seconds: i64,
+    /// Status of the prediction
+    pub status: PredictionStatus,
+    /// Title of the prediction
+    pub title: String,
+    /// ID of the winning outcome, if resolved
+    pub winning_outcome_id: Option<String>,
+}
+
+/// Who acted on a prediction
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct PredictionActor {
+    /// Type of the actor, e.g `USER`
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// ID of the user
+    pub user_id: Option<types::UserId>,
+    /// Display name of the user
+    pub user_display_name: Option<types::DisplayName>,
+    /// Client ID of the extension
+    pub extension_client_id: Option<String>,
+}
+
+/// An outcome of a prediction
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct PredictionOutcome {
+    /// ID of the outcome
+    pub id: String,
+    /// Color of the outcome, `BLUE` or `PINK`
+    pub color: String,
+    /// Title of the outcome
+    pub title: String,
+    /// Total amount of channel points predicted on this outcome
+    pub total_points: i64,
+    /// Total amount of users that predicted this outcome
+    pub total_users: i64,
+    /// Users that predicted the most channel points on this outcome
+    #[serde(deserialize_with = "pubsub::deserialize_default_from_null")]
+    pub top_predictors: Vec<Predictor>,
+    /// Badge for this outcome
+    pub badge: PredictionBadge,
+}
+
+/// A user that made a prediction
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Predictor {
+    /// ID of the prediction entry
+    pub id: String,
+    /// ID of the prediction
+    pub event_id: String,
+    /// ID of the outcome
+    pub outcome_id: String,
+    /// ID of the channel
+    pub channel_id: types::UserId,
+    /// Amount of channel points predicted
+    pub points: i64,
+    /// Time when prediction was made
+    pub predicted_at: types::Timestamp,
+    /// Time when prediction was last updated
+    pub updated_at: types::Timestamp,
+    /// ID of the user
+    pub user_id: types::UserId,
+    /// Result of the prediction, if resolved
+    pub result: Option<PredictionResult>,
+    /// Display name of the user
+    pub user_display_name: types::DisplayName,
+}
+
+/// Result of a prediction for a user
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct PredictionResult {
+    /// Type of the result, e.g `WIN` or `LOSE`
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Amount of channel points won
+    pub points_won: Option<i64>,
+    /// Whether the user acknowledged the result
+    pub is_acknowledged: bool,
+}
+
+/// Badge for a prediction outcome
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct PredictionBadge {
+    /// Version of the badge
+    pub version: String,
+    /// Set ID of the badge
+    pub set_id: String,
+}
+
+/// Status of a prediction
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum PredictionStatus {
+    /// Prediction is running and viewers can make predictions
+    Active,
+    /// Prediction is locked, viewers can no longer make predictions
+    Locked,
+    /// Prediction is resolved and a winning outcome was chosen
+    Resolved,
+    /// Prediction is being resolved
+    ResolvePending,
+    /// Prediction was canceled and channel points were refunded
+    Canceled,
+    /// Prediction is being canceled
+    CancelPending,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Response, TopicData};
+    use super::*;
+
+    #[test]
+    fn event_created() {
+        let message = r##"
+{
+    "type": "event-created",
+    "data": {
+        "timestamp": "2021-09-30T10:00:00.123456789Z",
+        "event": {
+            "id": "5e5b4a1e-5c1b-4b9a-9d8a-1a2b3c4d5e6f",
+            "channel_id": "27620241",
+            "created_at": "2021-09-30T10:00:00.000000000Z",
+            "created_by": {
+                "type": "USER",
+                "user_id": "27620241",
+                "user_display_name": "emilgardis",
+                "extension_client_id": null
+            },
+            "ended_at": null,
+            "ended_by": null,
+            "locked_at": null,
+            "locked_by": null,
+            "outcomes": [
+                {
+                    "id": "1a2b3c4d-0000-0000-0000-000000000001",
+                    "color": "BLUE",
+                    "title": "yes",
+                    "total_points": 0,
+                    "total_users": 0,
+                    "top_predictors": [],
+                    "badge": { "version": "blue-1", "set_id": "predictions" }
+                },
+                {
+                    "id": "1a2b3c4d-0000-0000-0000-000000000002",
+                    "color": "PINK",
+                    "title": "no",
+                    "total_points": 0,
+                    "total_users": 0,
+                    "top_predictors": [],
+                    "badge": { "version": "pink-2", "set_id": "predictions" }
+                }
+            ],
+            "prediction_window_seconds": 120,
+            "status": "ACTIVE",
+            "title": "will it work?",
+            "winning_outcome_id": null
+        }
+    }
+}
+"##;
+
+        let source = format!(
+            r#"{{"type": "MESSAGE", "data": {{ "topic": "predictions-channel-v1.27620241", "message": {:?} }}}}"#,
+            message
+        );
+        let actual = dbg!(Response::parse(&source).unwrap());
+        assert!(matches!(
+            actual,
+            Response::Message {
+                data: TopicData::PredictionsChannelV1 { .. },
+            }
+        ));
+    }
+
+    #[test]
+    fn event_updated() {
+        let message = r##"
+{
+    "type": "event-updated",
+    "data": {
+        "timestamp": "2021-09-30T10:03:00.123456789Z",
+        "event": {
+            "id": "5e5b4a1e-5c1b-4b9a-9d8a-1a2b3c4d5e6f",
+            "channel_id": "27620241",
+            "created_at": "2021-09-30T10:00:00.000000000Z",
+            "created_by": {
+                "type": "USER",
+                "user_id": "27620241",
+                "user_display_name": "emilgardis",
+                "extension_client_id": null
+            },
+            "ended_at": "2021-09-30T10:03:00.000000000Z",
+            "ended_by": {
+                "type": "USER",
+                "user_id": "27620241",
+                "user_display_name": "emilgardis",
+                "extension_client_id": null
+            },
+            "locked_at": "2021-09-30T10:02:00.000000000Z",
+            "locked_by": null,
+            "outcomes": [
+                {
+                    "id": "1a2b3c4d-0000-0000-0000-000000000001",
+                    "color": "BLUE",
+                    "title": "yes",
+                    "total_points": 100,
+                    "total_users": 1,
+                    "top_predictors": [
+                        {
+                            "id": "abcdef",
+                            "event_id": "5e5b4a1e-5c1b-4b9a-9d8a-1a2b3c4d5e6f",
+                            "outcome_id": "1a2b3c4d-0000-0000-0000-000000000001",
+                            "channel_id": "27620241",
+                            "points": 100,
+                            "predicted_at": "2021-09-30T10:01:00.000000000Z",
+                            "updated_at": "2021-09-30T10:03:00.000000000Z",
+                            "user_id": "1234",
+                            "result": {
+                                "type": "WIN",
+                                "points_won": 100,
+                                "is_acknowledged": false
+                            },
+                            "user_display_name": "TMI"
+                        }
+                    ],
+                    "badge": { "version": "blue-1", "set_id": "predictions" }
+                }
+            ],
+            "prediction_window_seconds": 120,
+            "status": "RESOLVED",
+            "title": "will it work?",
+            "winning_outcome_id": "1a2b3c4d-0000-0000-0000-000000000001"
+        }
+    }
+}
+"##;
+
+        let source = format!(
+            r#"{{"type": "MESSAGE", "data": {{ "topic": "predictions-channel-v1.27620241", "message": {:?} }}}}"#,
+            message
+        );
+        let actual = dbg!(Response::parse(&source).unwrap());
+        assert!(matches!(
+            actual,
+            Response::Message {
+                data: TopicData::PredictionsChannelV1 { .. },
+            }
+        ));
+    }
+
+    #[test]
+    fn check_deser() {
+        use std::convert::TryInto as _;
+        let s = "predictions-channel-v1.1234";
+        assert_eq!(
+            PredictionsChannelV1 { channel_id: 1234 },
+            s.to_string().try_into().unwrap()
+        );
+    }
+
+    #[test]
+    fn check_ser() {
+        let s = "predictions-channel-v1.1234";
+        let right: String = PredictionsChannelV1 { channel_id: 1234 }.into();
+        assert_eq!(s.to_string(), right);
+    }
+}
@@ -0,0 +1,72 @@
+//! Scopes needed by a set of endpoints, subscriptions and topics, see [`scopes_for`]
+
+use std::collections::BTreeSet;
+
+use twitch_oauth2::Scope;
+
+/// The scopes needed by an endpoint, EventSub subscription or PubSub topic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ScopeRequirement {
+    /// Scopes needed to use it
+    pub scope: &'static [Scope],
+    /// Scopes that unlock more data
+    pub opt_scope: &'static [Scope],
+}
+
+impl ScopeRequirement {
+    /// The [scopes](crate::helix::Request::SCOPE) needed by the Helix endpoint `R`
+    #[cfg(feature = "helix")]
+    #[cfg_attr(nightly, doc(cfg(feature = "helix")))]
+    pub fn endpoint<R: crate::helix::Request>() -> Self {
+        ScopeRequirement {
+            scope: R::SCOPE,
+            opt_scope: R::OPT_SCOPE,
+        }
+    }
+
+    /// The [scopes](crate::eventsub::EventSubscription::SCOPE) needed by the EventSub subscription `E`
+    #[cfg(feature = "eventsub")]
+    #[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
+    pub fn subscription<E: crate::eventsub::EventSubscription>() -> Self {
+        ScopeRequirement {
+            scope: E::SCOPE,
+            opt_scope: E::OPT_SCOPE,
+        }
+    }
+
+    /// The [scopes](crate::pubsub::Topic::SCOPE) needed by the PubSub topic `T`
+    #[cfg(feature = "pubsub")]
+    #[cfg_attr(nightly, doc(cfg(feature = "pubsub")))]
+    pub fn topic<T: crate::pubsub::Topic>() -> Self {
+        ScopeRequirement {
+            scope: T::SCOPE,
+            opt_scope: &[],
+        }
+    }
+}
+
+/// All scopes, required and optional, needed by `requirements`.
+///
+/// Use this to request exactly the scopes the endpoints, subscriptions and topics your application uses need.
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api2::{eventsub, helix, scopes_for, ScopeRequirement};
+///
+/// let scopes = scopes_for(&[
+///     ScopeRequirement::endpoint::<helix::users::GetUsersRequest>(),
+///     ScopeRequirement::endpoint::<helix::moderation::GetModeratorsRequest>(),
+///     ScopeRequirement::subscription::<eventsub::channel::ChannelBanV1>(),
+/// ]);
+/// assert!(scopes.contains(&twitch_oauth2::Scope::ModerationRead));
+/// assert!(scopes.contains(&twitch_oauth2::Scope::UserReadEmail));
+/// ```
+pub fn scopes_for(requirements: &[ScopeRequirement]) -> BTreeSet<Scope> {
+    requirements
+        .iter()
+        .flat_map(|requirement| requirement.scope.iter().chain(requirement.opt_scope))
+        .cloned()
+        .collect()
+}
@@ -65,6 +65,8 @@ impl<'a, C: crate::HttpClient<'a>> TmiClient<'a, C> {
 
     /// Get all the chatters in the chat
     ///
+    /// Prefer the helix endpoint `helix::chat::get_chatters` when you have a moderator token, this endpoint is not supported by twitch.
+    ///
     /// # Notes
     ///
     /// This function will aside from url sanitize the broadcasters username, will also remove any `#` and make it lowercase ascii
@@ -173,9 +175,9 @@ pub struct Chatters {
 #[derive(Debug)]
 pub enum HostsRequestId {
     /// Request the broadcaster that a given channel is hosting.
-    Host(UserId),
+    Host(types::UserId),
     /// Request a list of channels hosting a target broadcaster.
-    Target(UserId),
+    Target(types::UserId),
 }
 
 /// Returned by TMI at `https://tmi.twitch.tv/hosts`
@@ -195,9 +197,11 @@ pub struct GetHosts {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Host {
     /// User ID of the hosting channel
-    pub host_id: UserId,
+    #[serde(with = "numeric_user_id")]
+    pub host_id: types::UserId,
     /// User ID of the hosted channel. Will be missing if the given channel is not hosting anyone.
-    pub target_id: Option<UserId>,
+    #[serde(default, with = "numeric_user_id::option")]
+    pub target_id: Option<types::UserId>,
     /// Login of the hosting channel, if requested with `include_logins = true`
     pub host_login: Option<types::Nickname>,
     /// Login of the hosted channel, if requested with `include_logins = true`
@@ -208,8 +212,62 @@ pub struct Host {
     pub target_display_name: Option<types::Nickname>,
 }
 
-/// User ID
-pub type UserId = u64; // TMI user ID's appear to still be ints, even though Helix uses strings.
+/// TMI user ID's appear to still be ints, even though Helix uses strings.
+///
+/// (De)serializes a [`types::UserId`] as a number, also accepting strings when deserializing.
+mod numeric_user_id {
+    use crate::types;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumOrString {
+        Num(u64),
+        String(types::UserId),
+    }
+
+    impl From<NumOrString> for types::UserId {
+        fn from(id: NumOrString) -> Self {
+            match id {
+                NumOrString::Num(id) => id.into(),
+                NumOrString::String(id) => id,
+            }
+        }
+    }
+
+    pub fn serialize<S: Serializer>(id: &types::UserId, serializer: S) -> Result<S::Ok, S::Error> {
+        match id.as_str().parse::<u64>() {
+            Ok(id) => serializer.serialize_u64(id),
+            Err(_) => serializer.serialize_str(id.as_str()),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<types::UserId, D::Error> {
+        NumOrString::deserialize(deserializer).map(Into::into)
+    }
+
+    pub mod option {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(
+            id: &Option<types::UserId>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match id {
+                Some(id) => super::serialize(id, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<types::UserId>, D::Error> {
+            Ok(Option::<NumOrString>::deserialize(deserializer)?.map(Into::into))
+        }
+    }
+}
 
 /// Errors for [`TmiClient`] requests
 #[derive(thiserror::Error, Debug, displaydoc::Display)]
@@ -223,3 +281,20 @@ pub enum RequestError<RE: std::error::Error + Send + Sync + 'static> {
     /// could not parse body as utf8: {1}
     Utf8Error(Vec<u8>, std::str::Utf8Error),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numeric_ids() {
+        let source = r#"{"hosts":[{"host_id":27620241,"target_id":1234,"host_login":"emilgardis","target_login":"tmi","host_display_name":"emilgardis","target_display_name":"TMI"},{"host_id":"1234"}]}"#;
+        let hosts: GetHosts = crate::parse_json(source, true).unwrap();
+        assert_eq!(hosts.hosts[0].host_id, types::UserId::from(27620241));
+        assert_eq!(hosts.hosts[0].target_id, Some(types::UserId::from(1234)));
+        assert_eq!(hosts.hosts[1].host_id.as_str(), "1234");
+        assert_eq!(hosts.hosts[1].target_id, None);
+        let ser = serde_json::to_string(&hosts.hosts[0]).unwrap();
+        assert!(ser.starts_with(r#"{"host_id":27620241,"target_id":1234,"#));
+    }
+}
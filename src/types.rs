@@ -6,6 +6,16 @@ use serde::{Deserialize, Serialize};
 #[aliri_braid::braid(serde)]
 pub struct UserId;
 
+impl From<u64> for UserId {
+    fn from(id: u64) -> Self { UserId::new(id.to_string()) }
+}
+
+impl<'a> std::convert::TryFrom<&'a UserIdRef> for u64 {
+    type Error = std::num::ParseIntError;
+
+    fn try_from(id: &'a UserIdRef) -> Result<Self, Self::Error> { id.as_str().parse() }
+}
+
 /// A reward ID.
 #[aliri_braid::braid(serde)]
 pub struct RewardId;
@@ -24,18 +34,141 @@ pub type UserNameRef = NicknameRef;
 #[aliri_braid::braid(serde)]
 pub struct DisplayName;
 
+impl DisplayNameRef {
+    /// Whether this display name is a capitalization of `login`, comparing them case-insensitively.
+    ///
+    /// [Localized](DisplayNameRef::is_localized) display names, like `ハロー`, differ from the login and never match.
+    pub fn matches_login(&self, login: &UserNameRef) -> bool {
+        self.as_str()
+            .chars()
+            .flat_map(char::to_lowercase)
+            .eq(login.as_str().chars().flat_map(char::to_lowercase))
+    }
+
+    /// Whether this display name uses characters that can't be part of a login, so the login can't be derived from it.
+    pub fn is_localized(&self) -> bool {
+        !self
+            .as_str()
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+}
+
 /// A nickname, not capitalized.
 #[aliri_braid::braid(serde)]
 pub struct Nickname;
 
+/// An email address of a user
+#[aliri_braid::braid(serde)]
+pub struct Email;
+
+/// Any identifier of a user
+///
+/// Accepted by helpers like [`HelixClient::get_user`](crate::helix::HelixClient::get_user), so callers can pass whatever they have.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UserIdentifier {
+    /// The [ID](UserId) of the user
+    Id(UserId),
+    /// The [login](UserName) of the user
+    Login(UserName),
+    /// The [display name](DisplayName) of the user
+    DisplayName(DisplayName),
+}
+
+impl From<UserId> for UserIdentifier {
+    fn from(id: UserId) -> Self { UserIdentifier::Id(id) }
+}
+
+impl From<&UserIdRef> for UserIdentifier {
+    fn from(id: &UserIdRef) -> Self { UserIdentifier::Id(id.to_owned()) }
+}
+
+impl From<UserName> for UserIdentifier {
+    fn from(login: UserName) -> Self { UserIdentifier::Login(login) }
+}
+
+impl From<&UserNameRef> for UserIdentifier {
+    fn from(login: &UserNameRef) -> Self { UserIdentifier::Login(login.to_owned()) }
+}
+
+impl From<DisplayName> for UserIdentifier {
+    fn from(name: DisplayName) -> Self { UserIdentifier::DisplayName(name) }
+}
+
+impl From<&DisplayNameRef> for UserIdentifier {
+    fn from(name: &DisplayNameRef) -> Self { UserIdentifier::DisplayName(name.to_owned()) }
+}
+
 /// RFC3339 timestamp
-#[aliri_braid::braid(serde, validator)]
+///
+/// Timestamps are normalized on construction, the time-offset is always `Z` (UTC) and trailing zeros in the fractional seconds are removed.
+/// Other offsets are converted to UTC with the `time` feature, without it they are rejected.
+/// This means that timestamps with differing sub-second precision compare equal if they represent the same time.
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api2::types::Timestamp;
+///
+/// let time = Timestamp::new("2021-07-01T13:37:00.500Z").unwrap();
+/// assert_eq!(time.as_str(), "2021-07-01T13:37:00.5Z");
+/// assert_eq!(time, Timestamp::new("2021-07-01T13:37:00.50000Z").unwrap());
+/// assert!(Timestamp::new("2021-07-01T13:37:00Z").unwrap() < time);
+/// ```
+#[aliri_braid::braid(serde, normalizer)]
 pub struct Timestamp;
 
-impl aliri_braid::Validator for Timestamp {
+impl aliri_braid::Normalizer for Timestamp {
     type Error = TimestampParseError;
 
-    fn validate(s: &str) -> Result<(), Self::Error> {
+    fn normalize(s: &str) -> Result<std::borrow::Cow<'_, str>, Self::Error> {
+        use std::borrow::Cow;
+        let s: Cow<'_, str> = if s.contains(|c| c == 't' || c == 'z') {
+            Cow::Owned(s.replace('t', "T").replace('z', "Z"))
+        } else {
+            Cow::Borrowed(s)
+        };
+        Timestamp::validate_rfc3339(&s)?;
+        #[cfg(feature = "time")]
+        let s: Cow<'_, str> = if s.ends_with('Z') {
+            s
+        } else {
+            let utc =
+                time::OffsetDateTime::parse(&s, &time::format_description::well_known::Rfc3339)?
+                    .to_offset(time::UtcOffset::UTC);
+            Cow::Owned(utc.format(&time::format_description::well_known::Rfc3339)?)
+        };
+        Ok(trim_fraction(s))
+    }
+}
+
+/// Remove trailing zeros in the fractional seconds of a valid timestamp
+fn trim_fraction(s: std::borrow::Cow<'_, str>) -> std::borrow::Cow<'_, str> {
+    let dot = match s.find('.') {
+        Some(dot) => dot,
+        None => return s,
+    };
+    let end = s[dot + 1..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(s.len(), |i| dot + 1 + i);
+    let fraction = s[dot + 1..end].trim_end_matches('0');
+    if fraction.len() == end - dot - 1 {
+        return s;
+    }
+    let mut trimmed = String::with_capacity(s.len());
+    trimmed.push_str(&s[..dot]);
+    if !fraction.is_empty() {
+        trimmed.push('.');
+        trimmed.push_str(fraction);
+    }
+    trimmed.push_str(&s[end..]);
+    std::borrow::Cow::Owned(trimmed)
+}
+
+impl Timestamp {
+    /// Validate that a string is a valid RFC3339 timestamp
+    fn validate_rfc3339(s: &str) -> Result<(), TimestampParseError> {
         #[cfg(feature = "time")]
         {
             let _ = time::OffsetDateTime::parse(s, &time::format_description::well_known::Rfc3339)?;
@@ -147,6 +280,31 @@ impl TimestampParseError {
 }
 
 impl Timestamp {
+    /// Create a timestamp, treating an empty string as no timestamp.
+    ///
+    /// Twitch sometimes sends an empty string in place of a timestamp, this method makes it possible to handle that case without an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twitch_api2::types::Timestamp;
+    ///
+    /// assert_eq!(Timestamp::new_lenient("").unwrap(), None);
+    /// assert_eq!(
+    ///     Timestamp::new_lenient(" 2021-07-01T13:37:00Z ").unwrap(),
+    ///     Some(Timestamp::new("2021-07-01T13:37:00Z").unwrap())
+    /// );
+    /// assert!(Timestamp::new_lenient("not a timestamp").is_err());
+    /// ```
+    pub fn new_lenient(s: &str) -> Result<Option<Timestamp>, TimestampParseError> {
+        let s = s.trim();
+        if s.is_empty() {
+            Ok(None)
+        } else {
+            Timestamp::new(s).map(Some)
+        }
+    }
+
     /// Set the partial-time component of the timestamp.
     ///
     /// # Panics
@@ -240,6 +398,8 @@ impl Timestamp {
 impl TimestampRef {
     /// Normalize the timestamp into UTC time.
     ///
+    /// Timestamps are already normalized on construction, so this always returns the timestamp as is.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -247,23 +407,18 @@ impl TimestampRef {
     ///
     /// let time = Timestamp::new("2021-07-01T13:37:00Z").unwrap();
     /// assert_eq!(time.normalize()?.as_ref(), &time);
-    /// let time2 = Timestamp::new("2021-07-01T13:37:00-01:00").unwrap();
-    /// assert_ne!(time2.normalize()?.as_ref(), &time2);
     /// # Ok::<(), std::boxed::Box<dyn std::error::Error + 'static>>(())
     /// ```
-    #[allow(unreachable_code)]
     pub fn normalize(&'_ self) -> Result<std::borrow::Cow<'_, TimestampRef>, TimestampParseError> {
-        let s = self.as_str();
-        if s.ends_with('Z') {
-            Ok(self.into())
-        } else {
-            #[cfg(feature = "time")]
-            {
-                use std::convert::TryInto;
-                let utc = self.to_utc();
-                return Ok(std::borrow::Cow::Owned(utc.try_into()?));
-            }
-            panic!("non `Z` timestamps are not possible to use without the `time` feature enabled for `twitch_api2`")
+        Ok(self.into())
+    }
+
+    /// Split the timestamp into the date and time up to seconds, and the fractional seconds.
+    fn split_fraction(&self) -> (&str, &str) {
+        let s = self.as_str().trim_end_matches('Z');
+        match s.find('.') {
+            Some(dot) => (&s[..dot], &s[dot + 1..]),
+            None => (s, ""),
         }
     }
 
@@ -324,11 +479,15 @@ impl TimestampRef {
 }
 
 impl PartialOrd for Timestamp {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         // Defer to TimestampRef impl
         let this: &TimestampRef = self.as_ref();
         let other: &TimestampRef = other.as_ref();
-        this.partial_cmp(other)
+        this.cmp(other)
     }
 }
 
@@ -341,28 +500,17 @@ impl PartialOrd<Timestamp> for TimestampRef {
 }
 
 impl PartialOrd for TimestampRef {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        // to check ordering, we normalize offset, then do a lexicographic comparison if possible,
-        // We can do this because the timestamp should always be RFC3339 with time-offset = 'Z' with normalize.
-        // However, we need to make sure punctuation and length is correct. Without the `time` feature, it's impossible to get a non-UTC timestamp, so normalize will do nothing.
-        let this = self
-            .normalize()
-            .expect("normalization failed, this is a bug");
-        let other = other
-            .normalize()
-            .expect("normalization of other failed, this is a bug");
-        // If a punctuation exists in only one, we can't order.
-        #[allow(clippy::if_same_then_else)]
-        if this.as_ref().as_str().contains('.') ^ other.as_ref().as_str().contains('.') {
-            #[cfg(feature = "tracing")]
-            tracing::trace!("comparing two `Timestamps` with differing punctuation");
-            return None;
-        } else if this.0.len() != other.0.len() {
-            #[cfg(feature = "tracing")]
-            tracing::trace!("comparing two `Timestamps` with differing length");
-            return None;
-        }
-        this.as_str().partial_cmp(other.as_str())
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for TimestampRef {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Timestamps are normalized to RFC3339 with time-offset = 'Z' and no trailing zeros in the fractional seconds.
+        // This means we can compare the date and time lexicographically, and then the fractional seconds lexicographically.
+        let (this, this_fraction) = self.split_fraction();
+        let (other, other_fraction) = other.split_fraction();
+        this.cmp(other)
+            .then_with(|| this_fraction.cmp(other_fraction))
     }
 }
 
@@ -406,9 +554,10 @@ impl std::convert::TryFrom<time::OffsetDateTime> for Timestamp {
     type Error = time::error::Format;
 
     fn try_from(value: time::OffsetDateTime) -> Result<Self, Self::Error> {
-        Ok(Timestamp(
-            value.format(&time::format_description::well_known::Rfc3339)?,
-        ))
+        let s = value
+            .to_offset(time::UtcOffset::UTC)
+            .format(&time::format_description::well_known::Rfc3339)?;
+        Ok(Timestamp(trim_fraction(s.into()).into_owned()))
     }
 }
 
@@ -436,6 +585,77 @@ pub struct TeamId;
 #[aliri_braid::braid(serde)]
 pub struct StreamId;
 
+/// A Stream Marker ID
+#[aliri_braid::braid(serde)]
+pub struct StreamMarkerId;
+
+/// A language, as a lowercase [ISO 639-1](https://en.wikipedia.org/wiki/List_of_ISO_639-1_codes) code, optionally with a region like `zh-hk`, or `other` if the language is not supported by Twitch.
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api2::types::Language;
+///
+/// assert!(Language::new("en").is_ok());
+/// assert!(Language::new("zh-hk").is_ok());
+/// assert!(Language::new("other").is_ok());
+/// assert!(Language::new("EN").is_err());
+/// assert!(Language::new("english").is_err());
+/// ```
+#[aliri_braid::braid(serde, validator)]
+pub struct Language;
+
+impl aliri_braid::Validator for Language {
+    type Error = LanguageParseError;
+
+    fn validate(s: &str) -> Result<(), Self::Error> {
+        if s == "other" {
+            return Ok(());
+        }
+        let mut parts = s.split('-');
+        let is_valid = matches!(
+            parts.next(),
+            Some(code) if code.len() == 2 && code.bytes().all(|b| b.is_ascii_lowercase())
+        ) && parts.all(|region| {
+            (2..=4).contains(&region.len())
+                && region
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+        });
+        if is_valid {
+            Ok(())
+        } else {
+            Err(LanguageParseError::Invalid(s.to_owned()))
+        }
+    }
+}
+
+/// Errors that can occur when parsing a [`Language`].
+#[derive(Debug, thiserror::Error, displaydoc::Display)]
+#[non_exhaustive]
+pub enum LanguageParseError {
+    /// language {0:?} is not a lowercase ISO 639-1 code or `other`
+    Invalid(String),
+}
+
+/// A language as returned by Twitch, not validated.
+///
+/// Twitch may return languages that are not a [`Language`], like `asl` or an empty string.
+/// Use [`RawLanguageRef::to_language`] to get a validated [`Language`].
+#[aliri_braid::braid(serde)]
+pub struct RawLanguage;
+
+impl RawLanguageRef {
+    /// Validate this language, see [`Language::new`]
+    pub fn to_language(&self) -> Result<Language, LanguageParseError> {
+        Language::new(self.as_str().to_owned())
+    }
+}
+
+impl From<Language> for RawLanguage {
+    fn from(language: Language) -> Self { RawLanguage::new(language.into_string()) }
+}
+
 /// A message ID
 #[aliri_braid::braid(serde)]
 pub struct MsgId;
@@ -490,8 +710,81 @@ impl EmoteIdRef {
 pub(crate) static EMOTE_V2_URL_TEMPLATE: &str =
     "https://static-cdn.jtvnw.net/emoticons/v2/{{id}}/{{format}}/{{theme_mode}}/{{scale}}";
 
+/// IDs of some well-known global emotes.
+///
+/// These are plain strings, use [`EmoteIdRef::from_str`] to get an [`EmoteIdRef`].
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api2::types::{emote_ids, EmoteIdRef};
+///
+/// let kappa = EmoteIdRef::from_str(emote_ids::KAPPA);
+/// assert_eq!(
+///     kappa.default_render(),
+///     "https://static-cdn.jtvnw.net/emoticons/v2/25/default/light/1.0"
+/// );
+/// ```
+pub mod emote_ids {
+    /// `:)`
+    pub const SMILE: &str = "1";
+    /// `:(`
+    pub const FROWN: &str = "2";
+    /// `:D`
+    pub const BIG_SMILE: &str = "3";
+    /// `<3`
+    pub const HEART: &str = "9";
+    /// `Kappa`
+    pub const KAPPA: &str = "25";
+    /// `MrDestructoid`
+    pub const MR_DESTRUCTOID: &str = "28";
+    /// `DansGame`
+    pub const DANS_GAME: &str = "33";
+    /// `SwiftRage`
+    pub const SWIFT_RAGE: &str = "34";
+    /// `Kreygasm`
+    pub const KREYGASM: &str = "41";
+    /// `SMOrc`
+    pub const SMORC: &str = "52";
+    /// `BloodTrail`
+    pub const BLOOD_TRAIL: &str = "69";
+    /// `BibleThump`
+    pub const BIBLE_THUMP: &str = "86";
+    /// `ResidentSleeper`
+    pub const RESIDENT_SLEEPER: &str = "245";
+    /// `4Head`
+    pub const FOUR_HEAD: &str = "354";
+    /// `FailFish`
+    pub const FAIL_FISH: &str = "360";
+    /// `Keepo`
+    pub const KEEPO: &str = "1902";
+    /// `BabyRage`
+    pub const BABY_RAGE: &str = "22639";
+    /// `WutFace`
+    pub const WUT_FACE: &str = "28087";
+    /// `HeyGuys`
+    pub const HEY_GUYS: &str = "30259";
+    /// `KappaPride`
+    pub const KAPPA_PRIDE: &str = "55338";
+    /// `NotLikeThis`
+    pub const NOT_LIKE_THIS: &str = "58765";
+    /// `SeemsGood`
+    pub const SEEMS_GOOD: &str = "64138";
+    /// `VoHiYo`
+    pub const VO_HI_YO: &str = "81274";
+    /// `CoolStoryBob`
+    pub const COOL_STORY_BOB: &str = "123171";
+    /// `LUL`
+    pub const LUL: &str = "425618";
+}
+
 /// Formats for an emote.
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[serde(rename_all = "lowercase")]
 pub enum EmoteAnimationSetting {
     /// Static
@@ -506,6 +799,11 @@ impl std::fmt::Display for EmoteAnimationSetting {
 
 /// Background themes available for an emote.
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[serde(rename_all = "lowercase")]
 pub enum EmoteThemeMode {
     /// Light
@@ -524,6 +822,11 @@ impl std::fmt::Display for EmoteThemeMode {
 
 /// Scales available for an emote.
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub enum EmoteScale {
     /// 1.0
     #[serde(rename = "1.0")]
@@ -663,6 +966,87 @@ impl EmoteUrlBuilder<'_> {
             scale = self.scale,
         )
     }
+
+    /// Create the URL for this emote in the legacy v1 CDN format.
+    ///
+    /// Generated URL will be `"https://static-cdn.jtvnw.net/emoticons/v1/{emote_id}/{scale}"`.
+    /// The v1 format has no theme or animation, and only works for numeric emote ids, e.g. the ones in the `emotes` IRC tag.
+    pub fn render_v1(self) -> String {
+        format!(
+            "https://static-cdn.jtvnw.net/emoticons/v1/{emote_id}/{scale}",
+            emote_id = self.id,
+            scale = self.scale,
+        )
+    }
+}
+
+impl EmoteUrlBuilder<'static> {
+    /// Parse an emote URL in either the v1 or v2 CDN format.
+    ///
+    /// The returned builder renders in the v2 format with [`render`](EmoteUrlBuilder::render), which makes this a conversion from v1 to v2.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use twitch_api2::types::EmoteUrlBuilder;
+    /// let builder = EmoteUrlBuilder::from_url("https://static-cdn.jtvnw.net/emoticons/v1/25/3.0")?;
+    /// assert_eq!(
+    ///     builder.render(),
+    ///     "https://static-cdn.jtvnw.net/emoticons/v2/25/default/light/3.0"
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error + 'static>>(())
+    /// ```
+    pub fn from_url(url: &str) -> Result<Self, EmoteUrlParseError> {
+        let path = url
+            .strip_prefix("https://static-cdn.jtvnw.net/emoticons/")
+            .ok_or_else(|| EmoteUrlParseError::NotEmoteUrl(url.to_owned()))?;
+        let segments: Vec<&str> = path.split('/').collect();
+        let (id, animation_setting, theme_mode, scale) = match segments[..] {
+            ["v1", id, scale] => (id, None, EmoteThemeMode::Light, scale),
+            ["v2", id, format, theme_mode, scale] => (
+                id,
+                match format {
+                    "default" => None,
+                    "static" => Some(EmoteAnimationSetting::Static),
+                    "animated" => Some(EmoteAnimationSetting::Animated),
+                    _ => return Err(EmoteUrlParseError::InvalidSegment(format.to_owned())),
+                },
+                match theme_mode {
+                    "light" => EmoteThemeMode::Light,
+                    "dark" => EmoteThemeMode::Dark,
+                    _ => return Err(EmoteUrlParseError::InvalidSegment(theme_mode.to_owned())),
+                },
+                scale,
+            ),
+            _ => return Err(EmoteUrlParseError::NotEmoteUrl(url.to_owned())),
+        };
+        if id.is_empty() {
+            return Err(EmoteUrlParseError::NotEmoteUrl(url.to_owned()));
+        }
+        let scale = match scale {
+            "1.0" => EmoteScale::Size1_0,
+            "2.0" => EmoteScale::Size2_0,
+            "3.0" => EmoteScale::Size3_0,
+            _ => return Err(EmoteUrlParseError::InvalidSegment(scale.to_owned())),
+        };
+        Ok(EmoteUrlBuilder {
+            id: std::borrow::Cow::Owned(EmoteId::from(id)),
+            animation_setting,
+            theme_mode,
+            scale,
+            template: EMOTE_V2_URL_TEMPLATE.into(),
+        })
+    }
+}
+
+/// Errors that can occur when parsing an emote URL with [`EmoteUrlBuilder::from_url`].
+#[derive(Debug, thiserror::Error, displaydoc::Display)]
+#[non_exhaustive]
+pub enum EmoteUrlParseError {
+    /// url {0:?} is not a v1 or v2 emote CDN url
+    NotEmoteUrl(String),
+    /// emote url contains an unknown format, theme or scale: {0:?}
+    InvalidSegment(String),
 }
 
 /// An Emote Set ID
@@ -681,8 +1065,25 @@ pub struct HypeTrainId;
 #[aliri_braid::braid(serde)]
 pub struct CreatorGoalId;
 
+/// A Blocked Term ID
+#[aliri_braid::braid(serde)]
+pub struct BlockedTermId;
+
+/// A Charity Campaign ID
+#[aliri_braid::braid(serde)]
+pub struct CharityCampaignId;
+
+/// A Charity Donation ID
+#[aliri_braid::braid(serde)]
+pub struct CharityDonationId;
+
 /// An emote index as defined by eventsub, similar to IRC `emotes` twitch tag.
 #[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ResubscriptionEmote {
@@ -700,13 +1101,57 @@ impl std::fmt::Display for ResubscriptionEmote {
     }
 }
 
+/// An image URL where the size can be chosen by substituting `{width}` and `{height}`, or `%{width}` and `%{height}`.
+///
+/// Profile and offline images of users have a fixed size, they are not [templated](TemplatedImageUrlRef::is_templated).
+///
+/// # Examples
+///
+/// ```rust
+/// # use twitch_api2::types::TemplatedImageUrl;
+/// let url = TemplatedImageUrl::from(
+///     "https://static-cdn.jtvnw.net/ttv-boxart/Fortnite-{width}x{height}.jpg",
+/// );
+/// assert!(url.is_templated());
+/// assert_eq!(
+///     url.render(285, 380),
+///     "https://static-cdn.jtvnw.net/ttv-boxart/Fortnite-285x380.jpg"
+/// );
+/// ```
+#[aliri_braid::braid(serde)]
+pub struct TemplatedImageUrl;
+
+impl TemplatedImageUrlRef {
+    /// Returns `true` if the url contains a `{width}` or `{height}` pattern that can be substituted.
+    pub fn is_templated(&self) -> bool {
+        self.as_str().contains("{width}") || self.as_str().contains("{height}")
+    }
+
+    /// Render the url with the given size.
+    ///
+    /// If the url is not [templated](TemplatedImageUrlRef::is_templated), it is returned as is.
+    pub fn render(&self, width: u32, height: u32) -> String {
+        let (width, height) = (width.to_string(), height.to_string());
+        self.as_str()
+            .replace("%{width}", &width)
+            .replace("{width}", &width)
+            .replace("%{height}", &height)
+            .replace("{height}", &height)
+    }
+}
+
 /// A game or category as defined by Twitch
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct TwitchCategory {
     /// Template URL for the game’s box art.
-    pub box_art_url: String,
+    pub box_art_url: TemplatedImageUrl,
     /// Game or category ID.
     pub id: CategoryId,
     /// Game name.
@@ -715,6 +1160,11 @@ pub struct TwitchCategory {
 
 /// Subscription tiers
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[serde(field_identifier)]
 pub enum SubscriptionTier {
     /// Tier 1. $4.99
@@ -745,8 +1195,120 @@ impl Serialize for SubscriptionTier {
     }
 }
 
+/// Custom reward redemption statuses: UNFULFILLED, FULFILLED or CANCELED
+///
+/// Statuses not known to this crate deserialize as [`Unknown`](CustomRewardRedemptionStatus::Unknown).
+///
+/// Helix uses uppercase statuses and EventSub lowercase ones, both are accepted when deserializing. Serializes as uppercase.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub enum CustomRewardRedemptionStatus {
+    /// Unfulfilled reward - the user has claimed it but it is still pending.
+    #[serde(rename = "UNFULFILLED", alias = "unfulfilled")]
+    Unfulfilled,
+    /// Fulfilled reward - the user has claimed it and the reward has been granted.
+    #[serde(rename = "FULFILLED", alias = "fulfilled")]
+    Fulfilled,
+    /// Cancelled reward - the reward has been cancelled before fulfillment, and any spent points have been refunded.
+    #[serde(rename = "CANCELED", alias = "canceled")]
+    Canceled,
+    /// Unknown status, sent by EventSub when the status could not be determined
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
+}
+
+impl CustomRewardRedemptionStatus {
+    /// The status as used in EventSub, e.g. `fulfilled`
+    pub fn as_lowercase_str(&self) -> &'static str {
+        match self {
+            CustomRewardRedemptionStatus::Unfulfilled => "unfulfilled",
+            CustomRewardRedemptionStatus::Fulfilled => "fulfilled",
+            CustomRewardRedemptionStatus::Canceled => "canceled",
+            CustomRewardRedemptionStatus::Unknown => "unknown",
+        }
+    }
+
+    /// Whether the spent points were refunded to the user, i.e. the redemption was canceled
+    pub fn is_refunded(&self) -> bool { matches!(self, CustomRewardRedemptionStatus::Canceled) }
+}
+
+/// An AutoMod level, from [no filtering](AutoModLevel::Level0) to [the most aggressive filtering](AutoModLevel::Level4)
+///
+/// Levels are ordered, so `level >= AutoModLevel::Level2` checks for at least level 2. (De)serializes as a number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub enum AutoModLevel {
+    /// No filtering
+    Level0 = 0,
+    /// Level 1
+    Level1 = 1,
+    /// Level 2
+    Level2 = 2,
+    /// Level 3
+    Level3 = 3,
+    /// Level 4, the most aggressive filtering
+    Level4 = 4,
+}
+
+impl Default for AutoModLevel {
+    fn default() -> Self { AutoModLevel::Level0 }
+}
+
+impl From<AutoModLevel> for u8 {
+    fn from(level: AutoModLevel) -> Self { level as u8 }
+}
+
+impl std::convert::TryFrom<u8> for AutoModLevel {
+    type Error = u8;
+
+    fn try_from(level: u8) -> Result<Self, Self::Error> {
+        match level {
+            0 => Ok(AutoModLevel::Level0),
+            1 => Ok(AutoModLevel::Level1),
+            2 => Ok(AutoModLevel::Level2),
+            3 => Ok(AutoModLevel::Level3),
+            4 => Ok(AutoModLevel::Level4),
+            other => Err(other),
+        }
+    }
+}
+
+impl Serialize for AutoModLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: serde::Serializer {
+        serializer.serialize_u8(u8::from(*self))
+    }
+}
+
+impl<'de> Deserialize<'de> for AutoModLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: serde::Deserializer<'de> {
+        use std::convert::TryFrom;
+        let level = u8::deserialize(deserializer)?;
+        AutoModLevel::try_from(level).map_err(|level| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(level.into()),
+                &"an AutoMod level from 0 to 4",
+            )
+        })
+    }
+}
+
 /// Broadcaster types: "partner", "affiliate", or "".
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub enum BroadcasterType {
     /// Partner
     #[serde(rename = "partner")]
@@ -772,6 +1334,11 @@ impl Serialize for BroadcasterType {
 
 /// User types: "staff", "admin", "global_mod", or "".
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub enum UserType {
     /// Staff
     #[serde(rename = "staff")]
@@ -801,6 +1368,11 @@ impl Serialize for UserType {
 
 /// Period during which the video was created
 #[derive(PartialEq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[serde(rename_all = "lowercase")]
 pub enum VideoPeriod {
     /// Filter by all. Effectively a no-op
@@ -815,6 +1387,11 @@ pub enum VideoPeriod {
 
 /// Type of video
 #[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[serde(rename_all = "snake_case")]
 pub enum VideoType {
     /// A live video
@@ -842,6 +1419,11 @@ pub enum VideoType {
 
 /// Type of video
 #[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[serde(rename_all = "lowercase")]
 pub enum VideoPrivacy {
     /// Video is public
@@ -860,6 +1442,11 @@ pub enum VideoPrivacy {
     PartialEq,
     Eq,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[repr(u64)]
 #[non_exhaustive]
 pub enum CommercialLength {
@@ -902,6 +1489,11 @@ pub enum CommercialLengthParseError {
 
 /// A user according to many endpoints
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 pub struct User {
     /// ID of the user
@@ -915,11 +1507,16 @@ pub struct User {
     pub display_name: DisplayName,
     #[serde(default)]
     /// URL of the user's profile
-    pub profile_image_url: Option<String>,
+    pub profile_image_url: Option<TemplatedImageUrl>,
 }
 
 /// Links to the same image of different sizes
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Image {
@@ -933,6 +1530,11 @@ pub struct Image {
 
 /// Information about global cooldown
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct GlobalCooldown {
@@ -945,6 +1547,11 @@ pub struct GlobalCooldown {
 
 /// Reward redemption max
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[serde(untagged)]
 #[non_exhaustive]
@@ -967,8 +1574,29 @@ pub enum Max {
     },
 }
 
+impl Max {
+    /// The maximum amount of redemptions, `None` if the limit is not enabled.
+    pub fn limit(&self) -> Option<u32> {
+        match *self {
+            Max::MaxPerStream {
+                is_enabled,
+                max_per_stream: max,
+            }
+            | Max::MaxPerUserPerStream {
+                is_enabled,
+                max_per_user_per_stream: max,
+            } => is_enabled.then(|| max),
+        }
+    }
+}
+
 /// Poll choice
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct PollChoice {
@@ -987,6 +1615,11 @@ pub struct PollChoice {
 // FIXME: Poll status has different name depending on if returned from helix or eventsub. See https://twitch.uservoice.com/forums/310213-developers/suggestions/43402176
 /// Status of a poll
 #[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[serde(rename_all = "UPPERCASE")]
 #[non_exhaustive]
@@ -1011,9 +1644,19 @@ pub enum PollStatus {
     Invalid,
 }
 
+impl PollStatus {
+    /// Poll is currently in progress and viewers can vote.
+    pub fn is_active(&self) -> bool { matches!(self, PollStatus::Active) }
+}
+
 // FIXME: Prediction status has different name depending on if returned from helix or eventsub. See https://twitch.uservoice.com/forums/310213-developers/suggestions/43402197
 /// Status of the Prediction
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[serde(rename_all = "UPPERCASE")]
 #[non_exhaustive]
@@ -1032,8 +1675,38 @@ pub enum PredictionStatus {
     Locked,
 }
 
+impl PredictionStatus {
+    /// Prediction is not yet resolved or canceled, i.e. it is [active](PredictionStatus::Active) or [locked](PredictionStatus::Locked).
+    pub fn is_running(&self) -> bool {
+        matches!(self, PredictionStatus::Active | PredictionStatus::Locked)
+    }
+}
+
+/// Color of a prediction outcome
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone, Copy)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
+pub enum PredictionOutcomeColor {
+    /// Blue, the color of the first outcome, or of all outcomes in predictions with more than two outcomes.
+    #[serde(alias = "blue")]
+    Blue,
+    /// Pink, the color of the second outcome in predictions with two outcomes.
+    #[serde(alias = "pink")]
+    Pink,
+}
+
 /// Outcome for the Prediction
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct PredictionOutcome {
@@ -1047,13 +1720,18 @@ pub struct PredictionOutcome {
     pub channel_points: Option<i64>,
     /// Array of users who were the top predictors. null if none. Top 10
     pub top_predictors: Option<Vec<PredictionTopPredictors>>,
-    /// Color for the outcome. Valid values: BLUE, PINK
-    pub color: String,
+    /// Color for the outcome.
+    pub color: PredictionOutcomeColor,
 }
 
 // FIXME: eventsub adds prefix `user_*`. See https://discord.com/channels/325552783787032576/326772207844065290/842359030252437514
 /// Users who were the top predictors.
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct PredictionTopPredictors {
@@ -1076,6 +1754,11 @@ pub struct PredictionTopPredictors {
 
 /// Status of a message that is or was in AutoMod queue
 #[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[serde(rename_all = "UPPERCASE")]
 #[non_exhaustive]
@@ -1092,6 +1775,11 @@ pub enum AutomodStatus {
 
 /// Type of creator goal
 #[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum CreatorGoalType {
@@ -1101,10 +1789,426 @@ pub enum CreatorGoalType {
     Subscription,
 }
 
+/// A URL Twitch can deliver [webhook](crate::eventsub::TransportMethod::Webhook) notifications to
+///
+/// Twitch only accepts `https` URLs to public hosts, of at most [`CallbackUrl::MAX_LEN`] bytes.
+/// Checking this before subscribing turns a misconfigured callback into an error when the URL is created, instead of a rejected subscription.
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api2::types::CallbackUrl;
+///
+/// assert!(CallbackUrl::new("https://example.com/eventsub").is_ok());
+/// assert!(CallbackUrl::new("http://example.com/eventsub").is_err());
+/// assert!(CallbackUrl::new("https://localhost/eventsub").is_err());
+/// assert!(CallbackUrl::new_allow_private("https://localhost/eventsub").is_ok());
+/// ```
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, Debug, Clone)]
+#[serde(try_from = "String", into = "String")]
+pub struct CallbackUrl(String);
+
+impl CallbackUrl {
+    /// Longest callback URL accepted
+    pub const MAX_LEN: usize = 2048;
+
+    /// Check that `url` is a valid callback URL
+    pub fn new(url: impl Into<String>) -> Result<Self, CallbackUrlError> {
+        Self::parse(url.into(), false)
+    }
+
+    /// Check that `url` is a valid callback URL, allowing `localhost` and private addresses
+    ///
+    /// Twitch rejects these, use this only for local testing, e.g. with the Twitch CLI.
+    pub fn new_allow_private(url: impl Into<String>) -> Result<Self, CallbackUrlError> {
+        Self::parse(url.into(), true)
+    }
+
+    fn parse(url: String, allow_private: bool) -> Result<Self, CallbackUrlError> {
+        if url.len() > Self::MAX_LEN {
+            return Err(CallbackUrlError::TooLong(url.len()));
+        }
+        let parsed = url::Url::parse(&url)?;
+        if parsed.scheme() != "https" {
+            return Err(CallbackUrlError::NotHttps(parsed.scheme().to_owned()));
+        }
+        let private = match parsed.host() {
+            None => return Err(CallbackUrlError::MissingHost),
+            Some(url::Host::Domain(domain)) => {
+                let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+                domain == "localhost" || domain.ends_with(".localhost")
+            }
+            Some(url::Host::Ipv4(ip)) => {
+                ip.is_loopback()
+                    || ip.is_private()
+                    || ip.is_link_local()
+                    || ip.is_unspecified()
+                    || ip.is_broadcast()
+                    // 100.64.0.0/10, shared address space
+                    || (ip.octets()[0] == 100 && ip.octets()[1] & 0xc0 == 64)
+            }
+            Some(url::Host::Ipv6(ip)) => {
+                ip.is_loopback()
+                    || ip.is_unspecified()
+                    // fc00::/7, unique local
+                    || ip.segments()[0] & 0xfe00 == 0xfc00
+                    // fe80::/10, link local
+                    || ip.segments()[0] & 0xffc0 == 0xfe80
+                    || ip.to_ipv4().map_or(false, |ip| ip.is_loopback() || ip.is_private())
+            }
+        };
+        if private && !allow_private {
+            return Err(CallbackUrlError::PrivateHost(
+                parsed.host_str().unwrap_or_default().to_owned(),
+            ));
+        }
+        Ok(CallbackUrl(url))
+    }
+
+    /// The URL as a string
+    pub fn as_str(&self) -> &str { &self.0 }
+
+    /// Get the inner string
+    pub fn into_string(self) -> String { self.0 }
+}
+
+impl std::convert::TryFrom<String> for CallbackUrl {
+    type Error = CallbackUrlError;
+
+    fn try_from(url: String) -> Result<Self, Self::Error> { Self::parse(url, false) }
+}
+
+impl std::str::FromStr for CallbackUrl {
+    type Err = CallbackUrlError;
+
+    fn from_str(url: &str) -> Result<Self, Self::Err> { Self::new(url) }
+}
+
+impl From<CallbackUrl> for String {
+    fn from(url: CallbackUrl) -> Self { url.0 }
+}
+
+impl AsRef<str> for CallbackUrl {
+    fn as_ref(&self) -> &str { &self.0 }
+}
+
+impl std::fmt::Display for CallbackUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { f.write_str(&self.0) }
+}
+
+/// Errors that can occur when checking a [`CallbackUrl`]
+#[derive(Debug, thiserror::Error, displaydoc::Display)]
+#[non_exhaustive]
+pub enum CallbackUrlError {
+    /// could not parse the url
+    InvalidUrl(#[from] url::ParseError),
+    /// url has scheme {0:?}, only `https` is accepted
+    NotHttps(String),
+    /// url has no host
+    MissingHost,
+    /// host {0:?} is not public
+    PrivateHost(String),
+    /// url is {0} bytes long, longer than the maximum of 2048
+    TooLong(usize),
+}
+
+/// Implement [`arbitrary::Arbitrary`] for braids that accept any string
+#[cfg(feature = "arbitrary")]
+macro_rules! arbitrary_braid {
+    ($($braid:ty),* $(,)?) => {
+        $(
+            impl<'a> arbitrary::Arbitrary<'a> for $braid {
+                fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                    String::arbitrary(u).map(Into::into)
+                }
+            }
+        )*
+    };
+}
+#[cfg(feature = "arbitrary")]
+pub(crate) use arbitrary_braid;
+
+#[cfg(feature = "arbitrary")]
+arbitrary_braid![
+    UserId,
+    RewardId,
+    RedemptionId,
+    DisplayName,
+    Nickname,
+    Email,
+    CategoryId,
+    TagId,
+    VideoId,
+    EventSubId,
+    TeamId,
+    StreamId,
+    StreamMarkerId,
+    RawLanguage,
+    MsgId,
+    PollId,
+    PollChoiceId,
+    PredictionId,
+    PredictionOutcomeId,
+    BadgeSetId,
+    ChatBadgeId,
+    EmoteId,
+    EmoteSetId,
+    StreamSegmentId,
+    HypeTrainId,
+    CreatorGoalId,
+    BlockedTermId,
+    CharityCampaignId,
+    CharityDonationId,
+    TemplatedImageUrl,
+];
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Timestamp {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let s = format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            u.int_in_range(1970..=2100u32)?,
+            u.int_in_range(1..=12u32)?,
+            u.int_in_range(1..=28u32)?,
+            u.int_in_range(0..=23u32)?,
+            u.int_in_range(0..=59u32)?,
+            u.int_in_range(0..=59u32)?,
+        );
+        Timestamp::new(s).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Language {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let s = if u.ratio(1u8, 10)? {
+            "other".to_owned()
+        } else {
+            let mut code = String::new();
+            for _ in 0..2 {
+                code.push(char::from(u.int_in_range(b'a'..=b'z')?));
+            }
+            code
+        };
+        Language::new(s).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// Implement [`rkyv::Archive`] for braids, archiving them as strings
+///
+/// # Panics
+///
+/// Deserializing a [`Timestamp`] or [`Language`] panics if the archived string is not valid.
+#[cfg(feature = "rkyv")]
+macro_rules! rkyv_braid {
+    ($($braid:ident),* $(,)?) => {
+        $(
+            impl rkyv::Archive for $braid {
+                type Archived = rkyv::string::ArchivedString;
+                type Resolver = rkyv::string::StringResolver;
+
+                #[inline]
+                unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+                    rkyv::string::ArchivedString::resolve_from_str(self.as_str(), pos, resolver, out)
+                }
+            }
+
+            impl<S: rkyv::ser::Serializer + ?Sized> rkyv::Serialize<S> for $braid {
+                #[inline]
+                fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+                    rkyv::string::ArchivedString::serialize_from_str(self.as_str(), serializer)
+                }
+            }
+
+            impl<D: rkyv::Fallible + ?Sized> rkyv::Deserialize<$braid, D> for rkyv::string::ArchivedString {
+                #[inline]
+                fn deserialize(&self, _: &mut D) -> Result<$braid, D::Error> {
+                    $crate::types::rkyv_braid!(@new $braid, self.as_str())
+                }
+            }
+        )*
+    };
+    // Validated braids are validated again, archives can't be trusted to contain valid values.
+    // Deserializing can't fail with `rkyv::Infallible`, so an invalid value means a corrupt archive.
+    (@new Timestamp, $s:expr) => {
+        Ok(Timestamp::new($s).expect("archived timestamp should be valid"))
+    };
+    (@new Language, $s:expr) => {
+        Ok(Language::new($s).expect("archived language should be valid"))
+    };
+    (@new $braid:ident, $s:expr) => {
+        Ok($braid::from($s))
+    };
+}
+#[cfg(feature = "rkyv")]
+pub(crate) use rkyv_braid;
+
+#[cfg(feature = "rkyv")]
+rkyv_braid![
+    UserId,
+    RewardId,
+    RedemptionId,
+    DisplayName,
+    Nickname,
+    Email,
+    Timestamp,
+    CategoryId,
+    TagId,
+    VideoId,
+    EventSubId,
+    TeamId,
+    StreamId,
+    StreamMarkerId,
+    Language,
+    RawLanguage,
+    MsgId,
+    PollId,
+    PollChoiceId,
+    PredictionId,
+    PredictionOutcomeId,
+    BadgeSetId,
+    ChatBadgeId,
+    EmoteId,
+    EmoteSetId,
+    StreamSegmentId,
+    HypeTrainId,
+    CreatorGoalId,
+    BlockedTermId,
+    CharityCampaignId,
+    CharityDonationId,
+    TemplatedImageUrl,
+];
+
+/// Implement [`sqlx::Type`], [`sqlx::Encode`] and [`sqlx::Decode`] for braids, storing them as the database's string type
+#[cfg(feature = "sqlx")]
+macro_rules! sqlx_braid {
+    ($($braid:ident),* $(,)?) => {
+        $(
+            impl<DB: sqlx::Database> sqlx::Type<DB> for $braid
+            where String: sqlx::Type<DB>
+            {
+                fn type_info() -> DB::TypeInfo { <String as sqlx::Type<DB>>::type_info() }
+
+                fn compatible(ty: &DB::TypeInfo) -> bool { <String as sqlx::Type<DB>>::compatible(ty) }
+            }
+
+            impl<'q, DB: sqlx::Database> sqlx::Encode<'q, DB> for $braid
+            where String: sqlx::Encode<'q, DB>
+            {
+                fn encode(
+                    self,
+                    buf: &mut <DB as sqlx::database::HasArguments<'q>>::ArgumentBuffer,
+                ) -> sqlx::encode::IsNull {
+                    <String as sqlx::Encode<'q, DB>>::encode(self.into_string(), buf)
+                }
+
+                fn encode_by_ref(
+                    &self,
+                    buf: &mut <DB as sqlx::database::HasArguments<'q>>::ArgumentBuffer,
+                ) -> sqlx::encode::IsNull {
+                    <String as sqlx::Encode<'q, DB>>::encode(self.as_str().to_owned(), buf)
+                }
+
+                fn size_hint(&self) -> usize { self.as_str().len() }
+            }
+
+            impl<'r, DB: sqlx::Database> sqlx::Decode<'r, DB> for $braid
+            where String: sqlx::Decode<'r, DB>
+            {
+                fn decode(
+                    value: <DB as sqlx::database::HasValueRef<'r>>::ValueRef,
+                ) -> Result<Self, sqlx::error::BoxDynError> {
+                    let s = <String as sqlx::Decode<'r, DB>>::decode(value)?;
+                    $crate::types::sqlx_braid!(@new $braid, s)
+                }
+            }
+        )*
+    };
+    (@new Timestamp, $s:expr) => {
+        Timestamp::new($s).map_err(Into::into)
+    };
+    (@new Language, $s:expr) => {
+        Language::new($s).map_err(Into::into)
+    };
+    (@new $braid:ident, $s:expr) => {
+        Ok($braid::new($s))
+    };
+}
+#[cfg(feature = "sqlx")]
+pub(crate) use sqlx_braid;
+
+#[cfg(feature = "sqlx")]
+sqlx_braid![
+    UserId,
+    RewardId,
+    RedemptionId,
+    DisplayName,
+    Nickname,
+    Email,
+    Timestamp,
+    CategoryId,
+    TagId,
+    VideoId,
+    EventSubId,
+    TeamId,
+    StreamId,
+    StreamMarkerId,
+    Language,
+    RawLanguage,
+    MsgId,
+    PollId,
+    PollChoiceId,
+    PredictionId,
+    PredictionOutcomeId,
+    BadgeSetId,
+    ChatBadgeId,
+    EmoteId,
+    EmoteSetId,
+    StreamSegmentId,
+    HypeTrainId,
+    CreatorGoalId,
+    BlockedTermId,
+    CharityCampaignId,
+    CharityDonationId,
+    TemplatedImageUrl,
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    pub fn emote_url_v1_v2() {
+        let v1 = EmoteIdRef::from_str(emote_ids::KAPPA)
+            .url()
+            .size_2x()
+            .render_v1();
+        assert_eq!(v1, "https://static-cdn.jtvnw.net/emoticons/v1/25/2.0");
+        assert_eq!(
+            EmoteUrlBuilder::from_url(&v1).unwrap().dark_mode().render(),
+            "https://static-cdn.jtvnw.net/emoticons/v2/25/default/dark/2.0"
+        );
+
+        let v2 = "https://static-cdn.jtvnw.net/emoticons/v2/emotesv2_dc24652ada1e4c84a5e3ceebae4de709/animated/dark/3.0";
+        let builder = EmoteUrlBuilder::from_url(v2).unwrap();
+        assert_eq!(
+            builder.id.as_str(),
+            "emotesv2_dc24652ada1e4c84a5e3ceebae4de709"
+        );
+        assert_eq!(builder.render(), v2);
+
+        assert!(matches!(
+            EmoteUrlBuilder::from_url(
+                "https://static-cdn.jtvnw.net/emoticons/v2/25/default/sepia/1.0"
+            ),
+            Err(EmoteUrlParseError::InvalidSegment(_))
+        ));
+        assert!(matches!(
+            EmoteUrlBuilder::from_url("https://example.com/emoticons/v1/25/1.0"),
+            Err(EmoteUrlParseError::NotEmoteUrl(_))
+        ));
+    }
+
     #[test]
     pub fn time_test() {
         let mut time1 = Timestamp::new("2021-11-11T10:00:00Z").unwrap();
@@ -1117,4 +2221,141 @@ mod tests {
         #[cfg(feature = "time")]
         dbg!(time.normalize().unwrap());
     }
+
+    #[test]
+    pub fn time_normalize() {
+        let time1 = Timestamp::new("2021-11-11T10:00:00.120Z").unwrap();
+        let time2 = Timestamp::new("2021-11-11t10:00:00.12000z").unwrap();
+        assert_eq!(time1, time2);
+        assert_eq!(time1.as_str(), "2021-11-11T10:00:00.12Z");
+        assert_eq!(
+            Timestamp::new("2021-11-11T10:00:00.000Z").unwrap().as_str(),
+            "2021-11-11T10:00:00Z"
+        );
+        let time3 = Timestamp::new("2021-11-11T10:00:00Z").unwrap();
+        let time4 = Timestamp::new("2021-11-11T10:00:00.5Z").unwrap();
+        let time5 = Timestamp::new("2021-11-11T10:00:00.45Z").unwrap();
+        assert!(time3 < time5 && time5 < time4 && time1 < time4);
+        assert!(TimestampRef::from_normalized_str("2021-11-11T10:00:00.0Z").is_err());
+        #[cfg(feature = "time")]
+        assert_eq!(Timestamp::new("2021-11-11T11:00:00+01:00").unwrap(), time3);
+        assert!(Timestamp::new("").is_err());
+    }
+
+    #[test]
+    pub fn time_offset() {
+        let utc = Timestamp::new("2022-01-01T08:00:00Z").unwrap();
+        #[cfg(feature = "time")]
+        {
+            let offset = Timestamp::new("2022-01-01T10:00:00+02:00").unwrap();
+            assert_eq!(offset.as_str(), "2022-01-01T08:00:00Z");
+            assert_eq!(offset, utc);
+            let fraction = Timestamp::new("2022-01-01T10:00:00.50+02:00").unwrap();
+            assert_eq!(fraction.as_str(), "2022-01-01T08:00:00.5Z");
+            assert!(utc < fraction);
+            let later = Timestamp::new("2022-01-01T07:30:00.25-01:00").unwrap();
+            assert_eq!(later.as_str(), "2022-01-01T08:30:00.25Z");
+            assert!(fraction < later);
+        }
+        #[cfg(not(feature = "time"))]
+        {
+            assert!(Timestamp::new("2022-01-01T10:00:00+02:00").is_err());
+            assert!(Timestamp::new("2022-01-01T10:00:00.5+02:00").is_err());
+            assert!(Timestamp::new("2022-01-01T10:00:00.5-01:00").is_err());
+            let fraction = Timestamp::new("2022-01-01T08:00:00.50Z").unwrap();
+            assert_eq!(fraction.as_str(), "2022-01-01T08:00:00.5Z");
+            assert!(utc < fraction);
+        }
+    }
+
+    #[test]
+    pub fn templated_image_url() {
+        let url = TemplatedImageUrl::from("https://static-cdn.jtvnw.net/cf_vods/d2nvs31859zcd8/twitchdev/335921245/ce0f3a7f-57a3-4152-bc06-0c6610189fb3/thumb/index-0000000000-%{width}x%{height}.jpg");
+        assert!(url.is_templated());
+        assert_eq!(url.render(320, 180), "https://static-cdn.jtvnw.net/cf_vods/d2nvs31859zcd8/twitchdev/335921245/ce0f3a7f-57a3-4152-bc06-0c6610189fb3/thumb/index-0000000000-320x180.jpg");
+        let url = TemplatedImageUrl::from(
+            "https://clips-media-assets.twitch.tv/157589949-preview-480x272.jpg",
+        );
+        assert!(!url.is_templated());
+        assert_eq!(url.render(320, 180), url.as_str());
+    }
+
+    #[test]
+    pub fn language() {
+        assert_eq!(Language::new("de").unwrap().as_str(), "de");
+        assert!(Language::new("").is_err());
+        assert!(Language::new("en-").is_err());
+        assert!(serde_json::from_str::<Language>(r#""asl""#).is_err());
+        assert!(serde_json::from_str::<Language>(r#""pt-br""#).is_ok());
+        let raw = serde_json::from_str::<RawLanguage>(r#""asl""#).unwrap();
+        assert!(raw.to_language().is_err());
+        assert!(serde_json::from_str::<RawLanguage>(r#""""#).is_ok());
+        assert_eq!(
+            RawLanguage::from(Language::new("de").unwrap())
+                .to_language()
+                .unwrap()
+                .as_str(),
+            "de"
+        );
+    }
+
+    #[test]
+    pub fn display_name_login() {
+        let login = UserNameRef::from_str("justintv");
+        assert!(DisplayNameRef::from_str("JustinTV").matches_login(login));
+        assert!(!DisplayNameRef::from_str("JustinTV").is_localized());
+        assert!(!DisplayNameRef::from_str("JustinTV2").matches_login(login));
+        let localized = DisplayNameRef::from_str("ジャスティン");
+        assert!(localized.is_localized());
+        assert!(!localized.matches_login(login));
+    }
+
+    #[test]
+    pub fn user_id_numeric() {
+        use std::convert::TryFrom;
+        let id = UserId::from(27620241u64);
+        assert_eq!(id.as_str(), "27620241");
+        assert_eq!(u64::try_from(id.as_ref()).unwrap(), 27620241);
+        assert!(u64::try_from(UserIdRef::from_str("abc")).is_err());
+    }
+
+    #[test]
+    pub fn callback_url() {
+        assert!(CallbackUrl::new("https://example.com:443/eventsub?a=b").is_ok());
+        assert!(matches!(
+            CallbackUrl::new("http://example.com"),
+            Err(CallbackUrlError::NotHttps(scheme)) if scheme == "http"
+        ));
+        assert!(matches!(
+            CallbackUrl::new("example.com"),
+            Err(CallbackUrlError::InvalidUrl(_))
+        ));
+        for private in &[
+            "https://localhost",
+            "https://app.localhost/eventsub",
+            "https://127.0.0.1",
+            "https://10.0.0.1",
+            "https://192.168.1.2:8080",
+            "https://[::1]",
+            "https://[fd00::1]",
+        ] {
+            assert!(
+                matches!(
+                    CallbackUrl::new(*private),
+                    Err(CallbackUrlError::PrivateHost(_))
+                ),
+                "{}",
+                private
+            );
+            assert!(CallbackUrl::new_allow_private(*private).is_ok());
+        }
+        let long = format!("https://example.com/{}", "a".repeat(CallbackUrl::MAX_LEN));
+        assert!(matches!(
+            CallbackUrl::new(long),
+            Err(CallbackUrlError::TooLong(_))
+        ));
+        let url: CallbackUrl = serde_json::from_str(r#""https://example.com""#).unwrap();
+        assert_eq!(url.as_str(), "https://example.com");
+        assert!(serde_json::from_str::<CallbackUrl>(r#""https://localhost""#).is_err());
+    }
 }